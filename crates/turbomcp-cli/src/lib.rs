@@ -7,8 +7,10 @@
 //!
 //! - Connect to MCP servers via multiple transports (HTTP, WebSocket, STDIO)
 //! - List available tools and their schemas
-//! - Call tools with JSON arguments
-//! - Export tool schemas for documentation
+//! - Call tools with JSON arguments, optionally validating them against the
+//!   tool's input schema or streaming its progress
+//! - Run `list`, `call` and `read` commands over one persistent connection
+//! - Export tool, prompt and resource schemas for documentation
 //! - Support for authentication via bearer tokens
 //! - JSON and human-readable output formats
 //!
@@ -22,14 +24,26 @@
 //! turbomcp-cli tools-call --transport http --url http://localhost:8080/mcp \
 //!   add --arguments '{"a": 5, "b": 3}'
 //!
+//! # Follow a long-running tool's progress as it reports it
+//! turbomcp-cli tools-call --command "./my-server" --name build --stream
+//!
+//! # Run several commands against one server process
+//! turbomcp-cli interactive --command "./my-server"
+//!
 //! # Export tool schemas
 //! turbomcp-cli schema-export --transport http --url http://localhost:8080/mcp --json
+//!
+//! # Export prompts and resources alongside the tools
+//! turbomcp-cli schema-export --transport http --url http://localhost:8080/mcp \
+//!   --include-prompts --include-resources --output schemas.json
 //! ```
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use jsonschema::{Draft, JSONSchema};
 use serde_json::json;
 use std::collections::HashMap;
 use tokio::runtime::Runtime;
+use turbomcp_protocol::template;
 
 /// Main CLI application structure
 #[derive(Parser, Debug)]
@@ -61,8 +75,17 @@ pub enum Commands {
         /// Arguments as JSON (object)
         #[arg(long, default_value = "{}")]
         arguments: String,
+        /// Show progress and log output while the tool runs (stdio and ws)
+        #[arg(long)]
+        stream: bool,
+        /// Check the arguments against the tool's input schema before calling it
+        #[arg(long)]
+        validate: bool,
     },
-    /// Export tool schemas from a running server
+    /// Keep one connection open and run commands typed line by line
+    #[command(name = "interactive")]
+    Interactive(Connection),
+    /// Export tool schemas, and optionally prompts and resources, from a running server
     #[command(name = "schema-export")]
     SchemaExport {
         #[command(flatten)]
@@ -70,6 +93,8 @@ pub enum Commands {
         /// Output file path (if not specified, outputs to stdout)
         #[arg(long)]
         output: Option<String>,
+        #[command(flatten)]
+        sections: SchemaSections,
     },
 }
 
@@ -89,14 +114,31 @@ pub fn run_cli() {
                 conn,
                 name,
                 arguments,
+                stream,
+                validate,
             } => {
-                if let Err(e) = cmd_tools_call(conn, name, arguments).await {
+                let result = if stream {
+                    cmd_tools_call_stream(conn, name, arguments, validate).await
+                } else {
+                    cmd_tools_call(conn, name, arguments, validate).await
+                };
+                if let Err(e) = result {
                     eprintln!("error: {e}");
                     std::process::exit(1);
                 }
             }
-            Commands::SchemaExport { conn, output } => {
-                if let Err(e) = cmd_schema_export(conn, output).await {
+            Commands::Interactive(conn) => {
+                if let Err(e) = cmd_interactive(conn).await {
+                    eprintln!("error: {e}");
+                    std::process::exit(1);
+                }
+            }
+            Commands::SchemaExport {
+                conn,
+                output,
+                sections,
+            } => {
+                if let Err(e) = cmd_schema_export(conn, output, sections).await {
                     eprintln!("error: {e}");
                     std::process::exit(1);
                 }
@@ -120,9 +162,48 @@ pub struct Connection {
     /// Bearer token or API key
     #[arg(long)]
     pub auth: Option<String>,
-    /// Emit JSON output
+    /// Emit JSON output (same as `--format json`)
     #[arg(long)]
     pub json: bool,
+    /// How to print results
+    #[arg(long, value_enum, default_value_t, conflicts_with = "json")]
+    pub format: OutputFormat,
+}
+
+impl Connection {
+    /// The output format selected by `--format`, or by `--json`
+    pub fn output_format(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else {
+            self.format
+        }
+    }
+}
+
+/// Formats results can be printed in
+#[derive(Debug, Clone, Copy, Default, ValueEnum, PartialEq)]
+pub enum OutputFormat {
+    /// Compact JSON on one line
+    #[default]
+    Plain,
+    /// Pretty-printed JSON
+    Json,
+    /// YAML
+    Yaml,
+    /// Aligned columns for tool listings; other results print as `plain`
+    Table,
+}
+
+/// Sections `schema-export` adds alongside the tool schemas
+#[derive(Args, Debug, Clone, Default)]
+pub struct SchemaSections {
+    /// Also export prompts with their argument schemas
+    #[arg(long)]
+    pub include_prompts: bool,
+    /// Also export resources and resource templates
+    #[arg(long)]
+    pub include_resources: bool,
 }
 
 /// Available transport types for connecting to MCP servers
@@ -153,357 +234,904 @@ fn determine_transport(conn: &Connection) -> TransportKind {
     }
 }
 
-pub async fn cmd_tools_list(conn: Connection) -> Result<(), String> {
-    let transport = determine_transport(&conn);
-    match transport {
-        TransportKind::Stdio => stdio_list_tools(&conn).await,
-        TransportKind::Ws => ws_list_tools(&conn).await,
-        TransportKind::Http => http_list_tools(&conn).await,
+/// Open an initialized [`Session`] for a single command
+///
+/// In `--json` mode the negotiated server is reported on stderr, keeping
+/// stdout to the response.
+async fn connect(conn: &Connection) -> Result<Session, String> {
+    let session = Session::open(conn.clone()).await?;
+    if conn.output_format() == OutputFormat::Json {
+        let server = &session.initialize["serverInfo"];
+        eprintln!(
+            "Connected to {} {} (protocol {})",
+            server["name"].as_str().unwrap_or("server"),
+            server["version"].as_str().unwrap_or_default(),
+            session.initialize["protocolVersion"]
+                .as_str()
+                .unwrap_or("unknown")
+        );
     }
+    Ok(session)
+}
+
+/// Parse `--arguments`, which must be a JSON object
+fn parse_arguments(arguments: &str) -> Result<HashMap<String, serde_json::Value>, String> {
+    serde_json::from_str(arguments).map_err(|e| format!("invalid --arguments JSON: {e}"))
+}
+
+pub async fn cmd_tools_list(conn: Connection) -> Result<(), String> {
+    let mut session = connect(&conn).await?;
+    let response = session.request("tools/list", json!({})).await?;
+    output(&conn, &response)
 }
 
 pub async fn cmd_tools_call(
     conn: Connection,
     name: String,
     arguments: String,
+    validate: bool,
 ) -> Result<(), String> {
-    let transport = determine_transport(&conn);
-    match transport {
-        TransportKind::Stdio => stdio_call_tool(&conn, name, arguments).await,
-        TransportKind::Ws => ws_call_tool(&conn, name, arguments).await,
-        TransportKind::Http => http_call_tool(&conn, name, arguments).await,
+    let args = parse_arguments(&arguments)?;
+    let mut session = connect(&conn).await?;
+    if validate {
+        validate_call(&mut session, &name, &args).await?;
     }
+    let response = session
+        .request("tools/call", json!({"name": name, "arguments": args}))
+        .await?;
+    output(&conn, &response)
 }
 
-pub async fn cmd_schema_export(conn: Connection, output_path: Option<String>) -> Result<(), String> {
-    // Get schema data
-    let transport = determine_transport(&conn);
-    let schema_data = match transport {
-        TransportKind::Stdio => stdio_get_schemas(&conn).await?,
-        TransportKind::Ws => ws_get_schemas(&conn).await?,
-        TransportKind::Http => http_get_schemas(&conn).await?,
+/// Check arguments for `name` against the input schema `tools/list` gives it
+///
+/// Tools that are not listed, or whose schema is absent or uses `$ref`,
+/// are called unchecked after a warning.
+async fn validate_call(
+    session: &mut Session,
+    name: &str,
+    arguments: &HashMap<String, serde_json::Value>,
+) -> Result<(), String> {
+    let listing = list_result(session, "tools/list").await?;
+    let tool = listing["tools"]
+        .as_array()
+        .and_then(|tools| tools.iter().find(|tool| tool["name"] == name));
+    let schema = match tool.map(|tool| &tool["inputSchema"]) {
+        None => {
+            eprintln!("warning: tool '{name}' is not in the tool listing; skipping validation");
+            return Ok(());
+        }
+        Some(schema) if !schema.is_object() => {
+            eprintln!("warning: tool '{name}' has no input schema; skipping validation");
+            return Ok(());
+        }
+        Some(schema) if uses_ref(schema) => {
+            eprintln!("warning: input schema of '{name}' uses $ref; skipping validation");
+            return Ok(());
+        }
+        Some(schema) => schema,
     };
-    
-    // Output to file or stdout
-    if let Some(path) = output_path {
-        use std::fs;
-        let pretty_json = serde_json::to_string_pretty(&schema_data)
-            .map_err(|e| format!("Failed to format JSON: {e}"))?;
-        fs::write(&path, pretty_json)
-            .map_err(|e| format!("Failed to write to {}: {e}", path))?;
-        eprintln!("Schemas exported to {}", path);
-    } else {
-        output(&conn, &schema_data)?;
+    validate_arguments(schema, &json!(arguments))
+        .map_err(|e| format!("Arguments for '{name}' do not match its input schema: {e}"))
+}
+
+/// Whether `schema` refers to definitions with `$ref` anywhere within it
+fn uses_ref(schema: &serde_json::Value) -> bool {
+    match schema {
+        serde_json::Value::Object(map) => map.contains_key("$ref") || map.values().any(uses_ref),
+        serde_json::Value::Array(items) => items.iter().any(uses_ref),
+        _ => false,
     }
-    
-    Ok(())
 }
 
-async fn http_list_tools(conn: &Connection) -> Result<(), String> {
-    let req = json!({"jsonrpc":"2.0","id":"1","method":"tools/list"});
-    let res = http_post(conn, req).await?;
-    output(conn, &res)
+/// Validate tool arguments against an input schema
+///
+/// On failure, returns every violation, such as a missing required property
+/// or a value of the wrong type, prefixed with the path to the offending
+/// value and separated by semicolons.
+pub fn validate_arguments(
+    schema: &serde_json::Value,
+    arguments: &serde_json::Value,
+) -> Result<(), String> {
+    let compiled = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(schema)
+        .map_err(|e| format!("invalid input schema: {e}"))?;
+    compiled.validate(arguments).map_err(|errors| {
+        errors
+            .map(|e| match e.instance_path.to_string() {
+                path if path.is_empty() => e.to_string(),
+                path => format!("{path}: {e}"),
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    })
 }
 
-async fn http_call_tool(conn: &Connection, name: String, arguments: String) -> Result<(), String> {
-    let args_map: HashMap<String, serde_json::Value> =
-        serde_json::from_str(&arguments).map_err(|e| format!("invalid --arguments JSON: {e}"))?;
-    let req = json!({
-        "jsonrpc":"2.0","id":"1","method":"tools/call",
-        "params": {"name": name, "arguments": args_map}
-    });
-    let res = http_post(conn, req).await?;
-    output(conn, &res)
-}
-
-async fn http_get_schemas(conn: &Connection) -> Result<serde_json::Value, String> {
-    // List, then return each tool's inputSchema
-    let req = json!({"jsonrpc":"2.0","id":"1","method":"tools/list"});
-    let res = http_post(conn, req).await?;
-    if let Some(result) = res.get("result")
-        && let Some(tools) = result.get("tools").and_then(|v| v.as_array())
-    {
-        let mut out = vec![];
-        for t in tools {
-            let name = t.get("name").and_then(|v| v.as_str()).unwrap_or("");
-            let schema = t.get("inputSchema").cloned().unwrap_or(json!({}));
-            out.push(json!({"name": name, "schema": schema}));
+/// Progress token attached to streamed tool calls
+const STREAM_PROGRESS_TOKEN: &str = "turbomcp-cli";
+
+/// Call a tool, printing its progress and log notifications as they arrive
+///
+/// Each notification is printed as it is received: as one line of JSON in
+/// `--json` mode, so the output is newline-delimited JSON ending with the
+/// response, and otherwise as a progress bar or log line. The HTTP
+/// transport answers with the result alone, so there is nothing to stream.
+pub async fn cmd_tools_call_stream(
+    conn: Connection,
+    name: String,
+    arguments: String,
+    validate: bool,
+) -> Result<(), String> {
+    let response = call_tool_streaming(&conn, name, arguments, validate, |event| {
+        if let Some(line) = render_event(&conn, event) {
+            println!("{line}");
         }
-        return Ok(json!({"schemas": out}));
+    })
+    .await?;
+    if conn.output_format() == OutputFormat::Json {
+        println!("{response}");
+        Ok(())
+    } else {
+        output(&conn, &response)
     }
-    Ok(res)
 }
 
-async fn http_post(
+/// Call a tool with a progress token, passing every notification the
+/// server sends before the response to `on_event`
+pub async fn call_tool_streaming(
     conn: &Connection,
-    body: serde_json::Value,
+    name: String,
+    arguments: String,
+    validate: bool,
+    on_event: impl FnMut(&serde_json::Value),
 ) -> Result<serde_json::Value, String> {
-    let client = reqwest::Client::new();
-    let mut req = client.post(&conn.url).json(&body);
-    if let Some(auth) = &conn.auth {
-        req = req.bearer_auth(auth);
+    let args = parse_arguments(&arguments)?;
+    let mut session = connect(conn).await?;
+    if validate {
+        validate_call(&mut session, &name, &args).await?;
     }
-    let res = req.send().await.map_err(|e| e.to_string())?;
-    let status = res.status();
-    let text = res.text().await.map_err(|e| e.to_string())?;
-    if !status.is_success() {
-        return Err(format!("HTTP {status}: {text}"));
+    if determine_transport(conn) == TransportKind::Http {
+        eprintln!("warning: --stream needs the stdio or ws transport; waiting for the result");
     }
-    serde_json::from_str(&text).map_err(|e| format!("invalid JSON: {e}"))
+    let params = json!({
+        "name": name,
+        "arguments": args,
+        "_meta": {"progressToken": STREAM_PROGRESS_TOKEN}
+    });
+    session
+        .request_with_events("tools/call", params, on_event)
+        .await
 }
 
-// WebSocket implementation functions
-async fn ws_list_tools(conn: &Connection) -> Result<(), String> {
-    use serde_json::json;
+/// Width of the progress bar in human output
+const PROGRESS_BAR_WIDTH: usize = 20;
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 1,
-        "method": "tools/list",
-        "params": {}
-    });
-
-    let response = ws_send_request(conn, request).await?;
-    output(conn, &response)
+/// Render a notification received during a streamed call
+///
+/// Returns the notification itself as compact JSON in `--json` mode. In
+/// human mode, progress with a known total becomes a bar and percentage,
+/// progress without one a count, and log messages their level and data;
+/// notifications of any other kind are not shown.
+pub fn render_event(conn: &Connection, event: &serde_json::Value) -> Option<String> {
+    if conn.output_format() == OutputFormat::Json {
+        return Some(event.to_string());
+    }
+    let params = event.get("params")?;
+    let message = params
+        .get("message")
+        .and_then(|m| m.as_str())
+        .map(|m| format!(" {m}"))
+        .unwrap_or_default();
+    match event.get("method")?.as_str()? {
+        "notifications/progress" => {
+            let progress = params.get("progress")?.as_f64()?;
+            match params.get("total").and_then(|t| t.as_f64()) {
+                Some(total) if total > 0.0 => {
+                    let fraction = (progress / total).clamp(0.0, 1.0);
+                    let filled = (fraction * PROGRESS_BAR_WIDTH as f64).round() as usize;
+                    Some(format!(
+                        "[{}{}] {:>3.0}%{message}",
+                        "#".repeat(filled),
+                        "-".repeat(PROGRESS_BAR_WIDTH - filled),
+                        fraction * 100.0
+                    ))
+                }
+                _ => Some(format!("progress {progress}{message}")),
+            }
+        }
+        "notifications/progress/complete" => {
+            let status = params.get("status")?.as_str()?;
+            let summary = params
+                .get("summary")
+                .and_then(|s| s.as_str())
+                .map(|s| format!(": {s}"))
+                .unwrap_or_default();
+            Some(format!("finished ({status}){summary}"))
+        }
+        "notifications/message" => {
+            let level = params
+                .get("level")
+                .and_then(|l| l.as_str())
+                .unwrap_or("info");
+            let data = params.get("data")?;
+            let text = data
+                .as_str()
+                .map_or_else(|| data.to_string(), str::to_string);
+            Some(format!("[{level}] {text}"))
+        }
+        _ => None,
+    }
 }
 
-async fn ws_call_tool(conn: &Connection, name: String, arguments: String) -> Result<(), String> {
-    use serde_json::json;
+/// Whether a message from the server is a notification rather than the
+/// response to a request
+fn is_notification(message: &serde_json::Value) -> bool {
+    message.get("method").is_some() && message.get("id").is_none()
+}
 
-    let args: serde_json::Value =
-        serde_json::from_str(&arguments).map_err(|e| format!("Invalid JSON arguments: {e}"))?;
+pub async fn cmd_schema_export(
+    conn: Connection,
+    output_path: Option<String>,
+    sections: SchemaSections,
+) -> Result<(), String> {
+    // Get schema data
+    let schema_data = get_schemas(&conn, &sections).await?;
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 2,
-        "method": "tools/call",
-        "params": {
-            "name": name,
-            "arguments": args
-        }
-    });
+    // Output to file or stdout
+    if let Some(path) = output_path {
+        use std::fs;
+        let pretty_json = serde_json::to_string_pretty(&schema_data)
+            .map_err(|e| format!("Failed to format JSON: {e}"))?;
+        fs::write(&path, pretty_json)
+            .map_err(|e| format!("Failed to write to {}: {e}", path))?;
+        eprintln!("Schemas exported to {}", path);
+    } else {
+        output(&conn, &schema_data)?;
+    }
 
-    let response = ws_send_request(conn, request).await?;
-    output(conn, &response)
+    Ok(())
 }
 
-async fn ws_get_schemas(conn: &Connection) -> Result<serde_json::Value, String> {
-    use serde_json::json;
+async fn get_schemas(
+    conn: &Connection,
+    sections: &SchemaSections,
+) -> Result<serde_json::Value, String> {
+    let mut session = connect(conn).await?;
+    let tools = session.request("tools/list", json!({})).await?;
+    // Without a tool listing there is nothing to export; pass the response on
+    let Some(tools) = tools.get("result") else {
+        return Ok(tools);
+    };
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 3,
-        "method": "tools/list",
-        "params": {}
-    });
+    let prompts = if sections.include_prompts {
+        Some(list_result(&mut session, "prompts/list").await?)
+    } else {
+        None
+    };
+    let resources = if sections.include_resources {
+        let mut resources = list_result(&mut session, "resources/list").await?;
+        // Servers without a template listing report templates among resources
+        if let Ok(mut templates) = list_result(&mut session, "resources/templates/list").await
+            && let Some(templates) = templates.get_mut("resourceTemplates")
+        {
+            resources["resourceTemplates"] = templates.take();
+        }
+        Some(resources)
+    } else {
+        None
+    };
+    Ok(build_schema_document(
+        tools,
+        prompts.as_ref(),
+        resources.as_ref(),
+    ))
+}
 
-    let response = ws_send_request(conn, request).await?;
+async fn list_result(session: &mut Session, method: &str) -> Result<serde_json::Value, String> {
+    let mut response = session.request(method, json!({})).await?;
+    if let Some(error) = response.get("error") {
+        return Err(format!("{method} failed: {error}"));
+    }
+    response
+        .get_mut("result")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| format!("{method} returned no result"))
+}
+
+/// Build the `schema-export` document from listing results
+///
+/// `schemas` holds each tool's input schema. Prompts are exported with their
+/// arguments as an object schema of string properties, the form prompt
+/// arguments take on the wire. Templates come from `resourceTemplates`, as
+/// merged in from `resources/templates/list`, and from resources whose URI
+/// has `{param}` placeholders, as servers without a template listing report
+/// them; each is exported with its parameter names.
+pub fn build_schema_document(
+    tools: &serde_json::Value,
+    prompts: Option<&serde_json::Value>,
+    resources: Option<&serde_json::Value>,
+) -> serde_json::Value {
+    let list = |value: &serde_json::Value, key: &str| -> Vec<serde_json::Value> {
+        value
+            .get(key)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default()
+    };
 
-    // Transform response to extract schemas
-    if let Some(result) = response.get("result")
-        && let Some(tools) = result.get("tools").and_then(|t| t.as_array())
-    {
-        let mut out = Vec::new();
-        for tool in tools {
+    let schemas: Vec<_> = list(tools, "tools")
+        .iter()
+        .map(|tool| {
             let name = tool
                 .get("name")
                 .and_then(|n| n.as_str())
                 .unwrap_or("unknown");
             let schema = tool.get("inputSchema").cloned().unwrap_or(json!({}));
-            out.push(json!({"name": name, "schema": schema}));
+            json!({"name": name, "schema": schema})
+        })
+        .collect();
+    let mut document = json!({"schemas": schemas});
+
+    if let Some(prompts) = prompts {
+        let prompts: Vec<_> = list(prompts, "prompts")
+            .iter()
+            .map(|prompt| {
+                let mut properties = serde_json::Map::new();
+                let mut required = Vec::new();
+                for arg in list(prompt, "arguments") {
+                    let Some(name) = arg.get("name").and_then(|n| n.as_str()) else {
+                        continue;
+                    };
+                    let mut property = json!({"type": "string"});
+                    if let Some(description) = arg.get("description") {
+                        property["description"] = description.clone();
+                    }
+                    properties.insert(name.to_string(), property);
+                    if arg.get("required").and_then(|r| r.as_bool()) == Some(true) {
+                        required.push(name.to_string());
+                    }
+                }
+                json!({
+                    "name": prompt.get("name").cloned().unwrap_or(json!("unknown")),
+                    "description": prompt.get("description").cloned(),
+                    "schema": {
+                        "type": "object",
+                        "properties": properties,
+                        "required": required
+                    }
+                })
+            })
+            .collect();
+        document["prompts"] = json!(prompts);
+    }
+
+    if let Some(resources) = resources {
+        let parameters = |uri: &str| -> Vec<String> {
+            template::placeholders(uri)
+                .map(|names| names.into_iter().map(str::to_string).collect())
+                .unwrap_or_default()
+        };
+        let (listed, fixed): (Vec<_>, Vec<_>) =
+            list(resources, "resources")
+                .into_iter()
+                .partition(|resource| {
+                    resource
+                        .get("uri")
+                        .and_then(|u| u.as_str())
+                        .is_some_and(|uri| !parameters(uri).is_empty())
+                });
+        let mut templates = list(resources, "resourceTemplates");
+        for mut resource in listed {
+            let uri = resource
+                .as_object_mut()
+                .and_then(|r| r.remove("uri"))
+                .unwrap_or_default();
+            if !templates.iter().any(|t| t.get("uriTemplate") == Some(&uri)) {
+                resource["uriTemplate"] = uri;
+                templates.push(resource);
+            }
+        }
+        for template in &mut templates {
+            let names = parameters(
+                template
+                    .get("uriTemplate")
+                    .and_then(|u| u.as_str())
+                    .unwrap_or_default(),
+            );
+            template["parameters"] = json!(names);
         }
-        return Ok(json!({"schemas": out}));
+        document["resources"] = json!({"resources": fixed, "templates": templates});
     }
-    Ok(response)
-}
 
-async fn ws_send_request(
-    conn: &Connection,
-    request: serde_json::Value,
-) -> Result<serde_json::Value, String> {
-    use futures::{SinkExt, StreamExt};
-    use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+    document
+}
 
-    // Convert HTTP/HTTPS URL to WebSocket URL
-    let ws_url = conn
-        .url
+/// Convert the HTTP/HTTPS URL in `conn` to its WebSocket URL
+fn ws_url(conn: &Connection) -> String {
+    conn.url
         .replace("http://", "ws://")
         .replace("https://", "wss://")
-        .replace("/mcp", "/ws");
+        .replace("/mcp", "/ws")
+}
 
-    // Connect to WebSocket server
-    let (ws_stream, _) = connect_async(&ws_url)
-        .await
-        .map_err(|e| format!("Failed to connect to WebSocket at {ws_url}: {e}"))?;
+/// A line typed at the `interactive` prompt
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplCommand {
+    /// `list`: list the server's tools
+    List,
+    /// `call <tool> [json]`: call a tool, with `{}` when no arguments are given
+    Call {
+        /// Tool name
+        name: String,
+        /// Arguments object
+        arguments: serde_json::Value,
+    },
+    /// `read <uri>`: read a resource
+    Read {
+        /// Resource URI
+        uri: String,
+    },
+    /// `quit` or `exit`: close the session
+    Quit,
+}
 
-    let (mut ws_sender, mut ws_receiver) = ws_stream.split();
+impl ReplCommand {
+    /// Parse one input line, returning `None` for a blank line
+    pub fn parse(line: &str) -> Result<Option<Self>, String> {
+        let line = line.trim();
+        let (word, rest) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(word, rest)| (word, rest.trim()));
+        let command = match word {
+            "" => return Ok(None),
+            "list" => Self::List,
+            "quit" | "exit" => Self::Quit,
+            "call" => {
+                let (name, arguments) = rest
+                    .split_once(char::is_whitespace)
+                    .map_or((rest, "{}"), |(name, arguments)| (name, arguments.trim()));
+                if name.is_empty() {
+                    return Err("usage: call <tool> [json arguments]".to_string());
+                }
+                let arguments = serde_json::from_str(arguments)
+                    .map_err(|e| format!("Invalid JSON arguments: {e}"))?;
+                Self::Call {
+                    name: name.to_string(),
+                    arguments,
+                }
+            }
+            "read" if !rest.is_empty() => Self::Read {
+                uri: rest.to_string(),
+            },
+            "read" => return Err("usage: read <uri>".to_string()),
+            other => {
+                return Err(format!(
+                    "Unknown command '{other}'; expected list, call, read or quit"
+                ));
+            }
+        };
+        Ok(Some(command))
+    }
+}
 
-    // Send the JSON-RPC request
-    let request_text =
-        serde_json::to_string(&request).map_err(|e| format!("Failed to serialize request: {e}"))?;
+/// The link a [`Session`] keeps open between requests
+enum SessionLink {
+    Stdio {
+        child: std::process::Child,
+        stdin: std::process::ChildStdin,
+        stdout: std::io::BufReader<std::process::ChildStdout>,
+    },
+    Http {
+        client: reqwest::Client,
+        /// `Mcp-Session-Id` the server assigned at initialization, if any
+        session_id: Option<String>,
+    },
+    Ws(
+        tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+    ),
+}
 
-    ws_sender
-        .send(Message::Text(request_text))
-        .await
-        .map_err(|e| format!("Failed to send WebSocket message: {e}"))?;
-
-    // Wait for response
-    match ws_receiver.next().await {
-        Some(Ok(Message::Text(response_text))) => serde_json::from_str(&response_text)
-            .map_err(|e| format!("Failed to parse JSON response: {e}")),
-        Some(Ok(msg)) => Err(format!("Unexpected WebSocket message type: {msg:?}")),
-        Some(Err(e)) => Err(format!("WebSocket error: {e}")),
-        None => Err("WebSocket connection closed unexpectedly".to_string()),
-    }
+/// An initialized connection to a server
+///
+/// Opening a session performs the `initialize` handshake, after which the
+/// connection, and for stdio the server process, lasts until the session is
+/// dropped; a stdio server is then killed. Each single-shot command opens
+/// one for its requests, while `interactive` keeps one for every command.
+pub struct Session {
+    conn: Connection,
+    link: SessionLink,
+    next_id: u64,
+    /// The server's `initialize` result
+    pub initialize: serde_json::Value,
 }
 
-// Stdio implementation functions
-async fn stdio_list_tools(conn: &Connection) -> Result<(), String> {
-    use serde_json::json;
+impl Session {
+    /// Connect over the transport `conn` selects and initialize
+    pub async fn open(conn: Connection) -> Result<Self, String> {
+        let link = match determine_transport(&conn) {
+            TransportKind::Stdio => {
+                use std::process::{Command, Stdio};
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 1,
-        "method": "tools/list",
-        "params": {}
-    });
+                let command_str = conn.command.as_deref().unwrap_or(&conn.url);
+                let mut parts = command_str.split_whitespace();
+                let command = parts
+                    .next()
+                    .ok_or("No command specified for STDIO transport")?;
+                let mut child = Command::new(command)
+                    .args(parts)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .map_err(|e| format!("Failed to spawn command '{command}': {e}"))?;
+                let stdin = child.stdin.take().ok_or("Failed to get stdin handle")?;
+                let stdout = child.stdout.take().ok_or("Failed to get stdout handle")?;
+                SessionLink::Stdio {
+                    child,
+                    stdin,
+                    stdout: std::io::BufReader::new(stdout),
+                }
+            }
+            TransportKind::Http => SessionLink::Http {
+                client: reqwest::Client::new(),
+                session_id: None,
+            },
+            TransportKind::Ws => {
+                let url = ws_url(&conn);
+                let (stream, _) = tokio_tungstenite::connect_async(&url)
+                    .await
+                    .map_err(|e| format!("Failed to connect to WebSocket at {url}: {e}"))?;
+                SessionLink::Ws(stream)
+            }
+        };
+        let mut session = Self {
+            conn,
+            link,
+            next_id: 1,
+            initialize: serde_json::Value::Null,
+        };
 
-    let response = stdio_send_request(conn, request).await?;
-    output(conn, &response)
-}
+        let response = session
+            .request(
+                "initialize",
+                json!({
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": {},
+                    "clientInfo": {
+                        "name": "turbomcp-cli",
+                        "title": "TurboMCP CLI",
+                        "version": env!("CARGO_PKG_VERSION")
+                    }
+                }),
+            )
+            .await?;
+        session.initialize = match response.get("result") {
+            Some(result) => result.clone(),
+            None => return Err(format!("initialize failed: {response}")),
+        };
+        session
+            .send(json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+            .await?;
+        Ok(session)
+    }
 
-async fn stdio_call_tool(conn: &Connection, name: String, arguments: String) -> Result<(), String> {
-    use serde_json::json;
+    /// Capabilities the server reported at initialization
+    pub fn capabilities(&self) -> &serde_json::Value {
+        &self.initialize["capabilities"]
+    }
 
-    let args: serde_json::Value =
-        serde_json::from_str(&arguments).map_err(|e| format!("Invalid JSON arguments: {e}"))?;
+    /// Send a request and return the whole JSON-RPC response
+    ///
+    /// Notifications and other messages the server sends in between are
+    /// skipped.
+    pub async fn request(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, String> {
+        self.request_with_events(method, params, |_| {}).await
+    }
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 2,
-        "method": "tools/call",
-        "params": {
-            "name": name,
-            "arguments": args
+    /// Send a request, passing notifications that arrive before the
+    /// response to `on_event`
+    ///
+    /// An HTTP server answers with the response alone, so `on_event` is
+    /// never called there.
+    pub async fn request_with_events(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+        mut on_event: impl FnMut(&serde_json::Value),
+    ) -> Result<serde_json::Value, String> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params});
+        if let SessionLink::Http { .. } = self.link {
+            return self.http_request(request).await;
         }
-    });
+        self.send(request).await?;
+        loop {
+            let message = self.receive().await?;
+            if is_notification(&message) {
+                on_event(&message);
+            } else if message.get("method").is_none() && message["id"] == id {
+                return Ok(message);
+            }
+        }
+    }
 
-    let response = stdio_send_request(conn, request).await?;
-    output(conn, &response)
-}
+    /// Run one REPL command, returning the response to print
+    pub async fn execute(&mut self, command: &ReplCommand) -> Result<serde_json::Value, String> {
+        match command {
+            ReplCommand::List => self.request("tools/list", json!({})).await,
+            ReplCommand::Call { name, arguments } => {
+                self.request("tools/call", json!({"name": name, "arguments": arguments}))
+                    .await
+            }
+            ReplCommand::Read { uri } => self.request("resources/read", json!({"uri": uri})).await,
+            ReplCommand::Quit => Ok(serde_json::Value::Null),
+        }
+    }
 
-async fn stdio_get_schemas(conn: &Connection) -> Result<serde_json::Value, String> {
-    use serde_json::json;
+    /// Write one message to the server, without waiting for a reply
+    async fn send(&mut self, message: serde_json::Value) -> Result<(), String> {
+        if let SessionLink::Http { .. } = self.link {
+            return self.http_request(message).await.map(drop);
+        }
+        match &mut self.link {
+            SessionLink::Stdio { stdin, .. } => {
+                use std::io::Write;
+                writeln!(stdin, "{message}").map_err(|e| format!("Failed to write request: {e}"))
+            }
+            SessionLink::Ws(stream) => {
+                use futures::SinkExt;
+                stream
+                    .send(tokio_tungstenite::tungstenite::protocol::Message::Text(
+                        message.to_string(),
+                    ))
+                    .await
+                    .map_err(|e| format!("Failed to send WebSocket message: {e}"))
+            }
+            SessionLink::Http { .. } => unreachable!("HTTP messages are posted above"),
+        }
+    }
 
-    let request = json!({
-        "jsonrpc": "2.0",
-        "id": 3,
-        "method": "tools/list",
-        "params": {}
-    });
+    /// Read the next JSON message from a stdio or WebSocket server
+    async fn receive(&mut self) -> Result<serde_json::Value, String> {
+        match &mut self.link {
+            SessionLink::Stdio { stdout, .. } => {
+                use std::io::BufRead;
+                let mut line = String::new();
+                loop {
+                    line.clear();
+                    let read = stdout
+                        .read_line(&mut line)
+                        .map_err(|e| format!("Failed to read response: {e}"))?;
+                    if read == 0 {
+                        return Err("Server closed the connection".to_string());
+                    }
+                    // Servers may log to stdout; skip anything that is not JSON
+                    if let Ok(message) = serde_json::from_str(&line) {
+                        return Ok(message);
+                    }
+                }
+            }
+            SessionLink::Ws(stream) => {
+                use futures::StreamExt;
+                use tokio_tungstenite::tungstenite::protocol::Message;
+                loop {
+                    match stream.next().await {
+                        Some(Ok(Message::Text(text))) => {
+                            return serde_json::from_str(&text)
+                                .map_err(|e| format!("Failed to parse JSON response: {e}"));
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => return Err(format!("WebSocket error: {e}")),
+                        None => return Err("WebSocket connection closed unexpectedly".to_string()),
+                    }
+                }
+            }
+            SessionLink::Http { .. } => {
+                Err("HTTP responses arrive with their requests".to_string())
+            }
+        }
+    }
 
-    let response = stdio_send_request(conn, request).await?;
+    /// POST a message, carrying the session id the server assigned
+    async fn http_request(&mut self, body: serde_json::Value) -> Result<serde_json::Value, String> {
+        let SessionLink::Http { client, session_id } = &mut self.link else {
+            unreachable!("only called on HTTP sessions");
+        };
+        let mut req = client.post(&self.conn.url).json(&body);
+        if let Some(auth) = &self.conn.auth {
+            req = req.bearer_auth(auth);
+        }
+        if let Some(id) = session_id {
+            req = req.header("Mcp-Session-Id", id.as_str());
+        }
+        let res = req.send().await.map_err(|e| e.to_string())?;
+        if let Some(id) = res
+            .headers()
+            .get("Mcp-Session-Id")
+            .and_then(|v| v.to_str().ok())
+        {
+            *session_id = Some(id.to_string());
+        }
+        let status = res.status();
+        let text = res.text().await.map_err(|e| e.to_string())?;
+        if !status.is_success() {
+            return Err(format!("HTTP {status}: {text}"));
+        }
+        // Notifications are acknowledged with an empty body
+        if text.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+        serde_json::from_str(&text).map_err(|e| format!("invalid JSON: {e}"))
+    }
+}
 
-    // Transform response to extract schemas
-    if let Some(result) = response.get("result")
-        && let Some(tools) = result.get("tools").and_then(|t| t.as_array())
-    {
-        let mut out = Vec::new();
-        for tool in tools {
-            let name = tool
-                .get("name")
-                .and_then(|n| n.as_str())
-                .unwrap_or("unknown");
-            let schema = tool.get("inputSchema").cloned().unwrap_or(json!({}));
-            out.push(json!({"name": name, "schema": schema}));
+impl Drop for Session {
+    fn drop(&mut self) {
+        if let SessionLink::Stdio { child, .. } = &mut self.link {
+            let _ = child.kill();
+            let _ = child.wait();
         }
-        return Ok(json!({"schemas": out}));
     }
-    Ok(response)
 }
 
-async fn stdio_send_request(
-    conn: &Connection,
-    request: serde_json::Value,
-) -> Result<serde_json::Value, String> {
-    use std::io::{BufRead, BufReader, Write};
-    use std::process::{Command, Stdio};
-
-    // Use --command option if provided, otherwise use --url
-    let command_str = conn.command.as_deref().unwrap_or(&conn.url);
-    let mut parts = command_str.split_whitespace();
-    let command = parts
-        .next()
-        .ok_or("No command specified for STDIO transport")?;
-    let args: Vec<&str> = parts.collect();
-
-    let mut child = Command::new(command)
-        .args(&args)
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("Failed to spawn command '{command}': {e}"))?;
-
-    // Send request
-    let stdin = child.stdin.as_mut().ok_or("Failed to get stdin handle")?;
-    let request_str =
-        serde_json::to_string(&request).map_err(|e| format!("Failed to serialize request: {e}"))?;
-    writeln!(stdin, "{request_str}").map_err(|e| format!("Failed to write request: {e}"))?;
-
-    // Read response from stdout while discarding stderr
-    let stdout = child.stdout.take().ok_or("Failed to get stdout handle")?;
-    let mut reader = BufReader::new(stdout);
-    let mut response_line = String::new();
-    
-    // Read lines until we get valid JSON (ignore log lines)
+/// Open a session and run commands read from stdin until `quit` or EOF
+///
+/// Prints the server's name and negotiated capabilities on startup, then
+/// the response to each command as [`output`] would. A failed command is
+/// reported on stderr without ending the session.
+pub async fn cmd_interactive(conn: Connection) -> Result<(), String> {
+    use std::io::IsTerminal;
+
+    let mut session = Session::open(conn).await?;
+    let prompt = std::io::stdin().is_terminal();
+    run_repl(
+        &mut session,
+        std::io::stdin().lock(),
+        &mut std::io::stdout(),
+        prompt,
+    )
+    .await
+}
+
+/// Drive `session` with commands read line by line from `input`
+///
+/// With `prompt` set, a prompt is written to stderr before each line.
+pub async fn run_repl(
+    session: &mut Session,
+    input: impl std::io::BufRead,
+    out: &mut impl std::io::Write,
+    prompt: bool,
+) -> Result<(), String> {
+    let write_err = |e: std::io::Error| format!("Failed to write output: {e}");
+    let server = &session.initialize["serverInfo"];
+    if session.conn.output_format() == OutputFormat::Json {
+        writeln!(out, "{}", session.initialize).map_err(write_err)?;
+    } else {
+        writeln!(
+            out,
+            "Connected to {} {}",
+            server["name"].as_str().unwrap_or("server"),
+            server["version"].as_str().unwrap_or_default()
+        )
+        .map_err(write_err)?;
+        writeln!(out, "Capabilities: {}", session.capabilities()).map_err(write_err)?;
+    }
+
+    let mut lines = input.lines();
     loop {
-        response_line.clear();
-        let bytes_read = reader
-            .read_line(&mut response_line)
-            .map_err(|e| format!("Failed to read response: {e}"))?;
-            
-        if bytes_read == 0 {
-            return Err("No JSON response received from server".to_string());
+        if prompt {
+            eprint!("mcp> ");
         }
-        
-        // Try to parse as JSON - if it works, we found our response
-        if let Ok(_) = serde_json::from_str::<serde_json::Value>(&response_line) {
-            break;
+        let Some(line) = lines.next() else {
+            return Ok(());
+        };
+        let line = line.map_err(|e| format!("Failed to read input: {e}"))?;
+        let command = match ReplCommand::parse(&line) {
+            Ok(Some(ReplCommand::Quit)) => return Ok(()),
+            Ok(Some(command)) => command,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("error: {e}");
+                continue;
+            }
+        };
+        match session
+            .execute(&command)
+            .await
+            .and_then(|response| format_output(&session.conn, &response))
+        {
+            Ok(text) => writeln!(out, "{text}").map_err(write_err)?,
+            Err(e) => eprintln!("error: {e}"),
         }
-        
-        // If line starts with '{' it might be JSON, try it anyway
-        if response_line.trim().starts_with('{') {
-            break;
+    }
+}
+
+pub fn output(conn: &Connection, value: &serde_json::Value) -> Result<(), String> {
+    println!("{}", format_output(conn, value)?);
+    Ok(())
+}
+
+/// Format a response in the connection's [`OutputFormat`]
+pub fn format_output(conn: &Connection, value: &serde_json::Value) -> Result<String, String> {
+    match conn.output_format() {
+        OutputFormat::Plain => Ok(value.to_string()),
+        OutputFormat::Json => {
+            Ok(serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()))
         }
-        
-        // Otherwise it's probably a log line, continue reading
+        OutputFormat::Yaml => serde_yaml::to_string(value)
+            .map(|yaml| yaml.trim_end().to_string())
+            .map_err(|e| format!("Failed to format YAML: {e}")),
+        OutputFormat::Table => match value.pointer("/result/tools").and_then(|t| t.as_array()) {
+            Some(tools) => Ok(render_tools_table(tools)),
+            None => Ok(value.to_string()),
+        },
     }
+}
 
-    // Wait for process to complete
-    let output = child
-        .wait()
-        .map_err(|e| format!("Process execution failed: {e}"))?;
+/// Longest description shown in a tools table before it is cut short
+const TABLE_DESCRIPTION_WIDTH: usize = 48;
 
-    if !output.success() {
-        return Err(format!(
-            "Command failed with exit code: {}",
-            output.code().unwrap_or(-1)
-        ));
+/// Render tools as aligned name, description and required-parameter columns
+///
+/// Descriptions are cut to their first line and to
+/// `TABLE_DESCRIPTION_WIDTH` characters, ending in `…` when shortened.
+/// Missing descriptions and tools without required parameters show `-`.
+pub fn render_tools_table(tools: &[serde_json::Value]) -> String {
+    let mut rows = vec![[
+        "NAME".to_string(),
+        "DESCRIPTION".to_string(),
+        "REQUIRED".to_string(),
+    ]];
+    for tool in tools {
+        let name = tool["name"].as_str().unwrap_or("-").to_string();
+        let description = tool["description"]
+            .as_str()
+            .and_then(|d| d.lines().next())
+            .filter(|d| !d.trim().is_empty())
+            .map_or_else(
+                || "-".to_string(),
+                |d| truncate(d.trim(), TABLE_DESCRIPTION_WIDTH),
+            );
+        let required: Vec<&str> = tool["inputSchema"]["required"]
+            .as_array()
+            .map(|names| names.iter().filter_map(|n| n.as_str()).collect())
+            .unwrap_or_default();
+        let required = if required.is_empty() {
+            "-".to_string()
+        } else {
+            required.join(", ")
+        };
+        rows.push([name, description, required]);
     }
 
-    // Parse JSON response
-    serde_json::from_str(&response_line).map_err(|e| format!("Invalid JSON response: {e}"))
+    let width = |column: usize| {
+        rows.iter()
+            .map(|row| row[column].chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    let (name_width, description_width) = (width(0), width(1));
+    rows.iter()
+        .map(|[name, description, required]| {
+            format!("{name:<name_width$}  {description:<description_width$}  {required}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-pub fn output(conn: &Connection, value: &serde_json::Value) -> Result<(), String> {
-    if conn.json {
-        println!(
-            "{}",
-            serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
-        );
-    } else {
-        println!("{value}");
+/// Cut `text` to at most `max` characters, marking the cut with `…`
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
     }
-    Ok(())
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
 }
@@ -1,7 +1,8 @@
 //! Tests for CLI structure and basic functionality
 
 use clap::Parser;
-use turbomcp_cli::{Cli, Commands};
+use serde_json::json;
+use turbomcp_cli::{Cli, Commands, build_schema_document};
 
 #[test]
 fn test_cli_parsing_help() {
@@ -301,6 +302,100 @@ fn test_command_line_argument_patterns() {
     }
 }
 
+#[test]
+fn test_tools_call_accepts_namespaced_name() {
+    let cli = Cli::try_parse_from([
+        "turbomcp-cli",
+        "tools-call",
+        "--transport",
+        "stdio",
+        "--name",
+        "fs.read",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::ToolsCall { name, .. } => assert_eq!(name, "fs.read"),
+        _ => panic!("expected tools-call"),
+    }
+}
+
+#[test]
+fn test_schema_export_includes_prompts_and_resources() {
+    let cli = Cli::try_parse_from([
+        "turbomcp-cli",
+        "schema-export",
+        "--include-prompts",
+        "--include-resources",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::SchemaExport { sections, .. } => {
+            assert!(sections.include_prompts);
+            assert!(sections.include_resources);
+        }
+        _ => panic!("expected schema-export"),
+    }
+
+    let tools = json!({"tools": [{"name": "add", "inputSchema": {"type": "object"}}]});
+    let prompts = json!({"prompts": [{
+        "name": "summarize",
+        "description": "Summarize a document",
+        "arguments": [
+            {"name": "text", "description": "Text to summarize", "required": true},
+            {"name": "style"}
+        ]
+    }]});
+    let resources = json!({"resources": [
+        {"uri": "file:///readme.md", "name": "readme"},
+        {"uri": "users://{id}/posts/{post}", "name": "post", "mimeType": "application/json"}
+    ]});
+
+    let document = build_schema_document(&tools, Some(&prompts), Some(&resources));
+    assert_eq!(document["schemas"][0]["name"], "add");
+
+    let prompt = &document["prompts"][0];
+    assert_eq!(prompt["name"], "summarize");
+    assert_eq!(
+        prompt["schema"]["properties"]["text"],
+        json!({"type": "string", "description": "Text to summarize"})
+    );
+    assert_eq!(prompt["schema"]["required"], json!(["text"]));
+
+    assert_eq!(
+        document["resources"]["resources"],
+        json!([{"uri": "file:///readme.md", "name": "readme"}])
+    );
+    let template = &document["resources"]["templates"][0];
+    assert_eq!(template["uriTemplate"], "users://{id}/posts/{post}");
+    assert_eq!(template["parameters"], json!(["id", "post"]));
+    assert!(template.get("uri").is_none());
+
+    // Templates from `resources/templates/list` are exported as listed
+    let resources = json!({
+        "resources": [{"uri": "users://{id}/posts/{post}", "name": "post"}],
+        "resourceTemplates": [
+            {"uriTemplate": "users://{id}/posts/{post}", "name": "post"},
+            {"uriTemplate": "logs://{date}", "name": "logs"}
+        ]
+    });
+    let document = build_schema_document(&tools, None, Some(&resources));
+    assert_eq!(document["resources"]["resources"], json!([]));
+    let templates = document["resources"]["templates"].as_array().unwrap();
+    assert_eq!(templates.len(), 2);
+    assert_eq!(templates[1]["uriTemplate"], "logs://{date}");
+    assert_eq!(templates[1]["parameters"], json!(["date"]));
+
+    // A URI that does not parse as a template is an ordinary resource
+    let resources = json!({"resources": [{"uri": "notes://}draft{", "name": "draft"}]});
+    let document = build_schema_document(&tools, None, Some(&resources));
+    assert_eq!(document["resources"]["templates"], json!([]));
+
+    // Tools only unless asked
+    let document = build_schema_document(&tools, None, None);
+    assert!(document.get("prompts").is_none());
+    assert!(document.get("resources").is_none());
+}
+
 // Test that imports are working correctly
 #[test]
 fn test_required_imports() {
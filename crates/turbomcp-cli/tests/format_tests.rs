@@ -0,0 +1,112 @@
+//! Tests for the output formats selected with `--format`
+
+use clap::Parser;
+use serde_json::json;
+use turbomcp_cli::{
+    Cli, Commands, Connection, OutputFormat, TransportKind, format_output, render_tools_table,
+};
+
+#[test]
+fn test_format_flag_parses() {
+    let cli = Cli::try_parse_from(["turbomcp-cli", "tools-list", "--format", "table"]).unwrap();
+    match cli.command {
+        Commands::ToolsList(conn) => assert_eq!(conn.output_format(), OutputFormat::Table),
+        _ => panic!("expected tools-list"),
+    }
+
+    let cli = Cli::try_parse_from(["turbomcp-cli", "tools-list", "--json"]).unwrap();
+    match cli.command {
+        Commands::ToolsList(conn) => assert_eq!(conn.output_format(), OutputFormat::Json),
+        _ => panic!("expected tools-list"),
+    }
+
+    let cli = Cli::try_parse_from(["turbomcp-cli", "tools-list"]).unwrap();
+    match cli.command {
+        Commands::ToolsList(conn) => assert_eq!(conn.output_format(), OutputFormat::Plain),
+        _ => panic!("expected tools-list"),
+    }
+
+    assert!(
+        Cli::try_parse_from(["turbomcp-cli", "tools-list", "--json", "--format", "yaml"]).is_err()
+    );
+}
+
+fn connection(format: OutputFormat) -> Connection {
+    Connection {
+        transport: Some(TransportKind::Stdio),
+        url: "unused".to_string(),
+        command: None,
+        auth: None,
+        json: false,
+        format,
+    }
+}
+
+#[test]
+fn test_yaml_output() {
+    let response = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {"contents": [{"uri": "file:///notes.txt", "text": "remember"}]}
+    });
+    let yaml = format_output(&connection(OutputFormat::Yaml), &response).unwrap();
+    let parsed: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(parsed, response);
+    assert!(yaml.contains("uri: file:///notes.txt"), "{yaml}");
+    assert!(!yaml.ends_with('\n'));
+}
+
+#[test]
+fn test_table_output_of_a_tool_listing() {
+    let conn = connection(OutputFormat::Table);
+    let listing = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {"tools": [{"name": "ping", "inputSchema": {"type": "object"}}]}
+    });
+    assert_eq!(
+        format_output(&conn, &listing).unwrap(),
+        "NAME  DESCRIPTION  REQUIRED\nping  -            -"
+    );
+
+    // Anything other than a tool listing prints as plain JSON
+    let other = json!({"jsonrpc": "2.0", "id": 2, "result": {"content": []}});
+    assert_eq!(format_output(&conn, &other).unwrap(), other.to_string());
+}
+
+#[test]
+fn test_tools_table() {
+    let tools = [
+        json!({
+            "name": "add",
+            "description": "Add two numbers",
+            "inputSchema": {"type": "object", "required": ["a", "b"]}
+        }),
+        json!({
+            "name": "search_documents",
+            "description": "Search every indexed document for passages matching a query, ranked by relevance",
+            "inputSchema": {"type": "object", "required": ["query"]}
+        }),
+        json!({"name": "ping", "inputSchema": {"type": "object"}}),
+        json!({
+            "name": "summarize",
+            "description": "Summarize text\nLong explanation that is not shown",
+            "inputSchema": {"type": "object", "properties": {"text": {"type": "string"}}}
+        }),
+    ];
+
+    let expected = [
+        "NAME              DESCRIPTION                                       REQUIRED",
+        "add               Add two numbers                                   a, b",
+        "search_documents  Search every indexed document for passages matc…  query",
+        "ping              -                                                 -",
+        "summarize         Summarize text                                    -",
+    ]
+    .join("\n");
+    assert_eq!(render_tools_table(&tools), expected);
+}
+
+#[test]
+fn test_empty_tools_table_has_header_only() {
+    assert_eq!(render_tools_table(&[]), "NAME  DESCRIPTION  REQUIRED");
+}
@@ -2,7 +2,7 @@
 //! These tests focus on executing the actual CLI functions to achieve code coverage
 
 use serde_json::json;
-use turbomcp_cli::{Connection, TransportKind};
+use turbomcp_cli::{Connection, OutputFormat, SchemaSections, TransportKind};
 
 #[tokio::test]
 async fn test_cmd_tools_list_stdio_error() {
@@ -12,6 +12,7 @@ async fn test_cmd_tools_list_stdio_error() {
         url: "nonexistent_command".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // This should return an error since command execution will fail
@@ -29,11 +30,12 @@ async fn test_cmd_tools_call_stdio_error() {
         url: "nonexistent_command".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // This should return an error since command execution will fail
     let result =
-        turbomcp_cli::cmd_tools_call(conn, "test_tool".to_string(), "{}".to_string()).await;
+        turbomcp_cli::cmd_tools_call(conn, "test_tool".to_string(), "{}".to_string(), false).await;
     assert!(result.is_err());
     let error = result.unwrap_err();
     assert!(error.contains("Failed to spawn command"));
@@ -47,10 +49,11 @@ async fn test_cmd_schema_export_stdio_error() {
         url: "nonexistent_command".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // This should return an error since command execution will fail
-    let result = turbomcp_cli::cmd_schema_export(conn, None).await;
+    let result = turbomcp_cli::cmd_schema_export(conn, None, SchemaSections::default()).await;
     assert!(result.is_err());
     let error = result.unwrap_err();
     assert!(error.contains("Failed to spawn command"));
@@ -64,12 +67,17 @@ async fn test_http_call_tool_invalid_json() {
         url: "http://localhost:8080/test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // Test with invalid JSON arguments
-    let result =
-        turbomcp_cli::cmd_tools_call(conn, "test_tool".to_string(), "invalid json".to_string())
-            .await;
+    let result = turbomcp_cli::cmd_tools_call(
+        conn,
+        "test_tool".to_string(),
+        "invalid json".to_string(),
+        false,
+    )
+    .await;
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("invalid --arguments JSON"));
 }
@@ -82,6 +90,7 @@ async fn test_connection_debug_format() {
         url: "http://localhost:8080/test".to_string(),
         auth: Some("test_token".to_string()),
         json: true,
+        format: OutputFormat::Plain,
     };
 
     let debug_str = format!("{conn:?}");
@@ -115,6 +124,7 @@ async fn test_connection_clone() {
         url: "ws://localhost:8080/test".to_string(),
         auth: Some("token".to_string()),
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let cloned = conn.clone();
@@ -145,6 +155,7 @@ fn test_output_json_format() {
         url: "test".to_string(),
         auth: None,
         json: true,
+        format: OutputFormat::Plain,
     };
 
     let test_value = json!({"key": "value", "number": 42});
@@ -162,6 +173,7 @@ fn test_output_non_json_format() {
         url: "test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let test_value = json!({"key": "value", "number": 42});
@@ -180,6 +192,7 @@ async fn test_connection_with_auth() {
         url: "http://localhost:8080/test".to_string(),
         auth: Some("Bearer test_token_123".to_string()),
         json: true,
+        format: OutputFormat::Plain,
     };
 
     // Test that connection with auth can be created and used
@@ -195,6 +208,7 @@ async fn test_connection_without_auth() {
         url: "http://localhost:8080/test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // Test that connection without auth can be created and used
@@ -210,6 +224,7 @@ async fn test_different_url_formats() {
         url: "https://api.example.com/mcp".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let ws_conn = Connection {
@@ -218,6 +233,7 @@ async fn test_different_url_formats() {
         url: "wss://api.example.com/mcp".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // Test that different URL formats are accepted
@@ -234,6 +250,7 @@ async fn test_websocket_transport_mapping() {
         url: "ws://localhost:8080/test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // WebSocket commands currently delegate to HTTP implementations
@@ -244,10 +261,11 @@ async fn test_websocket_transport_mapping() {
     // Should fail due to network, not implementation
 
     let result =
-        turbomcp_cli::cmd_tools_call(conn.clone(), "test".to_string(), "{}".to_string()).await;
+        turbomcp_cli::cmd_tools_call(conn.clone(), "test".to_string(), "{}".to_string(), false)
+            .await;
     assert!(result.is_err());
 
-    let result = turbomcp_cli::cmd_schema_export(conn, None).await;
+    let result = turbomcp_cli::cmd_schema_export(conn, None, SchemaSections::default()).await;
     assert!(result.is_err());
 }
 
@@ -260,6 +278,7 @@ async fn test_malformed_json_arguments() {
         url: "http://localhost:8080/test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // Test various malformed JSON strings
@@ -277,6 +296,7 @@ async fn test_malformed_json_arguments() {
             conn.clone(),
             "test_tool".to_string(),
             malformed.to_string(),
+            false,
         )
         .await;
         assert!(result.is_err());
@@ -293,6 +313,7 @@ async fn test_valid_json_arguments() {
         url: "http://localhost:8080/test".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let valid_jsons = vec![
@@ -308,6 +329,7 @@ async fn test_valid_json_arguments() {
             conn.clone(),
             "test_tool".to_string(),
             valid_json.to_string(),
+            false,
         )
         .await;
         // Should fail with network error, not JSON parsing error
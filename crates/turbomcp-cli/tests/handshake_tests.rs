@@ -0,0 +1,79 @@
+//! Tests for the `initialize` handshake the single-shot commands perform
+#![cfg(unix)]
+
+use std::process::{Command, Output};
+
+/// Server that answers nothing until it has been initialized, then serves
+/// one `tools/list` or `tools/call`
+const STRICT_SERVER: &str = r#"read -r line
+case "$line" in
+  *'"method":"initialize"'*'"clientInfo"'*) ;;
+  *)
+    echo '{"jsonrpc":"2.0","id":1,"error":{"code":-32002,"message":"Server not initialized"}}'
+    exit 1 ;;
+esac
+echo '{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{"tools":{}},"serverInfo":{"name":"strict","version":"2.0.0"}}}'
+read -r line
+case "$line" in
+  *'"method":"notifications/initialized"'*) ;;
+  *) exit 1 ;;
+esac
+read -r line
+case "$line" in
+  *'"method":"tools/list"'*)
+    echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"greet","inputSchema":{"type":"object"}}]}}' ;;
+  *'"method":"tools/call"'*'"who":"you"'*)
+    echo '{"jsonrpc":"2.0","id":2,"result":{"content":[{"type":"text","text":"hello you"}]}}' ;;
+esac
+"#;
+
+/// Run the CLI against a fresh `STRICT_SERVER`
+fn run_against_strict_server(test: &str, args: &[&str]) -> Output {
+    let script = std::env::temp_dir().join(format!(
+        "turbomcp-cli-handshake-{test}-{}.sh",
+        std::process::id()
+    ));
+    std::fs::write(&script, STRICT_SERVER).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_turbomcp-cli"))
+        .args(args)
+        .args(["--command", &format!("sh {}", script.display())])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&script).unwrap();
+    output
+}
+
+#[test]
+fn test_tools_list_initializes_first() {
+    let output = run_against_strict_server("list", &["tools-list", "--json"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let response: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(response["result"]["tools"][0]["name"], "greet");
+    assert!(
+        stderr.contains("Connected to strict 2.0.0 (protocol 2025-06-18)"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_tools_call_initializes_first() {
+    let output = run_against_strict_server(
+        "call",
+        &[
+            "tools-call",
+            "--name",
+            "greet",
+            "--arguments",
+            r#"{"who":"you"}"#,
+        ],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("hello you"), "{stdout}");
+    // The server is only reported in --json mode
+    assert!(!stderr.contains("Connected to"), "{stderr}");
+}
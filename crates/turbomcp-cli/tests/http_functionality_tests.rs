@@ -2,7 +2,7 @@
 
 use clap::Parser;
 use serde_json::json;
-use turbomcp_cli::{Cli, Commands, Connection, TransportKind};
+use turbomcp_cli::{Cli, Commands, Connection, OutputFormat, TransportKind};
 
 #[test]
 fn test_cli_parsing_tools_list() {
@@ -53,6 +53,7 @@ fn test_cli_parsing_tools_call() {
             conn,
             name,
             arguments,
+            ..
         } => {
             assert!(matches!(conn.transport, Some(TransportKind::Ws)));
             assert_eq!(conn.url, "ws://localhost:8080/mcp");
@@ -121,6 +122,7 @@ fn test_cli_parsing_tools_call_with_defaults() {
             conn,
             name,
             arguments,
+            ..
         } => {
             assert!(matches!(conn.transport, None)); // None means auto-detection
             assert_eq!(conn.url, "http://localhost:8080/mcp"); // default
@@ -164,6 +166,7 @@ fn test_connection_comprehensive() {
         url: "https://api.example.com/mcp".to_string(),
         auth: Some("api_key_12345".to_string()),
         json: true,
+        format: OutputFormat::Plain,
     };
 
     // Test Debug formatting
@@ -266,6 +269,7 @@ async fn test_output_function_edge_cases() {
         url: "test".to_string(),
         auth: None,
         json: true,
+        format: OutputFormat::Plain,
     };
 
     // Test with complex JSON
@@ -318,6 +322,7 @@ async fn test_output_non_json_mode() {
         url: "test".to_string(),
         auth: None,
         json: false, // non-JSON mode
+        format: OutputFormat::Plain,
     };
 
     let test_data = json!({
@@ -348,6 +353,7 @@ fn test_url_formats() {
             url: url.to_string(),
             auth: None,
             json: false,
+            format: OutputFormat::Plain,
         };
 
         // Test that various URL formats are accepted
@@ -378,6 +384,7 @@ fn test_auth_token_formats() {
                 Some(token.to_string())
             },
             json: false,
+            format: OutputFormat::Plain,
         };
 
         if token.is_empty() {
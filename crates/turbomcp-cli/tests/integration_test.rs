@@ -52,7 +52,7 @@ fn test_schema_export_with_output() {
     let cli = Cli::try_parse_from(args).expect("Failed to parse CLI args");
     
     match cli.command {
-        Commands::SchemaExport { conn: _, output } => {
+        Commands::SchemaExport { conn: _, output, .. } => {
             assert_eq!(output, Some("test.json".to_string()));
         }
         _ => panic!("Expected SchemaExport command"),
@@ -0,0 +1,126 @@
+//! Tests for the interactive session mode
+
+use clap::Parser;
+use serde_json::json;
+use turbomcp_cli::{Cli, Commands, ReplCommand};
+
+#[test]
+fn test_interactive_subcommand_parses() {
+    let cli =
+        Cli::try_parse_from(["turbomcp-cli", "interactive", "--command", "./server"]).unwrap();
+    match cli.command {
+        Commands::Interactive(conn) => assert_eq!(conn.command.as_deref(), Some("./server")),
+        _ => panic!("expected interactive"),
+    }
+}
+
+#[test]
+fn test_repl_command_parsing() {
+    assert_eq!(
+        ReplCommand::parse("  list ").unwrap(),
+        Some(ReplCommand::List)
+    );
+    assert_eq!(ReplCommand::parse("").unwrap(), None);
+    assert_eq!(ReplCommand::parse("quit").unwrap(), Some(ReplCommand::Quit));
+    assert_eq!(ReplCommand::parse("exit").unwrap(), Some(ReplCommand::Quit));
+    assert_eq!(
+        ReplCommand::parse(r#"call add {"a": 1, "b": 2}"#).unwrap(),
+        Some(ReplCommand::Call {
+            name: "add".to_string(),
+            arguments: json!({"a": 1, "b": 2}),
+        })
+    );
+    assert_eq!(
+        ReplCommand::parse("call ping").unwrap(),
+        Some(ReplCommand::Call {
+            name: "ping".to_string(),
+            arguments: json!({}),
+        })
+    );
+    assert_eq!(
+        ReplCommand::parse("read file:///notes.txt").unwrap(),
+        Some(ReplCommand::Read {
+            uri: "file:///notes.txt".to_string(),
+        })
+    );
+
+    assert!(ReplCommand::parse("call").is_err());
+    assert!(ReplCommand::parse("call add {not json").is_err());
+    assert!(ReplCommand::parse("read").is_err());
+    assert!(ReplCommand::parse("delete everything").is_err());
+}
+
+/// Server answering any number of requests over one stdin, numbering the
+/// lines it has read so the test can tell a single process served them all
+#[cfg(unix)]
+const MOCK_SERVER: &str = r#"n=0
+while read -r line; do
+  n=$((n + 1))
+  case "$line" in
+    *'"method":"initialize"'*)
+      echo 'starting mock server'
+      echo '{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{"tools":{}},"serverInfo":{"name":"mock","version":"0.1.0"}}}' ;;
+    *'"method":"tools/list"'*)
+      echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"echo","inputSchema":{"type":"object"}}]}}' ;;
+    *'"method":"tools/call"'*'"text":"hi"'* | *'"text":"hi"'*'"method":"tools/call"'*)
+      echo '{"jsonrpc":"2.0","method":"notifications/message","params":{"level":"info","data":"echoing"}}'
+      echo "{\"jsonrpc\":\"2.0\",\"id\":3,\"result\":{\"content\":[{\"type\":\"text\",\"text\":\"hi from line $n\"}]}}" ;;
+    *'"method":"resources/read"'*)
+      echo '{"jsonrpc":"2.0","id":4,"result":{"contents":[{"uri":"file:///notes.txt","text":"remember"}]}}' ;;
+  esac
+done
+"#;
+
+#[cfg(unix)]
+#[test]
+fn test_repl_reuses_one_connection() {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let script = std::env::temp_dir().join(format!(
+        "turbomcp-cli-interactive-{}.sh",
+        std::process::id()
+    ));
+    std::fs::write(&script, MOCK_SERVER).unwrap();
+
+    let mut cli = Command::new(env!("CARGO_BIN_EXE_turbomcp-cli"))
+        .args(["interactive", "--command"])
+        .arg(format!("sh {}", script.display()))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let commands =
+        "list\ncall echo {\"text\": \"hi\"}\nbogus\n\nread file:///notes.txt\nquit\nlist\n";
+    cli.stdin
+        .take()
+        .unwrap()
+        .write_all(commands.as_bytes())
+        .unwrap();
+    let output = cli.wait_with_output().unwrap();
+    std::fs::remove_file(&script).unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "Connected to mock 0.1.0");
+    assert_eq!(lines[1], r#"Capabilities: {"tools":{}}"#);
+
+    // One response per command up to quit; the list after it is never sent
+    let responses: Vec<serde_json::Value> = lines[2..]
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["result"]["tools"][0]["name"], "echo");
+    // initialize, initialized and list came before the call on the same stdin
+    assert_eq!(
+        responses[1]["result"]["content"][0]["text"],
+        "hi from line 4"
+    );
+    assert_eq!(responses[2]["result"]["contents"][0]["text"], "remember");
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Unknown command 'bogus'"), "{stderr}");
+}
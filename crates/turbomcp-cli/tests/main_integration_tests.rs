@@ -4,8 +4,8 @@ use clap::Parser;
 use serde_json::json;
 use std::process::Command;
 use turbomcp_cli::{
-    Cli, Commands, Connection, TransportKind, cmd_schema_export, cmd_tools_call, cmd_tools_list,
-    output,
+    Cli, Commands, Connection, OutputFormat, SchemaSections, TransportKind, cmd_schema_export,
+    cmd_tools_call, cmd_tools_list, output,
 };
 
 // Test the main function indirectly through the binary
@@ -105,6 +105,7 @@ fn test_cli_parsing_tools_call() {
             conn,
             name,
             arguments,
+            ..
         } => {
             assert_eq!(conn.url, "http://test.com");
             assert_eq!(name, "test_tool");
@@ -199,6 +200,7 @@ fn test_output_json_format() {
         url: "http://test.com".to_string(),
         auth: None,
         json: true,
+        format: OutputFormat::Plain,
     };
 
     let value = json!({"test": "data", "number": 42});
@@ -215,6 +217,7 @@ fn test_output_non_json_format() {
         url: "http://test.com".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let value = json!({"test": "data"});
@@ -232,6 +235,7 @@ async fn test_cmd_tools_list_stdio_error() {
         url: "unused".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let result = cmd_tools_list(conn).await;
@@ -250,9 +254,10 @@ async fn test_cmd_tools_call_stdio_error() {
         url: "unused".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
-    let result = cmd_tools_call(conn, "test_tool".to_string(), "{}".to_string()).await;
+    let result = cmd_tools_call(conn, "test_tool".to_string(), "{}".to_string(), false).await;
     assert!(result.is_err());
 
     if let Err(e) = result {
@@ -268,9 +273,10 @@ async fn test_cmd_schema_export_stdio_error() {
         url: "unused".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
-    let result = cmd_schema_export(conn, None).await;
+    let result = cmd_schema_export(conn, None, SchemaSections::default()).await;
     assert!(result.is_err());
 
     if let Err(e) = result {
@@ -286,10 +292,17 @@ async fn test_cmd_tools_call_invalid_arguments() {
         url: "http://nonexistent.com".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     // This should fail due to invalid JSON arguments before even trying to connect
-    let result = cmd_tools_call(conn, "test_tool".to_string(), "invalid_json{".to_string()).await;
+    let result = cmd_tools_call(
+        conn,
+        "test_tool".to_string(),
+        "invalid_json{".to_string(),
+        false,
+    )
+    .await;
     assert!(result.is_err());
 
     if let Err(e) = result {
@@ -306,6 +319,7 @@ fn test_connection_debug_format() {
         url: "http://test.com".to_string(),
         auth: Some("token".to_string()),
         json: true,
+        format: OutputFormat::Plain,
     };
 
     let debug_str = format!("{conn:?}");
@@ -322,6 +336,7 @@ fn test_connection_clone() {
         command: None,
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let cloned = original.clone();
@@ -367,6 +382,7 @@ fn test_commands_enum_variants() {
         url: "http://test.com".to_string(),
         auth: None,
         json: false,
+        format: OutputFormat::Plain,
     };
 
     let tools_list = Commands::ToolsList(conn.clone());
@@ -374,8 +390,14 @@ fn test_commands_enum_variants() {
         conn: conn.clone(),
         name: "test".to_string(),
         arguments: "{}".to_string(),
+        stream: false,
+        validate: false,
+    };
+    let schema_export = Commands::SchemaExport {
+        conn,
+        output: None,
+        sections: SchemaSections::default(),
     };
-    let schema_export = Commands::SchemaExport { conn, output: None };
 
     // All should be debuggable
     let debug1 = format!("{tools_list:?}");
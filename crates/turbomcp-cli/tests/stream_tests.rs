@@ -0,0 +1,137 @@
+//! Tests for streaming the progress of tool calls
+
+use clap::Parser;
+use serde_json::json;
+use turbomcp_cli::{
+    Cli, Commands, Connection, OutputFormat, TransportKind, call_tool_streaming, render_event,
+};
+
+fn connection(url: &str, json: bool) -> Connection {
+    Connection {
+        transport: Some(TransportKind::Stdio),
+        url: url.to_string(),
+        command: None,
+        auth: None,
+        json,
+        format: OutputFormat::Plain,
+    }
+}
+
+#[test]
+fn test_tools_call_parses_stream_flag() {
+    let cli =
+        Cli::try_parse_from(["turbomcp-cli", "tools-call", "--name", "build", "--stream"]).unwrap();
+    match cli.command {
+        Commands::ToolsCall { stream, .. } => assert!(stream),
+        _ => panic!("expected tools-call"),
+    }
+}
+
+#[test]
+fn test_human_rendering() {
+    let conn = connection("unused", false);
+    let progress = json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/progress",
+        "params": {"progressToken": "t", "progress": 1, "total": 4, "message": "compiling"}
+    });
+    assert_eq!(
+        render_event(&conn, &progress).unwrap(),
+        "[#####---------------]  25% compiling"
+    );
+
+    let unbounded = json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/progress",
+        "params": {"progressToken": "t", "progress": 3}
+    });
+    assert_eq!(render_event(&conn, &unbounded).unwrap(), "progress 3");
+
+    let log = json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/message",
+        "params": {"level": "warning", "data": "cache miss"}
+    });
+    assert_eq!(render_event(&conn, &log).unwrap(), "[warning] cache miss");
+
+    let complete = json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/progress/complete",
+        "params": {"progressToken": "t", "status": "success", "summary": "3 targets"}
+    });
+    assert_eq!(
+        render_event(&conn, &complete).unwrap(),
+        "finished (success): 3 targets"
+    );
+
+    let other = json!({"jsonrpc": "2.0", "method": "notifications/tools/list_changed"});
+    assert!(render_event(&conn, &other).is_none());
+}
+
+#[test]
+fn test_json_rendering_is_one_line_per_event() {
+    let conn = connection("unused", true);
+    let progress = json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/progress",
+        "params": {"progressToken": "t", "progress": 1}
+    });
+    let line = render_event(&conn, &progress).unwrap();
+    assert!(!line.contains('\n'));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&line).unwrap(),
+        progress
+    );
+}
+
+/// Server answering `initialize` and then one `tools/call` carrying the
+/// CLI's progress token, reporting progress and a log line before the result
+#[cfg(unix)]
+const MOCK_SERVER: &str = r#"read request
+echo '{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{"tools":{}},"serverInfo":{"name":"builder","version":"1.0.0"}}}'
+read initialized
+read request
+case "$request" in
+  *'"progressToken":"turbomcp-cli"'*) ;;
+  *) exit 1 ;;
+esac
+echo 'starting mock server'
+echo '{"jsonrpc":"2.0","method":"notifications/progress","params":{"progressToken":"turbomcp-cli","progress":1,"total":2,"message":"compiling"}}'
+echo '{"jsonrpc":"2.0","method":"notifications/message","params":{"level":"info","data":"linking"}}'
+echo '{"jsonrpc":"2.0","method":"notifications/progress","params":{"progressToken":"turbomcp-cli","progress":2,"total":2}}'
+echo '{"jsonrpc":"2.0","method":"notifications/progress/complete","params":{"progressToken":"turbomcp-cli","status":"success"}}'
+echo '{"jsonrpc":"2.0","id":2,"result":{"content":[{"type":"text","text":"built"}]}}'
+"#;
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_streamed_call_reports_events_before_result() {
+    let script =
+        std::env::temp_dir().join(format!("turbomcp-cli-stream-{}.sh", std::process::id()));
+    std::fs::write(&script, MOCK_SERVER).unwrap();
+    let conn = connection(&format!("sh {}", script.display()), false);
+
+    let mut events = Vec::new();
+    let response = call_tool_streaming(
+        &conn,
+        "build".to_string(),
+        "{}".to_string(),
+        false,
+        |event| {
+            events.push(render_event(&conn, event).unwrap());
+        },
+    )
+    .await;
+    std::fs::remove_file(&script).unwrap();
+
+    assert_eq!(
+        events,
+        [
+            "[##########----------]  50% compiling",
+            "[info] linking",
+            "[####################] 100%",
+            "finished (success)",
+        ]
+    );
+    assert_eq!(response.unwrap()["result"]["content"][0]["text"], "built");
+}
@@ -0,0 +1,109 @@
+//! Tests for checking tool arguments against input schemas
+
+use clap::Parser;
+use serde_json::json;
+use turbomcp_cli::{Cli, Commands, validate_arguments};
+
+fn greet_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "who": {"type": "string"},
+            "times": {"type": "integer"}
+        },
+        "required": ["who"]
+    })
+}
+
+#[test]
+fn test_tools_call_parses_validate_flag() {
+    let cli = Cli::try_parse_from([
+        "turbomcp-cli",
+        "tools-call",
+        "--name",
+        "greet",
+        "--validate",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::ToolsCall { validate, .. } => assert!(validate),
+        _ => panic!("expected tools-call"),
+    }
+}
+
+#[test]
+fn test_conforming_arguments_pass() {
+    assert!(validate_arguments(&greet_schema(), &json!({"who": "you", "times": 2})).is_ok());
+}
+
+#[test]
+fn test_missing_required_property() {
+    let error = validate_arguments(&greet_schema(), &json!({"times": 2})).unwrap_err();
+    assert!(error.contains("\"who\" is a required property"), "{error}");
+}
+
+#[test]
+fn test_type_mismatch_names_the_property() {
+    let error = validate_arguments(&greet_schema(), &json!({"who": 5})).unwrap_err();
+    assert!(error.starts_with("/who: "), "{error}");
+    assert!(error.contains("\"string\""), "{error}");
+}
+
+/// Server listing a tool with a plain schema and one whose schema uses
+/// `$ref`, and answering every call
+#[cfg(unix)]
+const MOCK_SERVER: &str = r##"while read -r line; do
+  case "$line" in
+    *'"method":"initialize"'*)
+      echo '{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2025-06-18","capabilities":{"tools":{}},"serverInfo":{"name":"mock","version":"0.1.0"}}}' ;;
+    *'"method":"tools/list"'*)
+      echo '{"jsonrpc":"2.0","id":2,"result":{"tools":[{"name":"greet","inputSchema":{"type":"object","properties":{"who":{"type":"string"}},"required":["who"]}},{"name":"linked","inputSchema":{"$ref":"#/definitions/args","definitions":{"args":{"type":"object"}}}}]}}' ;;
+    *'"method":"tools/call"'*)
+      echo '{"jsonrpc":"2.0","id":3,"result":{"content":[{"type":"text","text":"called"}]}}' ;;
+  esac
+done
+"##;
+
+/// Run `tools-call --validate` for `tool` against a fresh `MOCK_SERVER`
+#[cfg(unix)]
+fn call_validated(test: &str, tool: &str, arguments: &str) -> std::process::Output {
+    let script = std::env::temp_dir().join(format!(
+        "turbomcp-cli-validate-{test}-{}.sh",
+        std::process::id()
+    ));
+    std::fs::write(&script, MOCK_SERVER).unwrap();
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_turbomcp-cli"))
+        .args(["tools-call", "--validate", "--name", tool])
+        .args(["--arguments", arguments])
+        .args(["--command", &format!("sh {}", script.display())])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&script).unwrap();
+    output
+}
+
+#[cfg(unix)]
+#[test]
+fn test_invalid_arguments_are_not_sent() {
+    let output = call_validated("invalid", "greet", r#"{"who": 5}"#);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Arguments for 'greet' do not match its input schema: /who:"),
+        "{stderr}"
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("called"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_ref_schema_is_skipped_with_warning() {
+    let output = call_validated("ref", "linked", r#"{"anything": true}"#);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(
+        stderr.contains("uses $ref; skipping validation"),
+        "{stderr}"
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("called"));
+}
@@ -0,0 +1,86 @@
+//! # Recording Proxy - Capturing MCP Traffic with Raw Transport Access
+//!
+//! **What this example demonstrates:**
+//! - Spawning an upstream MCP server with `ChildProcessTransport`
+//! - Forwarding messages verbatim with `Client::send_raw` / `Client::receive_raw`
+//! - Recording every message, in both directions, for later replay
+//!
+//! **Run with:** `cargo run --example recording_proxy -- <server command> [args...]`
+//!
+//! Point an MCP host at this command instead of the server. Traffic is
+//! relayed between this process's stdin/stdout and the child, and appended
+//! as JSON lines to the file named by `TURBOMCP_RECORDING` (default
+//! `mcp-recording.jsonl`).
+//!
+//! The proxy never uses the typed client API, so nothing competes with it for
+//! the server's responses.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::Duration;
+
+use turbomcp_client::Client;
+use turbomcp_transport::stdio::StdioTransport;
+use turbomcp_transport::{ChildProcessConfig, ChildProcessTransport, Transport, TransportMessage};
+
+/// How long to wait before polling again when neither side has a message
+const IDLE_POLL: Duration = Duration::from_millis(5);
+
+/// Append one message to the recording
+fn record(
+    out: &mut impl Write,
+    direction: &str,
+    message: &TransportMessage,
+) -> std::io::Result<()> {
+    let payload = serde_json::from_slice::<serde_json::Value>(&message.payload)
+        .unwrap_or_else(|_| String::from_utf8_lossy(&message.payload).into());
+    let entry = serde_json::json!({"direction": direction, "message": payload});
+    writeln!(out, "{entry}")?;
+    out.flush()
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let command = args
+        .next()
+        .ok_or("usage: recording_proxy <server command> [args...]")?;
+    let path = std::env::var("TURBOMCP_RECORDING").unwrap_or_else(|_| "mcp-recording.jsonl".into());
+    let mut recording = BufWriter::new(File::create(&path)?);
+
+    let mut upstream = Client::new(ChildProcessTransport::new(ChildProcessConfig {
+        command,
+        args: args.collect(),
+        ..ChildProcessConfig::default()
+    }));
+    upstream.transport_mut().connect().await?;
+
+    let mut downstream = StdioTransport::new();
+    downstream.connect().await?;
+    eprintln!("Recording MCP traffic to {path}");
+
+    loop {
+        let mut idle = true;
+
+        if let Some(message) = downstream.receive().await? {
+            record(&mut recording, "client->server", &message)?;
+            upstream.send_raw(message).await?;
+            idle = false;
+        }
+        if let Some(message) = upstream.receive_raw().await? {
+            record(&mut recording, "server->client", &message)?;
+            downstream.send(message).await?;
+            idle = false;
+        }
+
+        if idle {
+            if !upstream.transport_mut().is_process_alive() {
+                break;
+            }
+            tokio::time::sleep(IDLE_POLL).await;
+        }
+    }
+
+    eprintln!("Upstream server exited");
+    Ok(())
+}
@@ -0,0 +1,156 @@
+//! Bounded correlation of in-flight requests with their responses
+//!
+//! Every request the client sends is registered here under its JSON-RPC id
+//! until the matching response arrives. Entries carry a deadline: once it
+//! passes, the entry is evicted and its waiter sees the channel close, so a
+//! server that never answers cannot make the map grow. The map also refuses
+//! new entries beyond a fixed capacity.
+//!
+//! A response whose id is no longer registered (because the request timed
+//! out or was cancelled) is discarded rather than handed to a later request.
+//! [`CorrelationMap::cancel_all`] empties the map at once, failing every
+//! waiter with a [`Cancelled`](turbomcp_core::ErrorKind::Cancelled) error.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use tokio::sync::oneshot;
+use turbomcp_core::{Error, MessageId, Result};
+use turbomcp_protocol::jsonrpc::JsonRpcResponse;
+
+/// Default maximum number of requests awaiting a response
+pub const DEFAULT_MAX_PENDING_REQUESTS: usize = 1024;
+
+/// Default time to wait for a response before giving up on a request
+///
+/// Matches [`turbomcp_core::DEFAULT_TIMEOUT_MS`].
+pub const DEFAULT_REQUEST_TIMEOUT: Duration =
+    Duration::from_millis(turbomcp_core::DEFAULT_TIMEOUT_MS);
+
+/// Outcome delivered to a waiter: the response, or why it will never come
+pub type PendingOutcome = Result<JsonRpcResponse>;
+
+/// A registered request awaiting its response
+#[derive(Debug)]
+struct PendingRequest {
+    sender: oneshot::Sender<PendingOutcome>,
+    deadline: Instant,
+}
+
+/// Map of pending request ids to response channels
+#[derive(Debug)]
+pub struct CorrelationMap {
+    pending: Mutex<HashMap<MessageId, PendingRequest>>,
+    max_pending: usize,
+}
+
+impl CorrelationMap {
+    /// Create a map holding at most `max_pending` requests
+    pub fn new(max_pending: usize) -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+            max_pending,
+        }
+    }
+
+    /// Register a request and return the channel its response arrives on
+    ///
+    /// Expired entries are evicted first. Fails if the id is already pending
+    /// or the map is at capacity.
+    pub fn register(
+        &self,
+        id: MessageId,
+        timeout: Duration,
+    ) -> Result<oneshot::Receiver<PendingOutcome>> {
+        let now = Instant::now();
+        let mut pending = self.lock();
+        pending.retain(|_, request| request.deadline > now);
+
+        if pending.contains_key(&id) {
+            return Err(Error::bad_request(format!(
+                "Request id {id} is already pending"
+            )));
+        }
+        if pending.len() >= self.max_pending {
+            return Err(Error::rate_limited(format!(
+                "Too many pending requests (max {})",
+                self.max_pending
+            )));
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        pending.insert(
+            id,
+            PendingRequest {
+                sender,
+                deadline: now + timeout,
+            },
+        );
+        Ok(receiver)
+    }
+
+    /// Deliver a response to the request waiting for it
+    ///
+    /// Returns `false` if no request with the response's id is pending, in
+    /// which case the response is dropped.
+    pub fn complete(&self, response: JsonRpcResponse) -> bool {
+        let Some(id) = response.id.as_ref() else {
+            return false;
+        };
+        match self.lock().remove(id) {
+            Some(request) => request.sender.send(Ok(response)).is_ok(),
+            None => false,
+        }
+    }
+
+    /// Stop waiting for a request, returning whether it was pending
+    pub fn cancel(&self, id: &MessageId) -> bool {
+        self.lock().remove(id).is_some()
+    }
+
+    /// Fail every pending request with a cancellation error
+    ///
+    /// Returns the ids of the requests that were pending, leaving the map
+    /// empty. Telling the server about them is up to the caller.
+    pub fn cancel_all(&self, reason: &str) -> Vec<MessageId> {
+        let drained: Vec<_> = self.lock().drain().collect();
+        drained
+            .into_iter()
+            .map(|(id, request)| {
+                // The waiter may be gone, e.g. if its future was dropped
+                let _ = request.sender.send(Err(Error::cancelled(reason)));
+                id
+            })
+            .collect()
+    }
+
+    /// Evict every request whose deadline has passed, returning how many
+    pub fn evict_expired(&self) -> usize {
+        let now = Instant::now();
+        let mut pending = self.lock();
+        let before = pending.len();
+        pending.retain(|_, request| request.deadline > now);
+        before - pending.len()
+    }
+
+    /// Number of requests currently awaiting a response
+    pub fn pending_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Maximum number of requests that may be pending at once
+    pub const fn max_pending(&self) -> usize {
+        self.max_pending
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<MessageId, PendingRequest>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for CorrelationMap {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PENDING_REQUESTS)
+    }
+}
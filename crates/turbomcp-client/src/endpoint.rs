@@ -0,0 +1,209 @@
+//! Choosing a transport from a server address
+//!
+//! [`Client::connect`](crate::Client::connect) takes the address of a server
+//! as a URI and builds the matching transport, so connection code does not
+//! need to know which transport types exist:
+//!
+//! | Address                  | Transport                                      |
+//! |--------------------------|------------------------------------------------|
+//! | `stdio:`                 | this process's own stdin and stdout            |
+//! | `stdio:<command> [args]` | a server started as a child process            |
+//! | `tcp://<host>:<port>`    | TCP (`tcp` feature)                            |
+//! | `unix://<path>`          | Unix domain socket (`unix` feature, Unix only) |
+//! | `ws://...`, `wss://...`  | WebSocket (`websocket` feature)                |
+//!
+//! A child process command is split on whitespace; arguments containing
+//! spaces need a [`ChildProcessTransport`] built by hand. Schemes are matched
+//! case-insensitively. Parsing does not depend on which features are
+//! enabled: an address for a disabled transport parses, and fails only when
+//! the transport is created, with an error naming the feature to enable.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use turbomcp_core::{Error, Result};
+use turbomcp_transport::Transport;
+use turbomcp_transport::child_process::{ChildProcessConfig, ChildProcessTransport};
+
+/// Schemes [`Endpoint::parse`] understands, for error messages
+const SUPPORTED_SCHEMES: &str = "stdio:, tcp://, unix://, ws://, wss://";
+
+/// Where a server can be reached, parsed from its address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endpoint {
+    /// This process's own stdin and stdout
+    Stdio,
+    /// A server started as a child process, spoken to over its stdio
+    Command {
+        /// Program to run
+        program: String,
+        /// Arguments passed to the program
+        args: Vec<String>,
+    },
+    /// A TCP server
+    Tcp {
+        /// Host name or IP address
+        host: String,
+        /// Port number
+        port: u16,
+    },
+    /// A Unix domain socket
+    Unix(PathBuf),
+    /// A WebSocket server, with its full `ws://` or `wss://` URL
+    WebSocket(String),
+}
+
+impl Endpoint {
+    /// Parse a server address
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error for an unknown scheme or an address
+    /// missing the parts its scheme needs, such as a TCP port.
+    pub fn parse(uri: &str) -> Result<Self> {
+        let uri = uri.trim();
+        let Some((scheme, rest)) = uri.split_once(':') else {
+            return Err(Error::configuration(format!(
+                "Server address '{uri}' has no scheme; expected one of {SUPPORTED_SCHEMES}"
+            )));
+        };
+        match scheme.to_ascii_lowercase().as_str() {
+            "stdio" => {
+                let mut words = rest.trim_start_matches("//").split_whitespace();
+                Ok(match words.next() {
+                    None => Self::Stdio,
+                    Some(program) => Self::Command {
+                        program: program.to_string(),
+                        args: words.map(str::to_string).collect(),
+                    },
+                })
+            }
+            "tcp" => {
+                let authority = rest
+                    .strip_prefix("//")
+                    .unwrap_or(rest)
+                    .trim_end_matches('/');
+                let (host, port) = authority
+                    .rsplit_once(':')
+                    .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+                    .ok_or_else(|| {
+                        Error::configuration(format!(
+                            "TCP address '{uri}' needs a host and port, as in tcp://localhost:3000"
+                        ))
+                    })?;
+                let host = host.trim_start_matches('[').trim_end_matches(']');
+                if host.is_empty() {
+                    return Err(Error::configuration(format!(
+                        "TCP address '{uri}' has no host"
+                    )));
+                }
+                Ok(Self::Tcp {
+                    host: host.to_string(),
+                    port,
+                })
+            }
+            "unix" => {
+                let path = rest.strip_prefix("//").unwrap_or(rest);
+                if path.is_empty() {
+                    return Err(Error::configuration(format!(
+                        "Unix socket address '{uri}' has no path"
+                    )));
+                }
+                Ok(Self::Unix(PathBuf::from(path)))
+            }
+            "ws" | "wss" => Ok(Self::WebSocket(uri.to_string())),
+            other => Err(Error::configuration(format!(
+                "Unsupported transport scheme '{other}' in '{uri}'; expected one of {SUPPORTED_SCHEMES}"
+            ))),
+        }
+    }
+
+    /// Create the transport for this endpoint
+    ///
+    /// Child process, TCP and Unix socket transports are returned
+    /// unconnected; a WebSocket transport connects as it is created.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the transport's feature is not
+    /// enabled, or a transport error if a TCP host cannot be resolved or a
+    /// WebSocket connection fails.
+    pub async fn transport(&self) -> Result<Box<dyn Transport>> {
+        match self {
+            Self::Stdio => Ok(Box::new(turbomcp_transport::StdioTransport::new())),
+            Self::Command { program, args } => {
+                Ok(Box::new(ChildProcessTransport::new(ChildProcessConfig {
+                    command: program.clone(),
+                    args: args.clone(),
+                    ..ChildProcessConfig::default()
+                })))
+            }
+            #[cfg(feature = "tcp")]
+            Self::Tcp { host, port } => {
+                let remote = tokio::net::lookup_host((host.as_str(), *port))
+                    .await
+                    .map_err(|e| Error::transport(format!("Cannot resolve {host}: {e}")))?
+                    .next()
+                    .ok_or_else(|| Error::transport(format!("{host} has no addresses")))?;
+                let local = if remote.is_ipv4() {
+                    std::net::SocketAddr::from(([0, 0, 0, 0], 0))
+                } else {
+                    std::net::SocketAddr::from(([0u16; 8], 0))
+                };
+                Ok(Box::new(turbomcp_transport::TcpTransport::new_client(
+                    local, remote,
+                )))
+            }
+            #[cfg(all(feature = "unix", unix))]
+            Self::Unix(path) => Ok(Box::new(turbomcp_transport::UnixTransport::new_client(
+                path.clone(),
+            ))),
+            #[cfg(feature = "websocket")]
+            Self::WebSocket(url) => {
+                let transport = turbomcp_transport::WebSocketTransport::new(url)
+                    .await
+                    .map_err(|e| Error::transport(format!("Connect to {url} failed: {e}")))?;
+                Ok(Box::new(transport))
+            }
+            #[allow(unreachable_patterns)]
+            disabled => Err(Error::configuration(format!(
+                "Transport for {disabled} is not available: it needs {}",
+                disabled.requirement()
+            ))),
+        }
+    }
+
+    /// What must be enabled to use this endpoint's transport
+    fn requirement(&self) -> &'static str {
+        match self {
+            Self::Stdio | Self::Command { .. } => "the 'stdio' feature of turbomcp-transport",
+            Self::Tcp { .. } => "the 'tcp' feature of turbomcp-client",
+            Self::Unix(_) => "the 'unix' feature of turbomcp-client on a Unix platform",
+            Self::WebSocket(_) => "the 'websocket' feature of turbomcp-client",
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdio => f.write_str("stdio:"),
+            Self::Command { program, args } => {
+                write!(f, "stdio:{program}")?;
+                args.iter().try_for_each(|arg| write!(f, " {arg}"))
+            }
+            Self::Tcp { host, port } if host.contains(':') => write!(f, "tcp://[{host}]:{port}"),
+            Self::Tcp { host, port } => write!(f, "tcp://{host}:{port}"),
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+            Self::WebSocket(url) => f.write_str(url),
+        }
+    }
+}
+
+impl std::str::FromStr for Endpoint {
+    type Err = Box<Error>;
+
+    fn from_str(uri: &str) -> Result<Self> {
+        Self::parse(uri)
+    }
+}
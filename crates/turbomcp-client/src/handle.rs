@@ -0,0 +1,247 @@
+//! Concurrent requests over one connection
+//!
+//! [`Client`] methods borrow the client mutably, so its requests run one at
+//! a time, each reading from the transport until its own response arrives.
+//! [`Client::spawn_reader`] instead hands the connection to a dedicated
+//! reader task and returns a [`ClientHandle`] that any number of tasks can
+//! clone and send requests through at once.
+//!
+//! The connection is split in two halves. Outbound messages are queued on a
+//! channel that the reader task drains into the transport, so a request is
+//! never stuck behind another one's wait. Inbound, the reader routes each
+//! message by what it is: a response completes the waiter registered under
+//! its id in the shared [`CorrelationMap`], in whatever order responses
+//! arrive; a notification is published on the notification channel that
+//! [`ClientHandle::subscribe_notifications`] listens on; and a request from
+//! the server is answered, as the client itself would answer it.
+//!
+//! The reader stops once every handle has been dropped, or when the
+//! transport fails, in which case requests still waiting fail with a
+//! [`Cancelled`](ErrorKind::Cancelled) error.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use turbomcp_core::{Error, ErrorKind, MessageId, Result};
+use turbomcp_protocol::jsonrpc::{JsonRpcNotification, JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::methods;
+use turbomcp_protocol::types::CancelledNotification;
+use turbomcp_transport::{Transport, TransportMessage};
+
+use crate::correlation::CorrelationMap;
+use crate::{Client, ProtocolClient, response_result};
+
+/// Messages queued for the reader task before senders wait for room
+const OUTBOUND_BACKLOG: usize = 64;
+
+/// A message for the reader task to send, and where to report how it went
+type Outbound = (TransportMessage, oneshot::Sender<Result<()>>);
+
+/// What the reader task woke up for
+enum ReaderEvent {
+    /// A message to send, or `None` once every handle is gone
+    Send(Option<Outbound>),
+    /// The transport's next message, or `None` when held notifications are due
+    Received(Result<Option<TransportMessage>>),
+}
+
+impl<T: Transport + 'static> Client<T> {
+    /// Hand the connection to a reader task for concurrent requests
+    ///
+    /// Returns a cloneable [`ClientHandle`] and the reader task, which ends
+    /// with `Ok` once every handle has been dropped and with the transport
+    /// error if the connection fails. Initialize the client first; the
+    /// handle sends requests as they are, without checking capabilities.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use turbomcp_client::Client;
+    /// use turbomcp_transport::stdio::StdioTransport;
+    ///
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let (handle, reader) = client.spawn_reader();
+    /// let (tools, prompts) = tokio::join!(
+    ///     handle.request("tools/list", None),
+    ///     handle.request("prompts/list", None),
+    /// );
+    /// println!("{} and {}", tools?, prompts?);
+    ///
+    /// drop(handle);
+    /// reader.await.expect("reader task panicked")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn spawn_reader(self) -> (ClientHandle, JoinHandle<Result<()>>) {
+        let protocol = self.protocol;
+        let (outbound, queued) = mpsc::channel(OUTBOUND_BACKLOG);
+        let handle = ClientHandle {
+            outbound,
+            pending: Arc::clone(&protocol.pending),
+            next_id: Arc::new(AtomicU64::new(protocol.next_id.load(Ordering::Relaxed))),
+            request_timeout: protocol.request_timeout,
+            notifications: protocol.notifications.clone(),
+        };
+        (handle, tokio::spawn(protocol.run_reader(queued)))
+    }
+}
+
+impl<T: Transport> ProtocolClient<T> {
+    /// Send queued messages and route everything received until every
+    /// handle is gone or the transport fails
+    async fn run_reader(mut self, mut queued: mpsc::Receiver<Outbound>) -> Result<()> {
+        let outcome = loop {
+            // Sends go first, so a request is on the wire before its answer is awaited
+            let event = tokio::select! {
+                biased;
+                next = queued.recv() => ReaderEvent::Send(next),
+                received = self.wait_for_message() => ReaderEvent::Received(received),
+            };
+            let received = match event {
+                ReaderEvent::Send(None) => break Ok(()),
+                ReaderEvent::Send(Some((message, sent))) => {
+                    let _ = sent.send(self.send_message(message).await);
+                    continue;
+                }
+                ReaderEvent::Received(Err(e)) => break Err(e),
+                ReaderEvent::Received(Ok(received)) => received,
+            };
+            match self.deliver(received).await {
+                Ok(None) => {}
+                Ok(Some(response)) => {
+                    tracing::debug!(
+                        "Dropping error response without an id: {:?}",
+                        response.error
+                    );
+                }
+                Err(e) if e.kind == ErrorKind::Transport => break Err(e),
+                Err(e) => tracing::warn!("Dropping inbound message: {e}"),
+            }
+        };
+        if let Err(e) = &outcome {
+            self.pending
+                .cancel_all(&format!("Client reader stopped: {e}"));
+        }
+        outcome
+    }
+}
+
+/// Cloneable handle for sending requests over a connection concurrently
+///
+/// Created by [`Client::spawn_reader`]. Requests from any number of clones
+/// may be in flight at once; each one is answered by the response carrying
+/// its id, however the server orders its responses and notifications.
+#[derive(Debug, Clone)]
+pub struct ClientHandle {
+    outbound: mpsc::Sender<Outbound>,
+    pending: Arc<CorrelationMap>,
+    next_id: Arc<AtomicU64>,
+    request_timeout: Duration,
+    notifications: broadcast::Sender<JsonRpcNotification>,
+}
+
+impl ClientHandle {
+    /// Send a request and return the raw JSON result
+    ///
+    /// Like [`Client::raw_request`], the request fails with the server's
+    /// error, when no response arrives within the client's request timeout,
+    /// or when it is cancelled, in which case the server is sent
+    /// `notifications/cancelled` for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader task has stopped, the server answers
+    /// with an error, or the request times out or is cancelled.
+    pub async fn request(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request_id = MessageId::from(id.to_string());
+        let request = JsonRpcRequest {
+            jsonrpc: JsonRpcVersion,
+            id: request_id.clone(),
+            method: method.to_string(),
+            params,
+        };
+        let payload = serde_json::to_vec(&request)
+            .map_err(|e| Error::protocol(format!("Failed to serialize request: {e}")))?;
+        let message = TransportMessage::new(MessageId::from(format!("req-{id}")), payload.into());
+        let response_rx = self
+            .pending
+            .register(request_id.clone(), self.request_timeout)?;
+
+        // The reader completes the waiter; the entry is evicted however this ends
+        let timed_out = || {
+            Error::timeout(format!(
+                "Request '{method}' timed out after {:?}",
+                self.request_timeout
+            ))
+        };
+        let outcome = tokio::time::timeout(self.request_timeout, async {
+            self.send(message).await?;
+            response_rx.await.unwrap_or_else(|_| Err(timed_out()))
+        })
+        .await;
+        self.pending.cancel(&request_id);
+        if let Ok(Err(e)) = &outcome
+            && e.kind == ErrorKind::Cancelled
+        {
+            // Best effort, as the request has already failed locally
+            let _ = self.notify_cancelled(request_id, &e.message).await;
+        }
+        let response = outcome.map_err(|_| timed_out())??;
+        response_result(response)
+    }
+
+    /// Subscribe to every notification the server sends from now on
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<JsonRpcNotification> {
+        self.notifications.subscribe()
+    }
+
+    /// Number of requests currently awaiting a response
+    pub fn pending_requests(&self) -> usize {
+        self.pending.pending_count()
+    }
+
+    /// Queue a message for the reader task and wait until it is sent
+    async fn send(&self, message: TransportMessage) -> Result<()> {
+        let (sent, outcome) = oneshot::channel();
+        self.outbound
+            .send((message, sent))
+            .await
+            .map_err(|_| Self::reader_stopped())?;
+        outcome.await.map_err(|_| Self::reader_stopped())?
+    }
+
+    /// Tell the server to stop working on a cancelled request
+    async fn notify_cancelled(&self, request_id: MessageId, reason: &str) -> Result<()> {
+        let notification = CancelledNotification {
+            request_id,
+            reason: Some(reason.to_string()),
+        };
+        let note = JsonRpcNotification {
+            jsonrpc: JsonRpcVersion,
+            method: methods::CANCELLED.to_string(),
+            params: Some(serde_json::to_value(notification)?),
+        };
+        let payload = serde_json::to_vec(&note)
+            .map_err(|e| Error::protocol(format!("Failed to serialize notification: {e}")))?;
+        self.send(TransportMessage::new(
+            MessageId::from("notification"),
+            payload.into(),
+        ))
+        .await
+    }
+
+    fn reader_stopped() -> Box<Error> {
+        Error::transport("Client reader task has stopped".to_string())
+    }
+}
@@ -58,6 +58,91 @@
 //! # }
 //! ```
 //!
+//! ## Wire Tracing
+//!
+//! To debug protocol disagreements, every request, response and notification
+//! can be logged verbatim at `TRACE` level under the `turbomcp::wire` target.
+//! Enable it with [`ClientBuilder::with_wire_trace`] or by setting
+//! `TURBOMCP_WIRE_TRACE=1` (and `TURBOMCP_WIRE_TRACE_REDACT` to a
+//! comma-separated list of fields to redact).
+//!
+//! ## Message Interceptors
+//!
+//! For concerns that live below JSON-RPC, such as signing, metrics, or
+//! protocol translation, register a [`MessageInterceptor`] with
+//! [`ClientBuilder::with_interceptor`]. Interceptors see every raw
+//! [`TransportMessage`] and may rewrite its payload or metadata. Outbound
+//! messages pass through interceptors in registration order; inbound messages
+//! pass through them in reverse, so the first interceptor registered is the
+//! outermost layer on both paths.
+//!
+//! ## Connecting by Address
+//!
+//! [`Client::connect`] builds the transport from a server address such as
+//! `tcp://localhost:3000`, `unix:///run/mcp.sock`, `ws://host/mcp` or
+//! `stdio:./my-server --verbose`, then initializes the client. TCP, Unix
+//! socket and WebSocket addresses need the crate feature of the same name;
+//! see [`endpoint`] for the accepted forms.
+//!
+//! ## Transport Fallback
+//!
+//! Hosts that can reach a server several ways can list transports in order
+//! of preference with [`ClientBuilder::with_transport_chain`]; the client
+//! uses the first one that connects and initializes within the attempt
+//! timeout, and [`ChainConnection`] reports which one that was.
+//!
+//! ## Raw Transport Access
+//!
+//! Proxies, recorders and protocol bridges can step below JSON-RPC with
+//! [`Client::send_raw`] and [`Client::receive_raw`], which still pass through
+//! interceptors and wire tracing, or take the transport itself with
+//! [`Client::transport_mut`]. The typed API assumes it owns the connection:
+//! a raw receive can consume the response to a typed request or a
+//! notification that subscribers never see, and raw request ids can collide
+//! with the client's own. Keep raw and typed traffic apart in time, or use one
+//! connection for each.
+//!
+//! ## Concurrent Requests
+//!
+//! Requests made through [`Client`] run one at a time, as each borrows the
+//! client mutably. To have several in flight at once, initialize the client
+//! and call [`Client::spawn_reader`]: a dedicated reader task takes over the
+//! transport, and the returned [`ClientHandle`] can be cloned into as many
+//! tasks as needed. See [`handle`] for how messages are routed.
+//!
+//! ## Output Schema Validation
+//!
+//! Clients that rely on `structuredContent` can opt in to checking it against
+//! each tool's declared output schema with
+//! [`ClientBuilder::with_output_schema_validation`]. Schemas are cached from
+//! `tools/list`, which the client calls before the first tool call if it has
+//! not listed tools yet. A result that does not match fails the call with an
+//! error recognized by [`validation::is_output_schema_violation`].
+//!
+//! ## Tool Errors
+//!
+//! A tool can fail in two ways: the call never produces a result, or the
+//! tool runs and answers with a result flagged `isError`. [`Client::call_tool`]
+//! returns the latter as a successful call with `is_error` set, while
+//! [`Client::call_tool_outcome`] separates them: failed calls are `Err`, and
+//! tool failures are [`ToolOutcome::ToolError`]. Agents typically retry the
+//! first and show the second to the model.
+//!
+//! ## Sampling
+//!
+//! Servers may ask the client to run a completion on their behalf with a
+//! `sampling/createMessage` request. Hosts that own a model register a
+//! [`SamplingHandler`] with [`ClientBuilder::with_sampling_handler`], which
+//! also advertises the `sampling` capability at initialization. Such requests
+//! are answered whenever the client is reading from the transport, that is
+//! while one of its own requests is in flight, while waiting for
+//! notifications, or, after [`Client::spawn_reader`], by the reader task. Without a handler, or with sampling disabled, the client
+//! answers them with a method-not-found error.
+//!
+//! Filesystem roots work the same way: a [`RootsProvider`] registered with
+//! [`ClientBuilder::with_roots`] answers `roots/list`, and
+//! [`Client::notify_roots_changed`] tells the server to list them again.
+//!
 //! ## Error Handling
 //!
 //! The client provides comprehensive error handling with automatic retry logic:
@@ -75,19 +160,66 @@
 //! # }
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::broadcast;
 
-use turbomcp_core::{Error, PROTOCOL_VERSION, Result};
+use turbomcp_core::{Error, ErrorKind, MessageId, PROTOCOL_VERSION, Result};
 use turbomcp_protocol::jsonrpc::{
-    JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, JsonRpcVersion,
+    JsonRpcError, JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
+    JsonRpcVersion,
 };
+use turbomcp_protocol::template;
 use turbomcp_protocol::types::{
-    CallToolRequest, CallToolResult, ClientCapabilities as ProtocolClientCapabilities, Content,
-    InitializeRequest, InitializeResult as ProtocolInitializeResult, ListResourcesResult,
-    ListToolsResult, ServerCapabilities,
+    CallToolRequest, CallToolResult, CancelledNotification,
+    ClientCapabilities as ProtocolClientCapabilities, CompleteArgument, CompleteRequest,
+    CompleteResult, Completion, CompletionReference, Content, CreateMessageRequest,
+    CreateMessageResult, GetPromptRequest, GetPromptResult, InitializeRequest,
+    InitializeResult as ProtocolInitializeResult, ListPromptsResult, ListResourcesResult,
+    ListRootsResult, ListToolsResult, MAX_INITIALIZE_META_SIZE, Prompt, PromptInput, PromptMessage,
+    ReadResourceRequest, ReadResourceResult, ResourceContent, ResourceUpdatedNotification, Root,
+    RootsCapabilities, SamplingCapabilities, ServerCapabilities, initialize_meta_size,
 };
-use turbomcp_transport::{Transport, TransportMessage};
+use turbomcp_protocol::{
+    NotificationDeduplicator, NotificationReorderBuffer, error_codes, methods,
+};
+use turbomcp_transport::core::{TransportCapabilities, TransportConfig, TransportState};
+use turbomcp_transport::wire_trace::{WireDirection, WireTracer};
+use turbomcp_transport::{Transport, TransportMessage, TransportType};
+
+pub mod correlation;
+pub mod endpoint;
+pub mod handle;
+pub mod validation;
+
+use correlation::{CorrelationMap, DEFAULT_REQUEST_TIMEOUT, PendingOutcome};
+use validation::OutputSchemaCache;
+
+/// Notifications kept for each subscriber before the oldest are dropped
+pub const NOTIFICATION_BACKLOG: usize = 256;
+
+/// Cursors already followed while collecting every page of a listing
+#[derive(Debug, Default)]
+struct PageCursors(HashSet<String>);
+
+impl PageCursors {
+    /// Accept the cursor for the next page
+    ///
+    /// A cursor seen before would make the listing loop forever, so it is
+    /// reported as a protocol error.
+    fn follow(&mut self, method: &str, cursor: Option<String>) -> Result<Option<String>> {
+        match cursor {
+            Some(cursor) if !self.0.insert(cursor.clone()) => Err(Error::protocol(format!(
+                "Server repeated {method} cursor '{cursor}'"
+            ))),
+            cursor => Ok(cursor),
+        }
+    }
+}
 
 /// Client capability configuration
 ///
@@ -122,6 +254,52 @@ pub struct ClientCapabilities {
     pub sampling: bool,
 }
 
+/// Hook that observes or transforms raw transport messages
+///
+/// Interceptors run below the JSON-RPC layer: outbound messages have already
+/// been serialized, and inbound messages have not been parsed yet. Both
+/// methods default to passing the message through unchanged, so an
+/// implementation only overrides the direction it cares about. Returning an
+/// error aborts the request or notification with that error.
+///
+/// See the [crate-level documentation](crate#message-interceptors) for the
+/// order in which multiple interceptors run.
+#[async_trait]
+pub trait MessageInterceptor: Send + Sync + std::fmt::Debug {
+    /// Inspect or rewrite a message before it is sent
+    async fn on_outbound(&self, message: TransportMessage) -> Result<TransportMessage> {
+        Ok(message)
+    }
+
+    /// Inspect or rewrite a message after it is received
+    async fn on_inbound(&self, message: TransportMessage) -> Result<TransportMessage> {
+        Ok(message)
+    }
+}
+
+/// Answers the server's `sampling/createMessage` requests
+///
+/// Registered with [`ClientBuilder::with_sampling_handler`]. The handler runs
+/// on the task reading from the transport, so a slow model delays the
+/// client's own responses until it returns. An error is sent back to the
+/// server with the code from [`Error::jsonrpc_error_code`].
+#[async_trait]
+pub trait SamplingHandler: Send + Sync + std::fmt::Debug {
+    /// Produce the message the server asked for
+    async fn create_message(&self, request: CreateMessageRequest) -> Result<CreateMessageResult>;
+}
+
+/// Lists the filesystem roots the server may operate on
+///
+/// Registered with [`ClientBuilder::with_roots`] and called for each
+/// `roots/list` request. Every root must have a `file://` URI; a listing with
+/// any other scheme is not sent, and the server gets an error instead.
+#[async_trait]
+pub trait RootsProvider: Send + Sync + std::fmt::Debug {
+    /// Current roots, in the order they should be presented
+    async fn list_roots(&self) -> Result<Vec<Root>>;
+}
+
 /// JSON-RPC protocol handler for MCP communication
 ///
 /// Handles request/response correlation, serialization, and protocol-level concerns.
@@ -130,6 +308,16 @@ pub struct ClientCapabilities {
 struct ProtocolClient<T: Transport> {
     transport: T,
     next_id: AtomicU64,
+    wire_tracer: WireTracer,
+    interceptors: Vec<Arc<dyn MessageInterceptor>>,
+    unknown_notifications: UnknownNotificationPolicy,
+    sampling_handler: Option<Arc<dyn SamplingHandler>>,
+    roots_provider: Option<Arc<dyn RootsProvider>>,
+    pending: Arc<CorrelationMap>,
+    request_timeout: Duration,
+    notifications: broadcast::Sender<JsonRpcNotification>,
+    reorder: NotificationReorderBuffer,
+    dedup: NotificationDeduplicator,
 }
 
 impl<T: Transport> ProtocolClient<T> {
@@ -137,7 +325,148 @@ impl<T: Transport> ProtocolClient<T> {
         Self {
             transport,
             next_id: AtomicU64::new(1),
+            wire_tracer: WireTracer::from_env(),
+            interceptors: Vec::new(),
+            unknown_notifications: UnknownNotificationPolicy::default(),
+            sampling_handler: None,
+            roots_provider: None,
+            pending: Arc::default(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            notifications: broadcast::channel(NOTIFICATION_BACKLOG).0,
+            reorder: NotificationReorderBuffer::default(),
+            dedup: NotificationDeduplicator::default(),
+        }
+    }
+
+    /// Run outbound interceptors, trace, and send a message
+    async fn send_message(&mut self, mut message: TransportMessage) -> Result<()> {
+        for interceptor in &self.interceptors {
+            message = interceptor.on_outbound(message).await?;
+        }
+        self.wire_tracer.trace(WireDirection::Outbound, &message);
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| Error::transport(format!("Transport send failed: {e}")))
+    }
+
+    /// Receive a message if one is waiting, traced and intercepted
+    async fn try_receive_message(&mut self) -> Result<Option<TransportMessage>> {
+        let Some(message) = self
+            .transport
+            .receive()
+            .await
+            .map_err(|e| Error::transport(format!("Transport receive failed: {e}")))?
+        else {
+            return Ok(None);
+        };
+        self.intercept_inbound(message).await.map(Some)
+    }
+
+    /// Trace a received message and run inbound interceptors in reverse
+    async fn intercept_inbound(
+        &mut self,
+        mut message: TransportMessage,
+    ) -> Result<TransportMessage> {
+        self.wire_tracer.trace(WireDirection::Inbound, &message);
+        for interceptor in self.interceptors.iter().rev() {
+            message = interceptor.on_inbound(message).await?;
+        }
+        Ok(message)
+    }
+
+    /// Wait for the transport's next message
+    ///
+    /// Returns `None` instead once notifications held behind a gap in their
+    /// sequence are due to be released. Dropping the future loses nothing,
+    /// so callers may race it against other events; the message is then
+    /// handed to [`deliver`](Self::deliver).
+    async fn wait_for_message(&mut self) -> Result<Option<TransportMessage>> {
+        let gap_deadline = self.reorder.gap_deadline();
+        tokio::select! {
+            received = self.transport.receive_next() => received
+                .map(Some)
+                .map_err(|e| Error::transport(format!("Transport receive failed: {e}"))),
+            () = sleep_until(gap_deadline) => Ok(None),
+        }
+    }
+
+    /// Route what [`wait_for_message`](Self::wait_for_message) returned
+    ///
+    /// Responses, including each one in a batch response, complete their
+    /// pending requests, notifications are dispatched, and requests from the
+    /// server are answered. An error response without an id cannot be matched
+    /// to a request and is returned instead. Without a message, notifications
+    /// held behind a gap that timed out are released.
+    async fn deliver(
+        &mut self,
+        received: Option<TransportMessage>,
+    ) -> Result<Option<JsonRpcResponse>> {
+        let Some(message) = received else {
+            for note in self.reorder.flush_expired() {
+                self.publish_notification(note);
+            }
+            return Ok(None);
+        };
+        let message = self.intercept_inbound(message).await?;
+        match serde_json::from_slice(&message.payload) {
+            Ok(JsonRpcMessage::Response(response)) if response.id.is_none() => {
+                return Ok(Some(response));
+            }
+            Ok(JsonRpcMessage::Response(response)) => {
+                self.pending.complete(response);
+            }
+            Ok(JsonRpcMessage::ResponseBatch(batch)) => {
+                for response in batch {
+                    self.pending.complete(response);
+                }
+            }
+            Ok(JsonRpcMessage::Notification(note)) => {
+                self.dispatch_notification(note, message.metadata.event_id());
+            }
+            Ok(JsonRpcMessage::Request(request)) => {
+                self.answer_request(request).await?;
+            }
+            Ok(_) => {
+                return Err(Error::protocol(
+                    "Invalid JSON-RPC message: unexpected message type".to_string(),
+                ));
+            }
+            Err(e) => {
+                return Err(Error::protocol(format!("Invalid JSON-RPC message: {e}")));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Receive one message and deliver what it carries
+    async fn read_next(&mut self) -> Result<Option<JsonRpcResponse>> {
+        let received = self.wait_for_message().await?;
+        self.deliver(received).await
+    }
+
+    /// Drop replays, restore send order, then apply the unknown policy and
+    /// publish to subscribers
+    ///
+    /// A notification redelivered under an `event_id` already seen is handled
+    /// by the duplicate policy; see [`NotificationDeduplicator`]. Sequenced
+    /// notifications that arrive early are held until the ones before them
+    /// arrive; see [`NotificationReorderBuffer`].
+    fn dispatch_notification(&mut self, note: JsonRpcNotification, event_id: Option<&str>) {
+        if !self.dedup.accept(event_id, &note) {
+            return;
+        }
+        for note in self.reorder.push(note) {
+            self.publish_notification(note);
+        }
+    }
+
+    fn publish_notification(&self, note: JsonRpcNotification) {
+        if !methods::SERVER_NOTIFICATIONS.contains(&note.method.as_str()) {
+            self.unknown_notifications.apply(&note);
         }
+        // Sending only fails when nobody is subscribed
+        let _ = self.notifications.send(note);
     }
 
     /// Send JSON-RPC request and await typed response
@@ -147,9 +476,10 @@ impl<T: Transport> ProtocolClient<T> {
         params: Option<serde_json::Value>,
     ) -> Result<R> {
         let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request_id = turbomcp_core::MessageId::from(id.to_string());
         let request = JsonRpcRequest {
             jsonrpc: JsonRpcVersion,
-            id: turbomcp_core::MessageId::from(id.to_string()),
+            id: request_id.clone(),
             method: method.to_string(),
             params,
         };
@@ -162,32 +492,255 @@ impl<T: Transport> ProtocolClient<T> {
             turbomcp_core::MessageId::from(format!("req-{id}")),
             payload.into(),
         );
-        self.transport
-            .send(message)
-            .await
-            .map_err(|e| Error::transport(format!("Transport send failed: {e}")))?;
+        let mut response_rx = self
+            .pending
+            .register(request_id.clone(), self.request_timeout)?;
 
-        // Receive and deserialize response
-        let response_msg = self
-            .transport
-            .receive()
-            .await
-            .map_err(|e| Error::transport(format!("Transport receive failed: {e}")))?
-            .ok_or_else(|| Error::transport("No response received".to_string()))?;
+        // Await the response; the entry is evicted however this ends
+        let outcome = tokio::time::timeout(self.request_timeout, async {
+            self.send_message(message).await?;
+            self.await_response(&mut response_rx).await
+        })
+        .await;
+        self.pending.cancel(&request_id);
+        if let Ok(Err(e)) = &outcome
+            && e.kind == ErrorKind::Cancelled
+        {
+            // Best effort, as the request has already failed locally
+            let _ = self.notify_cancelled(request_id, &e.message).await;
+        }
+        let response = outcome.map_err(|_| self.timeout_error(method))??;
+        response_result(response)
+    }
+
+    /// Send requests as one JSON-RPC batch and await every response
+    ///
+    /// Results are in request order and fail independently: a request the
+    /// server answers with an error, or does not answer within the request
+    /// timeout, fails without affecting the others. Returns `None` when the
+    /// server rejects the batch as a whole with a single error that carries
+    /// no id, as servers without batch support do.
+    async fn batch_request<R: serde::de::DeserializeOwned>(
+        &mut self,
+        calls: Vec<(&str, Option<serde_json::Value>)>,
+    ) -> Result<Option<Vec<Result<R>>>> {
+        let mut requests: Vec<JsonRpcRequest> = Vec::with_capacity(calls.len());
+        let mut receivers = Vec::with_capacity(calls.len());
+        let first_id = self.next_id.load(Ordering::Relaxed);
+        for (method, params) in calls {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            let request_id = turbomcp_core::MessageId::from(id.to_string());
+            match self
+                .pending
+                .register(request_id.clone(), self.request_timeout)
+            {
+                Ok(receiver) => receivers.push(receiver),
+                Err(e) => {
+                    for request in &requests {
+                        self.pending.cancel(&request.id);
+                    }
+                    return Err(e);
+                }
+            }
+            requests.push(JsonRpcRequest {
+                jsonrpc: JsonRpcVersion,
+                id: request_id,
+                method: method.to_string(),
+                params,
+            });
+        }
+
+        let payload = serde_json::to_vec(&requests)
+            .map_err(|e| Error::protocol(format!("Failed to serialize batch: {e}")))?;
+        let message = TransportMessage::new(
+            turbomcp_core::MessageId::from(format!("batch-{first_id}")),
+            payload.into(),
+        );
+
+        // Collect responses until all have arrived or the timeout elapses
+        let mut responses: Vec<Option<PendingOutcome>> = requests.iter().map(|_| None).collect();
+        let timeout = self.request_timeout;
+        let outcome = tokio::time::timeout(timeout, async {
+            self.send_message(message).await?;
+            while let Some(waiting) = responses.iter().position(Option::is_none) {
+                let received = tokio::select! {
+                    biased;
+                    outcome = &mut receivers[waiting] => {
+                        let outcome = outcome.unwrap_or_else(|_| Err(Self::expired_error(timeout)));
+                        responses[waiting] = Some(outcome);
+                        continue;
+                    }
+                    received = self.wait_for_message() => received?,
+                };
+                if let Some(rejection) = self.deliver(received).await? {
+                    return Ok(Some(rejection));
+                }
+                for (slot, receiver) in responses.iter_mut().zip(&mut receivers) {
+                    if slot.is_none()
+                        && let Ok(outcome) = receiver.try_recv()
+                    {
+                        *slot = Some(outcome);
+                    }
+                }
+            }
+            Ok::<_, Box<Error>>(None)
+        })
+        .await;
+        for request in &requests {
+            self.pending.cancel(&request.id);
+        }
+        for (slot, request) in responses.iter().zip(&requests) {
+            if let Some(Err(e)) = slot
+                && e.kind == ErrorKind::Cancelled
+            {
+                let _ = self.notify_cancelled(request.id.clone(), &e.message).await;
+            }
+        }
+        match outcome {
+            Ok(Err(e)) => return Err(e),
+            Ok(Ok(Some(_rejection))) => return Ok(None),
+            Ok(Ok(None)) | Err(_) => {}
+        }
+
+        Ok(Some(
+            responses
+                .into_iter()
+                .zip(&requests)
+                .map(|(response, request)| match response {
+                    Some(Ok(response)) => response_result(response),
+                    Some(Err(e)) => Err(e),
+                    None => Err(self.timeout_error(&request.method)),
+                })
+                .collect(),
+        ))
+    }
+
+    fn timeout_error(&self, method: &str) -> Box<Error> {
+        Error::timeout(format!(
+            "Request '{method}' timed out after {:?}",
+            self.request_timeout
+        ))
+    }
 
-        let response: JsonRpcResponse = serde_json::from_slice(&response_msg.payload)
-            .map_err(|e| Error::protocol(format!("Invalid JSON-RPC response: {e}")))?;
+    /// Error for a waiter whose entry was evicted once its deadline passed
+    fn expired_error(timeout: Duration) -> Box<Error> {
+        Error::timeout(format!("Request timed out after {timeout:?}"))
+    }
 
-        if let Some(error) = response.error {
-            return Err(Error::rpc(error.code, &error.message));
+    /// Receive until the response for `response_rx` arrives
+    ///
+    /// Interleaved notifications go to the unknown notification policy, and
+    /// responses to requests that are no longer pending are dropped. The
+    /// wait also ends as soon as the request is cancelled.
+    async fn await_response(
+        &mut self,
+        response_rx: &mut tokio::sync::oneshot::Receiver<PendingOutcome>,
+    ) -> Result<JsonRpcResponse> {
+        let timeout = self.request_timeout;
+        loop {
+            let received = tokio::select! {
+                biased;
+                outcome = &mut *response_rx => {
+                    return outcome.unwrap_or_else(|_| Err(Self::expired_error(timeout)));
+                }
+                received = self.wait_for_message() => received?,
+            };
+            // Errors without an id (e.g. parse errors) answer the request in flight
+            if let Some(response) = self.deliver(received).await? {
+                return Ok(response);
+            }
+            if let Ok(outcome) = response_rx.try_recv() {
+                return outcome;
+            }
         }
+    }
+
+    /// Tell the server to stop working on a cancelled request
+    async fn notify_cancelled(&mut self, request_id: MessageId, reason: &str) -> Result<()> {
+        let notification = CancelledNotification {
+            request_id,
+            reason: Some(reason.to_string()),
+        };
+        let params = serde_json::to_value(notification)?;
+        self.notify(methods::CANCELLED, Some(params)).await
+    }
 
-        let result = response
-            .result
-            .ok_or_else(|| Error::protocol("Response missing result field".to_string()))?;
+    /// Answer a request the server sent to the client
+    ///
+    /// `sampling/createMessage` and `roots/list` are served when a sampling
+    /// handler or roots provider is registered; anything else gets a
+    /// method-not-found error.
+    async fn answer_request(&mut self, request: JsonRpcRequest) -> Result<()> {
+        let method = request.method.as_str();
+        let outcome = match (method, &self.sampling_handler, &self.roots_provider) {
+            (methods::CREATE_MESSAGE, Some(handler), _) => {
+                Self::create_message(handler.as_ref(), request.params).await
+            }
+            (methods::LIST_ROOTS, _, Some(provider)) => Self::list_roots(provider.as_ref()).await,
+            _ => Err(JsonRpcError {
+                code: error_codes::METHOD_NOT_FOUND,
+                message: format!("Method not found: {method}"),
+                data: None,
+            }),
+        };
+        let response = match outcome {
+            Ok(result) => JsonRpcResponse::success(result, request.id),
+            Err(error) => JsonRpcResponse::error(error, Some(request.id)),
+        };
+
+        let payload = serde_json::to_vec(&response)
+            .map_err(|e| Error::protocol(format!("Failed to serialize response: {e}")))?;
+        let message = TransportMessage::new(MessageId::from("response"), payload.into());
+        self.send_message(message).await
+    }
+
+    /// Run the sampling handler, mapping failures to JSON-RPC errors
+    async fn create_message(
+        handler: &dyn SamplingHandler,
+        params: Option<serde_json::Value>,
+    ) -> std::result::Result<serde_json::Value, JsonRpcError> {
+        let request: CreateMessageRequest = serde_json::from_value(params.unwrap_or_default())
+            .map_err(|e| JsonRpcError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("Invalid sampling request: {e}"),
+                data: None,
+            })?;
+        let result = handler
+            .create_message(request)
+            .await
+            .map_err(|e| JsonRpcError {
+                code: e.jsonrpc_error_code(),
+                message: e.message.clone(),
+                data: None,
+            })?;
+        serde_json::to_value(result).map_err(|e| JsonRpcError {
+            code: error_codes::INTERNAL_ERROR,
+            message: format!("Failed to serialize sampling result: {e}"),
+            data: None,
+        })
+    }
 
-        serde_json::from_value(result)
-            .map_err(|e| Error::protocol(format!("Invalid response format: {e}")))
+    /// Run the roots provider, rejecting roots outside the `file://` scheme
+    async fn list_roots(
+        provider: &dyn RootsProvider,
+    ) -> std::result::Result<serde_json::Value, JsonRpcError> {
+        let roots = provider.list_roots().await.map_err(|e| JsonRpcError {
+            code: e.jsonrpc_error_code(),
+            message: e.message.clone(),
+            data: None,
+        })?;
+        if let Some(root) = roots.iter().find(|root| !root.uri.starts_with("file://")) {
+            return Err(JsonRpcError {
+                code: error_codes::INTERNAL_ERROR,
+                message: format!("Root URI must use the file:// scheme: {}", root.uri),
+                data: None,
+            });
+        }
+        serde_json::to_value(ListRootsResult { roots }).map_err(|e| JsonRpcError {
+            code: error_codes::INTERNAL_ERROR,
+            message: format!("Failed to serialize roots: {e}"),
+            data: None,
+        })
     }
 
     /// Send JSON-RPC notification (no response expected)
@@ -205,12 +758,29 @@ impl<T: Transport> ProtocolClient<T> {
             turbomcp_core::MessageId::from("notification"),
             payload.into(),
         );
-        self.transport
-            .send(message)
-            .await
-            .map_err(|e| Error::transport(format!("Transport send failed: {e}")))?;
+        self.send_message(message).await
+    }
+}
+
+/// The typed result of a response, or its error
+fn response_result<R: serde::de::DeserializeOwned>(response: JsonRpcResponse) -> Result<R> {
+    if let Some(error) = response.error {
+        return Err(Error::rpc(error.code, &error.message));
+    }
 
-        Ok(())
+    let result = response
+        .result
+        .ok_or_else(|| Error::protocol("Response missing result field".to_string()))?;
+
+    serde_json::from_value(result)
+        .map_err(|e| Error::protocol(format!("Invalid response format: {e}")))
+}
+
+/// Sleep until `deadline`, or forever without one
+async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+        None => std::future::pending().await,
     }
 }
 
@@ -241,9 +811,21 @@ impl<T: Transport> ProtocolClient<T> {
 #[derive(Debug)]
 pub struct Client<T: Transport> {
     protocol: ProtocolClient<T>,
-    #[allow(dead_code)] // Stored for future capability negotiation features
     capabilities: ClientCapabilities,
     initialized: bool,
+    session_meta: HashMap<String, serde_json::Value>,
+    session_info: Option<SessionInfo>,
+    /// Notifications received but not yet examined by `wait_for_notification`
+    notification_backlog: broadcast::Receiver<JsonRpcNotification>,
+    /// Output schemas to check tool results against, when validation is on
+    output_schemas: Option<OutputSchemaCache>,
+    /// Most recent tool listing, reused while the server's version matches
+    tool_manifest: Option<ToolManifest>,
+    /// Tool manifest version last reported by the server, cleared when it
+    /// announces a tool list change
+    manifest_version: Option<String>,
+    /// Notifications checked for tool list changes before reusing the manifest
+    manifest_events: broadcast::Receiver<JsonRpcNotification>,
 }
 
 impl<T: Transport> Client<T> {
@@ -266,10 +848,18 @@ impl<T: Transport> Client<T> {
     /// let client = Client::new(transport);
     /// ```
     pub fn new(transport: T) -> Self {
+        let protocol = ProtocolClient::new(transport);
         Self {
-            protocol: ProtocolClient::new(transport),
+            notification_backlog: protocol.notifications.subscribe(),
+            manifest_events: protocol.notifications.subscribe(),
+            protocol,
             capabilities: ClientCapabilities::default(),
             initialized: false,
+            session_meta: HashMap::new(),
+            session_info: None,
+            output_schemas: None,
+            tool_manifest: None,
+            manifest_version: None,
         }
     }
 
@@ -297,10 +887,18 @@ impl<T: Transport> Client<T> {
     /// let client = Client::with_capabilities(transport, capabilities);
     /// ```
     pub fn with_capabilities(transport: T, capabilities: ClientCapabilities) -> Self {
+        let protocol = ProtocolClient::new(transport);
         Self {
-            protocol: ProtocolClient::new(transport),
+            notification_backlog: protocol.notifications.subscribe(),
+            manifest_events: protocol.notifications.subscribe(),
+            protocol,
             capabilities,
             initialized: false,
+            session_meta: HashMap::new(),
+            session_info: None,
+            output_schemas: None,
+            tool_manifest: None,
+            manifest_version: None,
         }
     }
 
@@ -335,15 +933,32 @@ impl<T: Transport> Client<T> {
     /// # }
     /// ```
     pub async fn initialize(&mut self) -> Result<InitializeResult> {
+        if initialize_meta_size(&self.session_meta) > MAX_INITIALIZE_META_SIZE {
+            return Err(Error::validation(format!(
+                "Session metadata exceeds {MAX_INITIALIZE_META_SIZE} bytes"
+            )));
+        }
+
         // Send actual MCP initialization request
         let request = InitializeRequest {
             protocol_version: PROTOCOL_VERSION.to_string(),
-            capabilities: ProtocolClientCapabilities::default(),
+            capabilities: ProtocolClientCapabilities {
+                sampling: self.capabilities.sampling.then_some(SamplingCapabilities),
+                roots: self
+                    .protocol
+                    .roots_provider
+                    .as_ref()
+                    .map(|_| RootsCapabilities {
+                        list_changed: Some(true),
+                    }),
+                ..Default::default()
+            },
             client_info: turbomcp_protocol::Implementation {
                 name: "turbomcp-client".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 title: Some("TurboMCP Client".to_string()),
             },
+            meta: (!self.session_meta.is_empty()).then(|| self.session_meta.clone()),
         };
 
         let protocol_response: ProtocolInitializeResult = self
@@ -351,6 +966,16 @@ impl<T: Transport> Client<T> {
             .request("initialize", Some(serde_json::to_value(request)?))
             .await?;
         self.initialized = true;
+        self.manifest_version = protocol_response.tool_manifest_version;
+        self.session_info = Some(SessionInfo {
+            protocol_version: protocol_response.protocol_version,
+            server_info: protocol_response.server_info.clone(),
+            server_capabilities: protocol_response.capabilities.clone(),
+            supported_versions: protocol_response.supported_versions.unwrap_or_default(),
+            instructions: protocol_response.instructions,
+            meta: protocol_response.meta.unwrap_or_default(),
+            transport_features: None,
+        });
 
         // Send initialized notification
         self.protocol
@@ -368,6 +993,7 @@ impl<T: Transport> Client<T> {
     ///
     /// Retrieves the list of tools that the server provides. Tools are functions
     /// that can be called to perform specific operations on the server.
+    /// Paginated listings are followed to the last page.
     ///
     /// # Returns
     ///
@@ -379,6 +1005,7 @@ impl<T: Transport> Client<T> {
     /// - The client is not initialized
     /// - The server doesn't support tools
     /// - The request fails
+    /// - The server sends the same page cursor twice
     ///
     /// # Examples
     ///
@@ -402,11 +1029,30 @@ impl<T: Transport> Client<T> {
         }
 
         // Send actual tools/list request
-        let response: ListToolsResult = self.protocol.request("tools/list", None).await?;
+        let response = self.fetch_tools().await?;
         let tool_names = response.tools.into_iter().map(|tool| tool.name).collect();
         Ok(tool_names)
     }
 
+    /// Fetch one page of the server's tool listing
+    ///
+    /// Pass `None` for the first page, then each page's `next_cursor` until
+    /// it is `None`. [`list_tools`](Self::list_tools) does this for you; use
+    /// this to stop early or to spread a large listing over time. Pages are
+    /// not cached as a [`ToolManifest`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized or the request fails.
+    pub async fn list_tools_page(&mut self, cursor: Option<String>) -> Result<ListToolsResult> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let params = cursor.map(|cursor| serde_json::json!({ "cursor": cursor }));
+        self.protocol.request(methods::LIST_TOOLS, params).await
+    }
+
     /// Call a tool on the server
     ///
     /// Executes a tool on the server with the provided arguments.
@@ -418,7 +1064,19 @@ impl<T: Transport> Client<T> {
     ///
     /// # Returns
     ///
-    /// Returns the result of the tool execution.
+    /// Returns a summary of the first content block the tool returned; use
+    /// [`call_tool_typed`](Self::call_tool_typed) for the full result.
+    /// Structured output, if the tool returned any, is included under
+    /// `structured_content`. A tool
+    /// that reports its own failure still returns `Ok`, with `is_error` set;
+    /// use [`call_tool_outcome`](Self::call_tool_outcome) to handle the two
+    /// kinds of failure separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized or the request
+    /// fails, or, with output schema validation enabled, if the result does
+    /// not match the tool's output schema.
     ///
     /// # Examples
     ///
@@ -443,107 +1101,1337 @@ impl<T: Transport> Client<T> {
         name: &str,
         arguments: Option<HashMap<String, serde_json::Value>>,
     ) -> Result<serde_json::Value> {
-        if !self.initialized {
-            return Err(Error::bad_request("Client not initialized"));
-        }
-
-        // Send actual tools/call request
-        let request = CallToolRequest {
-            name: name.to_string(),
-            arguments: Some(arguments.unwrap_or_default()),
-        };
-
-        let response: CallToolResult = self
-            .protocol
-            .request("tools/call", Some(serde_json::to_value(request)?))
-            .await?;
+        let response = self.call_tool_typed(name, arguments).await?;
 
         // Extract content from response - for simplicity, return the first text content
-        if let Some(content) = response.content.first() {
+        let mut summary = if let Some(content) = response.content.first() {
             match content {
-                Content::Text(text_content) => Ok(serde_json::json!({
+                Content::Text(text_content) => serde_json::json!({
                     "text": text_content.text,
                     "is_error": response.is_error.unwrap_or(false)
-                })),
-                Content::Image(image_content) => Ok(serde_json::json!({
+                }),
+                Content::Image(image_content) => serde_json::json!({
                     "image": image_content.data,
                     "mime_type": image_content.mime_type,
                     "is_error": response.is_error.unwrap_or(false)
-                })),
-                Content::Resource(resource_content) => Ok(serde_json::json!({
+                }),
+                Content::Resource(resource_content) => serde_json::json!({
                     "resource": resource_content.resource,
                     "annotations": resource_content.annotations,
                     "is_error": response.is_error.unwrap_or(false)
-                })),
-                Content::Audio(audio_content) => Ok(serde_json::json!({
+                }),
+                Content::Audio(audio_content) => serde_json::json!({
                     "audio": audio_content.data,
                     "mime_type": audio_content.mime_type,
                     "is_error": response.is_error.unwrap_or(false)
-                })),
-                Content::ResourceLink(resource_link) => Ok(serde_json::json!({
+                }),
+                Content::ResourceLink(resource_link) => serde_json::json!({
                     "resource_uri": resource_link.uri,
                     "is_error": response.is_error.unwrap_or(false)
-                })),
+                }),
             }
         } else {
-            Ok(serde_json::json!({
+            serde_json::json!({
                 "message": "No content returned",
                 "is_error": response.is_error.unwrap_or(false)
-            }))
+            })
+        };
+        if let Some(structured) = response.structured_content {
+            summary["structured_content"] = structured;
         }
+        Ok(summary)
     }
 
-    /// List available resources from the server
+    /// Call a tool and return its result as the server sent it
+    ///
+    /// Unlike [`call_tool`](Self::call_tool), which summarizes the first
+    /// content block, every content block is kept in order along with the
+    /// tool's `structuredContent`. A tool that reports failure is still an
+    /// `Ok` result with `is_error` set; see
+    /// [`call_tool_outcome`](Self::call_tool_outcome) to tell the two apart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, the request fails,
+    /// the server answers with a JSON-RPC error, or, with output schema
+    /// validation enabled, the result does not match the tool's output
+    /// schema.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::Content;
     /// # use turbomcp_transport::stdio::StdioTransport;
     /// # async fn example() -> turbomcp_core::Result<()> {
     /// let mut client = Client::new(StdioTransport::new());
     /// client.initialize().await?;
     ///
-    /// let resources = client.list_resources().await?;
-    /// for resource in resources {
-    ///     println!("Available resource: {}", resource);
+    /// let result = client.call_tool_typed("render_chart", None).await?;
+    /// for content in &result.content {
+    ///     match content {
+    ///         Content::Text(text) => println!("{}", text.text),
+    ///         Content::Image(image) => println!("<{} image>", image.mime_type),
+    ///         other => println!("{other:?}"),
+    ///     }
+    /// }
+    /// if let Some(data) = &result.structured_content {
+    ///     println!("data: {data}");
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_resources(&mut self) -> Result<Vec<String>> {
+    pub async fn call_tool_typed(
+        &mut self,
+        name: &str,
+        arguments: Option<HashMap<String, serde_json::Value>>,
+    ) -> Result<CallToolResult> {
+        let raw = self.call_tool_raw(name, arguments).await?;
+        Ok(serde_json::from_value(raw)?)
+    }
+
+    /// Call a tool, keeping tool failures apart from failed calls
+    ///
+    /// A tool that ran and reported failure comes back as an `Ok`
+    /// [`ToolOutcome::ToolError`]: the call itself worked, and the error is
+    /// the tool's answer, usually worth passing on to the model
+    /// rather than retrying. `Err` means the call did not produce a result at
+    /// all, from a transport failure, a JSON-RPC error response such as an
+    /// unknown tool or invalid arguments, or a timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, the request fails,
+    /// the server answers with a JSON-RPC error, or, with output schema
+    /// validation enabled, the result does not match the tool's output
+    /// schema.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::{Client, ToolOutcome};
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// match client.call_tool_outcome("divide", None).await {
+    ///     Ok(ToolOutcome::Success(result)) => println!("Result: {:?}", result.content),
+    ///     Ok(ToolOutcome::ToolError(result)) => println!("Tool failed: {:?}", result.content),
+    ///     Err(e) => eprintln!("Call failed, may be worth retrying: {e}"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn call_tool_outcome(
+        &mut self,
+        name: &str,
+        arguments: Option<HashMap<String, serde_json::Value>>,
+    ) -> Result<ToolOutcome> {
+        let raw = self.call_tool_raw(name, arguments).await?;
+        let result: CallToolResult = serde_json::from_value(raw)?;
+        Ok(ToolOutcome::from(result))
+    }
+
+    /// Send `tools/call` and return the unparsed result, validated if enabled
+    async fn call_tool_raw(
+        &mut self,
+        name: &str,
+        arguments: Option<HashMap<String, serde_json::Value>>,
+    ) -> Result<serde_json::Value> {
         if !self.initialized {
             return Err(Error::bad_request("Client not initialized"));
         }
 
-        // Send actual resources/list request
-        let response: ListResourcesResult = self.protocol.request("resources/list", None).await?;
-        let resource_uris = response
-            .resources
-            .into_iter()
-            .map(|resource| resource.uri)
-            .collect();
-        Ok(resource_uris)
+        // Send actual tools/call request
+        let request = CallToolRequest {
+            name: name.to_string(),
+            arguments: Some(arguments.unwrap_or_default()),
+        };
+
+        let raw: serde_json::Value = self
+            .protocol
+            .request("tools/call", Some(serde_json::to_value(request)?))
+            .await?;
+        if self.output_schemas.is_some() {
+            self.validate_tool_output(name, &raw).await?;
+        }
+        Ok(raw)
     }
-}
 
-/// Result of client initialization
-///
-/// Contains information about the server and the negotiated capabilities
-/// after a successful initialization handshake.
-///
-/// # Examples
-///
-/// ```rust,no_run
-/// # use turbomcp_client::Client;
-/// # use turbomcp_transport::stdio::StdioTransport;
-/// # async fn example() -> turbomcp_core::Result<()> {
-/// let mut client = Client::new(StdioTransport::new());
-/// let result = client.initialize().await?;
-///
-/// println!("Server: {}", result.server_info.name);
-/// println!("Version: {}", result.server_info.version);
-/// if let Some(title) = result.server_info.title {
+    /// List tools, refreshing cached output schemas when validating
+    ///
+    /// The cached manifest is returned instead of sending `tools/list` while
+    /// its version is the one the server last reported.
+    async fn fetch_tools(&mut self) -> Result<ListToolsResult> {
+        self.drain_manifest_events();
+        let current = self
+            .tool_manifest
+            .as_ref()
+            .filter(|manifest| self.manifest_version.as_ref() == Some(&manifest.version));
+        let response = match current {
+            Some(manifest) => ListToolsResult {
+                tools: manifest.tools.clone(),
+                next_cursor: None,
+                manifest_version: Some(manifest.version.clone()),
+            },
+            None => {
+                let mut response = self.list_tools_page(None).await?;
+                let mut cursors = PageCursors::default();
+                let mut cursor =
+                    cursors.follow(methods::LIST_TOOLS, response.next_cursor.take())?;
+                // Pages that disagree on the version were listed while the
+                // tools changed, so the combined listing is not cached
+                let mut consistent = true;
+                while let Some(next) = cursor {
+                    let page = self.list_tools_page(Some(next)).await?;
+                    consistent &= page.manifest_version == response.manifest_version;
+                    response.tools.extend(page.tools);
+                    cursor = cursors.follow(methods::LIST_TOOLS, page.next_cursor)?;
+                }
+                self.manifest_version.clone_from(&response.manifest_version);
+                self.tool_manifest = response
+                    .manifest_version
+                    .clone()
+                    .filter(|_| consistent)
+                    .map(|version| ToolManifest {
+                        version,
+                        tools: response.tools.clone(),
+                    });
+                response
+            }
+        };
+        if let Some(cache) = &mut self.output_schemas {
+            cache.update(&response.tools);
+        }
+        Ok(response)
+    }
+
+    /// Stop trusting the manifest version once the server's tools change
+    fn drain_manifest_events(&mut self) {
+        loop {
+            match self.manifest_events.try_recv() {
+                Ok(note) if note.method == methods::TOOL_LIST_CHANGED => {
+                    self.manifest_version = None;
+                }
+                // A missed notification may have been a tool list change
+                Err(broadcast::error::TryRecvError::Lagged(_)) => self.manifest_version = None,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+    }
+
+    /// Check a raw tool result against the tool's cached output schema
+    ///
+    /// Tools missing from the cache trigger one fresh listing, so schemas
+    /// are fetched lazily and tools added since the last listing are seen.
+    async fn validate_tool_output(&mut self, name: &str, raw: &serde_json::Value) -> Result<()> {
+        if self
+            .output_schemas
+            .as_ref()
+            .is_some_and(|cache| !cache.contains(name))
+        {
+            self.fetch_tools().await?;
+        }
+        match &self.output_schemas {
+            Some(cache) => cache.validate(name, raw),
+            None => Ok(()),
+        }
+    }
+
+    /// List the server's tools that match a predicate
+    ///
+    /// Unlike [`list_tools`](Self::list_tools), which returns names only, this
+    /// returns full [`Tool`] definitions including their annotations, so hosts
+    /// can implement policies such as auto-approving read-only or idempotent
+    /// tools.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized or the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let read_only = client
+    ///     .list_tools_filtered(|tool| {
+    ///         tool.annotations
+    ///             .as_ref()
+    ///             .and_then(|a| a.read_only_hint())
+    ///             .unwrap_or(false)
+    ///     })
+    ///     .await?;
+    /// for tool in read_only {
+    ///     println!("Safe to auto-run: {}", tool.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_tools_filtered<F>(&mut self, predicate: F) -> Result<Vec<Tool>>
+    where
+        F: Fn(&Tool) -> bool,
+    {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let response = self.fetch_tools().await?;
+        Ok(response
+            .tools
+            .into_iter()
+            .filter(|tool| predicate(tool))
+            .collect())
+    }
+
+    /// List available resources from the server
+    ///
+    /// Paginated listings are followed to the last page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, a request fails, or
+    /// the server sends the same page cursor twice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let resources = client.list_resources().await?;
+    /// for resource in resources {
+    ///     println!("Available resource: {}", resource);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_resources(&mut self) -> Result<Vec<String>> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let mut response = self.list_resources_page(None).await?;
+        let mut cursors = PageCursors::default();
+        let mut cursor = cursors.follow(methods::LIST_RESOURCES, response.next_cursor.take())?;
+        while let Some(next) = cursor {
+            let page = self.list_resources_page(Some(next)).await?;
+            response.resources.extend(page.resources);
+            cursor = cursors.follow(methods::LIST_RESOURCES, page.next_cursor)?;
+        }
+        let resource_uris = response
+            .resources
+            .into_iter()
+            .map(|resource| resource.uri)
+            .collect();
+        Ok(resource_uris)
+    }
+
+    /// Fetch one page of the server's resource listing
+    ///
+    /// Pass `None` for the first page, then each page's `next_cursor` until
+    /// it is `None`, as with [`list_tools_page`](Self::list_tools_page).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized or the request fails.
+    pub async fn list_resources_page(
+        &mut self,
+        cursor: Option<String>,
+    ) -> Result<ListResourcesResult> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let params = cursor.map(|cursor| serde_json::json!({ "cursor": cursor }));
+        self.protocol.request(methods::LIST_RESOURCES, params).await
+    }
+
+    /// Read a resource from the server
+    ///
+    /// Returns every content entry the server sent for `uri`, text and blob
+    /// alike: a resource may come back as several entries, such as an image
+    /// alongside its caption. Blob data stays base64-encoded, as on the wire.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::ResourceContent;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let result = client.read_resource("file:///README.md").await?;
+    /// for content in result.contents {
+    ///     match content {
+    ///         ResourceContent::Text(text) => println!("{}", text.text),
+    ///         ResourceContent::Blob(blob) => println!("<{} base64 bytes>", blob.blob.len()),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resource(&mut self, uri: &str) -> Result<ReadResourceResult> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let request = ReadResourceRequest::new(uri);
+        self.protocol
+            .request(methods::READ_RESOURCE, Some(serde_json::to_value(request)?))
+            .await
+    }
+
+    /// Read part of a resource from the server
+    ///
+    /// Requests `length` bytes (or everything after `offset` when `None`)
+    /// starting at byte `offset`, so large resources such as logs can be read
+    /// a slice at a time. The result's `range` describes the returned slice.
+    /// A server whose resource does not support ranges returns the whole
+    /// content with no `range` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let result = client
+    ///     .read_resource_range("file:///var/log/app.log", 4096, Some(1024))
+    ///     .await?;
+    /// match result.range {
+    ///     Some(range) => println!("Got bytes {}..{}", range.offset, range.offset + range.length),
+    ///     None => println!("Got the whole resource"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resource_range(
+        &mut self,
+        uri: &str,
+        offset: u64,
+        length: Option<u64>,
+    ) -> Result<ReadResourceResult> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let request = ReadResourceRequest::new(uri).with_range(offset, length);
+        self.protocol
+            .request(methods::READ_RESOURCE, Some(serde_json::to_value(request)?))
+            .await
+    }
+
+    /// Read several resources in one round-trip
+    ///
+    /// Sends every read as a single JSON-RPC batch and returns one result per
+    /// URI, in the order given. Reads succeed or fail independently: a URI
+    /// the server cannot read, or one it does not answer within the request
+    /// timeout, yields an `Err` in its slot while the other reads still return
+    /// their contents. The outer `Err` is reserved for failures of the whole
+    /// exchange, such as a closed transport.
+    ///
+    /// If the server rejects the batch itself, as servers without batch
+    /// support do, the resources are read one request at a time instead. The
+    /// same happens without a batch attempt when
+    /// [`negotiate_transport`](Self::negotiate_transport) found the server
+    /// does not advertise batching.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let uris = ["file:///src/lib.rs", "file:///src/main.rs", "file:///Cargo.toml"];
+    /// for (uri, result) in uris.iter().zip(client.read_resources(&uris).await?) {
+    ///     match result {
+    ///         Ok(resource) => println!("{uri}: {} content item(s)", resource.contents.len()),
+    ///         Err(e) => eprintln!("{uri}: {e}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_resources(
+        &mut self,
+        uris: &[&str],
+    ) -> Result<Vec<Result<ReadResourceResult>>> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+        if uris.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut calls = Vec::with_capacity(uris.len());
+        for uri in uris {
+            let request = serde_json::to_value(ReadResourceRequest::new(*uri))?;
+            calls.push((methods::READ_RESOURCE, Some(request)));
+        }
+        let batching = self
+            .session_info
+            .as_ref()
+            .and_then(|session| session.transport_features.as_ref())
+            .is_none_or(|features| features.batching);
+        if batching && let Some(results) = self.protocol.batch_request(calls.clone()).await? {
+            return Ok(results);
+        }
+
+        let mut results = Vec::with_capacity(calls.len());
+        for (method, params) in calls {
+            results.push(self.protocol.request(method, params).await);
+        }
+        Ok(results)
+    }
+
+    /// Bring a cached read of a resource up to date after an update
+    ///
+    /// When `update` carries a [`ResourcePatch`](turbomcp_protocol::ResourcePatch)
+    /// that fits `cached`, the patch is applied in place and nothing is sent.
+    /// Otherwise, with no patch, a copy that has missed an earlier update, or
+    /// a cached slice from a ranged read, the whole resource is read again and
+    /// replaces `cached`. Returns `true` if the patch was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a full read is needed and fails; `cached` is then
+    /// left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::{ReadResourceResult, ResourceUpdatedNotification};
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let uri = "file:///var/log/app.log";
+    /// let mut log = client.read_resources(&[uri]).await?.remove(0)?;
+    /// let note = client
+    ///     .wait_for_notification(
+    ///         |note| note.method == "notifications/resources/updated",
+    ///         Duration::from_secs(30),
+    ///     )
+    ///     .await?;
+    /// let update: ResourceUpdatedNotification =
+    ///     serde_json::from_value(note.params.unwrap_or_default()).expect("valid update");
+    /// client.apply_resource_update(&mut log, &update).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_resource_update(
+        &mut self,
+        cached: &mut ReadResourceResult,
+        update: &ResourceUpdatedNotification,
+    ) -> Result<bool> {
+        if let Some(patch) = &update.patch
+            && cached.range.is_none_or(|range| range.offset == 0)
+            && let [content] = cached.contents.as_mut_slice()
+            && patch.apply(content).is_ok()
+        {
+            // A whole-resource read reported as a range keeps describing it
+            if let (Some(range), ResourceContent::Text(text)) = (&mut cached.range, &*content) {
+                range.length = text.text.len() as u64;
+                range.total_size = Some(range.length);
+            }
+            return Ok(true);
+        }
+
+        let request = serde_json::to_value(ReadResourceRequest::new(update.uri.clone()))?;
+        *cached = self
+            .protocol
+            .request(methods::READ_RESOURCE, Some(request))
+            .await?;
+        Ok(false)
+    }
+
+    /// List the prompts the server offers
+    ///
+    /// Each [`Prompt`] carries its description and declared arguments, so
+    /// callers can tell which arguments [`get_prompt`](Self::get_prompt)
+    /// needs before fetching it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// for prompt in client.list_prompts().await? {
+    ///     let arguments = prompt.arguments.unwrap_or_default();
+    ///     let names: Vec<_> = arguments.iter().map(|arg| arg.name.as_str()).collect();
+    ///     println!("{}({})", prompt.name, names.join(", "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_prompts(&mut self) -> Result<Vec<Prompt>> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let response: ListPromptsResult =
+            self.protocol.request(methods::LIST_PROMPTS, None).await?;
+        Ok(response.prompts)
+    }
+
+    /// Fetch a prompt from the server
+    ///
+    /// `arguments` fill the prompt's declared arguments. The messages come
+    /// back exactly as the server sent them: image, audio and embedded
+    /// resource content is kept as such rather than reduced to text, so it can
+    /// be forwarded to a model that accepts it. Use [`render_prompt`] to fill
+    /// in any placeholders the server left in text messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::Content;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let prompt = client.get_prompt("describe_screenshot", None).await?;
+    /// for message in &prompt.messages {
+    ///     match &message.content {
+    ///         Content::Text(text) => println!("{:?}: {}", message.role, text.text),
+    ///         Content::Image(image) => println!("{:?}: <{}>", message.role, image.mime_type),
+    ///         other => println!("{:?}: {other:?}", message.role),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_prompt(
+        &mut self,
+        name: &str,
+        arguments: Option<PromptInput>,
+    ) -> Result<GetPromptResult> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let request = GetPromptRequest {
+            name: name.to_string(),
+            arguments,
+        };
+        self.protocol
+            .request(methods::GET_PROMPT, Some(serde_json::to_value(request)?))
+            .await
+    }
+
+    /// Ask the server to suggest values for a prompt or resource argument
+    ///
+    /// `value` is what the user has typed so far. The server sends at most
+    /// 100 suggestions; `total` and `has_more` on the result say whether
+    /// there were others.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_protocol::types::CompletionReference;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let reference = CompletionReference::Prompt {
+    ///     name: "translate".to_string(),
+    /// };
+    /// let completion = client.complete(reference, "language", "fr").await?;
+    /// println!("{}", completion.values.join(", "));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete(
+        &mut self,
+        reference: CompletionReference,
+        argument: &str,
+        value: &str,
+    ) -> Result<Completion> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+
+        let request = CompleteRequest {
+            reference,
+            argument: CompleteArgument {
+                name: argument.to_string(),
+                value: value.to_string(),
+            },
+            context: None,
+        };
+        let response: CompleteResult = self
+            .protocol
+            .request(methods::COMPLETE, Some(serde_json::to_value(request)?))
+            .await?;
+        Ok(response.completion)
+    }
+
+    /// Send an arbitrary JSON-RPC request and return the raw `result`
+    ///
+    /// An escape hatch for protocol extensions and vendor-specific methods the
+    /// typed API does not cover. Unlike the typed methods this does not require
+    /// `initialize()` first, so it can also be used for pre-initialization
+    /// extensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transport fails or the server responds with a
+    /// JSON-RPC error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let result = client
+    ///     .raw_request("x/echo", Some(serde_json::json!({"hello": "world"})))
+    ///     .await?;
+    /// println!("Echoed: {result}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn raw_request(
+        &mut self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        self.protocol.request(method, params).await
+    }
+
+    /// Send a message without the JSON-RPC layer
+    ///
+    /// The message passes through outbound interceptors and wire tracing but
+    /// is otherwise sent as is: nothing is correlated, and a request sent this
+    /// way is answered only by [`receive_raw`](Self::receive_raw). See the
+    /// [crate docs](crate#raw-transport-access) on mixing raw and typed use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an interceptor rejects the message or the
+    /// transport fails.
+    pub async fn send_raw(&mut self, message: TransportMessage) -> Result<()> {
+        self.protocol.send_message(message).await
+    }
+
+    /// Receive the next message without the JSON-RPC layer
+    ///
+    /// Returns `None` if nothing is waiting. The message passes through wire
+    /// tracing and inbound interceptors, but is not matched to a pending
+    /// request or delivered to notification subscribers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an interceptor rejects the message or the
+    /// transport fails.
+    pub async fn receive_raw(&mut self) -> Result<Option<TransportMessage>> {
+        self.protocol.try_receive_message().await
+    }
+
+    /// The underlying transport
+    pub const fn transport(&self) -> &T {
+        &self.protocol.transport
+    }
+
+    /// Mutable access to the underlying transport
+    ///
+    /// Messages sent or received directly bypass interceptors and wire
+    /// tracing as well as the JSON-RPC layer; prefer
+    /// [`send_raw`](Self::send_raw) and [`receive_raw`](Self::receive_raw)
+    /// unless the transport itself needs configuring.
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.protocol.transport
+    }
+
+    /// Subscribe to every notification the server sends from now on
+    ///
+    /// Notifications are delivered to each subscriber as the client receives
+    /// them while performing requests or waiting with
+    /// [`wait_for_notification`](Self::wait_for_notification). A subscriber
+    /// that falls more than [`NOTIFICATION_BACKLOG`] notifications behind
+    /// misses the oldest ones.
+    #[must_use]
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<JsonRpcNotification> {
+        self.protocol.notifications.subscribe()
+    }
+
+    /// How sequenced server notifications have arrived so far
+    ///
+    /// Servers that stamp notifications with a sequence number under
+    /// [`SEQUENCE_META_KEY`](turbomcp_protocol::SEQUENCE_META_KEY) have them
+    /// delivered in send order. The counters show how many arrived early,
+    /// how many were lost, and how many arrived too late and were dropped.
+    #[must_use]
+    pub fn notification_ordering_stats(&self) -> OrderingStats {
+        self.protocol.reorder.stats()
+    }
+
+    /// The last new event id a resumable transport reported
+    ///
+    /// Pass it as `Last-Event-ID` when resuming the stream after a
+    /// reconnect. Events replayed from before it that the client already
+    /// handled are recognized by their ids and not delivered twice; see
+    /// [`ClientBuilder::with_duplicate_notifications`].
+    #[must_use]
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.protocol.dedup.last_event_id()
+    }
+
+    /// How many notifications were received again under a known event id
+    #[must_use]
+    pub fn duplicate_notifications(&self) -> u64 {
+        self.protocol.dedup.duplicates()
+    }
+
+    /// Wait for a server notification matching `predicate`
+    ///
+    /// Notifications that arrived while earlier requests were in flight are
+    /// examined first, so one sent before the response that triggered it is
+    /// not missed. Otherwise the transport is read until a match arrives.
+    /// Waiting observes notifications without taking them from
+    /// [`subscribe_notifications`](Self::subscribe_notifications) receivers
+    /// or the unknown notification policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns a timeout error if no match arrives within `timeout`, or an
+    /// error if the transport fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::new(StdioTransport::new());
+    /// client.initialize().await?;
+    ///
+    /// let updated = client
+    ///     .wait_for_notification(
+    ///         |note| note.method == "notifications/resources/updated",
+    ///         Duration::from_secs(5),
+    ///     )
+    ///     .await?;
+    /// println!("Resource updated: {:?}", updated.params);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_notification<F>(
+        &mut self,
+        predicate: F,
+        timeout: Duration,
+    ) -> Result<JsonRpcNotification>
+    where
+        F: Fn(&JsonRpcNotification) -> bool,
+    {
+        tokio::time::timeout(timeout, async {
+            loop {
+                loop {
+                    match self.notification_backlog.try_recv() {
+                        Ok(note) if predicate(&note) => return Ok(note),
+                        Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {}
+                        Err(_) => break,
+                    }
+                }
+                // Late responses and id-less errors have no request to answer
+                self.protocol.read_next().await?;
+            }
+        })
+        .await
+        .map_err(|_| Error::timeout(format!("No matching notification within {timeout:?}")))?
+    }
+
+    /// Number of requests currently awaiting a response
+    ///
+    /// Requests leave the count when answered, timed out or cancelled, so a
+    /// value that keeps growing points at responses being lost.
+    pub fn pending_requests(&self) -> usize {
+        self.protocol.pending.pending_count()
+    }
+
+    /// Cancel every request still awaiting a response
+    ///
+    /// Sends `notifications/cancelled` for each pending request and clears
+    /// the pending-request map. Since requests borrow the client mutably,
+    /// the ones pending here were abandoned by dropping their futures; to
+    /// cancel requests while they are running, use a [`CancelHandle`].
+    /// Returns how many requests were cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a cancellation notification cannot be sent. The
+    /// map is cleared regardless.
+    pub async fn cancel_all(&mut self) -> Result<usize> {
+        let ids = self.protocol.pending.cancel_all(CANCEL_ALL_REASON);
+        for request_id in &ids {
+            self.protocol
+                .notify_cancelled(request_id.clone(), CANCEL_ALL_REASON)
+                .await?;
+        }
+        Ok(ids.len())
+    }
+
+    /// Tell the server the roots from [`ClientBuilder::with_roots`] changed
+    ///
+    /// Sends `notifications/roots/list_changed`; servers that care will send
+    /// `roots/list` again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized, has no roots
+    /// provider, or the notification cannot be sent.
+    pub async fn notify_roots_changed(&mut self) -> Result<()> {
+        if !self.initialized {
+            return Err(Error::bad_request("Client not initialized"));
+        }
+        if self.protocol.roots_provider.is_none() {
+            return Err(Error::configuration("No roots provider registered"));
+        }
+        self.protocol
+            .notify(methods::ROOTS_LIST_CHANGED, None)
+            .await
+    }
+
+    /// A handle for cancelling this client's requests from another task
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            pending: Arc::clone(&self.protocol.pending),
+        }
+    }
+
+    /// The most recent tool listing and its version
+    ///
+    /// Persist it and pass it to [`ClientBuilder::with_tool_manifest`] when
+    /// reconnecting: if the server still reports the same version, tool
+    /// listings are served from it without a `tools/list` round trip. `None`
+    /// until tools have been listed from a server that versions its listing.
+    pub const fn tool_manifest(&self) -> Option<&ToolManifest> {
+        self.tool_manifest.as_ref()
+    }
+
+    /// Details of the session negotiated by [`initialize`](Self::initialize)
+    ///
+    /// Returns `None` until the client has initialized. The server's
+    /// initialize `_meta` is in [`SessionInfo::meta`].
+    pub const fn session_info(&self) -> Option<&SessionInfo> {
+        self.session_info.as_ref()
+    }
+
+    /// Turn on the most efficient transport features the server shares
+    ///
+    /// Call after [`initialize`](Self::initialize). Compares what the
+    /// transport reports in its capabilities with what the server advertises
+    /// under the [`TRANSPORT_FEATURES_CAPABILITY`] experimental capability,
+    /// and enables the shared compression codec and binary wire format by
+    /// reconfiguring the transport. Batching, when the server does not
+    /// advertise it, is turned off so that
+    /// [`read_resources`](Self::read_resources) sends one request at a time.
+    /// The result is also recorded in [`SessionInfo::transport_features`].
+    ///
+    /// Anything not shared stays off, so a server that advertises nothing
+    /// keeps plain JSON messages. If the transport rejects the new
+    /// configuration, it is left as it was and every feature but batching is
+    /// reported off.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client is not initialized.
+    pub async fn negotiate_transport(&mut self) -> Result<TransportFeatures> {
+        let Some(session) = &self.session_info else {
+            return Err(Error::bad_request("Client not initialized"));
+        };
+        let advertised = session
+            .server_capabilities
+            .experimental
+            .as_ref()
+            .and_then(|experimental| experimental.get(TRANSPORT_FEATURES_CAPABILITY));
+        let mut features =
+            TransportFeatures::negotiate(self.protocol.transport.capabilities(), advertised);
+
+        if features.compression.is_some() || features.binary_format.is_some() {
+            let transport = &mut self.protocol.transport;
+            let mut config = TransportConfig {
+                transport_type: transport.transport_type(),
+                compression: features.compression.is_some(),
+                compression_algorithm: features.compression.clone(),
+                ..TransportConfig::default()
+            };
+            if let Some(format) = &features.binary_format {
+                config
+                    .custom
+                    .insert("binaryFormat".to_string(), format.clone().into());
+            }
+            if let Err(e) = transport.configure(config).await {
+                tracing::warn!(error = %e, "Transport rejected negotiated features");
+                features.compression = None;
+                features.binary_format = None;
+            }
+        }
+
+        if let Some(session) = &mut self.session_info {
+            session.transport_features = Some(features.clone());
+        }
+        Ok(features)
+    }
+
+    /// Find out what a server is and supports, then disconnect
+    ///
+    /// Connects the transport if it is not already connected, completes the
+    /// initialize handshake and closes the transport again, so the server
+    /// sees a session that ended rather than one left hanging. Intended for
+    /// health checks and catalogs of servers; the client is consumed, as its
+    /// session is over.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transport cannot connect, the handshake fails
+    /// or the transport fails to close. The transport is closed even when the
+    /// handshake fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use turbomcp_client::Client;
+    /// # use turbomcp_transport::stdio::StdioTransport;
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let probe = Client::new(StdioTransport::new()).probe().await?;
+    /// println!(
+    ///     "{} v{} speaks {}, tools: {}",
+    ///     probe.server_info.name,
+    ///     probe.server_info.version,
+    ///     probe.protocol_version,
+    ///     probe.supports_tools()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn probe(mut self) -> Result<ServerProbe> {
+        let started = Instant::now();
+        let transport = &mut self.protocol.transport;
+        if transport.state().await != TransportState::Connected {
+            transport
+                .connect()
+                .await
+                .map_err(|e| Error::transport(format!("Connect failed: {e}")))?;
+        }
+
+        let handshake = self.initialize().await;
+        let handshake_time = started.elapsed();
+        let closed = self
+            .protocol
+            .transport
+            .disconnect()
+            .await
+            .map_err(|e| Error::transport(format!("Disconnect failed: {e}")));
+        handshake?;
+        closed?;
+
+        let session = self
+            .session_info
+            .take()
+            .ok_or_else(|| Error::internal("Initialize did not record the session"))?;
+        Ok(ServerProbe {
+            server_info: session.server_info,
+            protocol_version: session.protocol_version,
+            supported_versions: session.supported_versions,
+            capabilities: session.server_capabilities,
+            instructions: session.instructions,
+            handshake_time,
+        })
+    }
+}
+
+impl Client<Box<dyn Transport>> {
+    /// Connect to the server at `uri` and initialize the client
+    ///
+    /// The scheme picks the transport: `stdio:` (optionally followed by a
+    /// command to run as a child process), `tcp://`, `unix://`, `ws://` or
+    /// `wss://`. Use [`ClientBuilder::connect_uri`] to configure the client
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error for an unknown scheme, a malformed
+    /// address or a transport whose feature is disabled, and a transport or
+    /// protocol error if connecting or initializing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use turbomcp_client::Client;
+    ///
+    /// # async fn example() -> turbomcp_core::Result<()> {
+    /// let mut client = Client::connect("stdio:./weather-server --units metric").await?;
+    /// let tools = client.list_tools().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect(uri: &str) -> Result<Self> {
+        ClientBuilder::new().connect_uri(uri).await
+    }
+}
+
+/// What a server reported about itself to [`Client::probe`]
+#[derive(Debug, Clone)]
+pub struct ServerProbe {
+    /// Name and version of the server
+    pub server_info: turbomcp_protocol::Implementation,
+
+    /// Protocol version the server answered with
+    pub protocol_version: String,
+
+    /// Every protocol version the server reported supporting, empty if it
+    /// did not report them
+    pub supported_versions: Vec<String>,
+
+    /// Capabilities the server advertised
+    pub capabilities: ServerCapabilities,
+
+    /// Usage instructions the server gave, if any
+    pub instructions: Option<String>,
+
+    /// Time taken to connect and complete the handshake
+    pub handshake_time: Duration,
+}
+
+impl ServerProbe {
+    /// Whether the server offers tools
+    #[must_use]
+    pub const fn supports_tools(&self) -> bool {
+        self.capabilities.tools.is_some()
+    }
+
+    /// Whether the server offers resources
+    #[must_use]
+    pub const fn supports_resources(&self) -> bool {
+        self.capabilities.resources.is_some()
+    }
+
+    /// Whether the server offers prompts
+    #[must_use]
+    pub const fn supports_prompts(&self) -> bool {
+        self.capabilities.prompts.is_some()
+    }
+}
+
+/// Reason given to the server for requests cancelled all at once
+pub const CANCEL_ALL_REASON: &str = "Client cancelled all outstanding requests";
+
+/// Cancels a client's outstanding requests from outside the client
+///
+/// Obtained from [`Client::cancel_handle`]. Requests borrow their client
+/// mutably, so this is how a shutdown or reset elsewhere in an application
+/// stops requests that are still running, including every request of a
+/// batch in flight.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use turbomcp_client::Client;
+/// # use turbomcp_transport::stdio::StdioTransport;
+/// # async fn example() -> turbomcp_core::Result<()> {
+/// let mut client = Client::new(StdioTransport::new());
+/// client.initialize().await?;
+///
+/// // Give up on everything still running after ten seconds
+/// let cancel = client.cancel_handle();
+/// tokio::spawn(async move {
+///     tokio::time::sleep(Duration::from_secs(10)).await;
+///     cancel.cancel_all();
+/// });
+/// client.list_tools().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    pending: Arc<CorrelationMap>,
+}
+
+impl CancelHandle {
+    /// Fail every request awaiting a response with a cancellation error
+    ///
+    /// Each request that is still running sends `notifications/cancelled`
+    /// for itself as it returns the [`Cancelled`](ErrorKind::Cancelled)
+    /// error. Returns how many requests were cancelled.
+    pub fn cancel_all(&self) -> usize {
+        self.pending.cancel_all(CANCEL_ALL_REASON).len()
+    }
+
+    /// Number of requests currently awaiting a response
+    pub fn pending_requests(&self) -> usize {
+        self.pending.pending_count()
+    }
+}
+
+/// A versioned tool listing that can be cached across connections
+///
+/// The version is the server's `manifestVersion` for the listing, an opaque
+/// digest that changes whenever any tool does.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ToolManifest {
+    /// Version the server reported for the listing
+    pub version: String,
+
+    /// The listed tools
+    pub tools: Vec<Tool>,
+}
+
+/// Session details recorded during initialization
+#[derive(Debug, Clone)]
+pub struct SessionInfo {
+    /// Protocol version the server answered with
+    pub protocol_version: String,
+
+    /// Information about the server
+    pub server_info: turbomcp_protocol::Implementation,
+
+    /// Capabilities supported by the server
+    pub server_capabilities: ServerCapabilities,
+
+    /// Every protocol version the server reported supporting, newest first
+    ///
+    /// Empty if the server does not report them, as servers predating the
+    /// `supportedVersions` handshake field do not.
+    pub supported_versions: Vec<String>,
+
+    /// Usage instructions the server gave, if any
+    pub instructions: Option<String>,
+
+    /// Metadata the server sent in its initialize `_meta`, empty if none
+    pub meta: HashMap<String, serde_json::Value>,
+
+    /// Transport features in effect, once
+    /// [`Client::negotiate_transport`] has run
+    pub transport_features: Option<TransportFeatures>,
+}
+
+/// Experimental server capability advertising optional transport features
+///
+/// The value is an object with a `compression` array of codec names, a
+/// `binaryFormats` array of wire format names and a `batching` flag; absent
+/// entries mean the feature is not supported.
+pub const TRANSPORT_FEATURES_CAPABILITY: &str = "transportFeatures";
+
+/// Transport features both the client and the server support
+///
+/// Agreed by [`Client::negotiate_transport`]. Features the two sides do not
+/// share are off.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransportFeatures {
+    /// Compression codec in use, the transport's most preferred one the
+    /// server also supports
+    pub compression: Option<String>,
+
+    /// Binary wire format in use instead of JSON text
+    pub binary_format: Option<String>,
+
+    /// Whether multiple requests are sent as one JSON-RPC batch
+    pub batching: bool,
+}
+
+impl TransportFeatures {
+    /// Pick the features `client` offers that the server's `advertised`
+    /// capability also lists
+    fn negotiate(client: &TransportCapabilities, advertised: Option<&serde_json::Value>) -> Self {
+        let server_list = |key: &str| -> Vec<&str> {
+            advertised
+                .and_then(|features| features.get(key))
+                .and_then(serde_json::Value::as_array)
+                .map(|names| names.iter().filter_map(serde_json::Value::as_str).collect())
+                .unwrap_or_default()
+        };
+        let shared = |offered: &[String], key: &str| {
+            let accepted = server_list(key);
+            offered
+                .iter()
+                .find(|name| accepted.iter().any(|a| a.eq_ignore_ascii_case(name)))
+                .cloned()
+        };
+
+        let compression = if client.supports_compression {
+            shared(&client.compression_algorithms, "compression")
+        } else {
+            None
+        };
+        let binary_formats: Vec<String> = client
+            .custom
+            .get("binaryFormats")
+            .and_then(|formats| serde_json::from_value(formats.clone()).ok())
+            .unwrap_or_default();
+        Self {
+            compression,
+            binary_format: shared(&binary_formats, "binaryFormats"),
+            batching: advertised
+                .and_then(|features| features.get("batching"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl SessionInfo {
+    /// Whether the server reported supporting `version`
+    ///
+    /// `None` if the server did not report its versions at all.
+    #[must_use]
+    pub fn supports_version(&self, version: &str) -> Option<bool> {
+        (!self.supported_versions.is_empty())
+            .then(|| self.supported_versions.iter().any(|v| v == version))
+    }
+}
+
+/// Result of client initialization
+///
+/// Contains information about the server and the negotiated capabilities
+/// after a successful initialization handshake.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use turbomcp_client::Client;
+/// # use turbomcp_transport::stdio::StdioTransport;
+/// # async fn example() -> turbomcp_core::Result<()> {
+/// let mut client = Client::new(StdioTransport::new());
+/// let result = client.initialize().await?;
+///
+/// println!("Server: {}", result.server_info.name);
+/// println!("Version: {}", result.server_info.version);
+/// if let Some(title) = result.server_info.title {
 ///     println!("Title: {}", title);
 /// }
 /// # Ok(())
@@ -560,6 +2448,67 @@ pub struct InitializeResult {
 
 // ServerCapabilities is now imported from turbomcp_protocol::types
 
+/// Result of a tool call that reached the tool
+///
+/// Returned by [`Client::call_tool_outcome`], which reports calls that never
+/// produced a result as errors instead.
+#[derive(Debug, Clone)]
+pub enum ToolOutcome {
+    /// The tool ran and succeeded
+    Success(CallToolResult),
+
+    /// The tool ran and reported a failure with `isError: true`
+    ToolError(CallToolResult),
+}
+
+impl ToolOutcome {
+    /// Whether the tool reported a failure
+    #[must_use]
+    pub const fn is_tool_error(&self) -> bool {
+        matches!(self, Self::ToolError(_))
+    }
+
+    /// The result the tool returned
+    #[must_use]
+    pub const fn result(&self) -> &CallToolResult {
+        match self {
+            Self::Success(result) | Self::ToolError(result) => result,
+        }
+    }
+
+    /// Take the result the tool returned
+    #[must_use]
+    pub fn into_result(self) -> CallToolResult {
+        match self {
+            Self::Success(result) | Self::ToolError(result) => result,
+        }
+    }
+
+    /// Text of the result's text blocks, one per line
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.result()
+            .content
+            .iter()
+            .filter_map(|content| match content {
+                Content::Text(text) => Some(text.text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl From<CallToolResult> for ToolOutcome {
+    fn from(result: CallToolResult) -> Self {
+        if result.is_error.unwrap_or(false) {
+            Self::ToolError(result)
+        } else {
+            Self::Success(result)
+        }
+    }
+}
+
 /// Builder for configuring and creating MCP clients
 ///
 /// Provides a fluent interface for configuring client options before creation.
@@ -582,6 +2531,40 @@ pub struct InitializeResult {
 #[derive(Debug, Default)]
 pub struct ClientBuilder {
     capabilities: ClientCapabilities,
+    wire_tracer: Option<WireTracer>,
+    interceptors: Vec<Arc<dyn MessageInterceptor>>,
+    unknown_notifications: UnknownNotificationPolicy,
+    duplicate_notifications: Option<(DuplicateNotificationPolicy, usize)>,
+    request_timeout: Option<Duration>,
+    max_pending_requests: Option<usize>,
+    session_meta: HashMap<String, serde_json::Value>,
+    transport_chain: Vec<Box<dyn Transport>>,
+    attempt_timeout: Option<Duration>,
+    output_schema_validation: bool,
+    tool_manifest: Option<ToolManifest>,
+    sampling_handler: Option<Arc<dyn SamplingHandler>>,
+    roots_provider: Option<Arc<dyn RootsProvider>>,
+}
+
+/// Default time allowed for each transport in a chain to connect and initialize
+pub const DEFAULT_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A client connected through the first working transport of a chain
+///
+/// Returned by [`ClientBuilder::connect`].
+#[derive(Debug)]
+pub struct ChainConnection {
+    /// Initialized client using the transport that succeeded
+    pub client: Client<Box<dyn Transport>>,
+
+    /// Position of the transport that succeeded in the chain
+    pub index: usize,
+
+    /// Type of the transport that succeeded
+    pub transport_type: TransportType,
+
+    /// The server's initialization response
+    pub initialize: InitializeResult,
 }
 
 impl ClientBuilder {
@@ -632,6 +2615,179 @@ impl ClientBuilder {
         self
     }
 
+    /// Answer the server's sampling requests with a handler
+    ///
+    /// Also enables sampling support, so the capability is advertised at
+    /// initialization. Disabling it again with
+    /// [`with_sampling`](Self::with_sampling) leaves the handler unused.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Called with each `sampling/createMessage` request
+    pub fn with_sampling_handler(mut self, handler: Arc<dyn SamplingHandler>) -> Self {
+        self.capabilities.sampling = true;
+        self.sampling_handler = Some(handler);
+        self
+    }
+
+    /// Answer the server's `roots/list` requests from a provider
+    ///
+    /// The `roots` capability is advertised at initialization, with
+    /// `listChanged` set; see [`Client::notify_roots_changed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Called with each `roots/list` request
+    pub fn with_roots(mut self, provider: Arc<dyn RootsProvider>) -> Self {
+        self.roots_provider = Some(provider);
+        self
+    }
+
+    /// Log every wire message with the given tracer
+    ///
+    /// Overrides the `TURBOMCP_WIRE_TRACE` environment configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracer` - The tracer to use, e.g. `WireTracer::enabled()`
+    pub fn with_wire_trace(mut self, tracer: WireTracer) -> Self {
+        self.wire_tracer = Some(tracer);
+        self
+    }
+
+    /// Add a raw message interceptor
+    ///
+    /// May be called repeatedly. Interceptors run outbound in the order they
+    /// were added and inbound in the reverse order.
+    ///
+    /// # Arguments
+    ///
+    /// * `interceptor` - The interceptor to append to the chain
+    pub fn with_interceptor(mut self, interceptor: impl MessageInterceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Set how long to wait for each response
+    ///
+    /// A request still unanswered after this long fails with a timeout error
+    /// and is evicted from the pending-request map, so a response arriving
+    /// later is dropped. Defaults to
+    /// [`DEFAULT_REQUEST_TIMEOUT`](correlation::DEFAULT_REQUEST_TIMEOUT),
+    /// 30 seconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait for a response
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Bound the number of requests that may await a response at once
+    ///
+    /// Requests beyond the limit fail immediately. Defaults to
+    /// [`DEFAULT_MAX_PENDING_REQUESTS`](correlation::DEFAULT_MAX_PENDING_REQUESTS).
+    ///
+    /// # Arguments
+    ///
+    /// * `max` - Maximum number of pending requests
+    pub fn with_max_pending_requests(mut self, max: usize) -> Self {
+        self.max_pending_requests = Some(max);
+        self
+    }
+
+    /// Add a key to the metadata sent in the initialize request's `_meta`
+    ///
+    /// Servers see it in their request context, so they can act on details
+    /// such as tenant or region. The serialized metadata must stay within
+    /// [`MAX_INITIALIZE_META_SIZE`] bytes, or initialization fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    /// * `value` - Any JSON value
+    pub fn with_session_meta(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.session_meta.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set what happens to server notifications the client does not recognize
+    ///
+    /// Defaults to [`UnknownNotificationPolicy::Ignore`]. Unknown
+    /// notifications are noticed while the client awaits a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Ignore, log, or hand unknown notifications to a handler
+    pub fn with_unknown_notification_policy(mut self, policy: UnknownNotificationPolicy) -> Self {
+        self.unknown_notifications = policy;
+        self
+    }
+
+    /// Set how notifications replayed under a known event id are handled
+    ///
+    /// Defaults to [`DuplicateNotificationPolicy::Drop`], remembering the
+    /// last [`DEFAULT_DEDUP_WINDOW`] ids. Only transports that report event
+    /// ids, such as a resumed SSE stream, are affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Drop, log, or deliver duplicates
+    /// * `window` - How many recent event ids to remember
+    pub fn with_duplicate_notifications(
+        mut self,
+        policy: DuplicateNotificationPolicy,
+        window: usize,
+    ) -> Self {
+        self.duplicate_notifications = Some((policy, window));
+        self
+    }
+
+    /// Check tool results against the tools' declared output schemas
+    ///
+    /// Off by default, since it may cost an extra `tools/list` round trip.
+    /// See [`validation`] for how schemas are cached and how mismatches are
+    /// reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to validate `structuredContent`
+    pub fn with_output_schema_validation(mut self, enabled: bool) -> Self {
+        self.output_schema_validation = enabled;
+        self
+    }
+
+    /// Start from a tool listing cached by an earlier connection
+    ///
+    /// Taken from [`Client::tool_manifest`]. It is used as long as the server
+    /// reports the same manifest version at initialization and has not
+    /// announced a tool list change.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - The cached listing
+    pub fn with_tool_manifest(mut self, manifest: ToolManifest) -> Self {
+        self.tool_manifest = Some(manifest);
+        self
+    }
+
+    /// Pass server notifications the client does not recognize to a handler
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - Called with each unrecognized notification
+    pub fn on_unknown_notification<F>(self, handler: F) -> Self
+    where
+        F: Fn(&JsonRpcNotification) + Send + Sync + 'static,
+    {
+        self.with_unknown_notification_policy(UnknownNotificationPolicy::handler(handler))
+    }
+
     /// Build a client with the configured options
     ///
     /// # Arguments
@@ -653,9 +2809,250 @@ impl ClientBuilder {
     ///     .build(StdioTransport::new());
     /// ```
     pub fn build<T: Transport>(self, transport: T) -> Client<T> {
-        Client::with_capabilities(transport, self.capabilities)
+        self.configure(transport)
+    }
+
+    /// Try transports in order until one connects and initializes
+    ///
+    /// Typical chains go from the most to the least preferred connection,
+    /// for example a Unix socket, then TCP, then a child process over stdio.
+    /// Each transport gets its own attempt timeout, so one that hangs does
+    /// not block the rest of the chain. Transports after the first working
+    /// one are dropped unused.
+    ///
+    /// # Arguments
+    ///
+    /// * `transports` - Transports to try, most preferred first
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use turbomcp_client::ClientBuilder;
+    /// use turbomcp_transport::Transport;
+    /// use turbomcp_transport::stdio::StdioTransport;
+    ///
+    /// # async fn example(
+    /// #     unix: Box<dyn Transport>,
+    /// #     tcp: Box<dyn Transport>,
+    /// # ) -> turbomcp_core::Result<()> {
+    /// let connection = ClientBuilder::new()
+    ///     .with_transport_chain(vec![unix, tcp, Box::new(StdioTransport::new())])
+    ///     .connect()
+    ///     .await?;
+    /// println!("Connected over {}", connection.transport_type);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_transport_chain(mut self, transports: Vec<Box<dyn Transport>>) -> Self {
+        self.transport_chain = transports;
+        self
+    }
+
+    /// Set how long each transport in a chain may take to connect and initialize
+    ///
+    /// Defaults to [`DEFAULT_ATTEMPT_TIMEOUT`].
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Limit for a single attempt
+    pub fn with_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// Connect through the transport chain
+    ///
+    /// Returns the initialized client together with the transport that
+    /// worked. See [`with_transport_chain`](Self::with_transport_chain).
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if no chain was set, or a transport error
+    /// listing each transport's failure if none of them worked.
+    pub async fn connect(mut self) -> Result<ChainConnection> {
+        let chain = std::mem::take(&mut self.transport_chain);
+        if chain.is_empty() {
+            return Err(Error::configuration("Transport chain is empty"));
+        }
+        let attempt_timeout = self.attempt_timeout.unwrap_or(DEFAULT_ATTEMPT_TIMEOUT);
+
+        let mut failures = Vec::with_capacity(chain.len());
+        for (index, mut transport) in chain.into_iter().enumerate() {
+            let transport_type = transport.transport_type();
+            let attempt = async {
+                transport
+                    .connect()
+                    .await
+                    .map_err(|e| Error::transport(format!("Connect failed: {e}")))?;
+                let mut client = self.configure(transport);
+                let initialize = client.initialize().await?;
+                Ok::<_, Box<Error>>((client, initialize))
+            };
+            match tokio::time::timeout(attempt_timeout, attempt).await {
+                Ok(Ok((client, initialize))) => {
+                    return Ok(ChainConnection {
+                        client,
+                        index,
+                        transport_type,
+                        initialize,
+                    });
+                }
+                Ok(Err(e)) => failures.push(format!("[{index}] {transport_type}: {e}")),
+                Err(_) => failures.push(format!(
+                    "[{index}] {transport_type}: timed out after {attempt_timeout:?}"
+                )),
+            }
+        }
+
+        Err(Error::transport(format!(
+            "No transport in the chain could connect: {}",
+            failures.join("; ")
+        )))
+    }
+
+    /// Connect to the server at `uri` and return the initialized client
+    ///
+    /// See [`Client::connect`] for the accepted addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if `uri` names no supported transport,
+    /// and a transport or protocol error if connecting or initializing fails.
+    pub async fn connect_uri(self, uri: &str) -> Result<Client<Box<dyn Transport>>> {
+        let endpoint = Endpoint::parse(uri)?;
+        let mut transport = endpoint.transport().await?;
+        if transport.state().await != TransportState::Connected {
+            transport
+                .connect()
+                .await
+                .map_err(|e| Error::transport(format!("Connect to {endpoint} failed: {e}")))?;
+        }
+        let mut client = self.configure(transport);
+        client.initialize().await?;
+        Ok(client)
+    }
+
+    /// Create a client with the configured options, leaving the builder reusable
+    fn configure<T: Transport>(&self, transport: T) -> Client<T> {
+        let mut client = Client::with_capabilities(transport, self.capabilities.clone());
+        if let Some(tracer) = &self.wire_tracer {
+            client.protocol.wire_tracer = tracer.clone();
+        }
+        client.protocol.interceptors = self.interceptors.clone();
+        client.protocol.unknown_notifications = self.unknown_notifications.clone();
+        if self.capabilities.sampling {
+            client.protocol.sampling_handler = self.sampling_handler.clone();
+        }
+        client.protocol.roots_provider = self.roots_provider.clone();
+        if let Some((policy, window)) = self.duplicate_notifications {
+            client.protocol.dedup = NotificationDeduplicator::new(policy, window);
+        }
+        if let Some(timeout) = self.request_timeout {
+            client.protocol.request_timeout = timeout;
+        }
+        if let Some(max) = self.max_pending_requests {
+            client.protocol.pending = Arc::new(CorrelationMap::new(max));
+        }
+        client.session_meta = self.session_meta.clone();
+        client.output_schemas = self
+            .output_schema_validation
+            .then(OutputSchemaCache::default);
+        client.tool_manifest = self.tool_manifest.clone();
+        client
     }
 }
 
+/// Render a prompt's message templates into final messages
+///
+/// Some servers return prompts whose text still contains `{name}`
+/// placeholders, leaving argument substitution to the client. This fills in
+/// every text message from `arguments`, using the same placeholder grammar as
+/// resource URI templates; non-text content is passed through unchanged.
+///
+/// # Errors
+///
+/// Returns a validation error naming the message and every placeholder that
+/// has no matching argument, or describing a malformed template.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use turbomcp_client::render_prompt;
+/// use turbomcp_protocol::types::{Content, GetPromptResult, PromptMessage, Role, TextContent};
+///
+/// let prompt = GetPromptResult {
+///     description: None,
+///     messages: vec![PromptMessage {
+///         role: Role::User,
+///         content: Content::Text(TextContent {
+///             text: "Review {file}".to_string(),
+///             annotations: None,
+///             meta: None,
+///         }),
+///     }],
+/// };
+/// let arguments = HashMap::from([("file".to_string(), "main.rs".to_string())]);
+///
+/// let messages = render_prompt(&prompt, &arguments).unwrap();
+/// # let Content::Text(text) = &messages[0].content else { unreachable!() };
+/// # assert_eq!(text.text, "Review main.rs");
+/// ```
+pub fn render_prompt(
+    prompt: &GetPromptResult,
+    arguments: &HashMap<String, String>,
+) -> Result<Vec<PromptMessage>> {
+    render_messages(prompt, |text| template::render(text, arguments))
+}
+
+/// Render a prompt whose arguments may refer to other arguments
+///
+/// Like [`render_prompt`], but placeholders inside argument values are
+/// expanded as well, so `{greeting}` with `greeting = "Hello {name}"` renders
+/// the name too. Expansion stops at `limits`; a cyclic or runaway set of
+/// arguments fails instead of hanging.
+///
+/// # Errors
+///
+/// Returns a validation error for a missing argument, a malformed template
+/// or argument value, or an expansion that exceeds `limits`.
+pub fn render_prompt_nested(
+    prompt: &GetPromptResult,
+    arguments: &HashMap<String, String>,
+    limits: &template::ExpansionLimits,
+) -> Result<Vec<PromptMessage>> {
+    render_messages(prompt, |text| {
+        template::render_nested(text, arguments, limits)
+    })
+}
+
+fn render_messages(
+    prompt: &GetPromptResult,
+    render: impl Fn(&str) -> std::result::Result<String, template::TemplateError>,
+) -> Result<Vec<PromptMessage>> {
+    prompt
+        .messages
+        .iter()
+        .enumerate()
+        .map(|(index, message)| {
+            let mut message = message.clone();
+            if let Content::Text(text) = &mut message.content {
+                text.text = render(&text.text).map_err(|e| {
+                    Error::validation(format!("Cannot render prompt message {index}: {e}"))
+                })?;
+            }
+            Ok(message)
+        })
+        .collect()
+}
+
 // Re-export types for public API
+pub use endpoint::Endpoint;
+pub use handle::ClientHandle;
+pub use turbomcp_protocol::notifications::DEFAULT_DEDUP_WINDOW;
 pub use turbomcp_protocol::types::ServerCapabilities as PublicServerCapabilities;
+pub use turbomcp_protocol::types::{Tool, ToolAnnotations};
+pub use turbomcp_protocol::{
+    DuplicateNotificationPolicy, OrderingStats, UnknownNotificationHandler,
+    UnknownNotificationPolicy,
+};
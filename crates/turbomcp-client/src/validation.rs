@@ -0,0 +1,116 @@
+//! Validation of tool results against their declared output schemas
+//!
+//! Tools may declare an `outputSchema` in `tools/list`; a server that does
+//! so must return `structuredContent` conforming to it. With
+//! [`ClientBuilder::with_output_schema_validation`](crate::ClientBuilder::with_output_schema_validation)
+//! the client checks this on every `tools/call`, caching schemas from the most
+//! recent tool listing, and fails calls whose results do not match.
+//!
+//! Most tools declare no output schema and return plain content. Their
+//! results, and those of tools the listing does not mention, pass unchecked
+//! rather than failing, so turning validation on only affects tools that
+//! make a structured promise.
+
+use std::collections::HashMap;
+
+use jsonschema::{Draft, JSONSchema};
+use serde_json::Value;
+use turbomcp_core::Error;
+use turbomcp_protocol::types::Tool;
+
+/// Operation recorded on errors for results that violate their output schema
+pub const OUTPUT_SCHEMA_VIOLATION: &str = "validate_tool_output";
+
+/// Whether `error` reports a tool result that violates its output schema
+///
+/// Such errors have kind [`Protocol`](turbomcp_core::ErrorKind::Protocol),
+/// since the server broke its own contract, and carry the tool name and the
+/// individual violations in their context metadata under `tool` and
+/// `violations`.
+#[must_use]
+pub fn is_output_schema_violation(error: &Error) -> bool {
+    error.context.operation.as_deref() == Some(OUTPUT_SCHEMA_VIOLATION)
+}
+
+/// Output schemas from the most recent tool listing
+#[derive(Debug, Default)]
+pub(crate) struct OutputSchemaCache {
+    /// Schema for each listed tool, `None` for tools that declare none
+    schemas: HashMap<String, Option<Value>>,
+}
+
+impl OutputSchemaCache {
+    /// Replace the cached schemas with those of `tools`
+    pub(crate) fn update(&mut self, tools: &[Tool]) {
+        self.schemas = tools
+            .iter()
+            .map(|tool| {
+                let schema = tool
+                    .output_schema
+                    .as_ref()
+                    .and_then(|schema| serde_json::to_value(schema).ok());
+                (tool.name.clone(), schema)
+            })
+            .collect();
+    }
+
+    /// Whether `tool` appeared in the cached listing
+    pub(crate) fn contains(&self, tool: &str) -> bool {
+        self.schemas.contains_key(tool)
+    }
+
+    /// Check a raw `tools/call` result against `tool`'s output schema
+    ///
+    /// Tools without a schema, tools missing from the listing and error
+    /// results are not checked.
+    pub(crate) fn validate(&self, tool: &str, result: &Value) -> turbomcp_core::Result<()> {
+        let schema = match self.schemas.get(tool) {
+            Some(Some(schema)) => schema,
+            Some(None) => {
+                tracing::debug!(tool, "Tool declares no output schema; result not validated");
+                return Ok(());
+            }
+            None => {
+                tracing::debug!(
+                    tool,
+                    "Tool is not in the tool listing; result not validated"
+                );
+                return Ok(());
+            }
+        };
+        if result.get("isError").and_then(Value::as_bool) == Some(true) {
+            return Ok(());
+        }
+
+        let violations = match result.get("structuredContent") {
+            None => vec!["structuredContent is missing".to_string()],
+            Some(structured) => {
+                let compiled = JSONSchema::options()
+                    .with_draft(Draft::Draft7)
+                    .compile(schema)
+                    .map_err(|e| {
+                        Error::protocol(format!("Tool '{tool}' has an invalid output schema: {e}"))
+                            .with_operation(OUTPUT_SCHEMA_VIOLATION)
+                            .with_context("tool", tool)
+                    })?;
+                match compiled.validate(structured) {
+                    Ok(()) => return Ok(()),
+                    Err(errors) => errors
+                        .map(|e| match e.instance_path.to_string() {
+                            path if path.is_empty() => e.to_string(),
+                            path => format!("{path}: {e}"),
+                        })
+                        .collect(),
+                }
+            }
+        };
+
+        Err(Error::protocol(format!(
+            "Tool '{tool}' returned a result that violates its output schema: {}",
+            violations.join("; ")
+        ))
+        .with_operation(OUTPUT_SCHEMA_VIOLATION)
+        .with_context("tool", tool)
+        .with_context("violations", violations))
+    }
+}
@@ -0,0 +1,119 @@
+//! Tests for choosing a transport from a server address
+
+use std::path::PathBuf;
+
+use turbomcp_client::{Client, Endpoint};
+use turbomcp_core::ErrorKind;
+use turbomcp_transport::TransportType;
+
+fn parse(uri: &str) -> Endpoint {
+    Endpoint::parse(uri).unwrap_or_else(|e| panic!("'{uri}' should parse: {e}"))
+}
+
+#[test]
+fn test_stdio_addresses() {
+    assert_eq!(parse("stdio:"), Endpoint::Stdio);
+    assert_eq!(parse("STDIO://"), Endpoint::Stdio);
+    assert_eq!(
+        parse("stdio:./weather-server --units metric"),
+        Endpoint::Command {
+            program: "./weather-server".to_string(),
+            args: vec!["--units".to_string(), "metric".to_string()],
+        }
+    );
+    assert_eq!(
+        parse("stdio:///usr/local/bin/mcp-server"),
+        Endpoint::Command {
+            program: "/usr/local/bin/mcp-server".to_string(),
+            args: Vec::new(),
+        }
+    );
+}
+
+#[test]
+fn test_tcp_addresses() {
+    assert_eq!(
+        parse("tcp://localhost:3000"),
+        Endpoint::Tcp {
+            host: "localhost".to_string(),
+            port: 3000
+        }
+    );
+    assert_eq!(
+        parse("tcp://[::1]:8080/"),
+        Endpoint::Tcp {
+            host: "::1".to_string(),
+            port: 8080
+        }
+    );
+    assert_eq!(parse("tcp://[::1]:8080").to_string(), "tcp://[::1]:8080");
+
+    for missing in ["tcp://localhost", "tcp://localhost:http", "tcp://:3000"] {
+        let error = Endpoint::parse(missing).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Configuration, "{missing}");
+    }
+}
+
+#[test]
+fn test_unix_addresses() {
+    assert_eq!(
+        parse("unix:///run/mcp.sock"),
+        Endpoint::Unix(PathBuf::from("/run/mcp.sock"))
+    );
+    assert_eq!(
+        parse("unix:mcp.sock"),
+        Endpoint::Unix(PathBuf::from("mcp.sock"))
+    );
+    assert!(Endpoint::parse("unix://").is_err());
+}
+
+#[test]
+fn test_websocket_addresses() {
+    assert_eq!(
+        parse("ws://localhost:9000/mcp"),
+        Endpoint::WebSocket("ws://localhost:9000/mcp".to_string())
+    );
+    assert_eq!(
+        parse("wss://example.com/mcp"),
+        Endpoint::WebSocket("wss://example.com/mcp".to_string())
+    );
+}
+
+#[test]
+fn test_unknown_schemes_are_rejected() {
+    for uri in ["http://localhost:8080/mcp", "localhost:3000", "./server"] {
+        let error = Endpoint::parse(uri).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Configuration, "{uri}");
+        assert!(error.message.contains("tcp://"), "{}", error.message);
+    }
+}
+
+#[tokio::test]
+async fn test_endpoints_build_their_transport() {
+    let transport = parse("stdio:").transport().await.unwrap();
+    assert_eq!(transport.transport_type(), TransportType::Stdio);
+    let transport = parse("stdio:mcp-server").transport().await.unwrap();
+    assert_eq!(transport.transport_type(), TransportType::ChildProcess);
+}
+
+#[cfg(feature = "tcp")]
+#[tokio::test]
+async fn test_tcp_endpoint_builds_tcp_transport() {
+    let transport = parse("tcp://127.0.0.1:3000").transport().await.unwrap();
+    assert_eq!(transport.transport_type(), TransportType::Tcp);
+}
+
+#[cfg(not(feature = "tcp"))]
+#[tokio::test]
+async fn test_disabled_transport_names_its_feature() {
+    let error = Client::connect("tcp://127.0.0.1:3000").await.unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Configuration);
+    assert!(error.message.contains("'tcp' feature"), "{}", error.message);
+}
+
+#[tokio::test]
+async fn test_connect_rejects_unknown_scheme_before_connecting() {
+    let error = Client::connect("gopher://example.com").await.unwrap_err();
+    assert_eq!(error.kind, ErrorKind::Configuration);
+    assert!(error.message.contains("'gopher'"), "{}", error.message);
+}
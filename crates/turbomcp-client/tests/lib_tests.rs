@@ -1,13 +1,20 @@
 //! Comprehensive tests for turbomcp-client lib.rs
 
 use async_trait::async_trait;
+use turbomcp_client::validation::is_output_schema_violation;
 use turbomcp_client::{
-    Client, ClientBuilder, ClientCapabilities, InitializeResult,
-    PublicServerCapabilities as ServerCapabilities,
+    Client, ClientBuilder, ClientCapabilities, InitializeResult, MessageInterceptor,
+    PublicServerCapabilities as ServerCapabilities, RootsProvider, SamplingHandler,
+    TRANSPORT_FEATURES_CAPABILITY, ToolOutcome, TransportFeatures,
+};
+use turbomcp_protocol::jsonrpc::JsonRpcNotification;
+use turbomcp_protocol::types::{
+    Content, CreateMessageRequest, CreateMessageResult, MAX_INITIALIZE_META_SIZE, Role, Root,
+    TextContent,
 };
 use turbomcp_transport::core::{
-    Transport, TransportCapabilities, TransportMessage, TransportMetrics, TransportResult,
-    TransportState, TransportType,
+    EVENT_ID_EXTENSION, Transport, TransportCapabilities, TransportConfig, TransportMessage,
+    TransportMetrics, TransportResult, TransportState, TransportType,
 };
 
 // Mock transport that implements the Transport trait
@@ -229,6 +236,18 @@ fn test_client_builder_multiple_instances() {
     assert!(format!("{builder2:?}").contains("ClientBuilder"));
 }
 
+#[test]
+fn test_client_builder_with_wire_trace() {
+    use turbomcp_transport::wire_trace::WireTracer;
+
+    let client = ClientBuilder::new()
+        .with_wire_trace(WireTracer::enabled().with_redacted_fields(["token"]))
+        .build(MockTransport::new());
+    let debug_str = format!("{client:?}");
+    assert!(debug_str.contains("enabled: true"));
+    assert!(debug_str.contains("token"));
+}
+
 // Test client with mock transport
 #[test]
 fn test_client_with_mock_transport() {
@@ -489,3 +508,2169 @@ fn test_client_boundary_conditions() {
     assert_eq!(result.server_info.name, long_name);
     assert_eq!(result.server_info.version, long_version);
 }
+
+// Content of the scripted server's only resource
+const SCRIPTED_LOG: &str = "line 0\nline 1\nline 2\nline 3\n";
+
+// Base64 image data in the scripted server's prompt
+const SCRIPTED_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJ";
+
+// Transport that answers requests from a canned server
+#[derive(Debug)]
+struct ScriptedServerTransport {
+    capabilities: TransportCapabilities,
+    responses: std::collections::VecDeque<TransportMessage>,
+    tools: Option<serde_json::Value>,
+    // Listing pages by method and the cursor that requests them
+    pages: std::collections::HashMap<(String, Option<String>), serde_json::Value>,
+    supported_versions: Option<serde_json::Value>,
+    transport_features: Option<serde_json::Value>,
+    reject_batches: bool,
+    batches_received: usize,
+    // Every message the client sent, as JSON
+    requests: Vec<serde_json::Value>,
+    // Messages sent after the next request arrives, ahead of its response
+    interjections: Vec<serde_json::Value>,
+    // Responses held back until this many requests are waiting
+    hold_responses: usize,
+    held: Vec<TransportMessage>,
+    // Empty receives before each message, as a transport waiting on its peer
+    idle_polls: usize,
+    polls_left: usize,
+    state: TransportState,
+    // Methods the client sent, with connects and disconnects in between
+    events: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+impl ScriptedServerTransport {
+    fn new() -> Self {
+        Self {
+            capabilities: TransportCapabilities::default(),
+            responses: std::collections::VecDeque::new(),
+            tools: None,
+            pages: std::collections::HashMap::new(),
+            supported_versions: None,
+            transport_features: None,
+            reject_batches: false,
+            batches_received: 0,
+            requests: Vec::new(),
+            interjections: Vec::new(),
+            hold_responses: 1,
+            held: Vec::new(),
+            idle_polls: 0,
+            polls_left: 0,
+            state: TransportState::Connected,
+            events: std::sync::Arc::default(),
+        }
+    }
+
+    // Start out disconnected, as a transport not yet opened
+    fn disconnected(mut self) -> Self {
+        self.state = TransportState::Disconnected;
+        self
+    }
+
+    // Answer batches with a single error, as a server without batch support
+    fn rejecting_batches(mut self) -> Self {
+        self.reject_batches = true;
+        self
+    }
+
+    // Report nothing waiting this many times before each message
+    fn idling(mut self, polls: usize) -> Self {
+        self.idle_polls = polls;
+        self.polls_left = polls;
+        self
+    }
+
+    // Replace the scripted tool listing
+    fn with_tools(mut self, tools: serde_json::Value) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    // Answer `method` with `page` when asked with `cursor`
+    fn with_page(mut self, method: &str, cursor: Option<&str>, page: serde_json::Value) -> Self {
+        self.pages
+            .insert((method.to_string(), cursor.map(str::to_string)), page);
+        self
+    }
+
+    // Report supported protocol versions in the initialize result
+    fn with_supported_versions(mut self, versions: &[&str]) -> Self {
+        self.supported_versions = Some(serde_json::json!(versions));
+        self
+    }
+
+    // Advertise transport features in the initialize result
+    fn with_transport_features(mut self, features: serde_json::Value) -> Self {
+        self.transport_features = Some(features);
+        self
+    }
+
+    // Offer compression codecs on the client's side of the transport
+    fn with_compression(mut self, codecs: &[&str]) -> Self {
+        self.capabilities.supports_compression = true;
+        self.capabilities.compression_algorithms = codecs.iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    // Send `message` once the next request arrives, before answering it
+    fn interjecting(mut self, message: serde_json::Value) -> Self {
+        self.interjections.push(message);
+        self
+    }
+
+    // Hold responses until `count` requests are waiting, then answer the
+    // newest first
+    fn answering_in_reverse(mut self, count: usize) -> Self {
+        self.hold_responses = count;
+        self
+    }
+
+    // Queue a server notification ahead of the next response
+    fn with_notification(self, method: &str) -> Self {
+        self.with_notification_params(method, serde_json::Value::Null)
+    }
+
+    // Queue a notification delivered under a stream event id
+    fn with_event(mut self, event_id: &str, method: &str, params: serde_json::Value) -> Self {
+        self = self.with_notification_params(method, params);
+        let message = self.responses.back_mut().unwrap();
+        message
+            .metadata
+            .extensions
+            .insert(EVENT_ID_EXTENSION.to_string(), event_id.into());
+        self
+    }
+
+    fn with_notification_params(mut self, method: &str, params: serde_json::Value) -> Self {
+        let mut notification = serde_json::json!({"jsonrpc": "2.0", "method": method});
+        if !params.is_null() {
+            notification["params"] = params;
+        }
+        self.with_server_message(notification)
+    }
+
+    // Queue any message from the server ahead of the next response
+    fn with_server_message(mut self, message: serde_json::Value) -> Self {
+        self.responses.push_back(TransportMessage::new(
+            turbomcp_core::MessageId::from("notification"),
+            bytes::Bytes::from(serde_json::to_vec(&message).unwrap()),
+        ));
+        self
+    }
+
+    fn result_for(&self, method: &str, params: &serde_json::Value) -> serde_json::Value {
+        let cursor = params["cursor"].as_str().map(str::to_string);
+        if let Some(page) = self.pages.get(&(method.to_string(), cursor)) {
+            return page.clone();
+        }
+        match method {
+            "tools/list" if self.tools.is_some() => serde_json::json!({"tools": self.tools}),
+            "initialize" => {
+                let mut result = serde_json::json!({
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": {"tools": {}},
+                    "serverInfo": {"name": "scripted", "version": "1.0.0"}
+                });
+                if let Some(versions) = &self.supported_versions {
+                    result["supportedVersions"] = versions.clone();
+                }
+                if let Some(features) = &self.transport_features {
+                    result["capabilities"]["experimental"] =
+                        serde_json::json!({TRANSPORT_FEATURES_CAPABILITY: features});
+                }
+                result
+            }
+            "tools/list" => serde_json::json!({
+                "tools": [
+                    {
+                        "name": "read_file",
+                        "inputSchema": {"type": "object"},
+                        "annotations": {"readOnlyHint": true, "idempotentHint": true}
+                    },
+                    {
+                        "name": "delete_file",
+                        "inputSchema": {"type": "object"},
+                        "annotations": {"readOnlyHint": false, "destructiveHint": true}
+                    },
+                    {"name": "unannotated", "inputSchema": {"type": "object"}}
+                ]
+            }),
+            "resources/read" if params["uri"] == "file:///logo.png" => serde_json::json!({
+                "contents": [
+                    {"uri": "file:///logo.png", "mimeType": "image/png", "blob": SCRIPTED_PNG},
+                    {"uri": "file:///logo.png", "mimeType": "text/plain", "text": "Company logo"}
+                ]
+            }),
+            "resources/read" => {
+                let offset = params["offset"].as_u64().unwrap_or(0) as usize;
+                let end = params["length"]
+                    .as_u64()
+                    .map_or(SCRIPTED_LOG.len(), |length| offset + length as usize)
+                    .min(SCRIPTED_LOG.len());
+                serde_json::json!({
+                    "contents": [{"uri": params["uri"], "text": &SCRIPTED_LOG[offset..end]}],
+                    "range": {
+                        "offset": offset,
+                        "length": end - offset,
+                        "totalSize": SCRIPTED_LOG.len()
+                    }
+                })
+            }
+            "tools/call" if params["name"] == "say_hello" => serde_json::json!({
+                "content": [{"type": "text", "text": "Hello!"}]
+            }),
+            "tools/call" if params["name"] == "render_chart" => serde_json::json!({
+                "content": [
+                    {"type": "image", "data": SCRIPTED_PNG, "mimeType": "image/png"},
+                    {"type": "text", "text": "Sales by quarter"}
+                ],
+                "structuredContent": {"quarters": [12, 15, 9, 20]}
+            }),
+            "tools/call" if params["name"] == "divide" => serde_json::json!({
+                "content": [{"type": "text", "text": "Cannot divide by zero"}],
+                "isError": true
+            }),
+            "prompts/list" => serde_json::json!({
+                "prompts": [
+                    {
+                        "name": "describe",
+                        "description": "Describe a screenshot",
+                        "arguments": [
+                            {"name": "subject", "description": "What to compare", "required": true}
+                        ]
+                    },
+                    {"name": "greet"}
+                ]
+            }),
+            "prompts/get" => serde_json::json!({
+                "description": "Describe a screenshot",
+                "messages": [
+                    {
+                        "role": "user",
+                        "content": {"type": "image", "data": SCRIPTED_PNG, "mimeType": "image/png"}
+                    },
+                    {
+                        "role": "user",
+                        "content": {
+                            "type": "resource",
+                            "resource": {"uri": "file:///notes.md", "text": "# Notes"}
+                        }
+                    },
+                    {
+                        "role": "user",
+                        "content": {"type": "text", "text": "Compare {subject} with the notes."}
+                    }
+                ]
+            }),
+            // Every other tool reports the same structured output
+            "tools/call" => serde_json::json!({
+                "content": [{"type": "text", "text": "It is warm"}],
+                "structuredContent": {"temperature": "warm"}
+            }),
+            _ => serde_json::Value::Null,
+        }
+    }
+
+    // The response to one request, or None for a notification
+    fn respond_to(&self, request: &serde_json::Value) -> Option<serde_json::Value> {
+        let id = request.get("id")?;
+        // Responses the client sends to the server's own requests
+        let method = request["method"].as_str()?;
+        let params = &request["params"];
+        let error = match method {
+            "tools/call" if params["name"] == "missing" => Some((-32602, "Unknown tool: missing")),
+            "resources/read" if params["uri"] == "file:///missing" => {
+                Some((-32002, "Resource not found"))
+            }
+            _ => None,
+        };
+        if let Some((code, message)) = error {
+            return Some(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {"code": code, "message": message}
+            }));
+        }
+
+        let mut result = self.result_for(method, params);
+        // Reflect initialize metadata, as a server that shares it would
+        if let Some(meta) = request.pointer("/params/_meta") {
+            result["_meta"] = meta.clone();
+        }
+        Some(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": result
+        }))
+    }
+}
+
+#[async_trait]
+impl Transport for ScriptedServerTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        self.state.clone()
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        self.state = TransportState::Connected;
+        self.events.lock().unwrap().push("(connect)".to_string());
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        self.state = TransportState::Disconnected;
+        self.events.lock().unwrap().push("(disconnect)".to_string());
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        let request: serde_json::Value = serde_json::from_slice(&message.payload).unwrap();
+        if let Some(method) = request["method"].as_str() {
+            self.events.lock().unwrap().push(method.to_string());
+        }
+        self.requests.push(request.clone());
+        let response = match &request {
+            serde_json::Value::Array(_) if self.reject_batches => Some(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": {"code": -32600, "message": "Batches are not supported"}
+            })),
+            serde_json::Value::Array(batch) => {
+                self.batches_received += 1;
+                let responses: Vec<_> = batch.iter().filter_map(|r| self.respond_to(r)).collect();
+                (!responses.is_empty()).then_some(serde_json::Value::Array(responses))
+            }
+            _ => self.respond_to(&request),
+        };
+        if let Some(response) = response {
+            // Echo request headers back, as a signing peer would
+            let mut reply = TransportMessage::new(
+                message.id,
+                bytes::Bytes::from(serde_json::to_vec(&response).unwrap()),
+            );
+            reply.metadata.headers = message.metadata.headers;
+            self.held.push(reply);
+            if self.held.len() >= self.hold_responses {
+                for message in self.interjections.drain(..) {
+                    self.responses.push_back(TransportMessage::new(
+                        turbomcp_core::MessageId::from("interjection"),
+                        bytes::Bytes::from(serde_json::to_vec(&message).unwrap()),
+                    ));
+                }
+                self.responses.extend(self.held.drain(..).rev());
+                self.hold_responses = 1;
+            }
+        }
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        if self.polls_left > 0 {
+            self.polls_left -= 1;
+            return Ok(None);
+        }
+        self.polls_left = self.idle_polls;
+        Ok(self.responses.pop_front())
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+
+    async fn configure(&mut self, config: TransportConfig) -> TransportResult<()> {
+        let codec = config.compression_algorithm.unwrap_or_default();
+        self.events.lock().unwrap().push(format!(
+            "(configure compression={} {codec})",
+            config.compression
+        ));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_requests_wait_while_the_transport_is_idle() {
+    let mut client = Client::new(ScriptedServerTransport::new().idling(3));
+    client.initialize().await.unwrap();
+
+    assert_eq!(client.list_tools().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_list_tools_filtered_by_read_only_annotation() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+
+    let read_only = client
+        .list_tools_filtered(|tool| {
+            tool.annotations
+                .as_ref()
+                .and_then(|a| a.read_only_hint())
+                .unwrap_or(false)
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(read_only.len(), 1);
+    assert_eq!(read_only[0].name, "read_file");
+    let annotations = read_only[0].annotations.as_ref().unwrap();
+    assert_eq!(annotations.idempotent_hint(), Some(true));
+    assert_eq!(annotations.destructive_hint(), None);
+
+    // Names-only listing is unchanged
+    assert_eq!(client.list_tools().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_read_resource_range_returns_slice() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    assert!(
+        client
+            .read_resource_range("log://app", 0, None)
+            .await
+            .is_err()
+    );
+    client.initialize().await.unwrap();
+
+    let result = client
+        .read_resource_range("log://app", 7, Some(14))
+        .await
+        .unwrap();
+
+    let contents = serde_json::to_value(&result.contents).unwrap();
+    assert_eq!(contents[0]["text"], "line 1\nline 2\n");
+    let range = result.range.unwrap();
+    assert_eq!((range.offset, range.length), (7, 14));
+    assert_eq!(range.total_size, Some(SCRIPTED_LOG.len() as u64));
+}
+
+#[tokio::test]
+async fn test_read_resources_batches_reads_and_isolates_failures() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+
+    let results = client
+        .read_resources(&["file:///a.log", "file:///missing", "file:///b.log"])
+        .await
+        .unwrap();
+
+    assert_eq!(client.transport_mut().batches_received, 1);
+    assert_eq!(results.len(), 3);
+    for (result, uri) in [
+        (&results[0], "file:///a.log"),
+        (&results[2], "file:///b.log"),
+    ] {
+        let contents = serde_json::to_value(&result.as_ref().unwrap().contents).unwrap();
+        assert_eq!(contents[0]["uri"], uri);
+        assert_eq!(contents[0]["text"], SCRIPTED_LOG);
+    }
+    let err = results[1].as_ref().unwrap_err();
+    assert!(err.to_string().contains("Resource not found"), "{err}");
+    assert_eq!(client.pending_requests(), 0);
+}
+
+#[tokio::test]
+async fn test_read_resources_falls_back_when_batches_are_rejected() {
+    let mut client = Client::new(ScriptedServerTransport::new().rejecting_batches());
+    client.initialize().await.unwrap();
+
+    let results = client
+        .read_resources(&["file:///a.log", "file:///missing"])
+        .await
+        .unwrap();
+
+    assert_eq!(client.transport_mut().batches_received, 0);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(client.read_resources(&[]).await.unwrap().is_empty());
+}
+
+fn resource_updated(uri: &str) -> impl Fn(&JsonRpcNotification) -> bool + '_ {
+    move |note| {
+        note.method == "notifications/resources/updated"
+            && note.params.as_ref().and_then(|p| p.get("uri")) == Some(&serde_json::json!(uri))
+    }
+}
+
+#[tokio::test]
+async fn test_apply_resource_update_appends_or_rereads() {
+    use turbomcp_protocol::types::{ResourceContent, ResourceUpdatedNotification};
+    use turbomcp_protocol::{ResourcePatch, ResourcePatchError};
+
+    fn text(result: &turbomcp_protocol::types::ReadResourceResult) -> &str {
+        match &result.contents[0] {
+            ResourceContent::Text(content) => &content.text,
+            ResourceContent::Blob(_) => panic!("expected text contents"),
+        }
+    }
+
+    let transport = ScriptedServerTransport::new().with_notification_params(
+        "notifications/resources/updated",
+        serde_json::json!({
+            "uri": "file:///app.log",
+            "patch": {"type": "append", "baseLength": SCRIPTED_LOG.len(), "text": "line 4\n"}
+        }),
+    );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+    let mut log = client
+        .read_resources(&["file:///app.log"])
+        .await
+        .unwrap()
+        .remove(0)
+        .unwrap();
+
+    // The patch fits the cached copy, so it is applied without a read
+    let note = client
+        .wait_for_notification(
+            resource_updated("file:///app.log"),
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    let update: ResourceUpdatedNotification = serde_json::from_value(note.params.unwrap()).unwrap();
+    assert!(
+        client
+            .apply_resource_update(&mut log, &update)
+            .await
+            .unwrap()
+    );
+    let expected = format!("{SCRIPTED_LOG}line 4\n");
+    assert_eq!(text(&log), expected);
+    assert_eq!(log.range.unwrap().total_size, Some(expected.len() as u64));
+
+    // Replaying the same patch finds the copy already past it
+    let mut patched = log.contents[0].clone();
+    assert_eq!(
+        update.patch.as_ref().unwrap().apply(&mut patched),
+        Err(ResourcePatchError::BaseMismatch {
+            expected: SCRIPTED_LOG.len() as u64,
+            actual: expected.len() as u64,
+        })
+    );
+    assert!(
+        !client
+            .apply_resource_update(&mut log, &update)
+            .await
+            .unwrap()
+    );
+    assert_eq!(text(&log), SCRIPTED_LOG);
+
+    // Updates without a patch are full reads
+    let plain = ResourceUpdatedNotification::new("file:///app.log");
+    assert!(
+        !client
+            .apply_resource_update(&mut log, &plain)
+            .await
+            .unwrap()
+    );
+    assert_eq!(text(&log), SCRIPTED_LOG);
+
+    let patch = ResourcePatch::Append {
+        base_length: SCRIPTED_LOG.len() as u64,
+        text: "line 4\n".to_string(),
+    };
+    assert_eq!(
+        serde_json::to_value(plain.with_patch(patch)).unwrap(),
+        serde_json::json!({
+            "uri": "file:///app.log",
+            "patch": {"type": "append", "baseLength": SCRIPTED_LOG.len(), "text": "line 4\n"}
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_wait_for_notification_sees_notifications_from_requests() {
+    let transport = ScriptedServerTransport::new()
+        .with_notification("notifications/tools/list_changed")
+        .with_notification_params(
+            "notifications/resources/updated",
+            serde_json::json!({"uri": "file:///a"}),
+        )
+        .with_notification_params(
+            "notifications/resources/updated",
+            serde_json::json!({"uri": "file:///b"}),
+        );
+    let mut client = Client::new(transport);
+    let mut observer = client.subscribe_notifications();
+
+    // All three notifications arrive while initialize awaits its response
+    client.initialize().await.unwrap();
+    let updated = client
+        .wait_for_notification(
+            resource_updated("file:///b"),
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.params.unwrap()["uri"], "file:///b");
+
+    // Waiting does not take notifications away from other subscribers
+    let mut observed = Vec::new();
+    while let Ok(note) = observer.try_recv() {
+        observed.push(note.method);
+    }
+    assert_eq!(observed.len(), 3);
+    assert_eq!(observed[0], "notifications/tools/list_changed");
+}
+
+#[tokio::test]
+async fn test_sequenced_notifications_are_delivered_in_order() {
+    let progress = |seq: u64| {
+        serde_json::json!({
+            "progressToken": "t",
+            "progress": seq,
+            "_meta": {"turbomcp/seq": seq}
+        })
+    };
+    // The transport hands them over as 1, 0, 2
+    let transport = ScriptedServerTransport::new()
+        .with_notification_params("notifications/progress", progress(1))
+        .with_notification_params("notifications/progress", progress(0))
+        .with_notification_params("notifications/progress", progress(2));
+    let mut client = Client::new(transport);
+    let mut observer = client.subscribe_notifications();
+
+    client
+        .wait_for_notification(
+            |note| note.params.as_ref().is_some_and(|p| p["progress"] == 2),
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+
+    let received: Vec<_> = std::iter::from_fn(|| observer.try_recv().ok())
+        .map(|note| note.params.unwrap()["progress"].as_u64().unwrap())
+        .collect();
+    assert_eq!(received, vec![0, 1, 2]);
+    assert_eq!(client.notification_ordering_stats().reordered, 1);
+}
+
+// Replayed events 1-3 after a resume, overlapping live events 2-4
+fn resumed_stream() -> ScriptedServerTransport {
+    let updated = |n: u32| serde_json::json!({"uri": format!("file:///log#{n}")});
+    let mut transport = ScriptedServerTransport::new();
+    for n in [1, 2, 3, 2, 3, 4] {
+        transport = transport.with_event(
+            &format!("event-{n}"),
+            "notifications/resources/updated",
+            updated(n),
+        );
+    }
+    transport.with_notification_params("notifications/resources/updated", updated(5))
+}
+
+async fn received_updates(client: &mut Client<ScriptedServerTransport>) -> Vec<String> {
+    let mut observer = client.subscribe_notifications();
+    client
+        .wait_for_notification(
+            |note| {
+                note.params
+                    .as_ref()
+                    .is_some_and(|p| p["uri"] == "file:///log#5")
+            },
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    std::iter::from_fn(|| observer.try_recv().ok())
+        .map(|note| note.params.unwrap()["uri"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_replayed_notifications_are_deduplicated() {
+    let mut client = Client::new(resumed_stream());
+    assert_eq!(
+        received_updates(&mut client).await,
+        [
+            "file:///log#1",
+            "file:///log#2",
+            "file:///log#3",
+            "file:///log#4",
+            "file:///log#5"
+        ]
+    );
+    assert_eq!(client.duplicate_notifications(), 2);
+    assert_eq!(client.last_event_id(), Some("event-4"));
+}
+
+#[tokio::test]
+async fn test_duplicate_policy_and_window_are_configurable() {
+    use turbomcp_client::DuplicateNotificationPolicy;
+
+    // Delivering duplicates passes every replayed event through
+    let mut client = ClientBuilder::new()
+        .with_duplicate_notifications(DuplicateNotificationPolicy::Deliver, 16)
+        .build(resumed_stream());
+    assert_eq!(received_updates(&mut client).await.len(), 7);
+    assert_eq!(client.duplicate_notifications(), 2);
+
+    // A one-id window has forgotten event-2 by the time it is replayed
+    let mut client = ClientBuilder::new()
+        .with_duplicate_notifications(DuplicateNotificationPolicy::Drop, 1)
+        .build(resumed_stream());
+    assert_eq!(received_updates(&mut client).await.len(), 7);
+    assert_eq!(client.duplicate_notifications(), 0);
+}
+
+#[tokio::test]
+async fn test_raw_messages_bypass_json_rpc_layer() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    assert_eq!(client.transport().transport_type(), TransportType::Stdio);
+    assert!(client.receive_raw().await.unwrap().is_none());
+
+    let request = br#"{"jsonrpc":"2.0","id":"raw-1","method":"tools/list"}"#;
+    client
+        .send_raw(TransportMessage::new(
+            turbomcp_core::MessageId::from("raw-1"),
+            bytes::Bytes::from_static(request),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(client.pending_requests(), 0);
+
+    let reply = client.receive_raw().await.unwrap().unwrap();
+    let reply: serde_json::Value = serde_json::from_slice(&reply.payload).unwrap();
+    assert_eq!(reply["id"], "raw-1");
+    assert_eq!(reply["result"]["tools"][0]["name"], "read_file");
+
+    // With the raw exchange complete, the typed API carries on as normal
+    client.initialize().await.unwrap();
+    assert_eq!(client.list_tools().await.unwrap()[0], "read_file");
+}
+
+#[tokio::test]
+async fn test_wait_for_notification_reads_transport() {
+    let transport = ScriptedServerTransport::new().with_notification_params(
+        "notifications/resources/updated",
+        serde_json::json!({"uri": "file:///a"}),
+    );
+    let mut client = Client::new(transport);
+
+    let updated = client
+        .wait_for_notification(
+            resource_updated("file:///a"),
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.method, "notifications/resources/updated");
+
+    let missing = client
+        .wait_for_notification(
+            resource_updated("file:///z"),
+            std::time::Duration::from_millis(50),
+        )
+        .await;
+    assert!(
+        missing
+            .unwrap_err()
+            .to_string()
+            .contains("No matching notification")
+    );
+}
+
+#[tokio::test]
+async fn test_list_tools_filtered_requires_initialization() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    assert!(client.list_tools_filtered(|_| true).await.is_err());
+}
+
+// Interceptor that tags outbound messages with a header and strips it inbound
+#[derive(Debug)]
+struct HeaderInterceptor {
+    name: &'static str,
+    log: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl MessageInterceptor for HeaderInterceptor {
+    async fn on_outbound(
+        &self,
+        mut message: TransportMessage,
+    ) -> turbomcp_core::Result<TransportMessage> {
+        message
+            .metadata
+            .headers
+            .insert(format!("x-{}", self.name), "1".to_string());
+        self.log.lock().unwrap().push(format!("out:{}", self.name));
+        Ok(message)
+    }
+
+    async fn on_inbound(
+        &self,
+        mut message: TransportMessage,
+    ) -> turbomcp_core::Result<TransportMessage> {
+        let mut seen: Vec<_> = message.metadata.headers.keys().cloned().collect();
+        seen.sort();
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("in:{}:{}", self.name, seen.join(",")));
+        message.metadata.headers.remove(&format!("x-{}", self.name));
+        Ok(message)
+    }
+}
+
+#[tokio::test]
+async fn test_interceptors_add_and_strip_headers_in_order() {
+    let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut client = ClientBuilder::new()
+        .with_interceptor(HeaderInterceptor {
+            name: "a",
+            log: std::sync::Arc::clone(&log),
+        })
+        .with_interceptor(HeaderInterceptor {
+            name: "b",
+            log: std::sync::Arc::clone(&log),
+        })
+        .build(ScriptedServerTransport::new());
+
+    client.raw_request("tools/list", None).await.unwrap();
+
+    // Outbound runs in registration order, inbound in reverse, and each
+    // interceptor strips only its own header
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec!["out:a", "out:b", "in:b:x-a,x-b", "in:a:x-a"]
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_notification_reaches_catch_all_handler() {
+    let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut client = ClientBuilder::new()
+        .on_unknown_notification({
+            let seen = std::sync::Arc::clone(&seen);
+            move |notification| seen.lock().unwrap().push(notification.method.clone())
+        })
+        .build(
+            ScriptedServerTransport::new()
+                .with_notification("notifications/tools/list_changed")
+                .with_notification("x/future/notification"),
+        );
+
+    // Notifications interleaved before the response do not break the request
+    let result = client.raw_request("tools/list", None).await.unwrap();
+    assert_eq!(result["tools"].as_array().unwrap().len(), 3);
+
+    // Only the unrecognized method reaches the handler
+    assert_eq!(*seen.lock().unwrap(), vec!["x/future/notification"]);
+}
+
+#[tokio::test]
+async fn test_response_found_among_interleaved_messages() {
+    let transport = ScriptedServerTransport::new()
+        .interjecting(serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/message",
+            "params": {"level": "info", "data": "listing tools"}
+        }))
+        .interjecting(serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "999",
+            "result": {"tools": []}
+        }));
+    let mut client = Client::new(transport);
+    let mut notifications = client.subscribe_notifications();
+
+    // The request is answered after a notification and a response to a
+    // request that is not pending, both sent once the request was received
+    let result = client.raw_request("tools/list", None).await.unwrap();
+    assert_eq!(result["tools"].as_array().unwrap().len(), 3);
+    assert_eq!(client.pending_requests(), 0);
+
+    let note = notifications.try_recv().unwrap();
+    assert_eq!(note.method, "notifications/message");
+    assert_eq!(note.params.unwrap()["data"], "listing tools");
+}
+
+// Sampling handler that answers with the last message's text reversed
+#[derive(Debug)]
+struct ReversingSampler;
+
+#[async_trait]
+impl SamplingHandler for ReversingSampler {
+    async fn create_message(
+        &self,
+        request: CreateMessageRequest,
+    ) -> turbomcp_core::Result<CreateMessageResult> {
+        let Some(Content::Text(text)) = request.messages.last().map(|m| &m.content) else {
+            return Err(turbomcp_core::Error::validation("Expected a text message"));
+        };
+        Ok(CreateMessageResult {
+            role: Role::Assistant,
+            content: Content::Text(TextContent {
+                text: text.text.chars().rev().collect(),
+                annotations: None,
+                meta: None,
+            }),
+            model: Some("reverser-1".to_string()),
+            stop_reason: Some("endTurn".to_string()),
+        })
+    }
+}
+
+fn sampling_request(text: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "s1",
+        "method": "sampling/createMessage",
+        "params": {
+            "messages": [{"role": "user", "content": {"type": "text", "text": text}}],
+            "maxTokens": 16
+        }
+    })
+}
+
+// The client's answer to the server's request with the given id
+fn response_to<'a>(transport: &'a ScriptedServerTransport, id: &str) -> &'a serde_json::Value {
+    transport
+        .requests
+        .iter()
+        .find(|message| message["id"] == id && message.get("method").is_none())
+        .expect("the server's request should be answered")
+}
+
+#[tokio::test]
+async fn test_sampling_request_answered_by_handler() {
+    let transport = ScriptedServerTransport::new().interjecting(sampling_request("stressed"));
+    let mut client = ClientBuilder::new()
+        .with_sampling_handler(std::sync::Arc::new(ReversingSampler))
+        .build(transport);
+
+    // The server asks for a completion while initialization is in flight
+    client.initialize().await.unwrap();
+
+    let transport = client.transport();
+    let initialize = &transport.requests[0];
+    assert_eq!(
+        initialize["params"]["capabilities"]["sampling"],
+        serde_json::json!({})
+    );
+    assert_eq!(
+        response_to(transport, "s1"),
+        &serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "s1",
+            "result": {
+                "role": "assistant",
+                "content": {"type": "text", "text": "desserts"},
+                "model": "reverser-1",
+                "stopReason": "endTurn"
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_sampling_handler_error_is_sent_to_server() {
+    let mut request = sampling_request("unused");
+    request["params"]["messages"][0]["content"] =
+        serde_json::json!({"type": "image", "data": "AAAA", "mimeType": "image/png"});
+    let transport = ScriptedServerTransport::new().interjecting(request);
+    let mut client = ClientBuilder::new()
+        .with_sampling_handler(std::sync::Arc::new(ReversingSampler))
+        .build(transport);
+    client.initialize().await.unwrap();
+
+    let response = response_to(client.transport(), "s1");
+    assert!(response.get("result").is_none());
+    assert_eq!(response["error"]["code"], -32600);
+    assert_eq!(response["error"]["message"], "Expected a text message");
+}
+
+#[tokio::test]
+async fn test_sampling_request_without_handler_is_method_not_found() {
+    for builder in [
+        ClientBuilder::new(),
+        ClientBuilder::new()
+            .with_sampling_handler(std::sync::Arc::new(ReversingSampler))
+            .with_sampling(false),
+    ] {
+        let transport = ScriptedServerTransport::new().interjecting(sampling_request("hello"));
+        let mut client = builder.build(transport);
+        client.initialize().await.unwrap();
+
+        let transport = client.transport();
+        assert!(
+            transport.requests[0]["params"]["capabilities"]
+                .get("sampling")
+                .is_none()
+        );
+        let response = response_to(transport, "s1");
+        assert!(response.get("result").is_none());
+        assert_eq!(response["error"]["code"], -32601);
+        assert_eq!(
+            response["error"]["message"],
+            "Method not found: sampling/createMessage"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_sampling_request_answered_while_waiting_for_notifications() {
+    let transport = ScriptedServerTransport::new()
+        .with_server_message(sampling_request("live"))
+        .with_notification("notifications/tools/list_changed");
+    let mut client = ClientBuilder::new()
+        .with_sampling_handler(std::sync::Arc::new(ReversingSampler))
+        .build(transport);
+
+    client
+        .wait_for_notification(
+            |note| note.method == "notifications/tools/list_changed",
+            std::time::Duration::from_secs(1),
+        )
+        .await
+        .unwrap();
+    let response = response_to(client.transport(), "s1");
+    assert_eq!(response["result"]["content"]["text"], "evil");
+}
+
+// Roots provider serving a fixed list
+#[derive(Debug)]
+struct FixedRoots(Vec<(&'static str, Option<&'static str>)>);
+
+#[async_trait]
+impl RootsProvider for FixedRoots {
+    async fn list_roots(&self) -> turbomcp_core::Result<Vec<Root>> {
+        Ok(self
+            .0
+            .iter()
+            .map(|(uri, name)| Root {
+                uri: uri.to_string(),
+                name: name.map(str::to_string),
+            })
+            .collect())
+    }
+}
+
+fn roots_request() -> serde_json::Value {
+    serde_json::json!({"jsonrpc": "2.0", "id": "r1", "method": "roots/list"})
+}
+
+#[tokio::test]
+async fn test_roots_request_answered_by_provider() {
+    let transport = ScriptedServerTransport::new().interjecting(roots_request());
+    let mut client = ClientBuilder::new()
+        .with_roots(std::sync::Arc::new(FixedRoots(vec![
+            ("file:///home/user/project", Some("project")),
+            ("file:///tmp/scratch", None),
+        ])))
+        .build(transport);
+    client.initialize().await.unwrap();
+
+    let transport = client.transport();
+    assert_eq!(
+        transport.requests[0]["params"]["capabilities"]["roots"],
+        serde_json::json!({"listChanged": true})
+    );
+    assert_eq!(
+        response_to(transport, "r1"),
+        &serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "r1",
+            "result": {
+                "roots": [
+                    {"uri": "file:///home/user/project", "name": "project"},
+                    {"uri": "file:///tmp/scratch"}
+                ]
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_roots_outside_file_scheme_are_rejected() {
+    let transport = ScriptedServerTransport::new().interjecting(roots_request());
+    let mut client = ClientBuilder::new()
+        .with_roots(std::sync::Arc::new(FixedRoots(vec![
+            ("file:///home/user/project", None),
+            ("https://example.com/repo", None),
+        ])))
+        .build(transport);
+    client.initialize().await.unwrap();
+
+    let response = response_to(client.transport(), "r1");
+    assert!(response.get("result").is_none());
+    assert_eq!(response["error"]["code"], -32603);
+    assert_eq!(
+        response["error"]["message"],
+        "Root URI must use the file:// scheme: https://example.com/repo"
+    );
+}
+
+#[tokio::test]
+async fn test_roots_request_without_provider_is_method_not_found() {
+    let transport = ScriptedServerTransport::new().interjecting(roots_request());
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+
+    let transport = client.transport();
+    assert!(
+        transport.requests[0]["params"]["capabilities"]
+            .get("roots")
+            .is_none()
+    );
+    assert_eq!(response_to(transport, "r1")["error"]["code"], -32601);
+}
+
+#[tokio::test]
+async fn test_notify_roots_changed() {
+    let mut client = ClientBuilder::new()
+        .with_roots(std::sync::Arc::new(FixedRoots(vec![])))
+        .build(ScriptedServerTransport::new());
+    assert!(client.notify_roots_changed().await.is_err());
+
+    client.initialize().await.unwrap();
+    client.notify_roots_changed().await.unwrap();
+    assert_eq!(
+        client.transport().requests.last().unwrap(),
+        &serde_json::json!({"jsonrpc": "2.0", "method": "notifications/roots/list_changed"})
+    );
+
+    // Without a provider the server was never told roots can change
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    assert!(client.notify_roots_changed().await.is_err());
+}
+
+fn tool_named(name: &str) -> serde_json::Value {
+    serde_json::json!({"name": name, "inputSchema": {"type": "object"}})
+}
+
+#[tokio::test]
+async fn test_list_tools_follows_every_page() {
+    let transport = ScriptedServerTransport::new()
+        .with_page(
+            "tools/list",
+            None,
+            serde_json::json!({"tools": [tool_named("first")], "nextCursor": "page-2"}),
+        )
+        .with_page(
+            "tools/list",
+            Some("page-2"),
+            serde_json::json!({"tools": [tool_named("second"), tool_named("third")]}),
+        );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+
+    assert_eq!(
+        client.list_tools().await.unwrap(),
+        ["first", "second", "third"]
+    );
+    let cursors: Vec<_> = client
+        .transport()
+        .requests
+        .iter()
+        .filter(|request| request["method"] == "tools/list")
+        .map(|request| request["params"]["cursor"].clone())
+        .collect();
+    assert_eq!(cursors, [serde_json::Value::Null, "page-2".into()]);
+}
+
+#[tokio::test]
+async fn test_list_tools_page_returns_one_page() {
+    let transport = ScriptedServerTransport::new()
+        .with_page(
+            "tools/list",
+            None,
+            serde_json::json!({"tools": [tool_named("first")], "nextCursor": "page-2"}),
+        )
+        .with_page(
+            "tools/list",
+            Some("page-2"),
+            serde_json::json!({"tools": [tool_named("second")]}),
+        );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+
+    let first = client.list_tools_page(None).await.unwrap();
+    assert_eq!(first.tools.len(), 1);
+    assert_eq!(first.next_cursor.as_deref(), Some("page-2"));
+
+    let second = client.list_tools_page(first.next_cursor).await.unwrap();
+    assert_eq!(second.tools[0].name, "second");
+    assert!(second.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_list_resources_follows_every_page() {
+    let transport = ScriptedServerTransport::new()
+        .with_page(
+            "resources/list",
+            None,
+            serde_json::json!({
+                "resources": [{"uri": "file:///a.txt", "name": "a"}],
+                "nextCursor": "more"
+            }),
+        )
+        .with_page(
+            "resources/list",
+            Some("more"),
+            serde_json::json!({"resources": [{"uri": "file:///b.txt", "name": "b"}]}),
+        );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+
+    assert_eq!(
+        client.list_resources().await.unwrap(),
+        ["file:///a.txt", "file:///b.txt"]
+    );
+}
+
+#[tokio::test]
+async fn test_repeated_cursor_stops_listing() {
+    let transport = ScriptedServerTransport::new()
+        .with_page(
+            "tools/list",
+            None,
+            serde_json::json!({"tools": [tool_named("first")], "nextCursor": "again"}),
+        )
+        .with_page(
+            "tools/list",
+            Some("again"),
+            serde_json::json!({"tools": [tool_named("second")], "nextCursor": "again"}),
+        )
+        .with_page(
+            "resources/list",
+            None,
+            serde_json::json!({"resources": [], "nextCursor": "again"}),
+        )
+        .with_page(
+            "resources/list",
+            Some("again"),
+            serde_json::json!({"resources": [], "nextCursor": "again"}),
+        );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+
+    let error = client.list_tools().await.unwrap_err();
+    assert!(error.message.contains("repeated tools/list cursor 'again'"));
+    let error = client.list_resources().await.unwrap_err();
+    assert!(error.message.contains("repeated resources/list cursor"));
+
+    // Each listing asked for the repeated cursor's page once
+    let listings = client
+        .transport()
+        .requests
+        .iter()
+        .filter(|request| {
+            request["method"]
+                .as_str()
+                .is_some_and(|m| m.ends_with("/list"))
+        })
+        .count();
+    assert_eq!(listings, 4);
+}
+
+#[tokio::test]
+async fn test_paginated_tool_manifest_is_cached() {
+    let transport = ScriptedServerTransport::new()
+        .with_page(
+            "tools/list",
+            None,
+            serde_json::json!({
+                "tools": [tool_named("first")],
+                "nextCursor": "page-2",
+                "manifestVersion": "v1"
+            }),
+        )
+        .with_page(
+            "tools/list",
+            Some("page-2"),
+            serde_json::json!({"tools": [tool_named("second")], "manifestVersion": "v1"}),
+        );
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+    client.list_tools().await.unwrap();
+
+    let manifest = client.tool_manifest().unwrap();
+    assert_eq!(manifest.version, "v1");
+    assert_eq!(manifest.tools.len(), 2);
+}
+
+#[tokio::test]
+async fn test_handle_correlates_concurrent_requests_answered_out_of_order() {
+    let transport = ScriptedServerTransport::new()
+        .answering_in_reverse(2)
+        .interjecting(serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/message",
+            "params": {"level": "info", "data": "listing"}
+        }));
+    let (handle, reader) = Client::new(transport).spawn_reader();
+    let mut notifications = handle.subscribe_notifications();
+
+    // Neither request is answered until both are in flight, and then the
+    // second is answered first, after a notification
+    let (tools, prompts) = tokio::join!(
+        handle.request("tools/list", None),
+        handle.request("prompts/list", None),
+    );
+    assert_eq!(tools.unwrap()["tools"].as_array().unwrap().len(), 3);
+    assert_eq!(prompts.unwrap()["prompts"][0]["name"], "describe");
+    assert_eq!(handle.pending_requests(), 0);
+
+    let note = notifications.recv().await.unwrap();
+    assert_eq!(note.method, "notifications/message");
+    assert_eq!(note.params.unwrap()["data"], "listing");
+
+    drop(handle);
+    reader.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn test_handle_clones_share_one_connection() {
+    let (handle, reader) = Client::new(ScriptedServerTransport::new()).spawn_reader();
+
+    let calls: Vec<_> = ["read_file", "say_hello", "divide"]
+        .into_iter()
+        .map(|name| {
+            let handle = handle.clone();
+            tokio::spawn(async move {
+                let params = serde_json::json!({"name": name, "arguments": {}});
+                handle.request("tools/call", Some(params)).await
+            })
+        })
+        .collect();
+    let mut texts = Vec::new();
+    for call in calls {
+        let result = call.await.unwrap().unwrap();
+        texts.push(result["content"][0]["text"].as_str().unwrap().to_string());
+    }
+    assert_eq!(texts, ["It is warm", "Hello!", "Cannot divide by zero"]);
+
+    drop(handle);
+    reader.await.unwrap().unwrap();
+}
+
+// Transport to a server that never answers on its own: by default nothing
+// is answered, but the handshake or each request once the next one arrives
+// can be, and every request sent is recorded
+#[derive(Debug, Default)]
+struct UnresponsiveTransport {
+    capabilities: TransportCapabilities,
+    answers_initialize: bool,
+    answers_late: bool,
+    previous_id: Option<serde_json::Value>,
+    replies: std::collections::VecDeque<TransportMessage>,
+    sent: std::sync::Arc<std::sync::Mutex<Vec<serde_json::Value>>>,
+}
+
+impl UnresponsiveTransport {
+    /// Complete the handshake, recording what is sent in `sent`
+    fn after_handshake(sent: std::sync::Arc<std::sync::Mutex<Vec<serde_json::Value>>>) -> Self {
+        Self {
+            answers_initialize: true,
+            sent,
+            ..Self::default()
+        }
+    }
+
+    /// Answer each request only once the next one arrives
+    fn lagging() -> Self {
+        Self {
+            answers_late: true,
+            ..Self::default()
+        }
+    }
+
+    fn reply(&mut self, reply: &serde_json::Value) {
+        self.replies.push_back(TransportMessage::new(
+            turbomcp_core::MessageId::from("reply"),
+            bytes::Bytes::from(serde_json::to_vec(reply).unwrap()),
+        ));
+    }
+}
+
+#[async_trait]
+impl Transport for UnresponsiveTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Connected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        let request: serde_json::Value = serde_json::from_slice(&message.payload).unwrap();
+        if self.answers_late
+            && let Some(id) = self.previous_id.replace(request["id"].clone())
+        {
+            self.reply(&serde_json::json!({"jsonrpc": "2.0", "id": id, "result": {}}));
+        }
+        if self.answers_initialize && request["method"] == "initialize" {
+            self.reply(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": {
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": {},
+                    "serverInfo": {"name": "stalled", "version": "1.0.0"}
+                }
+            }));
+        }
+        self.sent.lock().unwrap().push(request);
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        match self.replies.pop_front() {
+            Some(reply) => Ok(Some(reply)),
+            None => std::future::pending().await,
+        }
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
+
+#[tokio::test]
+async fn test_unanswered_request_times_out_and_is_evicted() {
+    let mut client = ClientBuilder::new()
+        .with_timeout(std::time::Duration::from_millis(50))
+        .build(UnresponsiveTransport::default());
+
+    for _ in 0..3 {
+        let err = client.raw_request("x/never", None).await.unwrap_err();
+        assert_eq!(err.kind, turbomcp_core::ErrorKind::Timeout);
+        assert_eq!(client.pending_requests(), 0);
+    }
+}
+
+#[tokio::test]
+async fn test_timeout_fires_at_configured_duration() {
+    let timeout = std::time::Duration::from_millis(200);
+    let mut client = ClientBuilder::new()
+        .with_timeout(timeout)
+        .build(UnresponsiveTransport::lagging());
+
+    let started = std::time::Instant::now();
+    let err = client.raw_request("x/slow", None).await.unwrap_err();
+    let elapsed = started.elapsed();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::Timeout);
+    assert!(elapsed >= timeout, "fired early, after {elapsed:?}");
+    assert!(elapsed < timeout * 5, "fired late, after {elapsed:?}");
+
+    // The first request's answer arrives during the second, and is not
+    // mistaken for the second's
+    let err = client.raw_request("x/slow", None).await.unwrap_err();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::Timeout);
+    assert_eq!(client.pending_requests(), 0);
+}
+
+#[tokio::test]
+async fn test_timeout_fires_while_the_transport_reports_nothing_waiting() {
+    let timeout = std::time::Duration::from_millis(100);
+    // Its receive returns `Ok(None)` every time, as idle stdio and TCP do
+    let mut client = ClientBuilder::new()
+        .with_timeout(timeout)
+        .build(MockTransport::new());
+
+    let started = std::time::Instant::now();
+    let err = client.raw_request("x/slow", None).await.unwrap_err();
+    let elapsed = started.elapsed();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::Timeout);
+    assert!(elapsed >= timeout, "fired early, after {elapsed:?}");
+    assert!(elapsed < timeout * 5, "fired late, after {elapsed:?}");
+    assert_eq!(client.pending_requests(), 0);
+}
+
+#[tokio::test]
+async fn test_correlation_map_is_bounded_and_evicts_expired() {
+    use turbomcp_client::correlation::CorrelationMap;
+    use turbomcp_core::MessageId;
+
+    let map = CorrelationMap::new(2);
+    let timeout = std::time::Duration::from_millis(20);
+    let mut first = map.register(MessageId::from("1"), timeout).unwrap();
+    let _second = map.register(MessageId::from("2"), timeout).unwrap();
+    assert!(map.register(MessageId::from("3"), timeout).is_err());
+    assert_eq!(map.pending_count(), 2);
+
+    tokio::time::sleep(std::time::Duration::from_millis(40)).await;
+    assert_eq!(map.evict_expired(), 2);
+    assert_eq!(map.pending_count(), 0);
+
+    // Evicted waiters see their channel close, and late responses are dropped
+    assert!(first.try_recv().is_err());
+    let late = turbomcp_protocol::jsonrpc::JsonRpcResponse::success(
+        serde_json::json!({}),
+        MessageId::from("1"),
+    );
+    assert!(!map.complete(late));
+
+    // Capacity frees up once expired entries are gone
+    assert!(map.register(MessageId::from("3"), timeout).is_ok());
+}
+
+// Ids of the requests the client told the server it cancelled
+fn cancelled_ids(sent: &std::sync::Mutex<Vec<serde_json::Value>>) -> Vec<serde_json::Value> {
+    sent.lock()
+        .unwrap()
+        .iter()
+        .filter(|message| message["method"] == "notifications/cancelled")
+        .map(|message| message["params"]["requestId"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_cancel_handle_fails_requests_in_flight() {
+    let sent = std::sync::Arc::default();
+    let mut client = Client::new(UnresponsiveTransport::after_handshake(
+        std::sync::Arc::clone(&sent),
+    ));
+    client.initialize().await.unwrap();
+    let cancel = client.cancel_handle();
+
+    // A batch puts several requests in flight at once
+    let reads = tokio::spawn(async move {
+        let uris = ["file:///a.log", "file:///b.log", "file:///c.log"];
+        let results = client.read_resources(&uris).await;
+        (client, results)
+    });
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        while cancel.pending_requests() < 3 {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("reads should be in flight");
+
+    assert_eq!(cancel.cancel_all(), 3);
+    let (client, results) = reads.await.unwrap();
+    let results = results.unwrap();
+    assert_eq!(results.len(), 3);
+    for result in results {
+        assert_eq!(
+            result.unwrap_err().kind,
+            turbomcp_core::ErrorKind::Cancelled
+        );
+    }
+    assert_eq!(client.pending_requests(), 0);
+
+    // The server hears about every request of the batch
+    let batch_ids: Vec<_> = sent.lock().unwrap()[2]
+        .as_array()
+        .expect("reads should go out as one batch")
+        .iter()
+        .map(|request| request["id"].clone())
+        .collect();
+    assert_eq!(cancelled_ids(&sent), batch_ids);
+}
+
+#[tokio::test]
+async fn test_cancel_all_notifies_server_of_abandoned_requests() {
+    let sent = std::sync::Arc::default();
+    let mut client = Client::new(UnresponsiveTransport::after_handshake(
+        std::sync::Arc::clone(&sent),
+    ));
+    client.initialize().await.unwrap();
+
+    // Dropping a request future leaves its entry behind
+    let abandoned = tokio::time::timeout(
+        std::time::Duration::from_millis(20),
+        client.raw_request("x/slow", None),
+    )
+    .await;
+    assert!(abandoned.is_err());
+    assert_eq!(client.pending_requests(), 1);
+
+    assert_eq!(client.cancel_all().await.unwrap(), 1);
+    assert_eq!(client.pending_requests(), 0);
+    let request_id = sent.lock().unwrap()[2]["id"].clone();
+    assert_eq!(cancelled_ids(&sent), vec![request_id]);
+}
+
+#[test]
+fn test_render_prompt_substitutes_arguments_in_every_message() {
+    use turbomcp_client::render_prompt;
+    use turbomcp_protocol::types::{GetPromptResult, ImageContent, PromptMessage};
+
+    fn text(role: Role, text: &str) -> PromptMessage {
+        PromptMessage {
+            role,
+            content: Content::Text(TextContent {
+                text: text.to_string(),
+                annotations: None,
+                meta: None,
+            }),
+        }
+    }
+
+    let prompt = GetPromptResult {
+        description: Some("Code review".to_string()),
+        messages: vec![
+            text(Role::User, "Review {file} for {focus} issues."),
+            text(Role::Assistant, "Reviewing {file}..."),
+            PromptMessage {
+                role: Role::User,
+                content: Content::Image(ImageContent {
+                    data: "aGVsbG8=".to_string(),
+                    mime_type: "image/png".to_string(),
+                    annotations: None,
+                    meta: None,
+                }),
+            },
+        ],
+    };
+    let arguments = std::collections::HashMap::from([
+        ("file".to_string(), "main.rs".to_string()),
+        ("focus".to_string(), "security".to_string()),
+    ]);
+
+    let messages = render_prompt(&prompt, &arguments).unwrap();
+    let texts: Vec<_> = messages
+        .iter()
+        .filter_map(|m| match &m.content {
+            Content::Text(t) => Some(t.text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        texts,
+        vec![
+            "Review main.rs for security issues.",
+            "Reviewing main.rs..."
+        ]
+    );
+    assert_eq!(messages.len(), 3);
+
+    let partial = std::collections::HashMap::from([("file".to_string(), "main.rs".to_string())]);
+    let err = render_prompt(&prompt, &partial).unwrap_err();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::Validation);
+    assert!(err.to_string().contains("message 0"));
+    assert!(err.to_string().contains("focus"));
+}
+
+#[tokio::test]
+async fn test_get_prompt_keeps_image_and_resource_content() {
+    use turbomcp_client::render_prompt;
+    use turbomcp_protocol::types::ResourceContent;
+
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    let prompt = client.get_prompt("describe", None).await.unwrap();
+    assert_eq!(prompt.messages.len(), 3);
+
+    let Content::Image(image) = &prompt.messages[0].content else {
+        panic!("expected image content, got {:?}", prompt.messages[0]);
+    };
+    assert_eq!(image.data, SCRIPTED_PNG);
+    assert_eq!(image.mime_type, "image/png");
+    let Content::Resource(embedded) = &prompt.messages[1].content else {
+        panic!("expected embedded resource, got {:?}", prompt.messages[1]);
+    };
+    let ResourceContent::Text(notes) = &embedded.resource else {
+        panic!("expected text resource, got {:?}", embedded.resource);
+    };
+    assert_eq!(notes.uri, "file:///notes.md");
+
+    // Rendering fills in the text and passes the other blocks through
+    let arguments = std::collections::HashMap::from([("subject".to_string(), "it".to_string())]);
+    let rendered = render_prompt(&prompt, &arguments).unwrap();
+    let wire = serde_json::to_value(&rendered).unwrap();
+    assert_eq!(
+        wire[0]["content"],
+        serde_json::json!({"type": "image", "data": SCRIPTED_PNG, "mimeType": "image/png"})
+    );
+    assert_eq!(
+        wire[1]["content"],
+        serde_json::json!({
+            "type": "resource",
+            "resource": {"uri": "file:///notes.md", "text": "# Notes"}
+        })
+    );
+    assert_eq!(wire[2]["content"]["text"], "Compare it with the notes.");
+}
+
+#[tokio::test]
+async fn test_get_prompt_requires_initialize() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    let err = client.get_prompt("describe", None).await.unwrap_err();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::BadRequest);
+}
+
+#[tokio::test]
+async fn test_list_prompts_returns_declared_arguments() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    let err = client.list_prompts().await.unwrap_err();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::BadRequest);
+
+    client.initialize().await.unwrap();
+    let prompts = client.list_prompts().await.unwrap();
+    assert_eq!(prompts.len(), 2);
+    assert_eq!(prompts[0].name, "describe");
+    let arguments = prompts[0].arguments.as_ref().unwrap();
+    assert_eq!(arguments[0].name, "subject");
+    assert_eq!(arguments[0].required, Some(true));
+    assert!(prompts[1].arguments.is_none());
+
+    let request = client.transport_mut().requests.last().unwrap().clone();
+    assert_eq!(request["method"], "prompts/list");
+    assert!(request.get("params").is_none_or(serde_json::Value::is_null));
+}
+
+#[tokio::test]
+async fn test_get_prompt_sends_name_and_arguments() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    let arguments =
+        std::collections::HashMap::from([("subject".to_string(), serde_json::json!("the mockup"))]);
+    client
+        .get_prompt("describe", Some(arguments))
+        .await
+        .unwrap();
+
+    let request = client.transport_mut().requests.last().unwrap().clone();
+    assert_eq!(request["method"], "prompts/get");
+    assert_eq!(
+        request["params"],
+        serde_json::json!({"name": "describe", "arguments": {"subject": "the mockup"}})
+    );
+}
+
+#[tokio::test]
+async fn test_read_resource_keeps_every_content_entry() {
+    use base64::Engine;
+    use turbomcp_protocol::types::ResourceContent;
+
+    let mut client = Client::new(ScriptedServerTransport::new());
+    let err = client.read_resource("file:///logo.png").await.unwrap_err();
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::BadRequest);
+
+    client.initialize().await.unwrap();
+    let result = client.read_resource("file:///logo.png").await.unwrap();
+    let request = client.transport_mut().requests.last().unwrap().clone();
+    assert_eq!(request["method"], "resources/read");
+    assert_eq!(
+        request["params"],
+        serde_json::json!({"uri": "file:///logo.png"})
+    );
+
+    assert_eq!(result.contents.len(), 2);
+    let ResourceContent::Blob(image) = &result.contents[0] else {
+        panic!("expected a blob, got {:?}", result.contents[0]);
+    };
+    assert_eq!(image.mime_type.as_deref(), Some("image/png"));
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(&image.blob)
+        .unwrap();
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(
+        base64::engine::general_purpose::STANDARD.encode(&bytes),
+        SCRIPTED_PNG
+    );
+    let ResourceContent::Text(caption) = &result.contents[1] else {
+        panic!("expected text, got {:?}", result.contents[1]);
+    };
+    assert_eq!(caption.text, "Company logo");
+}
+
+#[tokio::test]
+async fn test_call_tool_typed_keeps_every_content_block() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    let result = client.call_tool_typed("render_chart", None).await.unwrap();
+
+    assert_eq!(result.content.len(), 2);
+    let Content::Image(chart) = &result.content[0] else {
+        panic!("expected an image, got {:?}", result.content[0]);
+    };
+    assert_eq!(chart.data, SCRIPTED_PNG);
+    assert_eq!(chart.mime_type, "image/png");
+    let Content::Text(caption) = &result.content[1] else {
+        panic!("expected text, got {:?}", result.content[1]);
+    };
+    assert_eq!(caption.text, "Sales by quarter");
+    assert_eq!(
+        result.structured_content,
+        Some(serde_json::json!({"quarters": [12, 15, 9, 20]}))
+    );
+    assert_eq!(result.is_error, None);
+
+    // The summary still describes the first block
+    let summary = client.call_tool("render_chart", None).await.unwrap();
+    assert_eq!(summary["image"], SCRIPTED_PNG);
+    assert_eq!(summary["structured_content"]["quarters"][3], 20);
+}
+
+#[tokio::test]
+async fn test_session_info_records_initialize_meta() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    assert!(client.session_info().is_none());
+    client.initialize().await.unwrap();
+    let info = client.session_info().unwrap();
+    assert_eq!(info.server_info.name, "scripted");
+    assert_eq!(info.protocol_version, "2025-06-18");
+    assert!(info.meta.is_empty());
+
+    let mut client = ClientBuilder::new()
+        .with_session_meta("tenant", "acme")
+        .with_session_meta("flags", serde_json::json!(["beta"]))
+        .build(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    let meta = &client.session_info().unwrap().meta;
+    assert_eq!(meta["tenant"], "acme");
+    assert_eq!(meta["flags"], serde_json::json!(["beta"]));
+}
+
+#[tokio::test]
+async fn test_session_info_lists_supported_versions() {
+    let mut client = Client::new(
+        ScriptedServerTransport::new().with_supported_versions(&["2025-06-18", "2024-11-05"]),
+    );
+    client.initialize().await.unwrap();
+    let info = client.session_info().unwrap();
+    assert_eq!(info.supported_versions, ["2025-06-18", "2024-11-05"]);
+    assert_eq!(info.supports_version("2024-11-05"), Some(true));
+    assert_eq!(info.supports_version("2023-01-01"), Some(false));
+
+    // Servers that predate the field still initialize
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+    let info = client.session_info().unwrap();
+    assert!(info.supported_versions.is_empty());
+    assert_eq!(info.supports_version("2025-06-18"), None);
+}
+
+#[tokio::test]
+async fn test_oversized_session_meta_is_rejected_before_sending() {
+    let mut client = ClientBuilder::new()
+        .with_session_meta("blob", "x".repeat(MAX_INITIALIZE_META_SIZE))
+        .build(ScriptedServerTransport::new());
+    let err = client.initialize().await.unwrap_err();
+    assert!(err.to_string().contains("metadata"));
+    assert!(client.session_info().is_none());
+}
+
+// Transport whose connect either fails immediately or never completes
+#[derive(Debug)]
+struct UnreachableTransport {
+    capabilities: TransportCapabilities,
+    hang: bool,
+}
+
+impl UnreachableTransport {
+    fn boxed(hang: bool) -> Box<dyn Transport> {
+        Box::new(Self {
+            capabilities: TransportCapabilities::default(),
+            hang,
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for UnreachableTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Unix
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Disconnected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        if self.hang {
+            std::future::pending::<()>().await;
+        }
+        Err(turbomcp_transport::TransportError::ConnectionFailed(
+            "socket not found".to_string(),
+        ))
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn send(&mut self, _message: TransportMessage) -> TransportResult<()> {
+        unreachable!("never connected")
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        unreachable!("never connected")
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
+
+#[tokio::test]
+async fn test_transport_chain_falls_back_to_working_transport() {
+    let connection = ClientBuilder::new()
+        .with_transport_chain(vec![
+            UnreachableTransport::boxed(false),
+            Box::new(ScriptedServerTransport::new()),
+        ])
+        .connect()
+        .await
+        .unwrap();
+
+    assert_eq!(connection.index, 1);
+    assert_eq!(connection.transport_type, TransportType::Stdio);
+    assert_eq!(connection.initialize.server_info.name, "scripted");
+    assert!(connection.client.session_info().is_some());
+}
+
+#[tokio::test]
+async fn test_transport_chain_skips_hanging_transport_after_timeout() {
+    let connection = ClientBuilder::new()
+        .with_attempt_timeout(std::time::Duration::from_millis(50))
+        .with_transport_chain(vec![
+            UnreachableTransport::boxed(true),
+            Box::new(ScriptedServerTransport::new()),
+        ])
+        .connect()
+        .await
+        .unwrap();
+    assert_eq!(connection.index, 1);
+}
+
+#[tokio::test]
+async fn test_transport_chain_reports_every_failure() {
+    let err = ClientBuilder::new()
+        .with_attempt_timeout(std::time::Duration::from_millis(50))
+        .with_transport_chain(vec![
+            UnreachableTransport::boxed(false),
+            UnreachableTransport::boxed(true),
+        ])
+        .connect()
+        .await
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("[0] unix"));
+    assert!(message.contains("socket not found"));
+    assert!(message.contains("[1] unix: timed out"));
+
+    assert!(ClientBuilder::new().connect().await.is_err());
+}
+
+#[tokio::test]
+async fn test_output_schema_violation_is_reported() {
+    let tools = serde_json::json!([
+        {
+            "name": "get_weather",
+            "inputSchema": {"type": "object"},
+            "outputSchema": {
+                "type": "object",
+                "properties": {"temperature": {"type": "number"}},
+                "required": ["temperature"]
+            }
+        },
+        {
+            "name": "describe_weather",
+            "inputSchema": {"type": "object"},
+            "outputSchema": {
+                "type": "object",
+                "properties": {"temperature": {"type": "string"}}
+            }
+        }
+    ]);
+
+    // Without validation the mismatched result is returned as-is
+    let mut client = Client::new(ScriptedServerTransport::new().with_tools(tools.clone()));
+    client.initialize().await.unwrap();
+    let result = client.call_tool("get_weather", None).await.unwrap();
+    assert_eq!(result["structured_content"]["temperature"], "warm");
+
+    let mut client = ClientBuilder::new()
+        .with_output_schema_validation(true)
+        .build(ScriptedServerTransport::new().with_tools(tools));
+    client.initialize().await.unwrap();
+
+    // Schemas are fetched before the first call
+    let err = client.call_tool("get_weather", None).await.unwrap_err();
+    assert!(is_output_schema_violation(&err));
+    assert_eq!(err.kind, turbomcp_core::ErrorKind::Protocol);
+    assert_eq!(err.context.metadata["tool"], "get_weather");
+    assert!(err.message.contains("/temperature"));
+
+    let result = client.call_tool("describe_weather", None).await.unwrap();
+    assert_eq!(result["text"], "It is warm");
+}
+
+#[tokio::test]
+async fn test_tools_without_output_schema_pass_validation() {
+    let tools = serde_json::json!([
+        {
+            "name": "get_weather",
+            "inputSchema": {"type": "object"},
+            "outputSchema": {
+                "type": "object",
+                "properties": {"temperature": {"type": "number"}}
+            }
+        },
+        {"name": "say_hello", "inputSchema": {"type": "object"}}
+    ]);
+    let mut client = ClientBuilder::new()
+        .with_output_schema_validation(true)
+        .build(ScriptedServerTransport::new().with_tools(tools));
+    client.initialize().await.unwrap();
+
+    // Plain text with no structured content and no schema to check it against
+    let result = client.call_tool("say_hello", None).await.unwrap();
+    assert_eq!(result["text"], "Hello!");
+
+    // A tool that does declare a schema is still held to it
+    let err = client.call_tool("get_weather", None).await.unwrap_err();
+    assert!(is_output_schema_violation(&err));
+}
+
+#[tokio::test]
+async fn test_tool_errors_are_distinct_from_failed_calls() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    client.initialize().await.unwrap();
+
+    let outcome = client.call_tool_outcome("get_weather", None).await.unwrap();
+    assert!(matches!(outcome, ToolOutcome::Success(_)));
+    assert_eq!(outcome.text(), "It is warm");
+
+    // The tool ran and said no: a result, not an error
+    let outcome = client.call_tool_outcome("divide", None).await.unwrap();
+    assert!(outcome.is_tool_error());
+    assert_eq!(outcome.text(), "Cannot divide by zero");
+    assert_eq!(outcome.into_result().is_error, Some(true));
+
+    // The call itself failed: an error, with no result to report
+    let err = client.call_tool_outcome("missing", None).await.unwrap_err();
+    assert!(err.to_string().contains("Unknown tool: missing"));
+
+    // call_tool keeps reporting tool failures in-band
+    let summary = client.call_tool("divide", None).await.unwrap();
+    assert_eq!(summary["is_error"], true);
+    assert!(client.call_tool("missing", None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_probe_summarizes_server_and_disconnects() {
+    let transport = ScriptedServerTransport::new()
+        .with_supported_versions(&["2025-06-18", "2024-11-05"])
+        .disconnected();
+    let events = std::sync::Arc::clone(&transport.events);
+
+    let probe = Client::new(transport).probe().await.unwrap();
+    assert_eq!(probe.server_info.name, "scripted");
+    assert_eq!(probe.server_info.version, "1.0.0");
+    assert_eq!(probe.protocol_version, "2025-06-18");
+    assert_eq!(probe.supported_versions, ["2025-06-18", "2024-11-05"]);
+    assert!(probe.supports_tools());
+    assert!(!probe.supports_resources());
+    assert!(!probe.supports_prompts());
+    assert!(probe.instructions.is_none());
+
+    // A complete handshake, then the connection is closed
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "(connect)",
+            "initialize",
+            "notifications/initialized",
+            "(disconnect)"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_compression_enabled_only_when_both_sides_support_it() {
+    // Both sides: the client's preferred codec among the server's
+    let transport = ScriptedServerTransport::new()
+        .with_compression(&["zstd", "gzip"])
+        .with_transport_features(serde_json::json!({"compression": ["gzip", "br"]}));
+    let events = std::sync::Arc::clone(&transport.events);
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+    let features = client.negotiate_transport().await.unwrap();
+    assert_eq!(features.compression.as_deref(), Some("gzip"));
+    assert!(
+        events
+            .lock()
+            .unwrap()
+            .contains(&"(configure compression=true gzip)".to_string())
+    );
+    assert_eq!(
+        client.session_info().unwrap().transport_features,
+        Some(features)
+    );
+
+    // Only the server, only the client, or no codec in common
+    for transport in [
+        ScriptedServerTransport::new()
+            .with_transport_features(serde_json::json!({"compression": ["gzip"]})),
+        ScriptedServerTransport::new().with_compression(&["gzip"]),
+        ScriptedServerTransport::new()
+            .with_compression(&["zstd"])
+            .with_transport_features(serde_json::json!({"compression": ["gzip"]})),
+    ] {
+        let events = std::sync::Arc::clone(&transport.events);
+        let mut client = Client::new(transport);
+        client.initialize().await.unwrap();
+        let features = client.negotiate_transport().await.unwrap();
+        assert_eq!(features, TransportFeatures::default());
+        assert!(
+            !events
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|e| e.starts_with("(configure"))
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_batching_follows_negotiated_features() {
+    let mut client = Client::new(ScriptedServerTransport::new());
+    assert!(client.negotiate_transport().await.is_err());
+    client.initialize().await.unwrap();
+
+    // The server does not advertise batching, so reads go one at a time
+    assert!(!client.negotiate_transport().await.unwrap().batching);
+    let results = client
+        .read_resources(&["file:///a.log", "file:///b.log"])
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(client.transport_mut().batches_received, 0);
+
+    let transport = ScriptedServerTransport::new()
+        .with_transport_features(serde_json::json!({"batching": true}));
+    let mut client = Client::new(transport);
+    client.initialize().await.unwrap();
+    assert!(client.negotiate_transport().await.unwrap().batching);
+    client
+        .read_resources(&["file:///a.log", "file:///b.log"])
+        .await
+        .unwrap();
+    assert_eq!(client.transport_mut().batches_received, 1);
+}
@@ -0,0 +1,135 @@
+//! Soft memory budgets for request handlers
+//!
+//! Rust offers no cheap way to cap what one task allocates, so budgets rely on
+//! cooperation: a handler building something large calls
+//! [`RequestContext::charge_memory`](crate::RequestContext::charge_memory) as
+//! it goes, and the charge fails once the request's [`MemoryBudget`] would be
+//! overrun. The server also waits on [`MemoryBudget::exceeded`] alongside the
+//! handler, so a handler that ignores the failed charge is dropped at its next
+//! await point rather than left to finish.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use parking_lot::Mutex;
+use tokio_util::sync::CancellationToken;
+
+/// A charge that would have taken a budget past its limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Memory budget of {limit} bytes exceeded ({used} in use, {requested} more requested)")]
+pub struct BudgetExceeded {
+    /// The budget's limit in bytes
+    pub limit: usize,
+    /// Bytes already charged when the charge was refused
+    pub used: usize,
+    /// Bytes the refused charge asked for
+    pub requested: usize,
+}
+
+/// Memory a single request may report using
+#[derive(Debug)]
+pub struct MemoryBudget {
+    limit: usize,
+    used: AtomicUsize,
+    overrun: Mutex<Option<BudgetExceeded>>,
+    exceeded: CancellationToken,
+}
+
+impl MemoryBudget {
+    /// Create a budget of `limit` bytes
+    #[must_use]
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            used: AtomicUsize::new(0),
+            overrun: Mutex::new(None),
+            exceeded: CancellationToken::new(),
+        }
+    }
+
+    /// The limit in bytes
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Bytes charged so far
+    #[must_use]
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Acquire)
+    }
+
+    /// Record `bytes` more as in use
+    ///
+    /// A charge that does not fit is not recorded; it marks the budget as
+    /// exceeded and returns the error for the handler to propagate.
+    pub fn charge(&self, bytes: usize) -> Result<(), BudgetExceeded> {
+        let charged = self
+            .used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                used.checked_add(bytes).filter(|&total| total <= self.limit)
+            });
+        match charged {
+            Ok(_) => Ok(()),
+            Err(used) => {
+                let error = BudgetExceeded {
+                    limit: self.limit,
+                    used,
+                    requested: bytes,
+                };
+                self.overrun.lock().get_or_insert(error);
+                self.exceeded.cancel();
+                Err(error)
+            }
+        }
+    }
+
+    /// Return `bytes` previously charged, once they have been freed
+    pub fn release(&self, bytes: usize) {
+        let _ = self
+            .used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                Some(used.saturating_sub(bytes))
+            });
+    }
+
+    /// Whether a charge has been refused
+    #[must_use]
+    pub fn is_exceeded(&self) -> bool {
+        self.exceeded.is_cancelled()
+    }
+
+    /// Wait until a charge is refused, returning the first refusal
+    pub async fn exceeded(&self) -> BudgetExceeded {
+        self.exceeded.cancelled().await;
+        let overrun = *self.overrun.lock();
+        overrun.expect("budget is only marked exceeded after recording the overrun")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_refused_charge_marks_budget_exceeded() {
+        let budget = MemoryBudget::new(100);
+        budget.charge(60).unwrap();
+        budget.release(20);
+        budget.charge(60).unwrap();
+        assert_eq!(budget.used(), 100);
+        assert!(!budget.is_exceeded());
+
+        let error = budget.charge(1).unwrap_err();
+        assert_eq!(
+            error,
+            BudgetExceeded {
+                limit: 100,
+                used: 100,
+                requested: 1
+            }
+        );
+        assert_eq!(budget.used(), 100);
+        assert!(budget.is_exceeded());
+        assert_eq!(budget.exceeded().await, error);
+    }
+}
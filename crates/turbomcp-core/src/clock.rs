@@ -0,0 +1,116 @@
+//! Time sources for expiry and rate calculations
+//!
+//! Subsystems that expire or refill things over time, such as session
+//! management, rate limiting and result caches, read the time from a
+//! [`Clock`] instead of calling `Instant::now` or `Utc::now` directly. They
+//! use [`SystemClock`] unless given another clock, and tests can swap in a
+//! [`MockClock`] to move time forward without sleeping.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+
+/// Source of the current time
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /// Monotonic time, for measuring intervals
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, for timestamps
+    fn utc_now(&self) -> DateTime<Utc>;
+}
+
+/// Shared handle to a clock
+pub type SharedClock = Arc<dyn Clock>;
+
+/// The real time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// The system clock as a [`SharedClock`]
+    #[must_use]
+    pub fn shared() -> SharedClock {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that only moves when told to
+///
+/// Starts at the time it was created; [`advance`](Self::advance) moves both
+/// its monotonic and wall-clock time forward.
+#[derive(Debug)]
+pub struct MockClock {
+    instant: Instant,
+    utc: DateTime<Utc>,
+    elapsed: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// Create a clock stopped at the current time
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            instant: Instant::now(),
+            utc: Utc::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `by`
+    pub fn advance(&self, by: Duration) {
+        *self.elapsed.lock() += by;
+    }
+
+    /// Time the clock has been advanced by in total
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        *self.elapsed.lock()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.instant + self.elapsed()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        let elapsed = chrono::Duration::from_std(self.elapsed())
+            .expect("mock clock advanced beyond the range of a timestamp");
+        self.utc + elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_moves_only_when_advanced() {
+        let clock = MockClock::new();
+        let (start, start_utc) = (clock.now(), clock.utc_now());
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.now() - start, Duration::from_secs(90));
+        assert_eq!(clock.utc_now() - start_utc, chrono::Duration::seconds(90));
+    }
+}
@@ -53,15 +53,165 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
-use std::time::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 
 use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use crate::budget::{BudgetExceeded, MemoryBudget};
+use crate::error::{Error, Result};
 use crate::types::Timestamp;
 
+/// Metadata key holding the `_meta.progressToken` the client sent
+pub const PROGRESS_TOKEN_KEY: &str = "progress_token";
+
+/// Channel carrying server-to-client notifications and requests as JSON-RPC
+/// messages
+pub type NotificationSender = mpsc::UnboundedSender<serde_json::Value>;
+
+/// How long a server waits for the client to answer a request by default
+pub const DEFAULT_CLIENT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The server's own resources, looked up when a handler asks for one
+///
+/// Servers attach one to each request instead of copying every resource
+/// definition into it, so requests that never look pay nothing.
+pub trait ResourceLookup: std::fmt::Debug + Send + Sync {
+    /// The definition of the resource serving `uri`, as MCP `Resource` JSON
+    ///
+    /// A resource registered under exactly this URI is preferred over a
+    /// resource template that matches it.
+    fn resource_for(&self, uri: &str) -> Option<serde_json::Value>;
+}
+
+/// Requests from the server to its connected client, such as
+/// `sampling/createMessage` or `roots/list`
+///
+/// Requests are queued on the same channel as notifications. The server
+/// hands each response the client sends back to [`complete`](Self::complete),
+/// which wakes the matching [`request`](Self::request). Servers only create
+/// one for transports that carry messages both ways.
+#[derive(Debug, Clone)]
+pub struct ServerToClient {
+    outgoing: NotificationSender,
+    pending: Arc<Mutex<HashMap<String, oneshot::Sender<Result<serde_json::Value>>>>>,
+    next_id: Arc<AtomicU64>,
+    timeout: Duration,
+}
+
+impl ServerToClient {
+    /// Send requests through `outgoing`
+    #[must_use]
+    pub fn new(outgoing: NotificationSender) -> Self {
+        Self {
+            outgoing,
+            pending: Arc::default(),
+            next_id: Arc::default(),
+            timeout: DEFAULT_CLIENT_REQUEST_TIMEOUT,
+        }
+    }
+
+    /// Give up on responses that take longer than `timeout`
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send a request to the client and wait for its result
+    ///
+    /// # Errors
+    ///
+    /// Returns a transport error if the connection has gone away, a timeout
+    /// error if the client does not answer in time, and an RPC error if it
+    /// answers with one.
+    pub async fn request(
+        &self,
+        method: &str,
+        params: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let id = format!("server-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().insert(id.clone(), tx);
+
+        let mut message = serde_json::json!({"jsonrpc": "2.0", "id": id, "method": method});
+        if let Some(params) = params {
+            message["params"] = params;
+        }
+        if self.outgoing.send(message).is_err() {
+            self.pending.lock().remove(&id);
+            return Err(Error::transport("client connection has gone away"));
+        }
+
+        match tokio::time::timeout(self.timeout, rx).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => Err(Error::transport("client connection has gone away")),
+            Err(_) => {
+                self.pending.lock().remove(&id);
+                Err(Error::timeout(format!(
+                    "client did not answer {method} within {:?}",
+                    self.timeout
+                )))
+            }
+        }
+    }
+
+    /// Deliver a JSON-RPC response from the client
+    ///
+    /// Returns whether it answered a request still waiting here; responses
+    /// to unknown or timed-out requests are not.
+    pub fn complete(&self, response: &serde_json::Value) -> bool {
+        let Some(id) = response.get("id").and_then(serde_json::Value::as_str) else {
+            return false;
+        };
+        let Some(waiter) = self.pending.lock().remove(id) else {
+            return false;
+        };
+        let result = match response.get("error") {
+            Some(error) => Err(Error::rpc(
+                error
+                    .get("code")
+                    .and_then(serde_json::Value::as_i64)
+                    .and_then(|code| i32::try_from(code).ok())
+                    .unwrap_or(-32603),
+                error
+                    .get("message")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or("unknown error"),
+            )),
+            None => Ok(response
+                .get("result")
+                .cloned()
+                .unwrap_or(serde_json::Value::Null)),
+        };
+        // The requester may have timed out in the meantime
+        let _ = waiter.send(result);
+        true
+    }
+
+    /// Number of requests waiting for a response
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.pending.lock().len()
+    }
+}
+
+/// How a progress-tracked operation ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressStatus {
+    /// The operation finished and produced its result
+    Success,
+    /// The operation failed
+    Error,
+}
+
 /// Context information for request processing
 #[derive(Debug, Clone)]
 pub struct RequestContext {
@@ -92,6 +242,24 @@ pub struct RequestContext {
 
     /// Cancellation token
     pub cancellation_token: Option<Arc<CancellationToken>>,
+
+    /// Where notifications to the requesting client are sent, if anywhere
+    pub notifier: Option<NotificationSender>,
+
+    /// Also receives every notification sent to the client, if set
+    pub mirror: Option<NotificationSender>,
+
+    /// Requests to the requesting client; only set on duplex transports
+    pub client: Option<ServerToClient>,
+
+    /// Memory the request's handler may report using, if limited
+    pub memory_budget: Option<Arc<MemoryBudget>>,
+
+    /// The server's resources, for handlers that link to them
+    pub resources: Option<Arc<dyn ResourceLookup>>,
+
+    /// Set once the progress for the current token has been completed
+    progress_completed: Arc<AtomicBool>,
 }
 
 /// Context information for response processing
@@ -158,6 +326,12 @@ impl RequestContext {
             #[cfg(feature = "tracing")]
             span: None,
             cancellation_token: None,
+            notifier: None,
+            mirror: None,
+            client: None,
+            memory_budget: None,
+            resources: None,
+            progress_completed: Arc::default(),
         }
     }
     /// Return true if the request is authenticated according to context metadata
@@ -263,12 +437,176 @@ impl RequestContext {
         self
     }
 
+    /// Limit the memory the request's handler may report using
+    #[must_use]
+    pub fn with_memory_budget(mut self, budget: Arc<MemoryBudget>) -> Self {
+        self.memory_budget = Some(budget);
+        self
+    }
+
+    /// Let handlers look up the server's resources through `resources`
+    #[must_use]
+    pub fn with_resources(mut self, resources: Arc<dyn ResourceLookup>) -> Self {
+        self.resources = Some(resources);
+        self
+    }
+
+    /// Record the progress token the client attached to the request
+    #[must_use]
+    pub fn with_progress_token(mut self, token: impl Into<serde_json::Value>) -> Self {
+        self.progress_completed = Arc::default();
+        self.with_metadata(PROGRESS_TOKEN_KEY, token)
+    }
+
+    /// Send notifications for this request through `notifier`
+    #[must_use]
+    pub fn with_notifier(mut self, notifier: NotificationSender) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// Send a copy of every notification for this request to `mirror`
+    ///
+    /// The copy is sent whether or not the client is still connected, so
+    /// work that outlives the connection can still be followed.
+    #[must_use]
+    pub fn with_mirror(mut self, mirror: NotificationSender) -> Self {
+        self.mirror = Some(mirror);
+        self
+    }
+
+    /// Let handlers send requests to the client through `client`
+    #[must_use]
+    pub fn with_client(mut self, client: ServerToClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// The progress token the client attached to the request, if any
+    ///
+    /// Tokens are strings or integers; the value is kept as sent, so
+    /// notifications echo it back exactly.
+    #[must_use]
+    pub fn progress_token(&self) -> Option<&serde_json::Value> {
+        self.metadata.get(PROGRESS_TOKEN_KEY)
+    }
+
+    /// Send a notification to the requesting client
+    ///
+    /// Returns whether it was queued: it is dropped if the request has no
+    /// notifier or the connection has gone away. A [mirror](Self::with_mirror)
+    /// gets its copy either way.
+    pub fn notify(&self, method: &str, params: serde_json::Value) -> bool {
+        let notification = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        });
+        if let Some(mirror) = &self.mirror {
+            let _ = mirror.send(notification.clone());
+        }
+        self.notifier
+            .as_ref()
+            .is_some_and(|notifier| notifier.send(notification).is_ok())
+    }
+
+    /// Send a request to the client and wait for its typed result
+    ///
+    /// Use `serde_json::Value` as `R` to take the result as sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an unavailable error if the transport this request arrived on
+    /// only carries messages from the client, a serialization error if the
+    /// params or result do not convert, and otherwise the errors of
+    /// [`ServerToClient::request`].
+    pub async fn client_request<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<R> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| Error::unavailable("transport does not carry requests to the client"))?;
+        let params =
+            serde_json::to_value(params).map_err(|e| Error::serialization(e.to_string()))?;
+        let params = (!params.is_null()).then_some(params);
+        let result = client.request(method, params).await?;
+        serde_json::from_value(result).map_err(|e| Error::serialization(e.to_string()))
+    }
+
+    /// Send a `notifications/progress` carrying the client's progress token
+    ///
+    /// Clients only want progress for requests they attached a token to, so
+    /// nothing is sent without one, nor once the progress has been
+    /// completed. Returns whether a notification was queued.
+    pub fn notify_progress(
+        &self,
+        progress: f64,
+        total: Option<f64>,
+        message: Option<&str>,
+    ) -> bool {
+        let Some(token) = self.progress_token() else {
+            return false;
+        };
+        if self.progress_completed.load(Ordering::SeqCst) {
+            return false;
+        }
+        let mut params = serde_json::json!({"progressToken": token, "progress": progress});
+        if let Some(total) = total {
+            params["total"] = total.into();
+        }
+        if let Some(message) = message {
+            params["message"] = message.into();
+        }
+        self.notify("notifications/progress", params)
+    }
+
+    /// Send a `notifications/progress/complete` ending the client's progress
+    ///
+    /// Tells the client the operation is over and how it ended, so it need
+    /// not infer completion from `progress == total`. Sent at most once per
+    /// progress token, and only if the client attached one; later progress
+    /// updates are dropped. Returns whether this call queued the
+    /// notification.
+    pub fn notify_progress_complete(&self, status: ProgressStatus, summary: Option<&str>) -> bool {
+        let Some(token) = self.progress_token() else {
+            return false;
+        };
+        if self.progress_completed.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+        let mut params = serde_json::json!({"progressToken": token, "status": status});
+        if let Some(summary) = summary {
+            params["summary"] = summary.into();
+        }
+        self.notify("notifications/progress/complete", params)
+    }
+
+    /// Whether this request's progress has been completed
+    #[must_use]
+    pub fn is_progress_complete(&self) -> bool {
+        self.progress_completed.load(Ordering::SeqCst)
+    }
+
     /// Get elapsed time since request started
     #[must_use]
     pub fn elapsed(&self) -> std::time::Duration {
         self.start_time.elapsed()
     }
 
+    /// Report `bytes` more memory in use by the handler
+    ///
+    /// Handlers building large structures call this as they grow and stop
+    /// with the error once the request's budget is exhausted. Always succeeds
+    /// when the request has no budget.
+    pub fn charge_memory(&self, bytes: usize) -> std::result::Result<(), BudgetExceeded> {
+        self.memory_budget
+            .as_ref()
+            .map_or(Ok(()), |budget| budget.charge(bytes))
+    }
+
     /// Check if request is cancelled
     #[must_use]
     pub fn is_cancelled(&self) -> bool {
@@ -283,6 +621,20 @@ impl RequestContext {
         self.metadata.get(key)
     }
 
+    /// Get a header the request arrived with, ignoring the case of its name
+    ///
+    /// Servers record transport headers, such as HTTP request headers, under
+    /// the `headers` metadata key.
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.metadata
+            .get("headers")?
+            .as_object()?
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| value.as_str())
+    }
+
     /// Clone with new request ID (for sub-requests)
     #[must_use]
     pub fn derive(&self) -> Self {
@@ -297,6 +649,12 @@ impl RequestContext {
             #[cfg(feature = "tracing")]
             span: None,
             cancellation_token: self.cancellation_token.clone(),
+            notifier: self.notifier.clone(),
+            mirror: self.mirror.clone(),
+            client: self.client.clone(),
+            memory_budget: self.memory_budget.clone(),
+            resources: self.resources.clone(),
+            progress_completed: Arc::clone(&self.progress_completed),
         }
     }
 }
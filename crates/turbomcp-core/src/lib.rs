@@ -57,6 +57,8 @@
     clippy::used_underscore_binding  // Sometimes underscore bindings are needed
 )]
 
+pub mod budget;
+pub mod clock;
 pub mod context;
 pub mod error;
 pub mod error_utils;
@@ -71,9 +73,12 @@ pub mod utils;
 pub mod config;
 
 // Re-export commonly used types
+pub use budget::{BudgetExceeded, MemoryBudget};
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
 pub use context::{
-    ClientId, ClientIdExtractor, ClientSession, RequestContext, RequestContextExt, RequestInfo,
-    ResponseContext,
+    ClientId, ClientIdExtractor, ClientSession, DEFAULT_CLIENT_REQUEST_TIMEOUT, NotificationSender,
+    PROGRESS_TOKEN_KEY, ProgressStatus, RequestContext, RequestContextExt, RequestInfo,
+    ResourceLookup, ResponseContext, ServerToClient,
 };
 pub use error::{Error, ErrorKind, Result};
 pub use message::{Message, MessageId, MessageMetadata};
@@ -262,18 +262,17 @@ impl Message {
         match &self.payload {
             MessagePayload::Json(json_payload) => json_payload.parsed.as_ref().map_or_else(
                 || {
-                    #[cfg(feature = "simd")]
+                    #[cfg(all(feature = "simd", not(feature = "arbitrary-precision")))]
                     {
                         let mut json_bytes = json_payload.raw.to_vec();
                         simd_json::from_slice(&mut json_bytes).map_err(|e| {
                             Error::serialization(format!("SIMD JSON parsing failed: {e}"))
                         })
                     }
-                    #[cfg(not(feature = "simd"))]
+                    #[cfg(any(not(feature = "simd"), feature = "arbitrary-precision"))]
                     {
-                        serde_json::from_slice(&json_payload.raw).map_err(|e| {
-                            Error::serialization(format!("JSON parsing failed: {}", e))
-                        })
+                        serde_json::from_slice(&json_payload.raw)
+                            .map_err(|e| Error::serialization(format!("JSON parsing failed: {e}")))
                     }
                 },
                 |parsed| {
@@ -288,17 +287,17 @@ impl Message {
     // Private helper methods
 
     fn serialize_json(value: &impl Serialize) -> Result<BytesMut> {
-        #[cfg(feature = "simd")]
+        #[cfg(all(feature = "simd", not(feature = "arbitrary-precision")))]
         {
             sonic_rs::to_vec(value)
                 .map(|v| BytesMut::from(v.as_slice()))
                 .map_err(|e| Error::serialization(format!("SIMD JSON serialization failed: {e}")))
         }
-        #[cfg(not(feature = "simd"))]
+        #[cfg(any(not(feature = "simd"), feature = "arbitrary-precision"))]
         {
             serde_json::to_vec(value)
                 .map(|v| BytesMut::from(v.as_slice()))
-                .map_err(|e| Error::serialization(format!("JSON serialization failed: {}", e)))
+                .map_err(|e| Error::serialization(format!("JSON serialization failed: {e}")))
         }
     }
 
@@ -376,7 +375,7 @@ impl Message {
                         })
                 } else {
                     // Fallback: attempt to parse then encode
-                    #[cfg(feature = "simd")]
+                    #[cfg(all(feature = "simd", not(feature = "arbitrary-precision")))]
                     {
                         let mut json_bytes = json_payload.raw.to_vec();
                         let value: serde_json::Value = simd_json::from_slice(&mut json_bytes)
@@ -389,17 +388,16 @@ impl Message {
                             Error::serialization(format!("CBOR serialization failed: {e}"))
                         })
                     }
-                    #[cfg(not(feature = "simd"))]
+                    #[cfg(any(not(feature = "simd"), feature = "arbitrary-precision"))]
                     {
                         let value: serde_json::Value = serde_json::from_slice(&json_payload.raw)
                             .map_err(|e| {
                                 Error::serialization(format!(
-                                    "JSON parsing failed before CBOR: {}",
-                                    e
+                                    "JSON parsing failed before CBOR: {e}"
                                 ))
                             })?;
                         serde_cbor::to_vec(&value).map(Bytes::from).map_err(|e| {
-                            Error::serialization(format!("CBOR serialization failed: {}", e))
+                            Error::serialization(format!("CBOR serialization failed: {e}"))
                         })
                     }
                 }
@@ -13,6 +13,7 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use tokio::time::{Interval, interval};
 
+use crate::clock::{SharedClock, SystemClock};
 use crate::context::{ClientIdExtractor, ClientSession, RequestInfo};
 
 /// Configuration for session management
@@ -85,6 +86,8 @@ pub struct SessionManager {
     cleanup_timer: Arc<RwLock<Option<Interval>>>,
     /// Global statistics
     stats: Arc<RwLock<SessionStats>>,
+    /// Time source for activity stamps and expiry
+    clock: SharedClock,
 }
 
 /// Internal statistics tracking
@@ -137,9 +140,17 @@ impl SessionManager {
             session_history: Arc::new(RwLock::new(VecDeque::new())),
             cleanup_timer: Arc::new(RwLock::new(None)),
             stats: Arc::new(RwLock::new(SessionStats::default())),
+            clock: SystemClock::shared(),
         }
     }
 
+    /// Read the time from `clock` instead of the system clock
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Start the session manager (begin cleanup task)
     pub fn start(&self) {
         let mut timer_guard = self.cleanup_timer.write();
@@ -153,16 +164,35 @@ impl SessionManager {
         let config = self.config.clone();
         let session_history = self.session_history.clone();
         let stats = self.stats.clone();
+        let clock = self.clock.clone();
 
         tokio::spawn(async move {
             let mut timer = interval(config.cleanup_interval);
             loop {
                 timer.tick().await;
-                Self::cleanup_expired_sessions(&sessions, &config, &session_history, &stats);
+                Self::cleanup_expired_sessions(
+                    &sessions,
+                    &config,
+                    &session_history,
+                    &stats,
+                    &clock,
+                );
             }
         });
     }
 
+    /// Remove sessions idle for longer than the session timeout now, without
+    /// waiting for the cleanup task
+    pub fn cleanup_expired(&self) {
+        Self::cleanup_expired_sessions(
+            &self.sessions,
+            &self.config,
+            &self.session_history,
+            &self.stats,
+            &self.clock,
+        );
+    }
+
     /// Create or get existing session for a client
     #[must_use]
     pub fn get_or_create_session(
@@ -175,7 +205,9 @@ impl SessionManager {
                 // Enforce capacity before inserting a new session
                 self.enforce_capacity();
 
-                let session = ClientSession::new(client_id.clone(), transport_type);
+                let mut session = ClientSession::new(client_id.clone(), transport_type);
+                session.connected_at = self.clock.utc_now();
+                session.last_activity = session.connected_at;
                 self.sessions.insert(client_id.clone(), session.clone());
 
                 // Record session creation
@@ -195,6 +227,7 @@ impl SessionManager {
     pub fn update_client_activity(&self, client_id: &str) {
         if let Some(mut session) = self.sessions.get_mut(client_id) {
             session.update_activity();
+            session.last_activity = self.clock.utc_now();
 
             // Optional: enforce per-session request cap by early termination
             if let Some(cap) = self.config.max_requests_per_session
@@ -315,7 +348,7 @@ impl SessionManager {
             top_methods.truncate(10);
 
             // Calculate request rate (requests per minute over last hour)
-            let one_hour_ago = Utc::now() - Duration::hours(1);
+            let one_hour_ago = self.clock.utc_now() - Duration::hours(1);
             let recent_requests = history
                 .iter()
                 .filter(|req| req.timestamp > one_hour_ago)
@@ -406,8 +439,9 @@ impl SessionManager {
         config: &SessionConfig,
         session_history: &Arc<RwLock<VecDeque<SessionEvent>>>,
         stats: &Arc<RwLock<SessionStats>>,
+        clock: &SharedClock,
     ) {
-        let cutoff_time = Utc::now() - config.session_timeout;
+        let cutoff_time = clock.utc_now() - config.session_timeout;
         let mut expired_sessions = Vec::new();
 
         for entry in sessions.iter() {
@@ -425,7 +459,7 @@ impl SessionManager {
 
                 // Record event
                 let event = SessionEvent {
-                    timestamp: Utc::now(),
+                    timestamp: clock.utc_now(),
                     client_id,
                     event_type: SessionEventType::Expired,
                     metadata: HashMap::new(),
@@ -447,7 +481,7 @@ impl SessionManager {
         metadata: HashMap<String, serde_json::Value>,
     ) {
         let event = SessionEvent {
-            timestamp: Utc::now(),
+            timestamp: self.clock.utc_now(),
             client_id,
             event_type,
             metadata,
@@ -490,7 +524,7 @@ impl SessionManager {
 
                 // Record eviction as termination event
                 let event = SessionEvent {
-                    timestamp: Utc::now(),
+                    timestamp: self.clock.utc_now(),
                     client_id: client_id.clone(),
                     event_type: SessionEventType::Terminated,
                     metadata: {
@@ -613,6 +647,35 @@ mod tests {
         assert_eq!(analytics.active_sessions, 0);
     }
 
+    #[tokio::test]
+    async fn test_idle_sessions_expire_as_clock_advances() {
+        use crate::clock::{Clock, MockClock};
+
+        let clock = Arc::new(MockClock::new());
+        let config = SessionConfig {
+            session_timeout: Duration::minutes(30),
+            ..SessionConfig::default()
+        };
+        let manager = SessionManager::new(config).with_clock(clock.clone());
+        let _ = manager.get_or_create_session("idle".to_string(), "http".to_string());
+        let _ = manager.get_or_create_session("busy".to_string(), "http".to_string());
+
+        clock.advance(StdDuration::from_secs(20 * 60));
+        manager.update_client_activity("busy");
+        manager.cleanup_expired();
+        assert_eq!(manager.get_active_sessions().len(), 2);
+
+        // Only the session without recent activity is past the timeout
+        clock.advance(StdDuration::from_secs(15 * 60));
+        manager.cleanup_expired();
+        assert!(manager.get_session("idle").is_none());
+        assert!(manager.get_session("busy").is_some());
+
+        let events = manager.get_session_events(Some(1));
+        assert!(matches!(events[0].event_type, SessionEventType::Expired));
+        assert_eq!(events[0].timestamp, clock.utc_now());
+    }
+
     #[tokio::test]
     async fn test_parameter_sanitization() {
         let manager = SessionManager::new(SessionConfig::default());
@@ -0,0 +1,40 @@
+//! Tests for exact JSON number handling under the `arbitrary-precision` feature
+#![cfg(feature = "arbitrary-precision")]
+
+use bytes::Bytes;
+use serde_json::Value;
+use turbomcp_core::message::{Message, MessageId, SerializationFormat};
+
+/// Neither number survives a round trip through `f64`
+const AMOUNT: &str = "12345678901234567890.123456789";
+const LARGE_ID: &str = "340282366920938463463374607431768211455";
+
+fn payload() -> String {
+    format!(r#"{{"amount":{AMOUNT},"id":{LARGE_ID}}}"#)
+}
+
+#[test]
+fn test_numbers_lose_precision_as_f64() {
+    assert_ne!(AMOUNT.parse::<f64>().unwrap().to_string(), AMOUNT);
+    assert_ne!(LARGE_ID.parse::<f64>().unwrap().to_string(), LARGE_ID);
+}
+
+#[test]
+fn test_parsed_message_keeps_exact_numbers() {
+    let message = Message::deserialize(Bytes::from(payload())).unwrap();
+    let value: Value = message.parse_json().unwrap();
+
+    assert_eq!(value["amount"].to_string(), AMOUNT);
+    assert_eq!(value["id"].to_string(), LARGE_ID);
+}
+
+#[test]
+fn test_exact_numbers_round_trip_through_message() {
+    let value: Value = serde_json::from_str(&payload()).unwrap();
+    let message = Message::json(MessageId::from("1"), &value).unwrap();
+    let bytes = message.serialize(SerializationFormat::Json).unwrap();
+
+    assert_eq!(std::str::from_utf8(&bytes).unwrap(), payload());
+    let reparsed: Value = Message::deserialize(bytes).unwrap().parse_json().unwrap();
+    assert_eq!(reparsed, value);
+}
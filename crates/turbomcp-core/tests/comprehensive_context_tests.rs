@@ -77,6 +77,19 @@ fn test_request_context_with_metadata() {
     assert!(ctx.get_metadata("missing").is_none());
 }
 
+#[test]
+fn test_request_context_header_lookup() {
+    let ctx = RequestContext::new().with_metadata(
+        "headers",
+        serde_json::json!({"authorization": "Bearer abc", "x-trace": "t-1"}),
+    );
+
+    assert_eq!(ctx.header("Authorization"), Some("Bearer abc"));
+    assert_eq!(ctx.header("x-trace"), Some("t-1"));
+    assert!(ctx.header("cookie").is_none());
+    assert!(RequestContext::new().header("authorization").is_none());
+}
+
 #[test]
 fn test_request_context_with_cancellation_token() {
     let token = Arc::new(CancellationToken::new());
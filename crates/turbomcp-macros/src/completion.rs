@@ -0,0 +1,125 @@
+//! Completion macro implementation
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Expr, ItemFn, Lit, MetaNameValue, Token, parse::Parser, parse_macro_input,
+    punctuated::Punctuated,
+};
+
+/// What a `#[completion]` method completes
+struct CompletionTarget {
+    /// `CompletionReference` expression for the prompt or resource
+    reference: TokenStream2,
+    /// Argument name
+    argument: String,
+}
+
+/// Generate a completion handler for a method returning candidate values
+pub fn generate_completion_impl(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+
+    let target = match parse_completion_args(args.into()) {
+        Ok(target) => target,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let reference = &target.reference;
+    let argument = &target.argument;
+
+    let fn_name = &input.sig.ident;
+    let fn_vis = &input.vis;
+    let fn_block = &input.block;
+    let fn_sig = &input.sig;
+
+    let metadata_fn_name = syn::Ident::new(
+        &format!("__turbomcp_completion_metadata_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+    let handler_fn_name = syn::Ident::new(
+        &format!("__turbomcp_completion_handler_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    let expanded = quote! {
+        #fn_vis #fn_sig #fn_block
+
+        // The prompt or resource, and the argument of it, this method completes
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #metadata_fn_name() -> (turbomcp::CompletionReference, &'static str) {
+            (#reference, #argument)
+        }
+
+        // Bridge a completion request to the method: it receives the reference
+        // and the partial value, and returns the candidates
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #handler_fn_name(&self, request: turbomcp::CompleteRequest, _context: turbomcp::RequestContext) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<turbomcp::CompleteResult, turbomcp::ServerError>> + Send + '_>> {
+            Box::pin(async move {
+                let candidates = self.#fn_name(request.reference, request.argument.value).await
+                    .map_err(turbomcp::ServerError::from)?;
+                Ok(turbomcp::CompleteResult {
+                    completion: turbomcp::Completion::from(candidates),
+                })
+            })
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Parse `prompt = "..."` or `resource = "..."`, and `argument = "..."`
+fn parse_completion_args(args: TokenStream2) -> syn::Result<CompletionTarget> {
+    const USAGE: &str = "expected `#[completion(prompt = \"...\", argument = \"...\")]` \
+                         or `#[completion(resource = \"...\", argument = \"...\")]`";
+
+    let items = Punctuated::<MetaNameValue, Token![,]>::parse_terminated
+        .parse2(args.clone())
+        .map_err(|e| syn::Error::new(e.span(), USAGE))?;
+
+    let mut reference = None;
+    let mut argument = None;
+    for item in &items {
+        let Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) = &item.value
+        else {
+            return Err(syn::Error::new_spanned(
+                &item.value,
+                "completion attribute values must be string literals",
+            ));
+        };
+        let value = value.value();
+        let key = item.path.get_ident().map(ToString::to_string);
+        let duplicate = match key.as_deref() {
+            Some("prompt") => {
+                let prompt =
+                    quote! { turbomcp::CompletionReference::Prompt { name: #value.to_string() } };
+                reference.replace(prompt).is_some()
+            }
+            Some("resource") => {
+                let resource =
+                    quote! { turbomcp::CompletionReference::Resource { uri: #value.to_string() } };
+                reference.replace(resource).is_some()
+            }
+            Some("argument") => argument.replace(value).is_some(),
+            _ => return Err(syn::Error::new_spanned(&item.path, USAGE)),
+        };
+        if duplicate {
+            return Err(syn::Error::new_spanned(
+                item,
+                "a completion completes one argument of one prompt or resource",
+            ));
+        }
+    }
+
+    match (reference, argument) {
+        (Some(reference), Some(argument)) => Ok(CompletionTarget {
+            reference,
+            argument,
+        }),
+        _ => Err(syn::Error::new_spanned(args, USAGE)),
+    }
+}
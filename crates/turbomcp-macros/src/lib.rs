@@ -9,6 +9,7 @@
 //! - **`#[tool]`** - Mark methods as MCP tool handlers with automatic schema generation
 //! - **`#[prompt]`** - Mark methods as MCP prompt handlers with template support
 //! - **`#[resource]`** - Mark methods as MCP resource handlers with URI templates
+//! - **`#[completion]`** - Mark methods as argument completion handlers for prompts and resources
 //! - **Helper macros** - `mcp_error!`, `mcp_text!`, `tool_result!` for ergonomic content creation
 //!
 //! ## Usage
@@ -41,6 +42,7 @@
 
 use proc_macro::TokenStream;
 
+mod completion;
 mod helpers;
 mod prompt;
 mod resource;
@@ -70,6 +72,13 @@ mod tool;
 ///     }
 /// }
 /// ```
+///
+/// # Attributes
+///
+/// - `name`, `version`, `description`: server metadata reported on initialize
+/// - `namespace`: prefix for every tool name, so `#[server(namespace = "fs")]`
+///   lists and routes a `read` tool as `fs.read`. Tools are not namespaced
+///   unless this is set.
 #[proc_macro_attribute]
 pub fn server(args: TokenStream, input: TokenStream) -> TokenStream {
     // Implementation - only supports impl blocks (the correct pattern)
@@ -100,6 +109,80 @@ pub fn server(args: TokenStream, input: TokenStream) -> TokenStream {
 ///         Ok(a + b)
 ///     }
 /// }
+/// ```
+///
+/// # Parameter types
+///
+/// Every parameter other than the `Context` must be an owned, deserializable
+/// type so the tool's input schema can describe it. Borrowed types such as
+/// `&str`, tuples, arrays, trait types and the method's own type parameters
+/// are rejected at compile time with an error pointing at the parameter.
+///
+/// # Parameter descriptions
+///
+/// A parameter's doc comment becomes the `description` of its property in
+/// the input schema, with wrapped lines joined. `#[param(description =
+/// "...")]` sets it explicitly and takes precedence over the doc comment:
+///
+/// ```ignore
+/// #[tool("Search the catalogue")]
+/// async fn search(
+///     &self,
+///     /// Words to look for
+///     query: String,
+///     #[param(description = "Most results to return")] limit: Option<u32>,
+/// ) -> turbomcp::McpResult<String> {
+///     Ok(format!("{query} ({limit:?})"))
+/// }
+/// ```
+///
+/// # Output schema
+///
+/// A tool returning a struct, directly or as `McpResult<T>` or `Json<T>`,
+/// advertises an `outputSchema` generated from `T` when it derives
+/// `schemars::JsonSchema`, and its results carry the value as
+/// `structuredContent` alongside the JSON text:
+///
+/// ```ignore
+/// #[derive(Serialize, JsonSchema)]
+/// struct Forecast {
+///     city: String,
+///     celsius: f64,
+/// }
+///
+/// #[tool("Forecast the weather")]
+/// async fn forecast(&self, city: String) -> turbomcp::McpResult<Forecast> {
+///     Ok(Forecast { city, celsius: 21.5 })
+/// }
+/// ```
+///
+/// Strings, numbers, collections and types without a `JsonSchema`
+/// implementation get no output schema.
+///
+/// # Pre-warming
+///
+/// `prewarm = "method"` names an `async fn method(&self) -> McpResult<()>` on
+/// the same server that the `#[server]` run methods await before accepting
+/// requests, so expensive setup such as loading a model stays off the first
+/// call's critical path.
+///
+/// # Examples for hosts
+///
+/// `example = "<json>"` attaches a sample set of arguments to the tool's
+/// input schema as an entry of its `examples` field, which hosts may show to
+/// the model or in documentation. Repeat it for several examples. Each must
+/// be a JSON object naming only the tool's parameters; a raw string keeps the
+/// JSON readable:
+///
+/// ```ignore
+/// #[tool("Look up a user", example = r#"{"id": 42}"#, example = r#"{"id": 7}"#)]
+/// async fn lookup(&self, id: u64) -> turbomcp::McpResult<String> {
+///     Ok(format!("user {id}"))
+/// }
+/// ```
+///
+/// `output_example = "<json>"` likewise adds a sample result to the output
+/// schema of a tool returning a struct. Each must be a JSON object.
 #[proc_macro_attribute]
 pub fn tool(args: TokenStream, input: TokenStream) -> TokenStream {
     tool::generate_tool_impl(args, input)
@@ -107,6 +190,10 @@ pub fn tool(args: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Marks a method as a prompt handler
 ///
+/// The method may return anything implementing `turbomcp::IntoPromptResult`:
+/// plain text for a single user message, or content and messages of any
+/// type, including images and embedded resources, which are sent unchanged.
+///
 /// # Example
 ///
 /// ```ignore
@@ -117,6 +204,14 @@ pub fn tool(args: TokenStream, input: TokenStream) -> TokenStream {
 /// async fn code_prompt(&self, language: String) -> turbomcp::McpResult<String> {
 ///     Ok(format!("Generated {} code", language))
 /// }
+///
+/// #[prompt("Explain what the screenshot shows")]
+/// async fn explain_screenshot(&self, png: String) -> turbomcp::McpResult<Vec<turbomcp::Content>> {
+///     Ok(vec![
+///         turbomcp::image(png, "image/png"),
+///         turbomcp::text("What is shown in this screenshot?"),
+///     ])
+/// }
 /// # }
 #[proc_macro_attribute]
 pub fn prompt(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -125,6 +220,14 @@ pub fn prompt(args: TokenStream, input: TokenStream) -> TokenStream {
 
 /// Marks a method as a resource handler
 ///
+/// Parameters named after a variable of the URI template receive its value,
+/// parsed into the parameter type, and a parameter named `uri` receives the
+/// URI read. The method returns text (`String`), bytes (`Vec<u8>`, sent
+/// base64-encoded as a blob) or a `ResourceBody` holding either. Declare the
+/// type of the content with `mime_type`; without it the server infers one,
+/// `text/plain` for ordinary text. A method whose content type is only known
+/// once read returns a `TypedResource` instead.
+///
 /// # Example
 ///
 /// ```ignore
@@ -135,12 +238,54 @@ pub fn prompt(args: TokenStream, input: TokenStream) -> TokenStream {
 /// async fn get_config(&self, section: String) -> turbomcp::McpResult<String> {
 ///     Ok(format!("Config for section: {}", section))
 /// }
+///
+/// #[resource("config://{section}.json", mime_type = "application/json")]
+/// async fn get_config_json(&self, section: String) -> turbomcp::McpResult<String> {
+///     Ok(format!(r#"{{"section": "{section}"}}"#))
+/// }
 /// # }
+/// ```
 #[proc_macro_attribute]
 pub fn resource(args: TokenStream, input: TokenStream) -> TokenStream {
     resource::generate_resource_impl(args, input)
 }
 
+/// Marks a method as the completion handler for one prompt or resource argument
+///
+/// `#[server]` registers the method for `completion/complete` requests naming
+/// that prompt (`prompt = "name"`) or resource template (`resource = "uri"`)
+/// and argument. The method receives the request's
+/// `turbomcp::CompletionReference` and the partial value typed so far, and
+/// returns the candidate values, as a `Vec<String>` or a
+/// `turbomcp::Completion`.
+///
+/// Only the first 100 candidates are sent. The result's `total` counts all
+/// of them, and `hasMore` says whether any were left out.
+///
+/// # Example
+///
+/// ```ignore
+/// # use turbomcp_macros::completion;
+/// # struct MyServer;
+/// # impl MyServer {
+/// #[completion(prompt = "translate", argument = "language")]
+/// async fn complete_language(
+///     &self,
+///     _reference: turbomcp::CompletionReference,
+///     value: String,
+/// ) -> turbomcp::McpResult<Vec<String>> {
+///     Ok(["english", "french", "german"]
+///         .into_iter()
+///         .filter(|language| language.starts_with(&value))
+///         .map(String::from)
+///         .collect())
+/// }
+/// # }
+#[proc_macro_attribute]
+pub fn completion(args: TokenStream, input: TokenStream) -> TokenStream {
+    completion::generate_completion_impl(args, input)
+}
+
 /// Helper macro for creating MCP ContentBlock structures (advanced usage)
 ///
 /// **Note:** Most tool functions should simply return `String` using `format!()`.
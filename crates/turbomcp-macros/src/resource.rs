@@ -1,10 +1,11 @@
 //! Production-grade resource macro implementation with comprehensive argument parsing
 
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{
-    ItemFn, Lit, Meta, Token, parse::Parse, parse::ParseStream, parse_macro_input,
-    punctuated::Punctuated,
+    FnArg, ItemFn, Lit, LitStr, Meta, Pat, Signature, Token, parse::Parse, parse::ParseStream,
+    parse_macro_input, punctuated::Punctuated,
 };
 
 /// Comprehensive resource configuration for maximum utility and DX
@@ -12,17 +13,31 @@ use syn::{
 struct ResourceConfig {
     name: Option<String>,
     uri_template: Option<String>,
+    mime_type: Option<String>,
     tags: Vec<String>,
 }
 
 /// Production-grade attribute parser for comprehensive resource configuration
+///
+/// The URI template may lead, unnamed, before the named arguments.
 struct ResourceArgs {
+    uri_template: Option<LitStr>,
     items: Punctuated<Meta, Token![,]>,
 }
 
 impl Parse for ResourceArgs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let uri_template = if input.peek(LitStr) {
+            let uri_template = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            Some(uri_template)
+        } else {
+            None
+        };
         Ok(ResourceArgs {
+            uri_template,
             items: input.parse_terminated(Meta::parse, Token![,])?,
         })
     }
@@ -50,6 +65,14 @@ pub fn generate_resource_impl(args: TokenStream, input: TokenStream) -> TokenStr
     let uri_template = config
         .uri_template
         .unwrap_or_else(|| format!("resource://{}", fn_name));
+    let mime_type = match &config.mime_type {
+        Some(mime_type) => quote! { Some(#mime_type) },
+        None => quote! { None },
+    };
+    let (bindings, call_args) = match bind_uri_parameters(fn_sig, &uri_template) {
+        Ok(bound) => bound,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     // Generate comprehensive metadata function
     let metadata_fn_name = syn::Ident::new(
@@ -63,6 +86,15 @@ pub fn generate_resource_impl(args: TokenStream, input: TokenStream) -> TokenStr
         proc_macro2::Span::call_site(),
     );
 
+    let mime_type_fn_name = syn::Ident::new(
+        &format!("__turbomcp_resource_mime_type_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+    let handler_fn_name = syn::Ident::new(
+        &format!("__turbomcp_resource_handler_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
     // Generate tags as a vector literal
     let tags_tokens = if config.tags.is_empty() {
         quote! { vec![] }
@@ -99,6 +131,29 @@ pub fn generate_resource_impl(args: TokenStream, input: TokenStream) -> TokenStr
                 #tags_tokens
             )
         }
+
+        // MIME type declared with `mime_type = "..."`, if any
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #mime_type_fn_name() -> Option<&'static str> {
+            #mime_type
+        }
+
+        // Bridge a read of a matching URI to the method: parameters named after
+        // template variables get their values, and one named `uri` the whole URI
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #handler_fn_name(&self, request: turbomcp::ReadResourceRequest, _context: turbomcp::RequestContext) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<turbomcp::ReadResourceResult, turbomcp::ServerError>> + Send + '_>> {
+            Box::pin(async move {
+                let uri = request.uri;
+                let params = turbomcp::uri_template_params(#uri_template, &uri).unwrap_or_default();
+                #(#bindings)*
+
+                let result = self.#fn_name(#(#call_args),*).await
+                    .map_err(turbomcp::ServerError::from)?;
+                Ok(turbomcp::IntoResourceResult::into_resource_result(result, &uri, Self::#mime_type_fn_name()))
+            })
+        }
     };
 
     TokenStream::from(expanded)
@@ -111,11 +166,7 @@ fn parse_resource_args(
 ) -> Result<ResourceConfig, String> {
     if args.is_empty() {
         // #[resource] - simplest usage, function name becomes resource name
-        return Ok(ResourceConfig {
-            name: None,
-            uri_template: None,
-            tags: vec![],
-        });
+        return Ok(ResourceConfig::default());
     }
 
     let args: proc_macro2::TokenStream = args.into();
@@ -123,24 +174,27 @@ fn parse_resource_args(
     // First, try parsing as a simple string literal: #[resource("uri_template")]
     if let Ok(lit_str) = syn::parse2::<syn::LitStr>(args.clone()) {
         return Ok(ResourceConfig {
-            name: None,
             uri_template: Some(lit_str.value()),
-            tags: vec![],
+            ..ResourceConfig::default()
         });
     }
 
-    // Next, try parsing as structured arguments: #[resource(uri = "...", name = "...", tags = [...])]
+    // Next, try parsing as structured arguments: #[resource(uri = "...", name = "...", tags = [...])],
+    // optionally led by the URI template: #[resource("uri_template", mime_type = "...")]
     let parsed_args = match syn::parse2::<ResourceArgs>(args) {
         Ok(args) => args,
         Err(e) => {
             return Err(format!(
-                "Invalid resource macro arguments. Use:\n  #[resource] for default\n  #[resource(\"uri_template\")] for simple URI\n  #[resource(uri = \"...\", name = \"...\", tags = [...])] for advanced\nError: {}",
+                "Invalid resource macro arguments. Use:\n  #[resource] for default\n  #[resource(\"uri_template\")] for simple URI\n  #[resource(uri = \"...\", name = \"...\", mime_type = \"...\", tags = [...])] for advanced\nError: {}",
                 e
             ));
         }
     };
 
-    let mut config = ResourceConfig::default();
+    let mut config = ResourceConfig {
+        uri_template: parsed_args.uri_template.as_ref().map(LitStr::value),
+        ..ResourceConfig::default()
+    };
 
     // Process each attribute with comprehensive validation
     for meta in &parsed_args.items {
@@ -180,9 +234,29 @@ fn parse_resource_args(
                             );
                         }
                     }
+                    "mime_type" => {
+                        let mime_type = match &name_value.value {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: Lit::Str(lit_str),
+                                ..
+                            }) => lit_str.value(),
+                            _ => {
+                                return Err(
+                                    "Resource MIME type must be a string literal".to_string()
+                                );
+                            }
+                        };
+                        if !is_mime_type(&mime_type) {
+                            return Err(format!(
+                                "`{mime_type}` is not a MIME type; expected `type/subtype`, \
+                                 such as `application/json`"
+                            ));
+                        }
+                        config.mime_type = Some(mime_type);
+                    }
                     _ => {
                         return Err(format!(
-                            "Unknown resource attribute: {}. Supported: name, uri, tags",
+                            "Unknown resource attribute: {}. Supported: name, uri, mime_type, tags",
                             attr_name
                         ));
                     }
@@ -237,3 +311,70 @@ fn parse_resource_args(
 
     Ok(config)
 }
+
+/// Whether `value` has the `type/subtype` shape of a MIME type
+fn is_mime_type(value: &str) -> bool {
+    value.split_once('/').is_some_and(|(kind, subtype)| {
+        !kind.is_empty()
+            && !subtype.is_empty()
+            && !value.contains(char::is_whitespace)
+            && !subtype.contains('/')
+    })
+}
+
+/// Bind the method's parameters from a read URI: each is named after a template
+/// variable, parsed from its value, or is `uri`, the whole URI
+fn bind_uri_parameters(
+    sig: &Signature,
+    uri_template: &str,
+) -> syn::Result<(Vec<TokenStream2>, Vec<syn::Ident>)> {
+    let variables = turbomcp_protocol::template::placeholders(uri_template).map_err(|e| {
+        syn::Error::new_spanned(
+            &sig.ident,
+            format!("invalid resource URI template `{uri_template}`: {e}"),
+        )
+    })?;
+
+    let mut bindings = Vec::new();
+    let mut call_args = Vec::new();
+    for input in &sig.inputs {
+        let FnArg::Typed(pat_type) = input else {
+            continue;
+        };
+        let Pat::Ident(pat_ident) = &*pat_type.pat else {
+            return Err(syn::Error::new_spanned(
+                &pat_type.pat,
+                "resource parameters must be plain identifiers",
+            ));
+        };
+        let ident = &pat_ident.ident;
+        let ty = &pat_type.ty;
+        let name = ident.to_string();
+        let variable = name.trim_start_matches('_');
+
+        let binding = if variables.contains(&variable) {
+            quote! {
+                let #ident: #ty = params
+                    .get(#variable)
+                    .map(String::as_str)
+                    .unwrap_or_default()
+                    .parse()
+                    .map_err(|e| turbomcp::ServerError::invalid_params(
+                        format!("Invalid `{}` in resource URI {}: {}", #variable, uri, e)
+                    ))?;
+            }
+        } else if variable == "uri" {
+            quote! { let #ident: #ty = uri.clone().into(); }
+        } else {
+            return Err(syn::Error::new_spanned(
+                ident,
+                format!(
+                    "resource parameter `{name}` is neither `uri` nor a variable of `{uri_template}`"
+                ),
+            ));
+        };
+        bindings.push(binding);
+        call_args.push(ident.clone());
+    }
+    Ok((bindings, call_args))
+}
@@ -1,7 +1,63 @@
 //! Schema generation utilities
 
 use quote::quote;
-use syn::Type;
+use syn::{GenericArgument, PathArguments, Type};
+
+/// Check that a meaningful JSON schema can be generated for a tool parameter
+///
+/// Types with no JSON form, or whose shape is only known where the tool is
+/// called (the method's own type parameters), would otherwise be advertised
+/// with a schema that tells clients nothing. The error names the parameter
+/// and is spanned at the offending type, including nested ones such as the
+/// `&str` in `Option<&str>`.
+pub fn check_schema_support(
+    name: &str,
+    ty: &Type,
+    generics: &[&syn::Ident],
+) -> Result<(), syn::Error> {
+    let unsupported = |reason: &str| {
+        Err(syn::Error::new_spanned(
+            ty,
+            format!("cannot generate a JSON schema for parameter `{name}`: {reason}"),
+        ))
+    };
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(ident) = type_path.path.get_ident()
+                && generics.contains(&ident)
+            {
+                return unsupported(
+                    "generic type parameters have no fixed schema; use a concrete type",
+                );
+            }
+            for segment in &type_path.path.segments {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(inner) = arg {
+                            check_schema_support(name, inner, generics)?;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }
+        Type::Paren(inner) => check_schema_support(name, &inner.elem, generics),
+        Type::Group(inner) => check_schema_support(name, &inner.elem, generics),
+        Type::Reference(_) => unsupported(
+            "borrowed types cannot be deserialized from tool arguments; use an owned type such as `String` or `Vec<T>`",
+        ),
+        Type::Array(_) | Type::Slice(_) => {
+            unsupported("arrays and slices have no generated schema; use `Vec<T>`")
+        }
+        Type::Tuple(_) => {
+            unsupported("tuples have no generated schema; use a struct with named fields")
+        }
+        Type::ImplTrait(_) | Type::TraitObject(_) => {
+            unsupported("trait types have no schema; use a concrete type")
+        }
+        _ => unsupported("this type cannot be described by a JSON schema"),
+    }
+}
 
 /// Generate JSON schema for a Rust type
 #[allow(dead_code)]
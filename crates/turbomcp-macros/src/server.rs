@@ -2,6 +2,7 @@
 //!
 //! This macro provides ergonomic server creation by:
 //! - Automatically discovering and registering #[tool], #[resource], and #[prompt] methods
+//! - Registering #[completion] methods for `completion/complete` requests
 //! - Generating a complete MCP server with zero boilerplate
 //! - Integrating seamlessly with the existing builder pattern for advanced use cases
 //! - Providing proper JSON schemas and Context injection
@@ -31,14 +32,67 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
     let mut server_name: Option<String> = None;
     let mut server_version: Option<String> = None;
     let mut server_description: Option<String> = None;
+    let mut tool_namespace: Option<String> = None;
 
     // Analyze impl block for #[tool] methods
     let mut tool_methods = Vec::new();
     let mut tool_metadata_functions = Vec::new();
     let mut tool_handler_functions = Vec::new();
+    let mut tool_cache_functions = Vec::new();
+    let mut tool_max_input_functions = Vec::new();
+    let mut tool_output_limit_functions = Vec::new();
+    let mut tool_breaker_functions = Vec::new();
+    let mut tool_has_prewarm_functions = Vec::new();
+    let mut tool_prewarm_functions = Vec::new();
+    let mut tool_output_schema_functions = Vec::new();
+
+    // Analyze impl block for #[completion] methods
+    let mut completion_metadata_functions = Vec::new();
+    let mut completion_handler_functions = Vec::new();
+
+    // Analyze impl block for #[resource] methods
+    let mut resource_metadata_functions = Vec::new();
+    let mut resource_mime_type_functions = Vec::new();
+    let mut resource_handler_functions = Vec::new();
 
     for item in &input_impl.items {
         if let syn::ImplItem::Fn(method) = item {
+            if method
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("completion"))
+            {
+                let method_name = &method.sig.ident;
+                completion_metadata_functions.push(Ident::new(
+                    &format!("__turbomcp_completion_metadata_{method_name}"),
+                    Span::call_site(),
+                ));
+                completion_handler_functions.push(Ident::new(
+                    &format!("__turbomcp_completion_handler_{method_name}"),
+                    Span::call_site(),
+                ));
+            }
+
+            if method
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("resource"))
+            {
+                let method_name = &method.sig.ident;
+                resource_metadata_functions.push(Ident::new(
+                    &format!("__turbomcp_resource_metadata_{method_name}"),
+                    Span::call_site(),
+                ));
+                resource_mime_type_functions.push(Ident::new(
+                    &format!("__turbomcp_resource_mime_type_{method_name}"),
+                    Span::call_site(),
+                ));
+                resource_handler_functions.push(Ident::new(
+                    &format!("__turbomcp_resource_handler_{method_name}"),
+                    Span::call_site(),
+                ));
+            }
+
             // Check if method has #[tool] attribute
             for attr in &method.attrs {
                 if attr.path().is_ident("tool") {
@@ -51,7 +105,42 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                         &format!("__turbomcp_tool_handler_{method_name}"),
                         Span::call_site(),
                     );
+                    let cache_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_cache_ttl_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let max_input_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_max_input_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let output_limit_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_output_limit_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let breaker_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_circuit_breaker_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let has_prewarm_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_has_prewarm_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let prewarm_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_prewarm_{method_name}"),
+                        Span::call_site(),
+                    );
+                    let output_schema_fn_name = Ident::new(
+                        &format!("__turbomcp_tool_output_schema_{method_name}"),
+                        Span::call_site(),
+                    );
                     tool_methods.push(method_name.clone());
+                    tool_output_schema_functions.push(output_schema_fn_name);
+                    tool_has_prewarm_functions.push(has_prewarm_fn_name);
+                    tool_prewarm_functions.push(prewarm_fn_name);
+                    tool_breaker_functions.push(breaker_fn_name);
+                    tool_cache_functions.push(cache_fn_name);
+                    tool_max_input_functions.push(max_input_fn_name);
+                    tool_output_limit_functions.push(output_limit_fn_name);
                     tool_metadata_functions.push(metadata_fn_name);
                     tool_handler_functions.push(handler_fn_name);
                     break;
@@ -71,12 +160,35 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                     "name" => server_name = Some(val),
                     "version" => server_version = Some(val),
                     "description" => server_description = Some(val),
+                    "namespace" => tool_namespace = Some(val),
                     _ => {}
                 }
             }
         }
     }
 
+    if let Some(namespace) = &tool_namespace
+        && !is_valid_namespace(namespace)
+    {
+        return syn::Error::new(
+            Span::call_site(),
+            format!(
+                "invalid tool namespace `{namespace}`: use letters, digits, `_`, `-` and \
+                 inner `.` separators"
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+    let qualified_tool_name = match &tool_namespace {
+        Some(namespace) => quote! { format!("{}.{}", #namespace, tool) },
+        None => quote! { tool.to_string() },
+    };
+    let namespace_value = match &tool_namespace {
+        Some(namespace) => quote! { Some(#namespace) },
+        None => quote! { None },
+    };
+
     // Generate metadata function for testing and runtime
     let metadata_fn_name = Ident::new(
         &format!("__turbomcp_server_metadata_{struct_name}"),
@@ -105,6 +217,17 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                 (#name_value, #version_value, #description_value)
             }
 
+            /// Namespace prefixed to every tool name, from `#[server(namespace = ...)]`
+            pub fn tool_namespace() -> Option<&'static str> {
+                #namespace_value
+            }
+
+            /// Name a `#[tool]` method is listed and routed under
+            #[doc(hidden)]
+            pub fn __turbomcp_qualified_tool_name(tool: &str) -> String {
+                #qualified_tool_name
+            }
+
             /// Initialize context factory for this server
             fn create_context_factory() -> turbomcp::ContextFactory {
                 use turbomcp::{ContextFactory, ContextFactoryConfig, Container};
@@ -123,7 +246,7 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                     {
                         let (name, description, schema) = Self::#tool_metadata_functions();
                         tools.push((
-                            name.to_string(),
+                            Self::__turbomcp_qualified_tool_name(name),
                             description.to_string(),
                             schema
                         ));
@@ -212,8 +335,9 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                     {
                         let instance = server_instance.clone();
                         let (tool_name, tool_description, schema) = Self::#tool_metadata_functions();
+                        let tool_name = Self::__turbomcp_qualified_tool_name(tool_name);
                         let tool_handler = utils::tool_with_schema(
-                            tool_name,
+                            &tool_name,
                             tool_description,
                             schema,
                             move |req: CallToolRequest, ctx: RequestContext| {
@@ -224,10 +348,93 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                                 }
                             }
                         );
+                        // Opt-in result caching from #[tool(cacheable, ttl = ...)]
+                        let tool_handler = match Self::#tool_cache_functions() {
+                            Some(ttl_secs) => tool_handler.with_cache_ttl(std::time::Duration::from_secs(ttl_secs)),
+                            None => tool_handler,
+                        };
+                        // Structured output schema derived from the tool's return type
+                        let tool_handler = match Self::#tool_output_schema_functions() {
+                            Some(schema) => tool_handler.with_output_schema(schema),
+                            None => tool_handler,
+                        };
+                        // Per-tool argument size limit from #[tool(max_input = ...)]
+                        let tool_handler = match Self::#tool_max_input_functions() {
+                            Some(bytes) => tool_handler.with_max_input_size(bytes),
+                            None => tool_handler,
+                        };
+                        // Per-tool result size limit from #[tool(max_output = ...)]
+                        let tool_handler = match Self::#tool_output_limit_functions() {
+                            Some(limit) => tool_handler.with_output_limit(limit),
+                            None => tool_handler,
+                        };
+                        // Default circuit breaker from #[tool(circuit_breaker)]
+                        let tool_handler = if Self::#tool_breaker_functions() {
+                            tool_handler.with_circuit_breaker(Default::default())
+                        } else {
+                            tool_handler
+                        };
+                        // Boot-time hook from #[tool(prewarm = "...")]
+                        let tool_handler = if Self::#tool_has_prewarm_functions() {
+                            let instance = server_instance.clone();
+                            tool_handler.with_prewarm(move || {
+                                let instance = instance.clone();
+                                async move { instance.#tool_prewarm_functions().await }
+                            })
+                        } else {
+                            tool_handler
+                        };
                         builder = builder.tool(tool_name, tool_handler)?;
                     }
                 )*
 
+                // Completion handlers from #[completion] methods
+                #(
+                    {
+                        let instance = server_instance.clone();
+                        let (reference, argument) = Self::#completion_metadata_functions();
+                        let completion_handler = turbomcp::handlers::FunctionCompletionHandler::new(
+                            reference,
+                            argument,
+                            move |req: turbomcp::CompleteRequest, ctx: RequestContext| {
+                                let instance = instance.clone();
+                                async move {
+                                    instance.#completion_handler_functions(req, ctx).await
+                                }
+                            }
+                        );
+                        builder = builder.completion(completion_handler)?;
+                    }
+                )*
+
+                // Resource handlers from #[resource] methods
+                #(
+                    {
+                        let instance = server_instance.clone();
+                        let (resource_name, uri_template, _tags) = Self::#resource_metadata_functions();
+                        let resource = turbomcp::Resource {
+                            name: resource_name.to_string(),
+                            title: Some(resource_name.to_string()),
+                            uri: uri_template.to_string(),
+                            description: None,
+                            mime_type: Self::#resource_mime_type_functions().map(str::to_string),
+                            annotations: None,
+                            size: None,
+                            meta: None,
+                        };
+                        let resource_handler = turbomcp::handlers::FunctionResourceHandler::new(
+                            resource,
+                            move |req: turbomcp::ReadResourceRequest, ctx: RequestContext| {
+                                let instance = instance.clone();
+                                async move {
+                                    instance.#resource_handler_functions(req, ctx).await
+                                }
+                            }
+                        );
+                        builder = builder.resource(resource_name, resource_handler)?;
+                    }
+                )*
+
                 // If no tools discovered, provide helpful example
                 if Self::discover_tools().is_empty() {
                     builder = builder.tool(
@@ -243,6 +450,7 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
                                         meta: None,
                                     })],
                                     is_error: None,
+                                    structured_content: None,
                                 })
                             }
                         )
@@ -262,7 +470,8 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
             /// Test a tool call directly for testing
             ///
             /// This function enables direct testing of tool handlers without requiring
-            /// full server initialization or transport layer setup.
+            /// full server initialization or transport layer setup. Tools are named as
+            /// in `tools/list`, including any namespace.
             pub async fn test_tool_call(
                 &self,
                 tool_name: &str,
@@ -294,7 +503,7 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
 
                 // Find and call the appropriate handler
                 #(
-                    if tool_name == stringify!(#tool_methods) {
+                    if tool_name == Self::__turbomcp_qualified_tool_name(stringify!(#tool_methods)) {
                         return self.#tool_handler_functions(request, ctx).await;
                     }
                 )*
@@ -312,3 +521,14 @@ pub fn generate_server_impl(args: TokenStream, input_impl: ItemImpl) -> TokenStr
 
     TokenStream::from(expanded)
 }
+
+/// Whether `namespace` can prefix tool names: non-empty segments of letters,
+/// digits, `_` or `-`, separated by `.`
+fn is_valid_namespace(namespace: &str) -> bool {
+    namespace.split('.').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    })
+}
@@ -3,39 +3,59 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{FnArg, ItemFn, Pat, PatType, Signature, Type, parse_macro_input};
+use syn::{FnArg, ItemFn, Pat, PatType, ReturnType, Signature, Type, parse_macro_input};
 
 /// Generate tool implementation with auto-discovery
 pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemFn);
 
-    // Argument parsing - extract description
-    let raw_args = args.to_string();
-    let description = if raw_args.is_empty() {
-        format!("Tool: {}", input.sig.ident)
-    } else {
-        // Extract description from various formats
-        if let Some(desc_pos) = raw_args.find("description=") {
-            let after_eq = &raw_args[desc_pos + 12..];
-            if let Some(stripped) = after_eq.strip_prefix('"') {
-                if let Some(end) = stripped.find('"') {
-                    stripped[..end].to_string()
-                } else {
-                    raw_args.trim().trim_matches('"').to_string()
-                }
-            } else {
-                raw_args.trim().trim_matches('"').to_string()
-            }
-        } else {
-            // Assume the whole thing is a description
-            raw_args.trim().trim_matches('"').to_string()
+    // Argument parsing - extract description and caching options
+    let args = TokenStream2::from(args);
+    if let Err(err) = check_tool_keys(&args) {
+        return err.to_compile_error().into();
+    }
+    let tool_args = match parse_tool_args(&args.to_string()) {
+        Ok(tool_args) => tool_args,
+        Err(message) => {
+            return syn::Error::new(proc_macro2::Span::call_site(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let description = tool_args
+        .description
+        .unwrap_or_else(|| format!("Tool: {}", input.sig.ident));
+    let cache_ttl = match tool_args.cache_ttl_secs {
+        Some(secs) => quote! { Some(#secs) },
+        None => quote! { None },
+    };
+    let max_input = match tool_args.max_input_bytes {
+        Some(bytes) => quote! { Some(#bytes) },
+        None => quote! { None },
+    };
+    let output_limit = match tool_args.max_output_bytes {
+        Some(bytes) if tool_args.reject_oversized_output => {
+            quote! { Some(turbomcp::ToolOutputLimit::reject(#bytes)) }
         }
+        Some(bytes) => quote! { Some(turbomcp::ToolOutputLimit::truncate(#bytes)) },
+        None => quote! { None },
+    };
+    let circuit_breaker = tool_args.circuit_breaker;
+    let has_prewarm = tool_args.prewarm.is_some();
+    let prewarm_call = match &tool_args.prewarm {
+        Some(method) => quote! {
+            self.#method()
+                .await
+                .map_err(|e| turbomcp::ServerError::handler(e.to_string()))
+        },
+        None => quote! { Ok::<(), turbomcp::ServerError>(()) },
     };
 
     let fn_name = &input.sig.ident;
     let fn_vis = &input.vis;
     let fn_block = &input.block;
     let fn_sig = &input.sig;
+    let emitted_sig = strip_parameter_attributes(fn_sig);
     let tool_name = fn_name.to_string();
 
     // Generate metadata function that can be tested
@@ -44,13 +64,96 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
         proc_macro2::Span::call_site(),
     );
 
+    // Cache TTL function consulted by the server macro at registration time
+    let cache_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_cache_ttl_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    // Input size limit consulted by the server macro at registration time
+    let max_input_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_max_input_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    // Output size limit consulted by the server macro at registration time
+    let output_limit_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_output_limit_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    // Circuit breaker opt-in consulted by the server macro at registration time
+    let breaker_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_circuit_breaker_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    // Pre-warm hook consulted by the server macro at registration time
+    let has_prewarm_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_has_prewarm_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+    let prewarm_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_prewarm_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+
+    // Output schema consulted by the server macro at registration time
+    let output_schema_fn_name = syn::Ident::new(
+        &format!("__turbomcp_tool_output_schema_{fn_name}"),
+        proc_macro2::Span::call_site(),
+    );
+    let structured_output = structured_output_type(fn_sig);
+    let output_schema = match structured_output {
+        Some(ty) => quote! {
+            {
+                use turbomcp::{JsonSchemaOutput as _, NoOutputSchema as _};
+                (&&turbomcp::OutputSchemaProbe::<#ty>(::std::marker::PhantomData)).output_schema()
+            }
+        },
+        None => quote! { None },
+    };
+    if structured_output.is_none() && !tool_args.output_examples.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`output_example` requires a tool returning a struct",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let output_schema = match check_output_examples(&tool_args.output_examples) {
+        Ok(examples) => with_output_examples(output_schema, &examples),
+        Err(message) => {
+            return syn::Error::new(proc_macro2::Span::call_site(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let structured_content = if structured_output.is_some() {
+        quote! { value.ok().filter(::serde_json::Value::is_object) }
+    } else {
+        quote! { None }
+    };
+
     // Analyze function signature for schema generation
     let analysis = match analyze_function_signature(fn_sig) {
         Ok(analysis) => analysis,
         Err(err) => return err.to_compile_error().into(),
     };
 
-    let schema_generation = generate_schema(&analysis);
+    if let Err(err) = check_parameter_schemas(fn_sig, &analysis) {
+        return err.to_compile_error().into();
+    }
+
+    let examples = match check_examples(&tool_args.examples, &analysis) {
+        Ok(examples) => examples,
+        Err(message) => {
+            return syn::Error::new(proc_macro2::Span::call_site(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let schema_generation = with_examples(generate_schema(&analysis), &examples);
 
     // Generate parameter extraction code
     let param_extraction = generate_parameter_extraction(&analysis);
@@ -71,7 +174,7 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
     // Implementation that preserves function and enables auto-discovery
     let expanded = quote! {
         // Keep original function unchanged
-        #fn_vis #fn_sig #fn_block
+        #fn_vis #emitted_sig #fn_block
 
         // Generate metadata function as an associated function so server macro can call it
         #[doc(hidden)]
@@ -80,6 +183,55 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
             (#tool_name, #description, #schema_generation)
         }
 
+        // Result cache TTL in seconds, if the tool opted in with `cacheable`
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #cache_fn_name() -> Option<u64> {
+            #cache_ttl
+        }
+
+        // Maximum argument size in bytes, if set with `max_input`
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #max_input_fn_name() -> Option<usize> {
+            #max_input
+        }
+
+        // Result size limit, if set with `max_output`
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #output_limit_fn_name() -> Option<turbomcp::ToolOutputLimit> {
+            #output_limit
+        }
+
+        // Whether the tool opted into a circuit breaker with `circuit_breaker`
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #breaker_fn_name() -> bool {
+            #circuit_breaker
+        }
+
+        // Whether the tool named a boot-time hook with `prewarm = "method"`
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #has_prewarm_fn_name() -> bool {
+            #has_prewarm
+        }
+
+        // Run the tool's pre-warm hook, if it has one
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #prewarm_fn_name(&self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), turbomcp::ServerError>> + Send + '_>> {
+            Box::pin(async move { #prewarm_call })
+        }
+
+        // Schema of the tool's structured results, if it returns a struct
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        fn #output_schema_fn_name() -> Option<turbomcp::ToolOutputSchema> {
+            #output_schema
+        }
+
         // Generate public metadata function for testing capability
         /// Get metadata for this tool (name, description, JSON schema)
         ///
@@ -129,32 +281,18 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
 
                 // Call the actual method with extracted parameters (self is already available)
                 let result = self.#fn_name(#call_args).await
-                    .map_err(|e| match e {
-                        turbomcp::McpError::Server(server_err) => server_err,
-                        turbomcp::McpError::Tool(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Resource(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Prompt(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Protocol(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Context(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Unauthorized(msg) => turbomcp::ServerError::authorization(msg),
-                        turbomcp::McpError::Network(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::InvalidInput(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Schema(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Transport(msg) => turbomcp::ServerError::handler(msg),
-                        turbomcp::McpError::Serialization(e) => turbomcp::ServerError::from(e),
-                        turbomcp::McpError::Internal(msg) => turbomcp::ServerError::Internal(msg),
-                        turbomcp::McpError::InvalidRequest(msg) => turbomcp::ServerError::handler(msg),
-                    })?;
+                    .map_err(turbomcp::ServerError::from)?;
 
                 // Convert result to CallToolResult - properly serialize the result
-                let text = match ::serde_json::to_value(&result) {
+                let value = ::serde_json::to_value(&result);
+                let text = match &value {
                     Ok(val) if val.is_string() => {
                         // If result is already a string, use it directly
                         val.as_str().unwrap_or("").to_string()
                     }
                     Ok(val) => {
                         // For other types, use JSON representation
-                        ::serde_json::to_string(&val).unwrap_or_else(|_| format!("{:?}", result))
+                        ::serde_json::to_string(val).unwrap_or_else(|_| format!("{:?}", result))
                     }
                     Err(_) => {
                         // Fallback to Debug (Display not guaranteed for all types)
@@ -169,6 +307,7 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
                         meta: None,
                     })],
                     is_error: Some(false),  // Explicitly mark as success
+                    structured_content: #structured_content,
                 })
             })
         }
@@ -177,6 +316,319 @@ pub fn generate_tool_impl(args: TokenStream, input: TokenStream) -> TokenStream
     TokenStream::from(expanded)
 }
 
+/// Default result cache TTL for `#[tool(cacheable)]` without an explicit `ttl`
+const DEFAULT_CACHE_TTL_SECS: u64 = 300;
+
+/// Options parsed from `#[tool(...)]` arguments
+struct ToolArgs {
+    description: Option<String>,
+    cache_ttl_secs: Option<u64>,
+    max_input_bytes: Option<usize>,
+    max_output_bytes: Option<usize>,
+    reject_oversized_output: bool,
+    circuit_breaker: bool,
+    prewarm: Option<syn::Ident>,
+    examples: Vec<String>,
+    output_examples: Vec<String>,
+}
+
+/// Keys accepted as `key = value` in `#[tool(...)]`
+const TOOL_KEYS: &[&str] = &[
+    "description",
+    "ttl",
+    "max_input",
+    "max_output",
+    "on_oversized_output",
+    "prewarm",
+    "example",
+    "output_example",
+];
+
+/// Reject `key = value` arguments whose key `#[tool]` does not know
+///
+/// Checked on tokens rather than in [`parse_tool_args`] so the error points
+/// at the misspelled key.
+fn check_tool_keys(args: &TokenStream2) -> syn::Result<()> {
+    let mut tokens = args.clone().into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        let proc_macro2::TokenTree::Ident(key) = token else {
+            continue;
+        };
+        let assigned = matches!(
+            tokens.peek(),
+            Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == '='
+        );
+        if assigned && !TOOL_KEYS.iter().any(|known| key == known) {
+            let expected = TOOL_KEYS
+                .iter()
+                .map(|known| format!("`{known}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(syn::Error::new(
+                key.span(),
+                format!("unsupported `#[tool]` key; expected one of {expected}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parse `#[tool(...)]` arguments
+///
+/// Accepts a bare description string or `description = "..."`, plus the
+/// optional `cacheable` and `circuit_breaker` flags, `ttl = <seconds>`,
+/// `max_input = <bytes>`, `max_output = <bytes>`, `on_oversized_output =
+/// "truncate" | "reject"`, `prewarm = "<method>"` and any number of
+/// `example = "<json arguments>"` and `output_example = "<json result>"`.
+fn parse_tool_args(raw_args: &str) -> Result<ToolArgs, String> {
+    let mut description = None;
+    let mut cacheable = false;
+    let mut ttl = None;
+    let mut max_input = None;
+    let mut max_output = None;
+    let mut on_oversized_output = None;
+    let mut circuit_breaker = false;
+    let mut prewarm = None;
+    let mut examples = Vec::new();
+    let mut output_examples = Vec::new();
+
+    for part in split_top_level(raw_args) {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        match part.split_once('=') {
+            Some((key, value)) if key.trim() == "description" => {
+                description = Some(value.trim().trim_matches('"').to_string());
+            }
+            Some((key, value)) if key.trim() == "ttl" => {
+                let secs = value.trim().parse::<u64>().map_err(|_| {
+                    format!("`ttl` must be a number of seconds, got `{}`", value.trim())
+                })?;
+                ttl = Some(secs);
+            }
+            Some((key, value)) if key.trim() == "max_input" => {
+                let bytes = value.trim().parse::<usize>().map_err(|_| {
+                    format!(
+                        "`max_input` must be a number of bytes, got `{}`",
+                        value.trim()
+                    )
+                })?;
+                max_input = Some(bytes);
+            }
+            Some((key, value)) if key.trim() == "max_output" => {
+                let bytes = value.trim().parse::<usize>().map_err(|_| {
+                    format!(
+                        "`max_output` must be a number of bytes, got `{}`",
+                        value.trim()
+                    )
+                })?;
+                max_output = Some(bytes);
+            }
+            Some((key, value)) if key.trim() == "on_oversized_output" => {
+                let reject = match value.trim().trim_matches('"') {
+                    "truncate" => false,
+                    "reject" => true,
+                    other => {
+                        return Err(format!(
+                            "`on_oversized_output` must be \"truncate\" or \"reject\", got `{other}`"
+                        ));
+                    }
+                };
+                on_oversized_output = Some(reject);
+            }
+            Some((key, value)) if key.trim() == "prewarm" => {
+                let method = value.trim().trim_matches('"');
+                let ident = syn::parse_str::<syn::Ident>(method)
+                    .map_err(|_| format!("`prewarm` must name a method, got `{}`", value.trim()))?;
+                prewarm = Some(ident);
+            }
+            Some((key, value)) if key.trim() == "example" => {
+                let literal = syn::parse_str::<syn::LitStr>(value.trim()).map_err(|_| {
+                    format!(
+                        "`example` must be a string of JSON arguments, got `{}`",
+                        value.trim()
+                    )
+                })?;
+                examples.push(literal.value());
+            }
+            Some((key, value)) if key.trim() == "output_example" => {
+                let literal = syn::parse_str::<syn::LitStr>(value.trim()).map_err(|_| {
+                    format!(
+                        "`output_example` must be a string of a JSON result, got `{}`",
+                        value.trim()
+                    )
+                })?;
+                output_examples.push(literal.value());
+            }
+            _ if part == "cacheable" => cacheable = true,
+            _ if part == "circuit_breaker" => circuit_breaker = true,
+            _ => description = Some(part.trim_matches('"').to_string()),
+        }
+    }
+
+    if ttl.is_some() && !cacheable {
+        return Err("`ttl` requires `cacheable`".to_string());
+    }
+    if on_oversized_output.is_some() && max_output.is_none() {
+        return Err("`on_oversized_output` requires `max_output`".to_string());
+    }
+
+    Ok(ToolArgs {
+        description,
+        cache_ttl_secs: cacheable.then(|| ttl.unwrap_or(DEFAULT_CACHE_TTL_SECS)),
+        max_input_bytes: max_input,
+        max_output_bytes: max_output,
+        reject_oversized_output: on_oversized_output.unwrap_or(false),
+        circuit_breaker,
+        prewarm,
+        examples,
+        output_examples,
+    })
+}
+
+/// Check that each `example` is a JSON object of the tool's parameters
+///
+/// Examples are checked here, where a typo can be reported at compile time,
+/// and returned in their canonical JSON form.
+fn check_examples(examples: &[String], analysis: &FunctionAnalysis) -> Result<Vec<String>, String> {
+    examples
+        .iter()
+        .map(|example| {
+            let value: serde_json::Value = serde_json::from_str(example)
+                .map_err(|e| format!("`example` is not valid JSON: {e}"))?;
+            let Some(arguments) = value.as_object() else {
+                return Err(format!(
+                    "`example` must be a JSON object of tool arguments, got `{example}`"
+                ));
+            };
+            for name in arguments.keys() {
+                if !analysis.parameters.iter().any(|p| &p.name == name) {
+                    return Err(format!(
+                        "`example` sets `{name}`, which is not a parameter of this tool"
+                    ));
+                }
+            }
+            Ok(value.to_string())
+        })
+        .collect()
+}
+
+/// Check that each `output_example` is a JSON object, as structured results are
+fn check_output_examples(examples: &[String]) -> Result<Vec<String>, String> {
+    examples
+        .iter()
+        .map(|example| {
+            let value: serde_json::Value = serde_json::from_str(example)
+                .map_err(|e| format!("`output_example` is not valid JSON: {e}"))?;
+            if !value.is_object() {
+                return Err(format!(
+                    "`output_example` must be a JSON object of the tool's result, got `{example}`"
+                ));
+            }
+            Ok(value.to_string())
+        })
+        .collect()
+}
+
+/// Add checked examples to a generated output schema as its `examples`
+fn with_output_examples(schema: TokenStream2, examples: &[String]) -> TokenStream2 {
+    if examples.is_empty() {
+        return schema;
+    }
+    quote! {
+        (#schema).map(|mut schema: turbomcp::ToolOutputSchema| {
+            schema.examples = Some(vec![
+                #( ::serde_json::from_str(#examples).expect("checked by #[tool]") ),*
+            ]);
+            schema
+        })
+    }
+}
+
+/// Add checked examples to a generated input schema as its `examples` field
+fn with_examples(schema: TokenStream2, examples: &[String]) -> TokenStream2 {
+    if examples.is_empty() {
+        return schema;
+    }
+    quote! {
+        {
+            let mut schema: ::serde_json::Value = #schema;
+            schema["examples"] = ::serde_json::Value::Array(vec![
+                #( ::serde_json::from_str(#examples).expect("checked by #[tool]") ),*
+            ]);
+            schema
+        }
+    }
+}
+
+/// Add a parameter's description to its generated schema
+fn with_description(schema: TokenStream2, description: Option<&str>) -> TokenStream2 {
+    let Some(description) = description else {
+        return schema;
+    };
+    quote! {
+        {
+            let mut schema: ::serde_json::Value = #schema;
+            schema["description"] = ::serde_json::Value::String(#description.to_string());
+            schema
+        }
+    }
+}
+
+/// Split on commas that are not inside a string literal
+fn split_top_level(raw: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        // Raw strings such as r#"{"a": 1, "b": 2}"# are copied whole
+        let starts_raw = c == 'r'
+            && !in_string
+            && !current.ends_with(|p: char| p.is_alphanumeric() || p == '_')
+            && matches!(chars.peek(), Some('#' | '"'));
+        if starts_raw {
+            current.push(c);
+            let mut closing = String::from('"');
+            while let Some(hash) = chars.next_if_eq(&'#') {
+                closing.push(hash);
+                current.push(hash);
+            }
+            if let Some(quote) = chars.next_if_eq(&'"') {
+                current.push(quote);
+                let contents = current.len();
+                for c in chars.by_ref() {
+                    current.push(c);
+                    if current[contents..].ends_with(&closing) {
+                        break;
+                    }
+                }
+            }
+            continue;
+        }
+        match c {
+            '\\' if in_string => {
+                escaped = !escaped;
+                current.push(c);
+                continue;
+            }
+            '"' if !escaped => in_string = !in_string,
+            ',' if !in_string => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        escaped = false;
+        current.push(c);
+    }
+    parts.push(current);
+    parts
+}
+
 /// Analysis of function signature
 struct FunctionAnalysis {
     parameters: Vec<ParameterInfo>,
@@ -192,6 +644,7 @@ struct FunctionAnalysis {
 struct ParameterInfo {
     name: String,
     ty: Type,
+    description: Option<String>,
     _is_context: bool,
 }
 
@@ -211,7 +664,7 @@ fn analyze_function_signature(sig: &Signature) -> Result<FunctionAnalysis, syn::
                 has_self = true;
                 continue;
             }
-            FnArg::Typed(PatType { pat, ty, .. }) => {
+            FnArg::Typed(PatType { attrs, pat, ty, .. }) => {
                 if let Pat::Ident(pat_ident) = pat.as_ref() {
                     let param_name = &pat_ident.ident;
 
@@ -236,6 +689,7 @@ fn analyze_function_signature(sig: &Signature) -> Result<FunctionAnalysis, syn::
                         parameters.push(ParameterInfo {
                             name: param_name.to_string(),
                             ty: (**ty).clone(),
+                            description: parameter_description(attrs)?,
                             _is_context: false,
                         });
 
@@ -259,6 +713,75 @@ fn analyze_function_signature(sig: &Signature) -> Result<FunctionAnalysis, syn::
     })
 }
 
+/// Description of a parameter from `#[param(description = "...")]`, or
+/// failing that from its doc comment
+fn parameter_description(attrs: &[syn::Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut description = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("param")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("description") {
+                description = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `#[param]` key; expected `description = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(description.or_else(|| doc_text(attrs)))
+}
+
+/// Text of a doc comment, with wrapped lines joined by spaces and
+/// paragraphs separated by a blank line
+fn doc_text(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if let syn::Meta::NameValue(doc) = &attr.meta
+            && doc.path.is_ident("doc")
+            && let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(text),
+                ..
+            }) = &doc.value
+        {
+            lines.extend(text.value().lines().map(|line| line.trim().to_string()));
+        }
+    }
+    let paragraphs: Vec<String> = lines
+        .split(String::is_empty)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| paragraph.join(" "))
+        .collect();
+    (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"))
+}
+
+/// The signature without `#[param]` and doc attributes on its parameters,
+/// which the compiler does not accept there
+fn strip_parameter_attributes(sig: &Signature) -> Signature {
+    let mut sig = sig.clone();
+    for input in &mut sig.inputs {
+        if let FnArg::Typed(param) = input {
+            param
+                .attrs
+                .retain(|attr| !attr.path().is_ident("param") && !attr.path().is_ident("doc"));
+        }
+    }
+    sig
+}
+
+/// Check every parameter gets a meaningful schema, reporting all failures at once
+fn check_parameter_schemas(sig: &Signature, analysis: &FunctionAnalysis) -> Result<(), syn::Error> {
+    let generics: Vec<&syn::Ident> = sig.generics.type_params().map(|p| &p.ident).collect();
+    let mut errors = analysis.parameters.iter().filter_map(|param| {
+        crate::schema::check_schema_support(&param.name, &param.ty, &generics).err()
+    });
+    match errors.next() {
+        Some(mut first) => {
+            errors.for_each(|err| first.combine(err));
+            Err(first)
+        }
+        None => Ok(()),
+    }
+}
+
 /// Generate parameter extraction code
 #[allow(dead_code)]
 fn generate_parameter_extraction(analysis: &FunctionAnalysis) -> TokenStream2 {
@@ -280,6 +803,7 @@ fn generate_parameter_extraction(analysis: &FunctionAnalysis) -> TokenStream2 {
         let param_name_str = &param.name;
         let param_name_ident = syn::Ident::new(&param.name, proc_macro2::Span::call_site());
         let param_ty = &param.ty;
+        let expected = expected_type_name(&param.ty);
 
         // Check if this is an optional parameter
         let is_optional = is_option_type(&param.ty);
@@ -290,8 +814,8 @@ fn generate_parameter_extraction(analysis: &FunctionAnalysis) -> TokenStream2 {
                 let #param_name_ident: #param_ty = if let Some(args) = arguments {
                     args.get(#param_name_str)
                         .map(|v| ::serde_json::from_value(v.clone())
-                            .map_err(|e| turbomcp::ServerError::handler(
-                                format!("Invalid parameter {}: {}", #param_name_str, e)
+                            .map_err(|e| turbomcp::ServerError::invalid_argument(
+                                #param_name_str, #expected, e
                             )))
                         .transpose()?
                         .flatten()
@@ -304,14 +828,14 @@ fn generate_parameter_extraction(analysis: &FunctionAnalysis) -> TokenStream2 {
             extraction_code.extend(quote! {
                 let #param_name_ident = arguments
                     .as_ref()
-                    .ok_or_else(|| turbomcp::ServerError::handler("Missing arguments"))?
+                    .ok_or_else(|| turbomcp::ServerError::invalid_params("Missing arguments"))?
                     .get(#param_name_str)
-                    .ok_or_else(|| turbomcp::ServerError::handler(
+                    .ok_or_else(|| turbomcp::ServerError::invalid_params(
                         format!("Missing required parameter: {}", #param_name_str)
                     ))?;
                 let #param_name_ident: #param_ty = ::serde_json::from_value(#param_name_ident.clone())
-                    .map_err(|e| turbomcp::ServerError::handler(
-                        format!("Invalid parameter {}: {}", #param_name_str, e)
+                    .map_err(|e| turbomcp::ServerError::invalid_argument(
+                        #param_name_str, #expected, e
                     ))?;
             });
         }
@@ -320,6 +844,105 @@ fn generate_parameter_extraction(analysis: &FunctionAnalysis) -> TokenStream2 {
     extraction_code
 }
 
+/// Type named to clients whose argument does not convert, `T` for `Option<T>`
+fn expected_type_name(ty: &Type) -> String {
+    let inner = match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Option")
+            .and_then(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => args.args.first(),
+                _ => None,
+            })
+            .and_then(|arg| match arg {
+                syn::GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }),
+        _ => None,
+    };
+    let ty = inner.unwrap_or(ty);
+    quote!(#ty).to_string().replace(' ', "")
+}
+
+/// Types whose results are plain values rather than structured objects
+const UNSTRUCTURED_OUTPUTS: &[&str] = &[
+    "String",
+    "str",
+    "bool",
+    "char",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "f32",
+    "f64",
+    "Vec",
+    "VecDeque",
+    "HashSet",
+    "BTreeSet",
+    "HashMap",
+    "BTreeMap",
+    "Option",
+    "Value",
+    "Content",
+    "CallToolResult",
+];
+
+/// The type a tool's structured results take, if it returns one
+///
+/// Looks through `Result<T, _>`, `McpResult<T>` and the `Json<T>` wrapper
+/// for a named type that is not a scalar, collection or MCP content.
+/// Whether it gets an output schema is settled where the macro expands:
+/// only types implementing `JsonSchema` that describe an object do.
+fn structured_output_type(sig: &Signature) -> Option<&Type> {
+    let ReturnType::Type(_, ty) = &sig.output else {
+        return None;
+    };
+    let mut ty = ty.as_ref();
+    for wrapper in ["Result", "McpResult", "Json"] {
+        if let Some(inner) = first_type_argument(ty, wrapper) {
+            ty = inner;
+        }
+    }
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let ident = &type_path.path.segments.last()?.ident;
+    let is_generic = sig
+        .generics
+        .type_params()
+        .any(|param| &param.ident == ident);
+    (!is_generic && !UNSTRUCTURED_OUTPUTS.iter().any(|name| ident == name)).then_some(ty)
+}
+
+/// The first type argument of `ty` if it is a path ending in `wrapper<..>`
+fn first_type_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    })
+}
+
 /// Check if a type is Option<T>
 fn is_option_type(ty: &Type) -> bool {
     match ty {
@@ -355,7 +978,10 @@ fn generate_schema(analysis: &FunctionAnalysis) -> TokenStream2 {
 
     for p in &analysis.parameters {
         let key = syn::LitStr::new(&p.name, proc_macro2::Span::call_site());
-        let schema_ts = crate::schema::generate_json_schema(&p.ty);
+        let schema_ts = with_description(
+            crate::schema::generate_json_schema(&p.ty),
+            p.description.as_deref(),
+        );
         prop_entries.push((key.clone(), schema_ts));
 
         // Check if this parameter is required (non-Option type)
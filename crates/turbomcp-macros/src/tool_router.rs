@@ -68,6 +68,7 @@ pub fn generate_tool_router_impl(args: TokenStream, input: TokenStream) -> Token
                                     Ok(turbomcp::CallToolResult {
                                         content: vec![turbomcp::mcp_text!("Tool executed")],
                                         is_error: None,
+                                        structured_content: None,
                                     })
                                 })
                             },
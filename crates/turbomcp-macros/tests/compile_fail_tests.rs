@@ -0,0 +1,7 @@
+//! Compile-time diagnostics emitted by the macros for invalid input
+
+#[test]
+fn test_macro_compile_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
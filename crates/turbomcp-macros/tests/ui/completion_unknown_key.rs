@@ -0,0 +1,14 @@
+use turbomcp_macros::completion;
+
+struct Library;
+
+impl Library {
+    #[completion(prompts = "translate", argument = "language")]
+    async fn complete_language(&self, value: String) -> Result<Vec<String>, String> {
+        Ok(vec![value])
+    }
+}
+
+fn main() {
+    let _ = Library;
+}
@@ -0,0 +1,14 @@
+use turbomcp_macros::resource;
+
+struct Library;
+
+impl Library {
+    #[resource("books://{isbn")]
+    async fn book(&self, isbn: String) -> Result<String, String> {
+        Ok(isbn)
+    }
+}
+
+fn main() {
+    let _ = Library;
+}
@@ -0,0 +1,14 @@
+use turbomcp_macros::resource;
+
+struct Library;
+
+impl Library {
+    #[resource("books://{isbn}")]
+    async fn book(&self, isbn: String, edition: String) -> Result<String, String> {
+        Ok(format!("{isbn} {edition}"))
+    }
+}
+
+fn main() {
+    let _ = Library;
+}
@@ -0,0 +1,14 @@
+use turbomcp_macros::tool;
+
+struct Files;
+
+impl Files {
+    #[tool("Read a file")]
+    async fn read(&self, path: &str) -> Result<String, String> {
+        Ok(path.to_string())
+    }
+}
+
+fn main() {
+    let _ = Files;
+}
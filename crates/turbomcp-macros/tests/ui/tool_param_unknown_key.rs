@@ -0,0 +1,17 @@
+use turbomcp_macros::tool;
+
+struct Files;
+
+impl Files {
+    #[tool("Read a file")]
+    async fn read(
+        &self,
+        #[param(summary = "Path to read")] path: String,
+    ) -> Result<String, String> {
+        Ok(path)
+    }
+}
+
+fn main() {
+    let _ = Files;
+}
@@ -0,0 +1,14 @@
+use turbomcp_macros::tool;
+
+struct Calculator;
+
+impl Calculator {
+    #[tool(descripton = "Add two numbers")]
+    async fn add(&self, a: i64, b: i64) -> Result<i64, String> {
+        Ok(a + b)
+    }
+}
+
+fn main() {
+    let _ = Calculator;
+}
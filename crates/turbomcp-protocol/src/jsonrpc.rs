@@ -175,15 +175,19 @@ impl From<i32> for JsonRpcErrorCode {
 }
 
 /// JSON-RPC message type (union of request, response, notification)
+///
+/// Variants are tried in order when deserializing, so `Notification` must
+/// precede `Response`: every field of a response is optional, and a
+/// notification would otherwise parse as an empty response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum JsonRpcMessage {
     /// Request message
     Request(JsonRpcRequest),
-    /// Response message
-    Response(JsonRpcResponse),
     /// Notification message
     Notification(JsonRpcNotification),
+    /// Response message
+    Response(JsonRpcResponse),
     /// Batch of messages
     RequestBatch(JsonRpcBatch<JsonRpcRequest>),
     /// Batch of responses
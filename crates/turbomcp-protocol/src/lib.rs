@@ -39,6 +39,9 @@ pub use turbomcp_core::{Error, Result};
 // Core protocol modules
 pub mod capabilities;
 pub mod jsonrpc;
+pub mod notifications;
+pub mod patch;
+pub mod template;
 pub mod types;
 pub mod validation;
 pub mod versioning;
@@ -52,8 +55,14 @@ pub use types::{
     ClientNotification,
     // Core types
     ClientRequest,
+    // Completion
+    CompleteRequest,
+    CompleteResult,
+    Completion,
+    CompletionReference,
     // Content types
     Content,
+    ContentRange,
     // Sampling
     CreateMessageRequest,
     CreateMessageResult,
@@ -81,6 +90,9 @@ pub use types::{
     // Logging and progress
     LogLevel,
     LoggingNotification,
+    OperationRequest,
+    OperationState,
+    OperationStatus,
     ProgressNotification,
     ProgressToken,
     // Prompt types
@@ -122,6 +134,14 @@ pub use jsonrpc::{
 
 pub use capabilities::{CapabilityMatcher, CapabilityNegotiator, CapabilitySet};
 
+pub use notifications::{
+    DuplicateNotificationPolicy, NotificationDeduplicator, NotificationReorderBuffer,
+    NotificationSequencer, OrderingStats, SEQUENCE_META_KEY, UnknownNotificationHandler,
+    UnknownNotificationPolicy, notification_sequence,
+};
+
+pub use patch::{ResourcePatch, ResourcePatchError};
+
 pub use versioning::{VersionCompatibility, VersionManager, VersionRequirement};
 
 /// Current MCP protocol version
@@ -188,6 +208,12 @@ pub mod methods {
     /// Resource list changed notification
     pub const RESOURCE_LIST_CHANGED: &str = "notifications/resources/list_changed";
 
+    // List changes
+    /// Prompt list changed notification
+    pub const PROMPT_LIST_CHANGED: &str = "notifications/prompts/list_changed";
+    /// Tool list changed notification
+    pub const TOOL_LIST_CHANGED: &str = "notifications/tools/list_changed";
+
     // Logging
     /// Set logging level method
     pub const SET_LEVEL: &str = "logging/setLevel";
@@ -197,16 +223,54 @@ pub mod methods {
     // Progress
     /// Progress update notification
     pub const PROGRESS: &str = "notifications/progress";
+    /// Progress completion notification, sent once after the last update
+    pub const PROGRESS_COMPLETE: &str = "notifications/progress/complete";
+
+    // Cancellation
+    /// Request cancelled notification
+    pub const CANCELLED: &str = "notifications/cancelled";
+
+    // Shutdown
+    /// Server shutting down notification, sent before the connection closes
+    pub const SERVER_SHUTDOWN: &str = "notifications/server/shutdown";
+
+    // Resumable operations
+    /// Status of a resumable operation, by the id the client gave it
+    pub const GET_OPERATION: &str = "operations/get";
+    /// Re-attach to a resumable operation's progress from a new connection
+    pub const RESUME_OPERATION: &str = "operations/resume";
 
     // Sampling
     /// Create sampling message method
     pub const CREATE_MESSAGE: &str = "sampling/createMessage";
 
+    // Completion
+    /// Complete a prompt or resource argument method
+    pub const COMPLETE: &str = "completion/complete";
+
     // Roots
     /// List directory roots method
     pub const LIST_ROOTS: &str = "roots/list";
     /// Roots list changed notification
     pub const ROOTS_LIST_CHANGED: &str = "notifications/roots/list_changed";
+
+    /// Notifications a server may receive from a client
+    pub const CLIENT_NOTIFICATIONS: &[&str] =
+        &[INITIALIZED, PROGRESS, CANCELLED, ROOTS_LIST_CHANGED];
+
+    /// Notifications a client may receive from a server
+    pub const SERVER_NOTIFICATIONS: &[&str] = &[
+        LOG_MESSAGE,
+        RESOURCE_UPDATED,
+        RESOURCE_LIST_CHANGED,
+        PROGRESS,
+        PROGRESS_COMPLETE,
+        CANCELLED,
+        SERVER_SHUTDOWN,
+        PROMPT_LIST_CHANGED,
+        TOOL_LIST_CHANGED,
+        ROOTS_LIST_CHANGED,
+    ];
 }
 
 /// Protocol error codes (JSON-RPC standard + MCP extensions)
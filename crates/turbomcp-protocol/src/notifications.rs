@@ -0,0 +1,380 @@
+//! # Unknown Notification Handling
+//!
+//! JSON-RPC peers must tolerate notifications they do not understand, and as
+//! the MCP specification evolves, newer peers will send methods that older
+//! ones have never heard of. [`UnknownNotificationPolicy`] decides what a
+//! client or server does with such a notification: drop it, log it, or hand
+//! it to a catch-all handler.
+//!
+//! # Ordering
+//!
+//! A single connection delivers messages in the order they were sent, but
+//! pooled, multiplexed or reconnecting transports may not, and notifications
+//! such as progress updates only make sense in sequence. A sender stamps each
+//! notification of a session with a consecutive number under
+//! [`SEQUENCE_META_KEY`] in `params._meta` using a [`NotificationSequencer`],
+//! and the receiver passes them through a [`NotificationReorderBuffer`],
+//! which restores send order and reports gaps.
+//!
+//! # Deduplication
+//!
+//! A stream resumed after a reconnect, such as SSE with `Last-Event-ID`, may
+//! replay events the receiver already handled before the live stream catches
+//! up. When the transport reports an event id for each message, a
+//! [`NotificationDeduplicator`] remembers the most recent ids and applies a
+//! [`DuplicateNotificationPolicy`] to any notification it has seen before.
+//! It also tracks the last id seen, which is the one to resume from.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::jsonrpc::JsonRpcNotification;
+
+/// Catch-all handler invoked for unrecognized notifications
+pub type UnknownNotificationHandler = Arc<dyn Fn(&JsonRpcNotification) + Send + Sync>;
+
+/// What to do with a notification whose method is not recognized
+#[derive(Clone, Default)]
+pub enum UnknownNotificationPolicy {
+    /// Drop the notification silently (JSON-RPC forward compatibility)
+    #[default]
+    Ignore,
+    /// Log the notification method at `WARN` level, then drop it
+    Log,
+    /// Pass the notification to a handler
+    Handle(UnknownNotificationHandler),
+}
+
+impl UnknownNotificationPolicy {
+    /// Create a policy that passes unknown notifications to `handler`
+    pub fn handler<F>(handler: F) -> Self
+    where
+        F: Fn(&JsonRpcNotification) + Send + Sync + 'static,
+    {
+        Self::Handle(Arc::new(handler))
+    }
+
+    /// Apply the policy to an unrecognized notification
+    pub fn apply(&self, notification: &JsonRpcNotification) {
+        match self {
+            Self::Ignore => {}
+            Self::Log => tracing::warn!(
+                method = %notification.method,
+                "Received unknown notification"
+            ),
+            Self::Handle(handler) => handler(notification),
+        }
+    }
+}
+
+impl fmt::Debug for UnknownNotificationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ignore => f.write_str("Ignore"),
+            Self::Log => f.write_str("Log"),
+            Self::Handle(_) => f.write_str("Handle(<handler>)"),
+        }
+    }
+}
+
+/// Key in a notification's `params._meta` holding its sequence number
+pub const SEQUENCE_META_KEY: &str = "turbomcp/seq";
+
+/// Default number of out-of-order notifications held while waiting for a gap
+pub const DEFAULT_MAX_HELD_NOTIFICATIONS: usize = 32;
+
+/// Default time a gap in the sequence is waited on before it is given up as lost
+pub const DEFAULT_NOTIFICATION_GAP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Sequence number a sender stamped on `notification`, if any
+#[must_use]
+pub fn notification_sequence(notification: &JsonRpcNotification) -> Option<u64> {
+    notification
+        .params
+        .as_ref()?
+        .get("_meta")?
+        .get(SEQUENCE_META_KEY)?
+        .as_u64()
+}
+
+/// Stamps the notifications of one session with consecutive sequence numbers
+///
+/// Numbers start at zero. Use one sequencer per session so the receiver can
+/// tell a reordered notification from one that was lost.
+#[derive(Debug, Default)]
+pub struct NotificationSequencer {
+    next: AtomicU64,
+}
+
+impl NotificationSequencer {
+    /// Create a sequencer starting at zero
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next: AtomicU64::new(0),
+        }
+    }
+
+    /// Stamp `notification` with the next sequence number
+    ///
+    /// Returns the number, or `None` without using one if the params are not
+    /// a JSON object and so have nowhere to carry `_meta`.
+    pub fn stamp(&self, notification: &mut JsonRpcNotification) -> Option<u64> {
+        let params = notification
+            .params
+            .get_or_insert_with(|| Value::Object(serde_json::Map::new()))
+            .as_object_mut()?;
+        let meta = params
+            .entry("_meta")
+            .or_insert_with(|| Value::Object(serde_json::Map::new()))
+            .as_object_mut()?;
+        let sequence = self.next.fetch_add(1, Ordering::Relaxed);
+        meta.insert(SEQUENCE_META_KEY.to_string(), sequence.into());
+        Some(sequence)
+    }
+}
+
+/// Counters describing how sequenced notifications arrived
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderingStats {
+    /// Notifications that arrived early and were held back to restore order
+    pub reordered: u64,
+    /// Sequence numbers given up on as lost
+    pub missing: u64,
+    /// Notifications dropped for arriving after their number was passed
+    pub stale: u64,
+}
+
+/// Restores the send order of sequenced notifications
+///
+/// A notification that arrives ahead of its predecessors is held until they
+/// arrive. If more than the configured number are held, or the gap before
+/// them stays open longer than the gap timeout, the missing numbers are
+/// counted as lost and delivery resumes, so a dropped message cannot stall
+/// the session. Notifications without a sequence number pass straight
+/// through.
+///
+/// The timeout is checked as notifications arrive and whenever
+/// [`flush_expired`](Self::flush_expired) is called, which a receiver should
+/// do while the connection is idle.
+#[derive(Debug)]
+pub struct NotificationReorderBuffer {
+    next: u64,
+    held: BTreeMap<u64, JsonRpcNotification>,
+    max_held: usize,
+    gap_timeout: Duration,
+    /// When the current gap opened, while notifications are held
+    gap_opened: Option<Instant>,
+    stats: OrderingStats,
+}
+
+impl Default for NotificationReorderBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HELD_NOTIFICATIONS)
+    }
+}
+
+impl NotificationReorderBuffer {
+    /// Create a buffer holding at most `max_held` early notifications
+    #[must_use]
+    pub fn new(max_held: usize) -> Self {
+        Self {
+            next: 0,
+            held: BTreeMap::new(),
+            max_held,
+            gap_timeout: DEFAULT_NOTIFICATION_GAP_TIMEOUT,
+            gap_opened: None,
+            stats: OrderingStats::default(),
+        }
+    }
+
+    /// Give up on a gap once it has been open for `timeout`
+    #[must_use]
+    pub const fn with_gap_timeout(mut self, timeout: Duration) -> Self {
+        self.gap_timeout = timeout;
+        self
+    }
+
+    /// Accept a received notification, returning those now deliverable in order
+    pub fn push(&mut self, notification: JsonRpcNotification) -> Vec<JsonRpcNotification> {
+        let Some(sequence) = notification_sequence(&notification) else {
+            return vec![notification];
+        };
+        if sequence < self.next || self.held.contains_key(&sequence) {
+            self.stats.stale += 1;
+            return Vec::new();
+        }
+        if sequence > self.next {
+            self.stats.reordered += 1;
+        }
+        self.held.insert(sequence, notification);
+
+        if self.held.len() > self.max_held || self.gap_expired() {
+            self.skip_gap();
+        }
+        self.release()
+    }
+
+    /// Give up on a gap that has been open longer than the gap timeout,
+    /// returning the notifications held behind it
+    pub fn flush_expired(&mut self) -> Vec<JsonRpcNotification> {
+        if !self.gap_expired() {
+            return Vec::new();
+        }
+        self.skip_gap();
+        self.release()
+    }
+
+    /// When the open gap times out, while notifications are held behind one
+    ///
+    /// A receiver waiting for input can wake then to call
+    /// [`flush_expired`](Self::flush_expired).
+    #[must_use]
+    pub fn gap_deadline(&self) -> Option<Instant> {
+        self.gap_opened.map(|opened| opened + self.gap_timeout)
+    }
+
+    fn gap_expired(&self) -> bool {
+        self.gap_opened
+            .is_some_and(|opened| opened.elapsed() >= self.gap_timeout)
+    }
+
+    /// Count the numbers before the earliest held notification as lost
+    fn skip_gap(&mut self) {
+        if let Some(&earliest) = self.held.keys().next() {
+            self.stats.missing += earliest - self.next;
+            self.next = earliest;
+        }
+    }
+
+    /// Take the notifications now in order, timing any gap left behind them
+    fn release(&mut self) -> Vec<JsonRpcNotification> {
+        let mut ready = Vec::new();
+        while let Some(notification) = self.held.remove(&self.next) {
+            ready.push(notification);
+            self.next += 1;
+        }
+        if self.held.is_empty() {
+            self.gap_opened = None;
+        } else if !ready.is_empty() || self.gap_opened.is_none() {
+            self.gap_opened = Some(Instant::now());
+        }
+        ready
+    }
+
+    /// Sequence number expected next
+    #[must_use]
+    pub const fn next_sequence(&self) -> u64 {
+        self.next
+    }
+
+    /// How notifications have arrived so far
+    #[must_use]
+    pub const fn stats(&self) -> OrderingStats {
+        self.stats
+    }
+}
+
+/// Default number of recent event ids remembered for deduplication
+pub const DEFAULT_DEDUP_WINDOW: usize = 1024;
+
+/// What to do with a notification whose event id was already seen
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateNotificationPolicy {
+    /// Drop the duplicate silently
+    #[default]
+    Drop,
+    /// Log the duplicate's method and event id at `DEBUG` level, then drop it
+    Log,
+    /// Deliver duplicates again, turning deduplication off
+    Deliver,
+}
+
+/// Drops notifications redelivered under an event id already seen
+///
+/// Only the last `window` ids are remembered, so memory stays bounded on a
+/// long-lived stream; a replay reaching further back than that is delivered
+/// again. Notifications without an event id always pass.
+#[derive(Debug)]
+pub struct NotificationDeduplicator {
+    policy: DuplicateNotificationPolicy,
+    window: usize,
+    seen: HashSet<String>,
+    recent: VecDeque<String>,
+    last_event_id: Option<String>,
+    duplicates: u64,
+}
+
+impl Default for NotificationDeduplicator {
+    fn default() -> Self {
+        Self::new(DuplicateNotificationPolicy::default(), DEFAULT_DEDUP_WINDOW)
+    }
+}
+
+impl NotificationDeduplicator {
+    /// Create a deduplicator remembering the last `window` event ids
+    #[must_use]
+    pub fn new(policy: DuplicateNotificationPolicy, window: usize) -> Self {
+        Self {
+            policy,
+            window,
+            seen: HashSet::new(),
+            recent: VecDeque::new(),
+            last_event_id: None,
+            duplicates: 0,
+        }
+    }
+
+    /// Whether `notification`, received under `event_id`, should be delivered
+    pub fn accept(&mut self, event_id: Option<&str>, notification: &JsonRpcNotification) -> bool {
+        let Some(event_id) = event_id else {
+            return true;
+        };
+        if self.seen.contains(event_id) {
+            self.duplicates += 1;
+            match self.policy {
+                DuplicateNotificationPolicy::Drop => return false,
+                DuplicateNotificationPolicy::Log => {
+                    tracing::debug!(
+                        method = %notification.method,
+                        event_id,
+                        "Dropped duplicate notification"
+                    );
+                    return false;
+                }
+                DuplicateNotificationPolicy::Deliver => return true,
+            }
+        }
+
+        if self.window > 0 {
+            if self.recent.len() == self.window
+                && let Some(oldest) = self.recent.pop_front()
+            {
+                self.seen.remove(&oldest);
+            }
+            self.seen.insert(event_id.to_string());
+            self.recent.push_back(event_id.to_string());
+        }
+        self.last_event_id = Some(event_id.to_string());
+        true
+    }
+
+    /// The newest event id received, to resume the stream from
+    ///
+    /// Duplicates do not move it back to an older id.
+    #[must_use]
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// How many notifications arrived under an event id already seen
+    #[must_use]
+    pub const fn duplicates(&self) -> u64 {
+        self.duplicates
+    }
+}
@@ -0,0 +1,176 @@
+//! # Resource Patches
+//!
+//! A `notifications/resources/updated` normally only names the resource, and
+//! the client re-reads all of it. For resources that change often and a
+//! little at a time, such as a growing log or a live JSON document, the
+//! server may attach a [`ResourcePatch`] describing the change so a client
+//! holding the previous contents can update them in place.
+//!
+//! Patches are an optimisation, never a requirement:
+//!
+//! - Clients that do not know about patches ignore the extra field and
+//!   re-read the resource, exactly as for a plain update.
+//! - Clients that do apply patches fall back to a full read whenever
+//!   [`ResourcePatch::apply`] fails, for example because their copy has
+//!   missed an earlier update. Every patch names the version of the
+//!   contents it was made against (a length for appends, a
+//!   [`document_version`](ResourcePatch::document_version) for merge
+//!   patches), so a stale copy is detected rather than patched.
+//!
+//! On the wire the patch sits next to the URI:
+//!
+//! ```json
+//! {"uri": "file:///var/log/app.log",
+//!  "patch": {"type": "append", "baseLength": 1024, "text": "request served\n"}}
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::ResourceContent;
+
+/// Change to a resource's contents since the previous update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ResourcePatch {
+    /// Text appended to the end of a text resource
+    #[serde(rename_all = "camelCase")]
+    Append {
+        /// Length in bytes of the text being extended; a copy of any other
+        /// length has missed an update and must be re-read
+        base_length: u64,
+        /// The appended text
+        text: String,
+    },
+    /// [RFC 7396](https://www.rfc-editor.org/rfc/rfc7396) merge patch for a
+    /// text resource holding a JSON document
+    #[serde(rename_all = "camelCase")]
+    MergePatch {
+        /// [`document_version`](ResourcePatch::document_version) of the
+        /// document being patched; a copy of any other version has missed an
+        /// update and must be re-read
+        base_version: String,
+        /// The merge patch document
+        patch: Value,
+    },
+}
+
+/// Reasons a patch cannot be applied to a cached copy
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ResourcePatchError {
+    /// The cached copy is not the version the patch was made against
+    #[error("patch expects {expected} bytes of content but the copy has {actual}")]
+    BaseMismatch {
+        /// Length the patch was made against
+        expected: u64,
+        /// Length of the cached copy
+        actual: u64,
+    },
+    /// The cached document is not the version the merge patch was made against
+    #[error("patch expects document version {expected} but the copy is {actual}")]
+    VersionMismatch {
+        /// Version the patch was made against
+        expected: String,
+        /// Version of the cached copy
+        actual: String,
+    },
+    /// The patch only applies to text contents
+    #[error("patch cannot be applied to binary contents")]
+    NotText,
+    /// A merge patch target is not valid JSON
+    #[error("contents are not a JSON document: {0}")]
+    InvalidJson(String),
+}
+
+impl ResourcePatch {
+    /// Merge patch for the JSON document `base`
+    #[must_use]
+    pub fn merge(base: &Value, patch: Value) -> Self {
+        Self::MergePatch {
+            base_version: Self::document_version(base),
+            patch,
+        }
+    }
+
+    /// Version of a JSON document, as merge patches name their base
+    ///
+    /// A 64-bit FNV-1a digest of the document's compact serialization, in
+    /// hex. It depends only on the document, not on how its text is
+    /// formatted, so a server and a client holding the same document agree
+    /// on it.
+    #[must_use]
+    pub fn document_version(document: &Value) -> String {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+        let digest = document
+            .to_string()
+            .bytes()
+            .fold(OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+            });
+        format!("{digest:016x}")
+    }
+
+    /// Apply the patch to a cached copy of the resource's contents
+    ///
+    /// On error `content` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ResourcePatchError`] if the patch does not fit `content`;
+    /// the caller should then re-read the whole resource.
+    pub fn apply(&self, content: &mut ResourceContent) -> Result<(), ResourcePatchError> {
+        let ResourceContent::Text(content) = content else {
+            return Err(ResourcePatchError::NotText);
+        };
+        match self {
+            Self::Append { base_length, text } => {
+                let actual = content.text.len() as u64;
+                if actual != *base_length {
+                    return Err(ResourcePatchError::BaseMismatch {
+                        expected: *base_length,
+                        actual,
+                    });
+                }
+                content.text.push_str(text);
+            }
+            Self::MergePatch {
+                base_version,
+                patch,
+            } => {
+                let mut document: Value = serde_json::from_str(&content.text)
+                    .map_err(|e| ResourcePatchError::InvalidJson(e.to_string()))?;
+                let actual = Self::document_version(&document);
+                if actual != *base_version {
+                    return Err(ResourcePatchError::VersionMismatch {
+                        expected: base_version.clone(),
+                        actual,
+                    });
+                }
+                merge_patch(&mut document, patch);
+                content.text = document.to_string();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Apply an RFC 7396 merge patch to `target`
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(serde_json::Map::new());
+    }
+    if let Value::Object(target) = target {
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(key);
+            } else {
+                merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,443 @@
+//! # Placeholder Templates
+//!
+//! Resource URI templates (`file:///logs/{date}`) and prompt message
+//! templates (`Summarize {topic} for {audience}`) use the same `{name}`
+//! placeholder syntax. This module parses and renders both, so servers that
+//! validate templates and clients that fill them in agree on the grammar.
+//!
+//! [`render`] substitutes once: an argument value that itself contains
+//! `{name}` is inserted verbatim. [`render_nested`] expands placeholders in
+//! argument values too, within [`ExpansionLimits`], so a self-referential or
+//! exponentially growing set of arguments fails with an error rather than
+//! expanding forever.
+//!
+//! Resource URI templates are matched against URIs here as well:
+//! [`match_uri`] binds placeholder values and [`uri_templates_overlap`]
+//! finds a URI two templates would both claim. In a URI template a `{name}`
+//! matches one or more characters other than `/`, and a `*` matches any
+//! characters.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Template parsing and rendering errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    /// A `{` was never closed
+    #[error("unclosed '{{'")]
+    Unclosed,
+    /// A `{` appeared inside a placeholder
+    #[error("nested '{{'")]
+    Nested,
+    /// A `}` appeared outside a placeholder
+    #[error("unmatched '}}'")]
+    UnmatchedClose,
+    /// A placeholder has no name
+    #[error("empty placeholder name")]
+    EmptyName,
+    /// Placeholders without a value in the supplied arguments
+    #[error("missing argument(s): {}", .0.join(", "))]
+    MissingArguments(Vec<String>),
+    /// Substitution nested deeper than the limit, usually a cycle
+    #[error("substitution nested deeper than {limit} levels at '{{{name}}}'")]
+    DepthExceeded {
+        /// Placeholder whose expansion went past the limit
+        name: String,
+        /// Configured maximum depth
+        limit: usize,
+    },
+    /// The expanded text grew past the size limit
+    #[error("expansion exceeds {0} bytes")]
+    TooLarge(usize),
+    /// More placeholders were substituted than the limit allows
+    #[error("expansion needs more than {0} substitutions")]
+    TooManySubstitutions(usize),
+}
+
+/// Bounds on [`render_nested`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpansionLimits {
+    /// How many levels of argument values may themselves be expanded
+    pub max_depth: usize,
+    /// Largest expanded output, in bytes
+    pub max_len: usize,
+    /// Most placeholders substituted in one rendering, at any depth
+    pub max_substitutions: usize,
+}
+
+impl Default for ExpansionLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 16,
+            max_len: 1024 * 1024,
+            max_substitutions: 4096,
+        }
+    }
+}
+
+/// A piece of a parsed template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'a> {
+    Literal(&'a str),
+    Placeholder(&'a str),
+}
+
+fn parse(template: &str) -> Result<Vec<Segment<'_>>, TemplateError> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        if rest.as_bytes()[pos] == b'}' {
+            return Err(TemplateError::UnmatchedClose);
+        }
+        if pos > 0 {
+            segments.push(Segment::Literal(&rest[..pos]));
+        }
+        let after = &rest[pos + 1..];
+        let end = after.find('}').ok_or(TemplateError::Unclosed)?;
+        let name = &after[..end];
+        if name.contains('{') {
+            return Err(TemplateError::Nested);
+        }
+        if name.trim().is_empty() {
+            return Err(TemplateError::EmptyName);
+        }
+        segments.push(Segment::Placeholder(name));
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Literal(rest));
+    }
+    Ok(segments)
+}
+
+/// List placeholder names in order of appearance, including repeats
+pub fn placeholders(template: &str) -> Result<Vec<&str>, TemplateError> {
+    Ok(parse(template)?
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Placeholder(name) => Some(name),
+            Segment::Literal(_) => None,
+        })
+        .collect())
+}
+
+/// Substitute every `{name}` placeholder with its argument
+///
+/// Fails with [`TemplateError::MissingArguments`] listing every placeholder
+/// that has no argument. Arguments that no placeholder uses are ignored.
+pub fn render(
+    template: &str,
+    arguments: &HashMap<String, String>,
+) -> Result<String, TemplateError> {
+    let segments = parse(template)?;
+    check_arguments(&segments, arguments)?;
+
+    Ok(segments
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Literal(text) => text,
+            Segment::Placeholder(name) => arguments[name].as_str(),
+        })
+        .collect())
+}
+
+/// Substitute placeholders, expanding placeholders in argument values too
+///
+/// An argument whose value is `{other}` is replaced by the expansion of
+/// `other`, up to `limits.max_depth` levels. Going deeper fails with
+/// [`TemplateError::DepthExceeded`], which is how a cycle such as
+/// `a = "{b}"`, `b = "{a}"` surfaces. Output longer than `limits.max_len`
+/// fails with [`TemplateError::TooLarge`], and more than
+/// `limits.max_substitutions` placeholders in total with
+/// [`TemplateError::TooManySubstitutions`], which stops arguments that fan
+/// out exponentially even when they expand to nothing. Argument values must
+/// themselves be valid templates, so use [`render`] when they may contain
+/// literal braces.
+pub fn render_nested(
+    template: &str,
+    arguments: &HashMap<String, String>,
+    limits: &ExpansionLimits,
+) -> Result<String, TemplateError> {
+    let mut expansion = Expansion {
+        arguments,
+        limits,
+        substitutions: 0,
+        out: String::new(),
+    };
+    expansion.expand(template, 0)?;
+    Ok(expansion.out)
+}
+
+/// State of one [`render_nested`] call
+struct Expansion<'a> {
+    arguments: &'a HashMap<String, String>,
+    limits: &'a ExpansionLimits,
+    substitutions: usize,
+    out: String,
+}
+
+impl Expansion<'_> {
+    fn expand(&mut self, template: &str, depth: usize) -> Result<(), TemplateError> {
+        let segments = parse(template)?;
+        check_arguments(&segments, self.arguments)?;
+
+        for segment in segments {
+            match segment {
+                Segment::Literal(text) => {
+                    if self.out.len() + text.len() > self.limits.max_len {
+                        return Err(TemplateError::TooLarge(self.limits.max_len));
+                    }
+                    self.out.push_str(text);
+                }
+                Segment::Placeholder(name) => {
+                    if depth >= self.limits.max_depth {
+                        return Err(TemplateError::DepthExceeded {
+                            name: name.to_string(),
+                            limit: self.limits.max_depth,
+                        });
+                    }
+                    self.substitutions += 1;
+                    if self.substitutions > self.limits.max_substitutions {
+                        return Err(TemplateError::TooManySubstitutions(
+                            self.limits.max_substitutions,
+                        ));
+                    }
+                    let arguments = self.arguments;
+                    self.expand(&arguments[name], depth + 1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Fail with every placeholder in `segments` that has no argument
+fn check_arguments(
+    segments: &[Segment<'_>],
+    arguments: &HashMap<String, String>,
+) -> Result<(), TemplateError> {
+    let mut missing: Vec<String> = Vec::new();
+    for segment in segments {
+        if let Segment::Placeholder(name) = segment
+            && !arguments.contains_key(*name)
+            && !missing.iter().any(|m| m.as_str() == *name)
+        {
+            missing.push((*name).to_string());
+        }
+    }
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(TemplateError::MissingArguments(missing))
+    }
+}
+
+/// A piece of a resource URI template
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UriPart<'a> {
+    Literal(&'a str),
+    /// `{name}`: one or more characters other than `/`
+    Param(&'a str),
+    /// `*`: any characters
+    Wildcard,
+}
+
+fn uri_parts(template: &str) -> Result<Vec<UriPart<'_>>, TemplateError> {
+    let mut parts = Vec::new();
+    for segment in parse(template)? {
+        match segment {
+            Segment::Literal(text) => {
+                for (i, literal) in text.split('*').enumerate() {
+                    if i > 0 {
+                        parts.push(UriPart::Wildcard);
+                    }
+                    if !literal.is_empty() {
+                        parts.push(UriPart::Literal(literal));
+                    }
+                }
+            }
+            Segment::Placeholder(name) => parts.push(UriPart::Param(name)),
+        }
+    }
+    Ok(parts)
+}
+
+/// Values `uri` gives the placeholders of a resource URI `template`
+///
+/// Returns `None` when `uri` does not match the template, or the template
+/// does not parse. A `{name}` or `*` takes as much of the URI as it can
+/// while the rest still matches.
+#[must_use]
+pub fn match_uri(template: &str, uri: &str) -> Option<HashMap<String, String>> {
+    let parts = uri_parts(template).ok()?;
+    let mut matcher = UriMatcher {
+        parts: &parts,
+        uri,
+        failed: HashSet::new(),
+        values: Vec::new(),
+    };
+    if !matcher.matches_from(0, 0) {
+        return None;
+    }
+    // Values are found innermost first; a repeated name keeps its last one
+    Some(
+        matcher
+            .values
+            .into_iter()
+            .rev()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
+/// State of one [`match_uri`] call
+struct UriMatcher<'t, 'u> {
+    parts: &'t [UriPart<'t>],
+    uri: &'u str,
+    /// Positions, as (part, byte offset), known not to lead to a match
+    failed: HashSet<(usize, usize)>,
+    values: Vec<(&'t str, &'u str)>,
+}
+
+impl UriMatcher<'_, '_> {
+    /// Whether the parts from `part` on match the URI from byte `at` on
+    fn matches_from(&mut self, part: usize, at: usize) -> bool {
+        if self.failed.contains(&(part, at)) {
+            return false;
+        }
+        let uri = self.uri;
+        let rest = &uri[at..];
+        let matched = match self.parts.get(part).copied() {
+            None => rest.is_empty(),
+            Some(UriPart::Literal(literal)) => {
+                rest.starts_with(literal) && self.matches_from(part + 1, at + literal.len())
+            }
+            Some(UriPart::Wildcard) => std::iter::once(0)
+                .chain(rest.char_indices().map(|(i, c)| i + c.len_utf8()))
+                .rev()
+                .any(|end| self.matches_from(part + 1, at + end)),
+            Some(UriPart::Param(name)) => {
+                let segment = &rest[..rest.find('/').unwrap_or(rest.len())];
+                let end = segment
+                    .char_indices()
+                    .map(|(i, c)| i + c.len_utf8())
+                    .rev()
+                    .find(|&end| self.matches_from(part + 1, at + end));
+                match end {
+                    Some(end) => {
+                        self.values.push((name, &segment[..end]));
+                        true
+                    }
+                    None => false,
+                }
+            }
+        };
+        if !matched {
+            self.failed.insert((part, at));
+        }
+        matched
+    }
+}
+
+/// A character-at-a-time view of a [`UriPart`], for [`uri_templates_overlap`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UriSymbol {
+    Literal(char),
+    Param,
+    Wildcard,
+}
+
+/// Position while matching symbols: the symbol reached, and whether a
+/// `{name}` there has matched at least one character
+type UriState = (usize, bool);
+
+fn uri_symbols(template: &str) -> Result<Vec<UriSymbol>, TemplateError> {
+    let mut symbols = Vec::new();
+    for part in uri_parts(template)? {
+        match part {
+            UriPart::Literal(literal) => symbols.extend(literal.chars().map(UriSymbol::Literal)),
+            UriPart::Param(_) => symbols.push(UriSymbol::Param),
+            UriPart::Wildcard => symbols.push(UriSymbol::Wildcard),
+        }
+    }
+    Ok(symbols)
+}
+
+/// Where `state` can move without consuming a character
+fn uri_skip(symbols: &[UriSymbol], (index, in_param): UriState) -> Option<UriState> {
+    (in_param || symbols.get(index) == Some(&UriSymbol::Wildcard)).then_some((index + 1, false))
+}
+
+/// Where `state` moves on consuming `c`
+fn uri_step(symbols: &[UriSymbol], (index, in_param): UriState, c: char) -> Option<UriState> {
+    if in_param {
+        return (c != '/').then_some((index, true));
+    }
+    match symbols.get(index)? {
+        UriSymbol::Literal(literal) => (*literal == c).then_some((index + 1, false)),
+        UriSymbol::Param => (c != '/').then_some((index, true)),
+        UriSymbol::Wildcard => Some((index, false)),
+    }
+}
+
+/// A URI both resource URI templates match, if there is one
+///
+/// Two templates that share a URI leave it to chance which handler serves
+/// it; the URI returned shows the conflict. Templates that do not parse
+/// match nothing, so they overlap nothing.
+#[must_use]
+pub fn uri_templates_overlap(a: &str, b: &str) -> Option<String> {
+    let (a, b) = (uri_symbols(a).ok()?, uri_symbols(b).ok()?);
+    let literals: Vec<char> = a
+        .iter()
+        .chain(&b)
+        .filter_map(|symbol| match symbol {
+            UriSymbol::Literal(c) => Some(*c),
+            _ => None,
+        })
+        .collect();
+    // Any character the templates do not name stands in for all of them
+    let other = ('a'..='z')
+        .chain('0'..='9')
+        .find(|c| !literals.contains(c))
+        .unwrap_or('~');
+    let mut alphabet = literals;
+    alphabet.push('/');
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    // Tried first, so the URI found fills parameters with it
+    alphabet.insert(0, other);
+
+    // Search both templates in step for a common path to their ends,
+    // remembering how each pair of states was reached
+    let start = ((0, false), (0, false));
+    let mut reached: HashMap<_, Option<(_, Option<char>)>> = HashMap::from([(start, None)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pair @ (state_a, state_b)) = queue.pop_front() {
+        if state_a == (a.len(), false) && state_b == (b.len(), false) {
+            let mut uri = Vec::new();
+            let mut current = pair;
+            while let Some(Some((previous, c))) = reached.get(&current) {
+                uri.extend(c);
+                current = *previous;
+            }
+            return Some(uri.into_iter().rev().collect());
+        }
+
+        let skips = [
+            uri_skip(&a, state_a).map(|next| ((next, state_b), None)),
+            uri_skip(&b, state_b).map(|next| ((state_a, next), None)),
+        ];
+        let steps = alphabet.iter().filter_map(|&c| {
+            let next_a = uri_step(&a, state_a, c)?;
+            let next_b = uri_step(&b, state_b, c)?;
+            Some(((next_a, next_b), Some(c)))
+        });
+        for (next, c) in skips.into_iter().flatten().chain(steps) {
+            reached.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                Some((pair, c))
+            });
+        }
+    }
+    None
+}
@@ -6,6 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use turbomcp_core::MessageId;
+pub use turbomcp_core::ProgressStatus;
 
 /// Protocol version string
 pub type ProtocolVersion = String;
@@ -213,6 +214,10 @@ pub enum ClientRequest {
     /// List filesystem roots
     #[serde(rename = "roots/list")]
     ListRoots(ListRootsRequest),
+
+    /// Complete a prompt or resource argument
+    #[serde(rename = "completion/complete")]
+    Complete(CompleteRequest),
 }
 
 /// Server-initiated request
@@ -261,6 +266,10 @@ pub enum ServerNotification {
     #[serde(rename = "notifications/progress")]
     Progress(ProgressNotification),
 
+    /// Progress finished
+    #[serde(rename = "notifications/progress/complete")]
+    ProgressComplete(ProgressCompleteNotification),
+
     /// Request cancellation
     #[serde(rename = "notifications/cancelled")]
     Cancelled(CancelledNotification),
@@ -293,6 +302,11 @@ pub struct InitializeRequest {
     /// Client implementation info
     #[serde(rename = "clientInfo")]
     pub client_info: Implementation,
+    /// Deployment metadata for the session (region, tenant, feature flags)
+    ///
+    /// Limited to [`MAX_INITIALIZE_META_SIZE`] bytes when serialized.
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, serde_json::Value>>,
 }
 
 /// Initialize result
@@ -309,6 +323,43 @@ pub struct InitializeResult {
     /// Additional instructions for the client
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instructions: Option<String>,
+    /// Every protocol version the server can speak, newest first
+    ///
+    /// An extension to the handshake for diagnosing version mismatches;
+    /// servers that predate it leave it out.
+    #[serde(
+        rename = "supportedVersions",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub supported_versions: Option<Vec<ProtocolVersion>>,
+    /// Deployment metadata for the session (region, tenant, feature flags)
+    ///
+    /// Limited to [`MAX_INITIALIZE_META_SIZE`] bytes when serialized.
+    #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, serde_json::Value>>,
+    /// Version of the tool listing at initialization
+    ///
+    /// Matches [`ListToolsResult::manifest_version`], so a client holding a
+    /// listing with this version can skip `tools/list`.
+    #[serde(
+        rename = "toolManifestVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tool_manifest_version: Option<String>,
+}
+
+/// Maximum serialized size of the `_meta` map in an initialize request or result
+///
+/// Session metadata travels with the handshake and is kept for the whole
+/// session, so it is meant for a handful of small values, not payloads.
+pub const MAX_INITIALIZE_META_SIZE: usize = 16 * 1024;
+
+/// Serialized size of an initialize `_meta` map in bytes
+#[must_use]
+pub fn initialize_meta_size(meta: &HashMap<String, serde_json::Value>) -> usize {
+    serde_json::to_vec(meta).map_or(usize::MAX, |bytes| bytes.len())
 }
 
 /// Initialized notification (no parameters)
@@ -368,17 +419,62 @@ pub struct ServerCapabilities {
 }
 
 /// Sampling capabilities
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// Carries no settings, but is sent as an empty object: a unit struct would
+/// otherwise serialize as `null`, which reads as the capability being absent.
+#[derive(Debug, Clone, Default)]
 pub struct SamplingCapabilities;
 
+impl Serialize for SamplingCapabilities {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SamplingCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::de::IgnoredAny::deserialize(deserializer)?;
+        Ok(SamplingCapabilities)
+    }
+}
+
 /// Elicitation capabilities
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ElicitationCapabilities;
 
 /// Completion capabilities
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// Sent as an empty object, like [`SamplingCapabilities`].
+#[derive(Debug, Clone, Default)]
 pub struct CompletionCapabilities;
 
+impl Serialize for CompletionCapabilities {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CompletionCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::de::IgnoredAny::deserialize(deserializer)?;
+        Ok(CompletionCapabilities)
+    }
+}
+
 /// Roots capabilities
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct RootsCapabilities {
@@ -388,9 +484,33 @@ pub struct RootsCapabilities {
 }
 
 /// Logging capabilities
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+///
+/// Also an empty object on the wire; as `null` it would read back as absent.
+/// Servers send log messages at their default level until the client picks
+/// another with `logging/setLevel`.
+#[derive(Debug, Clone, Default)]
 pub struct LoggingCapabilities;
 
+impl Serialize for LoggingCapabilities {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LoggingCapabilities {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::de::IgnoredAny::deserialize(deserializer)?;
+        Ok(LoggingCapabilities)
+    }
+}
+
 /// Prompts capabilities
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PromptsCapabilities {
@@ -563,8 +683,38 @@ pub struct ToolAnnotations {
     pub custom: HashMap<String, serde_json::Value>,
 }
 
+impl ToolAnnotations {
+    /// `readOnlyHint`: the tool does not modify its environment
+    #[must_use]
+    pub fn read_only_hint(&self) -> Option<bool> {
+        self.bool_hint("readOnlyHint")
+    }
+
+    /// `destructiveHint`: the tool may perform destructive updates
+    #[must_use]
+    pub fn destructive_hint(&self) -> Option<bool> {
+        self.bool_hint("destructiveHint")
+    }
+
+    /// `idempotentHint`: repeated calls with the same arguments have no additional effect
+    #[must_use]
+    pub fn idempotent_hint(&self) -> Option<bool> {
+        self.bool_hint("idempotentHint")
+    }
+
+    /// `openWorldHint`: the tool interacts with external entities
+    #[must_use]
+    pub fn open_world_hint(&self) -> Option<bool> {
+        self.bool_hint("openWorldHint")
+    }
+
+    fn bool_hint(&self, key: &str) -> Option<bool> {
+        self.custom.get(key).and_then(serde_json::Value::as_bool)
+    }
+}
+
 /// Tool definition per MCP 2025-06-18 specification
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Tool {
     /// Tool name (programmatic identifier)
     pub name: String,
@@ -615,6 +765,9 @@ pub struct ToolInputSchema {
         skip_serializing_if = "Option::is_none"
     )]
     pub additional_properties: Option<bool>,
+    /// Example arguments, shown by hosts to guide callers of the tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<serde_json::Value>>,
 }
 
 /// Tool output schema definition
@@ -635,6 +788,35 @@ pub struct ToolOutputSchema {
         skip_serializing_if = "Option::is_none"
     )]
     pub additional_properties: Option<bool>,
+    /// Example results the tool may return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<serde_json::Value>>,
+}
+
+impl Default for ToolInputSchema {
+    /// An object schema accepting any arguments
+    fn default() -> Self {
+        Self {
+            schema_type: "object".to_string(),
+            properties: None,
+            required: None,
+            additional_properties: None,
+            examples: None,
+        }
+    }
+}
+
+impl Default for ToolOutputSchema {
+    /// An object schema accepting any result
+    fn default() -> Self {
+        Self {
+            schema_type: "object".to_string(),
+            properties: None,
+            required: None,
+            additional_properties: None,
+            examples: None,
+        }
+    }
 }
 
 /// List tools request (no parameters)
@@ -649,6 +831,16 @@ pub struct ListToolsResult {
     /// Optional continuation token
     #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none")]
     pub next_cursor: Option<String>,
+    /// Stable digest of the listed tools
+    ///
+    /// Changes whenever a tool is added, removed or redefined, and only then,
+    /// so clients can key cached listings on it across connections.
+    #[serde(
+        rename = "manifestVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub manifest_version: Option<String>,
 }
 
 /// Call tool request
@@ -662,13 +854,16 @@ pub struct CallToolRequest {
 }
 
 /// Call tool result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CallToolResult {
     /// Result content
     pub content: Vec<ContentBlock>,
     /// Whether the operation failed
     #[serde(rename = "isError", skip_serializing_if = "Option::is_none")]
     pub is_error: Option<bool>,
+    /// Structured output conforming to the tool's output schema, if any
+    #[serde(rename = "structuredContent", skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<serde_json::Value>,
 }
 
 // ============================================================================
@@ -879,6 +1074,37 @@ pub struct ListResourcesResult {
 pub struct ReadResourceRequest {
     /// Resource URI
     pub uri: Uri,
+    /// Byte offset to start reading from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    /// Maximum number of bytes to read; the rest of the resource if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u64>,
+}
+
+impl ReadResourceRequest {
+    /// Request the whole resource at `uri`
+    pub fn new(uri: impl Into<Uri>) -> Self {
+        Self {
+            uri: uri.into(),
+            offset: None,
+            length: None,
+        }
+    }
+
+    /// Request only `length` bytes (or the remainder) starting at `offset`
+    #[must_use]
+    pub const fn with_range(mut self, offset: u64, length: Option<u64>) -> Self {
+        self.offset = Some(offset);
+        self.length = length;
+        self
+    }
+
+    /// Whether only part of the resource was requested
+    #[must_use]
+    pub const fn is_ranged(&self) -> bool {
+        self.offset.is_some() || self.length.is_some()
+    }
 }
 
 /// Read resource result
@@ -886,6 +1112,25 @@ pub struct ReadResourceRequest {
 pub struct ReadResourceResult {
     /// Resource contents (can be text or binary)
     pub contents: Vec<ResourceContent>,
+    /// The slice of the resource the contents hold
+    ///
+    /// Absent when the contents are the whole resource, which is also how a
+    /// server answers a ranged read of a resource that does not support ranges.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<ContentRange>,
+}
+
+/// Byte range of a resource returned by a ranged read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentRange {
+    /// Offset of the first returned byte
+    pub offset: u64,
+    /// Number of bytes returned
+    pub length: u64,
+    /// Size of the whole resource, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_size: Option<u64>,
 }
 
 /// Subscribe to resource request
@@ -903,18 +1148,41 @@ pub struct UnsubscribeRequest {
 }
 
 /// Resource updated notification
+///
+/// May carry a [`ResourcePatch`](crate::patch::ResourcePatch) so clients
+/// holding the previous contents can update them without a full read.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceUpdatedNotification {
     /// Resource URI
     pub uri: Uri,
+    /// The change since the previous update, when the server can express it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<crate::patch::ResourcePatch>,
+}
+
+impl ResourceUpdatedNotification {
+    /// Announce that `uri` changed, leaving clients to re-read it
+    pub fn new(uri: impl Into<Uri>) -> Self {
+        Self {
+            uri: uri.into(),
+            patch: None,
+        }
+    }
+
+    /// Describe the change so clients can patch their copy instead
+    #[must_use]
+    pub fn with_patch(mut self, patch: crate::patch::ResourcePatch) -> Self {
+        self.patch = Some(patch);
+        self
+    }
 }
 
 // ============================================================================
 // Logging Types
 // ============================================================================
 
-/// Log level
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Log level, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum LogLevel {
     /// Debug level
@@ -980,6 +1248,23 @@ pub struct ProgressNotification {
     pub message: Option<String>,
 }
 
+/// Notification that a progress-tracked operation has finished
+///
+/// An extension to MCP progress: servers send it once, after the final
+/// `notifications/progress`, so clients can close progress bars without
+/// inferring completion from `progress == total`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressCompleteNotification {
+    /// The progress token which was given in the initial request
+    #[serde(rename = "progressToken")]
+    pub progress_token: ProgressToken,
+    /// Whether the operation succeeded
+    pub status: ProgressStatus,
+    /// Optional short description of the outcome
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
 /// Cancellation notification per MCP 2025-06-18 specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CancelledNotification {
@@ -993,6 +1278,70 @@ pub struct CancelledNotification {
     pub reason: Option<String>,
 }
 
+// ============================================================================
+// Resumable Operation Types
+// ============================================================================
+
+/// Key under a request's `_meta` naming it as a resumable operation
+///
+/// An extension to MCP: a client that may lose its connection during a long
+/// request sends a durable id of its choosing here. After reconnecting it
+/// calls `operations/get` to check on the request, or `operations/resume`
+/// to have its progress notifications sent to the new connection.
+pub const OPERATION_ID_META_KEY: &str = "operationId";
+
+/// Parameters of `operations/get` and `operations/resume`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationRequest {
+    /// Id the client gave the operation when it started it
+    #[serde(rename = "operationId")]
+    pub operation_id: String,
+    /// For `operations/resume`, the token the operation's remaining
+    /// progress notifications carry on this connection; defaults to the
+    /// operation id
+    #[serde(rename = "progressToken", skip_serializing_if = "Option::is_none")]
+    pub progress_token: Option<ProgressToken>,
+}
+
+/// Where a resumable operation stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OperationState {
+    /// Its handler is still working
+    Running,
+    /// It finished with a result
+    Completed,
+    /// It finished with an error
+    Failed,
+}
+
+/// Status of a resumable operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationStatus {
+    /// Id the client gave the operation
+    #[serde(rename = "operationId")]
+    pub operation_id: String,
+    /// Method of the request that started it
+    pub method: String,
+    /// Whether it is still running
+    pub state: OperationState,
+    /// The latest progress reported, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    /// Total of the latest progress reported, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// Message of the latest progress reported, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Result of the request, once completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// Error the request failed with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<crate::jsonrpc::JsonRpcError>,
+}
+
 // ============================================================================
 // Sampling Types
 // ============================================================================
@@ -1088,6 +1437,128 @@ pub struct CreateMessageResult {
     pub stop_reason: Option<String>,
 }
 
+// ============================================================================
+// Completion Types
+// ============================================================================
+
+/// Most values a completion may carry
+pub const MAX_COMPLETION_VALUES: usize = 100;
+
+/// What a completion request is completing an argument of
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum CompletionReference {
+    /// A prompt, by name
+    #[serde(rename = "ref/prompt")]
+    Prompt {
+        /// Prompt name
+        name: String,
+    },
+    /// A resource, by URI or URI template
+    #[serde(rename = "ref/resource")]
+    Resource {
+        /// Resource URI or URI template
+        uri: String,
+    },
+}
+
+impl CompletionReference {
+    /// The prompt name or resource URI referred to
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Prompt { name } => name,
+            Self::Resource { uri } => uri,
+        }
+    }
+}
+
+/// Argument being completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteArgument {
+    /// Argument name
+    pub name: String,
+    /// What the user has typed so far
+    pub value: String,
+}
+
+/// Arguments already filled in, which may narrow the completion
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompletionContext {
+    /// Values of the reference's other arguments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<HashMap<String, String>>,
+}
+
+/// Complete request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteRequest {
+    /// Prompt or resource the argument belongs to
+    #[serde(rename = "ref")]
+    pub reference: CompletionReference,
+    /// Argument to complete
+    pub argument: CompleteArgument,
+    /// Previously resolved arguments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<CompletionContext>,
+}
+
+/// Complete result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteResult {
+    /// Suggested values
+    pub completion: Completion,
+}
+
+/// Suggested values for an argument
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Completion {
+    /// Values, at most [`MAX_COMPLETION_VALUES`] of them
+    pub values: Vec<String>,
+    /// Number of values available, which may exceed those sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<usize>,
+    /// Whether more values exist than were sent
+    #[serde(rename = "hasMore", skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+}
+
+impl Completion {
+    /// Completion offering every one of `candidates` the limit allows
+    ///
+    /// `total` counts all the candidates, and `hasMore` says whether any
+    /// were cut.
+    #[must_use]
+    pub fn from_candidates(candidates: Vec<String>) -> Self {
+        Self {
+            total: Some(candidates.len()),
+            has_more: Some(false),
+            values: candidates,
+        }
+        .limited()
+    }
+
+    /// This completion cut to [`MAX_COMPLETION_VALUES`] values
+    ///
+    /// A cut completion reports `hasMore`, and its `total` defaults to the
+    /// number of values it had.
+    #[must_use]
+    pub fn limited(mut self) -> Self {
+        if self.values.len() > MAX_COMPLETION_VALUES {
+            self.total = self.total.or(Some(self.values.len()));
+            self.values.truncate(MAX_COMPLETION_VALUES);
+            self.has_more = Some(true);
+        }
+        self
+    }
+}
+
+impl From<Vec<String>> for Completion {
+    fn from(candidates: Vec<String>) -> Self {
+        Self::from_candidates(candidates)
+    }
+}
+
 // ============================================================================
 // Roots Types
 // ============================================================================
@@ -1131,15 +1602,7 @@ mod tests {
             name: "test_tool".to_string(),
             title: Some("Test Tool".to_string()),
             description: Some("A test tool".to_string()),
-            input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
-                properties: None,
-                required: None,
-                additional_properties: None,
-            },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&tool).unwrap();
@@ -1161,4 +1624,33 @@ mod tests {
         // Test the compatibility alias
         let _compatible: Content = text_content;
     }
+
+    #[test]
+    fn test_complete_request_wire_format() {
+        let request: CompleteRequest = serde_json::from_value(serde_json::json!({
+            "ref": {"type": "ref/resource", "uri": "file:///{path}"},
+            "argument": {"name": "path", "value": "src/"}
+        }))
+        .unwrap();
+        assert_eq!(
+            request.reference,
+            CompletionReference::Resource {
+                uri: "file:///{path}".to_string()
+            }
+        );
+        assert_eq!(request.argument.value, "src/");
+
+        let candidates = (0..150).map(|n| n.to_string()).collect();
+        let json = serde_json::to_value(Completion::from_candidates(candidates)).unwrap();
+        assert_eq!(
+            json["values"].as_array().unwrap().len(),
+            MAX_COMPLETION_VALUES
+        );
+        assert_eq!(json["total"], 150);
+        assert_eq!(json["hasMore"], true);
+        assert_eq!(
+            serde_json::to_value(CompletionCapabilities).unwrap(),
+            serde_json::json!({})
+        );
+    }
 }
@@ -354,6 +354,16 @@ impl ProtocolValidator {
             );
         }
 
+        if let Some(meta) = &request.meta
+            && initialize_meta_size(meta) > MAX_INITIALIZE_META_SIZE
+        {
+            ctx.add_error(
+                "META_TOO_LARGE",
+                format!("Initialize metadata exceeds {MAX_INITIALIZE_META_SIZE} bytes"),
+                Some("_meta".to_string()),
+            );
+        }
+
         ctx.into_result()
     }
 
@@ -670,15 +680,7 @@ mod tests {
             name: "test_tool".to_string(),
             title: Some("Test Tool".to_string()),
             description: Some("A test tool".to_string()),
-            input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
-                properties: None,
-                required: None,
-                additional_properties: None,
-            },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         };
 
         let result = validator.validate_tool(&tool);
@@ -687,12 +689,8 @@ mod tests {
         // Test empty name
         let invalid_tool = Tool {
             name: String::new(),
-            title: None,
-            description: None,
             input_schema: tool.input_schema.clone(),
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         };
 
         let result = validator.validate_tool(&invalid_tool);
@@ -737,6 +735,7 @@ mod tests {
                 title: Some("Test Client".to_string()),
                 version: "1.0.0".to_string(),
             },
+            meta: None,
         };
 
         let result = validator.validate_initialize_request(&request);
@@ -751,6 +750,7 @@ mod tests {
                 title: Some("Test Client".to_string()),
                 version: "1.0.0".to_string(),
             },
+            meta: None,
         };
 
         let result = validator.validate_initialize_request(&request_with_old_version);
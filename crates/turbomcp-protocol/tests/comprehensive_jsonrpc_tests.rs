@@ -395,6 +395,39 @@ fn test_utils_parse_message() {
     }
 }
 
+#[test]
+fn test_utils_parse_message_notification() {
+    let json = r#"{"jsonrpc":"2.0","method":"notifications/progress","params":{"progress":1}}"#;
+    let message = utils::parse_message(json).unwrap();
+
+    match message {
+        JsonRpcMessage::Notification(notification) => {
+            assert_eq!(notification.method, "notifications/progress");
+        }
+        other => panic!("Expected Notification message, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_unknown_notification_policy_handler() {
+    use std::sync::{Arc, Mutex};
+    use turbomcp_protocol::UnknownNotificationPolicy;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let policy = UnknownNotificationPolicy::handler({
+        let seen = Arc::clone(&seen);
+        move |n| seen.lock().unwrap().push(n.method.clone())
+    });
+    let notification = JsonRpcNotification::without_params("x/future".to_string());
+
+    UnknownNotificationPolicy::Ignore.apply(&notification);
+    UnknownNotificationPolicy::Log.apply(&notification);
+    policy.apply(&notification);
+
+    assert_eq!(*seen.lock().unwrap(), vec!["x/future".to_string()]);
+    assert_eq!(format!("{policy:?}"), "Handle(<handler>)");
+}
+
 #[test]
 fn test_utils_parse_message_invalid() {
     let json = r#"{"invalid": "json"}"#;
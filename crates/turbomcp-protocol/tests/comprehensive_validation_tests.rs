@@ -1032,15 +1032,7 @@ fn create_valid_tool() -> Tool {
         name: "test_tool".to_string(),
         title: Some("Test Tool".to_string()),
         description: Some("A test tool for validation".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     }
 }
 
@@ -1091,5 +1083,6 @@ fn create_valid_initialize_request() -> InitializeRequest {
             title: Some("Test Client".to_string()),
             version: "1.0.0".to_string(),
         },
+        meta: None,
     }
 }
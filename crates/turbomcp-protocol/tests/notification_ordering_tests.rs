@@ -0,0 +1,137 @@
+//! Tests for notification sequence numbers and client-side reordering
+
+use serde_json::json;
+use std::time::Duration;
+use turbomcp_protocol::jsonrpc::{JsonRpcNotification, JsonRpcVersion};
+use turbomcp_protocol::notifications::{
+    NotificationReorderBuffer, NotificationSequencer, OrderingStats, SEQUENCE_META_KEY,
+    notification_sequence,
+};
+
+fn progress(progress: u64) -> JsonRpcNotification {
+    JsonRpcNotification {
+        jsonrpc: JsonRpcVersion,
+        method: "notifications/progress".to_string(),
+        params: Some(json!({"progressToken": "t", "progress": progress})),
+    }
+}
+
+/// Notifications for `0..count`, each stamped by one sequencer
+fn stamped(count: u64) -> Vec<JsonRpcNotification> {
+    let sequencer = NotificationSequencer::new();
+    (0..count)
+        .map(|i| {
+            let mut note = progress(i);
+            assert_eq!(sequencer.stamp(&mut note), Some(i));
+            note
+        })
+        .collect()
+}
+
+fn sequences(notes: &[JsonRpcNotification]) -> Vec<u64> {
+    notes.iter().filter_map(notification_sequence).collect()
+}
+
+#[test]
+fn test_sequencer_stamps_meta_and_keeps_params() {
+    let mut note = progress(7);
+    note.params.as_mut().unwrap()["_meta"] = json!({"other": true});
+    let sequencer = NotificationSequencer::new();
+    sequencer.stamp(&mut note);
+
+    let params = note.params.unwrap();
+    assert_eq!(params["progress"], 7);
+    assert_eq!(params["_meta"]["other"], true);
+    assert_eq!(params["_meta"][SEQUENCE_META_KEY], 0);
+
+    let mut bare = JsonRpcNotification {
+        jsonrpc: JsonRpcVersion,
+        method: "notifications/initialized".to_string(),
+        params: None,
+    };
+    assert_eq!(sequencer.stamp(&mut bare), Some(1));
+    assert_eq!(notification_sequence(&bare), Some(1));
+
+    let mut array = JsonRpcNotification {
+        params: Some(json!([1, 2])),
+        ..bare
+    };
+    assert_eq!(sequencer.stamp(&mut array), None);
+}
+
+#[test]
+fn test_reorder_buffer_restores_send_order() {
+    let notes = stamped(4);
+    let mut buffer = NotificationReorderBuffer::default();
+
+    let mut delivered = Vec::new();
+    for i in [2, 0, 3, 1] {
+        delivered.extend(buffer.push(notes[i].clone()));
+    }
+    assert_eq!(sequences(&delivered), vec![0, 1, 2, 3]);
+    assert_eq!(buffer.next_sequence(), 4);
+    assert_eq!(
+        buffer.stats(),
+        OrderingStats {
+            reordered: 2,
+            missing: 0,
+            stale: 0,
+        }
+    );
+}
+
+#[test]
+fn test_reorder_buffer_detects_gap() {
+    let notes = stamped(5);
+    let mut buffer = NotificationReorderBuffer::new(2);
+
+    assert_eq!(sequences(&buffer.push(notes[0].clone())), vec![0]);
+    assert!(buffer.push(notes[0].clone()).is_empty());
+    assert_eq!(buffer.stats().stale, 1);
+
+    // 1 never arrives; holding a third early notification gives up on it
+    assert!(buffer.push(notes[3].clone()).is_empty());
+    assert!(buffer.push(notes[4].clone()).is_empty());
+    let delivered = buffer.push(notes[2].clone());
+    assert_eq!(sequences(&delivered), vec![2, 3, 4]);
+    assert_eq!(buffer.stats().missing, 1);
+
+    // A straggler for a number already given up on is dropped
+    assert!(buffer.push(notes[1].clone()).is_empty());
+    assert_eq!(buffer.stats().stale, 2);
+}
+
+#[test]
+fn test_unsequenced_notifications_pass_through() {
+    let mut buffer = NotificationReorderBuffer::default();
+    let notes = stamped(2);
+
+    assert!(buffer.push(notes[1].clone()).is_empty());
+    let delivered = buffer.push(progress(5));
+    assert_eq!(delivered.len(), 1);
+    assert_eq!(notification_sequence(&delivered[0]), None);
+    assert_eq!(sequences(&buffer.push(notes[0].clone())), vec![0, 1]);
+}
+
+#[test]
+fn test_reorder_buffer_gives_up_on_a_gap_after_its_timeout() {
+    let notes = stamped(4);
+    let mut buffer =
+        NotificationReorderBuffer::default().with_gap_timeout(Duration::from_millis(20));
+
+    assert_eq!(sequences(&buffer.push(notes[0].clone())), vec![0]);
+    assert_eq!(buffer.gap_deadline(), None);
+    // 1 never arrives; the one behind it is held until the gap times out
+    let pushed = std::time::Instant::now();
+    assert!(buffer.push(notes[2].clone()).is_empty());
+    let deadline = buffer.gap_deadline().expect("a gap is open");
+    assert!(deadline >= pushed + Duration::from_millis(20));
+    assert!(buffer.flush_expired().is_empty());
+
+    std::thread::sleep(Duration::from_millis(40));
+    assert_eq!(sequences(&buffer.flush_expired()), vec![2]);
+    assert_eq!(buffer.stats().missing, 1);
+    assert_eq!(buffer.gap_deadline(), None);
+    assert_eq!(sequences(&buffer.push(notes[3].clone())), vec![3]);
+    assert!(buffer.flush_expired().is_empty());
+}
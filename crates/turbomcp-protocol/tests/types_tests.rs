@@ -506,6 +506,19 @@ fn test_client_capabilities_with_values() {
     assert!(capabilities.elicitation.is_some());
 }
 
+#[test]
+fn test_sampling_capability_is_an_empty_object() {
+    let capabilities = ClientCapabilities {
+        sampling: Some(SamplingCapabilities),
+        ..Default::default()
+    };
+    let value = serde_json::to_value(&capabilities).unwrap();
+    assert_eq!(value, json!({"sampling": {}}));
+
+    let parsed: ClientCapabilities = serde_json::from_value(value).unwrap();
+    assert!(parsed.sampling.is_some());
+}
+
 #[test]
 fn test_server_capabilities_default() {
     let capabilities = ServerCapabilities::default();
@@ -568,6 +581,7 @@ fn test_initialize_request() {
             title: None,
             version: "1.0.0".to_string(),
         },
+        meta: None,
     };
 
     assert_eq!(request.protocol_version, "1.0.0");
@@ -585,6 +599,9 @@ fn test_initialize_result() {
             version: "1.0.0".to_string(),
         },
         instructions: Some("Welcome to the server".to_string()),
+        supported_versions: None,
+        meta: None,
+        tool_manifest_version: None,
     };
 
     assert_eq!(result.protocol_version, "1.0.0");
@@ -595,6 +612,39 @@ fn test_initialize_result() {
     );
 }
 
+#[test]
+fn test_initialize_meta_round_trip() {
+    let json = json!({
+        "protocolVersion": "2025-06-18",
+        "capabilities": {},
+        "clientInfo": {"name": "client", "version": "1.0.0"},
+        "_meta": {"region": "eu-west-1", "flags": ["beta"]}
+    });
+    let request: InitializeRequest = serde_json::from_value(json.clone()).unwrap();
+    let meta = request.meta.as_ref().unwrap();
+    assert_eq!(meta["region"], "eu-west-1");
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["_meta"],
+        json["_meta"]
+    );
+    assert!(initialize_meta_size(meta) < MAX_INITIALIZE_META_SIZE);
+
+    // Absent metadata stays absent on the wire
+    let result: InitializeResult = serde_json::from_value(json!({
+        "protocolVersion": "2025-06-18",
+        "capabilities": {},
+        "serverInfo": {"name": "server", "version": "1.0.0"}
+    }))
+    .unwrap();
+    assert!(result.meta.is_none());
+    assert!(
+        serde_json::to_value(&result)
+            .unwrap()
+            .get("_meta")
+            .is_none()
+    );
+}
+
 #[test]
 fn test_list_tools_request() {
     let request = ListToolsRequest;
@@ -607,6 +657,7 @@ fn test_list_tools_result() {
     let result = ListToolsResult {
         tools: vec![],
         next_cursor: Some("next".to_string()),
+        manifest_version: None,
     };
 
     assert!(result.tools.is_empty());
@@ -638,6 +689,7 @@ fn test_call_tool_result() {
     let result = CallToolResult {
         content,
         is_error: Some(false),
+        ..Default::default()
     };
 
     assert_eq!(result.content.len(), 1);
@@ -654,15 +706,7 @@ fn test_tool() {
         name: "calculator".to_string(),
         title: Some("Calculator Tool".to_string()),
         description: Some("Performs calculations".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     assert_eq!(tool.name, "calculator");
@@ -681,17 +725,8 @@ fn test_tool_with_annotations() {
 
     let tool = Tool {
         name: "annotated_tool".to_string(),
-        title: None,
-        description: None,
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
         annotations: Some(annotations),
-        meta: None,
+        ..Default::default()
     };
 
     assert!(tool.annotations.is_some());
@@ -706,10 +741,10 @@ fn test_tool_input_schema() {
     properties.insert("param1".to_string(), json!({"type": "string"}));
 
     let schema = ToolInputSchema {
-        schema_type: "object".to_string(),
         properties: Some(properties),
         required: Some(vec!["param1".to_string()]),
         additional_properties: Some(false),
+        ..Default::default()
     };
 
     assert_eq!(schema.schema_type, "object");
@@ -722,17 +757,7 @@ fn test_tool_input_schema() {
 fn test_tool_serialization() {
     let tool = Tool {
         name: "test".to_string(),
-        title: None,
-        description: None,
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let json = serde_json::to_string(&tool).unwrap();
@@ -855,6 +880,7 @@ fn test_client_request_variants() {
             title: None,
             version: "1.0.0".to_string(),
         },
+        meta: None,
     });
 
     let list_tools = ClientRequest::ListTools(ListToolsRequest);
@@ -941,7 +967,6 @@ fn test_comprehensive_serialization() {
         title: Some("Complex Tool".to_string()),
         description: Some("A complex tool for testing".to_string()),
         input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
             properties: Some({
                 let mut props = HashMap::new();
                 props.insert(
@@ -956,9 +981,9 @@ fn test_comprehensive_serialization() {
             }),
             required: Some(vec!["param1".to_string()]),
             additional_properties: Some(false),
+            ..Default::default()
         },
         output_schema: Some(ToolOutputSchema {
-            schema_type: "object".to_string(),
             properties: Some({
                 let mut props = HashMap::new();
                 props.insert("result".to_string(), json!({"type": "string"}));
@@ -966,6 +991,7 @@ fn test_comprehensive_serialization() {
             }),
             required: Some(vec!["result".to_string()]),
             additional_properties: Some(false),
+            ..Default::default()
         }),
         annotations: Some(ToolAnnotations {
             title: Some("Annotated Complex Tool".to_string()),
@@ -989,3 +1015,193 @@ fn test_comprehensive_serialization() {
     assert!(deserialized.annotations.is_some());
     assert!(deserialized.output_schema.is_some());
 }
+
+#[test]
+fn test_tool_annotation_hints() {
+    let annotations: ToolAnnotations = serde_json::from_value(json!({
+        "title": "Reader",
+        "readOnlyHint": true,
+        "idempotentHint": true,
+        "openWorldHint": "not-a-bool"
+    }))
+    .unwrap();
+
+    assert_eq!(annotations.read_only_hint(), Some(true));
+    assert_eq!(annotations.idempotent_hint(), Some(true));
+    assert_eq!(annotations.destructive_hint(), None);
+    assert_eq!(annotations.open_world_hint(), None);
+}
+
+#[test]
+fn test_template_placeholders_and_errors() {
+    use turbomcp_protocol::template::{self, TemplateError};
+
+    assert_eq!(
+        template::placeholders("file:///{dir}/{name}.{dir}").unwrap(),
+        vec!["dir", "name", "dir"]
+    );
+    assert_eq!(
+        template::placeholders("{open"),
+        Err(TemplateError::Unclosed)
+    );
+    assert_eq!(
+        template::placeholders("a{b{c}}"),
+        Err(TemplateError::Nested)
+    );
+    assert_eq!(
+        template::placeholders("a}"),
+        Err(TemplateError::UnmatchedClose)
+    );
+    assert_eq!(template::placeholders("{ }"), Err(TemplateError::EmptyName));
+
+    let err = template::render("{a}{b}{a}", &HashMap::new()).unwrap_err();
+    assert_eq!(err.to_string(), "missing argument(s): a, b");
+}
+
+#[test]
+fn test_nested_template_expansion_is_bounded() {
+    use turbomcp_protocol::template::{self, ExpansionLimits, TemplateError};
+
+    let limits = ExpansionLimits::default();
+    let arguments = HashMap::from([
+        ("greeting".to_string(), "Hello {name}".to_string()),
+        ("name".to_string(), "Ada".to_string()),
+    ]);
+    assert_eq!(
+        template::render_nested("{greeting}!", &arguments, &limits).unwrap(),
+        "Hello Ada!"
+    );
+    // Single-pass rendering leaves argument values alone
+    assert_eq!(
+        template::render("{greeting}!", &arguments).unwrap(),
+        "Hello {name}!"
+    );
+
+    // Self-reference ends at the depth limit instead of looping
+    let cycle = HashMap::from([
+        ("a".to_string(), "{b}".to_string()),
+        ("b".to_string(), "{a}".to_string()),
+    ]);
+    let err = template::render_nested("{a}", &cycle, &limits).unwrap_err();
+    assert!(matches!(
+        err,
+        TemplateError::DepthExceeded { limit: 16, .. }
+    ));
+
+    // Exponential fan-out that expands to nothing is still cut off
+    let mut fan_out: HashMap<String, String> = (0..12)
+        .map(|i| (format!("l{i}"), format!("{{l{}}}", i + 1).repeat(8)))
+        .collect();
+    fan_out.insert("l12".to_string(), String::new());
+    let err = template::render_nested("{l0}", &fan_out, &limits).unwrap_err();
+    assert_eq!(err, TemplateError::TooManySubstitutions(4096));
+
+    // Doubling text hits the size limit
+    let mut doubling: HashMap<String, String> = (0..15)
+        .map(|i| (format!("d{i}"), format!("{{d{0}}}{{d{0}}}", i + 1)))
+        .collect();
+    doubling.insert("d15".to_string(), "x".repeat(1024));
+    let err = template::render_nested("{d0}", &doubling, &limits).unwrap_err();
+    assert_eq!(err, TemplateError::TooLarge(1024 * 1024));
+}
+
+#[test]
+fn test_uri_templates_match_one_segment_per_placeholder() {
+    use turbomcp_protocol::template;
+
+    let params = template::match_uri("repo://{owner}/{name}.git", "repo://ada/engine.git").unwrap();
+    assert_eq!(params["owner"], "ada");
+    assert_eq!(params["name"], "engine");
+    assert!(template::match_uri("repo://{owner}", "repo://a/b").is_none());
+    assert!(template::match_uri("repo://{owner}", "repo://").is_none());
+    assert_eq!(
+        template::match_uri("docs://*/raw", "docs://a/b/raw"),
+        Some(HashMap::new())
+    );
+    // Malformed templates match nothing
+    assert!(template::match_uri("repo://{owner", "repo://{owner").is_none());
+
+    // A long URI that almost matches fails without backtracking forever
+    let uri = format!("x://{}", "a".repeat(512));
+    assert!(template::match_uri("x://{a}{b}{c}{d}!", &uri).is_none());
+
+    assert_eq!(
+        template::uri_templates_overlap("config://{section}", "config://general").as_deref(),
+        Some("config://general")
+    );
+    assert!(template::uri_templates_overlap("config://{section}", "config://a/b").is_none());
+}
+
+#[test]
+fn test_merge_patch_updates_json_resource() {
+    use turbomcp_protocol::{ResourcePatch, ResourcePatchError};
+
+    let mut content = ResourceContent::Text(TextResourceContents {
+        uri: "doc://live".to_string(),
+        mime_type: Some("application/json".to_string()),
+        text: r#"{"title":"Draft","tags":["a"],"owner":{"name":"ana","team":"core"}}"#.to_string(),
+        meta: None,
+    });
+    let base = json!({"title": "Draft", "tags": ["a"], "owner": {"name": "ana", "team": "core"}});
+    let patch: ResourcePatch = serde_json::from_value(json!({
+        "type": "mergePatch",
+        "baseVersion": ResourcePatch::document_version(&base),
+        "patch": {"title": "Final", "tags": null, "owner": {"team": "web"}}
+    }))
+    .unwrap();
+    assert_eq!(
+        patch,
+        ResourcePatch::merge(
+            &base,
+            json!({"title": "Final", "tags": null, "owner": {"team": "web"}})
+        )
+    );
+    patch.apply(&mut content).unwrap();
+
+    let ResourceContent::Text(text) = &content else {
+        panic!("expected text contents");
+    };
+    let document: serde_json::Value = serde_json::from_str(&text.text).unwrap();
+    assert_eq!(
+        document,
+        json!({"title": "Final", "owner": {"name": "ana", "team": "web"}})
+    );
+
+    let mut blob = ResourceContent::Blob(BlobResourceContents {
+        uri: "doc://image".to_string(),
+        mime_type: None,
+        blob: String::new(),
+        meta: None,
+    });
+    assert_eq!(patch.apply(&mut blob), Err(ResourcePatchError::NotText));
+}
+
+#[test]
+fn test_merge_patch_rejects_a_stale_copy() {
+    use turbomcp_protocol::{ResourcePatch, ResourcePatchError};
+
+    let text = r#"{"title":"Draft"}"#;
+    let mut content = ResourceContent::Text(TextResourceContents {
+        uri: "doc://live".to_string(),
+        mime_type: Some("application/json".to_string()),
+        text: text.to_string(),
+        meta: None,
+    });
+    // Made against a later version than the copy holds
+    let patch = ResourcePatch::merge(&json!({"title": "Review"}), json!({"title": "Final"}));
+    let err = patch.apply(&mut content).unwrap_err();
+    assert!(matches!(err, ResourcePatchError::VersionMismatch { .. }));
+
+    let ResourceContent::Text(unchanged) = &content else {
+        panic!("expected text contents");
+    };
+    assert_eq!(unchanged.text, text);
+
+    // Formatting does not change the version
+    assert_eq!(
+        ResourcePatch::document_version(&serde_json::from_str(text).unwrap()),
+        ResourcePatch::document_version(
+            &serde_json::from_str("{ \"title\" : \"Draft\" }").unwrap()
+        )
+    );
+}
@@ -0,0 +1,41 @@
+//! Records the git commit the server is built from, for `BuildInfo::git_hash`
+//!
+//! Builds outside a git checkout, such as from a published crate, simply leave
+//! the hash unset. Setting `TURBOMCP_GIT_HASH` in the environment overrides it.
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=TURBOMCP_GIT_HASH");
+    if std::env::var_os("TURBOMCP_GIT_HASH").is_some() {
+        return;
+    }
+
+    let Some(hash) = git(&["rev-parse", "--short=12", "HEAD"]) else {
+        return;
+    };
+    println!("cargo:rustc-env=TURBOMCP_GIT_HASH={hash}");
+
+    // Rebuild when HEAD moves, whether by checkout or by commit
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        let git_dir = Path::new(&git_dir);
+        println!("cargo:rerun-if-changed={}", git_dir.join("HEAD").display());
+        if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+            println!(
+                "cargo:rerun-if-changed={}",
+                git_dir.join(head_ref).display()
+            );
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
@@ -0,0 +1,99 @@
+//! Circuit breakers around individual tools
+//!
+//! Tools that depend on a flaky downstream opt in by returning a
+//! [`CircuitBreakerConfig`] from
+//! [`ToolHandler::circuit_breaker`](crate::handlers::ToolHandler::circuit_breaker),
+//! or through the `circuit_breakers` section of the server configuration,
+//! which takes precedence. Handler errors and results flagged `isError` count
+//! as failures. Once a breaker trips, calls to its tool fail immediately with
+//! a resource-exhausted error (`SERVER_OVERLOADED`, -32010) until the
+//! configured timeout passes; the calls after that are let through as probes,
+//! and enough successes close the breaker again.
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use dashmap::DashMap;
+use parking_lot::{Mutex, RwLock};
+use turbomcp_transport::robustness::CircuitBreaker;
+pub use turbomcp_transport::robustness::{CircuitBreakerConfig, CircuitState};
+
+use crate::{ServerError, ServerResult};
+
+/// Circuit breakers for every tool that has one, keyed by tool name
+#[derive(Debug, Default)]
+pub struct ToolCircuitBreakers {
+    /// Breaker settings from server configuration
+    configured: RwLock<HashMap<String, CircuitBreakerConfig>>,
+    /// Breakers created on each tool's first call
+    breakers: DashMap<String, Mutex<CircuitBreaker>>,
+}
+
+impl ToolCircuitBreakers {
+    /// Create an empty set of breakers
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the configured breaker settings
+    ///
+    /// Breakers are rebuilt from the new settings on each tool's next call,
+    /// so any open breaker is closed.
+    pub fn set_config(&self, configured: HashMap<String, CircuitBreakerConfig>) {
+        *self.configured.write() = configured;
+        self.breakers.clear();
+    }
+
+    /// Settings for `tool`: its configured entry, else the handler's own
+    #[must_use]
+    pub fn config_for(
+        &self,
+        tool: &str,
+        handler_config: Option<CircuitBreakerConfig>,
+    ) -> Option<CircuitBreakerConfig> {
+        self.configured.read().get(tool).cloned().or(handler_config)
+    }
+
+    /// Fail fast if `tool`'s breaker is open
+    pub fn check(&self, tool: &str, config: &CircuitBreakerConfig) -> ServerResult<()> {
+        let breaker = self
+            .breakers
+            .entry(tool.to_string())
+            .or_insert_with(|| Mutex::new(CircuitBreaker::new(config.clone())));
+        if breaker.lock().should_allow_operation() {
+            Ok(())
+        } else {
+            Err(ServerError::resource_exhausted(format!(
+                "Tool '{tool}' is unavailable: circuit breaker open"
+            )))
+        }
+    }
+
+    /// Record the outcome of a call that [`check`](Self::check) let through
+    pub fn record(&self, tool: &str, success: bool, duration: Duration) {
+        if let Some(breaker) = self.breakers.get(tool) {
+            breaker.lock().record_result(success, duration);
+        }
+    }
+
+    /// State of `tool`'s breaker, if it has been called with one
+    ///
+    /// An open breaker reports [`CircuitState::Open`] until the next call
+    /// after its timeout moves it to half-open.
+    #[must_use]
+    pub fn state(&self, tool: &str) -> Option<CircuitState> {
+        self.breakers
+            .get(tool)
+            .map(|breaker| breaker.lock().state())
+    }
+
+    /// State of every breaker that has been called
+    #[must_use]
+    pub fn states(&self) -> BTreeMap<String, CircuitState> {
+        self.breakers
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().lock().state()))
+            .collect()
+    }
+}
@@ -0,0 +1,49 @@
+//! What the running server was built from
+//!
+//! [`BuildInfo`] is reported by [`McpServer::build_info`](crate::McpServer::build_info)
+//! and included in every [`MetricsSnapshot`](crate::MetricsSnapshot), so a
+//! change in behavior can be matched to the deployment that introduced it.
+
+use serde::Serialize;
+
+/// Version, commit and feature set of this build of the server
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Version of the `turbomcp-server` crate
+    pub version: &'static str,
+    /// Abbreviated git commit hash, when built from a git checkout
+    pub git_hash: Option<&'static str>,
+    /// MCP protocol version the server speaks
+    pub protocol_version: &'static str,
+    /// Cargo features of `turbomcp-server` enabled in this build
+    pub features: Vec<&'static str>,
+}
+
+impl BuildInfo {
+    /// Build information for the running binary
+    #[must_use]
+    pub fn current() -> Self {
+        let features = [
+            ("auth", cfg!(feature = "auth")),
+            ("health-checks", cfg!(feature = "health-checks")),
+            ("hot-reload", cfg!(feature = "hot-reload")),
+            ("metrics", cfg!(feature = "metrics")),
+            ("middleware", cfg!(feature = "middleware")),
+            ("graceful-shutdown", cfg!(feature = "graceful-shutdown")),
+            ("stdio", cfg!(feature = "stdio")),
+            ("http", cfg!(feature = "http")),
+            ("websocket", cfg!(feature = "websocket")),
+            ("tcp", cfg!(feature = "tcp")),
+            ("unix", cfg!(feature = "unix")),
+        ];
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_hash: option_env!("TURBOMCP_GIT_HASH").filter(|hash| !hash.is_empty()),
+            protocol_version: turbomcp_protocol::PROTOCOL_VERSION,
+            features: features
+                .into_iter()
+                .filter_map(|(name, enabled)| enabled.then_some(name))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Result caching for deterministic tools
+//!
+//! Tools opt in by returning a [`ToolCachePolicy`] from
+//! [`ToolHandler::cache_policy`](crate::handlers::ToolHandler::cache_policy).
+//! The router then serves identical calls from the cache until the entry's TTL
+//! expires. Tools with side effects should simply not opt in.
+
+use dashmap::DashMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use turbomcp_core::{SharedClock, SystemClock};
+use turbomcp_protocol::types::CallToolResult;
+
+/// Default maximum number of cached results across all tools
+pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+/// Per-tool caching policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToolCachePolicy {
+    /// How long a cached result stays valid
+    pub ttl: Duration,
+}
+
+impl ToolCachePolicy {
+    /// Create a policy with the given time-to-live
+    #[must_use]
+    pub const fn with_ttl(ttl: Duration) -> Self {
+        Self { ttl }
+    }
+}
+
+impl Default for ToolCachePolicy {
+    fn default() -> Self {
+        Self::with_ttl(Duration::from_secs(300))
+    }
+}
+
+/// Cache hit/miss statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    /// Lookups served from the cache
+    pub hits: u64,
+    /// Lookups that fell through to the handler
+    pub misses: u64,
+    /// Entries currently stored
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups served from the cache (0.0 when there were none)
+    #[must_use]
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    result: CallToolResult,
+    inserted_at: Instant,
+    expires_at: Instant,
+}
+
+/// Bounded, TTL-based cache of tool results keyed by tool name and arguments
+#[derive(Debug)]
+pub struct ToolResultCache {
+    entries: DashMap<(String, String), CacheEntry>,
+    max_entries: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    clock: parking_lot::RwLock<SharedClock>,
+}
+
+impl ToolResultCache {
+    /// Create a cache holding at most `max_entries` results
+    #[must_use]
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            max_entries: max_entries.max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            clock: parking_lot::RwLock::new(SystemClock::shared()),
+        }
+    }
+
+    /// Expire entries by `clock` instead of the system clock
+    #[must_use]
+    pub fn with_clock(self, clock: SharedClock) -> Self {
+        self.set_clock(clock);
+        self
+    }
+
+    /// Switch to expiring entries by `clock`, keeping the capacity and
+    /// what is cached
+    pub fn set_clock(&self, clock: SharedClock) {
+        *self.clock.write() = clock;
+    }
+
+    /// Compute the cache key for a set of arguments
+    ///
+    /// Object keys are sorted recursively, so argument maps that differ only
+    /// in ordering produce the same key.
+    #[must_use]
+    pub fn cache_key(arguments: Option<&HashMap<String, Value>>) -> String {
+        match arguments {
+            None => String::new(),
+            Some(args) => {
+                let object = Value::Object(args.clone().into_iter().collect());
+                let mut key = String::new();
+                write_canonical(&object, &mut key);
+                key
+            }
+        }
+    }
+
+    /// Look up a cached result, recording a hit or miss
+    pub fn get(&self, tool: &str, key: &str) -> Option<CallToolResult> {
+        let lookup = (tool.to_string(), key.to_string());
+        let now = self.clock.read().now();
+        let cached = self
+            .entries
+            .get(&lookup)
+            .map(|entry| (entry.expires_at > now).then(|| entry.result.clone()));
+        let found = match cached {
+            Some(Some(result)) => Some(result),
+            Some(None) => {
+                self.entries.remove(&lookup);
+                None
+            }
+            None => None,
+        };
+        if found.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        found
+    }
+
+    /// Store a result, evicting expired entries (then the oldest) when full
+    pub fn insert(&self, tool: &str, key: String, result: CallToolResult, ttl: Duration) {
+        let now = self.clock.read().now();
+        if self.entries.len() >= self.max_entries {
+            self.entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if self.entries.len() >= self.max_entries {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|entry| entry.inserted_at)
+                .map(|entry| entry.key().clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(
+            (tool.to_string(), key),
+            CacheEntry {
+                result,
+                inserted_at: now,
+                expires_at: now + ttl,
+            },
+        );
+    }
+
+    /// Drop all cached results for one tool
+    pub fn invalidate_tool(&self, tool: &str) {
+        self.entries.retain(|(name, _), _| name != tool);
+    }
+
+    /// Drop every cached result
+    pub fn invalidate_all(&self) {
+        self.entries.clear();
+    }
+
+    /// Current hit/miss statistics
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.entries.len(),
+        }
+    }
+}
+
+impl Default for ToolResultCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES)
+    }
+}
+
+/// Serialize a JSON value with object keys in sorted order
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
@@ -2,11 +2,17 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use turbomcp_transport::core::TransportType;
+
+use crate::breaker::CircuitBreakerConfig;
+use crate::{ServerError, ServerResult};
+
 /// Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ServerConfig {
     /// Server name
     pub name: String,
@@ -22,12 +28,41 @@ pub struct ServerConfig {
     pub enable_tls: bool,
     /// TLS configuration
     pub tls: Option<TlsConfig>,
+    /// JSON-RPC methods each transport exposes, keyed by transport type
+    ///
+    /// Transports without an entry expose every method.
+    pub method_access: HashMap<TransportType, MethodAccessConfig>,
     /// Timeout configuration
     pub timeouts: TimeoutConfig,
     /// Rate limiting configuration
     pub rate_limiting: RateLimitingConfig,
+    /// Limits on connections that have not yet initialized
+    pub pre_init: PreInitLimitConfig,
     /// Logging configuration
     pub logging: LoggingConfig,
+    /// Tool allow/deny lists
+    pub tool_access: ToolAccessConfig,
+    /// How tool handler errors reach the client
+    pub tool_errors: ToolErrorConfig,
+    /// Scrubbing of tool text output
+    pub output_filter: OutputFilterConfig,
+    /// Circuit breakers for individual tools, keyed by tool name
+    pub circuit_breakers: HashMap<String, CircuitBreakerConfig>,
+    /// Pre-warming of tool handlers at boot
+    pub prewarm: PrewarmConfig,
+    /// Memory in bytes each tool call may use, if limited
+    ///
+    /// Handlers report what they allocate through
+    /// `RequestContext::charge_memory` and are stopped once they go over; the
+    /// serialized result must fit within the budget as well.
+    pub memory_budget: Option<usize>,
+    /// Stamp each session's notifications with consecutive sequence numbers
+    ///
+    /// Lets clients restore send order over transports that may reorder
+    /// messages; see `turbomcp_protocol::NotificationSequencer`.
+    pub sequence_notifications: bool,
+    /// Configuration reload behaviour
+    pub reload: ReloadConfig,
     /// Additional configuration
     pub additional: HashMap<String, serde_json::Value>,
 }
@@ -42,7 +77,8 @@ pub struct TlsConfig {
 }
 
 /// Timeout configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TimeoutConfig {
     /// Request timeout
     pub request_timeout: Duration,
@@ -50,10 +86,15 @@ pub struct TimeoutConfig {
     pub connection_timeout: Duration,
     /// Keep-alive timeout
     pub keep_alive_timeout: Duration,
+    /// Upper bound on shutdown; in-flight work still running after this is abandoned
+    pub shutdown_timeout: Duration,
+    /// Time allowed for startup tasks to complete before the server gives up
+    pub startup_timeout: Duration,
 }
 
 /// Rate limiting configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RateLimitingConfig {
     /// Enable rate limiting
     pub enabled: bool,
@@ -63,8 +104,26 @@ pub struct RateLimitingConfig {
     pub burst_capacity: u32,
 }
 
+/// Limits on what a connection may send before `initialize`
+///
+/// A well-behaved client initializes first, so a connection that sends a
+/// burst of messages or malformed traffic before doing so is dropped
+/// rather than served. The limits stop applying once the connection sends
+/// `initialize`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PreInitLimitConfig {
+    /// Enforce the limits
+    pub enabled: bool,
+    /// Messages a connection may send in any one second before initializing
+    pub max_messages_per_second: u32,
+    /// Malformed messages a connection may send before initializing
+    pub max_invalid_messages: u32,
+}
+
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct LoggingConfig {
     /// Log level
     pub level: String,
@@ -74,6 +133,197 @@ pub struct LoggingConfig {
     pub file: Option<PathBuf>,
 }
 
+/// Tool access configuration
+///
+/// An empty `allowed` list permits every registered tool; `denied` always wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolAccessConfig {
+    /// Tools that may be listed and called (empty = all)
+    pub allowed: Vec<String>,
+    /// Tools that are hidden and rejected
+    pub denied: Vec<String>,
+}
+
+impl ToolAccessConfig {
+    /// Check whether a tool is permitted by these lists
+    #[must_use]
+    pub fn is_allowed(&self, tool: &str) -> bool {
+        if self.denied.iter().any(|t| t == tool) {
+            return false;
+        }
+        self.allowed.is_empty() || self.allowed.iter().any(|t| t == tool)
+    }
+}
+
+/// How a tool handler's `Err` is reported to the client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolErrorMode {
+    /// Answer the call with a JSON-RPC error
+    #[default]
+    Protocol,
+    /// Answer with a `CallToolResult` flagged `isError` whose text is the
+    /// error message, so the model can read it and react within its turn
+    Result,
+}
+
+/// Tool error reporting, globally and per tool
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolErrorConfig {
+    /// Mode for tools without an entry in `tools`
+    pub mode: ToolErrorMode,
+    /// Per-tool modes, keyed by tool name
+    pub tools: HashMap<String, ToolErrorMode>,
+}
+
+impl ToolErrorConfig {
+    /// Mode for `tool`: its configured entry, else the handler's own, else
+    /// the global mode
+    #[must_use]
+    pub fn mode_for(&self, tool: &str, handler_mode: Option<ToolErrorMode>) -> ToolErrorMode {
+        self.tools
+            .get(tool)
+            .copied()
+            .or(handler_mode)
+            .unwrap_or(self.mode)
+    }
+}
+
+/// JSON-RPC method allow/deny lists for one transport
+///
+/// An empty `allowed` list permits every method; `denied` always wins.
+/// `initialize` and `ping` are always permitted, so a client on any
+/// transport can complete the handshake. A method that is not permitted is
+/// answered as if it did not exist.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MethodAccessConfig {
+    /// Methods clients on this transport may call (empty = all)
+    pub allowed: Vec<String>,
+    /// Methods hidden from clients on this transport
+    pub denied: Vec<String>,
+}
+
+impl MethodAccessConfig {
+    /// Check whether a method is permitted by these lists
+    #[must_use]
+    pub fn is_allowed(&self, method: &str) -> bool {
+        if method == "initialize" || method == "ping" {
+            return true;
+        }
+        if self.denied.iter().any(|m| m == method) {
+            return false;
+        }
+        self.allowed.is_empty() || self.allowed.iter().any(|m| m == method)
+    }
+}
+
+/// Output filter configuration
+///
+/// When enabled, text content in tool results is checked against every
+/// pattern and denied term before the result is sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OutputFilterConfig {
+    /// Enable output filtering
+    pub enabled: bool,
+    /// Regular expressions to match
+    pub patterns: Vec<String>,
+    /// Literal terms to match, ignoring case
+    pub denied_terms: Vec<String>,
+    /// What to do with a result that matches
+    pub action: OutputFilterAction,
+    /// Text substituted for each match when redacting
+    pub replacement: String,
+}
+
+/// How the output filter treats a matching tool result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFilterAction {
+    /// Replace matched text and deliver the rest of the result
+    #[default]
+    Redact,
+    /// Replace the whole result with an error
+    Reject,
+}
+
+impl OutputFilterConfig {
+    /// Check that every pattern is a valid regular expression
+    pub fn validate(&self) -> ServerResult<()> {
+        for pattern in &self.patterns {
+            regex::Regex::new(pattern).map_err(|e| {
+                ServerError::configuration_with_key(
+                    format!("Invalid output filter pattern '{pattern}': {e}"),
+                    "output_filter.patterns",
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Startup pre-warm settings
+///
+/// Every registered tool's [`prewarm`](crate::ToolHandler::prewarm) hook
+/// runs before the server accepts its first request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrewarmConfig {
+    /// Maximum number of tools warmed at once
+    pub max_concurrency: usize,
+    /// Refuse to start when a tool fails to warm, rather than logging a
+    /// warning and serving anyway
+    pub fail_on_error: bool,
+}
+
+/// Configuration reload settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReloadConfig {
+    /// Re-read the configuration file when SIGHUP is received (Unix only)
+    ///
+    /// The handler is only installed when `config_file` is set, so a server
+    /// without one keeps the default SIGHUP behavior.
+    pub on_sighup: bool,
+    /// Configuration file to re-read; reloading is a no-op when unset
+    pub config_file: Option<PathBuf>,
+}
+
+impl ReloadConfig {
+    /// Whether the run methods should reload on SIGHUP
+    ///
+    /// Requires both `on_sighup` and a `config_file` to reload from.
+    #[must_use]
+    pub const fn reloads_on_sighup(&self) -> bool {
+        self.on_sighup && self.config_file.is_some()
+    }
+}
+
+impl ServerConfig {
+    /// Load configuration from a JSON file
+    ///
+    /// Missing fields fall back to their defaults, so a file only needs to
+    /// contain the settings it overrides.
+    pub fn from_file(path: impl AsRef<Path>) -> ServerResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            ServerError::configuration_with_key(
+                format!("Failed to read configuration file: {e}"),
+                path.display().to_string(),
+            )
+        })?;
+        serde_json::from_str(&contents).map_err(|e| {
+            ServerError::configuration_with_key(
+                format!("Invalid configuration file: {e}"),
+                path.display().to_string(),
+            )
+        })
+    }
+}
+
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
@@ -84,9 +334,19 @@ impl Default for ServerConfig {
             port: 8080,
             enable_tls: false,
             tls: None,
+            method_access: HashMap::new(),
             timeouts: TimeoutConfig::default(),
             rate_limiting: RateLimitingConfig::default(),
+            pre_init: PreInitLimitConfig::default(),
             logging: LoggingConfig::default(),
+            tool_access: ToolAccessConfig::default(),
+            tool_errors: ToolErrorConfig::default(),
+            output_filter: OutputFilterConfig::default(),
+            circuit_breakers: HashMap::new(),
+            prewarm: PrewarmConfig::default(),
+            memory_budget: None,
+            sequence_notifications: false,
+            reload: ReloadConfig::default(),
             additional: HashMap::new(),
         }
     }
@@ -98,6 +358,8 @@ impl Default for TimeoutConfig {
             request_timeout: Duration::from_secs(30),
             connection_timeout: Duration::from_secs(10),
             keep_alive_timeout: Duration::from_secs(60),
+            shutdown_timeout: Duration::from_secs(30),
+            startup_timeout: Duration::from_secs(60),
         }
     }
 }
@@ -112,6 +374,16 @@ impl Default for RateLimitingConfig {
     }
 }
 
+impl Default for PreInitLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_messages_per_second: 10,
+            max_invalid_messages: 5,
+        }
+    }
+}
+
 impl Default for LoggingConfig {
     fn default() -> Self {
         Self {
@@ -122,6 +394,36 @@ impl Default for LoggingConfig {
     }
 }
 
+impl Default for OutputFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            patterns: Vec::new(),
+            denied_terms: Vec::new(),
+            action: OutputFilterAction::Redact,
+            replacement: "[REDACTED]".to_string(),
+        }
+    }
+}
+
+impl Default for PrewarmConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 4,
+            fail_on_error: true,
+        }
+    }
+}
+
+impl Default for ReloadConfig {
+    fn default() -> Self {
+        Self {
+            on_sighup: true,
+            config_file: None,
+        }
+    }
+}
+
 /// Configuration builder
 #[derive(Debug)]
 pub struct ConfigurationBuilder {
@@ -180,6 +482,13 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Restrict the JSON-RPC methods clients on `transport` may call
+    #[must_use]
+    pub fn method_access(mut self, transport: TransportType, access: MethodAccessConfig) -> Self {
+        self.config.method_access.insert(transport, access);
+        self
+    }
+
     /// Set request timeout
     #[must_use]
     pub const fn request_timeout(mut self, timeout: Duration) -> Self {
@@ -187,6 +496,20 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Set shutdown timeout
+    #[must_use]
+    pub const fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeouts.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Set startup timeout
+    #[must_use]
+    pub const fn startup_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeouts.startup_timeout = timeout;
+        self
+    }
+
     /// Enable rate limiting
     #[must_use]
     pub const fn rate_limiting(mut self, requests_per_second: u32, burst_capacity: u32) -> Self {
@@ -196,12 +519,86 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Limit what connections may send before initializing
+    #[must_use]
+    pub const fn pre_init_limit(
+        mut self,
+        max_messages_per_second: u32,
+        max_invalid_messages: u32,
+    ) -> Self {
+        self.config.pre_init.enabled = true;
+        self.config.pre_init.max_messages_per_second = max_messages_per_second;
+        self.config.pre_init.max_invalid_messages = max_invalid_messages;
+        self
+    }
+
     /// Set log level
     pub fn log_level(mut self, level: impl Into<String>) -> Self {
         self.config.logging.level = level.into();
         self
     }
 
+    /// Set the file re-read on configuration reload
+    #[must_use]
+    pub fn config_file(mut self, path: PathBuf) -> Self {
+        self.config.reload.config_file = Some(path);
+        self
+    }
+
+    /// Set tool allow/deny lists
+    #[must_use]
+    pub fn tool_access(mut self, tool_access: ToolAccessConfig) -> Self {
+        self.config.tool_access = tool_access;
+        self
+    }
+
+    /// Set how tool handler errors are reported
+    #[must_use]
+    pub fn tool_errors(mut self, tool_errors: ToolErrorConfig) -> Self {
+        self.config.tool_errors = tool_errors;
+        self
+    }
+
+    /// Put a circuit breaker around the named tool
+    #[must_use]
+    pub fn circuit_breaker(
+        mut self,
+        tool: impl Into<String>,
+        config: CircuitBreakerConfig,
+    ) -> Self {
+        self.config.circuit_breakers.insert(tool.into(), config);
+        self
+    }
+
+    /// Enable output filtering with the given rules
+    #[must_use]
+    pub fn output_filter(mut self, output_filter: OutputFilterConfig) -> Self {
+        self.config.output_filter = output_filter;
+        self.config.output_filter.enabled = true;
+        self
+    }
+
+    /// Set how tools are pre-warmed at boot
+    #[must_use]
+    pub const fn prewarm(mut self, prewarm: PrewarmConfig) -> Self {
+        self.config.prewarm = prewarm;
+        self
+    }
+
+    /// Limit the memory each tool call may use to `bytes`
+    #[must_use]
+    pub const fn memory_budget(mut self, bytes: usize) -> Self {
+        self.config.memory_budget = Some(bytes);
+        self
+    }
+
+    /// Stamp each session's notifications with sequence numbers
+    #[must_use]
+    pub const fn sequence_notifications(mut self) -> Self {
+        self.config.sequence_notifications = true;
+        self
+    }
+
     /// Build the configuration
     #[must_use]
     pub fn build(self) -> ServerConfig {
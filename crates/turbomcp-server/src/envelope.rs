@@ -0,0 +1,131 @@
+//! JSON-RPC envelope validation
+//!
+//! Every message from a client is checked against the JSON-RPC 2.0 envelope
+//! before it is deserialized, so a malformed message is rejected the same
+//! way whatever is wrong with it. Without this, a request whose `id` has the
+//! wrong type would parse as a notification and go unanswered, and one
+//! missing `jsonrpc` would be dropped as unparseable. The check runs ahead
+//! of the middleware stack and the router, so neither authentication nor a
+//! handler ever sees a message that is not a well-formed request,
+//! notification or response; violations are answered with
+//! `INVALID_REQUEST` (-32600).
+
+use serde_json::Value;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcError, JsonRpcResponse, JsonRpcVersion};
+
+/// JSON-RPC error code for a malformed envelope
+pub const INVALID_REQUEST: i32 = turbomcp_protocol::error_codes::INVALID_REQUEST;
+
+/// Why a message's envelope was rejected
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid Request: {reason}")]
+pub struct EnvelopeError {
+    /// The message's `id`, when it had a usable one
+    pub id: Option<RequestId>,
+    /// What was wrong with the envelope
+    pub reason: &'static str,
+}
+
+impl EnvelopeError {
+    /// The `INVALID_REQUEST` response to send back for this message
+    #[must_use]
+    pub fn to_response(&self) -> JsonRpcResponse {
+        JsonRpcResponse {
+            jsonrpc: JsonRpcVersion,
+            id: self.id.clone(),
+            result: None,
+            error: Some(JsonRpcError {
+                code: INVALID_REQUEST,
+                message: self.to_string(),
+                data: None,
+            }),
+        }
+    }
+}
+
+/// Check that one message has a valid JSON-RPC 2.0 envelope
+///
+/// Requests and notifications need `"jsonrpc": "2.0"`, a string `method`
+/// and, if present, object or array `params`; requests also need a string
+/// or integer `id`. Responses (messages with `result` or `error` and no
+/// `method`) need the version and an `id` that is a string, integer or
+/// null.
+pub fn validate_envelope(message: &Value) -> Result<(), EnvelopeError> {
+    let Some(object) = message.as_object() else {
+        return Err(EnvelopeError {
+            id: None,
+            reason: "message must be a JSON object",
+        });
+    };
+
+    let raw_id = object.get("id");
+    let id = raw_id.and_then(|id| match id {
+        Value::String(s) => Some(RequestId::String(s.clone())),
+        Value::Number(n) => n.as_i64().map(RequestId::Number),
+        _ => None,
+    });
+    let reject = |reason| {
+        Err(EnvelopeError {
+            id: id.clone(),
+            reason,
+        })
+    };
+
+    match object.get("jsonrpc") {
+        None => return reject("missing 'jsonrpc' member"),
+        Some(version) if version != "2.0" => return reject("'jsonrpc' must be exactly \"2.0\""),
+        Some(_) => {}
+    }
+
+    let is_response = !object.contains_key("method")
+        && (object.contains_key("result") || object.contains_key("error"));
+    match raw_id {
+        Some(Value::Null) if is_response => {}
+        Some(Value::Null) => return reject("'id' must not be null"),
+        Some(_) if id.is_none() => return reject("'id' must be a string or an integer"),
+        None if is_response => return reject("missing 'id' member"),
+        _ => {}
+    }
+    if is_response {
+        return Ok(());
+    }
+
+    match object.get("method") {
+        None => return reject("missing 'method' member"),
+        Some(Value::String(_)) => {}
+        Some(_) => return reject("'method' must be a string"),
+    }
+    match object.get("params") {
+        None | Some(Value::Object(_) | Value::Array(_)) => Ok(()),
+        Some(_) => reject("'params' must be an object or an array"),
+    }
+}
+
+/// Drop batch entries with malformed envelopes
+///
+/// Returns the well-formed entries, still in order, and a response for each
+/// rejected one. An empty batch is itself an invalid request, answered with
+/// a single error object rather than an array.
+pub fn screen_batch(items: Vec<Value>) -> (Vec<Value>, Vec<JsonRpcResponse>) {
+    if items.is_empty() {
+        let error = EnvelopeError {
+            id: None,
+            reason: "batch must not be empty",
+        };
+        return (Vec::new(), vec![error.to_response()]);
+    }
+
+    let mut rejected = Vec::new();
+    let valid = items
+        .into_iter()
+        .filter(|item| match validate_envelope(item) {
+            Ok(()) => true,
+            Err(error) => {
+                rejected.push(error.to_response());
+                false
+            }
+        })
+        .collect();
+    (valid, rejected)
+}
@@ -89,6 +89,24 @@ pub enum ServerError {
         method: Option<String>,
     },
 
+    /// Invalid method parameters
+    #[error("Invalid params: {message}")]
+    InvalidParams {
+        /// Error message
+        message: String,
+    },
+
+    /// A tool argument does not convert to its parameter's type
+    #[error("Invalid params: argument '{argument}' must be {expected}: {reason}")]
+    InvalidArgument {
+        /// Name of the argument
+        argument: String,
+        /// Type the parameter expects
+        expected: String,
+        /// Why the value does not convert
+        reason: String,
+    },
+
     /// Resource not found
     #[error("Resource not found: {resource}")]
     NotFound {
@@ -117,6 +135,20 @@ pub enum ServerError {
         timeout_ms: u64,
     },
 
+    /// Error reported to the client with its own JSON-RPC code
+    ///
+    /// Used for errors raised outside this crate that already know which
+    /// code they map to.
+    #[error("{message}")]
+    JsonRpc {
+        /// JSON-RPC error code
+        code: i32,
+        /// Error message
+        message: String,
+        /// Value for the `data` member of the error
+        data: Option<serde_json::Value>,
+    },
+
     /// Resource exhaustion
     #[error("Resource exhausted: {resource}")]
     ResourceExhausted {
@@ -240,6 +272,28 @@ impl ServerError {
         }
     }
 
+    /// Create an invalid params error
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::InvalidParams {
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for an argument that does not convert to `expected`
+    ///
+    /// `reason` is typically the `serde_json` deserialization error.
+    pub fn invalid_argument(
+        argument: impl Into<String>,
+        expected: impl Into<String>,
+        reason: impl std::fmt::Display,
+    ) -> Self {
+        Self::InvalidArgument {
+            argument: argument.into(),
+            expected: expected.into(),
+            reason: reason.to_string(),
+        }
+    }
+
     /// Create a not found error
     pub fn not_found(resource: impl Into<String>) -> Self {
         Self::NotFound {
@@ -247,6 +301,15 @@ impl ServerError {
         }
     }
 
+    /// Create an error reported with the given JSON-RPC code
+    pub fn json_rpc(code: i32, message: impl Into<String>) -> Self {
+        Self::JsonRpc {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
     /// Create a timeout error
     pub fn timeout(operation: impl Into<String>, timeout_ms: u64) -> Self {
         Self::Timeout {
@@ -300,6 +363,7 @@ impl ServerError {
     pub const fn error_code(&self) -> i32 {
         match self {
             Self::Core(_) => -32603,
+            Self::InvalidParams { .. } | Self::InvalidArgument { .. } => -32602,
             Self::NotFound { .. } => -32004,
             Self::Authentication { .. } => -32008,
             Self::Authorization { .. } => -32005,
@@ -307,9 +371,28 @@ impl ServerError {
             Self::ResourceExhausted { .. } => -32010,
             Self::Timeout { .. } => -32603,
             Self::Handler { .. } => -32002,
+            Self::JsonRpc { code, .. } => *code,
             _ => -32603,
         }
     }
+
+    /// Structured details for the `data` member of a JSON-RPC error
+    #[must_use]
+    pub fn error_data(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::InvalidArgument {
+                argument,
+                expected,
+                reason,
+            } => Some(serde_json::json!({
+                "argument": argument,
+                "expected": expected,
+                "reason": reason,
+            })),
+            Self::JsonRpc { data, .. } => data.clone(),
+            _ => None,
+        }
+    }
 }
 
 /// Error recovery strategies
@@ -371,6 +454,19 @@ impl ErrorContext {
     }
 }
 
+impl From<turbomcp_core::BudgetExceeded> for ServerError {
+    fn from(exceeded: turbomcp_core::BudgetExceeded) -> Self {
+        Self::resource_exhausted_with_usage(
+            format!(
+                "request memory budget ({} bytes in use, {} more requested, {}-byte budget)",
+                exceeded.used, exceeded.requested, exceeded.limit
+            ),
+            exceeded.used.saturating_add(exceeded.requested),
+            exceeded.limit,
+        )
+    }
+}
+
 // Conversion from core errors to server errors
 impl From<Box<turbomcp_core::Error>> for ServerError {
     fn from(core_error: Box<turbomcp_core::Error>) -> Self {
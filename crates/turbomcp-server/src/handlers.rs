@@ -1,18 +1,29 @@
 //! Handler traits and implementations for MCP operations
 
 use async_trait::async_trait;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::{Bytes, BytesMut};
+use futures::stream::{Stream, TryStreamExt};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::pin::Pin;
 use std::sync::Arc;
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::LogLevel;
 use turbomcp_protocol::types::{
-    CallToolRequest, CallToolResult, CreateMessageRequest, CreateMessageResult, EmptyResult,
+    BlobResourceContents, CallToolRequest, CallToolResult, CompleteRequest, CompleteResult,
+    CompletionReference, ContentRange, CreateMessageRequest, CreateMessageResult, EmptyResult,
     GetPromptRequest, GetPromptResult, LoggingCapabilities, Prompt, ReadResourceRequest,
-    ReadResourceResult, Resource, SamplingCapabilities, SetLevelRequest, Tool, ToolInputSchema,
+    ReadResourceResult, Resource, ResourceContent, SamplingCapabilities, SetLevelRequest,
+    TextResourceContents, Tool, ToolInputSchema, ToolOutputSchema,
 };
 
-use crate::ServerResult;
+use crate::breaker::CircuitBreakerConfig;
+use crate::cache::ToolCachePolicy;
+use crate::config::ToolErrorMode;
+use crate::output_limit::ToolOutputLimit;
+use crate::{ServerError, ServerResult};
 
 /// Type alias for existence check functions to reduce complexity
 type ExistenceCheckFn = Arc<dyn Fn(&str) -> BoxFuture<bool> + Send + Sync>;
@@ -39,6 +50,44 @@ pub trait ToolHandler: Send + Sync {
     fn allowed_roles(&self) -> Option<&[String]> {
         None
     }
+
+    /// Result caching policy. None (the default) disables caching; only
+    /// deterministic, side-effect-free tools should opt in.
+    fn cache_policy(&self) -> Option<ToolCachePolicy> {
+        None
+    }
+
+    /// Maximum serialized size of the call arguments in bytes. None (the
+    /// default) leaves the tool bounded only by the transport message limit.
+    fn max_input_size(&self) -> Option<usize> {
+        None
+    }
+
+    /// Limit on the serialized size of results. None (the default) sends
+    /// results of any size; see [`output_limit`](crate::output_limit).
+    fn output_limit(&self) -> Option<ToolOutputLimit> {
+        None
+    }
+
+    /// Circuit breaker settings. None (the default) calls the tool however
+    /// often it fails; see [`breaker`](crate::breaker).
+    fn circuit_breaker(&self) -> Option<CircuitBreakerConfig> {
+        None
+    }
+
+    /// How errors from [`handle`](Self::handle) are reported. None (the
+    /// default) defers to the server's `tool_errors` configuration.
+    fn error_mode(&self) -> Option<ToolErrorMode> {
+        None
+    }
+
+    /// Prepare expensive resources before the server accepts requests
+    ///
+    /// Called once at boot; see [`prewarm`](crate::prewarm). The default does
+    /// nothing, leaving any setup to the first call.
+    async fn prewarm(&self) -> ServerResult<()> {
+        Ok(())
+    }
 }
 
 /// Prompt handler trait for processing prompt requests
@@ -64,6 +113,11 @@ pub trait PromptHandler: Send + Sync {
 #[async_trait]
 pub trait ResourceHandler: Send + Sync {
     /// Handle a resource read request
+    ///
+    /// Handlers that support ranged reads return only the bytes selected by
+    /// the request's `offset` and `length` and describe them in the result's
+    /// `range`. Handlers that ignore the range return the whole resource with
+    /// no `range`, which tells the client it got everything.
     async fn handle(
         &self,
         request: ReadResourceRequest,
@@ -80,6 +134,73 @@ pub trait ResourceHandler: Send + Sync {
     async fn metadata(&self, _uri: &str) -> Option<HashMap<String, Value>> {
         None
     }
+
+    /// The streaming handler behind this resource, if it has one
+    ///
+    /// Lets callers that can forward chunks, such as
+    /// [`RequestRouter::open_resource_stream`](crate::RequestRouter::open_resource_stream),
+    /// bypass the buffered [`handle`](Self::handle) path.
+    fn as_streaming(&self) -> Option<&dyn StreamingResourceHandler> {
+        None
+    }
+}
+
+/// Resource content delivered as a sequence of byte chunks
+pub type ResourceStream = Pin<Box<dyn Stream<Item = ServerResult<Bytes>> + Send>>;
+
+/// Resource handler that produces its content lazily
+///
+/// An alternative to [`ResourceHandler`] for content that is large or
+/// produced over time, such as a big file read in chunks or a log being
+/// tailed. Register it wrapped in [`StreamingResource`].
+#[async_trait]
+pub trait StreamingResourceHandler: Send + Sync {
+    /// Open a stream over the content of the requested resource
+    ///
+    /// The stream always starts at the first byte of the resource; the
+    /// `offset` and `length` of a ranged read are applied to it afterwards.
+    async fn open(
+        &self,
+        request: ReadResourceRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<ResourceStream>;
+
+    /// Get the resource definition
+    fn resource_definition(&self) -> Resource;
+
+    /// Check if resource exists
+    async fn exists(&self, _uri: &str) -> bool {
+        true
+    }
+}
+
+/// Restrict `stream` to `length` bytes, or the remainder, starting at `offset`
+///
+/// Chunks before the range are dropped without being held, and the
+/// underlying stream is not polled past the end of the range.
+#[must_use]
+pub fn slice_stream(stream: ResourceStream, offset: u64, length: Option<u64>) -> ResourceStream {
+    let state = (stream, offset, length.unwrap_or(u64::MAX));
+    Box::pin(futures::stream::try_unfold(
+        state,
+        |(mut stream, mut skip, remaining)| async move {
+            while remaining > 0
+                && let Some(mut chunk) = stream.try_next().await?
+            {
+                let len = chunk.len() as u64;
+                if len <= skip {
+                    skip -= len;
+                    continue;
+                }
+                // `skip < len`, so both conversions fit the chunk's usize length
+                chunk = chunk.slice(skip as usize..);
+                chunk.truncate(remaining.min(chunk.len() as u64) as usize);
+                let remaining = remaining - chunk.len() as u64;
+                return Ok(Some((chunk, (stream, 0, remaining))));
+            }
+            Ok(None)
+        },
+    ))
 }
 
 /// Sampling handler trait for processing sampling requests
@@ -117,6 +238,26 @@ pub trait LoggingHandler: Send + Sync {
     }
 }
 
+/// Completion handler trait for suggesting argument values
+///
+/// Each handler completes one argument of one prompt or resource, and is
+/// consulted by `completion/complete` requests naming that pair.
+#[async_trait]
+pub trait CompletionHandler: Send + Sync {
+    /// Suggest values for the argument from the partial value in the request
+    async fn handle(
+        &self,
+        request: CompleteRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<CompleteResult>;
+
+    /// The prompt or resource whose argument this completes
+    fn reference(&self) -> CompletionReference;
+
+    /// Name of the argument this completes
+    fn argument(&self) -> String;
+}
+
 /// Composite handler that can handle multiple types of requests
 pub trait CompositeHandler: Send + Sync {
     /// Get tool handler if this composite handles tools
@@ -259,6 +400,18 @@ pub struct FunctionToolHandler {
     >,
     /// Allowed roles (RBAC)
     allowed_roles: Option<Vec<String>>,
+    /// Result caching policy
+    cache_policy: Option<ToolCachePolicy>,
+    /// Maximum argument size in bytes
+    max_input_size: Option<usize>,
+    /// Result size limit
+    output_limit: Option<ToolOutputLimit>,
+    /// Circuit breaker settings
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    /// How handler errors are reported
+    error_mode: Option<ToolErrorMode>,
+    /// Startup pre-warm hook
+    prewarm: Option<Arc<dyn Fn() -> BoxFuture<ServerResult<()>> + Send + Sync>>,
 }
 
 impl std::fmt::Debug for FunctionToolHandler {
@@ -296,8 +449,67 @@ impl FunctionToolHandler {
             tool,
             handler,
             allowed_roles,
+            cache_policy: None,
+            max_input_size: None,
+            output_limit: None,
+            circuit_breaker: None,
+            error_mode: None,
+            prewarm: None,
         }
     }
+
+    /// Cache results of this tool for `ttl`, keyed by its arguments
+    #[must_use]
+    pub fn with_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.cache_policy = Some(ToolCachePolicy::with_ttl(ttl));
+        self
+    }
+
+    /// Reject calls whose arguments serialize to more than `bytes`
+    #[must_use]
+    pub const fn with_max_input_size(mut self, bytes: usize) -> Self {
+        self.max_input_size = Some(bytes);
+        self
+    }
+
+    /// Truncate or reject results larger than `limit` allows
+    #[must_use]
+    pub const fn with_output_limit(mut self, limit: ToolOutputLimit) -> Self {
+        self.output_limit = Some(limit);
+        self
+    }
+
+    /// Stop calling this tool for a while after repeated failures
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Advertise `schema` as the shape of this tool's `structuredContent`
+    #[must_use]
+    pub fn with_output_schema(mut self, schema: ToolOutputSchema) -> Self {
+        self.tool.output_schema = Some(schema);
+        self
+    }
+
+    /// Report this tool's errors in `mode`, whatever the server default
+    #[must_use]
+    pub const fn with_error_mode(mut self, mode: ToolErrorMode) -> Self {
+        self.error_mode = Some(mode);
+        self
+    }
+
+    /// Run `prewarm` at server boot, before the first call
+    #[must_use]
+    pub fn with_prewarm<F, Fut>(mut self, prewarm: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<()>> + Send + 'static,
+    {
+        self.prewarm = Some(Arc::new(move || Box::pin(prewarm()) as BoxFuture<_>));
+        self
+    }
 }
 
 #[async_trait]
@@ -317,6 +529,33 @@ impl ToolHandler for FunctionToolHandler {
     fn allowed_roles(&self) -> Option<&[String]> {
         self.allowed_roles.as_deref()
     }
+
+    fn cache_policy(&self) -> Option<ToolCachePolicy> {
+        self.cache_policy
+    }
+
+    fn max_input_size(&self) -> Option<usize> {
+        self.max_input_size
+    }
+
+    fn output_limit(&self) -> Option<ToolOutputLimit> {
+        self.output_limit
+    }
+
+    fn circuit_breaker(&self) -> Option<CircuitBreakerConfig> {
+        self.circuit_breaker.clone()
+    }
+
+    fn error_mode(&self) -> Option<ToolErrorMode> {
+        self.error_mode
+    }
+
+    async fn prewarm(&self) -> ServerResult<()> {
+        match &self.prewarm {
+            Some(prewarm) => prewarm().await,
+            None => Ok(()),
+        }
+    }
 }
 
 /// Function-based prompt handler
@@ -366,6 +605,68 @@ impl PromptHandler for FunctionPromptHandler {
     }
 }
 
+/// Function-based completion handler
+pub struct FunctionCompletionHandler {
+    /// Prompt or resource the argument belongs to
+    reference: CompletionReference,
+    /// Argument completed
+    argument: String,
+    /// Handler function
+    handler: Arc<
+        dyn Fn(CompleteRequest, RequestContext) -> BoxFuture<ServerResult<CompleteResult>>
+            + Send
+            + Sync,
+    >,
+}
+
+impl std::fmt::Debug for FunctionCompletionHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FunctionCompletionHandler")
+            .field("reference", &self.reference)
+            .field("argument", &self.argument)
+            .finish()
+    }
+}
+
+impl FunctionCompletionHandler {
+    /// Create a new function-based completion handler
+    pub fn new<F, Fut>(
+        reference: CompletionReference,
+        argument: impl Into<String>,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(CompleteRequest, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<CompleteResult>> + Send + 'static,
+    {
+        let handler = Arc::new(move |req, ctx| Box::pin(handler(req, ctx)) as BoxFuture<_>);
+        Self {
+            reference,
+            argument: argument.into(),
+            handler,
+        }
+    }
+}
+
+#[async_trait]
+impl CompletionHandler for FunctionCompletionHandler {
+    async fn handle(
+        &self,
+        request: CompleteRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<CompleteResult> {
+        (self.handler)(request, ctx).await
+    }
+
+    fn reference(&self) -> CompletionReference {
+        self.reference.clone()
+    }
+
+    fn argument(&self) -> String {
+        self.argument.clone()
+    }
+}
+
 /// Function-based resource handler
 pub struct FunctionResourceHandler {
     /// Resource definition
@@ -424,10 +725,123 @@ impl ResourceHandler for FunctionResourceHandler {
     }
 }
 
+/// Default limit on content buffered when a streaming resource is read
+/// through `resources/read`
+pub const DEFAULT_MAX_BUFFERED_RESOURCE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Adapter registering a [`StreamingResourceHandler`] as a resource
+///
+/// Callers that can forward chunks get the stream itself through
+/// [`ResourceHandler::as_streaming`]. A plain `resources/read` has to return
+/// the whole content in one response, so that path collects the chunks, up to
+/// a size limit, into a single text entry (or a base64 blob when the content
+/// is not UTF-8 or the resource declares a binary MIME type).
+pub struct StreamingResource<H> {
+    handler: H,
+    max_buffered_size: usize,
+}
+
+impl<H> std::fmt::Debug for StreamingResource<H> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingResource")
+            .field("max_buffered_size", &self.max_buffered_size)
+            .finish()
+    }
+}
+
+impl<H: StreamingResourceHandler> StreamingResource<H> {
+    /// Wrap a streaming handler
+    pub const fn new(handler: H) -> Self {
+        Self {
+            handler,
+            max_buffered_size: DEFAULT_MAX_BUFFERED_RESOURCE_SIZE,
+        }
+    }
+
+    /// Set the most content a buffered `resources/read` may collect
+    #[must_use]
+    pub const fn with_max_buffered_size(mut self, max_buffered_size: usize) -> Self {
+        self.max_buffered_size = max_buffered_size;
+        self
+    }
+}
+
+#[async_trait]
+impl<H: StreamingResourceHandler> ResourceHandler for StreamingResource<H> {
+    async fn handle(
+        &self,
+        request: ReadResourceRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<ReadResourceResult> {
+        let uri = request.uri.clone();
+        let range = request
+            .is_ranged()
+            .then(|| (request.offset.unwrap_or(0), request.length));
+        let mut stream = self.handler.open(request, ctx).await?;
+        if let Some((offset, length)) = range {
+            stream = slice_stream(stream, offset, length);
+        }
+        let mut buffer = BytesMut::new();
+        while let Some(chunk) = stream.try_next().await? {
+            if buffer.len() + chunk.len() > self.max_buffered_size {
+                return Err(ServerError::handler(format!(
+                    "Resource '{uri}' exceeds {} bytes; read it as a stream instead",
+                    self.max_buffered_size
+                )));
+            }
+            buffer.extend_from_slice(&chunk);
+        }
+
+        let definition = self.handler.resource_definition();
+        let range = range.map(|(offset, _)| ContentRange {
+            offset,
+            length: buffer.len() as u64,
+            total_size: definition.size,
+        });
+        let mime_type = definition.mime_type;
+        // A declared binary type is kept as bytes even when they happen to be UTF-8
+        let textual = mime_type.as_deref().is_none_or(crate::mime::is_textual);
+        let text = textual
+            .then(|| String::from_utf8(buffer.to_vec()).ok())
+            .flatten();
+        let content = match text {
+            Some(text) => ResourceContent::Text(TextResourceContents {
+                uri,
+                mime_type,
+                text,
+                meta: None,
+            }),
+            None => ResourceContent::Blob(BlobResourceContents {
+                uri,
+                mime_type,
+                blob: BASE64.encode(&buffer),
+                meta: None,
+            }),
+        };
+        Ok(ReadResourceResult {
+            contents: vec![content],
+            range,
+        })
+    }
+
+    fn resource_definition(&self) -> Resource {
+        self.handler.resource_definition()
+    }
+
+    async fn exists(&self, uri: &str) -> bool {
+        self.handler.exists(uri).await
+    }
+
+    fn as_streaming(&self) -> Option<&dyn StreamingResourceHandler> {
+        Some(&self.handler)
+    }
+}
+
 /// Utility functions for creating handlers
 pub mod utils {
     use super::{
-        CallToolRequest, CallToolResult, FunctionPromptHandler, FunctionResourceHandler,
+        CallToolRequest, CallToolResult, CompleteRequest, CompleteResult, CompletionReference,
+        FunctionCompletionHandler, FunctionPromptHandler, FunctionResourceHandler,
         FunctionToolHandler, GetPromptRequest, GetPromptResult, Prompt, ReadResourceRequest,
         ReadResourceResult, RequestContext, Resource, ServerResult, Tool, ToolInputSchema,
     };
@@ -446,14 +860,12 @@ pub mod utils {
             title: Some(name.to_string()),
             description: Some(description.to_string()),
             input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
                 properties: Some(std::collections::HashMap::new()), // Extensible for macro schemas
                 required: Some(Vec::new()), // Extensible for macro-generated required fields
                 additional_properties: Some(false),
+                ..Default::default()
             },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         };
         FunctionToolHandler::new(tool, handler)
     }
@@ -469,7 +881,7 @@ pub mod utils {
         F: Fn(CallToolRequest, RequestContext) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = ServerResult<CallToolResult>> + Send + 'static,
     {
-        // Extract properties, required, and additionalProperties from the schema
+        // Extract properties, required, additionalProperties and examples from the schema
         let properties = schema
             .get("properties")
             .and_then(|v| v.as_object())
@@ -500,19 +912,24 @@ pub mod utils {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
+        let examples = schema
+            .get("examples")
+            .and_then(|v| v.as_array())
+            .filter(|examples| !examples.is_empty())
+            .cloned();
+
         let tool = Tool {
             name: name.to_string(),
             title: Some(name.to_string()),
             description: Some(description.to_string()),
             input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
                 properties: Some(properties),
                 required: Some(required),
                 additional_properties: Some(additional_properties),
+                examples,
+                ..Default::default()
             },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         };
         FunctionToolHandler::new(tool, handler)
     }
@@ -551,4 +968,36 @@ pub mod utils {
         };
         FunctionResourceHandler::new(resource, handler)
     }
+
+    /// Create a handler completing `argument` of the prompt named `prompt`
+    pub fn prompt_completion<F, Fut>(
+        prompt: &str,
+        argument: &str,
+        handler: F,
+    ) -> FunctionCompletionHandler
+    where
+        F: Fn(CompleteRequest, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<CompleteResult>> + Send + 'static,
+    {
+        let reference = CompletionReference::Prompt {
+            name: prompt.to_string(),
+        };
+        FunctionCompletionHandler::new(reference, argument, handler)
+    }
+
+    /// Create a handler completing `argument` of the resource template `uri`
+    pub fn resource_completion<F, Fut>(
+        uri: &str,
+        argument: &str,
+        handler: F,
+    ) -> FunctionCompletionHandler
+    where
+        F: Fn(CompleteRequest, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<CompleteResult>> + Send + 'static,
+    {
+        let reference = CompletionReference::Resource {
+            uri: uri.to_string(),
+        };
+        FunctionCompletionHandler::new(reference, argument, handler)
+    }
 }
@@ -0,0 +1,170 @@
+//! Recent requests of each session, for live debugging
+//!
+//! When one connection misbehaves, its last few requests usually say more
+//! than aggregate metrics, and full wire tracing is too heavy to leave on.
+//! Given a [`SessionHistory`] through
+//! [`ServerBuilder::with_session_history`](crate::ServerBuilder::with_session_history),
+//! the server keeps, for every session, a ring of the last `capacity`
+//! requests it answered, each summarized as a
+//! [`HistoryEntry`]. Read it through
+//! [`McpServer::session_history`](crate::McpServer::session_history) while
+//! the session is live; its entries are dropped when the session ends.
+//!
+//! Memory is bounded per session by the capacity and per entry by capping
+//! every client-supplied string. Arguments are kept only as a short preview,
+//! with the values of sensitive fields such as passwords and tokens replaced
+//! before anything is stored.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::Serialize;
+use turbomcp_transport::wire_trace::WireTracer;
+
+use crate::request_log::{RequestRecord, RequestStatus};
+
+/// Fields whose values are redacted from argument previews by default
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "password",
+    "secret",
+    "token",
+    "accessToken",
+    "access_token",
+    "apiKey",
+    "api_key",
+    "authorization",
+];
+
+/// Longest argument preview kept, in characters
+pub const MAX_ARGUMENTS_PREVIEW: usize = 256;
+
+/// Longest method, tool name or request id kept, in characters
+const MAX_NAME_LEN: usize = 128;
+
+/// Summary of one request a session made
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistoryEntry {
+    /// When the request arrived
+    pub timestamp: DateTime<Utc>,
+    /// JSON-RPC id of the request, as JSON text
+    pub request_id: String,
+    /// JSON-RPC method
+    pub method: String,
+    /// Tool called, for `tools/call`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    /// Redacted, truncated JSON of the request's parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<String>,
+    /// Whether the response carried a result or an error
+    pub status: RequestStatus,
+    /// JSON-RPC error code of an error response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    /// Time from arrival to response, in milliseconds
+    pub duration_ms: f64,
+}
+
+/// Bounded request history of every live session
+///
+/// Clones share the recorded sessions.
+#[derive(Debug, Clone)]
+pub struct SessionHistory {
+    capacity: usize,
+    redactor: WireTracer,
+    sessions: Arc<DashMap<String, VecDeque<HistoryEntry>>>,
+}
+
+impl SessionHistory {
+    /// Keep the last `capacity` requests of each session
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            redactor: WireTracer::enabled()
+                .with_redacted_fields(DEFAULT_REDACTED_FIELDS.iter().copied()),
+            sessions: Arc::default(),
+        }
+    }
+
+    /// Redact these fields from argument previews instead of the defaults
+    #[must_use]
+    pub fn with_redacted_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redactor = WireTracer::enabled().with_redacted_fields(fields);
+        self
+    }
+
+    /// Requests remembered per session
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Recent requests of `session_id`, oldest first
+    ///
+    /// Empty for sessions that have ended or made no requests.
+    #[must_use]
+    pub fn session(&self, session_id: &str) -> Vec<HistoryEntry> {
+        self.sessions
+            .get(session_id)
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Ids of the sessions with recorded requests
+    #[must_use]
+    pub fn sessions(&self) -> Vec<String> {
+        self.sessions
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect()
+    }
+
+    /// Preview of request parameters, redacted and truncated for storage
+    pub(crate) fn preview(&self, params: Option<&serde_json::Value>) -> Option<String> {
+        let params = self.redactor.redact(params?.clone());
+        Some(truncate(params.to_string(), MAX_ARGUMENTS_PREVIEW))
+    }
+
+    /// Remember a request answered in the record's session
+    pub(crate) fn record(&self, record: &RequestRecord, arguments: Option<String>) {
+        let Some(session_id) = &record.session_id else {
+            return;
+        };
+        let entry = HistoryEntry {
+            timestamp: record.timestamp,
+            request_id: truncate(record.request_id.to_string(), MAX_NAME_LEN),
+            method: truncate(record.method.clone(), MAX_NAME_LEN),
+            tool: record.tool.clone().map(|tool| truncate(tool, MAX_NAME_LEN)),
+            arguments,
+            status: record.status,
+            error_code: record.error_code,
+            duration_ms: record.duration_ms,
+        };
+        let mut entries = self.sessions.entry(session_id.clone()).or_default();
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Forget a session that has ended
+    pub fn end_session(&self, session_id: &str) {
+        self.sessions.remove(session_id);
+    }
+}
+
+/// Cut `text` to at most `max` characters, marking where it was cut
+fn truncate(mut text: String, max: usize) -> String {
+    if let Some((cut, _)) = text.char_indices().nth(max) {
+        text.truncate(cut);
+        text.push('…');
+    }
+    text
+}
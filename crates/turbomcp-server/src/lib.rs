@@ -56,29 +56,67 @@ pub const SERVER_NAME: &str = "turbomcp-server";
 /// Server version
 pub const SERVER_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub mod breaker;
+pub mod build_info;
+pub mod cache;
 pub mod config;
+pub mod envelope;
 pub mod error;
 pub mod handlers;
+pub mod history;
 pub mod lifecycle;
 pub mod metrics;
 pub mod middleware;
+pub mod mime;
+pub mod operations;
+pub mod output_limit;
+pub mod prewarm;
 pub mod registry;
+pub mod reload;
+pub mod request_log;
 pub mod routing;
+pub mod self_check;
 pub mod server;
+pub mod session;
+pub mod startup;
 
 // Re-export main types for convenience
-pub use config::{Configuration, ConfigurationBuilder, ServerConfig};
+pub use breaker::{CircuitBreakerConfig, CircuitState, ToolCircuitBreakers};
+pub use build_info::BuildInfo;
+pub use cache::{CacheStats, ToolCachePolicy, ToolResultCache};
+pub use config::{
+    Configuration, ConfigurationBuilder, MethodAccessConfig, OutputFilterAction,
+    OutputFilterConfig, PreInitLimitConfig, PrewarmConfig, ServerConfig, ToolErrorConfig,
+    ToolErrorMode,
+};
+pub use envelope::{EnvelopeError, validate_envelope};
 pub use error::{ServerError, ServerResult};
-pub use handlers::{PromptHandler, ResourceHandler, SamplingHandler, ToolHandler};
+pub use handlers::{
+    CompletionHandler, PromptHandler, ResourceHandler, ResourceStream, SamplingHandler,
+    StreamingResource, StreamingResourceHandler, ToolHandler,
+};
+pub use history::{HistoryEntry, SessionHistory};
 pub use lifecycle::{HealthStatus, ServerLifecycle, ShutdownSignal};
-pub use metrics::{MetricsCollector, ServerMetrics};
+pub use metrics::{MetricsCollector, MetricsSink, MetricsSnapshot, ServerMetrics};
 pub use middleware::{
     AuthenticationMiddleware, LoggingMiddleware, Middleware, MiddlewareLayer, MiddlewareStack,
-    RateLimitMiddleware, SecurityHeadersConfig, SecurityHeadersMiddleware,
+    OutputFilterMiddleware, RateLimitMiddleware, RequestFlow, SecurityHeadersConfig,
+    SecurityHeadersMiddleware,
+};
+pub use operations::OperationRegistry;
+pub use output_limit::{OutputLimitAction, ToolOutputLimit};
+pub use prewarm::{PrewarmFailure, PrewarmReport};
+pub use registry::{HandlerInfo, HandlerRegistry, Registry, RegistryBuilder};
+pub use reload::{ConfigChange, ConfigReloader, ReloadReport};
+pub use request_log::{JsonLinesSink, RequestLogSink, RequestLogger, RequestRecord, RequestStatus};
+pub use routing::{
+    CLIENT_META_KEY, DEFAULT_LOG_LEVEL, FunctionRouteHandler, LOG_LEVEL_KEY, RequestRouter, Route,
+    Router, matches_uri_template, uri_template_params, uri_templates_overlap,
 };
-pub use registry::{HandlerRegistry, Registry, RegistryBuilder};
-pub use routing::{RequestRouter, Route, Router};
+pub use self_check::{SelfCheckIssue, SelfCheckReport};
 pub use server::{McpServer, ServerBuilder, ShutdownHandle};
+pub use session::{CapabilityHook, SessionCapabilities};
+pub use startup::Readiness;
 
 // Re-export protocol types
 pub use turbomcp_protocol::jsonrpc::{
@@ -113,5 +151,5 @@ pub mod prelude {
     };
 
     // Re-export macros
-    pub use turbomcp_macros::{prompt, resource, server as server_macro, tool};
+    pub use turbomcp_macros::{completion, prompt, resource, server as server_macro, tool};
 }
@@ -3,11 +3,20 @@
 //! This module provides a comprehensive, lock-free metrics collection system designed
 //! for high-performance production environments with zero-allocation hot paths.
 
+use dashmap::DashMap;
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::types::{CallToolResult, Content, TextContent, Tool};
+
+use crate::breaker::CircuitState;
+use crate::build_info::BuildInfo;
+use crate::handlers::FunctionToolHandler;
+use crate::{ServerError, ServerResult};
 
 /// Production-grade server metrics collector with lock-free atomic operations
 #[derive(Debug)]
@@ -64,6 +73,12 @@ pub struct ServerMetrics {
     /// Response time histogram for latency distribution analysis
     pub response_time_buckets: ResponseTimeHistogram,
 
+    /// Latency histograms for each tool that has been called, keyed by name
+    pub tool_latency: DashMap<String, ResponseTimeHistogram>,
+
+    /// Circuit breaker state after each tool's latest call, for tools with one
+    pub tool_circuit_state: DashMap<String, CircuitState>,
+
     /// Server start time for uptime calculation
     pub start_time: Instant,
 }
@@ -158,6 +173,47 @@ impl ResponseTimeHistogram {
             self.bucket_inf.fetch_add(1, Ordering::Relaxed);
         }
     }
+
+    /// Current count of each bucket, labelled by its upper bound
+    pub fn counts(&self) -> [(&'static str, u64); 13] {
+        [
+            ("1ms", &self.bucket_1ms),
+            ("5ms", &self.bucket_5ms),
+            ("10ms", &self.bucket_10ms),
+            ("25ms", &self.bucket_25ms),
+            ("50ms", &self.bucket_50ms),
+            ("100ms", &self.bucket_100ms),
+            ("250ms", &self.bucket_250ms),
+            ("500ms", &self.bucket_500ms),
+            ("1s", &self.bucket_1s),
+            ("2_5s", &self.bucket_2_5s),
+            ("5s", &self.bucket_5s),
+            ("10s", &self.bucket_10s),
+            ("inf", &self.bucket_inf),
+        ]
+        .map(|(label, bucket)| (label, bucket.load(Ordering::Relaxed)))
+    }
+
+    /// Zero every bucket
+    pub fn reset(&self) {
+        for bucket in [
+            &self.bucket_1ms,
+            &self.bucket_5ms,
+            &self.bucket_10ms,
+            &self.bucket_25ms,
+            &self.bucket_50ms,
+            &self.bucket_100ms,
+            &self.bucket_250ms,
+            &self.bucket_500ms,
+            &self.bucket_1s,
+            &self.bucket_2_5s,
+            &self.bucket_5s,
+            &self.bucket_10s,
+            &self.bucket_inf,
+        ] {
+            bucket.store(0, Ordering::Relaxed);
+        }
+    }
 }
 
 impl ServerMetrics {
@@ -192,6 +248,8 @@ impl ServerMetrics {
 
             custom: RwLock::new(HashMap::new()),
             response_time_buckets: ResponseTimeHistogram::new(),
+            tool_latency: DashMap::new(),
+            tool_circuit_state: DashMap::new(),
             start_time: Instant::now(),
         }
     }
@@ -250,6 +308,29 @@ impl ServerMetrics {
         }
     }
 
+    /// Record a completed tool call along with its latency
+    ///
+    /// Only call this for registered tools: each distinct name gets its own
+    /// histogram, so recording arbitrary client-supplied names would grow
+    /// the map without bound.
+    pub fn record_tool_call_latency(&self, tool: &str, success: bool, duration: Duration) {
+        self.record_tool_call(success);
+        let duration_us = duration.as_micros() as u64;
+        if let Some(histogram) = self.tool_latency.get(tool) {
+            histogram.record(duration_us);
+        } else {
+            self.tool_latency
+                .entry(tool.to_string())
+                .or_default()
+                .record(duration_us);
+        }
+    }
+
+    /// Record the state of a tool's circuit breaker
+    pub fn record_tool_circuit_state(&self, tool: &str, state: CircuitState) {
+        self.tool_circuit_state.insert(tool.to_string(), state);
+    }
+
     /// Update connection metrics with proper lifecycle tracking  
     #[inline]
     pub fn record_connection_established(&self) {
@@ -325,6 +406,37 @@ impl ServerMetrics {
         }
     }
 
+    /// Zero all counters and histograms
+    ///
+    /// Gauges describing current state (requests in flight, active
+    /// connections, memory and CPU usage) and the uptime are kept, since
+    /// resetting them would make later readings wrong.
+    pub fn reset(&self) {
+        for counter in [
+            &self.requests_total,
+            &self.requests_successful,
+            &self.requests_failed,
+            &self.errors_total,
+            &self.errors_validation,
+            &self.errors_auth,
+            &self.errors_network,
+            &self.errors_timeout,
+            &self.total_response_time_us,
+            &self.max_response_time_us,
+            &self.tool_calls_total,
+            &self.tool_calls_successful,
+            &self.tool_calls_failed,
+            &self.connections_total,
+            &self.connections_rejected,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.min_response_time_us.store(u64::MAX, Ordering::Relaxed);
+        self.response_time_buckets.reset();
+        self.tool_latency.clear();
+        self.custom.write().clear();
+    }
+
     /// Lock-free atomic update of minimum response time
     fn update_min_response_time(&self, new_value: u64) {
         loop {
@@ -370,6 +482,112 @@ impl Default for ServerMetrics {
     }
 }
 
+/// Destination for the measurements a server takes while it runs
+///
+/// The server reports each event to every sink registered with
+/// [`ServerBuilder::with_metrics_sink`](crate::ServerBuilder::with_metrics_sink),
+/// after recording it in its own [`ServerMetrics`]. Implement this to feed
+/// statsd, OpenTelemetry or any other system directly instead of scraping
+/// the built-in collector. Every method defaults to doing nothing, and all of
+/// them are called on the request path, so they should not block.
+pub trait MetricsSink: Send + Sync {
+    /// A request was received and is about to be processed
+    fn request_started(&self) {}
+
+    /// A request was answered successfully after `duration`
+    fn request_succeeded(&self, duration: Duration) {
+        let _ = duration;
+    }
+
+    /// A request failed after `duration`, with `error_type` such as
+    /// `"validation"`, `"auth"` or `"handler"`
+    fn request_failed(&self, error_type: &str, duration: Duration) {
+        let _ = (error_type, duration);
+    }
+
+    /// A registered tool finished running after `duration`
+    fn tool_called(&self, tool: &str, success: bool, duration: Duration) {
+        let _ = (tool, success, duration);
+    }
+
+    /// The circuit breaker of `tool` was left in `state` by its latest call
+    fn tool_circuit_state(&self, tool: &str, state: &CircuitState) {
+        let _ = (tool, state);
+    }
+
+    /// A connection was dropped for breaking the server's limits
+    fn connection_rejected(&self) {}
+}
+
+impl MetricsSink for ServerMetrics {
+    fn request_started(&self) {
+        self.record_request_start();
+    }
+
+    fn request_succeeded(&self, duration: Duration) {
+        self.record_request_success(duration);
+    }
+
+    fn request_failed(&self, error_type: &str, duration: Duration) {
+        self.record_request_failure(error_type, duration);
+    }
+
+    fn tool_called(&self, tool: &str, success: bool, duration: Duration) {
+        self.record_tool_call_latency(tool, success, duration);
+    }
+
+    fn tool_circuit_state(&self, tool: &str, state: &CircuitState) {
+        self.record_tool_circuit_state(tool, state.clone());
+    }
+
+    fn connection_rejected(&self) {
+        self.record_connection_rejected();
+    }
+}
+
+/// Point-in-time copy of the server metrics, suitable for serialization
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricsSnapshot {
+    /// Every metric reported by [`ComprehensiveMetricsCollector`]
+    pub metrics: BTreeMap<String, f64>,
+    /// Latency histogram bucket counts for each tool
+    pub tool_latency: BTreeMap<String, BTreeMap<&'static str, u64>>,
+    /// Circuit breaker state for each tool that has one
+    pub tool_circuit_state: BTreeMap<String, CircuitState>,
+    /// What the server was built from
+    pub build: BuildInfo,
+}
+
+impl MetricsSnapshot {
+    /// Capture the current values of `metrics`
+    #[must_use]
+    pub fn capture(metrics: &Arc<ServerMetrics>) -> Self {
+        let tool_latency = metrics
+            .tool_latency
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key().clone(),
+                    entry.value().counts().into_iter().collect(),
+                )
+            })
+            .collect();
+        Self {
+            metrics: ComprehensiveMetricsCollector::new(Arc::clone(metrics))
+                .collect()
+                .into_iter()
+                .collect(),
+            tool_latency,
+            tool_circuit_state: metrics
+                .tool_circuit_state
+                .iter()
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
+                .collect(),
+            build: BuildInfo::current(),
+        }
+    }
+}
+
 /// Metrics collector trait for extensible metric collection systems
 pub trait MetricsCollector: Send + Sync {
     /// Collect metrics into a HashMap for export to monitoring systems
@@ -571,3 +789,82 @@ impl MetricsCollector for ComprehensiveMetricsCollector {
         metrics
     }
 }
+
+/// Name of the built-in tool returning a [`MetricsSnapshot`]
+pub const METRICS_TOOL: &str = "__metrics";
+
+/// Name of the built-in tool resetting the server metrics
+pub const METRICS_RESET_TOOL: &str = "__metrics_reset";
+
+/// Build the metrics admin tools, callable only by clients with one of `roles`
+///
+/// The role check runs inside the handlers as well as through the router's
+/// RBAC, so it holds even when request validation is disabled.
+pub(crate) fn admin_tools(
+    metrics: &Arc<ServerMetrics>,
+    roles: &[String],
+) -> [(&'static str, FunctionToolHandler); 2] {
+    let snapshot = {
+        let (metrics, required) = (Arc::clone(metrics), roles.to_vec());
+        FunctionToolHandler::new_with_roles(
+            admin_tool(METRICS_TOOL, "Snapshot of the server metrics"),
+            move |_req, ctx| {
+                let allowed = require_roles(&ctx, &required);
+                let metrics = Arc::clone(&metrics);
+                async move {
+                    allowed?;
+                    let snapshot = serde_json::to_string(&MetricsSnapshot::capture(&metrics))?;
+                    Ok(text_result(snapshot))
+                }
+            },
+            Some(roles.to_vec()),
+        )
+    };
+    let reset = {
+        let (metrics, required) = (Arc::clone(metrics), roles.to_vec());
+        FunctionToolHandler::new_with_roles(
+            admin_tool(METRICS_RESET_TOOL, "Reset the server metrics counters"),
+            move |_req, ctx| {
+                let allowed = require_roles(&ctx, &required);
+                let metrics = Arc::clone(&metrics);
+                async move {
+                    allowed?;
+                    metrics.reset();
+                    Ok(text_result("Metrics reset".to_string()))
+                }
+            },
+            Some(roles.to_vec()),
+        )
+    };
+    [(METRICS_TOOL, snapshot), (METRICS_RESET_TOOL, reset)]
+}
+
+fn require_roles(ctx: &RequestContext, roles: &[String]) -> ServerResult<()> {
+    if ctx.has_any_role(roles) {
+        Ok(())
+    } else {
+        Err(ServerError::authorization(
+            "Metrics tools require an administrator role",
+        ))
+    }
+}
+
+fn admin_tool(name: &str, description: &str) -> Tool {
+    Tool {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        ..Default::default()
+    }
+}
+
+fn text_result(text: String) -> CallToolResult {
+    CallToolResult {
+        content: vec![Content::Text(TextContent {
+            text,
+            annotations: None,
+            meta: None,
+        })],
+        is_error: Some(false),
+        ..Default::default()
+    }
+}
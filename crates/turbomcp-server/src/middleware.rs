@@ -5,9 +5,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
-use turbomcp_core::RequestContext;
-use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcResponse};
+use turbomcp_core::{RequestContext, SharedClock, SystemClock};
+use turbomcp_protocol::jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse};
 
+use crate::config::{OutputFilterAction, OutputFilterConfig};
 use crate::{ServerError, ServerResult};
 
 /// Middleware trait for processing requests and responses
@@ -27,6 +28,21 @@ pub trait Middleware: Send + Sync {
         ctx: &RequestContext,
     ) -> ServerResult<()>;
 
+    /// Answer the request instead of its handler
+    ///
+    /// Called right after this middleware's `process_request`. Returning a
+    /// response ends the request phase: later middleware and the handler are
+    /// skipped, and the response goes through `process_response` like any
+    /// other. Useful for maintenance mode, feature flags and canned answers.
+    /// The response's id is set to the request's.
+    async fn intercept(
+        &self,
+        _request: &JsonRpcRequest,
+        _ctx: &RequestContext,
+    ) -> ServerResult<Option<JsonRpcResponse>> {
+        Ok(None)
+    }
+
     /// Get middleware name
     fn name(&self) -> &str;
 
@@ -41,6 +57,15 @@ pub trait Middleware: Send + Sync {
     }
 }
 
+/// Where a request goes after the request phase of a [`MiddlewareStack`]
+#[derive(Debug)]
+pub enum RequestFlow {
+    /// On to the handler
+    Continue(JsonRpcRequest, RequestContext),
+    /// Answered by a middleware's [`intercept`](Middleware::intercept)
+    Respond(JsonRpcResponse, RequestContext),
+}
+
 /// Middleware stack for composing multiple middleware
 pub struct MiddlewareStack {
     /// Ordered list of middleware
@@ -106,7 +131,12 @@ impl MiddlewareStack {
     where
         M: Middleware + 'static,
     {
-        self.middleware.push(Arc::new(middleware));
+        self.add_shared(Arc::new(middleware));
+    }
+
+    /// Add middleware that is already shared
+    pub fn add_shared(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
         self.sort_by_priority();
     }
 
@@ -116,29 +146,62 @@ impl MiddlewareStack {
     }
 
     /// Process request through all middleware
+    ///
+    /// Responses from [`Middleware::intercept`] are not consulted; see
+    /// [`process_request_flow`](Self::process_request_flow).
     pub async fn process_request(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<(JsonRpcRequest, RequestContext)> {
+        match self.run_request_phase(request, ctx, false).await? {
+            RequestFlow::Continue(request, ctx) => Ok((request, ctx)),
+            RequestFlow::Respond(..) => unreachable!("interception is disabled"),
+        }
+    }
+
+    /// Process request through middleware until one answers it
+    ///
+    /// Returns [`RequestFlow::Respond`] if a middleware's
+    /// [`intercept`](Middleware::intercept) produced a response, in which
+    /// case the remaining middleware did not see the request.
+    pub async fn process_request_flow(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<RequestFlow> {
+        self.run_request_phase(request, ctx, true).await
+    }
+
+    async fn run_request_phase(
         &self,
         mut request: JsonRpcRequest,
         mut ctx: RequestContext,
-    ) -> ServerResult<(JsonRpcRequest, RequestContext)> {
+        interception: bool,
+    ) -> ServerResult<RequestFlow> {
         // Record a start timestamp for end-to-end latency
         let global_start = Instant::now();
+        let mut intercepted = None;
         for middleware in &self.middleware {
             if !middleware.enabled() {
                 continue;
             }
 
             let start = Instant::now();
+            let phase = async {
+                middleware.process_request(&mut request, &mut ctx).await?;
+                if interception {
+                    middleware.intercept(&request, &ctx).await
+                } else {
+                    Ok(None)
+                }
+            };
 
             // Apply timeout if configured
             let result = if self.config.timeout_ms > 0 {
-                tokio::time::timeout(
-                    Duration::from_millis(self.config.timeout_ms),
-                    middleware.process_request(&mut request, &mut ctx),
-                )
-                .await
+                tokio::time::timeout(Duration::from_millis(self.config.timeout_ms), phase).await
             } else {
-                Ok(middleware.process_request(&mut request, &mut ctx).await)
+                Ok(phase.await)
             };
 
             let duration = start.elapsed();
@@ -152,7 +215,17 @@ impl MiddlewareStack {
             }
 
             match result {
-                Ok(Ok(())) => continue,
+                Ok(Ok(None)) => continue,
+                Ok(Ok(Some(mut response))) => {
+                    tracing::debug!(
+                        middleware = middleware.name(),
+                        method = %request.method,
+                        "Middleware answered the request"
+                    );
+                    response.id = Some(request.id.clone());
+                    intercepted = Some(response);
+                    break;
+                }
                 Ok(Err(e)) => {
                     if self.config.enable_recovery {
                         tracing::warn!(
@@ -203,7 +276,10 @@ impl MiddlewareStack {
             "middleware_time_ms",
             global_start.elapsed().as_millis() as u64,
         );
-        Ok((request, ctx))
+        Ok(match intercepted {
+            Some(response) => RequestFlow::Respond(response, ctx),
+            None => RequestFlow::Continue(request, ctx),
+        })
     }
 
     /// Process response through all middleware (in reverse order)
@@ -386,7 +462,7 @@ impl AuthenticationMiddleware {
         Self {
             provider: Arc::new(provider),
             config: AuthConfig {
-                skip_methods: vec!["initialize".to_string()],
+                skip_methods: vec!["initialize".to_string(), "ping".to_string()],
                 scheme: AuthScheme::Bearer,
                 token_expiry: Duration::from_secs(3600),
             },
@@ -412,8 +488,9 @@ impl Middleware for AuthenticationMiddleware {
         request: &mut JsonRpcRequest,
         _ctx: &mut RequestContext,
     ) -> ServerResult<()> {
-        // Skip authentication for certain methods
-        if self.config.skip_methods.contains(&request.method) {
+        // Skip authentication for certain methods; `ping` is a liveness check
+        // and must always be answerable
+        if request.method == "ping" || self.config.skip_methods.contains(&request.method) {
             return Ok(());
         }
 
@@ -497,6 +574,8 @@ pub enum KeyExtractor {
 pub struct RateLimiter {
     /// Rate limit entries
     entries: Arc<RwLock<HashMap<String, RateLimitEntry>>>,
+    /// Time source for refills and expiry
+    clock: SharedClock,
     /// Cleanup task handle (None in tests)
     _cleanup_handle: Option<tokio::task::JoinHandle<()>>,
 }
@@ -515,16 +594,23 @@ struct RateLimitEntry {
 impl RateLimiter {
     /// Create new rate limiter with background cleanup task
     #[must_use]
-    pub fn new(_requests_per_second: u32, _burst_capacity: u32) -> Self {
+    pub fn new(requests_per_second: u32, burst_capacity: u32) -> Self {
+        Self::with_clock(requests_per_second, burst_capacity, SystemClock::shared())
+    }
+
+    /// Create a rate limiter that measures time with `clock`
+    #[must_use]
+    pub fn with_clock(_requests_per_second: u32, _burst_capacity: u32, clock: SharedClock) -> Self {
         let entries = Arc::new(RwLock::new(HashMap::<String, RateLimitEntry>::new()));
 
         // Cleanup task
         let cleanup_entries = Arc::clone(&entries);
+        let cleanup_clock = Arc::clone(&clock);
         let cleanup_handle = tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(60));
             loop {
                 interval.tick().await;
-                let now = Instant::now();
+                let now = cleanup_clock.now();
                 let mut entries = cleanup_entries.write().await;
                 entries.retain(|_, entry| entry.expires_at > now);
             }
@@ -532,6 +618,7 @@ impl RateLimiter {
 
         Self {
             entries,
+            clock,
             _cleanup_handle: Some(cleanup_handle),
         }
     }
@@ -539,11 +626,16 @@ impl RateLimiter {
     /// Create new rate limiter for testing (no background tasks)
     #[must_use]
     #[cfg(test)]
-    pub fn new_for_testing(_requests_per_second: u32, _burst_capacity: u32) -> Self {
+    pub fn new_for_testing(
+        _requests_per_second: u32,
+        _burst_capacity: u32,
+        clock: SharedClock,
+    ) -> Self {
         let entries = Arc::new(RwLock::new(HashMap::<String, RateLimitEntry>::new()));
 
         Self {
             entries,
+            clock,
             _cleanup_handle: None, // No cleanup task in tests
         }
     }
@@ -556,7 +648,7 @@ impl RateLimiter {
         burst_capacity: u32,
     ) -> bool {
         let mut entries = self.entries.write().await;
-        let now = Instant::now();
+        let now = self.clock.now();
 
         let entry = entries.entry(key.to_string()).or_insert(RateLimitEntry {
             tokens: burst_capacity,
@@ -583,13 +675,29 @@ impl RateLimiter {
     }
 }
 
+impl Drop for RateLimiter {
+    fn drop(&mut self) {
+        // Stop the cleanup task so replaced limiters (e.g. after a config reload) don't leak
+        if let Some(handle) = self._cleanup_handle.take() {
+            handle.abort();
+        }
+    }
+}
+
 impl RateLimitMiddleware {
     /// Create new rate limit middleware
     #[must_use]
     pub fn new(config: RateLimitConfig) -> Self {
-        let limiter = Arc::new(RateLimiter::new(
+        Self::with_clock(config, SystemClock::shared())
+    }
+
+    /// Create rate limit middleware that refills tokens by `clock`
+    #[must_use]
+    pub fn with_clock(config: RateLimitConfig, clock: SharedClock) -> Self {
+        let limiter = Arc::new(RateLimiter::with_clock(
             config.requests_per_second,
             config.burst_capacity,
+            clock,
         ));
 
         Self { limiter, config }
@@ -598,10 +706,11 @@ impl RateLimitMiddleware {
     /// Create new rate limit middleware for testing (no background tasks)
     #[must_use]
     #[cfg(test)]
-    pub fn new_for_testing(config: RateLimitConfig) -> Self {
+    pub fn new_for_testing(config: RateLimitConfig, clock: SharedClock) -> Self {
         let limiter = Arc::new(RateLimiter::new_for_testing(
             config.requests_per_second,
             config.burst_capacity,
+            clock,
         ));
 
         Self { limiter, config }
@@ -1083,6 +1192,128 @@ impl Middleware for SecurityHeadersMiddleware {
     }
 }
 
+/// Scrubs secrets and disallowed content from tool text output
+///
+/// Built from an [`OutputFilterConfig`]; denied terms are matched as literal
+/// text ignoring case. Only text blocks in tool results are inspected.
+#[derive(Debug, Clone)]
+pub struct OutputFilterMiddleware {
+    rules: Vec<regex::Regex>,
+    action: OutputFilterAction,
+    replacement: String,
+}
+
+impl OutputFilterMiddleware {
+    /// Compile the rules in `config`
+    pub fn new(config: &OutputFilterConfig) -> ServerResult<Self> {
+        config.validate()?;
+        let patterns = config.patterns.iter().cloned();
+        let terms = config
+            .denied_terms
+            .iter()
+            .map(|term| format!("(?i){}", regex::escape(term)));
+        let rules = patterns
+            .chain(terms)
+            .map(|rule| {
+                regex::Regex::new(&rule).map_err(|e| {
+                    ServerError::configuration_with_key(
+                        format!("Invalid output filter rule: {e}"),
+                        "output_filter",
+                    )
+                })
+            })
+            .collect::<ServerResult<_>>()?;
+        Ok(Self {
+            rules,
+            action: config.action,
+            replacement: config.replacement.clone(),
+        })
+    }
+
+    /// Apply the rules to `text`, returning whether anything matched
+    ///
+    /// Matches are replaced only when the action is
+    /// [`OutputFilterAction::Redact`].
+    pub fn filter_text(&self, text: &mut String) -> bool {
+        let mut matched = false;
+        for rule in &self.rules {
+            if !rule.is_match(text) {
+                continue;
+            }
+            matched = true;
+            if self.action == OutputFilterAction::Redact {
+                *text = rule
+                    .replace_all(text, regex::NoExpand(&self.replacement))
+                    .into_owned();
+            }
+        }
+        matched
+    }
+}
+
+#[async_trait]
+impl Middleware for OutputFilterMiddleware {
+    async fn process_request(
+        &self,
+        _request: &mut JsonRpcRequest,
+        _ctx: &mut RequestContext,
+    ) -> ServerResult<()> {
+        Ok(())
+    }
+
+    async fn process_response(
+        &self,
+        response: &mut JsonRpcResponse,
+        ctx: &RequestContext,
+    ) -> ServerResult<()> {
+        let Some(blocks) = response
+            .result
+            .as_mut()
+            .and_then(|result| result.get_mut("content"))
+            .and_then(serde_json::Value::as_array_mut)
+        else {
+            return Ok(());
+        };
+
+        let mut matched = false;
+        for block in blocks {
+            if block.get("type").and_then(serde_json::Value::as_str) != Some("text") {
+                continue;
+            }
+            if let Some(serde_json::Value::String(text)) = block.get_mut("text") {
+                matched |= self.filter_text(text);
+            }
+        }
+        if !matched {
+            return Ok(());
+        }
+
+        tracing::warn!(
+            request_id = %ctx.request_id,
+            action = ?self.action,
+            "Output filter matched tool result"
+        );
+        if self.action == OutputFilterAction::Reject {
+            let error = ServerError::authorization("Tool result withheld by output filter");
+            response.result = None;
+            response.error = Some(JsonRpcError {
+                code: error.error_code(),
+                message: error.to_string(),
+                data: None,
+            });
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "output_filter"
+    }
+
+    fn priority(&self) -> u32 {
+        1100 // First on the response path, so even logging sees filtered output
+    }
+}
+
 /// Middleware layer for easier composition
 pub type MiddlewareLayer = Arc<dyn Middleware>;
 
@@ -0,0 +1,139 @@
+//! MIME types for resource contents that arrive without one
+//!
+//! Clients generally render contents without a MIME type as text, which
+//! garbles images and archives. Before a `resources/read` result is sent, the
+//! router fills in a type for every entry that lacks one: first from the URI's
+//! file extension, then from well-known magic numbers at the start of the
+//! content, and otherwise `text/plain` for text entries and
+//! [`OCTET_STREAM`] for blobs. Text entries starting with a binary format's
+//! magic number are re-encoded as blobs, so the client is told to treat them
+//! as bytes. The extension alone never turns text into a blob: text
+//! extracted from `report.pdf` is still text.
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use turbomcp_protocol::types::{BlobResourceContents, ReadResourceResult, ResourceContent};
+
+/// Type given to binary content nothing more is known about
+pub const OCTET_STREAM: &str = "application/octet-stream";
+
+/// Known file extensions, lowercase, and their MIME types
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("bmp", "image/bmp"),
+    ("ico", "image/x-icon"),
+    ("svg", "image/svg+xml"),
+    ("pdf", "application/pdf"),
+    ("zip", "application/zip"),
+    ("gz", "application/gzip"),
+    ("tar", "application/x-tar"),
+    ("wasm", "application/wasm"),
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("mp4", "video/mp4"),
+    ("json", "application/json"),
+    ("xml", "application/xml"),
+    ("yaml", "application/yaml"),
+    ("yml", "application/yaml"),
+    ("toml", "application/toml"),
+    ("js", "text/javascript"),
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("md", "text/markdown"),
+    ("txt", "text/plain"),
+];
+
+/// Leading bytes identifying common binary formats
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"\0asm", "application/wasm"),
+];
+
+/// Base64 characters decoded to sniff a blob; a multiple of four, covering
+/// the longest signature
+const SNIFF_CHARS: usize = 16;
+
+/// Guess the MIME type of content at `uri` from its extension or leading bytes
+///
+/// Returns `None` when neither identifies it.
+#[must_use]
+pub fn infer_mime_type(uri: &str, content: &[u8]) -> Option<&'static str> {
+    from_extension(uri).or_else(|| sniff(content))
+}
+
+/// Whether content of type `mime_type` is text a client can display as such
+#[must_use]
+pub fn is_textual(mime_type: &str) -> bool {
+    let essence = mime_type.split(';').next().unwrap_or_default().trim();
+    essence.starts_with("text/")
+        || essence.ends_with("+json")
+        || essence.ends_with("+xml")
+        || matches!(
+            essence,
+            "application/json" | "application/xml" | "application/yaml" | "application/toml"
+        )
+}
+
+/// Give every entry of `result` without a MIME type an inferred one
+pub(crate) fn fill_mime_types(result: &mut ReadResourceResult) {
+    for content in &mut result.contents {
+        match content {
+            ResourceContent::Text(text) if text.mime_type.is_none() => {
+                if let Some(mime_type) = sniff(text.text.as_bytes()) {
+                    let blob = BlobResourceContents {
+                        uri: std::mem::take(&mut text.uri),
+                        mime_type: Some(mime_type.to_string()),
+                        blob: BASE64.encode(&text.text),
+                        meta: text.meta.take(),
+                    };
+                    *content = ResourceContent::Blob(blob);
+                } else {
+                    let mime_type = from_extension(&text.uri)
+                        .filter(|mime_type| is_textual(mime_type))
+                        .unwrap_or("text/plain");
+                    text.mime_type = Some(mime_type.to_string());
+                }
+            }
+            ResourceContent::Blob(blob) if blob.mime_type.is_none() => {
+                // Decoding a short prefix is enough to check the signatures
+                let prefix = blob.blob.get(..SNIFF_CHARS).unwrap_or(&blob.blob);
+                let head = BASE64.decode(prefix).unwrap_or_default();
+                let mime_type = infer_mime_type(&blob.uri, &head).unwrap_or(OCTET_STREAM);
+                blob.mime_type = Some(mime_type.to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn from_extension(uri: &str) -> Option<&'static str> {
+    let path = uri.split(['?', '#']).next().unwrap_or_default();
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let (_, extension) = file_name.rsplit_once('.')?;
+    EXTENSIONS
+        .iter()
+        .find(|(known, _)| extension.eq_ignore_ascii_case(known))
+        .map(|&(_, mime_type)| mime_type)
+}
+
+fn sniff(content: &[u8]) -> Option<&'static str> {
+    if content.len() >= 12 && &content[..4] == b"RIFF" && &content[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+    SIGNATURES
+        .iter()
+        .find(|(signature, _)| content.starts_with(signature))
+        .map(|&(_, mime_type)| mime_type)
+}
@@ -0,0 +1,243 @@
+//! Long-running requests a client can re-attach to after reconnecting
+//!
+//! A request carrying `_meta.operationId` is tracked as a resumable
+//! operation under that id. Its handler keeps running if the connection
+//! that sent it drops, and from any connection the client can then:
+//!
+//! - call `operations/get` with the `operationId` for the operation's state,
+//!   its latest progress and, once it has finished, its result or error;
+//! - call `operations/resume` with the `operationId` and a `progressToken`
+//!   to get the same status back and have the operation's remaining
+//!   `notifications/progress` and `notifications/progress/complete` sent to
+//!   this connection under that token.
+//!
+//! An operation started without a progress token reports progress against
+//! its operation id. Finished operations are kept for a retention period,
+//! five minutes unless changed with [`OperationRegistry::with_retention`],
+//! so a client that reconnects after the end can still collect the result;
+//! after that they are forgotten. The operation id may be reused once the
+//! earlier operation has finished.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use serde_json::Value;
+use tokio::sync::mpsc;
+use turbomcp_core::{NotificationSender, RequestContext};
+use turbomcp_protocol::jsonrpc::JsonRpcResponse;
+use turbomcp_protocol::methods;
+use turbomcp_protocol::types::{OperationState, OperationStatus};
+
+use crate::{ServerError, ServerResult};
+
+/// How long finished operations are kept by default
+pub const DEFAULT_OPERATION_RETENTION: Duration = Duration::from_secs(300);
+
+/// Resumable operations started on a server, by operation id
+///
+/// Clones share the operations and the retention period.
+#[derive(Debug, Clone)]
+pub struct OperationRegistry {
+    operations: Arc<DashMap<String, Arc<Operation>>>,
+    retention: Arc<Mutex<Duration>>,
+}
+
+/// One resumable operation
+#[derive(Debug)]
+struct Operation {
+    progress: Mutex<Progress>,
+    /// Connections that resumed the operation, with their progress tokens
+    subscribers: Mutex<Vec<(Value, NotificationSender)>>,
+}
+
+/// What an operation has reported so far
+#[derive(Debug)]
+struct Progress {
+    status: OperationStatus,
+    finished_at: Option<Instant>,
+}
+
+impl Default for OperationRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OperationRegistry {
+    /// Create an empty registry keeping finished operations for
+    /// [`DEFAULT_OPERATION_RETENTION`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            operations: Arc::default(),
+            retention: Arc::new(Mutex::new(DEFAULT_OPERATION_RETENTION)),
+        }
+    }
+
+    /// Keep finished operations for `retention` instead
+    #[must_use]
+    pub fn with_retention(self, retention: Duration) -> Self {
+        self.set_retention(retention);
+        self
+    }
+
+    /// Keep finished operations for `retention` from now on
+    ///
+    /// Every clone of the registry sees the change.
+    pub fn set_retention(&self, retention: Duration) {
+        *self.retention.lock() = retention;
+    }
+
+    /// Status of the operation with this id, if it is known
+    #[must_use]
+    pub fn get(&self, operation_id: &str) -> Option<OperationStatus> {
+        self.purge_finished();
+        let operation = self.operations.get(operation_id)?;
+        Some(operation.progress.lock().status.clone())
+    }
+
+    /// Number of operations running or kept after finishing
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.purge_finished();
+        self.operations.len()
+    }
+
+    /// Whether no operations are running or kept
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Track the request `ctx` belongs to as operation `operation_id`
+    ///
+    /// Returns the context its handler should run with, which copies
+    /// notifications to the operation.
+    pub(crate) fn start(
+        &self,
+        operation_id: &str,
+        method: &str,
+        ctx: RequestContext,
+    ) -> ServerResult<RequestContext> {
+        self.purge_finished();
+        let operation = Arc::new(Operation {
+            progress: Mutex::new(Progress {
+                status: OperationStatus {
+                    operation_id: operation_id.to_string(),
+                    method: method.to_string(),
+                    state: OperationState::Running,
+                    progress: None,
+                    total: None,
+                    message: None,
+                    result: None,
+                    error: None,
+                },
+                finished_at: None,
+            }),
+            subscribers: Mutex::default(),
+        });
+        match self.operations.entry(operation_id.to_string()) {
+            dashmap::mapref::entry::Entry::Occupied(existing)
+                if existing.get().progress.lock().finished_at.is_none() =>
+            {
+                return Err(ServerError::invalid_params(format!(
+                    "Operation '{operation_id}' is already running"
+                )));
+            }
+            entry => {
+                entry.insert(Arc::clone(&operation));
+            }
+        }
+
+        let (mirror, copies) = mpsc::unbounded_channel();
+        tokio::spawn(operation.forward(copies));
+        Ok(ctx.with_mirror(mirror))
+    }
+
+    /// Record how the operation's request was answered
+    pub(crate) fn finish(&self, operation_id: &str, response: &JsonRpcResponse) {
+        let Some(operation) = self.operations.get(operation_id).map(|op| Arc::clone(&op)) else {
+            return;
+        };
+        let mut progress = operation.progress.lock();
+        progress.finished_at = Some(Instant::now());
+        progress.status.state = if response.error.is_some() {
+            OperationState::Failed
+        } else {
+            OperationState::Completed
+        };
+        progress.status.result.clone_from(&response.result);
+        progress.status.error.clone_from(&response.error);
+    }
+
+    /// Send the operation's remaining progress through `notifier` under
+    /// `token`, and return its status
+    ///
+    /// A finished operation has no more progress to send; its status carries
+    /// the outcome.
+    pub(crate) fn resume(
+        &self,
+        operation_id: &str,
+        token: Value,
+        notifier: Option<NotificationSender>,
+    ) -> ServerResult<OperationStatus> {
+        self.purge_finished();
+        let operation = self
+            .operations
+            .get(operation_id)
+            .map(|op| Arc::clone(&op))
+            .ok_or_else(|| unknown_operation(operation_id))?;
+        // Held so the operation cannot finish between the check and the
+        // subscription, leaving this connection waiting for nothing
+        let progress = operation.progress.lock();
+        if let Some(notifier) = notifier
+            && progress.finished_at.is_none()
+        {
+            operation.subscribers.lock().push((token, notifier));
+        }
+        Ok(progress.status.clone())
+    }
+
+    /// Forget operations finished longer ago than the retention period
+    fn purge_finished(&self) {
+        let retention = *self.retention.lock();
+        self.operations.retain(|_, operation| {
+            operation
+                .progress
+                .lock()
+                .finished_at
+                .is_none_or(|finished| finished.elapsed() < retention)
+        });
+    }
+}
+
+impl Operation {
+    /// Follow the progress the handler reports, passing it on to the
+    /// connections that resumed the operation
+    async fn forward(self: Arc<Self>, mut copies: mpsc::UnboundedReceiver<Value>) {
+        while let Some(notification) = copies.recv().await {
+            let method = notification["method"].as_str().unwrap_or_default();
+            if method == methods::PROGRESS {
+                let params = &notification["params"];
+                let mut progress = self.progress.lock();
+                progress.status.progress = params["progress"].as_f64();
+                progress.status.total = params["total"].as_f64();
+                progress.status.message = params["message"].as_str().map(str::to_string);
+            } else if method != methods::PROGRESS_COMPLETE {
+                continue;
+            }
+            self.subscribers.lock().retain(|(token, notifier)| {
+                let mut notification = notification.clone();
+                notification["params"]["progressToken"] = token.clone();
+                notifier.send(notification).is_ok()
+            });
+        }
+    }
+}
+
+/// Error for an operation id the registry does not know
+pub(crate) fn unknown_operation(operation_id: &str) -> ServerError {
+    ServerError::not_found(format!("Operation '{operation_id}'"))
+}
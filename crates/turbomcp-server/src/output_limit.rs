@@ -0,0 +1,152 @@
+//! Size limits on tool results
+//!
+//! Tools opt in by returning a [`ToolOutputLimit`] from
+//! [`ToolHandler::output_limit`](crate::handlers::ToolHandler::output_limit),
+//! or with `#[tool(max_output = ...)]`. The router measures the serialized
+//! result once the handler returns and, when it is over the limit, either
+//! truncates it or fails the call, depending on the limit's
+//! [`OutputLimitAction`].
+//!
+//! Truncation keeps whole content items while they fit, cuts the first text
+//! item that does not, drops the rest and appends a text item saying how large
+//! the output was. The result stays within the limit unless the limit is too
+//! small to hold even that marker.
+
+use turbomcp_protocol::types::{CallToolResult, Content, TextContent};
+
+use crate::routing::json_size;
+use crate::{ServerError, ServerResult};
+
+/// What to do with a result over its tool's limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputLimitAction {
+    /// Cut the result down and mark it as truncated
+    #[default]
+    Truncate,
+    /// Fail the call with an error
+    Reject,
+}
+
+/// Per-tool output size limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToolOutputLimit {
+    /// Maximum serialized size of the result in bytes
+    pub max_bytes: usize,
+    /// What happens to larger results
+    pub action: OutputLimitAction,
+}
+
+impl ToolOutputLimit {
+    /// Truncate results larger than `max_bytes`
+    #[must_use]
+    pub const fn truncate(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            action: OutputLimitAction::Truncate,
+        }
+    }
+
+    /// Fail calls whose result is larger than `max_bytes`
+    #[must_use]
+    pub const fn reject(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            action: OutputLimitAction::Reject,
+        }
+    }
+
+    /// Apply the limit to the result of `tool`
+    pub(crate) fn enforce(
+        &self,
+        tool: &str,
+        result: CallToolResult,
+    ) -> ServerResult<CallToolResult> {
+        let size = json_size(&result);
+        if size <= self.max_bytes {
+            return Ok(result);
+        }
+        match self.action {
+            OutputLimitAction::Reject => Err(ServerError::handler(format!(
+                "Output of tool '{tool}' is {size} bytes, exceeding its limit of {} bytes",
+                self.max_bytes
+            ))),
+            OutputLimitAction::Truncate => {
+                tracing::debug!(tool, size, max = self.max_bytes, "Truncating tool output");
+                Ok(self.truncated(result, size))
+            }
+        }
+    }
+
+    fn truncated(&self, result: CallToolResult, size: usize) -> CallToolResult {
+        let marker = text(format!(
+            "[output truncated: {size} bytes exceeded the {}-byte limit]",
+            self.max_bytes
+        ));
+        let mut truncated = CallToolResult {
+            content: vec![marker],
+            is_error: result.is_error,
+            ..Default::default()
+        };
+        // Every item kept before the marker also costs a separating comma
+        let mut budget = self.max_bytes.saturating_sub(json_size(&truncated));
+        let mut kept = Vec::new();
+
+        for item in result.content {
+            let item_size = json_size(&item) + 1;
+            if item_size <= budget {
+                budget -= item_size;
+                kept.push(item);
+                continue;
+            }
+            if let Content::Text(content) = item
+                && let Some(prefix) = longest_fitting_prefix(content, budget)
+            {
+                kept.push(Content::Text(prefix));
+            }
+            break;
+        }
+
+        kept.append(&mut truncated.content);
+        truncated.content = kept;
+        truncated
+    }
+}
+
+/// Cut `content` to the longest text whose item fits in `budget`, separator included
+fn longest_fitting_prefix(mut content: TextContent, budget: usize) -> Option<TextContent> {
+    let text = std::mem::take(&mut content.text);
+    let fits = |content: &TextContent| json_size(&Content::Text(content.clone())) < budget;
+    if !fits(&content) {
+        return None;
+    }
+
+    // Escaping makes the serialized size grow unevenly with the text, so
+    // search for the cut rather than computing it
+    let (mut low, mut high) = (0, text.len());
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        content.text = text[..floor_char_boundary(&text, mid)].to_string();
+        if fits(&content) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    content.text = text[..floor_char_boundary(&text, low)].to_string();
+    (!content.text.is_empty()).then_some(content)
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn text(text: String) -> Content {
+    Content::Text(TextContent {
+        text,
+        annotations: None,
+        meta: None,
+    })
+}
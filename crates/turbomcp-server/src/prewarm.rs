@@ -0,0 +1,97 @@
+//! Startup pre-warming of tool handlers
+//!
+//! Tools that load a model, fill a cache or open a connection pool would
+//! otherwise do that work inside the first client's call. A tool opts in by
+//! overriding [`ToolHandler::prewarm`](crate::ToolHandler::prewarm) (or with
+//! [`FunctionToolHandler::with_prewarm`](crate::handlers::FunctionToolHandler::with_prewarm));
+//! the run methods call every hook before the transport accepts its first
+//! message, a bounded number at a time. A failing hook stops the server
+//! from starting unless [`PrewarmConfig::fail_on_error`] is turned off.
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::stream::{self, StreamExt};
+
+use crate::config::PrewarmConfig;
+use crate::handlers::ToolHandler;
+use crate::registry::HandlerRegistry;
+
+/// A tool whose pre-warm hook failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrewarmFailure {
+    /// Registered name of the tool
+    pub tool: String,
+    /// The error the hook returned
+    pub error: String,
+}
+
+impl fmt::Display for PrewarmFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tool '{}': {}", self.tool, self.error)
+    }
+}
+
+/// Outcome of pre-warming a registry
+#[derive(Debug, Clone, Default)]
+pub struct PrewarmReport {
+    /// Number of tools whose hook succeeded
+    pub warmed: usize,
+    /// Tools whose hook failed, sorted by name
+    pub failures: Vec<PrewarmFailure>,
+}
+
+impl PrewarmReport {
+    /// True when every hook succeeded
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for PrewarmReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "{} tool(s) pre-warmed", self.warmed);
+        }
+        write!(f, "{} tool(s) failed to pre-warm:", self.failures.len())?;
+        for failure in &self.failures {
+            write!(f, "\n  - {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the pre-warm hook of every tool in a registry
+///
+/// At most `config.max_concurrency` hooks run at once. Every hook is run
+/// even if some fail, so the report lists all failures.
+pub async fn prewarm_registry(registry: &HandlerRegistry, config: &PrewarmConfig) -> PrewarmReport {
+    let tools: Vec<(String, Arc<dyn ToolHandler>)> = registry
+        .tools
+        .iter()
+        .map(|entry| (entry.key().clone(), Arc::clone(entry.value())))
+        .collect();
+
+    let outcomes: Vec<_> = stream::iter(tools)
+        .map(|(name, handler)| async move {
+            let outcome = handler.prewarm().await;
+            (name, outcome)
+        })
+        .buffer_unordered(config.max_concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut report = PrewarmReport::default();
+    for (tool, outcome) in outcomes {
+        match outcome {
+            Ok(()) => report.warmed += 1,
+            Err(e) => report.failures.push(PrewarmFailure {
+                tool,
+                error: e.to_string(),
+            }),
+        }
+    }
+    report.failures.sort_by(|a, b| a.tool.cmp(&b.tool));
+    report
+}
@@ -40,11 +40,14 @@ use dashmap::DashMap;
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::sync::Arc;
-use turbomcp_protocol::types::{Prompt, Resource, Tool};
+use turbomcp_core::ResourceLookup;
+use turbomcp_protocol::types::{CompletionReference, Prompt, Resource, Tool};
 
 use crate::handlers::{
-    HandlerMetadata, LoggingHandler, PromptHandler, ResourceHandler, SamplingHandler, ToolHandler,
+    CompletionHandler, HandlerMetadata, LoggingHandler, PromptHandler, ResourceHandler,
+    SamplingHandler, ToolHandler,
 };
+use crate::routing::matches_uri_template;
 use crate::{ServerError, ServerResult};
 
 /// Handler registry for managing all server handlers
@@ -59,6 +62,8 @@ pub struct HandlerRegistry {
     pub sampling: DashMap<String, Arc<dyn SamplingHandler>>,
     /// Logging handlers
     pub logging: DashMap<String, Arc<dyn LoggingHandler>>,
+    /// Completion handlers, by the reference and argument they complete
+    pub completions: DashMap<(CompletionReference, String), Arc<dyn CompletionHandler>>,
     /// Handler metadata
     metadata: DashMap<String, HandlerMetadata>,
     /// Registry configuration
@@ -73,6 +78,7 @@ impl std::fmt::Debug for HandlerRegistry {
             .field("resources_count", &self.resources.len())
             .field("sampling_count", &self.sampling.len())
             .field("logging_count", &self.logging.len())
+            .field("completions_count", &self.completions.len())
             .finish()
     }
 }
@@ -169,6 +175,7 @@ impl HandlerRegistry {
             resources: DashMap::new(),
             sampling: DashMap::new(),
             logging: DashMap::new(),
+            completions: DashMap::new(),
             metadata: DashMap::new(),
             config: Arc::new(RwLock::new(RegistryConfig::default())),
         }
@@ -183,6 +190,7 @@ impl HandlerRegistry {
             resources: DashMap::new(),
             sampling: DashMap::new(),
             logging: DashMap::new(),
+            completions: DashMap::new(),
             metadata: DashMap::new(),
             config: Arc::new(RwLock::new(config)),
         }
@@ -381,6 +389,47 @@ impl HandlerRegistry {
         Ok(())
     }
 
+    /// Register a completion handler for the argument it names
+    ///
+    /// Replaces any handler already completing the same argument.
+    pub fn register_completion<C>(&self, handler: C) -> ServerResult<()>
+    where
+        C: CompletionHandler + 'static,
+    {
+        let reference = handler.reference();
+        let argument = handler.argument();
+        let name = format!("{}/{argument}", reference.name());
+
+        // Check limits
+        if self.completions.len() >= self.config.read().max_handlers_per_type {
+            return Err(ServerError::handler(format!(
+                "Maximum number of completion handlers ({}) exceeded",
+                self.config.read().max_handlers_per_type
+            )));
+        }
+
+        // Register the handler
+        self.completions
+            .insert((reference, argument), Arc::new(handler));
+
+        // Store metadata
+        let metadata = HandlerMetadata {
+            name: name.clone(),
+            version: "1.0.0".to_string(),
+            description: None,
+            tags: vec!["completion".to_string()],
+            created_at: chrono::Utc::now(),
+            config: HashMap::new(),
+            metrics_enabled: self.config.read().enable_metrics,
+            rate_limit: None,
+            allowed_roles: None,
+        };
+        self.metadata.insert(format!("completion:{name}"), metadata);
+
+        tracing::info!("Registered completion handler: {}", name);
+        Ok(())
+    }
+
     /// Get a tool handler by name
     #[must_use]
     pub fn get_tool(&self, name: &str) -> Option<Arc<dyn ToolHandler>> {
@@ -419,6 +468,18 @@ impl HandlerRegistry {
             .map(|entry| Arc::clone(entry.value()))
     }
 
+    /// Get the handler completing `argument` of `reference`
+    #[must_use]
+    pub fn get_completion(
+        &self,
+        reference: &CompletionReference,
+        argument: &str,
+    ) -> Option<Arc<dyn CompletionHandler>> {
+        self.completions
+            .get(&(reference.clone(), argument.to_string()))
+            .map(|entry| Arc::clone(entry.value()))
+    }
+
     /// List all tool names
     #[must_use]
     pub fn list_tools(&self) -> Vec<String> {
@@ -461,33 +522,92 @@ impl HandlerRegistry {
             .collect()
     }
 
-    /// Get all tool definitions
+    /// Get all tool definitions, sorted by registered name
+    ///
+    /// This is what `tools/list` returns, before any tool access lists.
     #[must_use]
     pub fn get_tool_definitions(&self) -> Vec<Tool> {
-        self.tools
-            .iter()
-            .map(|entry| entry.value().tool_definition())
+        self.tool_infos()
+            .into_iter()
+            .map(|info| info.definition)
             .collect()
     }
 
-    /// Get all prompt definitions
+    /// Get all prompt definitions, sorted by registered name
     #[must_use]
     pub fn get_prompt_definitions(&self) -> Vec<Prompt> {
-        self.prompts
-            .iter()
-            .map(|entry| entry.value().prompt_definition())
+        self.prompt_infos()
+            .into_iter()
+            .map(|info| info.definition)
             .collect()
     }
 
-    /// Get all resource definitions
+    /// Get all resource definitions, sorted by registered name
     #[must_use]
     pub fn get_resource_definitions(&self) -> Vec<Resource> {
-        self.resources
-            .iter()
-            .map(|entry| entry.value().resource_definition())
+        self.resource_infos()
+            .into_iter()
+            .map(|info| info.definition)
             .collect()
     }
 
+    /// Describe a registered tool
+    #[must_use]
+    pub fn tool_info(&self, name: &str) -> Option<HandlerInfo<Tool>> {
+        let handler = self.get_tool(name)?;
+        Some(self.info("tool", name, handler.tool_definition()))
+    }
+
+    /// Describe a registered prompt
+    #[must_use]
+    pub fn prompt_info(&self, name: &str) -> Option<HandlerInfo<Prompt>> {
+        let handler = self.get_prompt(name)?;
+        Some(self.info("prompt", name, handler.prompt_definition()))
+    }
+
+    /// Describe a registered resource
+    #[must_use]
+    pub fn resource_info(&self, name: &str) -> Option<HandlerInfo<Resource>> {
+        let handler = self.get_resource(name)?;
+        Some(self.info("resource", name, handler.resource_definition()))
+    }
+
+    /// Describe every registered tool, sorted by name
+    #[must_use]
+    pub fn tool_infos(&self) -> Vec<HandlerInfo<Tool>> {
+        let mut infos: Vec<_> = self
+            .tools
+            .iter()
+            .map(|entry| self.info("tool", entry.key(), entry.value().tool_definition()))
+            .collect();
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        infos
+    }
+
+    /// Describe every registered prompt, sorted by name
+    #[must_use]
+    pub fn prompt_infos(&self) -> Vec<HandlerInfo<Prompt>> {
+        let mut infos: Vec<_> = self
+            .prompts
+            .iter()
+            .map(|entry| self.info("prompt", entry.key(), entry.value().prompt_definition()))
+            .collect();
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        infos
+    }
+
+    /// Describe every registered resource, sorted by name
+    #[must_use]
+    pub fn resource_infos(&self) -> Vec<HandlerInfo<Resource>> {
+        let mut infos: Vec<_> = self
+            .resources
+            .iter()
+            .map(|entry| self.info("resource", entry.key(), entry.value().resource_definition()))
+            .collect();
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        infos
+    }
+
     /// Unregister a tool handler
     pub fn unregister_tool(&self, name: &str) -> bool {
         let removed = self.tools.remove(name).is_some();
@@ -525,6 +645,7 @@ impl HandlerRegistry {
         self.resources.clear();
         self.sampling.clear();
         self.logging.clear();
+        self.completions.clear();
         self.metadata.clear();
         tracing::info!("Cleared all handlers from registry");
     }
@@ -561,6 +682,14 @@ impl HandlerRegistry {
         f(&mut config);
     }
 
+    fn info<D>(&self, kind: &str, name: &str, definition: D) -> HandlerInfo<D> {
+        HandlerInfo {
+            name: name.to_string(),
+            definition,
+            metadata: self.get_metadata(&format!("{kind}:{name}")),
+        }
+    }
+
     // Private validation methods
 
     fn validate_tool_handler(&self, handler: &dyn ToolHandler) -> ServerResult<()> {
@@ -622,26 +751,72 @@ impl HandlerRegistry {
             return Err(ServerError::handler("Resource name cannot be empty"));
         }
 
-        // Check for duplicate URIs
+        // Check for duplicate URIs, and templates that share a URI with
+        // another and would leave which handler serves it to chance
         for entry in &self.resources {
-            if entry.value().resource_definition().uri == resource_def.uri {
+            let existing = entry.value().resource_definition();
+            if existing.uri == resource_def.uri {
                 return Err(ServerError::handler(format!(
                     "Resource with URI '{}' already exists",
                     resource_def.uri
                 )));
             }
+            if let Some(uri) =
+                crate::routing::uri_templates_overlap(&existing.uri, &resource_def.uri)
+            {
+                return Err(ServerError::handler(format!(
+                    "Resource URI '{}' overlaps '{}' of resource '{}': both match '{uri}'",
+                    resource_def.uri,
+                    existing.uri,
+                    entry.key()
+                )));
+            }
         }
 
         Ok(())
     }
 }
 
+impl ResourceLookup for HandlerRegistry {
+    fn resource_for(&self, uri: &str) -> Option<serde_json::Value> {
+        let definitions: Vec<Resource> = self
+            .resources
+            .iter()
+            .map(|entry| entry.value().resource_definition())
+            .collect();
+        // A concrete resource describes the URI better than a template does
+        let resource = definitions
+            .iter()
+            .find(|resource| resource.uri == uri)
+            .or_else(|| {
+                definitions
+                    .iter()
+                    .find(|resource| matches_uri_template(&resource.uri, uri))
+            })?;
+        serde_json::to_value(resource).ok()
+    }
+}
+
 impl Default for HandlerRegistry {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// A registered handler as in-process tooling sees it
+///
+/// Returned by [`HandlerRegistry::tool_infos`] and friends for admin UIs and
+/// other reflection that should not go through the protocol.
+#[derive(Debug, Clone)]
+pub struct HandlerInfo<D> {
+    /// Name the handler was registered under
+    pub name: String,
+    /// Definition exactly as the matching `*/list` method reports it
+    pub definition: D,
+    /// Metadata recorded at registration
+    pub metadata: Option<HandlerMetadata>,
+}
+
 /// Registry statistics
 #[derive(Debug, Clone)]
 pub struct RegistryStats {
@@ -0,0 +1,277 @@
+//! Hot reloading of server configuration
+//!
+//! Long-running servers can pick up a subset of configuration changes without
+//! a restart. A reload re-reads the configured file and applies the settings
+//! that can change safely at runtime:
+//!
+//! - `logging.level` (forwarded to a user-supplied hook, since the tracing
+//!   subscriber is owned by the application)
+//! - `rate_limiting` (the rate limit middleware is rebuilt)
+//! - `tool_access` (allow/deny lists consulted by the router)
+//! - `tool_errors` (whether tool failures are protocol errors or results)
+//! - `memory_budget` (the per-call memory budget, for calls made afterwards)
+//! - `method_access` (per-transport method lists, also consulted by the router)
+//! - `output_filter` (the output filter middleware is rebuilt)
+//!
+//! Changes to bind address, port, TLS and timeouts are reported but ignored with
+//! a warning; they take effect on the next restart.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::config::ServerConfig;
+use crate::middleware::MiddlewareStack;
+use crate::routing::RequestRouter;
+use crate::{ServerError, ServerResult};
+
+/// Callback invoked with the new log level after a reload changes it
+pub type LogLevelHook = Arc<dyn Fn(&str) -> ServerResult<()> + Send + Sync>;
+
+/// A single configuration setting that differed between old and new config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Dotted setting path, e.g. `logging.level`
+    pub setting: String,
+    /// Previous value
+    pub old: String,
+    /// New value
+    pub new: String,
+}
+
+impl ConfigChange {
+    fn new(setting: &str, old: impl std::fmt::Debug, new: impl std::fmt::Debug) -> Self {
+        Self {
+            setting: setting.to_string(),
+            old: format!("{old:?}"),
+            new: format!("{new:?}"),
+        }
+    }
+}
+
+/// Outcome of a configuration reload
+#[derive(Debug, Clone, Default)]
+pub struct ReloadReport {
+    /// Changes that were applied
+    pub applied: Vec<ConfigChange>,
+    /// Changes that require a restart and were ignored
+    pub ignored: Vec<ConfigChange>,
+}
+
+impl ReloadReport {
+    /// True when the new configuration was identical to the running one
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty() && self.ignored.is_empty()
+    }
+}
+
+/// Applies configuration reloads to a running server
+///
+/// Obtained from [`McpServer::config_reloader`](crate::McpServer::config_reloader).
+/// Reloads are atomic with respect to request processing: the middleware stack
+/// is write-locked for the duration of the swap, so a request entering the
+/// stack observes either the old or the new settings, never a mix. Concurrent
+/// reloads are serialized by the same lock.
+#[derive(Clone)]
+pub struct ConfigReloader {
+    source: Option<PathBuf>,
+    live: Arc<parking_lot::RwLock<ServerConfig>>,
+    router: Arc<RequestRouter>,
+    middleware: Arc<RwLock<MiddlewareStack>>,
+    log_level_hook: Option<LogLevelHook>,
+}
+
+impl std::fmt::Debug for ConfigReloader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigReloader")
+            .field("source", &self.source)
+            .field("has_log_level_hook", &self.log_level_hook.is_some())
+            .finish()
+    }
+}
+
+impl ConfigReloader {
+    pub(crate) fn new(
+        source: Option<PathBuf>,
+        live: Arc<parking_lot::RwLock<ServerConfig>>,
+        router: Arc<RequestRouter>,
+        middleware: Arc<RwLock<MiddlewareStack>>,
+        log_level_hook: Option<LogLevelHook>,
+    ) -> Self {
+        Self {
+            source,
+            live,
+            router,
+            middleware,
+            log_level_hook,
+        }
+    }
+
+    /// Configuration file this reloader reads from, if any
+    #[must_use]
+    pub fn source(&self) -> Option<&PathBuf> {
+        self.source.as_ref()
+    }
+
+    /// Re-read the configuration file and apply hot-reloadable settings
+    ///
+    /// Returns an empty report when no configuration file is configured.
+    pub async fn reload(&self) -> ServerResult<ReloadReport> {
+        let Some(path) = &self.source else {
+            tracing::warn!("Configuration reload requested but no config file is set");
+            return Ok(ReloadReport::default());
+        };
+        let config = ServerConfig::from_file(path)?;
+        self.apply(config).await
+    }
+
+    /// Apply a new configuration to the running server
+    ///
+    /// The new configuration is validated before anything is changed; on error
+    /// the running configuration is left untouched.
+    pub async fn apply(&self, new: ServerConfig) -> ServerResult<ReloadReport> {
+        new.logging
+            .level
+            .parse::<tracing::level_filters::LevelFilter>()
+            .map_err(|_| {
+                ServerError::configuration_with_key(
+                    format!("Invalid log level '{}'", new.logging.level),
+                    "logging.level",
+                )
+            })?;
+        new.output_filter.validate()?;
+
+        let mut stack = self.middleware.write().await;
+        let old = self.live.read().clone();
+        let mut report = ReloadReport::default();
+
+        if old.logging.level != new.logging.level {
+            if let Some(hook) = &self.log_level_hook {
+                hook(&new.logging.level)?;
+            }
+            report.applied.push(ConfigChange::new(
+                "logging.level",
+                &old.logging.level,
+                &new.logging.level,
+            ));
+        }
+
+        let old_rl = &old.rate_limiting;
+        let new_rl = &new.rate_limiting;
+        if old_rl != new_rl {
+            stack.remove("rate_limit");
+            if new_rl.enabled {
+                crate::server::install_rate_limit(&mut stack, new_rl, self.router.clock());
+            }
+            report
+                .applied
+                .push(ConfigChange::new("rate_limiting", old_rl, new_rl));
+        }
+
+        if old.output_filter != new.output_filter {
+            stack.remove("output_filter");
+            if new.output_filter.enabled {
+                crate::server::install_output_filter(&mut stack, &new.output_filter)?;
+            }
+            report.applied.push(ConfigChange::new(
+                "output_filter",
+                &old.output_filter,
+                &new.output_filter,
+            ));
+        }
+
+        if old.tool_access != new.tool_access {
+            self.router.set_tool_access(new.tool_access.clone());
+            report.applied.push(ConfigChange::new(
+                "tool_access",
+                &old.tool_access,
+                &new.tool_access,
+            ));
+        }
+
+        if old.tool_errors != new.tool_errors {
+            self.router.set_tool_errors(new.tool_errors.clone());
+            report.applied.push(ConfigChange::new(
+                "tool_errors",
+                &old.tool_errors,
+                &new.tool_errors,
+            ));
+        }
+
+        if old.memory_budget != new.memory_budget {
+            self.router.set_memory_budget(new.memory_budget);
+            report.applied.push(ConfigChange::new(
+                "memory_budget",
+                old.memory_budget,
+                new.memory_budget,
+            ));
+        }
+
+        if old.method_access != new.method_access {
+            self.router.set_method_access(new.method_access.clone());
+            report.applied.push(ConfigChange::new(
+                "method_access",
+                &old.method_access,
+                &new.method_access,
+            ));
+        }
+
+        if old.bind_address != new.bind_address {
+            report.ignored.push(ConfigChange::new(
+                "bind_address",
+                &old.bind_address,
+                &new.bind_address,
+            ));
+        }
+        if old.port != new.port {
+            report
+                .ignored
+                .push(ConfigChange::new("port", old.port, new.port));
+        }
+        if old.enable_tls != new.enable_tls {
+            report.ignored.push(ConfigChange::new(
+                "enable_tls",
+                old.enable_tls,
+                new.enable_tls,
+            ));
+        }
+        if old.timeouts != new.timeouts {
+            report
+                .ignored
+                .push(ConfigChange::new("timeouts", &old.timeouts, &new.timeouts));
+        }
+
+        // Only hot-reloadable settings are copied into the live configuration
+        {
+            let mut live = self.live.write();
+            live.logging.level.clone_from(&new.logging.level);
+            live.rate_limiting = new.rate_limiting.clone();
+            live.tool_access = new.tool_access.clone();
+            live.tool_errors = new.tool_errors.clone();
+            live.method_access = new.method_access.clone();
+            live.output_filter = new.output_filter.clone();
+        }
+        drop(stack);
+
+        for change in &report.applied {
+            tracing::info!(
+                setting = %change.setting,
+                old = %change.old,
+                new = %change.new,
+                "Configuration setting reloaded"
+            );
+        }
+        for change in &report.ignored {
+            tracing::warn!(
+                setting = %change.setting,
+                old = %change.old,
+                new = %change.new,
+                "Configuration setting requires a restart; ignoring"
+            );
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,210 @@
+//! Durable per-request logs for audit and analytics
+//!
+//! Metrics aggregate; a [`RequestLogger`] keeps one [`RequestRecord`] per
+//! request the server answers, including requests rejected by middleware.
+//! Records are handed to a background task that writes them to a
+//! [`RequestLogSink`], such as the [`JsonLinesSink`] file format. Logging never
+//! holds up a request: when the sink falls behind and the queue fills, new
+//! records are dropped and counted in [`RequestLogger::dropped`].
+
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcResponse};
+use turbomcp_protocol::methods;
+
+/// Default number of records queued for the sink before new ones are dropped
+pub const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+/// How a logged request ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestStatus {
+    /// Answered with a result
+    Success,
+    /// Answered with an error
+    Error,
+}
+
+/// One request and how the server answered it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestRecord {
+    /// When the request arrived
+    pub timestamp: DateTime<Utc>,
+    /// JSON-RPC id of the request
+    pub request_id: serde_json::Value,
+    /// JSON-RPC method
+    pub method: String,
+    /// Tool called, for `tools/call`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    /// Time from arrival to response, in milliseconds
+    pub duration_ms: f64,
+    /// Whether the response carried a result or an error
+    pub status: RequestStatus,
+    /// JSON-RPC error code of an error response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<i32>,
+    /// Client the request came from, if identified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// Correlation id shared with the request's traces
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Session the request belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+impl RequestRecord {
+    /// Start the record of a request arriving now
+    pub(crate) fn begin(request: &JsonRpcRequest, ctx: &RequestContext) -> Self {
+        let tool = (request.method == methods::CALL_TOOL)
+            .then(|| request.params.as_ref()?.get("name")?.as_str())
+            .flatten()
+            .map(str::to_string);
+        Self {
+            timestamp: Utc::now(),
+            request_id: serde_json::to_value(&request.id).unwrap_or_default(),
+            method: request.method.clone(),
+            tool,
+            duration_ms: 0.0,
+            status: RequestStatus::Success,
+            error_code: None,
+            client_id: None,
+            correlation_id: None,
+            session_id: None,
+        }
+        .with_context(ctx)
+    }
+
+    /// Pick up identifiers from the context, such as those middleware added
+    pub(crate) fn with_context(mut self, ctx: &RequestContext) -> Self {
+        self.client_id = ctx.client_id.clone().or(self.client_id);
+        self.session_id = ctx.session_id.clone().or(self.session_id);
+        self.correlation_id = ctx
+            .get_metadata("correlation_id")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+            .or(self.correlation_id);
+        self
+    }
+
+    /// Complete the record with the response sent after `elapsed`
+    pub(crate) fn finish(mut self, response: &JsonRpcResponse, elapsed: Duration) -> Self {
+        self.duration_ms = elapsed.as_secs_f64() * 1000.0;
+        if let Some(error) = &response.error {
+            self.status = RequestStatus::Error;
+            self.error_code = Some(error.code);
+        }
+        self
+    }
+}
+
+/// Destination for request records
+#[async_trait]
+pub trait RequestLogSink: Send + Sync {
+    /// Persist one record
+    async fn write(&self, record: &RequestRecord) -> std::io::Result<()>;
+}
+
+/// Sink appending one JSON object per line to a file
+#[derive(Debug)]
+pub struct JsonLinesSink {
+    file: tokio::sync::Mutex<tokio::fs::File>,
+}
+
+impl JsonLinesSink {
+    /// Append to the file at `path`, creating it if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened for appending.
+    pub async fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        Ok(Self {
+            file: tokio::sync::Mutex::new(file),
+        })
+    }
+}
+
+#[async_trait]
+impl RequestLogSink for JsonLinesSink {
+    async fn write(&self, record: &RequestRecord) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        let mut file = self.file.lock().await;
+        file.write_all(&line).await?;
+        file.flush().await
+    }
+}
+
+/// Non-blocking front for a [`RequestLogSink`]
+#[derive(Debug, Clone)]
+pub struct RequestLogger {
+    queue: mpsc::Sender<RequestRecord>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl RequestLogger {
+    /// Write records to `sink` from a background task
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn new<S: RequestLogSink + 'static>(sink: S) -> Self {
+        Self::with_capacity(sink, DEFAULT_QUEUE_CAPACITY)
+    }
+
+    /// Like [`new`](Self::new), queueing at most `capacity` records
+    pub fn with_capacity<S: RequestLogSink + 'static>(sink: S, capacity: usize) -> Self {
+        let (queue, mut records) = mpsc::channel::<RequestRecord>(capacity.max(1));
+        tokio::spawn(async move {
+            while let Some(record) = records.recv().await {
+                if let Err(e) = sink.write(&record).await {
+                    tracing::warn!(
+                        error = %e,
+                        method = %record.method,
+                        "Failed to write request log record"
+                    );
+                }
+            }
+        });
+        Self {
+            queue,
+            dropped: Arc::default(),
+        }
+    }
+
+    /// Log to a JSON-lines file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be opened for appending.
+    pub async fn json_lines(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(JsonLinesSink::open(path).await?))
+    }
+
+    /// Queue a record, dropping it if the sink has fallen behind
+    pub fn log(&self, record: RequestRecord) {
+        if self.queue.try_send(record).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Number of records dropped because the queue was full or closed
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
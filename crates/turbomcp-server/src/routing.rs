@@ -1,26 +1,47 @@
 //! Request routing and handler dispatch system
 
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
 use dashmap::DashMap;
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
-use turbomcp_core::RequestContext;
+use std::time::Duration;
+use turbomcp_core::{
+    MemoryBudget, ProgressStatus, RequestContext, ResourceLookup, SharedClock, SystemClock,
+};
 use turbomcp_protocol::{
     jsonrpc::{JsonRpcRequest, JsonRpcResponse, JsonRpcVersion},
+    template,
     types::{
-        CallToolRequest, CreateMessageRequest, EmptyResult, GetPromptRequest, Implementation,
-        InitializeRequest, InitializeResult, ListPromptsResult, ListResourcesResult,
-        ListRootsResult, ListToolsResult, LoggingCapabilities, PromptsCapabilities,
-        ReadResourceRequest, ResourcesCapabilities, Root, ServerCapabilities, SetLevelRequest,
-        SubscribeRequest, ToolsCapabilities, UnsubscribeRequest,
+        CallToolRequest, CallToolResult, CompleteRequest, CompleteResult, Completion,
+        CompletionCapabilities, Content, CreateMessageRequest, EmptyResult, GetPromptRequest,
+        Implementation, InitializeRequest, InitializeResult, ListPromptsResult,
+        ListResourcesResult, ListRootsResult, ListToolsResult, LogLevel, LoggingCapabilities,
+        MAX_INITIALIZE_META_SIZE, OPERATION_ID_META_KEY, OperationRequest, PromptsCapabilities,
+        ReadResourceRequest, ResourceContent, ResourcesCapabilities, Root, ServerCapabilities,
+        SetLevelRequest, SubscribeRequest, TextContent, Tool, ToolsCapabilities,
+        UnsubscribeRequest, initialize_meta_size,
     },
 };
 
+use crate::breaker::ToolCircuitBreakers;
+use crate::cache::ToolResultCache;
+use crate::config::{MethodAccessConfig, ToolAccessConfig, ToolErrorConfig, ToolErrorMode};
+use crate::handlers::{ResourceStream, slice_stream};
+use crate::operations::{OperationRegistry, unknown_operation};
 use crate::registry::HandlerRegistry;
+use crate::session::{CapabilityHook, SessionCapabilities, SessionValues};
 use crate::{ServerError, ServerResult};
 use futures::stream::{self, StreamExt};
 use jsonschema::{Draft, JSONSchema};
+use turbomcp_transport::core::TransportType;
 
 /// Request router for dispatching MCP requests to appropriate handlers
+///
+/// Clones share all state: handlers, subscriptions, sessions and settings.
+#[derive(Clone)]
 pub struct RequestRouter {
     /// Handler registry
     registry: Arc<HandlerRegistry>,
@@ -29,7 +50,31 @@ pub struct RequestRouter {
     /// Custom route handlers
     custom_routes: HashMap<String, Arc<dyn RouteHandler>>,
     /// Resource subscription counters by URI
-    resource_subscriptions: DashMap<String, usize>,
+    resource_subscriptions: Arc<DashMap<String, usize>>,
+    /// Tool allow/deny lists (hot-reloadable)
+    tool_access: Arc<parking_lot::RwLock<ToolAccessConfig>>,
+    /// How tool handler errors are reported (hot-reloadable)
+    tool_errors: Arc<parking_lot::RwLock<ToolErrorConfig>>,
+    /// Memory each tool call may use, in bytes (hot-reloadable)
+    memory_budget: Arc<parking_lot::RwLock<Option<usize>>>,
+    /// Method allow/deny lists for each transport (hot-reloadable)
+    method_access: Arc<parking_lot::RwLock<HashMap<TransportType, MethodAccessConfig>>>,
+    /// Results of tools that opted into caching
+    tool_cache: Arc<ToolResultCache>,
+    /// Time source for caching and rate limiting
+    clock: SharedClock,
+    /// Circuit breakers for tools that have one
+    circuit_breakers: Arc<ToolCircuitBreakers>,
+    /// Metadata returned to clients in the initialize result
+    server_meta: HashMap<String, serde_json::Value>,
+    /// Metadata each session's client sent with its initialize request
+    client_meta: SessionValues<HashMap<String, serde_json::Value>>,
+    /// Least severe log level each session's client asked for with `logging/setLevel`
+    log_level: SessionValues<LogLevel>,
+    /// Capabilities negotiated by each session
+    session_capabilities: SessionCapabilities,
+    /// Requests clients may re-attach to after reconnecting
+    operations: OperationRegistry,
 }
 
 impl std::fmt::Debug for RequestRouter {
@@ -114,6 +159,141 @@ impl Default for RouteMetadata {
     }
 }
 
+/// Request context metadata key holding the client's initialize `_meta`
+///
+/// Present on every request of a session whose client sent metadata when it
+/// initialized; other sessions do not see it.
+pub const CLIENT_META_KEY: &str = "client_meta";
+
+/// Request context metadata key holding the least severe [`LogLevel`] the
+/// session's client wants `notifications/message` for
+///
+/// Present on every routed request: the level the client set with
+/// `logging/setLevel`, or [`DEFAULT_LOG_LEVEL`] if it set none.
+pub const LOG_LEVEL_KEY: &str = "log_level";
+
+/// Log level in effect for sessions whose client has not set one
+pub const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Info;
+
+/// Methods dispatched by the router itself
+///
+/// Custom routes cannot shadow these.
+const BUILTIN_METHODS: &[&str] = &[
+    "initialize",
+    "ping",
+    "tools/list",
+    "tools/call",
+    "prompts/list",
+    "prompts/get",
+    "resources/list",
+    "resources/read",
+    "resources/subscribe",
+    "resources/unsubscribe",
+    "logging/setLevel",
+    "completion/complete",
+    "sampling/createMessage",
+    "roots/list",
+    "operations/get",
+    "operations/resume",
+];
+
+/// How a routed request ended, for the completion of its progress
+///
+/// Tool results flagged `isError` count as errors, like failed requests.
+fn progress_outcome(response: &JsonRpcResponse) -> (ProgressStatus, Option<&str>) {
+    if let Some(error) = &response.error {
+        return (ProgressStatus::Error, Some(&error.message));
+    }
+    let tool_error = response
+        .result
+        .as_ref()
+        .and_then(|result| result.get("isError"))
+        .and_then(serde_json::Value::as_bool)
+        == Some(true);
+    if tool_error {
+        (ProgressStatus::Error, None)
+    } else {
+        (ProgressStatus::Success, None)
+    }
+}
+
+/// Check whether a method is one of the standard methods handled by the router
+#[must_use]
+pub fn is_builtin_method(method: &str) -> bool {
+    BUILTIN_METHODS.contains(&method)
+}
+
+type BoxFuture<T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + Send>>;
+
+/// Function-based handler for a custom JSON-RPC method
+///
+/// The function receives the request params and returns the `result` value;
+/// errors become JSON-RPC error responses.
+pub struct FunctionRouteHandler {
+    /// Method this handler answers
+    method: String,
+    /// Handler function
+    handler: Arc<
+        dyn Fn(
+                Option<serde_json::Value>,
+                RequestContext,
+            ) -> BoxFuture<ServerResult<serde_json::Value>>
+            + Send
+            + Sync,
+    >,
+}
+
+impl std::fmt::Debug for FunctionRouteHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FunctionRouteHandler")
+            .field("method", &self.method)
+            .finish()
+    }
+}
+
+impl FunctionRouteHandler {
+    /// Create a handler for `method`
+    pub fn new<F, Fut>(method: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(Option<serde_json::Value>, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<serde_json::Value>> + Send + 'static,
+    {
+        Self {
+            method: method.into(),
+            handler: Arc::new(move |params, ctx| Box::pin(handler(params, ctx)) as BoxFuture<_>),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RouteHandler for FunctionRouteHandler {
+    async fn handle(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<JsonRpcResponse> {
+        let result = (self.handler)(request.params, ctx).await?;
+        Ok(JsonRpcResponse {
+            jsonrpc: JsonRpcVersion,
+            id: Some(request.id),
+            result: Some(result),
+            error: None,
+        })
+    }
+
+    fn can_handle(&self, method: &str) -> bool {
+        method == self.method
+    }
+
+    fn metadata(&self) -> RouteMetadata {
+        RouteMetadata {
+            name: self.method.clone(),
+            methods: vec![self.method.clone()],
+            ..RouteMetadata::default()
+        }
+    }
+}
+
 impl RequestRouter {
     /// Create a new request router
     #[must_use]
@@ -122,7 +302,19 @@ impl RequestRouter {
             registry,
             config: RouterConfig::default(),
             custom_routes: HashMap::new(),
-            resource_subscriptions: DashMap::new(),
+            resource_subscriptions: Arc::new(DashMap::new()),
+            tool_access: Arc::new(parking_lot::RwLock::new(ToolAccessConfig::default())),
+            tool_errors: Arc::new(parking_lot::RwLock::new(ToolErrorConfig::default())),
+            memory_budget: Arc::new(parking_lot::RwLock::new(None)),
+            method_access: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            tool_cache: Arc::new(ToolResultCache::default()),
+            clock: SystemClock::shared(),
+            circuit_breakers: Arc::new(ToolCircuitBreakers::new()),
+            server_meta: HashMap::new(),
+            client_meta: SessionValues::default(),
+            log_level: SessionValues::default(),
+            session_capabilities: SessionCapabilities::new(),
+            operations: OperationRegistry::new(),
         }
     }
 
@@ -133,11 +325,234 @@ impl RequestRouter {
             registry,
             config,
             custom_routes: HashMap::new(),
-            resource_subscriptions: DashMap::new(),
+            resource_subscriptions: Arc::new(DashMap::new()),
+            tool_access: Arc::new(parking_lot::RwLock::new(ToolAccessConfig::default())),
+            tool_errors: Arc::new(parking_lot::RwLock::new(ToolErrorConfig::default())),
+            memory_budget: Arc::new(parking_lot::RwLock::new(None)),
+            method_access: Arc::new(parking_lot::RwLock::new(HashMap::new())),
+            tool_cache: Arc::new(ToolResultCache::default()),
+            clock: SystemClock::shared(),
+            circuit_breakers: Arc::new(ToolCircuitBreakers::new()),
+            server_meta: HashMap::new(),
+            client_meta: SessionValues::default(),
+            log_level: SessionValues::default(),
+            session_capabilities: SessionCapabilities::new(),
+            operations: OperationRegistry::new(),
         }
     }
 
+    /// Replace the tool allow/deny lists
+    ///
+    /// Takes effect for the next `tools/list` or `tools/call`; calls already
+    /// dispatched to a handler are unaffected.
+    pub fn set_tool_access(&self, tool_access: ToolAccessConfig) {
+        *self.tool_access.write() = tool_access;
+    }
+
+    /// Get the current tool allow/deny lists
+    #[must_use]
+    pub fn tool_access(&self) -> ToolAccessConfig {
+        self.tool_access.read().clone()
+    }
+
+    /// Replace how tool handler errors are reported
+    pub fn set_tool_errors(&self, tool_errors: ToolErrorConfig) {
+        *self.tool_errors.write() = tool_errors;
+    }
+
+    /// Limit the memory each tool call may use, or lift the limit with `None`
+    ///
+    /// Takes effect for calls dispatched afterwards.
+    pub fn set_memory_budget(&self, bytes: Option<usize>) {
+        *self.memory_budget.write() = bytes;
+    }
+
+    /// Replace the per-transport method allow/deny lists
+    ///
+    /// Requests are matched to a transport by the `transport` entry of their
+    /// context metadata; requests without one are not restricted.
+    pub fn set_method_access(&self, method_access: HashMap<TransportType, MethodAccessConfig>) {
+        *self.method_access.write() = method_access;
+    }
+
+    /// Whether clients on the request's transport may call `method`
+    fn method_permitted_on_transport(&self, ctx: &RequestContext, method: &str) -> bool {
+        let Some(transport) = ctx
+            .get_metadata("transport")
+            .and_then(serde_json::Value::as_str)
+        else {
+            return true;
+        };
+        self.method_access
+            .read()
+            .iter()
+            .find(|(transport_type, _)| transport_type.to_string() == transport)
+            .is_none_or(|(_, access)| access.is_allowed(method))
+    }
+
+    /// Get the tool result cache (for statistics and manual invalidation)
+    #[must_use]
+    pub const fn tool_cache(&self) -> &Arc<ToolResultCache> {
+        &self.tool_cache
+    }
+
+    /// Time source for caching and rate limiting
+    #[must_use]
+    pub const fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
+    /// Measure time with `clock` instead of the system clock
+    ///
+    /// Cached tool results expire by it from now on, and servers built
+    /// around this router refill rate limits by it.
+    pub fn set_clock(&mut self, clock: SharedClock) {
+        self.tool_cache.set_clock(Arc::clone(&clock));
+        self.clock = clock;
+    }
+
+    /// Get the tool circuit breakers (for state inspection and configuration)
+    #[must_use]
+    pub const fn circuit_breakers(&self) -> &Arc<ToolCircuitBreakers> {
+        &self.circuit_breakers
+    }
+
+    /// Add a key to the metadata returned in the initialize result
+    ///
+    /// Fails if the metadata would exceed [`MAX_INITIALIZE_META_SIZE`] bytes.
+    pub fn insert_server_meta(
+        &mut self,
+        key: impl Into<String>,
+        value: serde_json::Value,
+    ) -> ServerResult<()> {
+        let key = key.into();
+        let mut meta = self.server_meta.clone();
+        meta.insert(key.clone(), value);
+        if initialize_meta_size(&meta) > MAX_INITIALIZE_META_SIZE {
+            return Err(ServerError::configuration(format!(
+                "Initialize metadata exceeds {MAX_INITIALIZE_META_SIZE} bytes after adding '{key}'"
+            )));
+        }
+        self.server_meta = meta;
+        Ok(())
+    }
+
+    /// Metadata returned to clients in the initialize result
+    #[must_use]
+    pub const fn server_meta(&self) -> &HashMap<String, serde_json::Value> {
+        &self.server_meta
+    }
+
+    /// Decide each session's capabilities at initialize time with `hook`
+    ///
+    /// See [`session`](crate::session) for how the result is enforced.
+    pub fn set_capability_hook(&mut self, hook: CapabilityHook) {
+        self.session_capabilities.set_hook(hook);
+    }
+
+    /// Capabilities negotiated by each session
+    #[must_use]
+    pub const fn session_capabilities(&self) -> &SessionCapabilities {
+        &self.session_capabilities
+    }
+
+    /// Resumable operations, running or recently finished
+    #[must_use]
+    pub const fn operations(&self) -> &OperationRegistry {
+        &self.operations
+    }
+
+    /// Keep finished resumable operations for `retention`
+    ///
+    /// Applies to operations already tracked as well as later ones.
+    pub fn set_operation_retention(&self, retention: Duration) {
+        self.operations.set_retention(retention);
+    }
+
+    /// Metadata the client of `ctx`'s session sent with its initialize
+    /// request, if any
+    #[must_use]
+    pub fn client_meta(&self, ctx: &RequestContext) -> Option<HashMap<String, serde_json::Value>> {
+        self.client_meta.get(ctx)
+    }
+
+    /// Forget what a session that has ended negotiated and asked for
+    pub fn end_session(&self, session_id: &str) {
+        self.session_capabilities.end_session(session_id);
+        self.client_meta.end_session(session_id);
+        self.log_level.end_session(session_id);
+    }
+
+    /// Log level in effect for `ctx`'s session
+    ///
+    /// The level its client last set with `logging/setLevel`, else
+    /// [`DEFAULT_LOG_LEVEL`].
+    #[must_use]
+    pub fn log_level(&self, ctx: &RequestContext) -> LogLevel {
+        self.log_level.get(ctx).unwrap_or(DEFAULT_LOG_LEVEL)
+    }
+
+    /// Read a resource as a stream of byte chunks
+    ///
+    /// Resources registered through a
+    /// [`StreamingResourceHandler`](crate::handlers::StreamingResourceHandler)
+    /// yield chunks as the handler produces them, so large content never has
+    /// to be held in memory at once. Other resources are read normally and
+    /// yield one chunk per content entry, with blobs base64-decoded.
+    ///
+    /// A ranged request yields only the requested bytes, whether or not the
+    /// resource handler supports ranges itself.
+    pub async fn open_resource_stream(
+        &self,
+        request: ReadResourceRequest,
+        ctx: RequestContext,
+    ) -> ServerResult<ResourceStream> {
+        let handler = self
+            .registry
+            .resources
+            .iter()
+            .find(|entry| {
+                matches_uri_template(&entry.value().resource_definition().uri, &request.uri)
+            })
+            .map(|entry| Arc::clone(entry.value()))
+            .ok_or_else(|| ServerError::not_found(format!("Resource '{}'", request.uri)))?;
+
+        let range = request
+            .is_ranged()
+            .then(|| (request.offset.unwrap_or(0), request.length));
+        if let Some(streaming) = handler.as_streaming() {
+            let stream = streaming.open(request, ctx).await?;
+            return Ok(match range {
+                Some((offset, length)) => slice_stream(stream, offset, length),
+                None => stream,
+            });
+        }
+
+        let result = handler.handle(request, ctx).await?;
+        // A handler that honored the range already returned just the slice
+        let range = range.filter(|_| result.range.is_none());
+        let chunks = result
+            .contents
+            .into_iter()
+            .map(|content| match content {
+                ResourceContent::Text(text) => Ok(Bytes::from(text.text)),
+                ResourceContent::Blob(blob) => BASE64
+                    .decode(blob.blob)
+                    .map(Bytes::from)
+                    .map_err(|e| ServerError::handler(format!("Invalid base64 blob: {e}"))),
+            })
+            .collect::<Vec<_>>();
+        let stream: ResourceStream = Box::pin(stream::iter(chunks));
+        Ok(match range {
+            Some((offset, length)) => slice_stream(stream, offset, length),
+            None => stream,
+        })
+    }
+
     /// Add a custom route handler
+    ///
+    /// Fails if any of the handler's methods is already routed, including the
+    /// standard MCP methods.
     pub fn add_route<H>(&mut self, handler: H) -> ServerResult<()>
     where
         H: RouteHandler + 'static,
@@ -146,6 +561,12 @@ impl RequestRouter {
         let handler_arc: Arc<dyn RouteHandler> = Arc::new(handler);
 
         for method in &metadata.methods {
+            if is_builtin_method(method) {
+                return Err(ServerError::routing_with_method(
+                    format!("Method '{method}' is a built-in MCP method"),
+                    method.clone(),
+                ));
+            }
             if self.custom_routes.contains_key(method) {
                 return Err(ServerError::routing_with_method(
                     format!("Route for method '{method}' already exists"),
@@ -168,10 +589,68 @@ impl RequestRouter {
             return self.error_response(&request, e);
         }
 
+        // Methods a transport does not expose look like methods that do not exist
+        if !self.method_permitted_on_transport(&ctx, &request.method) {
+            return self.method_not_found_response(&request);
+        }
+
+        if let Err(e) = self.session_capabilities.check(&ctx, &request.method) {
+            return self.error_response(&request, e);
+        }
+
+        let ctx = match self.client_meta.get(&ctx) {
+            Some(meta) => ctx.with_metadata(
+                CLIENT_META_KEY,
+                serde_json::Value::Object(meta.into_iter().collect()),
+            ),
+            None => ctx,
+        };
+        let log_level = self.log_level(&ctx);
+        let ctx = ctx.with_metadata(
+            LOG_LEVEL_KEY,
+            serde_json::to_value(log_level).unwrap_or_default(),
+        );
+
+        // Remember the client's progress token so handlers can report against it
+        let ctx = match request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("_meta")?.get("progressToken"))
+        {
+            Some(token) => ctx.with_progress_token(token.clone()),
+            None => ctx,
+        };
+        // Track requests the client may re-attach to, reporting their
+        // progress against the operation id unless given a token
+        let operation_id = request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("_meta")?.get(OPERATION_ID_META_KEY)?.as_str())
+            .map(str::to_string);
+        let ctx = match &operation_id {
+            Some(id) => {
+                let ctx = match ctx.progress_token() {
+                    Some(_) => ctx,
+                    None => ctx.with_progress_token(id.clone()),
+                };
+                match self.operations.start(id, &request.method, ctx) {
+                    Ok(ctx) => ctx,
+                    Err(e) => return self.error_response(&request, e),
+                }
+            }
+            None => ctx,
+        };
+        // Tools and prompts may link to the server's own resources
+        let ctx = ctx.with_resources(Arc::clone(&self.registry) as Arc<dyn ResourceLookup>);
+        // Kept to end the client's progress once the handler returns, unless
+        // the handler already did
+        let progress = ctx.progress_token().is_some().then(|| ctx.clone());
+
         // Handle the request
         let result = match request.method.as_str() {
             // Core protocol methods
             "initialize" => self.handle_initialize(request, ctx).await,
+            "ping" => self.handle_ping(request, ctx).await,
 
             // Tool methods
             "tools/list" => self.handle_list_tools(request, ctx).await,
@@ -190,12 +669,19 @@ impl RequestRouter {
             // Logging methods
             "logging/setLevel" => self.handle_set_log_level(request, ctx).await,
 
+            // Completion methods
+            "completion/complete" => self.handle_complete(request, ctx).await,
+
             // Sampling methods
             "sampling/createMessage" => self.handle_create_message(request, ctx).await,
 
             // Roots methods
             "roots/list" => self.handle_list_roots(request, ctx).await,
 
+            // Resumable operations
+            "operations/get" => self.handle_get_operation(request, ctx).await,
+            "operations/resume" => self.handle_resume_operation(request, ctx).await,
+
             // Custom routes
             method => {
                 if let Some(handler) = self.custom_routes.get(method) {
@@ -217,6 +703,15 @@ impl RequestRouter {
             tracing::warn!("Response validation failed: {}", e);
         }
 
+        // Recorded first, so a client told of the completion finds the result
+        if let Some(id) = &operation_id {
+            self.operations.finish(id, &result);
+        }
+        if let Some(progress) = progress {
+            let (status, summary) = progress_outcome(&result);
+            progress.notify_progress_complete(status, summary);
+        }
+
         result
     }
 
@@ -242,10 +737,27 @@ impl RequestRouter {
     async fn handle_initialize(
         &self,
         request: JsonRpcRequest,
-        _ctx: RequestContext,
+        ctx: RequestContext,
     ) -> JsonRpcResponse {
         match self.parse_params::<InitializeRequest>(&request) {
-            Ok(_init_request) => {
+            Ok(init_request) => {
+                if let Some(meta) = &init_request.meta
+                    && initialize_meta_size(meta) > MAX_INITIALIZE_META_SIZE
+                {
+                    return self.error_response(
+                        &request,
+                        ServerError::invalid_params(format!(
+                            "Initialize metadata exceeds {MAX_INITIALIZE_META_SIZE} bytes"
+                        )),
+                    );
+                }
+                let capabilities = self.session_capabilities.negotiate(
+                    &init_request,
+                    &ctx,
+                    self.get_server_capabilities(),
+                );
+                self.client_meta.set(&ctx, init_request.meta);
+
                 let result = InitializeResult {
                     protocol_version: turbomcp_protocol::PROTOCOL_VERSION.to_string(),
                     server_info: Implementation {
@@ -253,8 +765,16 @@ impl RequestRouter {
                         title: Some("TurboMCP Server".to_string()),
                         version: crate::SERVER_VERSION.to_string(),
                     },
-                    capabilities: self.get_server_capabilities(),
+                    capabilities,
                     instructions: None,
+                    supported_versions: Some(
+                        turbomcp_protocol::SUPPORTED_VERSIONS
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect(),
+                    ),
+                    meta: (!self.server_meta.is_empty()).then(|| self.server_meta.clone()),
+                    tool_manifest_version: Some(manifest_version(&self.listed_tools())),
                 };
 
                 self.success_response(&request, result)
@@ -263,28 +783,56 @@ impl RequestRouter {
         }
     }
 
+    /// Liveness check; answered with an empty result, even before initialize
+    async fn handle_ping(&self, request: JsonRpcRequest, _ctx: RequestContext) -> JsonRpcResponse {
+        self.success_response(&request, EmptyResult {})
+    }
+
     async fn handle_list_tools(
         &self,
         request: JsonRpcRequest,
         _ctx: RequestContext,
     ) -> JsonRpcResponse {
-        let tools = self.registry.get_tool_definitions();
+        let tools = self.listed_tools();
         let result = ListToolsResult {
+            manifest_version: Some(manifest_version(&tools)),
             tools,
             next_cursor: None,
         };
         self.success_response(&request, result)
     }
 
+    /// Tools shown to clients, leaving out those disabled by configuration
+    fn listed_tools(&self) -> Vec<Tool> {
+        let access = self.tool_access.read();
+        self.registry
+            .get_tool_definitions()
+            .into_iter()
+            .filter(|tool| access.is_allowed(&tool.name))
+            .collect()
+    }
+
     async fn handle_call_tool(
         &self,
         request: JsonRpcRequest,
         ctx: RequestContext,
     ) -> JsonRpcResponse {
+        if let Err(e) = self.check_tool_input_size(&request) {
+            return self.error_response(&request, e);
+        }
+
         match self.parse_params::<CallToolRequest>(&request) {
             Ok(call_request) => {
                 let tool_name = &call_request.name;
 
+                if !self.tool_access.read().is_allowed(tool_name) {
+                    let error = ServerError::authorization_with_resource(
+                        format!("Tool '{tool_name}' is disabled by configuration"),
+                        tool_name.clone(),
+                    );
+                    return self.error_response(&request, error);
+                }
+
                 if let Some(handler) = self.registry.get_tool(tool_name) {
                     // RBAC: if handler metadata enforces allowed roles, check RequestContext
                     if self.config.validate_requests
@@ -354,26 +902,103 @@ impl RequestRouter {
                                 let instance = serde_json::Value::Object(
                                     arguments.clone().into_iter().collect(),
                                 );
-                                let mut error_messages: Vec<String> = Vec::new();
-                                if let Err(iter) = compiled.validate(&instance) {
-                                    for e in iter {
-                                        error_messages.push(format!("{}: {}", e.instance_path, e));
-                                    }
-                                }
-                                if !error_messages.is_empty() {
-                                    let joined = error_messages.join("; ");
-                                    let err = ServerError::routing_with_method(
-                                        format!("Argument validation failed: {joined}"),
-                                        "tools/call".to_string(),
-                                    );
+                                if let Err(errors) = compiled.validate(&instance) {
+                                    let err = schema_argument_error(props, errors);
                                     return self.error_response(&request, err);
                                 }
                             }
                         }
                     }
-                    match handler.handle(call_request, ctx).await {
-                        Ok(result) => self.success_response(&request, result),
-                        Err(e) => self.error_response(&request, e),
+                    let cache_policy = handler.cache_policy();
+                    let cache_key = cache_policy
+                        .map(|_| ToolResultCache::cache_key(call_request.arguments.as_ref()));
+                    if let Some(key) = &cache_key
+                        && let Some(cached) = self.tool_cache.get(tool_name, key)
+                    {
+                        return self.success_response(&request, cached);
+                    }
+
+                    let tool_name = tool_name.clone();
+                    let breaker = self
+                        .circuit_breakers
+                        .config_for(&tool_name, handler.circuit_breaker());
+                    if let Some(config) = &breaker
+                        && let Err(e) = self.circuit_breakers.check(&tool_name, config)
+                    {
+                        return self.error_response(&request, e);
+                    }
+
+                    let budget = self
+                        .memory_budget
+                        .read()
+                        .map(MemoryBudget::new)
+                        .map(Arc::new);
+                    let started = std::time::Instant::now();
+                    let outcome = match &budget {
+                        // Stop a handler that keeps going after its budget ran out
+                        Some(budget) => {
+                            let ctx = ctx.with_memory_budget(Arc::clone(budget));
+                            tokio::select! {
+                                biased;
+                                outcome = handler.handle(call_request, ctx) => outcome,
+                                exceeded = budget.exceeded() => Err(exceeded.into()),
+                            }
+                        }
+                        None => handler.handle(call_request, ctx).await,
+                    };
+                    if breaker.is_some() {
+                        let success = outcome
+                            .as_ref()
+                            .is_ok_and(|result| result.is_error != Some(true));
+                        self.circuit_breakers
+                            .record(&tool_name, success, started.elapsed());
+                    }
+                    let outcome = match handler.output_limit() {
+                        Some(limit) => outcome.and_then(|result| limit.enforce(&tool_name, result)),
+                        None => outcome,
+                    };
+                    // Fail before the response copies an over-budget result
+                    let outcome = match &budget {
+                        Some(budget) => outcome.and_then(|result| {
+                            check_result_size(&tool_name, budget.limit(), result)
+                        }),
+                        None => outcome,
+                    };
+                    match outcome {
+                        Ok(result) => {
+                            if let (Some(policy), Some(key)) = (cache_policy, cache_key)
+                                && result.is_error != Some(true)
+                            {
+                                self.tool_cache
+                                    .insert(&tool_name, key, result.clone(), policy.ttl);
+                            }
+                            self.success_response(&request, result)
+                        }
+                        Err(e) => {
+                            // Bad arguments are the caller's mistake, not the tool's
+                            let mode = if matches!(e, ServerError::InvalidArgument { .. }) {
+                                ToolErrorMode::Protocol
+                            } else {
+                                self.tool_errors
+                                    .read()
+                                    .mode_for(&tool_name, handler.error_mode())
+                            };
+                            match mode {
+                                ToolErrorMode::Protocol => self.error_response(&request, e),
+                                ToolErrorMode::Result => {
+                                    let result = CallToolResult {
+                                        content: vec![Content::Text(TextContent {
+                                            text: e.to_string(),
+                                            annotations: None,
+                                            meta: None,
+                                        })],
+                                        is_error: Some(true),
+                                        ..Default::default()
+                                    };
+                                    self.success_response(&request, result)
+                                }
+                            }
+                        }
                     }
                 } else {
                     let error = ServerError::not_found(format!("Tool '{tool_name}'"));
@@ -445,9 +1070,12 @@ impl RequestRouter {
                 // Find handler by matching URI pattern
                 for handler in &self.registry.resources {
                     let resource_def = handler.value().resource_definition();
-                    if self.matches_uri_pattern(&resource_def.uri, resource_uri) {
+                    if matches_uri_template(&resource_def.uri, resource_uri) {
                         match handler.value().handle(resource_request, ctx).await {
-                            Ok(result) => return self.success_response(&request, result),
+                            Ok(mut result) => {
+                                crate::mime::fill_mime_types(&mut result);
+                                return self.success_response(&request, result);
+                            }
                             Err(e) => return self.error_response(&request, e),
                         }
                     }
@@ -460,6 +1088,43 @@ impl RequestRouter {
         }
     }
 
+    async fn handle_get_operation(
+        &self,
+        request: JsonRpcRequest,
+        _ctx: RequestContext,
+    ) -> JsonRpcResponse {
+        match self.parse_params::<OperationRequest>(&request) {
+            Ok(params) => match self.operations.get(&params.operation_id) {
+                Some(status) => self.success_response(&request, status),
+                None => self.error_response(&request, unknown_operation(&params.operation_id)),
+            },
+            Err(e) => self.error_response(&request, e),
+        }
+    }
+
+    async fn handle_resume_operation(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> JsonRpcResponse {
+        match self.parse_params::<OperationRequest>(&request) {
+            Ok(params) => {
+                let token = params
+                    .progress_token
+                    .unwrap_or_else(|| params.operation_id.clone());
+                match self.operations.resume(
+                    &params.operation_id,
+                    serde_json::Value::String(token),
+                    ctx.notifier,
+                ) {
+                    Ok(status) => self.success_response(&request, status),
+                    Err(e) => self.error_response(&request, e),
+                }
+            }
+            Err(e) => self.error_response(&request, e),
+        }
+    }
+
     async fn handle_subscribe_resource(
         &self,
         request: JsonRpcRequest,
@@ -513,15 +1178,52 @@ impl RequestRouter {
     ) -> JsonRpcResponse {
         match self.parse_params::<SetLevelRequest>(&request) {
             Ok(level_request) => {
-                // Use first available logging handler
-                if let Some(handler_entry) = self.registry.logging.iter().next() {
-                    match handler_entry.value().handle(level_request, ctx).await {
-                        Ok(result) => self.success_response(&request, result),
-                        Err(e) => self.error_response(&request, e),
+                let level = level_request.level;
+                // A registered logging handler may still refuse the level
+                let result = match self.registry.logging.iter().next() {
+                    Some(handler_entry) => {
+                        handler_entry
+                            .value()
+                            .handle(level_request, ctx.clone())
+                            .await
                     }
-                } else {
-                    let error = ServerError::not_found("No logging handler available");
-                    self.error_response(&request, error)
+                    None => Ok(EmptyResult {}),
+                };
+                match result {
+                    Ok(result) => {
+                        self.log_level.set(&ctx, Some(level));
+                        self.success_response(&request, result)
+                    }
+                    Err(e) => self.error_response(&request, e),
+                }
+            }
+            Err(e) => self.error_response(&request, e),
+        }
+    }
+
+    async fn handle_complete(
+        &self,
+        request: JsonRpcRequest,
+        ctx: RequestContext,
+    ) -> JsonRpcResponse {
+        match self.parse_params::<CompleteRequest>(&request) {
+            Ok(complete_request) => {
+                let handler = self
+                    .registry
+                    .get_completion(&complete_request.reference, &complete_request.argument.name);
+                // Arguments without a handler just have nothing to suggest
+                let Some(handler) = handler else {
+                    let result = CompleteResult {
+                        completion: Completion::from_candidates(Vec::new()),
+                    };
+                    return self.success_response(&request, result);
+                };
+                match handler.handle(complete_request, ctx).await {
+                    Ok(mut result) => {
+                        result.completion = result.completion.limited();
+                        self.success_response(&request, result)
+                    }
+                    Err(e) => self.error_response(&request, e),
                 }
             }
             Err(e) => self.error_response(&request, e),
@@ -608,31 +1310,57 @@ impl RequestRouter {
             } else {
                 Some(ResourcesCapabilities::default())
             },
-            logging: if self.registry.logging.is_empty() {
+            // The router keeps the level itself, handler or not
+            logging: Some(LoggingCapabilities),
+            completions: if self.registry.completions.is_empty() {
                 None
             } else {
-                Some(LoggingCapabilities)
+                Some(CompletionCapabilities)
             },
-            completions: None, // Completion capabilities not enabled by default
             experimental: None,
         }
     }
 
+    /// Enforce the called tool's input size limit before its arguments are deserialized
+    fn check_tool_input_size(&self, request: &JsonRpcRequest) -> ServerResult<()> {
+        let Some(params) = &request.params else {
+            return Ok(());
+        };
+        let Some(name) = params.get("name").and_then(serde_json::Value::as_str) else {
+            return Ok(());
+        };
+        let Some(limit) = self
+            .registry
+            .get_tool(name)
+            .and_then(|handler| handler.max_input_size())
+        else {
+            return Ok(());
+        };
+
+        let size = params.get("arguments").map_or(0, json_size);
+        if size > limit {
+            return Err(ServerError::invalid_params(format!(
+                "Arguments for tool '{name}' are {size} bytes, exceeding its limit of {limit} bytes"
+            )));
+        }
+        Ok(())
+    }
+
     fn parse_params<T>(&self, request: &JsonRpcRequest) -> ServerResult<T>
     where
         T: serde::de::DeserializeOwned,
     {
         match &request.params {
             Some(params) => serde_json::from_value(params.clone()).map_err(|e| {
-                ServerError::routing_with_method(
-                    format!("Invalid parameters: {e}"),
-                    request.method.clone(),
-                )
+                ServerError::invalid_params(format!(
+                    "Invalid parameters for {}: {e}",
+                    request.method
+                ))
             }),
-            None => Err(ServerError::routing_with_method(
-                "Missing required parameters".to_string(),
-                request.method.clone(),
-            )),
+            None => Err(ServerError::invalid_params(format!(
+                "Missing required parameters for {}",
+                request.method
+            ))),
         }
     }
 
@@ -656,7 +1384,7 @@ impl RequestRouter {
             error: Some(turbomcp_protocol::jsonrpc::JsonRpcError {
                 code: error.error_code(),
                 message: error.to_string(),
-                data: None,
+                data: error.error_data(),
             }),
         }
     }
@@ -727,47 +1455,127 @@ impl RequestRouter {
             _ => Ok(()),
         }
     }
+}
 
-    fn matches_uri_pattern(&self, pattern: &str, uri: &str) -> bool {
-        // Convert simple templates to regex (very basic):
-        // - '*' => '.*'
-        // - '{param}' => '[^/]+'
-        let mut regex_str = String::from("^");
-        let mut chars = pattern.chars().peekable();
-        while let Some(c) = chars.next() {
-            match c {
-                '*' => regex_str.push_str(".*"),
-                '{' => {
-                    // consume until '}'
-                    for nc in chars.by_ref() {
-                        if nc == '}' {
-                            break;
-                        }
-                    }
-                    regex_str.push_str("[^/]+");
-                }
-                '.' | '+' | '?' | '(' | ')' | '|' | '^' | '$' | '[' | ']' | '\\' => {
-                    regex_str.push('\\');
-                    regex_str.push(c);
-                }
-                other => regex_str.push(other),
-            }
-        }
-        regex_str.push('$');
-        let re = regex::Regex::new(&regex_str).unwrap_or_else(|_| regex::Regex::new("^$").unwrap());
-        re.is_match(uri)
+/// Whether `uri` matches a resource URI `template`
+///
+/// A `{param}` matches one path segment and `*` matches anything, as
+/// [`template::match_uri`] reads them.
+#[must_use]
+pub fn matches_uri_template(template: &str, uri: &str) -> bool {
+    template::match_uri(template, uri).is_some()
+}
+
+/// Values `uri` gives the `{param}` variables of a resource URI `template`
+///
+/// Returns `None` when `uri` does not match the template.
+#[must_use]
+pub fn uri_template_params(template: &str, uri: &str) -> Option<HashMap<String, String>> {
+    template::match_uri(template, uri)
+}
+
+/// A URI both resource URI templates match, if there is one
+///
+/// Two templates that share a URI leave it to chance which handler serves
+/// it, so registration rejects them; the URI returned shows the conflict.
+#[must_use]
+pub fn uri_templates_overlap(a: &str, b: &str) -> Option<String> {
+    template::uri_templates_overlap(a, b)
+}
+
+/// Digest of a tool listing, independent of listing order
+///
+/// Tools are converted to JSON values first: their object keys are sorted,
+/// while the structs' own maps would serialize in hash order.
+fn manifest_version(tools: &[Tool]) -> String {
+    let by_name: BTreeMap<&str, serde_json::Value> = tools
+        .iter()
+        .map(|tool| {
+            let value = serde_json::to_value(tool).unwrap_or_default();
+            (tool.name.as_str(), value)
+        })
+        .collect();
+    let bytes = serde_json::to_vec(&by_name).unwrap_or_default();
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Error for tool arguments that fail the tool's input schema
+///
+/// The first error on a single argument names it and the JSON type its
+/// schema expects. Errors on the arguments as a whole, such as a missing
+/// required one, are reported as plain invalid params.
+fn schema_argument_error<'a>(
+    properties: &HashMap<String, serde_json::Value>,
+    errors: impl Iterator<Item = jsonschema::ValidationError<'a>>,
+) -> ServerError {
+    let mut messages = Vec::new();
+    for error in errors {
+        let path = error.instance_path.to_string();
+        let argument = path
+            .strip_prefix('/')
+            .and_then(|path| path.split('/').next())
+            .filter(|argument| !argument.is_empty())
+            .map(|argument| argument.replace("~1", "/").replace("~0", "~"));
+        let Some(argument) = argument else {
+            messages.push(error.to_string());
+            continue;
+        };
+        let expected = match properties
+            .get(&argument)
+            .and_then(|schema| schema.get("type"))
+        {
+            Some(serde_json::Value::String(ty)) => ty.clone(),
+            Some(serde_json::Value::Array(types)) => types
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" or "),
+            _ => "a value matching its schema".to_string(),
+        };
+        return ServerError::invalid_argument(argument, expected, error);
     }
+    ServerError::invalid_params(format!(
+        "Argument validation failed: {}",
+        messages.join("; ")
+    ))
 }
 
-impl Clone for RequestRouter {
-    fn clone(&self) -> Self {
-        Self {
-            registry: Arc::clone(&self.registry),
-            config: self.config.clone(),
-            custom_routes: self.custom_routes.clone(),
-            resource_subscriptions: DashMap::new(),
+/// Fail a tool result whose serialized form would not fit in `budget` bytes
+fn check_result_size(
+    tool: &str,
+    budget: usize,
+    result: CallToolResult,
+) -> ServerResult<CallToolResult> {
+    let size = json_size(&result);
+    if size > budget {
+        return Err(ServerError::resource_exhausted_with_usage(
+            format!("memory budget of tool '{tool}' ({size}-byte result, {budget}-byte budget)"),
+            size,
+            budget,
+        ));
+    }
+    Ok(result)
+}
+
+/// Serialized size of a value in bytes, without allocating the output
+pub(crate) fn json_size<T: serde::Serialize + ?Sized>(value: &T) -> usize {
+    struct Counter(usize);
+
+    impl std::io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
         }
     }
+
+    let mut counter = Counter(0);
+    // Writing to a counter cannot fail
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
 }
 
 /// Route definition for custom routing
@@ -0,0 +1,206 @@
+//! Startup self-check of registered handlers
+//!
+//! Registration mistakes such as a malformed tool schema or an unbalanced
+//! resource URI template otherwise only show up when a client first calls the
+//! handler. The self-check inspects every registered definition up front and
+//! reports all problems at once, so a broken server fails at boot.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use jsonschema::{Draft, JSONSchema};
+use serde_json::Value;
+use turbomcp_protocol::template;
+
+use crate::registry::HandlerRegistry;
+
+/// A single problem found by the self-check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfCheckIssue {
+    /// Kind of handler (`tool`, `resource` or `prompt`)
+    pub kind: &'static str,
+    /// Registered name of the handler
+    pub name: String,
+    /// What is wrong
+    pub problem: String,
+}
+
+impl fmt::Display for SelfCheckIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} '{}': {}", self.kind, self.name, self.problem)
+    }
+}
+
+/// Outcome of a self-check
+#[derive(Debug, Clone, Default)]
+pub struct SelfCheckReport {
+    /// Problems found, in registry order per handler kind
+    pub issues: Vec<SelfCheckIssue>,
+}
+
+impl SelfCheckReport {
+    /// True when no problems were found
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, kind: &'static str, name: &str, problem: impl Into<String>) {
+        self.issues.push(SelfCheckIssue {
+            kind,
+            name: name.to_string(),
+            problem: problem.into(),
+        });
+    }
+}
+
+impl fmt::Display for SelfCheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "all handlers passed the self-check");
+        }
+        write!(f, "{} problem(s) found:", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n  - {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check every tool, resource and prompt definition in a registry
+#[must_use]
+pub fn check_registry(registry: &HandlerRegistry) -> SelfCheckReport {
+    let mut report = SelfCheckReport::default();
+
+    for entry in &registry.tools {
+        let tool = entry.value().tool_definition();
+        let name = entry.key();
+
+        if tool.input_schema.schema_type != "object" {
+            report.push(
+                "tool",
+                name,
+                format!(
+                    "input schema type must be \"object\", found \"{}\"",
+                    tool.input_schema.schema_type
+                ),
+            );
+        }
+        check_schema(
+            &mut report,
+            name,
+            "input schema",
+            serde_json::to_value(&tool.input_schema),
+        );
+        check_required(
+            &mut report,
+            name,
+            "input schema",
+            tool.input_schema.properties.as_ref(),
+            tool.input_schema.required.as_ref(),
+        );
+
+        if let Some(output) = &tool.output_schema {
+            check_schema(
+                &mut report,
+                name,
+                "output schema",
+                serde_json::to_value(output),
+            );
+            check_required(
+                &mut report,
+                name,
+                "output schema",
+                output.properties.as_ref(),
+                output.required.as_ref(),
+            );
+        }
+    }
+
+    for entry in &registry.resources {
+        let resource = entry.value().resource_definition();
+        if let Err(problem) = parse_uri_template(&resource.uri) {
+            report.push(
+                "resource",
+                entry.key(),
+                format!("URI template '{}': {problem}", resource.uri),
+            );
+        }
+    }
+
+    for entry in &registry.prompts {
+        let prompt = entry.value().prompt_definition();
+        let mut seen = HashSet::new();
+        for argument in prompt.arguments.iter().flatten() {
+            if argument.name.trim().is_empty() {
+                report.push("prompt", entry.key(), "argument with an empty name");
+            } else if !seen.insert(argument.name.as_str()) {
+                report.push(
+                    "prompt",
+                    entry.key(),
+                    format!("duplicate argument '{}'", argument.name),
+                );
+            }
+        }
+    }
+
+    report
+}
+
+fn check_schema(
+    report: &mut SelfCheckReport,
+    name: &str,
+    which: &str,
+    schema: serde_json::Result<Value>,
+) {
+    let schema = match schema {
+        Ok(schema) => schema,
+        Err(e) => {
+            report.push("tool", name, format!("{which} is not serializable: {e}"));
+            return;
+        }
+    };
+    if let Err(e) = JSONSchema::options()
+        .with_draft(Draft::Draft7)
+        .compile(&schema)
+    {
+        report.push(
+            "tool",
+            name,
+            format!("{which} is not valid JSON Schema: {e}"),
+        );
+    }
+}
+
+fn check_required(
+    report: &mut SelfCheckReport,
+    name: &str,
+    which: &str,
+    properties: Option<&std::collections::HashMap<String, Value>>,
+    required: Option<&Vec<String>>,
+) {
+    for field in required.into_iter().flatten() {
+        if !properties.is_some_and(|props| props.contains_key(field)) {
+            report.push(
+                "tool",
+                name,
+                format!("{which} requires '{field}', which is not a declared property"),
+            );
+        }
+    }
+}
+
+/// Parse a resource URI template, returning its parameter names
+fn parse_uri_template(uri: &str) -> Result<Vec<&str>, String> {
+    if uri.trim().is_empty() {
+        return Err("URI is empty".to_string());
+    }
+
+    let params = template::placeholders(uri).map_err(|e| e.to_string())?;
+    for (i, param) in params.iter().enumerate() {
+        if params[..i].contains(param) {
+            return Err(format!("duplicate parameter '{param}'"));
+        }
+    }
+    Ok(params)
+}
@@ -1,25 +1,52 @@
 //! Core MCP server implementation
 
+use std::collections::VecDeque;
+use std::future::Future;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{OnceCell, RwLock, mpsc};
 
 use crate::{
-    config::ServerConfig,
+    build_info::BuildInfo,
+    config::{
+        OutputFilterConfig, PreInitLimitConfig, PrewarmConfig, RateLimitingConfig, ServerConfig,
+        ToolErrorMode,
+    },
+    envelope,
     error::ServerResult,
-    handlers::{PromptHandler, ResourceHandler, ToolHandler},
-    lifecycle::{HealthStatus, ServerLifecycle},
-    metrics::ServerMetrics,
-    middleware::{KeyExtractor, MiddlewareStack, RateLimitConfig, RateLimitMiddleware},
+    handlers::{
+        CompletionHandler, PromptHandler, ResourceHandler, StreamingResource,
+        StreamingResourceHandler, ToolHandler,
+    },
+    history::SessionHistory,
+    lifecycle::{HealthStatus, ServerLifecycle, ServerState},
+    metrics::{MetricsSink, ServerMetrics},
+    middleware::{
+        KeyExtractor, Middleware, MiddlewareStack, OutputFilterMiddleware, RateLimitConfig,
+        RateLimitMiddleware, RequestFlow,
+    },
+    prewarm::{PrewarmReport, prewarm_registry},
     registry::HandlerRegistry,
-    routing::RequestRouter,
+    reload::{ConfigReloader, LogLevelHook, ReloadReport},
+    request_log::{RequestLogger, RequestRecord},
+    routing::{FunctionRouteHandler, RequestRouter},
+    self_check::{SelfCheckReport, check_registry},
+    session::CapabilityHook,
+    startup::{Readiness, StartupTask, run_startup_tasks},
 };
 
 use bytes::Bytes;
-use tokio::time::{Duration, sleep};
-use turbomcp_core::RequestContext;
-use turbomcp_protocol::jsonrpc::{JsonRpcMessage, JsonRpcRequest, JsonRpcResponse};
+use tokio::time::{Duration, Instant, sleep, timeout_at};
+use turbomcp_core::{MessageId, NotificationSender, RequestContext, ServerToClient, SharedClock};
+use turbomcp_protocol::jsonrpc::{
+    JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
+};
+use turbomcp_protocol::types::{InitializeRequest, ServerCapabilities};
+use turbomcp_protocol::{NotificationSequencer, UnknownNotificationPolicy, methods};
 use turbomcp_transport::StdioTransport;
-use turbomcp_transport::core::{TransportError, TransportMessageMetadata};
+use turbomcp_transport::core::{
+    TransportError, TransportMessageMetadata, TransportResult, TransportType,
+};
+use turbomcp_transport::wire_trace::{WireDirection, WireTracer};
 use turbomcp_transport::{Transport, TransportMessage};
 
 /// Handle for triggering graceful server shutdown
@@ -46,7 +73,6 @@ impl ShutdownHandle {
 
     /// Check if shutdown has been initiated
     pub async fn is_shutting_down(&self) -> bool {
-        use crate::lifecycle::ServerState;
         matches!(
             self.lifecycle.state().await,
             ServerState::ShuttingDown | ServerState::Stopped
@@ -69,6 +95,175 @@ pub struct McpServer {
     lifecycle: Arc<ServerLifecycle>,
     /// Server metrics
     metrics: Arc<ServerMetrics>,
+    /// Sinks receiving measurements alongside the built-in metrics
+    metrics_sinks: Vec<Arc<dyn MetricsSink>>,
+    /// Running configuration, updated by hot reloads
+    live_config: Arc<parking_lot::RwLock<ServerConfig>>,
+    /// Hook applying reloaded log levels to the application's subscriber
+    log_level_hook: Option<LogLevelHook>,
+    /// Wire-level message tracer (disabled unless configured)
+    wire_tracer: WireTracer,
+    /// What to do with notifications the server does not recognize
+    unknown_notifications: UnknownNotificationPolicy,
+    /// Outcome of the boot-time tool pre-warm, once it has succeeded
+    prewarmed: OnceCell<PrewarmReport>,
+    /// Tasks that must complete before the server is ready
+    startup_tasks: Vec<StartupTask>,
+    /// Set once the startup tasks have completed
+    started: OnceCell<()>,
+    /// Readiness shared with health endpoints
+    readiness: Readiness,
+    /// Durable log of every answered request, if configured
+    request_logger: Option<RequestLogger>,
+    /// Recent requests of each live session, if enabled
+    session_history: Option<SessionHistory>,
+}
+
+/// Install the rate limit middleware for a rate limiting configuration,
+/// refilling by `clock`
+pub(crate) fn install_rate_limit(
+    stack: &mut MiddlewareStack,
+    config: &RateLimitingConfig,
+    clock: &SharedClock,
+) {
+    let rate_config = RateLimitConfig {
+        requests_per_second: config.requests_per_second,
+        burst_capacity: config.burst_capacity,
+        key_extractor: KeyExtractor::Global,
+    };
+
+    #[cfg(test)]
+    let rate_middleware = RateLimitMiddleware::new_for_testing(rate_config, Arc::clone(clock));
+
+    #[cfg(not(test))]
+    let rate_middleware = RateLimitMiddleware::with_clock(rate_config, Arc::clone(clock));
+
+    stack.add(rate_middleware);
+}
+
+/// Request context carrying the transport metadata a message arrived with
+///
+/// Headers are exposed through [`RequestContext::header`] and
+/// transport-specific extensions under `transport_extensions`.
+fn transport_context(
+    transport_type: TransportType,
+    metadata: &TransportMessageMetadata,
+) -> RequestContext {
+    let mut ctx = RequestContext::new().with_metadata("transport", transport_type.to_string());
+    if !metadata.headers.is_empty() {
+        ctx = ctx.with_metadata("headers", serde_json::json!(metadata.headers));
+    }
+    if !metadata.extensions.is_empty() {
+        ctx = ctx.with_metadata(
+            "transport_extensions",
+            serde_json::json!(metadata.extensions),
+        );
+    }
+    ctx
+}
+
+/// State of one transport connection to a client
+struct Connection {
+    /// Everything received over the transport belongs to this session
+    session_id: String,
+    /// Where handlers queue notifications and requests for the client
+    notifier: NotificationSender,
+    /// What handlers queued, waiting to be sent
+    outgoing: mpsc::UnboundedReceiver<serde_json::Value>,
+    /// Requests to the client; only on transports that carry both directions
+    client: Option<ServerToClient>,
+    /// Messages that arrived while a handler was waiting on the client
+    deferred: VecDeque<TransportMessage>,
+    /// Limits on the client until it initializes, if enforced
+    pre_init: Option<PreInitGuard>,
+    /// Numbers the session's notifications, if enabled
+    sequencer: Option<NotificationSequencer>,
+}
+
+/// Enforces a [`PreInitLimitConfig`] on a connection that has not yet
+/// sent `initialize`
+#[derive(Debug)]
+struct PreInitGuard {
+    limits: PreInitLimitConfig,
+    /// Start of the current one-second window
+    window_start: Instant,
+    /// Messages received in the current window
+    in_window: u32,
+    /// Malformed messages received so far
+    invalid: u32,
+}
+
+impl PreInitGuard {
+    fn new(limits: &PreInitLimitConfig) -> Option<Self> {
+        limits.enabled.then(|| Self {
+            limits: limits.clone(),
+            window_start: Instant::now(),
+            in_window: 0,
+            invalid: 0,
+        })
+    }
+
+    /// Count a message, returning whether it is the client's `initialize`
+    ///
+    /// Fails with the limit broken once the client sends too many messages
+    /// in one second or too many malformed ones.
+    fn admit(&mut self, payload: &[u8]) -> Result<bool, String> {
+        let now = Instant::now();
+        if now.duration_since(self.window_start) >= Duration::from_secs(1) {
+            self.window_start = now;
+            self.in_window = 0;
+        }
+        self.in_window += 1;
+        if self.in_window > self.limits.max_messages_per_second {
+            return Err(format!(
+                "more than {} messages in one second",
+                self.limits.max_messages_per_second
+            ));
+        }
+
+        let message = serde_json::from_slice::<serde_json::Value>(payload).ok();
+        let well_formed = message.as_ref().is_some_and(|message| match message {
+            serde_json::Value::Array(items) => {
+                !items.is_empty()
+                    && items
+                        .iter()
+                        .all(|item| envelope::validate_envelope(item).is_ok())
+            }
+            single => envelope::validate_envelope(single).is_ok(),
+        });
+        if !well_formed {
+            self.invalid += 1;
+            if self.invalid > self.limits.max_invalid_messages {
+                return Err(format!(
+                    "more than {} malformed messages",
+                    self.limits.max_invalid_messages
+                ));
+            }
+        }
+        Ok(well_formed && message.is_some_and(|message| message["method"] == methods::INITIALIZE))
+    }
+}
+
+impl Connection {
+    /// Let the handlers of a request reach the client
+    fn attach(&self, ctx: RequestContext) -> RequestContext {
+        let ctx = ctx
+            .with_session_id(&self.session_id)
+            .with_notifier(self.notifier.clone());
+        match &self.client {
+            Some(client) => ctx.with_client(client.clone()),
+            None => ctx,
+        }
+    }
+}
+
+/// Install the output filter middleware for an output filter configuration
+pub(crate) fn install_output_filter(
+    stack: &mut MiddlewareStack,
+    config: &OutputFilterConfig,
+) -> ServerResult<()> {
+    stack.add(OutputFilterMiddleware::new(config)?);
+    Ok(())
 }
 
 impl std::fmt::Debug for McpServer {
@@ -85,28 +280,37 @@ impl McpServer {
     pub fn new(config: ServerConfig) -> Self {
         let registry = Arc::new(HandlerRegistry::new());
         let router = Arc::new(RequestRouter::new(Arc::clone(&registry)));
+        Self::with_router(config, registry, router)
+    }
+
+    /// Create a server dispatching through `router`, configured by `config`
+    fn with_router(
+        config: ServerConfig,
+        registry: Arc<HandlerRegistry>,
+        router: Arc<RequestRouter>,
+    ) -> Self {
         let mut stack = MiddlewareStack::new();
         // Auto-install rate limiting if enabled in config
         if config.rate_limiting.enabled {
-            #[cfg(test)]
-            let rate_middleware = RateLimitMiddleware::new_for_testing(RateLimitConfig {
-                requests_per_second: config.rate_limiting.requests_per_second,
-                burst_capacity: config.rate_limiting.burst_capacity,
-                key_extractor: KeyExtractor::Global,
-            });
-
-            #[cfg(not(test))]
-            let rate_middleware = RateLimitMiddleware::new(RateLimitConfig {
-                requests_per_second: config.rate_limiting.requests_per_second,
-                burst_capacity: config.rate_limiting.burst_capacity,
-                key_extractor: KeyExtractor::Global,
-            });
-
-            stack.add(rate_middleware);
+            install_rate_limit(&mut stack, &config.rate_limiting, router.clock());
         }
+        // An invalid filter is reported again by `validate` before serving
+        if config.output_filter.enabled
+            && let Err(e) = install_output_filter(&mut stack, &config.output_filter)
+        {
+            tracing::error!(error = %e, "Output filter not installed");
+        }
+        router.set_tool_access(config.tool_access.clone());
+        router.set_tool_errors(config.tool_errors.clone());
+        router.set_memory_budget(config.memory_budget);
+        router.set_method_access(config.method_access.clone());
+        router
+            .circuit_breakers()
+            .set_config(config.circuit_breakers.clone());
         let middleware = Arc::new(RwLock::new(stack));
         let lifecycle = Arc::new(ServerLifecycle::new());
         let metrics = Arc::new(ServerMetrics::new());
+        let live_config = Arc::new(parking_lot::RwLock::new(config.clone()));
 
         Self {
             config,
@@ -115,6 +319,17 @@ impl McpServer {
             middleware,
             lifecycle,
             metrics,
+            metrics_sinks: Vec::new(),
+            live_config,
+            log_level_hook: None,
+            wire_tracer: WireTracer::from_env(),
+            unknown_notifications: UnknownNotificationPolicy::default(),
+            prewarmed: OnceCell::new(),
+            startup_tasks: Vec::new(),
+            started: OnceCell::new(),
+            readiness: Readiness::default(),
+            request_logger: None,
+            session_history: None,
         }
     }
 
@@ -148,6 +363,153 @@ impl McpServer {
         &self.metrics
     }
 
+    /// Report a measurement to the built-in metrics and every registered sink
+    fn record_metric(&self, record: impl Fn(&dyn MetricsSink)) {
+        record(self.metrics.as_ref());
+        for sink in &self.metrics_sinks {
+            record(sink.as_ref());
+        }
+    }
+
+    /// Time since the server was created
+    #[must_use]
+    pub fn uptime(&self) -> Duration {
+        self.metrics.start_time.elapsed()
+    }
+
+    /// Version, commit and features the server was built with
+    #[must_use]
+    pub fn build_info(&self) -> BuildInfo {
+        BuildInfo::current()
+    }
+
+    /// Get a snapshot of the running configuration
+    ///
+    /// Unlike [`config`](Self::config), which returns the configuration the
+    /// server was started with, this reflects settings applied by hot reloads.
+    #[must_use]
+    pub fn current_config(&self) -> ServerConfig {
+        self.live_config.read().clone()
+    }
+
+    /// Get a reloader for applying configuration changes at runtime
+    ///
+    /// The reloader reads from `config.reload.config_file`. On Unix, the run
+    /// methods also trigger it on SIGHUP when `config.reload.on_sighup` is set
+    /// and there is a file to reload from.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use turbomcp_server::ServerBuilder;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let server = ServerBuilder::new()
+    ///     .config_file("/etc/my-server/config.json")
+    ///     .build();
+    /// let reloader = server.config_reloader();
+    ///
+    /// let report = reloader.reload().await?;
+    /// for change in &report.applied {
+    ///     println!("{} changed from {} to {}", change.setting, change.old, change.new);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn config_reloader(&self) -> ConfigReloader {
+        ConfigReloader::new(
+            self.config.reload.config_file.clone(),
+            Arc::clone(&self.live_config),
+            Arc::clone(&self.router),
+            Arc::clone(&self.middleware),
+            self.log_level_hook.clone(),
+        )
+    }
+
+    /// Re-read the configuration file and apply hot-reloadable settings
+    pub async fn reload_config(&self) -> ServerResult<ReloadReport> {
+        self.config_reloader().reload().await
+    }
+
+    /// Check every registered tool, resource and prompt definition
+    ///
+    /// Validates tool input/output schemas as JSON Schema, resource URI
+    /// templates and prompt arguments, collecting every problem found.
+    #[must_use]
+    pub fn self_check(&self) -> SelfCheckReport {
+        check_registry(&self.registry)
+    }
+
+    /// Fail if the [`self_check`](Self::self_check) finds any problem
+    ///
+    /// The run methods call this before serving, so a misregistered handler
+    /// stops the server at boot rather than failing its first request.
+    pub fn validate(&self) -> ServerResult<()> {
+        self.config.output_filter.validate()?;
+        validate_registry(&self.registry)
+    }
+
+    /// Run every registered tool's pre-warm hook
+    ///
+    /// The run methods call this before serving. Hooks run at most once per
+    /// server; later calls, such as from a second transport, return the first
+    /// report. Fails if any hook fails and `config.prewarm.fail_on_error` is
+    /// set; otherwise the failures are logged and included in the report.
+    pub async fn prewarm(&self) -> ServerResult<PrewarmReport> {
+        self.prewarmed
+            .get_or_try_init(|| async {
+                let report = prewarm_registry(&self.registry, &self.config.prewarm).await;
+                if !report.is_ok() {
+                    if self.config.prewarm.fail_on_error {
+                        return Err(crate::ServerError::Lifecycle(report.to_string()));
+                    }
+                    tracing::warn!(%report, "Serving despite tool pre-warm failures");
+                }
+                Ok(report)
+            })
+            .await
+            .cloned()
+    }
+
+    /// Run the registered startup tasks, then mark the server ready
+    ///
+    /// The run methods call this after [`prewarm`](Self::prewarm), so no
+    /// message is read before setup is done. Tasks run once per server; a
+    /// later call returns at once. Fails with an error naming the task if one
+    /// fails or `config.timeouts.startup_timeout` elapses first, in which
+    /// case the server stays unready.
+    pub async fn startup(&self) -> ServerResult<()> {
+        self.started
+            .get_or_try_init(|| async {
+                let timeout = self.config.timeouts.startup_timeout;
+                run_startup_tasks(&self.startup_tasks, timeout).await?;
+                self.readiness.mark_ready();
+                tracing::info!("Server ready");
+                Ok(())
+            })
+            .await
+            .copied()
+    }
+
+    /// Whether the startup tasks have all completed
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.readiness.is_ready()
+    }
+
+    /// A handle reporting readiness, for a `/readyz` style endpoint
+    #[must_use]
+    pub fn readiness(&self) -> Readiness {
+        self.readiness.clone()
+    }
+
+    /// Recent requests of each live session, if enabled with
+    /// [`ServerBuilder::with_session_history`]
+    #[must_use]
+    pub const fn session_history(&self) -> Option<&SessionHistory> {
+        self.session_history.as_ref()
+    }
+
     /// Get a shutdown handle for graceful server termination
     ///
     /// This handle enables external control over server shutdown, essential for:
@@ -346,8 +708,68 @@ impl McpServer {
         self.run_with_transport(transport).await
     }
 
-    /// Generic transport runner (DRY principle)
-    async fn run_with_transport<T: Transport>(&self, mut transport: T) -> ServerResult<()> {
+    /// Run the server over an already connected transport
+    ///
+    /// This is the loop behind all `run_*` methods, exposed for custom
+    /// transports. It first runs [`validate`](Self::validate) and refuses to
+    /// serve if any registered handler is malformed, then runs
+    /// [`prewarm`](Self::prewarm) and [`startup`](Self::startup) so no message
+    /// is read before every tool and startup task is ready. It returns when the transport disconnects or shutdown is
+    /// triggered. Shutdown is bounded by `config.timeouts.shutdown_timeout`
+    /// (see [`ServerBuilder::with_shutdown_timeout`]): a message still being
+    /// handled when the timeout elapses is abandoned and logged, so the
+    /// process can always terminate.
+    pub async fn run_with_transport<T: Transport>(&self, transport: T) -> ServerResult<()> {
+        self.prepare_to_serve().await?;
+        let end = self.serve_session(transport).await;
+        log_shutdown_summary(&[end]);
+        Ok(())
+    }
+
+    /// Serve several transports at once, shutting them down together
+    ///
+    /// Each transport is its own session, run as in
+    /// [`run_with_transport`](Self::run_with_transport) after a single
+    /// self-check, pre-warm and startup. On shutdown every session, whatever
+    /// its transport, stops reading new messages at once; in-flight messages
+    /// and queued notifications are then drained within one shared
+    /// `shutdown_timeout`, each client is sent
+    /// `notifications/server/shutdown`, and only then are the transports
+    /// disconnected. A summary of sessions drained and forcibly closed is
+    /// logged once all have ended.
+    pub async fn run_with_transports(
+        &self,
+        transports: Vec<Box<dyn Transport>>,
+    ) -> ServerResult<()> {
+        self.prepare_to_serve().await?;
+        let ends = futures::future::join_all(
+            transports
+                .into_iter()
+                .map(|transport| self.serve_session(transport)),
+        )
+        .await;
+        log_shutdown_summary(&ends);
+        Ok(())
+    }
+
+    /// Check, warm up and start the server, then install signal handlers
+    async fn prepare_to_serve(&self) -> ServerResult<()> {
+        if let Err(e) = self.validate() {
+            tracing::error!(error = %e, "Server self-check failed; refusing to start");
+            self.lifecycle.shutdown().await;
+            return Err(e);
+        }
+        if let Err(e) = self.prewarm().await {
+            tracing::error!(error = %e, "Tool pre-warm failed; refusing to start");
+            self.lifecycle.shutdown().await;
+            return Err(e);
+        }
+        if let Err(e) = self.startup().await {
+            tracing::error!(error = %e, "Startup task failed; refusing to start");
+            self.lifecycle.shutdown().await;
+            return Err(e);
+        }
+
         // Install signal handlers for graceful shutdown (Ctrl+C / SIGTERM)
         let lifecycle_for_sigint = self.lifecycle.clone();
         tokio::spawn(async move {
@@ -373,23 +795,131 @@ impl McpServer {
                     Err(e) => tracing::warn!(error = %e, "Failed to install SIGTERM handler"),
                 }
             });
+
+            if self.config.reload.reloads_on_sighup() {
+                let reloader = self.config_reloader();
+                tokio::spawn(async move {
+                    use tokio::signal::unix::{SignalKind, signal};
+                    match signal(SignalKind::hangup()) {
+                        Ok(mut sighup) => {
+                            while sighup.recv().await.is_some() {
+                                tracing::info!("SIGHUP received, reloading configuration");
+                                if let Err(e) = reloader.reload().await {
+                                    tracing::error!(error = %e, "Configuration reload failed");
+                                }
+                            }
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to install SIGHUP handler"),
+                    }
+                });
+            }
         }
+        Ok(())
+    }
 
+    /// Serve one session until its transport goes away or shutdown finishes
+    async fn serve_session<T: Transport>(&self, mut transport: T) -> SessionEnd {
         // Shutdown signal
         let mut shutdown = self.lifecycle.shutdown_signal();
+        let shutdown_timeout = self.config.timeouts.shutdown_timeout;
+        // Set when shutdown is requested; bounds all remaining work
+        let mut deadline: Option<Instant> = None;
+        // Whether work was abandoned because the deadline passed
+        let mut forced = false;
+        // Notifications and requests handlers send to the client, such as
+        // progress reports
+        let (notifier, outgoing) = mpsc::unbounded_channel();
+        let client = transport.capabilities().supports_bidirectional.then(|| {
+            ServerToClient::new(notifier.clone()).with_timeout(self.config.timeouts.request_timeout)
+        });
+        let mut connection = Connection {
+            session_id: uuid::Uuid::new_v4().to_string(),
+            notifier,
+            outgoing,
+            client,
+            deferred: VecDeque::new(),
+            pre_init: PreInitGuard::new(&self.config.pre_init),
+            sequencer: self
+                .config
+                .sequence_notifications
+                .then(NotificationSequencer::new),
+        };
+
+        // Shutdown may have begun before this session subscribed
+        if matches!(
+            self.lifecycle.state().await,
+            ServerState::ShuttingDown | ServerState::Stopped
+        ) {
+            deadline = Some(Instant::now() + shutdown_timeout);
+        }
 
         // Main message processing loop
-        loop {
+        while deadline.is_none() {
             tokio::select! {
                 _ = shutdown.recv() => {
                     tracing::info!("Shutdown signal received");
+                    deadline = Some(Instant::now() + shutdown_timeout);
                     break;
                 }
-                res = transport.receive() => {
+                // Sent by work that outlived the request it belongs to
+                Some(notification) = connection.outgoing.recv() => {
+                    self.send_notification(
+                        &mut transport,
+                        connection.sequencer.as_ref(),
+                        &notification,
+                    )
+                    .await;
+                }
+                res = self.next_message(&mut transport, &mut connection.deferred) => {
                     match res {
                         Ok(Some(message)) => {
-                            if let Err(e) = self.handle_transport_message(&mut transport, message).await {
-                                tracing::warn!(error = %e, "Failed to handle transport message");
+                            if let Some(guard) = &mut connection.pre_init {
+                                match guard.admit(&message.payload) {
+                                    Ok(false) => {}
+                                    Ok(true) => connection.pre_init = None,
+                                    Err(violation) => {
+                                        self.record_metric(|sink| sink.connection_rejected());
+                                        tracing::warn!(
+                                            target: "turbomcp::security",
+                                            event = "pre_init_limit_exceeded",
+                                            session_id = %connection.session_id,
+                                            transport = %transport.transport_type(),
+                                            %violation,
+                                            "Dropping connection that misbehaved before initializing"
+                                        );
+                                        break;
+                                    }
+                                }
+                            }
+                            let message_id = message.id.clone();
+                            let handling =
+                                self.handle_transport_message(&mut transport, message, &mut connection);
+                            tokio::pin!(handling);
+                            let outcome = tokio::select! {
+                                res = &mut handling => Some(res),
+                                _ = shutdown.recv() => {
+                                    tracing::info!(
+                                        ?shutdown_timeout,
+                                        "Shutdown signal received; waiting for in-flight message"
+                                    );
+                                    let until = Instant::now() + shutdown_timeout;
+                                    deadline = Some(until);
+                                    timeout_at(until, &mut handling).await.ok()
+                                }
+                            };
+                            match outcome {
+                                Some(Ok(())) => {}
+                                Some(Err(e)) => {
+                                    tracing::warn!(error = %e, "Failed to handle transport message");
+                                }
+                                None => {
+                                    tracing::warn!(
+                                        message_id = %message_id,
+                                        ?shutdown_timeout,
+                                        "Shutdown timeout elapsed; abandoning in-flight message"
+                                    );
+                                    forced = true;
+                                }
                             }
                         }
                         Ok(None) => {
@@ -402,6 +932,10 @@ impl McpServer {
                                     tracing::info!("Transport receive channel disconnected; shutting down");
                                     break;
                                 }
+                                TransportError::ConnectionLost(reason) => {
+                                    tracing::info!(%reason, "Transport connection lost; shutting down");
+                                    break;
+                                }
                                 _ => {
                                     tracing::error!(error = %e, "Transport receive failed");
                                     // Backoff on errors
@@ -414,21 +948,166 @@ impl McpServer {
             }
         }
 
-        // Disconnect transport
-        if let Err(e) = transport.disconnect().await {
-            tracing::warn!(error = %e, "Error while disconnecting transport");
+        self.router.end_session(&connection.session_id);
+        if let Some(history) = &self.session_history {
+            history.end_session(&connection.session_id);
         }
 
-        tracing::info!("Server shutdown complete");
-        Ok(())
+        // Tell the client before its connection goes away, sending whatever
+        // handlers queued first
+        if let Some(until) = deadline {
+            let outgoing = &mut connection.outgoing;
+            let sequencer = connection.sequencer.as_ref();
+            let farewell = async {
+                while let Ok(notification) = outgoing.try_recv() {
+                    self.send_notification(&mut transport, sequencer, &notification)
+                        .await;
+                }
+                let notice = JsonRpcNotification {
+                    jsonrpc: turbomcp_protocol::jsonrpc::JsonRpcVersion,
+                    method: methods::SERVER_SHUTDOWN.to_string(),
+                    params: None,
+                };
+                match serde_json::to_value(&notice) {
+                    Ok(notice) => {
+                        self.send_notification(&mut transport, sequencer, &notice)
+                            .await;
+                    }
+                    Err(e) => tracing::warn!(error = %e, "Failed to encode shutdown notification"),
+                }
+            };
+            if timeout_at(until, farewell).await.is_err() {
+                tracing::warn!(
+                    session_id = %connection.session_id,
+                    "Shutdown timeout elapsed; skipping shutdown notification"
+                );
+                forced = true;
+            }
+        }
+
+        // Disconnect transport, within whatever remains of the shutdown budget
+        let until = deadline.unwrap_or_else(|| Instant::now() + shutdown_timeout);
+        match timeout_at(until, transport.disconnect()).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => tracing::warn!(error = %e, "Error while disconnecting transport"),
+            Err(_) => {
+                tracing::warn!(
+                    ?shutdown_timeout,
+                    "Shutdown timeout elapsed; abandoning transport disconnect"
+                );
+                forced = true;
+            }
+        }
+
+        match (deadline, forced) {
+            (None, _) => SessionEnd::Disconnected,
+            (Some(_), false) => SessionEnd::Drained,
+            (Some(_), true) => SessionEnd::ForciblyClosed,
+        }
     }
 }
 
+/// How a session served by [`McpServer::serve_session`] ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SessionEnd {
+    /// The client went away before shutdown
+    Disconnected,
+    /// Shutdown finished all of the session's work in time
+    Drained,
+    /// Shutdown abandoned some of the session's work at its timeout
+    ForciblyClosed,
+}
+
+/// Log how the sessions of a stopping server ended
+fn log_shutdown_summary(ends: &[SessionEnd]) {
+    let count = |kind| ends.iter().filter(|&&end| end == kind).count();
+    tracing::info!(
+        sessions = ends.len(),
+        drained = count(SessionEnd::Drained),
+        forcibly_closed = count(SessionEnd::ForciblyClosed),
+        disconnected = count(SessionEnd::Disconnected),
+        "Server shutdown complete"
+    );
+}
+
 impl McpServer {
+    /// The next message to handle: any set aside while a handler waited on
+    /// the client, then whatever the transport delivers
+    async fn next_message(
+        &self,
+        transport: &mut dyn Transport,
+        deferred: &mut VecDeque<TransportMessage>,
+    ) -> TransportResult<Option<TransportMessage>> {
+        if let Some(message) = deferred.pop_front() {
+            return Ok(Some(message));
+        }
+        let received = transport.receive().await;
+        if let Ok(Some(message)) = &received {
+            self.wire_tracer.trace(WireDirection::Inbound, message);
+        }
+        received
+    }
+
+    /// Run `routing` while relaying between its handlers and the client
+    ///
+    /// A handler waiting on [`RequestContext::client_request`] needs a
+    /// response that arrives over this transport, while the main loop is
+    /// busy with the handler's own request. Until `routing` finishes, what
+    /// handlers queue is sent straight away, responses from the client are
+    /// matched to their requests, and any other message is set aside for
+    /// the main loop.
+    async fn route_with_client<F: Future>(
+        &self,
+        transport: &mut dyn Transport,
+        connection: &mut Connection,
+        routing: F,
+    ) -> F::Output {
+        let Some(client) = connection.client.clone() else {
+            return routing.await;
+        };
+        tokio::pin!(routing);
+        let mut receiving = true;
+        loop {
+            tokio::select! {
+                output = &mut routing => return output,
+                Some(message) = connection.outgoing.recv() => {
+                    self.send_notification(transport, connection.sequencer.as_ref(), &message)
+                        .await;
+                }
+                res = transport.receive(), if receiving => match res {
+                    Ok(Some(message)) => {
+                        self.wire_tracer.trace(WireDirection::Inbound, &message);
+                        let answered =
+                            serde_json::from_slice::<serde_json::Value>(&message.payload)
+                                .is_ok_and(|value| client.complete(&value));
+                        if !answered {
+                            connection.deferred.push_back(message);
+                        }
+                    }
+                    Ok(None) => sleep(Duration::from_millis(5)).await,
+                    Err(e) => {
+                        // Waiting requests time out; the main loop deals with the transport
+                        tracing::warn!(
+                            error = %e,
+                            "Transport receive failed while a handler awaited the client"
+                        );
+                        receiving = false;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Handle one inbound message, replying over `transport`
+    ///
+    /// Handlers reach the client through `connection`. On duplex transports
+    /// what they queue is sent as they go; otherwise it is sent ahead of the
+    /// response, since the transport is busy until then.
     async fn handle_transport_message(
         &self,
         transport: &mut dyn Transport,
         message: TransportMessage,
+        connection: &mut Connection,
     ) -> ServerResult<()> {
         // Convert bytes to str
         let json_str = match std::str::from_utf8(&message.payload) {
@@ -439,16 +1118,59 @@ impl McpServer {
             }
         };
 
-        // Parse JSON-RPC
-        let parsed = serde_json::from_str::<JsonRpcMessage>(json_str);
+        // Parse JSON-RPC, rejecting malformed envelopes before middleware
+        // and routing see them
+        let mut rejected = Vec::new();
+        let mut is_batch = false;
+        let parsed = match serde_json::from_str::<serde_json::Value>(json_str) {
+            Ok(serde_json::Value::Array(items)) => {
+                // `[]` is not a batch, so its error goes back on its own
+                is_batch = !items.is_empty();
+                let (valid, invalid) = envelope::screen_batch(items);
+                rejected = invalid;
+                if valid.is_empty() {
+                    Ok(None)
+                } else {
+                    serde_json::from_value(serde_json::Value::Array(valid)).map(Some)
+                }
+            }
+            Ok(single) => match envelope::validate_envelope(&single) {
+                Ok(()) => serde_json::from_value(single).map(Some),
+                Err(e) => {
+                    tracing::debug!(error = %e, "Rejected malformed JSON-RPC envelope");
+                    rejected.push(e.to_response());
+                    Ok(None)
+                }
+            },
+            Err(e) => Err(e),
+        };
         let response_json = match parsed {
-            Ok(JsonRpcMessage::Request(req)) => {
-                let ctx = RequestContext::new().with_metadata("transport", "stdio");
+            Ok(Some(JsonRpcMessage::Request(req))) => {
+                let started = Instant::now();
+                self.record_metric(|sink| sink.request_started());
+                let ctx = connection.attach(transport_context(
+                    transport.transport_type(),
+                    &message.metadata,
+                ));
+                let record = (self.request_logger.is_some() || self.session_history.is_some())
+                    .then(|| RequestRecord::begin(&req, &ctx));
+                let arguments = self
+                    .session_history
+                    .as_ref()
+                    .and_then(|history| history.preview(req.params.as_ref()));
                 // Process through middleware stack before routing
-                let (req, ctx) = match self.middleware.read().await.process_request(req, ctx).await
+                let flow = match self
+                    .middleware
+                    .read()
+                    .await
+                    .process_request_flow(req, ctx)
+                    .await
                 {
-                    Ok(tuple) => tuple,
+                    Ok(flow) => flow,
                     Err(e) => {
+                        self.record_metric(|sink| {
+                            sink.request_failed(middleware_error_type(&e), started.elapsed());
+                        });
                         // Convert middleware error to JSON-RPC error response
                         let error = turbomcp_protocol::jsonrpc::JsonRpcError {
                             code: e.error_code(),
@@ -461,6 +1183,7 @@ impl McpServer {
                             result: None,
                             error: Some(error),
                         };
+                        self.log_request(record, arguments, &response, started);
                         let reply = TransportMessage::with_metadata(
                             message.id,
                             Bytes::from(
@@ -469,47 +1192,81 @@ impl McpServer {
                             ),
                             TransportMessageMetadata::with_content_type("application/json"),
                         );
+                        self.wire_tracer.trace(WireDirection::Outbound, &reply);
                         let _ = transport.send(reply).await;
                         return Ok(());
                     }
                 };
-                // Process request through middleware
-                let (processed_req, updated_ctx) = match self
-                    .middleware
-                    .read()
-                    .await
-                    .process_request(req, ctx.clone())
-                    .await
-                {
-                    Ok(r) => r,
-                    Err(e) => {
-                        // Return error response for middleware rejection
-                        let error_response = turbomcp_protocol::jsonrpc::JsonRpcResponse {
-                            jsonrpc: turbomcp_protocol::jsonrpc::JsonRpcVersion,
-                            id: None,
-                            result: None,
-                            error: Some(turbomcp_protocol::jsonrpc::JsonRpcError {
-                                code: -32603,
-                                message: format!("Middleware error: {e}"),
-                                data: None,
-                            }),
+                let (mut resp, updated_ctx) = match flow {
+                    // A middleware answered; the handler never runs
+                    RequestFlow::Respond(response, ctx) => (response, ctx),
+                    RequestFlow::Continue(req, ctx) => {
+                        // Process request through middleware
+                        let (processed_req, updated_ctx) = match self
+                            .middleware
+                            .read()
+                            .await
+                            .process_request(req, ctx.clone())
+                            .await
+                        {
+                            Ok(r) => r,
+                            Err(e) => {
+                                self.record_metric(|sink| {
+                                    sink.request_failed(
+                                        middleware_error_type(&e),
+                                        started.elapsed(),
+                                    );
+                                });
+                                // Return error response for middleware rejection
+                                let error_response = turbomcp_protocol::jsonrpc::JsonRpcResponse {
+                                    jsonrpc: turbomcp_protocol::jsonrpc::JsonRpcVersion,
+                                    id: None,
+                                    result: None,
+                                    error: Some(turbomcp_protocol::jsonrpc::JsonRpcError {
+                                        code: -32603,
+                                        message: format!("Middleware error: {e}"),
+                                        data: None,
+                                    }),
+                                };
+                                self.log_request(record, arguments, &error_response, started);
+                                let mut reply = TransportMessage::new(
+                                    turbomcp_core::MessageId::from("error"),
+                                    Bytes::from(
+                                        serde_json::to_string(&error_response)
+                                            .unwrap_or_else(|_| "{}".to_string()),
+                                    ),
+                                );
+                                reply.metadata =
+                                    TransportMessageMetadata::with_content_type("application/json");
+                                self.wire_tracer.trace(WireDirection::Outbound, &reply);
+                                let _ = transport.send(reply).await;
+                                return Ok(());
+                            }
                         };
-                        let mut reply = TransportMessage::new(
-                            turbomcp_core::MessageId::from("error"),
-                            Bytes::from(
-                                serde_json::to_string(&error_response)
-                                    .unwrap_or_else(|_| "{}".to_string()),
-                            ),
-                        );
-                        reply.metadata =
-                            TransportMessageMetadata::with_content_type("application/json");
-                        let _ = transport.send(reply).await;
-                        return Ok(());
+
+                        // Per-tool latency is only tracked for registered tools, so
+                        // clients cannot grow the histogram map with arbitrary names
+                        let called_tool = (processed_req.method == methods::CALL_TOOL)
+                            .then(|| processed_req.params.as_ref()?.get("name")?.as_str())
+                            .flatten()
+                            .filter(|name| self.registry.get_tool(name).is_some())
+                            .map(str::to_string);
+                        let routed = Instant::now();
+                        let routing = self.router.route(processed_req, updated_ctx.clone());
+                        let resp: JsonRpcResponse =
+                            self.route_with_client(transport, connection, routing).await;
+                        if let Some(tool) = called_tool {
+                            let elapsed = routed.elapsed();
+                            self.record_metric(|sink| {
+                                sink.tool_called(&tool, resp.error.is_none(), elapsed);
+                            });
+                            if let Some(state) = self.router.circuit_breakers().state(&tool) {
+                                self.record_metric(|sink| sink.tool_circuit_state(&tool, &state));
+                            }
+                        }
+                        (resp, updated_ctx)
                     }
                 };
-
-                let mut resp: JsonRpcResponse =
-                    self.router.route(processed_req, updated_ctx.clone()).await;
                 // Process response through middleware
                 resp = match self
                     .middleware
@@ -531,31 +1288,90 @@ impl McpServer {
                     },
                 };
 
+                let elapsed = started.elapsed();
+                if resp.error.is_some() {
+                    self.record_metric(|sink| sink.request_failed("handler", elapsed));
+                } else {
+                    self.record_metric(|sink| sink.request_succeeded(elapsed));
+                }
+                let record = record.map(|record| record.with_context(&updated_ctx));
+                self.log_request(record, arguments, &resp, started);
+
                 serde_json::to_string(&resp).ok()
             }
-            Ok(JsonRpcMessage::RequestBatch(batch)) => {
+            Ok(Some(JsonRpcMessage::RequestBatch(batch))) => {
+                let started = Instant::now();
                 // Convert batch to Vec<JsonRpcRequest>
                 let requests: Vec<JsonRpcRequest> = batch.items;
-                let ctx = RequestContext::new().with_metadata("transport", "stdio");
+                let ctx = connection.attach(transport_context(
+                    transport.transport_type(),
+                    &message.metadata,
+                ));
+                // Each item is logged as a request of its own, answered with the batch
+                let logged = self.request_logger.is_some() || self.session_history.is_some();
+                let mut records: Vec<_> = requests
+                    .iter()
+                    .filter(|_| logged)
+                    .map(|req| {
+                        let arguments = self
+                            .session_history
+                            .as_ref()
+                            .and_then(|history| history.preview(req.params.as_ref()));
+                        (req.id.clone(), RequestRecord::begin(req, &ctx), arguments)
+                    })
+                    .collect();
                 // Process each request through middleware by reusing the router’s batch processing
-                let responses = self.router.route_batch(requests, ctx).await;
+                let routing = self.router.route_batch(requests, ctx);
+                let mut responses = self.route_with_client(transport, connection, routing).await;
+                // Responses arrive in completion order, so pair them up by id
+                for response in &responses {
+                    let Some(at) = records
+                        .iter()
+                        .position(|(id, ..)| response.id.as_ref() == Some(id))
+                    else {
+                        continue;
+                    };
+                    let (_, record, arguments) = records.swap_remove(at);
+                    self.log_request(Some(record), arguments, response, started);
+                }
+                responses.append(&mut rejected);
                 serde_json::to_string(&responses).ok()
             }
-            Ok(JsonRpcMessage::Notification(_note)) => {
+            Ok(Some(JsonRpcMessage::Notification(note))) => {
+                if !methods::CLIENT_NOTIFICATIONS.contains(&note.method.as_str()) {
+                    self.unknown_notifications.apply(&note);
+                }
                 // No response for notifications
                 None
             }
-            // Ignore responses from client (server-initiated only)
-            Ok(
-                JsonRpcMessage::Response(_)
-                | JsonRpcMessage::ResponseBatch(_)
-                | JsonRpcMessage::MessageBatch(_),
-            ) => None,
+            // Answers to requests from work that outlived its own request
+            Ok(Some(JsonRpcMessage::Response(resp))) => {
+                let answered = connection.client.as_ref().is_some_and(|client| {
+                    serde_json::to_value(&resp).is_ok_and(|value| client.complete(&value))
+                });
+                if !answered {
+                    tracing::debug!(id = ?resp.id, "Ignoring response to no pending request");
+                }
+                None
+            }
+            Ok(Some(JsonRpcMessage::ResponseBatch(_) | JsonRpcMessage::MessageBatch(_))) => None,
+            Ok(None) => None,
             Err(e) => {
                 tracing::warn!(error = %e, "Failed to parse JSON-RPC message");
                 None
             }
         };
+        // Answer rejected envelopes that no batch response already carries
+        let response_json = match rejected.as_slice() {
+            [] => response_json,
+            [single] if !is_batch => serde_json::to_string(single).ok(),
+            all => serde_json::to_string(all).ok(),
+        };
+
+        while let Ok(notification) = connection.outgoing.try_recv() {
+            self.send_notification(transport, connection.sequencer.as_ref(), &notification)
+                .await;
+        }
 
         if let Some(resp_str) = response_json {
             let reply = TransportMessage::with_metadata(
@@ -563,6 +1379,7 @@ impl McpServer {
                 Bytes::from(resp_str),
                 TransportMessageMetadata::with_content_type("application/json"),
             );
+            self.wire_tracer.trace(WireDirection::Outbound, &reply);
             if let Err(e) = transport.send(reply).await {
                 tracing::warn!(error = %e, "Failed to send response over transport");
             }
@@ -572,12 +1389,111 @@ impl McpServer {
     }
 }
 
+impl McpServer {
+    /// Add a request the server has answered to the request log and the
+    /// session's history
+    fn log_request(
+        &self,
+        record: Option<RequestRecord>,
+        arguments: Option<String>,
+        response: &JsonRpcResponse,
+        started: Instant,
+    ) {
+        let Some(record) = record.map(|record| record.finish(response, started.elapsed())) else {
+            return;
+        };
+        if let Some(history) = &self.session_history {
+            history.record(&record, arguments);
+        }
+        if let Some(logger) = &self.request_logger {
+            logger.log(record);
+        }
+    }
+
+    /// Send a notification or request a handler queued for the client
+    ///
+    /// With a `sequencer`, notifications are stamped with the session's next
+    /// sequence number first; requests, which carry an id, are sent as they
+    /// are.
+    async fn send_notification(
+        &self,
+        transport: &mut dyn Transport,
+        sequencer: Option<&NotificationSequencer>,
+        notification: &serde_json::Value,
+    ) {
+        let payload = match sequencer {
+            Some(sequencer) if notification.get("id").is_none() => {
+                serde_json::from_value::<JsonRpcNotification>(notification.clone())
+                    .ok()
+                    .and_then(|mut notification| {
+                        sequencer.stamp(&mut notification);
+                        serde_json::to_string(&notification).ok()
+                    })
+                    .unwrap_or_else(|| notification.to_string())
+            }
+            _ => notification.to_string(),
+        };
+        let message = TransportMessage::with_metadata(
+            MessageId::Uuid(uuid::Uuid::new_v4()),
+            Bytes::from(payload),
+            TransportMessageMetadata::with_content_type("application/json"),
+        );
+        self.wire_tracer.trace(WireDirection::Outbound, &message);
+        if let Err(e) = transport.send(message).await {
+            tracing::warn!(error = %e, "Failed to send notification over transport");
+        }
+    }
+}
+
+/// Run the self-check and turn a failing report into a configuration error
+fn validate_registry(registry: &HandlerRegistry) -> ServerResult<()> {
+    let report = check_registry(registry);
+    if report.is_ok() {
+        Ok(())
+    } else {
+        Err(crate::ServerError::configuration(format!(
+            "Server self-check failed: {report}"
+        )))
+    }
+}
+
+/// Metrics error category for a request rejected by middleware
+const fn middleware_error_type(error: &crate::ServerError) -> &'static str {
+    match error {
+        crate::ServerError::Authentication { .. } | crate::ServerError::Authorization { .. } => {
+            "auth"
+        }
+        crate::ServerError::Timeout { .. } => "timeout",
+        _ => "middleware",
+    }
+}
+
 /// Server builder for convenient server construction
 pub struct ServerBuilder {
     /// Server configuration
     config: ServerConfig,
     /// Registry builder
-    registry: HandlerRegistry,
+    registry: Arc<HandlerRegistry>,
+    /// Router holding custom method routes
+    router: RequestRouter,
+    /// Hook applying reloaded log levels
+    log_level_hook: Option<LogLevelHook>,
+    /// Wire tracer overriding the environment configuration
+    wire_tracer: Option<WireTracer>,
+    /// Policy for unrecognized notifications
+    unknown_notifications: UnknownNotificationPolicy,
+    /// Metrics shared with the built server and any admin tools
+    metrics: Arc<ServerMetrics>,
+    /// Additional destinations for the server's measurements
+    metrics_sinks: Vec<Arc<dyn MetricsSink>>,
+    /// Durable per-request log
+    request_logger: Option<RequestLogger>,
+    /// Per-session request history
+    session_history: Option<SessionHistory>,
+    /// Middleware added alongside the configured built-ins
+    middleware: Vec<Arc<dyn Middleware>>,
+    /// Tasks gating readiness
+    startup_tasks: Vec<StartupTask>,
 }
 
 impl std::fmt::Debug for ServerBuilder {
@@ -592,9 +1508,20 @@ impl ServerBuilder {
     /// Create a new server builder
     #[must_use]
     pub fn new() -> Self {
+        let registry = Arc::new(HandlerRegistry::new());
         Self {
             config: ServerConfig::default(),
-            registry: HandlerRegistry::new(),
+            router: RequestRouter::new(Arc::clone(&registry)),
+            registry,
+            log_level_hook: None,
+            wire_tracer: None,
+            unknown_notifications: UnknownNotificationPolicy::default(),
+            metrics: Arc::new(ServerMetrics::new()),
+            metrics_sinks: Vec::new(),
+            request_logger: None,
+            session_history: None,
+            middleware: Vec::new(),
+            startup_tasks: Vec::new(),
         }
     }
 
@@ -616,6 +1543,262 @@ impl ServerBuilder {
         self
     }
 
+    /// Set the configuration file re-read on reload (and on SIGHUP, on Unix)
+    pub fn config_file(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.config.reload.config_file = Some(path.into());
+        self
+    }
+
+    /// Set a hook that applies reloaded log levels
+    ///
+    /// The server does not own the tracing subscriber, so applications that
+    /// want `logging.level` to be hot-reloadable install a reloadable filter
+    /// and update it from this hook.
+    pub fn on_log_level_change<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str) -> ServerResult<()> + Send + Sync + 'static,
+    {
+        self.log_level_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Log every inbound and outbound wire message with this tracer
+    ///
+    /// Overrides the `TURBOMCP_WIRE_TRACE` environment configuration. Messages
+    /// are logged at `TRACE` level under the `turbomcp::wire` target; see
+    /// [`turbomcp_transport::wire_trace`] for redaction of sensitive fields.
+    pub fn with_wire_trace(mut self, tracer: WireTracer) -> Self {
+        self.wire_tracer = Some(tracer);
+        self
+    }
+
+    /// Record every answered request through `logger`
+    ///
+    /// Records carry the method, tool, duration, status and the client,
+    /// correlation and session ids; see [`crate::request_log`]. Use
+    /// [`RequestLogger::json_lines`] for a JSON-lines file.
+    #[must_use]
+    pub fn with_request_logger(mut self, logger: RequestLogger) -> Self {
+        self.request_logger = Some(logger);
+        self
+    }
+
+    /// Keep the recent requests of each live session in `history`
+    ///
+    /// Read them through [`McpServer::session_history`] while debugging;
+    /// see [`crate::history`] for what is kept and redacted. Use
+    /// [`SessionHistory::new`] with the number of requests to remember.
+    #[must_use]
+    pub fn with_session_history(mut self, history: SessionHistory) -> Self {
+        self.session_history = Some(history);
+        self
+    }
+
+    /// Run `middleware` on every request, ordered by its priority
+    ///
+    /// Besides inspecting and rejecting requests, middleware can answer them
+    /// itself through [`Middleware::intercept`].
+    #[must_use]
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Set what happens to notifications the server does not recognize
+    ///
+    /// Defaults to [`UnknownNotificationPolicy::Ignore`], as JSON-RPC requires
+    /// for forward compatibility. Use [`UnknownNotificationPolicy::Log`] while
+    /// debugging a client that sends newer notifications.
+    pub fn with_unknown_notification_policy(mut self, policy: UnknownNotificationPolicy) -> Self {
+        self.unknown_notifications = policy;
+        self
+    }
+
+    /// Pass notifications the server does not recognize to a catch-all handler
+    pub fn on_unknown_notification<F>(self, handler: F) -> Self
+    where
+        F: Fn(&JsonRpcNotification) + Send + Sync + 'static,
+    {
+        self.with_unknown_notification_policy(UnknownNotificationPolicy::handler(handler))
+    }
+
+    /// Stamp each session's notifications with consecutive sequence numbers
+    ///
+    /// The number goes under
+    /// [`SEQUENCE_META_KEY`](turbomcp_protocol::SEQUENCE_META_KEY) in
+    /// `params._meta`, so a client can restore send order over transports
+    /// that may reorder messages, and tell a late notification from a lost
+    /// one. Requests the server sends the client are not numbered.
+    #[must_use]
+    pub const fn with_notification_sequencing(mut self) -> Self {
+        self.config.sequence_notifications = true;
+        self
+    }
+
+    /// Set the maximum time shutdown may take
+    ///
+    /// Once shutdown is triggered, a handler still running after this timeout
+    /// is abandoned and the run method returns. Defaults to 30 seconds.
+    #[must_use]
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeouts.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Limit the memory each tool call may use to `bytes`
+    ///
+    /// Handlers report allocations through
+    /// [`RequestContext::charge_memory`](turbomcp_core::RequestContext::charge_memory);
+    /// a call going over the budget, or returning a result that serializes to
+    /// more than it, fails with a resource-exhausted error. Combine with
+    /// per-tool output limits to truncate large results instead.
+    #[must_use]
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self {
+        self.config.memory_budget = Some(bytes);
+        self
+    }
+
+    /// Set how tool handler errors are reported by default
+    ///
+    /// [`ToolErrorMode::Result`] turns a handler's `Err` into a tool result
+    /// flagged `isError`, which hosts show to the model instead of aborting
+    /// the turn. Individual tools can override this through the handler or
+    /// `tool_errors.tools` in the configuration.
+    #[must_use]
+    pub fn with_tool_error_mode(mut self, mode: ToolErrorMode) -> Self {
+        self.config.tool_errors.mode = mode;
+        self
+    }
+
+    /// Set how tools are pre-warmed before the server accepts requests
+    ///
+    /// By default up to four tools warm at once and any failure stops the
+    /// server from starting.
+    #[must_use]
+    pub fn with_prewarm(mut self, prewarm: PrewarmConfig) -> Self {
+        self.config.prewarm = prewarm;
+        self
+    }
+
+    /// Register an async task that must complete before the server is ready
+    ///
+    /// Tasks run concurrently at startup, after tools are pre-warmed, and
+    /// the server reads no request until all have completed. A task that
+    /// fails, or outlasts the startup timeout, stops the server from
+    /// starting. See [`startup`](crate::startup).
+    #[must_use]
+    pub fn with_startup_task<F, Fut>(mut self, name: impl Into<String>, task: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ServerResult<()>> + Send + 'static,
+    {
+        self.startup_tasks.push(StartupTask::new(name, task));
+        self
+    }
+
+    /// Set how long startup tasks may take before the server gives up
+    ///
+    /// Defaults to 60 seconds.
+    #[must_use]
+    pub fn with_startup_timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeouts.startup_timeout = timeout;
+        self
+    }
+
+    /// Read the time from `clock` instead of the system clock
+    ///
+    /// Tool result caches expire and rate limits refill by this clock, so
+    /// tests can move time forward with a
+    /// [`MockClock`](turbomcp_core::MockClock) instead of sleeping. Timeouts
+    /// on requests, startup and shutdown still run on tokio's timer.
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.router.set_clock(clock);
+        self
+    }
+
+    /// Keep finished resumable operations for `retention`
+    ///
+    /// Clients re-attaching within it can still collect the result; see
+    /// [`crate::operations`].
+    #[must_use]
+    pub fn with_operation_retention(self, retention: Duration) -> Self {
+        self.router.set_operation_retention(retention);
+        self
+    }
+
+    /// Decide each session's capabilities when it initializes
+    ///
+    /// `hook` receives the client's initialize request, the request context
+    /// and the capabilities the server would offer by default, and returns
+    /// the capabilities for that session. Methods outside them are refused
+    /// for the rest of the session.
+    #[must_use]
+    pub fn with_session_capabilities<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&InitializeRequest, &RequestContext, ServerCapabilities) -> ServerCapabilities
+            + Send
+            + Sync
+            + 'static,
+    {
+        let hook: CapabilityHook = Arc::new(hook);
+        self.router.set_capability_hook(hook);
+        self
+    }
+
+    /// Add a key to the metadata sent to clients in the initialize result
+    ///
+    /// Use this for deployment details clients may act on, such as region or
+    /// enabled features. Metadata the client sends back is available to
+    /// handlers under [`CLIENT_META_KEY`](crate::routing::CLIENT_META_KEY) in
+    /// the request context. Fails if the metadata would exceed
+    /// [`MAX_INITIALIZE_META_SIZE`](turbomcp_protocol::types::MAX_INITIALIZE_META_SIZE)
+    /// bytes when serialized.
+    pub fn with_session_meta(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> ServerResult<Self> {
+        self.router.insert_server_meta(key, value.into())?;
+        Ok(self)
+    }
+
+    /// Expose the server metrics through built-in admin tools
+    ///
+    /// Registers [`METRICS_TOOL`](crate::metrics::METRICS_TOOL), which returns
+    /// a [`MetricsSnapshot`](crate::metrics::MetricsSnapshot) including
+    /// per-tool latency histograms, and
+    /// [`METRICS_RESET_TOOL`](crate::metrics::METRICS_RESET_TOOL), which zeroes
+    /// the counters. Neither is exposed unless this is called. Only callers
+    /// whose `auth.roles` context metadata contains one of `roles` may use
+    /// them, so at least one role is required.
+    pub fn with_metrics_tools<S: Into<String>>(
+        self,
+        roles: impl IntoIterator<Item = S>,
+    ) -> ServerResult<Self> {
+        let roles: Vec<String> = roles.into_iter().map(Into::into).collect();
+        if roles.is_empty() {
+            return Err(crate::ServerError::configuration(
+                "Metrics tools require at least one authorized role",
+            ));
+        }
+        for (name, handler) in crate::metrics::admin_tools(&self.metrics, &roles) {
+            self.registry.register_tool(name, handler)?;
+        }
+        Ok(self)
+    }
+
+    /// Report the server's measurements to `sink` as well
+    ///
+    /// The built-in [`ServerMetrics`] keep recording, so [`McpServer::metrics`]
+    /// and the metrics tools work as before; sinks receive the same events as
+    /// they happen. Call this once per sink.
+    #[must_use]
+    pub fn with_metrics_sink<M: MetricsSink + 'static>(mut self, sink: M) -> Self {
+        self.metrics_sinks.push(Arc::new(sink));
+        self
+    }
+
     /// Add a tool handler
     pub fn tool<T>(self, name: impl Into<String>, handler: T) -> ServerResult<Self>
     where
@@ -643,12 +1826,90 @@ impl ServerBuilder {
         Ok(self)
     }
 
+    /// Add a handler completing a prompt or resource argument
+    ///
+    /// The server advertises the `completions` capability once any are added.
+    pub fn completion<C>(self, handler: C) -> ServerResult<Self>
+    where
+        C: CompletionHandler + 'static,
+    {
+        self.registry.register_completion(handler)?;
+        Ok(self)
+    }
+
+    /// Add a resource whose content is produced as a stream of chunks
+    ///
+    /// Shorthand for registering the handler wrapped in a
+    /// [`StreamingResource`](crate::handlers::StreamingResource).
+    pub fn streaming_resource<H>(self, name: impl Into<String>, handler: H) -> ServerResult<Self>
+    where
+        H: StreamingResourceHandler + 'static,
+    {
+        self.resource(name, StreamingResource::new(handler))
+    }
+
+    /// Add a handler for a custom JSON-RPC method
+    ///
+    /// Lets servers implement protocol extensions and vendor-specific methods
+    /// (conventionally prefixed, e.g. `x/custom`). The handler receives the
+    /// request params and returns the response `result`. Custom methods pass
+    /// through middleware and metrics like standard ones; registering a
+    /// standard MCP method or the same method twice is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use turbomcp_server::ServerBuilder;
+    /// # fn main() -> turbomcp_server::ServerResult<()> {
+    /// let server = ServerBuilder::new()
+    ///     .with_method("x/echo", |params, _ctx| async move {
+    ///         Ok(params.unwrap_or_default())
+    ///     })?
+    ///     .build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_method<F, Fut>(
+        mut self,
+        method: impl Into<String>,
+        handler: F,
+    ) -> ServerResult<Self>
+    where
+        F: Fn(Option<serde_json::Value>, RequestContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ServerResult<serde_json::Value>> + Send + 'static,
+    {
+        self.router
+            .add_route(FunctionRouteHandler::new(method, handler))?;
+        Ok(self)
+    }
+
+    /// Run the startup self-check against the handlers registered so far
+    ///
+    /// See [`McpServer::validate`].
+    pub fn validate(&self) -> ServerResult<()> {
+        validate_registry(&self.registry)
+    }
+
     /// Build the server
     #[must_use]
     pub fn build(self) -> McpServer {
-        let mut server = McpServer::new(self.config);
-        server.registry = Arc::new(self.registry);
-        server.router = Arc::new(RequestRouter::new(Arc::clone(&server.registry)));
+        let mut server = McpServer::with_router(self.config, self.registry, Arc::new(self.router));
+        server.metrics = self.metrics;
+        server.metrics_sinks = self.metrics_sinks;
+        server.log_level_hook = self.log_level_hook;
+        if let Some(tracer) = self.wire_tracer {
+            server.wire_tracer = tracer;
+        }
+        server.unknown_notifications = self.unknown_notifications;
+        server.request_logger = self.request_logger;
+        server.session_history = self.session_history;
+        server.startup_tasks = self.startup_tasks;
+        if let Ok(mut stack) = server.middleware.try_write() {
+            // Nothing else holds the new server's stack yet
+            for middleware in self.middleware {
+                stack.add_shared(middleware);
+            }
+        }
         server
     }
 }
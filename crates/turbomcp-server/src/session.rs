@@ -0,0 +1,189 @@
+//! Per-session server capabilities
+//!
+//! By default every client is offered the same capabilities, derived from
+//! what the server has registered. A [`CapabilityHook`] lets the server
+//! decide per session instead: at initialize time it sees the client's
+//! initialize request and the request context (including the user, roles
+//! and claims recorded by authentication middleware, if it is configured to
+//! authenticate `initialize`) and returns the capabilities to offer, so
+//! trusted or premium clients can be given more than others.
+//!
+//! What the hook returns is both advertised and enforced. For the rest of
+//! the session, a method whose capability was withheld, such as `tools/call`
+//! when `tools` is absent, is refused with an authorization error. Sessions
+//! are keyed by [`RequestContext::session_id`]; the server gives each
+//! transport connection its own, and requests without one are not gated.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::types::{InitializeRequest, ServerCapabilities};
+
+use crate::{ServerError, ServerResult};
+
+/// Computes a session's capabilities from its initialize request
+///
+/// Receives the request, its context and the capabilities the server would
+/// offer by default, and returns the capabilities to offer this session.
+pub type CapabilityHook = Arc<
+    dyn Fn(&InitializeRequest, &RequestContext, ServerCapabilities) -> ServerCapabilities
+        + Send
+        + Sync,
+>;
+
+/// Capabilities negotiated by each session, when a hook decides them
+///
+/// Clones share the negotiated sessions.
+#[derive(Clone, Default)]
+pub struct SessionCapabilities {
+    /// Hook deciding each session's capabilities
+    hook: Option<CapabilityHook>,
+    /// Capabilities granted to each initialized session, by session id
+    negotiated: Arc<DashMap<String, ServerCapabilities>>,
+}
+
+impl std::fmt::Debug for SessionCapabilities {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionCapabilities")
+            .field("hook", &self.hook.is_some())
+            .field("sessions", &self.negotiated.len())
+            .finish()
+    }
+}
+
+impl SessionCapabilities {
+    /// Offer every session the default capabilities
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decide each session's capabilities with `hook`
+    pub fn set_hook(&mut self, hook: CapabilityHook) {
+        self.hook = Some(hook);
+    }
+
+    /// Capabilities to offer the session initializing with `request`
+    ///
+    /// Without a hook these are `defaults`, and nothing is gated. With one,
+    /// the hook's answer is remembered for the session and enforced by
+    /// [`check`](Self::check).
+    pub fn negotiate(
+        &self,
+        request: &InitializeRequest,
+        ctx: &RequestContext,
+        defaults: ServerCapabilities,
+    ) -> ServerCapabilities {
+        let Some(hook) = &self.hook else {
+            return defaults;
+        };
+        let capabilities = hook(request, ctx, defaults);
+        if let Some(session_id) = &ctx.session_id {
+            self.negotiated
+                .insert(session_id.clone(), capabilities.clone());
+        }
+        capabilities
+    }
+
+    /// Capabilities negotiated by `session_id`, if a hook decided them
+    #[must_use]
+    pub fn get(&self, session_id: &str) -> Option<ServerCapabilities> {
+        self.negotiated
+            .get(session_id)
+            .map(|entry| entry.value().clone())
+    }
+
+    /// Forget a session that has ended
+    pub fn end_session(&self, session_id: &str) {
+        self.negotiated.remove(session_id);
+    }
+
+    /// Refuse `method` if the request's session was not granted it
+    pub fn check(&self, ctx: &RequestContext, method: &str) -> ServerResult<()> {
+        let Some(capabilities) = ctx
+            .session_id
+            .as_ref()
+            .and_then(|session_id| self.negotiated.get(session_id))
+        else {
+            return Ok(());
+        };
+        if method_permitted(&capabilities, method) {
+            Ok(())
+        } else {
+            Err(ServerError::authorization_with_resource(
+                format!("Method '{method}' is not available in this session"),
+                method,
+            ))
+        }
+    }
+}
+
+/// A value each session keeps for itself, such as what its client sent at
+/// initialize
+///
+/// Keyed by [`RequestContext::session_id`]; requests without one, such as
+/// those routed directly in process, share a single value. Clones share the
+/// values.
+pub(crate) struct SessionValues<T> {
+    values: Arc<DashMap<Option<String>, T>>,
+}
+
+impl<T> Clone for SessionValues<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: Arc::clone(&self.values),
+        }
+    }
+}
+
+impl<T> Default for SessionValues<T> {
+    fn default() -> Self {
+        Self {
+            values: Arc::new(DashMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> SessionValues<T> {
+    /// The value of the session `ctx` belongs to, if it set one
+    pub(crate) fn get(&self, ctx: &RequestContext) -> Option<T> {
+        self.values
+            .get(&ctx.session_id)
+            .map(|entry| entry.value().clone())
+    }
+
+    /// Set the value of the session `ctx` belongs to, or clear it with `None`
+    pub(crate) fn set(&self, ctx: &RequestContext, value: Option<T>) {
+        match value {
+            Some(value) => {
+                self.values.insert(ctx.session_id.clone(), value);
+            }
+            None => {
+                self.values.remove(&ctx.session_id);
+            }
+        }
+    }
+
+    /// Forget the value of a session that has ended
+    pub(crate) fn end_session(&self, session_id: &str) {
+        self.values.remove(&Some(session_id.to_string()));
+    }
+}
+
+/// Whether `capabilities` include the one `method` belongs to
+///
+/// Methods are grouped by their prefix (`tools/call` needs `tools`);
+/// methods outside the capability groups are always permitted.
+#[must_use]
+pub fn method_permitted(capabilities: &ServerCapabilities, method: &str) -> bool {
+    let group = method.split_once('/').map_or(method, |(group, _)| group);
+    match group {
+        "tools" => capabilities.tools.is_some(),
+        "prompts" => capabilities.prompts.is_some(),
+        "resources" => capabilities.resources.is_some(),
+        "logging" => capabilities.logging.is_some(),
+        "completion" => capabilities.completions.is_some(),
+        _ => true,
+    }
+}
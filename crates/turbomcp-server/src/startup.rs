@@ -0,0 +1,129 @@
+//! Async startup tasks that gate readiness
+//!
+//! Some setup has to finish before a server can answer usefully, such as
+//! connecting to a database or fetching configuration from a remote store.
+//! Tasks registered with
+//! [`ServerBuilder::with_startup_task`](crate::ServerBuilder::with_startup_task)
+//! run concurrently once tools are pre-warmed, and the server only reports
+//! itself ready, and only reads its first request, after all of them have
+//! completed. Each task's start and completion is logged with a running
+//! count. A task that fails, or is still running when
+//! `config.timeouts.startup_timeout` elapses, stops the server from starting
+//! with an error naming it.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::future::try_join_all;
+use tokio::sync::watch;
+use tokio::time::{Duration, Instant, timeout_at};
+
+use crate::error::{ServerError, ServerResult};
+
+type StartupFuture = Pin<Box<dyn Future<Output = ServerResult<()>> + Send>>;
+
+/// A named task run before the server becomes ready
+#[derive(Clone)]
+pub(crate) struct StartupTask {
+    name: String,
+    run: Arc<dyn Fn() -> StartupFuture + Send + Sync>,
+}
+
+impl StartupTask {
+    pub(crate) fn new<F, Fut>(name: impl Into<String>, task: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ServerResult<()>> + Send + 'static,
+    {
+        Self {
+            name: name.into(),
+            run: Arc::new(move || Box::pin(task()) as StartupFuture),
+        }
+    }
+}
+
+impl fmt::Debug for StartupTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StartupTask")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether a server has finished starting up
+///
+/// Cloned handles share the state, so a health endpoint can hold one and
+/// answer readiness probes while the server starts.
+#[derive(Debug, Clone)]
+pub struct Readiness {
+    ready: Arc<watch::Sender<bool>>,
+}
+
+impl Default for Readiness {
+    fn default() -> Self {
+        Self {
+            ready: Arc::new(watch::Sender::new(false)),
+        }
+    }
+}
+
+impl Readiness {
+    /// True once every startup task has completed
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        *self.ready.borrow()
+    }
+
+    /// Wait until the server is ready
+    pub async fn wait(&self) {
+        let mut ready = self.ready.subscribe();
+        // The sender lives as long as `self`, so this cannot fail
+        let _ = ready.wait_for(|&ready| ready).await;
+    }
+
+    pub(crate) fn mark_ready(&self) {
+        self.ready.send_replace(true);
+    }
+}
+
+/// Run every task concurrently, failing on the first error or at `timeout`
+pub(crate) async fn run_startup_tasks(
+    tasks: &[StartupTask],
+    timeout: Duration,
+) -> ServerResult<()> {
+    let total = tasks.len();
+    let completed = AtomicUsize::new(0);
+    let deadline = Instant::now() + timeout;
+
+    try_join_all(tasks.iter().map(|task| {
+        let completed = &completed;
+        async move {
+            tracing::info!(task = %task.name, "Startup task started");
+            let started = Instant::now();
+            match timeout_at(deadline, (task.run)()).await {
+                Ok(Ok(())) => {
+                    let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+                    tracing::info!(
+                        task = %task.name,
+                        elapsed_ms = started.elapsed().as_millis() as u64,
+                        "Startup task completed ({done}/{total})"
+                    );
+                    Ok(())
+                }
+                Ok(Err(e)) => Err(ServerError::Lifecycle(format!(
+                    "Startup task '{}' failed: {e}",
+                    task.name
+                ))),
+                Err(_) => Err(ServerError::Lifecycle(format!(
+                    "Startup task '{}' did not complete within {timeout:?}",
+                    task.name
+                ))),
+            }
+        }
+    }))
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! Tests for server uptime and build information
+
+use std::time::Duration;
+use turbomcp_server::{MetricsSnapshot, ServerBuilder};
+
+#[tokio::test]
+async fn test_uptime_increases() {
+    let server = ServerBuilder::new().build();
+    let before = server.uptime();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let after = server.uptime();
+    assert!(
+        after >= before + Duration::from_millis(20),
+        "{before:?} -> {after:?}"
+    );
+}
+
+#[test]
+fn test_build_info_is_populated() {
+    let server = ServerBuilder::new().build();
+    let info = server.build_info();
+
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.protocol_version, turbomcp_protocol::PROTOCOL_VERSION);
+    assert_eq!(
+        info.features.contains(&"metrics"),
+        cfg!(feature = "metrics")
+    );
+    // Only present when built from a git checkout
+    if let Some(hash) = info.git_hash {
+        assert!(!hash.is_empty());
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()), "{hash}");
+    }
+
+    let snapshot = MetricsSnapshot::capture(server.metrics());
+    assert_eq!(snapshot.build, info);
+    assert!(snapshot.metrics.contains_key("uptime_seconds"));
+}
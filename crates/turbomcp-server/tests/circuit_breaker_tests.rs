@@ -0,0 +1,132 @@
+//! Tests for per-tool circuit breakers
+
+mod common;
+
+use common::{text_result, tool_definition};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcResponse, JsonRpcVersion};
+use turbomcp_protocol::types::Tool;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::registry::HandlerRegistry;
+use turbomcp_server::routing::RequestRouter;
+use turbomcp_server::{CircuitBreakerConfig, CircuitState, ServerError};
+
+/// Tool whose downstream is up or down depending on `healthy`
+fn downstream_tool(healthy: Arc<AtomicBool>, calls: Arc<AtomicUsize>) -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some("Look something up downstream".to_string()),
+        ..tool_definition("lookup")
+    };
+    FunctionToolHandler::new(tool, move |_req, _ctx| {
+        let healthy = Arc::clone(&healthy);
+        let calls = Arc::clone(&calls);
+        async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            if !healthy.load(Ordering::SeqCst) {
+                return Err(ServerError::handler("downstream unavailable"));
+            }
+            Ok(text_result("found"))
+        }
+    })
+}
+
+fn breaker_config() -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        failure_threshold: 2,
+        success_threshold: 1,
+        timeout: Duration::from_millis(50),
+        rolling_window_size: 10,
+        minimum_requests: 1,
+    }
+}
+
+async fn call(router: &RequestRouter, id: i64) -> JsonRpcResponse {
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(id),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": "lookup", "arguments": {}})),
+    };
+    router.route(request, RequestContext::new()).await
+}
+
+#[tokio::test]
+async fn test_breaker_trips_and_recovers() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let registry = HandlerRegistry::new();
+    registry
+        .register_tool(
+            "lookup",
+            downstream_tool(Arc::clone(&healthy), Arc::clone(&calls))
+                .with_circuit_breaker(breaker_config()),
+        )
+        .unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+    let breakers = router.circuit_breakers();
+
+    // Two downstream failures trip the breaker
+    for id in 1..=2 {
+        let response = call(&router, id).await;
+        assert_eq!(response.error.unwrap().code, -32002);
+    }
+    assert_eq!(breakers.state("lookup"), Some(CircuitState::Open));
+
+    // While open, calls fail fast without reaching the handler
+    let response = call(&router, 3).await;
+    let error = response.error.unwrap();
+    assert_eq!(error.code, -32010);
+    assert!(error.message.contains("circuit breaker open"));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // After the timeout a successful probe closes it again
+    healthy.store(true, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let response = call(&router, 4).await;
+    assert!(response.error.is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(breakers.state("lookup"), Some(CircuitState::Closed));
+    assert_eq!(
+        breakers.states(),
+        [("lookup".to_string(), CircuitState::Closed)].into()
+    );
+}
+
+#[tokio::test]
+async fn test_configured_breaker_applies_without_handler_opt_in() {
+    let healthy = Arc::new(AtomicBool::new(false));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let registry = HandlerRegistry::new();
+    registry
+        .register_tool(
+            "lookup",
+            downstream_tool(Arc::clone(&healthy), Arc::clone(&calls)),
+        )
+        .unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+
+    // No breaker: every call reaches the failing handler
+    for id in 1..=3 {
+        call(&router, id).await;
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(router.circuit_breakers().state("lookup"), None);
+
+    router
+        .circuit_breakers()
+        .set_config(HashMap::from([("lookup".to_string(), breaker_config())]));
+    for id in 4..=6 {
+        call(&router, id).await;
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+    assert_eq!(
+        router.circuit_breakers().state("lookup"),
+        Some(CircuitState::Open)
+    );
+}
@@ -0,0 +1,100 @@
+//! Tests for handlers sending requests to the client
+
+mod common;
+
+use common::{text_result, tool_definition};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines};
+use tokio::time::timeout;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::types::{Content, CreateMessageResult};
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+/// Tool that asks the client's model to summarize and returns the answer
+fn summarize_tool() -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("summarize"), |_req, ctx| async move {
+        let sampled: CreateMessageResult = ctx
+            .client_request(
+                "sampling/createMessage",
+                json!({
+                    "messages": [{"role": "user", "content": {"type": "text", "text": "Summarize"}}],
+                    "maxTokens": 100,
+                }),
+            )
+            .await?;
+        let Content::Text(answer) = sampled.content else {
+            panic!("expected text from the client");
+        };
+        Ok(text_result(format!("summary: {}", answer.text)))
+    })
+}
+
+/// Read the next message the server wrote
+async fn next_message(lines: &mut Lines<BufReader<DuplexStream>>) -> Value {
+    let line = timeout(Duration::from_secs(5), lines.next_line())
+        .await
+        .expect("server should answer")
+        .unwrap()
+        .unwrap();
+    serde_json::from_str(&line).unwrap()
+}
+
+#[tokio::test]
+async fn test_handler_samples_from_client() {
+    let server = ServerBuilder::new()
+        .tool("summarize", summarize_tool())
+        .unwrap()
+        .build();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+    let mut lines = BufReader::new(client_in).lines();
+
+    client_out
+        .write_all(
+            b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"tools/call\",\"params\":{\"name\":\"summarize\",\"arguments\":{}}}\n",
+        )
+        .await
+        .unwrap();
+
+    // The tool's sampling request reaches the client before the tool returns
+    let sampling = next_message(&mut lines).await;
+    assert_eq!(sampling["method"], "sampling/createMessage");
+    assert_eq!(sampling["params"]["maxTokens"], 100);
+    let reply = json!({
+        "jsonrpc": "2.0",
+        "id": sampling["id"],
+        "result": {
+            "role": "assistant",
+            "content": {"type": "text", "text": "all good"},
+            "model": "test-model",
+        },
+    });
+    client_out
+        .write_all(format!("{reply}\n").as_bytes())
+        .await
+        .unwrap();
+
+    let response = next_message(&mut lines).await;
+    assert_eq!(response["id"], 1);
+    assert_eq!(
+        response["result"]["content"][0]["text"],
+        "summary: all good"
+    );
+}
+
+#[tokio::test]
+async fn test_client_request_needs_duplex_transport() {
+    // Contexts only carry a client handle on transports that can reach it
+    let error = RequestContext::new()
+        .client_request::<Value>("roots/list", ())
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind, turbomcp_core::ErrorKind::Unavailable);
+}
@@ -0,0 +1,105 @@
+//! In-memory transport shared by tests that run a server end to end
+//!
+//! Also included by path from other crates' tests, so it depends only on
+//! the transport layer.
+
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+use turbomcp_transport::core::{
+    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
+    TransportResult, TransportState, TransportType,
+};
+
+/// Sends messages into a [`ChannelTransport`]
+pub type Inbox = mpsc::UnboundedSender<TransportMessage>;
+
+/// Receives what a [`ChannelTransport`] sends
+pub type Outbox = mpsc::UnboundedReceiver<TransportMessage>;
+
+/// One end of an in-memory, bidirectional transport
+#[derive(Debug)]
+pub struct ChannelTransport {
+    transport_type: TransportType,
+    tx: mpsc::UnboundedSender<TransportMessage>,
+    rx: mpsc::UnboundedReceiver<TransportMessage>,
+    capabilities: TransportCapabilities,
+}
+
+impl ChannelTransport {
+    fn new(
+        tx: mpsc::UnboundedSender<TransportMessage>,
+        rx: mpsc::UnboundedReceiver<TransportMessage>,
+    ) -> Self {
+        Self {
+            transport_type: TransportType::Stdio,
+            tx,
+            rx,
+            capabilities: TransportCapabilities::default(),
+        }
+    }
+
+    /// Two transports connected to each other
+    pub fn pair() -> (Self, Self) {
+        let (a_tx, a_rx) = mpsc::unbounded_channel();
+        let (b_tx, b_rx) = mpsc::unbounded_channel();
+        (Self::new(a_tx, b_rx), Self::new(b_tx, a_rx))
+    }
+
+    /// A transport and the channels at its other end, for driving a server
+    /// with raw payloads
+    pub fn raw() -> (Self, Inbox, Outbox) {
+        let (inbox, rx) = mpsc::unbounded_channel();
+        let (tx, outbox) = mpsc::unbounded_channel();
+        (Self::new(tx, rx), inbox, outbox)
+    }
+
+    /// Report `transport_type` rather than stdio
+    pub fn posing_as(mut self, transport_type: TransportType) -> Self {
+        self.transport_type = transport_type;
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for ChannelTransport {
+    fn transport_type(&self) -> TransportType {
+        self.transport_type
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Connected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        self.tx
+            .send(message)
+            .map_err(|_| TransportError::SendFailed("peer disconnected".to_string()))
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        match self.rx.recv().await {
+            Some(message) => Ok(Some(message)),
+            None => Err(TransportError::ReceiveFailed(
+                "peer disconnected".to_string(),
+            )),
+        }
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
@@ -1,4 +1,19 @@
 //! Common test utilities and helpers to reduce duplication across test suite
+//!
+//! Fixtures that more than one test file needs live here rather than in
+//! each file: [`ScriptedTransport`] and [`ChannelTransport`] for running a
+//! server over a transport, and [`tool_definition`] and [`text_result`] for
+//! tools. Build variations from these with struct update syntax instead of
+//! spelling out whole literals, so adding a field to a protocol type only
+//! touches the tests that use it.
+
+#![allow(dead_code)]
+
+mod channel;
+mod scripted;
+
+pub use channel::{ChannelTransport, Inbox, Outbox};
+pub use scripted::ScriptedTransport;
 
 use async_trait::async_trait;
 use serde_json::json;
@@ -6,6 +21,7 @@ use std::sync::Arc;
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::RequestId;
 use turbomcp_protocol::jsonrpc::*;
+use turbomcp_protocol::types::{CallToolResult, Content, TextContent, Tool};
 use turbomcp_server::middleware::*;
 use turbomcp_server::{ServerError, ServerResult};
 
@@ -58,6 +74,26 @@ pub fn create_test_request() -> JsonRpcRequest {
     }
 }
 
+/// Create a tool definition taking an object with no declared properties
+pub fn tool_definition(name: &str) -> Tool {
+    Tool {
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Create a successful tool result holding a single text block
+pub fn text_result(text: impl Into<String>) -> CallToolResult {
+    CallToolResult {
+        content: vec![Content::Text(TextContent {
+            text: text.into(),
+            annotations: None,
+            meta: None,
+        })],
+        ..Default::default()
+    }
+}
+
 /// Create a standardized test request context
 pub fn create_test_context() -> RequestContext {
     RequestContext::new()
@@ -0,0 +1,83 @@
+//! Transport replaying a fixed list of messages to a server
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::collections::VecDeque;
+use turbomcp_core::MessageId;
+use turbomcp_transport::core::{
+    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
+    TransportResult, TransportState, TransportType,
+};
+
+/// Transport that delivers a fixed list of messages and then disconnects,
+/// or stays idle if built with [`ScriptedTransport::then_idle`]
+#[derive(Debug)]
+pub struct ScriptedTransport {
+    incoming: VecDeque<TransportMessage>,
+    idle_when_drained: bool,
+    capabilities: TransportCapabilities,
+}
+
+impl ScriptedTransport {
+    pub fn new(payloads: &[&'static str]) -> Self {
+        Self {
+            incoming: payloads
+                .iter()
+                .enumerate()
+                .map(|(i, payload)| {
+                    TransportMessage::new(MessageId::from(i.to_string()), Bytes::from(*payload))
+                })
+                .collect(),
+            idle_when_drained: false,
+            capabilities: TransportCapabilities::default(),
+        }
+    }
+
+    /// Report nothing waiting, rather than a disconnect, once every message
+    /// has been delivered
+    pub fn then_idle(mut self) -> Self {
+        self.idle_when_drained = true;
+        self
+    }
+}
+
+#[async_trait]
+impl Transport for ScriptedTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Connected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn send(&mut self, _message: TransportMessage) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        match self.incoming.pop_front() {
+            Some(message) => Ok(Some(message)),
+            None if self.idle_when_drained => Ok(None),
+            None => Err(TransportError::ReceiveFailed(
+                "peer disconnected".to_string(),
+            )),
+        }
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
@@ -5,7 +5,10 @@ use serde_json::{Value, json};
 use std::collections::HashMap;
 use std::sync::Arc;
 use turbomcp_core::RequestContext;
-use turbomcp_protocol::{jsonrpc::*, types::RequestId};
+use turbomcp_protocol::{
+    jsonrpc::*,
+    types::{LogLevel, RequestId},
+};
 use turbomcp_server::{
     ServerError, ServerResult,
     registry::HandlerRegistry,
@@ -355,6 +358,7 @@ async fn test_handle_initialize_missing_params() {
     assert!(response.error.is_some());
 
     if let Some(error) = response.error {
+        assert_eq!(error.code, -32602);
         assert!(error.message.contains("Missing required parameters"));
     }
 }
@@ -586,19 +590,17 @@ async fn test_handle_set_log_level_no_handler() {
     let router = RequestRouter::new(registry);
 
     let level_params = json!({
-        "level": "info"
+        "level": "error"
     });
 
     let request = create_basic_request("logging/setLevel", Some(level_params));
     let ctx = create_test_context();
 
-    let response = router.route(request, ctx).await;
-    assert!(response.result.is_none());
-    assert!(response.error.is_some());
-
-    if let Some(error) = response.error {
-        assert!(error.message.contains("No logging handler available"));
-    }
+    // The router keeps the level itself when no logging handler is registered
+    let response = router.route(request, ctx.clone()).await;
+    assert!(response.error.is_none());
+    assert_eq!(response.result, Some(json!({})));
+    assert_eq!(router.log_level(&ctx), LogLevel::Error);
 }
 
 #[tokio::test]
@@ -832,6 +834,7 @@ async fn test_parse_params_invalid_json() {
     assert!(response.error.is_some());
 
     if let Some(error) = response.error {
+        assert_eq!(error.code, -32602);
         assert!(error.message.contains("Invalid parameters"));
     }
 }
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use turbomcp_server::config::*;
+use turbomcp_transport::core::TransportType;
 
 // ============================================================================
 // Default Configuration Tests
@@ -30,6 +31,7 @@ fn test_server_config_default() {
     assert_eq!(timeouts.request_timeout, Duration::from_secs(30));
     assert_eq!(timeouts.connection_timeout, Duration::from_secs(10));
     assert_eq!(timeouts.keep_alive_timeout, Duration::from_secs(60));
+    assert_eq!(timeouts.shutdown_timeout, Duration::from_secs(30));
 
     // Test default rate limiting config
     let rate_limiting = &config.rate_limiting;
@@ -54,6 +56,7 @@ fn test_timeout_config_default() {
     assert_eq!(timeout_config.request_timeout, Duration::from_secs(30));
     assert_eq!(timeout_config.connection_timeout, Duration::from_secs(10));
     assert_eq!(timeout_config.keep_alive_timeout, Duration::from_secs(60));
+    assert_eq!(timeout_config.shutdown_timeout, Duration::from_secs(30));
 }
 
 #[test]
@@ -74,6 +77,19 @@ fn test_logging_config_default() {
     assert!(log_config.file.is_none());
 }
 
+#[test]
+fn test_sighup_reload_needs_a_config_file() {
+    let mut reload = ReloadConfig::default();
+    assert!(reload.on_sighup);
+    assert!(!reload.reloads_on_sighup());
+
+    reload.config_file = Some(PathBuf::from("/etc/turbomcp/config.json"));
+    assert!(reload.reloads_on_sighup());
+
+    reload.on_sighup = false;
+    assert!(!reload.reloads_on_sighup());
+}
+
 // ============================================================================
 // Configuration Builder Tests - Normal Use Cases
 // ============================================================================
@@ -275,17 +291,50 @@ fn test_server_config_json_roundtrip() {
             request_timeout: Duration::from_secs(60),
             connection_timeout: Duration::from_secs(15),
             keep_alive_timeout: Duration::from_secs(90),
+            shutdown_timeout: Duration::from_secs(5),
+            startup_timeout: Duration::from_secs(20),
         },
         rate_limiting: RateLimitingConfig {
             enabled: false,
             requests_per_second: 50,
             burst_capacity: 100,
         },
+        pre_init: PreInitLimitConfig {
+            enabled: true,
+            max_messages_per_second: 3,
+            max_invalid_messages: 1,
+        },
         logging: LoggingConfig {
             level: "warn".to_string(),
             structured: false,
             file: Some(PathBuf::from("/var/log/server.log")),
         },
+        tool_access: ToolAccessConfig {
+            allowed: vec!["echo".to_string()],
+            denied: Vec::new(),
+        },
+        tool_errors: ToolErrorConfig {
+            mode: ToolErrorMode::Result,
+            tools: HashMap::from([("deploy".to_string(), ToolErrorMode::Protocol)]),
+        },
+        output_filter: OutputFilterConfig::default(),
+        circuit_breakers: HashMap::new(),
+        method_access: HashMap::from([(
+            TransportType::Tcp,
+            MethodAccessConfig {
+                allowed: vec!["tools/list".to_string()],
+                denied: Vec::new(),
+            },
+        )]),
+        prewarm: PrewarmConfig {
+            max_concurrency: 2,
+            fail_on_error: false,
+        },
+        memory_budget: Some(64 * 1024 * 1024),
+        reload: ReloadConfig {
+            on_sighup: false,
+            config_file: Some(PathBuf::from("/etc/turbomcp/config.json")),
+        },
         additional,
     };
 
@@ -310,6 +359,17 @@ fn test_server_config_json_roundtrip() {
     assert_eq!(tls.cert_file, original_tls.cert_file);
     assert_eq!(tls.key_file, original_tls.key_file);
 
+    // Per-transport method lists are keyed by transport name
+    assert!(json.contains(r#""method_access":{"tcp":"#));
+    assert_eq!(
+        original_config.method_access,
+        deserialized_config.method_access
+    );
+    assert_eq!(original_config.prewarm, deserialized_config.prewarm);
+    assert_eq!(original_config.pre_init, deserialized_config.pre_init);
+    assert!(json.contains(r#""tool_errors":{"mode":"result""#));
+    assert_eq!(original_config.tool_errors, deserialized_config.tool_errors);
+
     // Test additional fields
     assert_eq!(
         original_config.additional.len(),
@@ -0,0 +1,172 @@
+//! Tests for shutting down sessions across several transports together
+
+mod common;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use common::{text_result, tool_definition};
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use turbomcp_core::MessageId;
+use turbomcp_protocol::methods;
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_transport::core::{
+    Transport, TransportCapabilities, TransportMessage, TransportMetrics, TransportResult,
+    TransportState, TransportType,
+};
+
+/// What a client saw over its transport, in order
+type Seen = Arc<Mutex<Vec<String>>>;
+
+/// Transport that delivers fixed messages and records what the server does
+#[derive(Debug)]
+struct RecordingTransport {
+    kind: TransportType,
+    incoming: VecDeque<TransportMessage>,
+    seen: Seen,
+    capabilities: TransportCapabilities,
+}
+
+impl RecordingTransport {
+    fn new(kind: TransportType, payloads: &[&'static str]) -> (Self, Seen) {
+        let seen = Seen::default();
+        let transport = Self {
+            kind,
+            incoming: payloads
+                .iter()
+                .enumerate()
+                .map(|(i, payload)| {
+                    TransportMessage::new(MessageId::from(i.to_string()), Bytes::from(*payload))
+                })
+                .collect(),
+            seen: Arc::clone(&seen),
+            capabilities: TransportCapabilities::default(),
+        };
+        (transport, seen)
+    }
+}
+
+#[async_trait]
+impl Transport for RecordingTransport {
+    fn transport_type(&self) -> TransportType {
+        self.kind
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Connected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        self.seen.lock().push("disconnect".to_string());
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        let sent: Value = serde_json::from_slice(&message.payload).unwrap();
+        let entry = match sent["method"].as_str() {
+            Some(method) => method.to_string(),
+            None => format!("response {}", sent["id"]),
+        };
+        self.seen.lock().push(entry);
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        Ok(self.incoming.pop_front())
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
+
+/// Tool that takes a moment to finish once started
+fn slow_tool(started: Arc<Notify>) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("slow"), move |_req, _ctx| {
+        let started = Arc::clone(&started);
+        async move {
+            started.notify_one();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(text_result("done"))
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_shutdown_drains_sessions_on_every_transport() {
+    let started = Arc::new(Notify::new());
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_shutdown_timeout(Duration::from_secs(5))
+            .tool("slow", slow_tool(Arc::clone(&started)))
+            .unwrap()
+            .build(),
+    );
+
+    let (busy, busy_seen) = RecordingTransport::new(
+        TransportType::Tcp,
+        &[
+            r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"slow","arguments":{}}}"#,
+        ],
+    );
+    let (idle_stdio, stdio_seen) = RecordingTransport::new(TransportType::Stdio, &[]);
+    let (idle_ws, ws_seen) = RecordingTransport::new(TransportType::WebSocket, &[]);
+    let transports: Vec<Box<dyn Transport>> =
+        vec![Box::new(busy), Box::new(idle_stdio), Box::new(idle_ws)];
+
+    let run = tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transports(transports).await }
+    });
+    timeout(Duration::from_secs(5), started.notified())
+        .await
+        .expect("tool call should start");
+    server.shutdown_handle().shutdown().await;
+
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("every session should stop")
+        .unwrap()
+        .unwrap();
+
+    // The in-flight call finished before the client was told and disconnected
+    assert_eq!(
+        *busy_seen.lock(),
+        ["response 7", methods::SERVER_SHUTDOWN, "disconnect"]
+    );
+    for seen in [stdio_seen, ws_seen] {
+        assert_eq!(*seen.lock(), [methods::SERVER_SHUTDOWN, "disconnect"]);
+    }
+}
+
+#[tokio::test]
+async fn test_sessions_started_after_shutdown_close_immediately() {
+    let server = ServerBuilder::new()
+        .with_shutdown_timeout(Duration::from_secs(30))
+        .build();
+    server.shutdown_handle().shutdown().await;
+
+    let (transport, seen) = RecordingTransport::new(TransportType::Stdio, &[]);
+    timeout(
+        Duration::from_secs(5),
+        server.run_with_transports(vec![Box::new(transport)]),
+    )
+    .await
+    .expect("no session should outlive a shutdown already under way")
+    .unwrap();
+    assert_eq!(*seen.lock(), [methods::SERVER_SHUTDOWN, "disconnect"]);
+}
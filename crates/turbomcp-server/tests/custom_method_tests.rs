@@ -0,0 +1,91 @@
+//! Tests for custom JSON-RPC methods (protocol extensions)
+
+mod common;
+
+use common::ChannelTransport;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::time::timeout;
+use turbomcp_client::Client;
+use turbomcp_server::{ServerBuilder, ServerError};
+
+#[tokio::test]
+async fn test_custom_method_round_trip() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_method("x/echo", |params, _ctx| async move {
+                Ok(json!({ "echo": params }))
+            })
+            .unwrap()
+            .build(),
+    );
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    let run = tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(server_transport).await }
+    });
+    let mut client = Client::new(client_transport);
+
+    let result = client
+        .raw_request("x/echo", Some(json!({"n": 1})))
+        .await
+        .unwrap();
+    assert_eq!(result, json!({"echo": {"n": 1}}));
+
+    let err = client.raw_request("x/missing", None).await.unwrap_err();
+    assert!(err.to_string().contains("x/missing"));
+
+    let metrics = server.metrics();
+    assert_eq!(metrics.requests_total.load(Ordering::Relaxed), 2);
+    assert_eq!(metrics.requests_successful.load(Ordering::Relaxed), 1);
+    assert_eq!(metrics.requests_failed.load(Ordering::Relaxed), 1);
+
+    // Dropping the client disconnects the transport and stops the server
+    drop(client);
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("server should stop when the client disconnects")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_custom_method_errors_become_rpc_errors() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_method("x/fail", |_params, _ctx| async move {
+                Err::<serde_json::Value, _>(ServerError::handler("extension refused"))
+            })
+            .unwrap()
+            .build(),
+    );
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(server_transport).await }
+    });
+    let mut client = Client::new(client_transport);
+
+    let err = client.raw_request("x/fail", None).await.unwrap_err();
+    assert!(err.to_string().contains("extension refused"));
+}
+
+#[test]
+fn test_custom_method_cannot_shadow_builtin_or_duplicate() {
+    let builtin = ServerBuilder::new().with_method("tools/call", |_params, _ctx| async move {
+        Ok(serde_json::Value::Null)
+    });
+    assert!(builtin.is_err());
+
+    let duplicate = ServerBuilder::new()
+        .with_method("x/once", |_params, _ctx| async move {
+            Ok(serde_json::Value::Null)
+        })
+        .unwrap()
+        .with_method("x/once", |_params, _ctx| async move {
+            Ok(serde_json::Value::Null)
+        });
+    assert!(duplicate.is_err());
+}
@@ -0,0 +1,176 @@
+//! Tests for JSON-RPC envelope validation
+
+mod common;
+
+use bytes::Bytes;
+use common::{ChannelTransport, Inbox, Outbox};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::time::timeout;
+use turbomcp_core::MessageId;
+use turbomcp_protocol::RequestId;
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::envelope::{INVALID_REQUEST, screen_batch, validate_envelope};
+use turbomcp_transport::core::TransportMessage;
+
+/// Start a server with one custom method and return a raw connection to it
+fn serve() -> (Inbox, Outbox) {
+    let (transport, to_server, from_server) = ChannelTransport::raw();
+    let server = ServerBuilder::new()
+        .with_method("x/echo", |params, _ctx| async move {
+            Ok(params.unwrap_or(Value::Null))
+        })
+        .unwrap()
+        .build();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+    (to_server, from_server)
+}
+
+async fn exchange(to_server: &Inbox, from_server: &mut Outbox, payload: &str) -> Value {
+    to_server
+        .send(TransportMessage::new(
+            MessageId::from("raw"),
+            Bytes::from(payload.to_string()),
+        ))
+        .unwrap();
+    let reply = timeout(Duration::from_secs(5), from_server.recv())
+        .await
+        .expect("server should answer")
+        .unwrap();
+    serde_json::from_slice(&reply.payload).unwrap()
+}
+
+#[test]
+fn test_each_malformed_envelope_is_an_invalid_request() {
+    let cases = [
+        (json!([1]), "JSON object"),
+        (json!({"method": "ping", "id": 1}), "missing 'jsonrpc'"),
+        (
+            json!({"jsonrpc": "1.0", "method": "ping", "id": 1}),
+            "\"2.0\"",
+        ),
+        (
+            json!({"jsonrpc": 2.0, "method": "ping", "id": 1}),
+            "\"2.0\"",
+        ),
+        (
+            json!({"jsonrpc": "2.0", "method": "ping", "id": true}),
+            "string or an integer",
+        ),
+        (
+            json!({"jsonrpc": "2.0", "method": "ping", "id": {"n": 1}}),
+            "string or an integer",
+        ),
+        (
+            json!({"jsonrpc": "2.0", "method": "ping", "id": 1.5}),
+            "string or an integer",
+        ),
+        (
+            json!({"jsonrpc": "2.0", "method": "ping", "id": null}),
+            "must not be null",
+        ),
+        (json!({"jsonrpc": "2.0", "id": 1}), "missing 'method'"),
+        (json!({"jsonrpc": "2.0", "method": 7, "id": 1}), "'method'"),
+        (
+            json!({"jsonrpc": "2.0", "method": "ping", "params": "x", "id": 1}),
+            "'params'",
+        ),
+        (json!({"jsonrpc": "2.0", "result": {}}), "missing 'id'"),
+    ];
+
+    for (message, reason) in cases {
+        let error = validate_envelope(&message).unwrap_err();
+        assert!(error.reason.contains(reason), "{message}: {error}");
+        let response = error.to_response();
+        assert_eq!(response.error.unwrap().code, INVALID_REQUEST, "{message}");
+    }
+}
+
+#[test]
+fn test_well_formed_envelopes_pass() {
+    for message in [
+        json!({"jsonrpc": "2.0", "method": "ping", "id": 1}),
+        json!({"jsonrpc": "2.0", "method": "ping", "id": "a", "params": {}}),
+        json!({"jsonrpc": "2.0", "method": "ping", "id": 2, "params": []}),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+        json!({"jsonrpc": "2.0", "result": {}, "id": 3}),
+        json!({"jsonrpc": "2.0", "error": {"code": -1, "message": "x"}, "id": null}),
+    ] {
+        assert!(validate_envelope(&message).is_ok(), "{message}");
+    }
+}
+
+#[test]
+fn test_rejection_keeps_a_usable_id() {
+    let error = validate_envelope(&json!({"id": "req-1", "method": "ping"})).unwrap_err();
+    assert_eq!(error.id, Some(RequestId::String("req-1".to_string())));
+
+    let error = validate_envelope(&json!({"jsonrpc": "2.0", "id": false})).unwrap_err();
+    assert_eq!(error.id, None);
+}
+
+#[test]
+fn test_batches_are_screened_per_entry() {
+    let (valid, rejected) = screen_batch(vec![
+        json!({"jsonrpc": "2.0", "method": "ping", "id": 1}),
+        json!({"method": "ping", "id": 2}),
+    ]);
+    assert_eq!(valid.len(), 1);
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].id, Some(RequestId::Number(2)));
+
+    let (valid, rejected) = screen_batch(Vec::new());
+    assert!(valid.is_empty());
+    assert_eq!(rejected[0].error.as_ref().unwrap().code, INVALID_REQUEST);
+}
+
+#[tokio::test]
+async fn test_server_answers_malformed_envelopes_before_routing() {
+    let (to_server, mut from_server) = serve();
+
+    for (payload, id) in [
+        (r#"{"method":"x/echo","id":1}"#, json!(1)),
+        (r#"{"jsonrpc":"1.0","method":"x/echo","id":2}"#, json!(2)),
+        (
+            r#"{"jsonrpc":"2.0","method":"x/echo","id":true}"#,
+            Value::Null,
+        ),
+        (r#"{"jsonrpc":"2.0","id":"no-method"}"#, json!("no-method")),
+    ] {
+        let response = exchange(&to_server, &mut from_server, payload).await;
+        assert_eq!(response["error"]["code"], INVALID_REQUEST, "{payload}");
+        assert_eq!(response["id"], id, "{payload}");
+        assert!(response.get("result").is_none());
+    }
+
+    // A well-formed request on the same connection is still routed
+    let response = exchange(
+        &to_server,
+        &mut from_server,
+        r#"{"jsonrpc":"2.0","method":"x/echo","params":{"n":1},"id":5}"#,
+    )
+    .await;
+    assert_eq!(response["result"], json!({"n": 1}));
+}
+
+#[tokio::test]
+async fn test_server_routes_valid_batch_entries_and_rejects_the_rest() {
+    let (to_server, mut from_server) = serve();
+
+    let response = exchange(
+        &to_server,
+        &mut from_server,
+        r#"[{"jsonrpc":"2.0","method":"x/echo","params":[1],"id":1},{"jsonrpc":"2.0","id":2}]"#,
+    )
+    .await;
+    let responses = response.as_array().unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0]["result"], json!([1]));
+    assert_eq!(responses[1]["error"]["code"], INVALID_REQUEST);
+    assert_eq!(responses[1]["id"], 2);
+
+    let response = exchange(&to_server, &mut from_server, "[]").await;
+    assert!(response.is_object(), "{response}");
+    assert_eq!(response["error"]["code"], INVALID_REQUEST);
+    assert_eq!(response["id"], Value::Null);
+}
@@ -9,15 +9,7 @@ fn test_handler_wrapper_new() {
         name: "test_tool".to_string(),
         title: Some("Test Tool".to_string()),
         description: Some("A test tool".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool_def, |_request, _ctx| async move {
@@ -73,15 +65,7 @@ fn test_function_tool_handler_definition() {
         name: "echo".to_string(),
         title: Some("Echo Tool".to_string()),
         description: Some("Echoes input".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool.clone(), |_request, _ctx| async move {
@@ -101,15 +85,7 @@ fn test_function_tool_handler_roles() {
         name: "admin_tool".to_string(),
         title: Some("Admin Tool".to_string()),
         description: Some("Admin only".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let roles = vec!["admin".to_string(), "superuser".to_string()];
@@ -133,15 +109,7 @@ fn test_function_tool_handler_no_roles() {
         name: "public_tool".to_string(),
         title: Some("Public Tool".to_string()),
         description: Some("Available to all".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool, |_request, _ctx| async move {
@@ -275,15 +243,7 @@ fn test_function_tool_handler_debug() {
         name: "debug_tool".to_string(),
         title: Some("Debug Tool".to_string()),
         description: Some("For testing debug output".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool, |_request, _ctx| async move {
@@ -345,15 +305,7 @@ fn test_handler_wrapper_debug() {
         name: "wrapper_test".to_string(),
         title: Some("Wrapper Test".to_string()),
         description: Some("For testing wrapper debug".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool, |_request, _ctx| async move {
@@ -375,15 +327,7 @@ fn test_handler_wrapper_with_metadata() {
         name: "meta_test".to_string(),
         title: Some("Metadata Test".to_string()),
         description: Some("For testing metadata".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool, |_request, _ctx| async move {
@@ -424,15 +368,7 @@ fn test_handler_wrapper_update_metadata() {
         name: "update_test".to_string(),
         title: Some("Update Test".to_string()),
         description: Some("For testing metadata updates".to_string()),
-        input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
-            properties: None,
-            required: None,
-            additional_properties: None,
-        },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     let handler = FunctionToolHandler::new(tool, |_request, _ctx| async move {
@@ -0,0 +1,151 @@
+//! Tests for middleware answering requests in place of their handlers
+
+mod common;
+
+use async_trait::async_trait;
+use common::tool_definition;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcResponse};
+use turbomcp_protocol::types::CallToolResult;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{Middleware, ServerBuilder, ServerResult};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+/// Answers every tool call with a maintenance notice
+struct MaintenanceMode;
+
+#[async_trait]
+impl Middleware for MaintenanceMode {
+    async fn process_request(
+        &self,
+        _request: &mut JsonRpcRequest,
+        _ctx: &mut RequestContext,
+    ) -> ServerResult<()> {
+        Ok(())
+    }
+
+    async fn intercept(
+        &self,
+        request: &JsonRpcRequest,
+        _ctx: &RequestContext,
+    ) -> ServerResult<Option<JsonRpcResponse>> {
+        if request.method != "tools/call" {
+            return Ok(None);
+        }
+        let result = json!({
+            "content": [{"type": "text", "text": "Down for maintenance"}],
+            "isError": true,
+        });
+        Ok(Some(JsonRpcResponse::success(result, request.id.clone())))
+    }
+
+    async fn process_response(
+        &self,
+        _response: &mut JsonRpcResponse,
+        _ctx: &RequestContext,
+    ) -> ServerResult<()> {
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "maintenance"
+    }
+}
+
+/// Marks every response it sees
+struct Stamp;
+
+#[async_trait]
+impl Middleware for Stamp {
+    async fn process_request(
+        &self,
+        _request: &mut JsonRpcRequest,
+        _ctx: &mut RequestContext,
+    ) -> ServerResult<()> {
+        Ok(())
+    }
+
+    async fn process_response(
+        &self,
+        response: &mut JsonRpcResponse,
+        _ctx: &RequestContext,
+    ) -> ServerResult<()> {
+        if let Some(result) = response.result.as_mut() {
+            result["_meta"] = json!({"stamped": true});
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "stamp"
+    }
+
+    // Runs after the maintenance middleware on requests
+    fn priority(&self) -> u32 {
+        200
+    }
+}
+
+fn recording_tool(called: Arc<AtomicBool>) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("deploy"), move |_req, _ctx| {
+        called.store(true, Ordering::SeqCst);
+        async {
+            Ok(CallToolResult {
+                content: vec![],
+                ..Default::default()
+            })
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_middleware_answers_without_running_handler() {
+    let called = Arc::new(AtomicBool::new(false));
+    let server = ServerBuilder::new()
+        .tool("deploy", recording_tool(Arc::clone(&called)))
+        .unwrap()
+        .with_middleware(MaintenanceMode)
+        .with_middleware(Stamp)
+        .build();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "tools/call",
+        "params": {"name": "deploy", "arguments": {}},
+    });
+    client_out
+        .write_all(format!("{request}\n").as_bytes())
+        .await
+        .unwrap();
+    let line = timeout(
+        Duration::from_secs(5),
+        BufReader::new(client_in).lines().next_line(),
+    )
+    .await
+    .expect("server should answer")
+    .unwrap()
+    .unwrap();
+    let response: Value = serde_json::from_str(&line).unwrap();
+
+    assert_eq!(response["id"], 3);
+    assert_eq!(
+        response["result"]["content"][0]["text"],
+        "Down for maintenance"
+    );
+    // Middleware the request never reached still sees the canned response
+    assert_eq!(response["result"]["_meta"]["stamped"], true);
+    assert!(!called.load(Ordering::SeqCst));
+}
@@ -0,0 +1,144 @@
+//! Tests for per-request memory budgets
+
+mod common;
+
+use common::{text_result, tool_definition};
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{McpServer, ServerBuilder, ServerError, ToolOutputLimit};
+
+/// Error code of a resource-exhausted failure
+const RESOURCE_EXHAUSTED: i32 = -32010;
+
+/// Tool returning `size` bytes of text without reporting any of it
+fn report(size: usize) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("report"), move |_req, _ctx| async move {
+        Ok(text_result("x".repeat(size)))
+    })
+}
+
+async fn call(server: &McpServer, name: &str) -> turbomcp_protocol::jsonrpc::JsonRpcResponse {
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(1),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": name, "arguments": {}})),
+    };
+    server.router().route(request, RequestContext::new()).await
+}
+
+#[tokio::test]
+async fn test_handler_ignoring_its_budget_is_aborted() {
+    let chunks = Arc::new(AtomicUsize::new(0));
+    let counted = Arc::clone(&chunks);
+    // Builds a gigabyte of rows, ignoring refused charges
+    let hoarder = FunctionToolHandler::new(tool_definition("hoard"), move |_req, ctx| {
+        let chunks = Arc::clone(&counted);
+        async move {
+            let mut rows = Vec::new();
+            while rows.len() < 1 << 20 {
+                let _ = ctx.charge_memory(1024);
+                rows.push(vec![0u8; 1024]);
+                chunks.fetch_add(1, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+            }
+            Ok(text_result(format!("{} rows", rows.len())))
+        }
+    });
+    let server = ServerBuilder::new()
+        .with_memory_budget(16 * 1024)
+        .tool("hoard", hoarder)
+        .unwrap()
+        .build();
+
+    let response = call(&server, "hoard").await;
+    let error = response.error.expect("over-budget handler must fail");
+    assert_eq!(error.code, RESOURCE_EXHAUSTED);
+    assert!(error.message.contains("memory budget"), "{}", error.message);
+
+    // The handler was dropped, not left running in the background
+    let produced = chunks.load(Ordering::SeqCst);
+    assert_eq!(produced, 17);
+    tokio::task::yield_now().await;
+    assert_eq!(chunks.load(Ordering::SeqCst), produced);
+}
+
+#[tokio::test]
+async fn test_refused_charge_propagates_as_error() {
+    let builder = FunctionToolHandler::new(tool_definition("build"), |_req, ctx| async move {
+        ctx.charge_memory(4096)?;
+        ctx.charge_memory(4096)?;
+        Ok(text_result("built".to_string()))
+    });
+    let server = ServerBuilder::new()
+        .with_memory_budget(6000)
+        .tool("build", builder)
+        .unwrap()
+        .build();
+
+    let error = call(&server, "build").await.error.unwrap();
+    assert_eq!(error.code, RESOURCE_EXHAUSTED);
+    assert!(
+        error.message.contains("6000-byte budget"),
+        "{}",
+        error.message
+    );
+}
+
+#[tokio::test]
+async fn test_over_budget_result_is_rejected() {
+    let server = ServerBuilder::new()
+        .with_memory_budget(4096)
+        .tool("report", report(100_000))
+        .unwrap()
+        .build();
+    let error = call(&server, "report").await.error.unwrap();
+    assert_eq!(error.code, RESOURCE_EXHAUSTED);
+    assert!(error.message.contains("'report'"), "{}", error.message);
+
+    // Results within the budget, and calls without one, are unaffected
+    let server = ServerBuilder::new()
+        .with_memory_budget(4096)
+        .tool("report", report(100))
+        .unwrap()
+        .build();
+    assert!(call(&server, "report").await.result.is_some());
+    let server = ServerBuilder::new()
+        .tool("report", report(100_000))
+        .unwrap()
+        .build();
+    assert!(call(&server, "report").await.result.is_some());
+}
+
+#[tokio::test]
+async fn test_output_limit_truncates_within_budget() {
+    let handler = report(100_000).with_output_limit(ToolOutputLimit::truncate(2048));
+    let server = ServerBuilder::new()
+        .with_memory_budget(4096)
+        .tool("report", handler)
+        .unwrap()
+        .build();
+
+    let result = call(&server, "report").await.result.unwrap();
+    let marker = result["content"][1]["text"].as_str().unwrap();
+    assert!(marker.starts_with("[output truncated:"), "{marker}");
+}
+
+#[test]
+fn test_budget_error_is_resource_exhausted() {
+    let budget = turbomcp_core::MemoryBudget::new(10);
+    let error: ServerError = budget.charge(11).unwrap_err().into();
+    assert!(matches!(
+        error,
+        ServerError::ResourceExhausted {
+            current: Some(11),
+            max: Some(10),
+            ..
+        }
+    ));
+}
@@ -0,0 +1,108 @@
+//! Tests for per-transport method allow/deny lists
+
+mod common;
+
+use bytes::Bytes;
+use common::{ChannelTransport, Inbox, Outbox, text_result, tool_definition};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+use turbomcp_core::MessageId;
+use turbomcp_protocol::types::Tool;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{ConfigurationBuilder, McpServer, MethodAccessConfig};
+use turbomcp_transport::core::{TransportMessage, TransportType};
+
+/// A raw client connection to `server` over a transport of `transport_type`
+struct Connection {
+    to_server: Inbox,
+    from_server: Outbox,
+}
+
+impl Connection {
+    fn open(server: &Arc<McpServer>, transport_type: TransportType) -> Self {
+        let (transport, to_server, from_server) = ChannelTransport::raw();
+        let transport = transport.posing_as(transport_type);
+        tokio::spawn({
+            let server = Arc::clone(server);
+            async move { server.run_with_transport(transport).await }
+        });
+        Self {
+            to_server,
+            from_server,
+        }
+    }
+
+    async fn request(&mut self, method: &str, params: Value) -> Value {
+        let request = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+        self.to_server
+            .send(TransportMessage::new(
+                MessageId::from("raw"),
+                Bytes::from(request.to_string()),
+            ))
+            .unwrap();
+        let reply = timeout(Duration::from_secs(5), self.from_server.recv())
+            .await
+            .expect("server should answer")
+            .unwrap();
+        serde_json::from_slice(&reply.payload).unwrap()
+    }
+}
+
+fn delete_tool() -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some("Delete the search index".to_string()),
+        ..tool_definition("delete_index")
+    };
+    FunctionToolHandler::new(tool, |_req, _ctx| async move { Ok(text_result("deleted")) })
+}
+
+#[tokio::test]
+async fn test_method_allowed_on_unix_is_denied_on_tcp() {
+    let config = ConfigurationBuilder::new()
+        .method_access(
+            TransportType::Tcp,
+            MethodAccessConfig {
+                allowed: vec!["tools/list".to_string()],
+                denied: Vec::new(),
+            },
+        )
+        .build();
+    let server = Arc::new(McpServer::new(config));
+    server
+        .registry()
+        .register_tool("delete_index", delete_tool())
+        .unwrap();
+
+    let mut unix = Connection::open(&server, TransportType::Unix);
+    let mut tcp = Connection::open(&server, TransportType::Tcp);
+    let call = json!({"name": "delete_index", "arguments": {}});
+
+    let response = unix.request("tools/call", call.clone()).await;
+    assert_eq!(response["result"]["content"][0]["text"], "deleted");
+
+    let response = tcp.request("tools/call", call).await;
+    assert_eq!(response["error"]["code"], -32601);
+    assert_eq!(response["id"], 1);
+
+    // Allowed methods and the handshake still work over TCP
+    let response = tcp.request("tools/list", json!({})).await;
+    assert_eq!(response["result"]["tools"][0]["name"], "delete_index");
+    let response = tcp.request("ping", json!({})).await;
+    assert!(response.get("error").is_none());
+}
+
+#[test]
+fn test_denied_methods_win_over_allowed() {
+    let access = MethodAccessConfig {
+        allowed: vec!["tools/list".to_string(), "tools/call".to_string()],
+        denied: vec!["tools/call".to_string(), "initialize".to_string()],
+    };
+    assert!(access.is_allowed("tools/list"));
+    assert!(!access.is_allowed("tools/call"));
+    assert!(!access.is_allowed("prompts/list"));
+    assert!(access.is_allowed("initialize"));
+    assert!(access.is_allowed("ping"));
+    assert!(MethodAccessConfig::default().is_allowed("prompts/list"));
+}
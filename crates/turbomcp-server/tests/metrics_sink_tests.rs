@@ -0,0 +1,102 @@
+//! Tests for routing server measurements to custom metrics sinks
+
+mod common;
+
+use common::{text_result, tool_definition};
+use parking_lot::Mutex;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{MetricsSink, ServerBuilder};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+/// Sink writing each event it receives as a line of text
+#[derive(Clone, Default)]
+struct CapturingSink {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl MetricsSink for CapturingSink {
+    fn request_started(&self) {
+        self.events.lock().push("request_started".to_string());
+    }
+
+    fn request_succeeded(&self, _duration: Duration) {
+        self.events.lock().push("request_succeeded".to_string());
+    }
+
+    fn request_failed(&self, error_type: &str, _duration: Duration) {
+        self.events
+            .lock()
+            .push(format!("request_failed {error_type}"));
+    }
+
+    fn tool_called(&self, tool: &str, success: bool, _duration: Duration) {
+        self.events
+            .lock()
+            .push(format!("tool_called {tool} {success}"));
+    }
+}
+
+fn echo_tool() -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("echo"), |_req, _ctx| async {
+        Ok(text_result("echo"))
+    })
+}
+
+#[tokio::test]
+async fn test_custom_sink_receives_measurements() {
+    let sink = CapturingSink::default();
+    let server = ServerBuilder::new()
+        .tool("echo", echo_tool())
+        .unwrap()
+        .with_metrics_sink(sink.clone())
+        .build();
+    let metrics = server.metrics().clone();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    let mut lines = BufReader::new(client_in).lines();
+    for (id, tool) in [(1, "echo"), (2, "missing")] {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": {"name": tool, "arguments": {}},
+        });
+        client_out
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .unwrap();
+        timeout(Duration::from_secs(5), lines.next_line())
+            .await
+            .expect("server should answer")
+            .unwrap()
+            .unwrap();
+    }
+
+    // Only registered tools are reported by name
+    assert_eq!(
+        *sink.events.lock(),
+        [
+            "request_started",
+            "tool_called echo true",
+            "request_succeeded",
+            "request_started",
+            "request_failed handler",
+        ]
+    );
+
+    // The built-in metrics are still kept
+    assert_eq!(metrics.requests_total.load(Ordering::Relaxed), 2);
+    assert_eq!(metrics.requests_failed.load(Ordering::Relaxed), 1);
+    assert_eq!(metrics.tool_calls_successful.load(Ordering::Relaxed), 1);
+}
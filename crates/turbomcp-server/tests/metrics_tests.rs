@@ -235,3 +235,36 @@ async fn test_error_rate_calculation() {
     let error_rate = metrics.error_rate_percent();
     assert!((error_rate - 33.33).abs() < 0.1);
 }
+
+#[tokio::test]
+async fn test_reset_keeps_gauges() {
+    let metrics = ServerMetrics::new();
+    metrics.record_request_start();
+    metrics.record_request_start();
+    metrics.record_request_success(Duration::from_millis(3));
+    metrics.record_connection_established();
+    metrics.record_tool_call_latency("square", true, Duration::from_millis(3));
+    metrics.record_custom("queue_depth", 4.0);
+
+    metrics.reset();
+
+    assert_eq!(metrics.requests_total.load(Ordering::Relaxed), 0);
+    assert_eq!(metrics.requests_successful.load(Ordering::Relaxed), 0);
+    assert_eq!(metrics.tool_calls_total.load(Ordering::Relaxed), 0);
+    assert_eq!(
+        metrics.min_response_time_us.load(Ordering::Relaxed),
+        u64::MAX
+    );
+    assert!(
+        metrics
+            .response_time_buckets
+            .counts()
+            .iter()
+            .all(|(_, count)| *count == 0)
+    );
+    assert!(metrics.tool_latency.is_empty());
+    assert!(metrics.custom.read().is_empty());
+    // Still one request in flight and one connection open
+    assert_eq!(metrics.requests_in_flight.load(Ordering::Relaxed), 1);
+    assert_eq!(metrics.connections_active.load(Ordering::Relaxed), 1);
+}
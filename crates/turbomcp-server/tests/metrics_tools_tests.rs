@@ -0,0 +1,99 @@
+//! Tests for the opt-in `__metrics` and `__metrics_reset` admin tools
+
+use serde_json::{Value, json};
+use std::time::Duration;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcResponse, JsonRpcVersion};
+use turbomcp_protocol::types::CallToolResult;
+use turbomcp_server::metrics::{METRICS_RESET_TOOL, METRICS_TOOL};
+use turbomcp_server::{McpServer, MessageId, ServerBuilder};
+
+fn admin_ctx() -> RequestContext {
+    RequestContext::new().with_metadata("auth", json!({"roles": ["admin"]}))
+}
+
+async fn call(server: &McpServer, tool: &str, ctx: RequestContext) -> JsonRpcResponse {
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": tool, "arguments": {}})),
+        id: MessageId::from(1),
+    };
+    server.router().route(request, ctx).await
+}
+
+fn text_of(response: JsonRpcResponse) -> String {
+    let result: CallToolResult = serde_json::from_value(response.result.unwrap()).unwrap();
+    let content = serde_json::to_value(&result.content[0]).unwrap();
+    content["text"].as_str().unwrap().to_string()
+}
+
+fn metrics_server() -> McpServer {
+    ServerBuilder::new()
+        .with_metrics_tools(["admin"])
+        .unwrap()
+        .build()
+}
+
+#[tokio::test]
+async fn test_metrics_tool_returns_snapshot_with_tool_latency() {
+    let server = metrics_server();
+    let metrics = server.metrics();
+    metrics.record_request_start();
+    metrics.record_request_success(Duration::from_millis(2));
+    metrics.record_tool_call_latency("square", true, Duration::from_millis(3));
+    metrics.record_tool_call_latency("square", false, Duration::from_millis(30));
+
+    let snapshot: Value =
+        serde_json::from_str(&text_of(call(&server, METRICS_TOOL, admin_ctx()).await)).unwrap();
+
+    assert_eq!(snapshot["metrics"]["requests_total"], 1.0);
+    assert_eq!(snapshot["metrics"]["tool_calls_total"], 2.0);
+    assert_eq!(snapshot["metrics"]["tool_calls_failed"], 1.0);
+    let square = &snapshot["tool_latency"]["square"];
+    assert_eq!(square["5ms"], 1);
+    assert_eq!(square["50ms"], 1);
+    assert_eq!(square["1ms"], 0);
+}
+
+#[tokio::test]
+async fn test_metrics_reset_tool_zeroes_counters() {
+    let server = metrics_server();
+    server
+        .metrics()
+        .record_tool_call_latency("square", true, Duration::from_millis(3));
+
+    let response = call(&server, METRICS_RESET_TOOL, admin_ctx()).await;
+    assert!(response.error.is_none());
+
+    let snapshot: Value =
+        serde_json::from_str(&text_of(call(&server, METRICS_TOOL, admin_ctx()).await)).unwrap();
+    assert_eq!(snapshot["metrics"]["tool_calls_total"], 0.0);
+    assert_eq!(snapshot["tool_latency"], json!({}));
+}
+
+#[tokio::test]
+async fn test_metrics_tools_require_role() {
+    let server = metrics_server();
+
+    for ctx in [
+        RequestContext::new(),
+        RequestContext::new().with_metadata("auth", json!({"roles": ["user"]})),
+    ] {
+        let response = call(&server, METRICS_RESET_TOOL, ctx).await;
+        assert!(response.error.is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_tools_are_off_by_default() {
+    let server = ServerBuilder::new().build();
+
+    let response = call(&server, METRICS_TOOL, admin_ctx()).await;
+    assert!(response.error.is_some());
+    assert!(
+        ServerBuilder::new()
+            .with_metrics_tools(Vec::<String>::new())
+            .is_err()
+    );
+}
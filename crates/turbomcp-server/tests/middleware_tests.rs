@@ -7,7 +7,9 @@ mod common;
 
 use async_trait::async_trait;
 use common::*;
-use turbomcp_core::RequestContext;
+use std::sync::Arc;
+use std::time::Duration;
+use turbomcp_core::{MockClock, RequestContext};
 use turbomcp_protocol::jsonrpc::*;
 use turbomcp_server::ServerResult;
 use turbomcp_server::middleware::*;
@@ -244,6 +246,31 @@ async fn test_middleware_metadata_propagation() {
     assert!(processed_ctx.metadata.contains_key("correlation_id"));
 }
 
+#[tokio::test]
+async fn test_rate_limit_refills_as_clock_advances() {
+    let clock = Arc::new(MockClock::new());
+    let limiter = RateLimitMiddleware::with_clock(
+        RateLimitConfig {
+            requests_per_second: 1,
+            burst_capacity: 2,
+            key_extractor: KeyExtractor::Global,
+        },
+        clock.clone(),
+    );
+    let mut req = create_test_request();
+    let mut ctx = create_test_context();
+
+    for _ in 0..2 {
+        assert!(limiter.process_request(&mut req, &mut ctx).await.is_ok());
+    }
+    assert!(limiter.process_request(&mut req, &mut ctx).await.is_err());
+
+    // One second at one request per second buys exactly one more
+    clock.advance(Duration::from_secs(1));
+    assert!(limiter.process_request(&mut req, &mut ctx).await.is_ok());
+    assert!(limiter.process_request(&mut req, &mut ctx).await.is_err());
+}
+
 // ============================================================================
 // Property-based Tests
 // ============================================================================
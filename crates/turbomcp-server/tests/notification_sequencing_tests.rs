@@ -0,0 +1,60 @@
+//! Tests for numbering the notifications a server sends each session
+
+mod common;
+
+use common::{ChannelTransport, text_result, tool_definition};
+use serde_json::json;
+use std::sync::Arc;
+use turbomcp_client::Client;
+use turbomcp_protocol::notification_sequence;
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::handlers::FunctionToolHandler;
+
+/// Tool reporting three steps to the client
+fn steps_tool() -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("steps"), |_req, ctx| async move {
+        for step in 0..3 {
+            ctx.notify("x/step", json!({ "step": step }));
+        }
+        Ok(text_result("done"))
+    })
+}
+
+/// Call `steps` on a server built by `builder`, returning the sequence
+/// numbers of the notifications received
+async fn step_sequences(builder: ServerBuilder) -> Vec<Option<u64>> {
+    let server = Arc::new(builder.tool("steps", steps_tool()).unwrap().build());
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn(async move { server.run_with_transport(server_transport).await });
+
+    let mut client = Client::new(client_transport);
+    client.initialize().await.unwrap();
+    let mut notifications = client.subscribe_notifications();
+    client.call_tool("steps", None).await.unwrap();
+
+    let mut sequences = Vec::new();
+    while let Ok(notification) = notifications.try_recv() {
+        if notification.method == "x/step" {
+            sequences.push(notification_sequence(&notification));
+        }
+    }
+    assert_eq!(client.notification_ordering_stats().missing, 0);
+    sequences
+}
+
+#[tokio::test]
+async fn test_sequenced_session_numbers_notifications_in_send_order() {
+    let sequences = step_sequences(ServerBuilder::new().with_notification_sequencing()).await;
+    assert_eq!(sequences.len(), 3);
+    let numbers: Vec<u64> = sequences.into_iter().map(Option::unwrap).collect();
+    assert!(
+        numbers.windows(2).all(|pair| pair[1] == pair[0] + 1),
+        "{numbers:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_notifications_are_unnumbered_by_default() {
+    let sequences = step_sequences(ServerBuilder::new()).await;
+    assert_eq!(sequences, vec![None, None, None]);
+}
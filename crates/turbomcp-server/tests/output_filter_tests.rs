@@ -0,0 +1,89 @@
+//! Tests for output filtering of tool text results
+
+use serde_json::{Value, json};
+use turbomcp_core::{MessageId, RequestContext};
+use turbomcp_protocol::jsonrpc::{JsonRpcResponse, JsonRpcVersion};
+use turbomcp_server::middleware::{Middleware, OutputFilterMiddleware};
+use turbomcp_server::{OutputFilterAction, OutputFilterConfig, ServerResult};
+
+fn tool_result(content: Value) -> JsonRpcResponse {
+    JsonRpcResponse {
+        jsonrpc: JsonRpcVersion,
+        result: Some(json!({"content": content, "isError": false})),
+        error: None,
+        id: Some(MessageId::from("call-1")),
+    }
+}
+
+fn filter_config(action: OutputFilterAction) -> OutputFilterConfig {
+    OutputFilterConfig {
+        enabled: true,
+        patterns: vec![r"sk-live-[A-Za-z0-9]+".to_string()],
+        denied_terms: vec!["Project Falcon".to_string()],
+        action,
+        ..OutputFilterConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_matching_text_is_redacted() -> ServerResult<()> {
+    let middleware = OutputFilterMiddleware::new(&filter_config(OutputFilterAction::Redact))?;
+    let mut response = tool_result(json!([
+        {"type": "text", "text": "key=sk-live-abc123 for project falcon"},
+        {"type": "text", "text": "nothing to see"},
+        {"type": "image", "data": "sk-live-abc123", "mimeType": "image/png"}
+    ]));
+
+    middleware
+        .process_response(&mut response, &RequestContext::new())
+        .await?;
+
+    let content = &response.result.as_ref().unwrap()["content"];
+    assert_eq!(content[0]["text"], "key=[REDACTED] for [REDACTED]");
+    assert_eq!(content[1]["text"], "nothing to see");
+    // Only text blocks are inspected
+    assert_eq!(content[2]["data"], "sk-live-abc123");
+    assert!(response.error.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reject_replaces_result_with_error() -> ServerResult<()> {
+    let middleware = OutputFilterMiddleware::new(&filter_config(OutputFilterAction::Reject))?;
+    let mut response = tool_result(json!([{"type": "text", "text": "token sk-live-xyz"}]));
+
+    middleware
+        .process_response(&mut response, &RequestContext::new())
+        .await?;
+
+    assert!(response.result.is_none());
+    let error = response.error.unwrap();
+    assert!(error.message.contains("output filter"));
+    assert!(!error.message.contains("sk-live"));
+    assert_eq!(response.id, Some(MessageId::from("call-1")));
+
+    // Clean results pass through untouched
+    let mut clean = tool_result(json!([{"type": "text", "text": "all good"}]));
+    middleware
+        .process_response(&mut clean, &RequestContext::new())
+        .await?;
+    assert_eq!(clean.result.unwrap()["content"][0]["text"], "all good");
+    Ok(())
+}
+
+#[test]
+fn test_invalid_pattern_is_rejected() {
+    let config = OutputFilterConfig {
+        patterns: vec!["(unclosed".to_string()],
+        ..OutputFilterConfig::default()
+    };
+    assert!(config.validate().is_err());
+    assert!(OutputFilterMiddleware::new(&config).is_err());
+
+    // Denied terms are literal text, not regular expressions
+    let config = OutputFilterConfig {
+        denied_terms: vec!["(unclosed".to_string()],
+        ..OutputFilterConfig::default()
+    };
+    assert!(OutputFilterMiddleware::new(&config).is_ok());
+}
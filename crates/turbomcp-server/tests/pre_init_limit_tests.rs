@@ -0,0 +1,115 @@
+//! Tests for limiting what connections send before initializing
+
+use serde_json::json;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+use turbomcp_server::config::ConfigurationBuilder;
+use turbomcp_server::{McpServer, ServerBuilder};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+const PINGS: usize = 30;
+
+fn pings() -> String {
+    (1..=PINGS)
+        .map(|id| {
+            format!(
+                "{}\n",
+                json!({"jsonrpc": "2.0", "id": id, "method": "ping"})
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_flooding_before_initialize_drops_connection() {
+    let server = ServerBuilder::new().build();
+    let metrics = server.metrics().clone();
+    let (mut client_out, server_in) = tokio::io::duplex(8192);
+    let (server_out, client_in) = tokio::io::duplex(8192);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    let run = tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    client_out.write_all(pings().as_bytes()).await.unwrap();
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("server should drop the flooding connection")
+        .unwrap()
+        .unwrap();
+    assert_eq!(metrics.connections_rejected.load(Ordering::Relaxed), 1);
+
+    // Only the messages within the limit were answered
+    let mut lines = BufReader::new(client_in).lines();
+    let mut answered = 0;
+    while let Ok(Some(_)) = lines.next_line().await {
+        answered += 1;
+    }
+    assert_eq!(answered, 10);
+}
+
+#[tokio::test]
+async fn test_malformed_messages_before_initialize_drop_connection() {
+    let config = ConfigurationBuilder::new().pre_init_limit(100, 2).build();
+    let server = McpServer::new(config);
+    let metrics = server.metrics().clone();
+    let (mut client_out, server_in) = tokio::io::duplex(8192);
+    let (server_out, _client_in) = tokio::io::duplex(8192);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    let run = tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    for id in 1..=3 {
+        let malformed = json!({"method": "ping", "id": id});
+        client_out
+            .write_all(format!("{malformed}\n").as_bytes())
+            .await
+            .unwrap();
+    }
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("server should drop the connection on the third malformed message")
+        .unwrap()
+        .unwrap();
+    assert_eq!(metrics.connections_rejected.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn test_initialized_connection_is_not_limited() {
+    let server = ServerBuilder::new().build();
+    let metrics = server.metrics().clone();
+    let (mut client_out, server_in) = tokio::io::duplex(8192);
+    let (server_out, client_in) = tokio::io::duplex(8192);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    let initialize = json!({
+        "jsonrpc": "2.0",
+        "id": 0,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": {"name": "test-client", "version": "1.0.0"}
+        }
+    });
+    client_out
+        .write_all(format!("{initialize}\n{}", pings()).as_bytes())
+        .await
+        .unwrap();
+
+    let mut lines = BufReader::new(client_in).lines();
+    for _ in 0..=PINGS {
+        let line = timeout(Duration::from_secs(5), lines.next_line())
+            .await
+            .expect("every message should be answered")
+            .unwrap()
+            .unwrap();
+        let response: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert!(response["error"].is_null(), "{response}");
+    }
+    assert_eq!(metrics.connections_rejected.load(Ordering::Relaxed), 0);
+}
@@ -0,0 +1,212 @@
+//! Tests for startup pre-warming of tool handlers
+
+mod common;
+
+use bytes::Bytes;
+use common::{ChannelTransport, Inbox, Outbox, tool_definition};
+use parking_lot::Mutex;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use turbomcp_core::MessageId;
+use turbomcp_protocol::types::CallToolResult;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{McpServer, PrewarmConfig, ServerBuilder, ServerError};
+use turbomcp_transport::core::TransportMessage;
+
+/// Tool whose handler and pre-warm hook are given by the caller
+fn tool<F, Fut>(
+    name: &str,
+    call: impl Fn() + Send + Sync + 'static,
+    prewarm: F,
+) -> FunctionToolHandler
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Result<(), ServerError>> + Send + 'static,
+{
+    let call = Arc::new(call);
+    FunctionToolHandler::new(tool_definition(name), move |_req, _ctx| {
+        call();
+        async move {
+            Ok(CallToolResult {
+                content: Vec::new(),
+                is_error: Some(false),
+                ..Default::default()
+            })
+        }
+    })
+    .with_prewarm(prewarm)
+}
+
+/// Run `server` on a raw connection, returning its ends and the run task
+fn serve(
+    server: McpServer,
+) -> (
+    Inbox,
+    Outbox,
+    tokio::task::JoinHandle<Result<(), ServerError>>,
+) {
+    let (transport, to_server, from_server) = ChannelTransport::raw();
+    let run = tokio::spawn(async move { server.run_with_transport(transport).await });
+    (to_server, from_server, run)
+}
+
+fn send(to_server: &Inbox, request: &Value) {
+    to_server
+        .send(TransportMessage::new(
+            MessageId::from("raw"),
+            Bytes::from(request.to_string()),
+        ))
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_prewarm_runs_before_server_accepts_requests() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let on_call = Arc::clone(&events);
+    let on_prewarm = Arc::clone(&events);
+    let server = ServerBuilder::new()
+        .tool(
+            "classify",
+            tool(
+                "classify",
+                move || on_call.lock().push("call"),
+                move || {
+                    let events = Arc::clone(&on_prewarm);
+                    async move {
+                        // Loading the model takes a while
+                        sleep(Duration::from_millis(100)).await;
+                        events.lock().push("prewarm");
+                        Ok(())
+                    }
+                },
+            ),
+        )
+        .unwrap()
+        .build();
+
+    let (to_server, mut from_server, _run) = serve(server);
+    // Sent while the pre-warm is still running
+    send(
+        &to_server,
+        &json!({"jsonrpc": "2.0", "id": 1, "method": "tools/call", "params": {"name": "classify"}}),
+    );
+
+    let reply = timeout(Duration::from_secs(5), from_server.recv())
+        .await
+        .expect("server should answer")
+        .unwrap();
+    let response: Value = serde_json::from_slice(&reply.payload).unwrap();
+    assert_eq!(response["result"]["isError"], false);
+    assert_eq!(*events.lock(), vec!["prewarm", "call"]);
+}
+
+#[tokio::test]
+async fn test_failed_prewarm_stops_boot() {
+    let server = ServerBuilder::new()
+        .tool(
+            "classify",
+            tool(
+                "classify",
+                || {},
+                || async { Err(ServerError::handler("model file missing")) },
+            ),
+        )
+        .unwrap()
+        .build();
+
+    let (to_server, mut from_server, run) = serve(server);
+    send(
+        &to_server,
+        &json!({"jsonrpc": "2.0", "id": 1, "method": "ping"}),
+    );
+
+    let error = run.await.unwrap().unwrap_err().to_string();
+    assert!(error.contains("classify"), "{error}");
+    assert!(error.contains("model file missing"), "{error}");
+    assert!(from_server.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn test_failed_prewarm_can_warn_and_serve() {
+    let server = ServerBuilder::new()
+        .with_prewarm(PrewarmConfig {
+            fail_on_error: false,
+            ..PrewarmConfig::default()
+        })
+        .tool(
+            "classify",
+            tool(
+                "classify",
+                || {},
+                || async { Err(ServerError::handler("model file missing")) },
+            ),
+        )
+        .unwrap()
+        .build();
+
+    let report = server.prewarm().await.unwrap();
+    assert_eq!(report.warmed, 0);
+    assert_eq!(report.failures[0].tool, "classify");
+
+    let (to_server, mut from_server, _run) = serve(server);
+    send(
+        &to_server,
+        &json!({"jsonrpc": "2.0", "id": 1, "method": "ping"}),
+    );
+    let reply = timeout(Duration::from_secs(5), from_server.recv())
+        .await
+        .expect("server should answer")
+        .unwrap();
+    let response: Value = serde_json::from_slice(&reply.payload).unwrap();
+    assert!(response.get("error").is_none());
+}
+
+#[tokio::test]
+async fn test_prewarm_parallelism_is_bounded_and_runs_once() {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let runs = Arc::new(AtomicUsize::new(0));
+
+    let mut builder = ServerBuilder::new().with_prewarm(PrewarmConfig {
+        max_concurrency: 2,
+        fail_on_error: true,
+    });
+    for i in 0..5 {
+        let (in_flight, peak, runs) =
+            (Arc::clone(&in_flight), Arc::clone(&peak), Arc::clone(&runs));
+        let name = format!("tool_{i}");
+        builder = builder
+            .tool(
+                name.clone(),
+                tool(
+                    &name,
+                    || {},
+                    move || {
+                        let (in_flight, peak, runs) =
+                            (Arc::clone(&in_flight), Arc::clone(&peak), Arc::clone(&runs));
+                        async move {
+                            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                            peak.fetch_max(now, Ordering::SeqCst);
+                            sleep(Duration::from_millis(20)).await;
+                            in_flight.fetch_sub(1, Ordering::SeqCst);
+                            runs.fetch_add(1, Ordering::SeqCst);
+                            Ok(())
+                        }
+                    },
+                ),
+            )
+            .unwrap();
+    }
+    let server = builder.build();
+
+    let report = server.prewarm().await.unwrap();
+    assert!(report.is_ok());
+    assert_eq!(report.warmed, 5);
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+
+    server.prewarm().await.unwrap();
+    assert_eq!(runs.load(Ordering::SeqCst), 5);
+}
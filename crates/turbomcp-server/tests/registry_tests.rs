@@ -6,7 +6,7 @@ use async_trait::async_trait;
 use turbomcp_protocol::types::*;
 use turbomcp_server::handlers::*;
 use turbomcp_server::registry::*;
-use turbomcp_server::{RequestContext, ServerResult};
+use turbomcp_server::{RequestContext, ServerResult, uri_template_params, uri_templates_overlap};
 
 // Mock implementations for testing
 
@@ -45,23 +45,15 @@ impl ToolHandler for MockToolHandler {
                 meta: None,
             })],
             is_error: Some(false),
+            ..Default::default()
         })
     }
 
     fn tool_definition(&self) -> Tool {
         Tool {
             name: self.name.clone(),
-            title: None,
             description: self.description.clone(),
-            input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
-                properties: None,
-                required: None,
-                additional_properties: None,
-            },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         }
     }
 }
@@ -135,6 +127,7 @@ impl ResourceHandler for MockResourceHandler {
                 text: "Mock resource content".to_string(),
                 meta: None,
             })],
+            range: None,
         })
     }
 
@@ -557,6 +550,81 @@ fn test_register_resource_handler_duplicate_uri_validation() {
     assert_eq!(registry.resources.len(), 1);
 }
 
+#[test]
+fn test_register_resource_handler_overlapping_template_validation() {
+    let registry = HandlerRegistry::new();
+    let files = MockResourceHandler::new("files", "file://{path}");
+    let text_files = MockResourceHandler::new("text_files", "file://{name}.txt");
+    assert!(registry.register_resource("files", files).is_ok());
+
+    let error = registry
+        .register_resource("text_files", text_files)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("'file://{name}.txt'"), "{error}");
+    assert!(
+        error.contains("'file://{path}' of resource 'files'"),
+        "{error}"
+    );
+    assert!(error.contains("both match 'file://a.txt'"), "{error}");
+    assert_eq!(registry.resources.len(), 1);
+
+    // A parameter stops at '/', so these never match the same URI
+    let nested = MockResourceHandler::new("nested", "file://{dir}/{name}");
+    assert!(registry.register_resource("nested", nested).is_ok());
+}
+
+#[test]
+fn test_resource_lookup_resolves_uris_on_demand() {
+    use turbomcp_core::ResourceLookup;
+
+    let registry = HandlerRegistry::new();
+    let books = MockResourceHandler::new("books", "books://{isbn}");
+    let notes = MockResourceHandler::new("notes", "notes://today");
+    registry.register_resource("books", books).unwrap();
+    registry.register_resource("notes", notes).unwrap();
+
+    let book = registry.resource_for("books://978-0262510875").unwrap();
+    assert_eq!(book["name"], "books");
+    let note = registry.resource_for("notes://today").unwrap();
+    assert_eq!(note["name"], "notes");
+    assert!(registry.resource_for("films://vertigo").is_none());
+
+    // Resources registered later are found too
+    let films = MockResourceHandler::new("films", "films://{title}");
+    registry.register_resource("films", films).unwrap();
+    assert!(registry.resource_for("films://vertigo").is_some());
+}
+
+#[test]
+fn test_uri_templates_overlap() {
+    assert_eq!(
+        uri_templates_overlap("docs://*", "docs://{id}/raw").as_deref(),
+        Some("docs://b/raw")
+    );
+    assert_eq!(
+        uri_templates_overlap("config://{section}", "config://general").as_deref(),
+        Some("config://general")
+    );
+    assert!(uri_templates_overlap("config://{section}", "config://a/b").is_none());
+    assert!(uri_templates_overlap("a://{x}", "b://{x}").is_none());
+}
+
+#[test]
+fn test_uri_template_params() {
+    let params = uri_template_params("repo://{owner}/{name}.git", "repo://ada/engine.git").unwrap();
+    assert_eq!(params.len(), 2);
+    assert_eq!(params["owner"], "ada");
+    assert_eq!(params["name"], "engine");
+
+    assert!(uri_template_params("repo://{owner}", "repo://a/b").is_none());
+    assert!(
+        uri_template_params("stats://usage", "stats://usage")
+            .unwrap()
+            .is_empty()
+    );
+}
+
 // ============================================================================
 // Sampling Handler Registration Tests
 // ============================================================================
@@ -1237,3 +1305,75 @@ fn test_registry_error_handling() {
     // Only one tool should be registered
     assert_eq!(registry.tools.len(), 1);
 }
+
+#[tokio::test]
+async fn test_registry_query_matches_list_methods() {
+    use serde_json::Value;
+    use turbomcp_protocol::RequestId;
+    use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+    use turbomcp_server::ServerBuilder;
+
+    let server = ServerBuilder::new()
+        .tool(
+            "search",
+            MockToolHandler::with_description("search", "Search the index"),
+        )
+        .unwrap()
+        .tool("echo", MockToolHandler::new("echo"))
+        .unwrap()
+        .prompt("greeting", MockPromptHandler::new("greeting"))
+        .unwrap()
+        .resource(
+            "config",
+            MockResourceHandler::new("config", "file:///config.toml"),
+        )
+        .unwrap()
+        .build();
+    let registry = server.registry();
+
+    let tools = registry.tool_infos();
+    let names: Vec<_> = tools.iter().map(|info| info.name.as_str()).collect();
+    assert_eq!(names, ["echo", "search"]);
+    let search = registry.tool_info("search").unwrap();
+    assert_eq!(
+        search.definition.description.as_deref(),
+        Some("Search the index")
+    );
+    assert_eq!(search.definition.input_schema.schema_type, "object");
+    assert!(search.metadata.unwrap().tags.contains(&"tool".to_string()));
+    assert!(registry.tool_info("missing").is_none());
+
+    assert_eq!(registry.prompt_infos()[0].definition.name, "greeting");
+    let config = registry.resource_info("config").unwrap();
+    assert_eq!(config.definition.uri, "file:///config.toml");
+    assert_eq!(config.metadata.unwrap().name, "config");
+
+    // The definitions are exactly what the list methods put on the wire
+    for (method, key, expected) in [
+        (
+            "tools/list",
+            "tools",
+            serde_json::to_value(registry.get_tool_definitions()).unwrap(),
+        ),
+        (
+            "prompts/list",
+            "prompts",
+            serde_json::to_value(registry.get_prompt_definitions()).unwrap(),
+        ),
+        (
+            "resources/list",
+            "resources",
+            serde_json::to_value(registry.get_resource_definitions()).unwrap(),
+        ),
+    ] {
+        let request = JsonRpcRequest {
+            jsonrpc: JsonRpcVersion,
+            id: RequestId::Number(1),
+            method: method.to_string(),
+            params: None,
+        };
+        let response = server.router().route(request, RequestContext::new()).await;
+        let result: Value = response.result.unwrap();
+        assert_eq!(result[key], expected, "{method}");
+    }
+}
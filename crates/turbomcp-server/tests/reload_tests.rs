@@ -0,0 +1,110 @@
+//! Tests for hot reloading of server configuration
+
+mod common;
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::ChannelTransport;
+use turbomcp_client::Client;
+use turbomcp_core::MockClock;
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::config::{ServerConfig, ToolAccessConfig};
+
+fn temp_config_path(tag: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "turbomcp-reload-{tag}-{}.json",
+        uuid::Uuid::new_v4()
+    ))
+}
+
+#[tokio::test]
+async fn test_reload_changes_log_level() {
+    let path = temp_config_path("log-level");
+    std::fs::write(&path, r#"{"logging": {"level": "debug"}}"#).unwrap();
+
+    let applied_levels = Arc::new(Mutex::new(Vec::new()));
+    let hook_levels = Arc::clone(&applied_levels);
+    let server = ServerBuilder::new()
+        .config_file(&path)
+        .on_log_level_change(move |level| {
+            hook_levels.lock().unwrap().push(level.to_string());
+            Ok(())
+        })
+        .build();
+    assert_eq!(server.current_config().logging.level, "info");
+
+    let report = server.reload_config().await.unwrap();
+
+    assert_eq!(report.applied.len(), 1);
+    assert_eq!(report.applied[0].setting, "logging.level");
+    assert!(report.ignored.is_empty());
+    assert_eq!(server.current_config().logging.level, "debug");
+    assert_eq!(*applied_levels.lock().unwrap(), vec!["debug".to_string()]);
+    // Startup configuration is preserved
+    assert_eq!(server.config().logging.level, "info");
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[tokio::test]
+async fn test_reload_ignores_bind_address_changes() {
+    let server = ServerBuilder::new().build();
+    let mut new_config = ServerConfig::default();
+    new_config.port = 9090;
+    new_config.tool_access = ToolAccessConfig {
+        allowed: Vec::new(),
+        denied: vec!["dangerous".to_string()],
+    };
+
+    let report = server.config_reloader().apply(new_config).await.unwrap();
+
+    assert_eq!(report.ignored.len(), 1);
+    assert_eq!(report.ignored[0].setting, "port");
+    assert_eq!(server.current_config().port, 8080);
+    assert!(!server.router().tool_access().is_allowed("dangerous"));
+}
+
+#[tokio::test]
+async fn test_reload_rejects_invalid_log_level_atomically() {
+    let server = ServerBuilder::new().build();
+    let mut new_config = ServerConfig::default();
+    new_config.logging.level = "chatty".to_string();
+    new_config.rate_limiting.requests_per_second = 1;
+
+    assert!(server.config_reloader().apply(new_config).await.is_err());
+    assert_eq!(server.current_config().logging.level, "info");
+    assert_eq!(
+        server.current_config().rate_limiting.requests_per_second,
+        100
+    );
+}
+
+#[tokio::test]
+async fn test_reload_without_config_file_is_noop() {
+    let server = ServerBuilder::new().build();
+    let report = server.reload_config().await.unwrap();
+    assert!(report.is_empty());
+}
+
+#[tokio::test]
+async fn test_reloaded_rate_limit_refills_by_the_server_clock() {
+    let clock = Arc::new(MockClock::new());
+    let server = Arc::new(ServerBuilder::new().with_clock(clock.clone()).build());
+    let mut new_config = ServerConfig::default();
+    new_config.rate_limiting.requests_per_second = 1;
+    new_config.rate_limiting.burst_capacity = 2;
+    server.config_reloader().apply(new_config).await.unwrap();
+
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    let serving = Arc::clone(&server);
+    tokio::spawn(async move { serving.run_with_transport(server_transport).await });
+    let mut client = Client::new(client_transport);
+    client.initialize().await.unwrap();
+    client.list_tools().await.unwrap();
+    assert!(client.list_tools().await.is_err());
+
+    clock.advance(Duration::from_secs(1));
+    client.list_tools().await.unwrap();
+}
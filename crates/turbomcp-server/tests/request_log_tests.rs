@@ -0,0 +1,142 @@
+//! Tests for durable request logs
+
+mod common;
+
+use async_trait::async_trait;
+use common::{text_result, tool_definition};
+use serde_json::{Value, json};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{RequestLogSink, RequestLogger, RequestRecord, RequestStatus, ServerBuilder};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+fn echo_tool() -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("echo"), |_req, _ctx| async {
+        Ok(text_result("echo"))
+    })
+}
+
+/// Send `message` to a server logging to a fresh file, and return the first
+/// `count` records it logs
+async fn logged_records(message: Value, count: usize) -> Vec<Value> {
+    let path = std::env::temp_dir().join(format!("requests-{}.jsonl", uuid::Uuid::new_v4()));
+    let server = ServerBuilder::new()
+        .tool("echo", echo_tool())
+        .unwrap()
+        .with_request_logger(RequestLogger::json_lines(&path).await.unwrap())
+        .build();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    client_out
+        .write_all(format!("{message}\n").as_bytes())
+        .await
+        .unwrap();
+    let mut lines = BufReader::new(client_in).lines();
+    timeout(Duration::from_secs(5), lines.next_line())
+        .await
+        .expect("server should answer")
+        .unwrap()
+        .unwrap();
+
+    // Records are written in the background, shortly after the response
+    let records = timeout(Duration::from_secs(5), async {
+        loop {
+            let logged = tokio::fs::read_to_string(&path).await.unwrap_or_default();
+            if logged.lines().count() >= count {
+                return logged
+                    .lines()
+                    .take(count)
+                    .map(|line| serde_json::from_str::<Value>(line).unwrap())
+                    .collect::<Vec<_>>();
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("requests should be logged");
+    let _ = std::fs::remove_file(&path);
+    records
+}
+
+fn call_echo(id: u64) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": {"name": "echo", "arguments": {}},
+    })
+}
+
+#[tokio::test]
+async fn test_completed_request_is_logged_as_json_lines() {
+    let records = logged_records(call_echo(7), 1).await;
+    let record = &records[0];
+
+    assert_eq!(record["request_id"], 7);
+    assert_eq!(record["method"], "tools/call");
+    assert_eq!(record["tool"], "echo");
+    assert_eq!(record["status"], "success");
+    assert!(record["duration_ms"].as_f64().unwrap() >= 0.0);
+    assert!(record["timestamp"].is_string());
+    assert!(record["correlation_id"].is_string());
+    assert!(record["session_id"].is_string());
+    assert!(record.get("error_code").is_none());
+}
+
+#[tokio::test]
+async fn test_each_batch_item_is_logged() {
+    let records = logged_records(json!([call_echo(1), call_echo(2)]), 2).await;
+
+    let mut ids: Vec<_> = records
+        .iter()
+        .map(|record| record["request_id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [1, 2]);
+    for record in &records {
+        assert_eq!(record["tool"], "echo");
+        assert_eq!(record["status"], "success");
+    }
+}
+
+/// Sink that never finishes writing
+struct StalledSink;
+
+#[async_trait]
+impl RequestLogSink for StalledSink {
+    async fn write(&self, _record: &RequestRecord) -> std::io::Result<()> {
+        std::future::pending().await
+    }
+}
+
+#[tokio::test]
+async fn test_records_are_dropped_when_sink_falls_behind() {
+    let logger = RequestLogger::with_capacity(StalledSink, 1);
+    let record = RequestRecord {
+        timestamp: chrono::Utc::now(),
+        request_id: json!(1),
+        method: "ping".to_string(),
+        tool: None,
+        duration_ms: 0.1,
+        status: RequestStatus::Success,
+        error_code: None,
+        client_id: None,
+        correlation_id: None,
+        session_id: None,
+    };
+
+    // One record is stuck in the sink and one fills the queue; logging the
+    // rest returns at once and counts them
+    for _ in 0..5 {
+        logger.log(record.clone());
+        tokio::task::yield_now().await;
+    }
+    assert!(logger.dropped() >= 3, "dropped {}", logger.dropped());
+}
@@ -0,0 +1,157 @@
+//! Tests for MIME type inference on resource contents
+
+use async_trait::async_trait;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+use futures::stream;
+use serde_json::{Value, json};
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::types::{ReadResourceRequest, Resource};
+use turbomcp_server::handlers::FunctionResourceHandler;
+use turbomcp_server::{
+    McpServer, MessageId, ResourceStream, ServerBuilder, ServerResult, StreamingResourceHandler,
+};
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR\0\0\0\x01\0\0\0\x01\x08\x06";
+
+/// Streams a PNG image, declaring no MIME type for it
+struct Logo;
+
+#[async_trait]
+impl StreamingResourceHandler for Logo {
+    async fn open(
+        &self,
+        _request: ReadResourceRequest,
+        _ctx: RequestContext,
+    ) -> ServerResult<ResourceStream> {
+        Ok(Box::pin(stream::iter([Ok(Bytes::from_static(PNG))])))
+    }
+
+    fn resource_definition(&self) -> Resource {
+        resource("logo", "file:///assets/logo.png")
+    }
+}
+
+fn resource(name: &str, uri: &str) -> Resource {
+    Resource {
+        name: name.to_string(),
+        title: None,
+        uri: uri.to_string(),
+        description: None,
+        mime_type: None,
+        annotations: None,
+        size: None,
+        meta: None,
+    }
+}
+
+/// Resource at `uri` answering with the given contents entry, URI filled in
+fn fixed(uri: &str, entry: Value) -> FunctionResourceHandler {
+    FunctionResourceHandler::new(resource(uri, uri), move |req, _ctx| {
+        let mut entry = entry.clone();
+        entry["uri"] = json!(req.uri);
+        async move { Ok(serde_json::from_value(json!({"contents": [entry]})).unwrap()) }
+    })
+}
+
+async fn read(server: &McpServer, uri: &str) -> Value {
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "resources/read".to_string(),
+        params: Some(json!({"uri": uri})),
+        id: MessageId::from(1),
+    };
+    let response = server.router().route(request, RequestContext::new()).await;
+    response.result.expect("resource read succeeds")["contents"][0].clone()
+}
+
+#[tokio::test]
+async fn test_png_without_mime_type_is_binary() {
+    let server = ServerBuilder::new()
+        .streaming_resource("logo", Logo)
+        .unwrap()
+        .build();
+
+    let content = read(&server, "file:///assets/logo.png").await;
+    assert_eq!(content["mimeType"], "image/png");
+    assert!(content.get("text").is_none());
+    let blob = BASE64.decode(content["blob"].as_str().unwrap()).unwrap();
+    assert_eq!(blob, PNG);
+}
+
+#[tokio::test]
+async fn test_text_with_binary_extension_stays_text() {
+    let server = ServerBuilder::new()
+        .resource(
+            "report",
+            fixed(
+                "file:///report.PDF?v=2",
+                json!({"text": "Quarterly report"}),
+            ),
+        )
+        .unwrap()
+        .resource("page", fixed("file:///page.html", json!({"text": "<p>"})))
+        .unwrap()
+        .build();
+
+    let content = read(&server, "file:///report.PDF?v=2").await;
+    assert_eq!(content["mimeType"], "text/plain");
+    assert_eq!(content["text"], "Quarterly report");
+    assert!(content.get("blob").is_none());
+    assert_eq!(
+        read(&server, "file:///page.html").await["mimeType"],
+        "text/html"
+    );
+}
+
+#[tokio::test]
+async fn test_text_with_binary_signature_becomes_blob() {
+    let server = ServerBuilder::new()
+        .resource("doc", fixed("data://doc", json!({"text": "%PDF-1.7"})))
+        .unwrap()
+        .build();
+
+    let content = read(&server, "data://doc").await;
+    assert_eq!(content["mimeType"], "application/pdf");
+    assert_eq!(content["blob"], BASE64.encode("%PDF-1.7"));
+}
+
+#[tokio::test]
+async fn test_missing_mime_type_falls_back_by_content() {
+    let gif = BASE64.encode(b"GIF89a\x01\0\x01\0\x80\0\0\xff\xff\xff");
+    let server = ServerBuilder::new()
+        .resource(
+            "dump",
+            fixed("data://dump", json!({"blob": BASE64.encode([0, 1, 2])})),
+        )
+        .unwrap()
+        .resource("frame", fixed("data://frame", json!({"blob": gif})))
+        .unwrap()
+        .resource("note", fixed("note://today", json!({"text": "hello"})))
+        .unwrap()
+        .resource(
+            "report",
+            fixed(
+                "file:///report.bin",
+                json!({"text": "{}", "mimeType": "application/json"}),
+            ),
+        )
+        .unwrap()
+        .build();
+
+    assert_eq!(
+        read(&server, "data://dump").await["mimeType"],
+        "application/octet-stream"
+    );
+    assert_eq!(read(&server, "data://frame").await["mimeType"], "image/gif");
+    assert_eq!(
+        read(&server, "note://today").await["mimeType"],
+        "text/plain"
+    );
+    // A MIME type the handler gave is left alone
+    let report = read(&server, "file:///report.bin").await;
+    assert_eq!(report["mimeType"], "application/json");
+    assert_eq!(report["text"], "{}");
+}
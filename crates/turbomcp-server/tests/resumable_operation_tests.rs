@@ -0,0 +1,207 @@
+//! Tests for re-attaching to long-running operations after reconnecting
+
+mod common;
+
+use common::{text_result, tool_definition};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream, Lines};
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{McpServer, ServerBuilder};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+/// Tool reporting one step of three, then waiting for `proceed` to finish
+fn build_tool(proceed: Arc<Notify>) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("build"), move |_req, ctx| {
+        let proceed = Arc::clone(&proceed);
+        async move {
+            ctx.notify_progress(1.0, Some(3.0), Some("compiling"));
+            proceed.notified().await;
+            ctx.notify_progress(2.0, Some(3.0), Some("linking"));
+            ctx.notify_progress(3.0, Some(3.0), Some("packaging"));
+            Ok(text_result("built"))
+        }
+    })
+}
+
+/// One client connection to the server
+struct Connection {
+    writer: DuplexStream,
+    lines: Lines<BufReader<DuplexStream>>,
+}
+
+impl Connection {
+    async fn open(server: &Arc<McpServer>) -> Self {
+        let (writer, server_in) = tokio::io::duplex(4096);
+        let (server_out, reader) = tokio::io::duplex(4096);
+        let mut transport = StdioTransport::with_io(server_in, server_out);
+        transport.connect().await.unwrap();
+        let server = Arc::clone(server);
+        tokio::spawn(async move { server.run_with_transport(transport).await });
+        Self {
+            writer,
+            lines: BufReader::new(reader).lines(),
+        }
+    }
+
+    async fn send(&mut self, message: Value) {
+        self.writer
+            .write_all(format!("{message}\n").as_bytes())
+            .await
+            .unwrap();
+    }
+
+    async fn next(&mut self) -> Value {
+        let line = timeout(Duration::from_secs(5), self.lines.next_line())
+            .await
+            .expect("server should send a message")
+            .unwrap()
+            .unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    async fn request(&mut self, id: u64, method: &str, params: Value) -> Value {
+        self.send(json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}))
+            .await;
+        loop {
+            let message = self.next().await;
+            if message["id"] == id {
+                return message;
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_client_reattaches_to_operation_after_reconnect() {
+    let proceed = Arc::new(Notify::new());
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("build", build_tool(Arc::clone(&proceed)))
+            .unwrap()
+            .build(),
+    );
+
+    // Start the build, see its first progress, then lose the connection
+    let mut first = Connection::open(&server).await;
+    first
+        .send(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": "build",
+                "arguments": {},
+                "_meta": {"operationId": "build-42", "progressToken": "first"}
+            }
+        }))
+        .await;
+    let progress = first.next().await;
+    assert_eq!(progress["method"], "notifications/progress");
+    assert_eq!(progress["params"]["progressToken"], "first");
+    drop(first);
+
+    // The build is still running when the client comes back
+    let mut second = Connection::open(&server).await;
+    let status = second
+        .request(1, "operations/get", json!({"operationId": "build-42"}))
+        .await;
+    assert_eq!(status["result"]["state"], "running");
+    assert_eq!(status["result"]["method"], "tools/call");
+    assert_eq!(status["result"]["progress"], 1.0);
+    assert_eq!(status["result"]["total"], 3.0);
+    assert_eq!(status["result"]["message"], "compiling");
+
+    let resumed = second
+        .request(
+            2,
+            "operations/resume",
+            json!({"operationId": "build-42", "progressToken": "second"}),
+        )
+        .await;
+    assert_eq!(resumed["result"]["state"], "running");
+
+    // The remaining progress arrives on the new connection, under its token
+    proceed.notify_one();
+    for (step, message) in [(2.0, "linking"), (3.0, "packaging")] {
+        let progress = second.next().await;
+        assert_eq!(progress["method"], "notifications/progress");
+        assert_eq!(progress["params"]["progressToken"], "second");
+        assert_eq!(progress["params"]["progress"], step);
+        assert_eq!(progress["params"]["message"], message);
+    }
+    let complete = second.next().await;
+    assert_eq!(complete["method"], "notifications/progress/complete");
+    assert_eq!(complete["params"]["progressToken"], "second");
+    assert_eq!(complete["params"]["status"], "success");
+
+    // The result waits for the client to collect it
+    let status = second
+        .request(3, "operations/get", json!({"operationId": "build-42"}))
+        .await;
+    assert_eq!(status["result"]["state"], "completed");
+    assert_eq!(status["result"]["result"]["content"][0]["text"], "built");
+}
+
+#[tokio::test]
+async fn test_finished_operations_are_forgotten_after_retention() {
+    let proceed = Arc::new(Notify::new());
+    proceed.notify_one();
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("build", build_tool(proceed))
+            .unwrap()
+            .with_operation_retention(Duration::from_millis(50))
+            .build(),
+    );
+    let mut client = Connection::open(&server).await;
+    let response = client
+        .request(
+            1,
+            "tools/call",
+            json!({"name": "build", "arguments": {}, "_meta": {"operationId": "build-7"}}),
+        )
+        .await;
+    assert!(response["error"].is_null());
+    let operations = server.router().operations();
+    assert_eq!(operations.len(), 1);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(operations.is_empty());
+    let unknown = client
+        .request(2, "operations/resume", json!({"operationId": "build-7"}))
+        .await;
+    assert_eq!(unknown["error"]["code"], -32004);
+}
+
+#[tokio::test]
+async fn test_retention_set_through_a_router_clone_applies_to_the_server() {
+    let proceed = Arc::new(Notify::new());
+    proceed.notify_one();
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("build", build_tool(proceed))
+            .unwrap()
+            .build(),
+    );
+    server
+        .router()
+        .clone()
+        .set_operation_retention(Duration::from_millis(50));
+    let mut client = Connection::open(&server).await;
+    let response = client
+        .request(
+            1,
+            "tools/call",
+            json!({"name": "build", "arguments": {}, "_meta": {"operationId": "build-8"}}),
+        )
+        .await;
+    assert!(response["error"].is_null());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(server.router().operations().is_empty());
+}
@@ -5,9 +5,12 @@ use serde_json::json;
 use std::sync::Arc;
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::{jsonrpc::*, types::*};
-use turbomcp_server::ServerResult;
+use turbomcp_server::middleware::{
+    AuthConfig, AuthContext, AuthProvider, AuthScheme, AuthenticationMiddleware, MiddlewareStack,
+};
 use turbomcp_server::registry::HandlerRegistry;
 use turbomcp_server::routing::*;
+use turbomcp_server::{ServerError, ServerResult};
 
 // Simple mock route handler
 #[derive(Debug)]
@@ -496,3 +499,51 @@ async fn test_simple_route_handler_handle() {
     assert!(response.result.is_some());
     assert!(response.error.is_none());
 }
+
+// Auth provider that rejects every request
+struct RejectAllAuthProvider;
+
+#[async_trait]
+impl AuthProvider for RejectAllAuthProvider {
+    async fn authenticate(&self, _request: &JsonRpcRequest) -> ServerResult<AuthContext> {
+        Err(ServerError::authentication("no credentials"))
+    }
+
+    async fn validate_token(&self, _token: &str) -> ServerResult<AuthContext> {
+        Err(ServerError::authentication("no credentials"))
+    }
+}
+
+#[tokio::test]
+async fn test_ping_on_uninitialized_unauthenticated_session() {
+    // Custom skip list that does not mention ping: it must still bypass auth
+    let mut stack = MiddlewareStack::new();
+    stack.add(AuthenticationMiddleware::with_config(
+        RejectAllAuthProvider,
+        AuthConfig {
+            skip_methods: Vec::new(),
+            scheme: AuthScheme::Bearer,
+            token_expiry: std::time::Duration::from_secs(60),
+        },
+    ));
+
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::String("ping-1".to_string()),
+        method: "ping".to_string(),
+        params: None,
+    };
+
+    let (request, ctx) = stack
+        .process_request(request, create_test_context())
+        .await
+        .expect("ping must bypass authentication");
+
+    // No initialize has been routed through this router
+    let router = RequestRouter::new(Arc::new(HandlerRegistry::new()));
+    let response = router.route(request, ctx).await;
+
+    assert!(response.error.is_none());
+    assert_eq!(response.id, Some(RequestId::String("ping-1".to_string())));
+    assert_eq!(response.result, Some(json!({})));
+}
@@ -0,0 +1,91 @@
+//! Tests for the startup self-check of registered handlers
+
+mod common;
+
+use common::tool_definition;
+use serde_json::json;
+use std::collections::HashMap;
+use turbomcp_protocol::types::CallToolResult;
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::handlers::FunctionToolHandler;
+
+fn tool_with_schema(
+    name: &str,
+    properties: HashMap<String, serde_json::Value>,
+    required: Vec<String>,
+) -> FunctionToolHandler {
+    let mut tool = tool_definition(name);
+    tool.input_schema.properties = Some(properties);
+    tool.input_schema.required = Some(required);
+    FunctionToolHandler::new(tool, |_req, _ctx| async move {
+        Ok(CallToolResult {
+            content: Vec::new(),
+            is_error: Some(false),
+            ..Default::default()
+        })
+    })
+}
+
+#[test]
+fn test_valid_server_passes() {
+    let server = ServerBuilder::new()
+        .tool(
+            "add",
+            tool_with_schema(
+                "add",
+                HashMap::from([
+                    ("a".to_string(), json!({"type": "number"})),
+                    ("b".to_string(), json!({"type": "number"})),
+                ]),
+                vec!["a".to_string(), "b".to_string()],
+            ),
+        )
+        .unwrap()
+        .build();
+
+    assert!(server.self_check().is_ok());
+    assert!(server.validate().is_ok());
+}
+
+#[test]
+fn test_invalid_schema_fails_validation_with_tool_name() {
+    let builder = ServerBuilder::new()
+        .tool(
+            "broken",
+            tool_with_schema(
+                "broken",
+                HashMap::from([("x".to_string(), json!({"type": "strng"}))]),
+                Vec::new(),
+            ),
+        )
+        .unwrap();
+
+    let err = builder.validate().unwrap_err().to_string();
+    assert!(err.contains("broken"), "error should name the tool: {err}");
+
+    let report = builder.build().self_check();
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(report.issues[0].kind, "tool");
+    assert!(report.issues[0].problem.contains("not valid JSON Schema"));
+}
+
+#[test]
+fn test_undeclared_required_field_is_reported() {
+    let server = ServerBuilder::new()
+        .tool(
+            "lookup",
+            tool_with_schema(
+                "lookup",
+                HashMap::from([("id".to_string(), json!({"type": "string"}))]),
+                vec!["id".to_string(), "iid".to_string()],
+            ),
+        )
+        .unwrap()
+        .build();
+
+    let report = server.self_check();
+    assert!(!report.is_ok());
+    assert_eq!(report.issues.len(), 1);
+    assert!(report.issues[0].problem.contains("'iid'"));
+    assert!(report.to_string().contains("tool 'lookup'"));
+}
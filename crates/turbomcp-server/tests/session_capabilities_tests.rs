@@ -0,0 +1,127 @@
+//! Tests for per-session capability negotiation
+
+mod common;
+
+use common::{ChannelTransport, text_result, tool_definition};
+use std::sync::Arc;
+use turbomcp_client::{Client, ClientBuilder};
+use turbomcp_protocol::types::{ServerCapabilities, Tool};
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::session::method_permitted;
+use turbomcp_server::{McpServer, ServerBuilder};
+
+fn echo_tool() -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some("Echo the input back".to_string()),
+        ..tool_definition("echo")
+    };
+    FunctionToolHandler::new(tool, |_req, _ctx| async move { Ok(text_result("echo")) })
+}
+
+/// Connect a new client to `server` over its own transport
+fn connect(server: &Arc<McpServer>, tier: &str) -> Client<ChannelTransport> {
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn({
+        let server = Arc::clone(server);
+        async move { server.run_with_transport(server_transport).await }
+    });
+    ClientBuilder::new()
+        .with_session_meta("tier", tier)
+        .build(client_transport)
+}
+
+#[tokio::test]
+async fn test_clients_negotiate_different_capabilities() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("echo", echo_tool())
+            .unwrap()
+            .with_session_capabilities(|request, _ctx, defaults| {
+                let premium = request
+                    .meta
+                    .as_ref()
+                    .and_then(|meta| meta.get("tier"))
+                    .is_some_and(|tier| tier == "premium");
+                if premium {
+                    defaults
+                } else {
+                    ServerCapabilities {
+                        tools: None,
+                        ..defaults
+                    }
+                }
+            })
+            .build(),
+    );
+
+    let mut premium = connect(&server, "premium");
+    let mut basic = connect(&server, "basic");
+    premium.initialize().await.unwrap();
+    basic.initialize().await.unwrap();
+
+    // Each client is told what its own session may use
+    assert!(
+        premium
+            .session_info()
+            .unwrap()
+            .server_capabilities
+            .tools
+            .is_some()
+    );
+    assert!(
+        basic
+            .session_info()
+            .unwrap()
+            .server_capabilities
+            .tools
+            .is_none()
+    );
+
+    // ...and the server holds each session to it
+    assert_eq!(premium.list_tools().await.unwrap(), ["echo"]);
+    let result = premium.call_tool("echo", None).await.unwrap();
+    assert_eq!(result["content"][0]["text"], "echo");
+
+    let err = basic.list_tools().await.unwrap_err();
+    assert!(err.to_string().contains("-32005"), "{err}");
+    let err = basic.call_tool("echo", None).await.unwrap_err();
+    assert!(err.to_string().contains("not available"), "{err}");
+
+    // Methods outside the capability groups stay open to everyone
+    basic.raw_request("ping", None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_without_hook_every_session_gets_the_defaults() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("echo", echo_tool())
+            .unwrap()
+            .build(),
+    );
+    let mut client = connect(&server, "basic");
+    client.initialize().await.unwrap();
+    assert!(
+        client
+            .session_info()
+            .unwrap()
+            .server_capabilities
+            .tools
+            .is_some()
+    );
+    assert_eq!(client.list_tools().await.unwrap(), ["echo"]);
+}
+
+#[test]
+fn test_methods_map_to_capability_groups() {
+    let tools_only = ServerCapabilities {
+        tools: Some(Default::default()),
+        ..Default::default()
+    };
+    assert!(method_permitted(&tools_only, "tools/call"));
+    assert!(method_permitted(&tools_only, "ping"));
+    assert!(method_permitted(&tools_only, "x/custom"));
+    assert!(!method_permitted(&tools_only, "prompts/get"));
+    assert!(!method_permitted(&tools_only, "resources/read"));
+    assert!(!method_permitted(&tools_only, "logging/setLevel"));
+}
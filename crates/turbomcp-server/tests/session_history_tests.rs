@@ -0,0 +1,95 @@
+//! Tests for per-session request history
+
+mod common;
+
+use common::{text_result, tool_definition};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::time::timeout;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{RequestStatus, ServerBuilder, SessionHistory};
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+fn login_tool() -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("login"), |_req, _ctx| async {
+        Ok(text_result("welcome"))
+    })
+}
+
+#[tokio::test]
+async fn test_history_keeps_last_requests_of_session() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("login", login_tool())
+            .unwrap()
+            .with_session_history(SessionHistory::new(3))
+            .build(),
+    );
+    let history = server.session_history().unwrap().clone();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    let run = tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(transport).await }
+    });
+
+    let mut lines = BufReader::new(client_in).lines();
+    for id in 1..=5 {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": {"name": "login", "arguments": {"user": "ada", "password": "hunter2"}},
+        });
+        client_out
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .unwrap();
+        timeout(Duration::from_secs(5), lines.next_line())
+            .await
+            .expect("server should answer")
+            .unwrap()
+            .unwrap();
+    }
+
+    let sessions = history.sessions();
+    assert_eq!(sessions.len(), 1);
+    let entries = history.session(&sessions[0]);
+
+    // Only the last three are kept, oldest first
+    let ids: Vec<_> = entries
+        .iter()
+        .map(|entry| entry.request_id.as_str())
+        .collect();
+    assert_eq!(ids, ["3", "4", "5"]);
+    for entry in &entries {
+        assert_eq!(entry.method, "tools/call");
+        assert_eq!(entry.tool.as_deref(), Some("login"));
+        assert_eq!(entry.status, RequestStatus::Success);
+        assert!(entry.duration_ms >= 0.0);
+        let arguments = entry.arguments.as_deref().unwrap();
+        assert!(arguments.contains("ada"), "{arguments}");
+        assert!(arguments.contains("[REDACTED]"), "{arguments}");
+        assert!(!arguments.contains("hunter2"), "{arguments}");
+    }
+
+    // The history goes with the session
+    drop(client_out);
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("session should end when the client hangs up")
+        .unwrap()
+        .unwrap();
+    assert!(history.sessions().is_empty());
+    assert!(history.session(&sessions[0]).is_empty());
+}
+
+#[test]
+fn test_history_is_disabled_by_default() {
+    assert!(ServerBuilder::new().build().session_history().is_none());
+}
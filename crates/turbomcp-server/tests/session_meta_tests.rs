@@ -0,0 +1,108 @@
+//! Tests for metadata exchanged during initialize
+
+mod common;
+
+use common::ChannelTransport;
+use serde_json::json;
+use std::sync::Arc;
+use turbomcp_client::ClientBuilder;
+use turbomcp_protocol::types::MAX_INITIALIZE_META_SIZE;
+use turbomcp_server::{CLIENT_META_KEY, ServerBuilder};
+
+#[tokio::test]
+async fn test_initialize_meta_round_trip() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_session_meta("region", "eu-west-1")
+            .unwrap()
+            .with_method("x/client-meta", |_params, ctx| async move {
+                Ok(ctx
+                    .get_metadata(CLIENT_META_KEY)
+                    .cloned()
+                    .unwrap_or_default())
+            })
+            .unwrap()
+            .build(),
+    );
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(server_transport).await }
+    });
+    let mut client = ClientBuilder::new()
+        .with_session_meta("tenant", "acme")
+        .build(client_transport);
+
+    client.initialize().await.unwrap();
+    let info = client.session_info().unwrap();
+    assert_eq!(info.meta.get("region"), Some(&json!("eu-west-1")));
+
+    let seen = client.raw_request("x/client-meta", None).await.unwrap();
+    assert_eq!(seen, json!({"tenant": "acme"}));
+}
+
+#[tokio::test]
+async fn test_initialize_without_meta_leaves_it_absent() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_method("x/client-meta", |_params, ctx| async move {
+                Ok(json!(ctx.get_metadata(CLIENT_META_KEY).is_some()))
+            })
+            .unwrap()
+            .build(),
+    );
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(server_transport).await }
+    });
+    let mut client = ClientBuilder::new().build(client_transport);
+
+    client.initialize().await.unwrap();
+    assert!(client.session_info().unwrap().meta.is_empty());
+    let seen = client.raw_request("x/client-meta", None).await.unwrap();
+    assert_eq!(seen, json!(false));
+}
+
+#[tokio::test]
+async fn test_each_session_sees_only_its_own_client_meta() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_method("x/client-meta", |_params, ctx| async move {
+                Ok(ctx
+                    .get_metadata(CLIENT_META_KEY)
+                    .cloned()
+                    .unwrap_or_default())
+            })
+            .unwrap()
+            .build(),
+    );
+    let mut clients = Vec::new();
+    let mut server_transports: Vec<Box<dyn Transport>> = Vec::new();
+    for tenant in ["acme", "globex"] {
+        let (client_transport, server_transport) = ChannelTransport::pair();
+        server_transports.push(Box::new(server_transport));
+        clients.push(
+            ClientBuilder::new()
+                .with_session_meta("tenant", tenant)
+                .build(client_transport),
+        );
+    }
+    tokio::spawn(async move { server.run_with_transports(server_transports).await });
+    for client in &mut clients {
+        client.initialize().await.unwrap();
+    }
+
+    // The second initialize must not have replaced the first session's metadata
+    for (client, tenant) in clients.iter_mut().zip(["acme", "globex"]) {
+        let seen = client.raw_request("x/client-meta", None).await.unwrap();
+        assert_eq!(seen, json!({ "tenant": tenant }));
+    }
+}
+
+#[test]
+fn test_oversized_server_meta_is_rejected() {
+    let result =
+        ServerBuilder::new().with_session_meta("blob", "x".repeat(MAX_INITIALIZE_META_SIZE));
+    assert!(result.is_err());
+}
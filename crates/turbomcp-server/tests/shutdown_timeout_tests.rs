@@ -0,0 +1,93 @@
+//! Tests for bounded server shutdown
+
+mod common;
+
+use common::{ScriptedTransport, tool_definition};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use turbomcp_protocol::types::{CallToolResult, Tool};
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::{ServerBuilder, ServerResult};
+
+fn stuck_tool(started: Arc<Notify>) -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some("Never finishes".to_string()),
+        ..tool_definition("stuck")
+    };
+    FunctionToolHandler::new(tool, move |_req, _ctx| {
+        let started = Arc::clone(&started);
+        async move {
+            started.notify_one();
+            std::future::pending::<ServerResult<CallToolResult>>().await
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_stuck_handler_is_abandoned_after_shutdown_timeout() {
+    let started = Arc::new(Notify::new());
+    let shutdown_timeout = Duration::from_millis(200);
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_shutdown_timeout(shutdown_timeout)
+            .tool("stuck", stuck_tool(Arc::clone(&started)))
+            .unwrap()
+            .build(),
+    );
+    let transport = ScriptedTransport::new(&[
+        r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"stuck","arguments":{}}}"#,
+    ])
+    .then_idle();
+
+    let run = tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run_with_transport(transport).await }
+    });
+
+    timeout(Duration::from_secs(5), started.notified())
+        .await
+        .expect("handler should start");
+    let shutdown_started = Instant::now();
+    server.shutdown_handle().shutdown().await;
+
+    let result = timeout(Duration::from_secs(5), run)
+        .await
+        .expect("shutdown must not hang on a stuck handler")
+        .unwrap();
+
+    assert!(result.is_ok());
+    assert!(shutdown_started.elapsed() >= shutdown_timeout);
+}
+
+#[tokio::test]
+async fn test_idle_server_shuts_down_promptly() {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_shutdown_timeout(Duration::from_secs(30))
+            .build(),
+    );
+    assert_eq!(
+        server.config().timeouts.shutdown_timeout,
+        Duration::from_secs(30)
+    );
+
+    let run = tokio::spawn({
+        let server = Arc::clone(&server);
+        async move {
+            server
+                .run_with_transport(ScriptedTransport::new(&[]).then_idle())
+                .await
+        }
+    });
+    // Give the loop a moment to subscribe to the shutdown signal
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    server.shutdown_handle().shutdown().await;
+
+    let result = timeout(Duration::from_secs(5), run)
+        .await
+        .expect("idle server should stop well before the shutdown timeout")
+        .unwrap();
+    assert!(result.is_ok());
+}
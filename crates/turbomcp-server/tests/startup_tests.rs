@@ -0,0 +1,88 @@
+//! Tests for startup tasks gating server readiness
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::timeout;
+use turbomcp_server::{ServerBuilder, ServerError};
+
+#[tokio::test]
+async fn test_server_is_not_ready_until_startup_task_completes() {
+    let connected = Arc::new(Notify::new());
+    let gate = Arc::clone(&connected);
+    let server = Arc::new(
+        ServerBuilder::new()
+            .with_startup_task("database", move || {
+                let gate = Arc::clone(&gate);
+                async move {
+                    gate.notified().await;
+                    Ok(())
+                }
+            })
+            .build(),
+    );
+    let readiness = server.readiness();
+    assert!(!server.is_ready());
+
+    let starting = Arc::clone(&server);
+    let startup = tokio::spawn(async move { starting.startup().await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!readiness.is_ready());
+    assert!(!startup.is_finished());
+
+    // The database connection comes up
+    connected.notify_one();
+    timeout(Duration::from_secs(5), readiness.wait())
+        .await
+        .expect("server should become ready");
+    startup.await.unwrap().unwrap();
+    assert!(server.is_ready());
+
+    // Tasks run once; starting again is immediate
+    timeout(Duration::from_secs(1), server.startup())
+        .await
+        .expect("startup should not rerun the task")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_failed_startup_task_stops_boot() {
+    let server = ServerBuilder::new()
+        .with_startup_task("config", || async { Ok(()) })
+        .with_startup_task("database", || async {
+            Err(ServerError::handler("connection refused"))
+        })
+        .build();
+
+    let error = server.startup().await.unwrap_err();
+    assert!(matches!(error, ServerError::Lifecycle(_)));
+    let message = error.to_string();
+    assert!(message.contains("'database'"), "{message}");
+    assert!(message.contains("connection refused"), "{message}");
+    assert!(!server.is_ready());
+}
+
+#[tokio::test]
+async fn test_startup_task_times_out() {
+    let server = ServerBuilder::new()
+        .with_startup_timeout(Duration::from_millis(50))
+        .with_startup_task("remote_config", || std::future::pending())
+        .build();
+
+    let error = timeout(Duration::from_secs(5), server.startup())
+        .await
+        .expect("startup should give up at its timeout")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("'remote_config'"), "{error}");
+    assert!(error.contains("did not complete"), "{error}");
+    assert!(!server.is_ready());
+}
+
+#[tokio::test]
+async fn test_server_without_startup_tasks_becomes_ready() {
+    let server = ServerBuilder::new().build();
+    assert!(!server.is_ready());
+    server.startup().await.unwrap();
+    assert!(server.is_ready());
+}
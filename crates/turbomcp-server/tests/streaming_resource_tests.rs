@@ -0,0 +1,250 @@
+//! Tests for resources that deliver their content as a stream of chunks
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::StreamExt;
+use futures::stream;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::types::{ReadResourceRequest, ReadResourceResult, Resource};
+use turbomcp_server::handlers::FunctionResourceHandler;
+use turbomcp_server::{
+    MessageId, ResourceStream, ServerBuilder, ServerResult, StreamingResource,
+    StreamingResourceHandler,
+};
+
+/// Streams `chunks` numbered lines, counting how many have been produced
+struct NumberedLines {
+    chunks: usize,
+    produced: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl StreamingResourceHandler for NumberedLines {
+    async fn open(
+        &self,
+        _request: ReadResourceRequest,
+        _ctx: RequestContext,
+    ) -> ServerResult<ResourceStream> {
+        let produced = Arc::clone(&self.produced);
+        Ok(Box::pin(stream::iter(0..self.chunks).map(move |i| {
+            produced.fetch_add(1, Ordering::SeqCst);
+            Ok(Bytes::from(format!("line {i}\n")))
+        })))
+    }
+
+    fn resource_definition(&self) -> Resource {
+        resource("lines", "log://lines")
+    }
+}
+
+fn resource(name: &str, uri: &str) -> Resource {
+    Resource {
+        name: name.to_string(),
+        title: None,
+        uri: uri.to_string(),
+        description: None,
+        mime_type: Some("text/plain".to_string()),
+        annotations: None,
+        size: None,
+        meta: None,
+    }
+}
+
+fn read_request(uri: &str) -> ReadResourceRequest {
+    ReadResourceRequest::new(uri)
+}
+
+#[tokio::test]
+async fn test_streaming_resource_delivers_chunks_lazily() {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let server = ServerBuilder::new()
+        .streaming_resource(
+            "lines",
+            NumberedLines {
+                chunks: 3,
+                produced: Arc::clone(&produced),
+            },
+        )
+        .unwrap()
+        .build();
+
+    let mut stream = server
+        .router()
+        .open_resource_stream(read_request("log://lines"), RequestContext::new())
+        .await
+        .unwrap();
+    assert_eq!(produced.load(Ordering::SeqCst), 0);
+
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first, Bytes::from("line 0\n"));
+    assert_eq!(produced.load(Ordering::SeqCst), 1);
+
+    let rest: Vec<Bytes> = stream.map(Result::unwrap).collect().await;
+    assert_eq!(rest, vec![Bytes::from("line 1\n"), Bytes::from("line 2\n")]);
+}
+
+#[tokio::test]
+async fn test_streaming_resource_is_buffered_for_resources_read() {
+    let server = ServerBuilder::new()
+        .streaming_resource(
+            "lines",
+            NumberedLines {
+                chunks: 3,
+                produced: Arc::new(AtomicUsize::new(0)),
+            },
+        )
+        .unwrap()
+        .build();
+
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "resources/read".to_string(),
+        params: Some(json!({"uri": "log://lines"})),
+        id: MessageId::from(1),
+    };
+    let response = server.router().route(request, RequestContext::new()).await;
+    let result: ReadResourceResult = serde_json::from_value(response.result.unwrap()).unwrap();
+    let contents = serde_json::to_value(&result.contents).unwrap();
+    assert_eq!(contents[0]["text"], "line 0\nline 1\nline 2\n");
+    assert_eq!(contents[0]["mimeType"], "text/plain");
+}
+
+#[tokio::test]
+async fn test_buffered_read_enforces_size_limit() {
+    let handler = StreamingResource::new(NumberedLines {
+        chunks: 100,
+        produced: Arc::new(AtomicUsize::new(0)),
+    })
+    .with_max_buffered_size(64);
+    let server = ServerBuilder::new()
+        .resource("lines", handler)
+        .unwrap()
+        .build();
+
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "resources/read".to_string(),
+        params: Some(json!({"uri": "log://lines"})),
+        id: MessageId::from(1),
+    };
+    let response = server.router().route(request, RequestContext::new()).await;
+    assert!(response.error.unwrap().message.contains("stream"));
+
+    // The stream itself is not subject to the buffering limit
+    let chunks = server
+        .router()
+        .open_resource_stream(read_request("log://lines"), RequestContext::new())
+        .await
+        .unwrap()
+        .count()
+        .await;
+    assert_eq!(chunks, 100);
+}
+
+#[tokio::test]
+async fn test_owned_resource_streams_as_single_chunk() {
+    let handler =
+        FunctionResourceHandler::new(resource("note", "note://a"), |req, _ctx| async move {
+            Ok(serde_json::from_value(json!({
+                "contents": [{"uri": req.uri, "text": "hello"}]
+            }))
+            .unwrap())
+        });
+    let server = ServerBuilder::new()
+        .resource("note", handler)
+        .unwrap()
+        .build();
+
+    let chunks: Vec<Bytes> = server
+        .router()
+        .open_resource_stream(read_request("note://a"), RequestContext::new())
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(chunks, vec![Bytes::from("hello")]);
+
+    let missing = server
+        .router()
+        .open_resource_stream(read_request("note://missing"), RequestContext::new())
+        .await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_ranged_read_returns_only_the_slice() {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let server = ServerBuilder::new()
+        .streaming_resource(
+            "lines",
+            NumberedLines {
+                chunks: 100,
+                produced: Arc::clone(&produced),
+            },
+        )
+        .unwrap()
+        .build();
+
+    // Each line is 7 bytes; read lines 2 and 3 plus the first byte of line 4
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "resources/read".to_string(),
+        params: Some(json!({"uri": "log://lines", "offset": 14, "length": 15})),
+        id: MessageId::from(1),
+    };
+    let response = server.router().route(request, RequestContext::new()).await;
+    let result: ReadResourceResult = serde_json::from_value(response.result.unwrap()).unwrap();
+    let contents = serde_json::to_value(&result.contents).unwrap();
+    assert_eq!(contents[0]["text"], "line 2\nline 3\nl");
+    let range = result.range.unwrap();
+    assert_eq!((range.offset, range.length), (14, 15));
+    // The stream is not read past the end of the range
+    assert_eq!(produced.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn test_ranged_read_of_unranged_resource() {
+    let handler =
+        FunctionResourceHandler::new(resource("note", "note://a"), |req, _ctx| async move {
+            Ok(serde_json::from_value(json!({
+                "contents": [{"uri": req.uri, "text": "hello world"}]
+            }))
+            .unwrap())
+        });
+    let server = ServerBuilder::new()
+        .resource("note", handler)
+        .unwrap()
+        .build();
+
+    // Over `resources/read` the handler's full content comes back without a range
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        method: "resources/read".to_string(),
+        params: Some(json!({"uri": "note://a", "offset": 6})),
+        id: MessageId::from(1),
+    };
+    let response = server.router().route(request, RequestContext::new()).await;
+    let result: ReadResourceResult = serde_json::from_value(response.result.unwrap()).unwrap();
+    let contents = serde_json::to_value(&result.contents).unwrap();
+    assert_eq!(contents[0]["text"], "hello world");
+    assert!(result.range.is_none());
+
+    // In-process streams are sliced by the router instead
+    let chunks: Vec<Bytes> = server
+        .router()
+        .open_resource_stream(
+            read_request("note://a").with_range(6, Some(3)),
+            RequestContext::new(),
+        )
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(chunks, vec![Bytes::from("wor")]);
+}
@@ -0,0 +1,206 @@
+//! Tests for opt-in tool result caching
+
+mod common;
+
+use common::tool_definition;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use turbomcp_core::{MockClock, RequestContext};
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::types::{CallToolResult, Content, TextContent, Tool};
+use turbomcp_server::ToolResultCache;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::registry::HandlerRegistry;
+use turbomcp_server::routing::RequestRouter;
+
+fn counting_tool(calls: Arc<AtomicUsize>) -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some("Square a number".to_string()),
+        ..tool_definition("square")
+    };
+    FunctionToolHandler::new(tool, move |req, _ctx| {
+        let calls = Arc::clone(&calls);
+        async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            let x = req
+                .arguments
+                .as_ref()
+                .and_then(|args| args.get("x"))
+                .and_then(serde_json::Value::as_i64)
+                .unwrap_or_default();
+            Ok(CallToolResult {
+                content: vec![Content::Text(TextContent {
+                    text: (x * x).to_string(),
+                    annotations: None,
+                    meta: None,
+                })],
+                is_error: Some(false),
+                ..Default::default()
+            })
+        }
+    })
+}
+
+fn call_request(id: i64, arguments: serde_json::Value) -> JsonRpcRequest {
+    JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(id),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": "square", "arguments": arguments})),
+    }
+}
+
+fn router_with(handler: FunctionToolHandler) -> RequestRouter {
+    let registry = HandlerRegistry::new();
+    registry.register_tool("square", handler).unwrap();
+    RequestRouter::new(Arc::new(registry))
+}
+
+#[tokio::test]
+async fn test_cached_second_call_does_not_rerun_handler() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let router =
+        router_with(counting_tool(Arc::clone(&calls)).with_cache_ttl(Duration::from_secs(60)));
+
+    let first = router
+        .route(call_request(1, json!({"x": 4})), RequestContext::new())
+        .await;
+    let second = router
+        .route(call_request(2, json!({"x": 4})), RequestContext::new())
+        .await;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(first.result, second.result);
+    assert_eq!(second.id, Some(RequestId::Number(2)));
+
+    let stats = router.tool_cache().stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 1);
+    assert!((stats.hit_rate() - 0.5).abs() < f64::EPSILON);
+
+    // Different arguments miss the cache
+    router
+        .route(call_request(3, json!({"x": 5})), RequestContext::new())
+        .await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // Manual invalidation forces a re-run
+    router.tool_cache().invalidate_tool("square");
+    router
+        .route(call_request(4, json!({"x": 4})), RequestContext::new())
+        .await;
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_uncached_tool_always_runs() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let router = router_with(counting_tool(Arc::clone(&calls)));
+
+    for id in 0..3 {
+        router
+            .route(call_request(id, json!({"x": 2})), RequestContext::new())
+            .await;
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(router.tool_cache().stats().entries, 0);
+}
+
+#[test]
+fn test_cache_key_is_independent_of_argument_order() {
+    let mut a = HashMap::new();
+    a.insert("x".to_string(), json!(1));
+    a.insert(
+        "nested".to_string(),
+        json!({"b": 2, "a": [1, {"d": 4, "c": 3}]}),
+    );
+    a.insert("y".to_string(), json!("two"));
+
+    let reordered: serde_json::Map<String, serde_json::Value> = serde_json::from_str(
+        r#"{"y": "two", "nested": {"a": [1, {"c": 3, "d": 4}], "b": 2}, "x": 1}"#,
+    )
+    .unwrap();
+    let b: HashMap<String, serde_json::Value> = reordered.into_iter().collect();
+
+    assert_eq!(
+        ToolResultCache::cache_key(Some(&a)),
+        ToolResultCache::cache_key(Some(&b))
+    );
+}
+
+#[test]
+fn test_cache_is_bounded_and_expires() {
+    let cache = ToolResultCache::new(2);
+    let result = CallToolResult {
+        content: Vec::new(),
+        is_error: Some(false),
+        ..Default::default()
+    };
+
+    for key in ["1", "2", "3"] {
+        cache.insert(
+            "t",
+            key.to_string(),
+            result.clone(),
+            Duration::from_secs(60),
+        );
+    }
+    assert_eq!(cache.stats().entries, 2);
+
+    cache.insert("t", "expired".to_string(), result, Duration::ZERO);
+    assert!(cache.get("t", "expired").is_none());
+}
+
+#[test]
+fn test_entries_expire_when_clock_passes_ttl() {
+    let clock = Arc::new(MockClock::new());
+    let cache = ToolResultCache::new(8).with_clock(clock.clone());
+    let result = CallToolResult {
+        content: Vec::new(),
+        is_error: Some(false),
+        ..Default::default()
+    };
+    cache.insert("t", "k".to_string(), result, Duration::from_secs(60));
+
+    clock.advance(Duration::from_secs(59));
+    assert!(cache.get("t", "k").is_some());
+    clock.advance(Duration::from_secs(1));
+    assert!(cache.get("t", "k").is_none());
+}
+
+#[test]
+fn test_switching_clocks_keeps_capacity_and_entries() {
+    let cache = ToolResultCache::new(2);
+    let result = CallToolResult {
+        content: Vec::new(),
+        is_error: Some(false),
+        ..Default::default()
+    };
+    cache.insert(
+        "t",
+        "kept".to_string(),
+        result.clone(),
+        Duration::from_secs(60),
+    );
+
+    let clock = Arc::new(MockClock::new());
+    cache.set_clock(clock.clone());
+    assert!(cache.get("t", "kept").is_some());
+    for key in ["1", "2", "3"] {
+        cache.insert(
+            "t",
+            key.to_string(),
+            result.clone(),
+            Duration::from_secs(60),
+        );
+    }
+    assert_eq!(cache.stats().entries, 2);
+
+    clock.advance(Duration::from_secs(60));
+    assert!(cache.get("t", "3").is_none());
+}
@@ -0,0 +1,151 @@
+//! Tests for reporting tool errors as protocol errors or `isError` results
+
+mod common;
+
+use common::tool_definition;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::registry::HandlerRegistry;
+use turbomcp_server::routing::RequestRouter;
+use turbomcp_server::{ServerError, ToolErrorConfig, ToolErrorMode};
+
+/// Tool that always fails with "disk full"
+fn failing_tool(name: &str) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition(name), |_req, _ctx| async {
+        Err(ServerError::handler("disk full"))
+    })
+}
+
+/// Call `tool` and return the response as it goes on the wire
+async fn call(router: &RequestRouter, tool: &str) -> Value {
+    let request = JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(1),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": tool, "arguments": {}})),
+    };
+    serde_json::to_value(router.route(request, RequestContext::new()).await).unwrap()
+}
+
+fn assert_protocol_error(response: &Value) {
+    assert!(response.get("result").is_none(), "{response}");
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("disk full"),
+        "{response}"
+    );
+}
+
+fn assert_error_result(response: &Value) {
+    assert!(response.get("error").is_none(), "{response}");
+    assert_eq!(response["result"]["isError"], true);
+    assert_eq!(response["result"]["content"][0]["type"], "text");
+    assert!(
+        response["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("disk full"),
+        "{response}"
+    );
+}
+
+#[tokio::test]
+async fn test_global_mode_controls_wire_shape() {
+    let registry = HandlerRegistry::new();
+    registry
+        .register_tool("write", failing_tool("write"))
+        .unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+
+    // Protocol errors by default
+    assert_protocol_error(&call(&router, "write").await);
+
+    router.set_tool_errors(ToolErrorConfig {
+        mode: ToolErrorMode::Result,
+        ..ToolErrorConfig::default()
+    });
+    let response = call(&router, "write").await;
+    assert_error_result(&response);
+    assert_eq!(response["id"], 1);
+}
+
+#[tokio::test]
+async fn test_per_tool_modes_override_global_mode() {
+    let registry = HandlerRegistry::new();
+    registry
+        .register_tool("write", failing_tool("write"))
+        .unwrap();
+    registry
+        .register_tool(
+            "search",
+            failing_tool("search").with_error_mode(ToolErrorMode::Result),
+        )
+        .unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+
+    // The handler's own mode applies under the default global mode
+    assert_protocol_error(&call(&router, "write").await);
+    assert_error_result(&call(&router, "search").await);
+
+    // A configured entry wins over both the handler and the global mode
+    router.set_tool_errors(ToolErrorConfig {
+        mode: ToolErrorMode::Result,
+        tools: HashMap::from([("search".to_string(), ToolErrorMode::Protocol)]),
+    });
+    assert_error_result(&call(&router, "write").await);
+    assert_protocol_error(&call(&router, "search").await);
+}
+
+#[test]
+fn test_mode_for_precedence() {
+    let config = ToolErrorConfig {
+        mode: ToolErrorMode::Protocol,
+        tools: HashMap::from([("write".to_string(), ToolErrorMode::Protocol)]),
+    };
+    assert_eq!(
+        config.mode_for("write", Some(ToolErrorMode::Result)),
+        ToolErrorMode::Protocol
+    );
+    assert_eq!(
+        config.mode_for("search", Some(ToolErrorMode::Result)),
+        ToolErrorMode::Result
+    );
+    assert_eq!(config.mode_for("search", None), ToolErrorMode::Protocol);
+}
+
+#[tokio::test]
+async fn test_argument_errors_stay_protocol_errors() {
+    let tool = FunctionToolHandler::new(tool_definition("count"), |_req, _ctx| async {
+        Err(ServerError::invalid_argument(
+            "n",
+            "u32",
+            "invalid type: string \"ten\", expected u32",
+        ))
+    });
+    let registry = HandlerRegistry::new();
+    registry.register_tool("count", tool).unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+    router.set_tool_errors(ToolErrorConfig {
+        mode: ToolErrorMode::Result,
+        ..ToolErrorConfig::default()
+    });
+
+    let response = call(&router, "count").await;
+    assert!(response.get("result").is_none(), "{response}");
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(
+        response["error"]["data"],
+        json!({
+            "argument": "n",
+            "expected": "u32",
+            "reason": "invalid type: string \"ten\", expected u32",
+        })
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests for per-tool input size limits
+
+mod common;
+
+use common::tool_definition;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::types::CallToolResult;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::registry::HandlerRegistry;
+use turbomcp_server::routing::RequestRouter;
+
+fn echo_tool(name: &str, calls: Arc<AtomicUsize>) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition(name), move |_req, _ctx| {
+        let calls = Arc::clone(&calls);
+        async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(CallToolResult {
+                content: Vec::new(),
+                is_error: Some(false),
+                ..Default::default()
+            })
+        }
+    })
+}
+
+fn call(name: &str, text: &str) -> JsonRpcRequest {
+    JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(1),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": name, "arguments": {"text": text}})),
+    }
+}
+
+#[tokio::test]
+async fn test_oversized_arguments_are_rejected_for_limited_tool() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let registry = HandlerRegistry::new();
+    registry
+        .register_tool(
+            "small",
+            echo_tool("small", Arc::clone(&calls)).with_max_input_size(64),
+        )
+        .unwrap();
+    registry
+        .register_tool("large", echo_tool("large", Arc::clone(&calls)))
+        .unwrap();
+    let router = RequestRouter::new(Arc::new(registry));
+    let big = "x".repeat(1024);
+
+    let rejected = router
+        .route(call("small", &big), RequestContext::new())
+        .await;
+    let error = rejected.error.expect("oversized input must be rejected");
+    assert_eq!(error.code, -32602);
+    assert!(error.message.contains("small"));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    // Small input to the limited tool, and large input to the unlimited one, still run
+    let ok = router
+        .route(call("small", "hi"), RequestContext::new())
+        .await;
+    assert!(ok.error.is_none());
+    let ok = router
+        .route(call("large", &big), RequestContext::new())
+        .await;
+    assert!(ok.error.is_none());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
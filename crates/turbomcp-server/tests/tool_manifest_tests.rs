@@ -0,0 +1,112 @@
+//! Tests for versioned tool manifests and client-side listing caches
+
+mod common;
+
+use async_trait::async_trait;
+use common::{ChannelTransport, tool_definition};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use turbomcp_client::{Client, ClientBuilder, MessageInterceptor, ToolManifest};
+use turbomcp_protocol::types::{CallToolResult, Tool};
+use turbomcp_server::ServerBuilder;
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_transport::core::TransportMessage;
+
+/// Interceptor counting the `tools/list` requests a client sends
+#[derive(Debug, Default)]
+struct ListingCounter(Arc<AtomicUsize>);
+
+#[async_trait]
+impl MessageInterceptor for ListingCounter {
+    async fn on_outbound(
+        &self,
+        message: TransportMessage,
+    ) -> turbomcp_core::Result<TransportMessage> {
+        let json: serde_json::Value = serde_json::from_slice(&message.payload)?;
+        if json["method"] == "tools/list" {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(message)
+    }
+}
+
+fn tool(name: &str, description: &str) -> FunctionToolHandler {
+    let tool = Tool {
+        description: Some(description.to_string()),
+        ..tool_definition(name)
+    };
+    FunctionToolHandler::new(tool, |_req, _ctx| async {
+        Ok(CallToolResult {
+            content: vec![],
+            ..Default::default()
+        })
+    })
+}
+
+/// Start a server with one `echo` tool and connect a client built by `builder`
+async fn connect(description: &str, builder: ClientBuilder) -> Client<ChannelTransport> {
+    let server = Arc::new(
+        ServerBuilder::new()
+            .tool("echo", tool("echo", description))
+            .unwrap()
+            .build(),
+    );
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn(async move { server.run_with_transport(server_transport).await });
+    let mut client = builder.build(client_transport);
+    client.initialize().await.unwrap();
+    client
+}
+
+/// Manifest cached by a client's first connection to a server
+async fn cached_manifest(description: &str) -> ToolManifest {
+    let mut client = connect(description, ClientBuilder::new()).await;
+    client.list_tools().await.unwrap();
+    client
+        .tool_manifest()
+        .cloned()
+        .expect("listing should be versioned")
+}
+
+#[tokio::test]
+async fn test_reconnecting_client_reuses_cached_manifest() {
+    let manifest = cached_manifest("Echo input").await;
+    let listings = Arc::new(AtomicUsize::new(0));
+
+    // A new server with the same tools reports the same version
+    let mut client = connect(
+        "Echo input",
+        ClientBuilder::new()
+            .with_tool_manifest(manifest.clone())
+            .with_interceptor(ListingCounter(Arc::clone(&listings))),
+    )
+    .await;
+    let tools = client.list_tools().await.unwrap();
+
+    assert_eq!(tools, vec!["echo".to_string()]);
+    assert_eq!(listings.load(Ordering::SeqCst), 0);
+    assert_eq!(client.tool_manifest().unwrap().version, manifest.version);
+}
+
+#[tokio::test]
+async fn test_changed_tools_bypass_cached_manifest() {
+    let manifest = cached_manifest("Echo input").await;
+    let listings = Arc::new(AtomicUsize::new(0));
+
+    let mut client = connect(
+        "Echo input back",
+        ClientBuilder::new()
+            .with_tool_manifest(manifest.clone())
+            .with_interceptor(ListingCounter(Arc::clone(&listings))),
+    )
+    .await;
+    let tools = client.list_tools_filtered(|_| true).await.unwrap();
+
+    assert_eq!(listings.load(Ordering::SeqCst), 1);
+    assert_eq!(tools[0].description.as_deref(), Some("Echo input back"));
+    assert_ne!(client.tool_manifest().unwrap().version, manifest.version);
+
+    // The refreshed listing is cached in turn
+    client.list_tools().await.unwrap();
+    assert_eq!(listings.load(Ordering::SeqCst), 1);
+}
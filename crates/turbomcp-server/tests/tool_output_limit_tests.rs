@@ -0,0 +1,97 @@
+//! Tests for per-tool output size limits
+
+mod common;
+
+use common::tool_definition;
+use serde_json::json;
+use std::sync::Arc;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::RequestId;
+use turbomcp_protocol::jsonrpc::{JsonRpcRequest, JsonRpcVersion};
+use turbomcp_protocol::types::{CallToolResult, Content, TextContent};
+use turbomcp_server::handlers::FunctionToolHandler;
+use turbomcp_server::registry::HandlerRegistry;
+use turbomcp_server::routing::RequestRouter;
+use turbomcp_server::{ToolErrorConfig, ToolErrorMode, ToolOutputLimit};
+
+/// Tool returning a short heading and then `size` bytes of text
+fn dump_tool(size: usize) -> FunctionToolHandler {
+    FunctionToolHandler::new(tool_definition("dump"), move |_req, _ctx| async move {
+        let text = |text: String| {
+            Content::Text(TextContent {
+                text,
+                annotations: None,
+                meta: None,
+            })
+        };
+        Ok(CallToolResult {
+            content: vec![text("log:".to_string()), text("é\"".repeat(size / 3))],
+            ..Default::default()
+        })
+    })
+}
+
+fn router_with(tool: FunctionToolHandler) -> RequestRouter {
+    let registry = HandlerRegistry::new();
+    registry.register_tool("dump", tool).unwrap();
+    RequestRouter::new(Arc::new(registry))
+}
+
+fn call() -> JsonRpcRequest {
+    JsonRpcRequest {
+        jsonrpc: JsonRpcVersion,
+        id: RequestId::Number(1),
+        method: "tools/call".to_string(),
+        params: Some(json!({"name": "dump", "arguments": {}})),
+    }
+}
+
+#[tokio::test]
+async fn test_oversized_output_is_truncated_with_marker() {
+    let router = router_with(dump_tool(100_000).with_output_limit(ToolOutputLimit::truncate(1024)));
+
+    let response = router.route(call(), RequestContext::new()).await;
+    let result = response.result.expect("truncated output is still a result");
+    assert!(serde_json::to_vec(&result).unwrap().len() <= 1024);
+
+    let content = result["content"].as_array().unwrap();
+    assert_eq!(content.len(), 3);
+    assert_eq!(content[0]["text"], "log:");
+    // The cut text is a prefix of the original and uses up most of the budget
+    let cut = content[1]["text"].as_str().unwrap();
+    assert!(cut.len() > 500);
+    assert!("é\"".repeat(cut.len()).starts_with(cut));
+    let marker = content[2]["text"].as_str().unwrap();
+    assert!(marker.starts_with("[output truncated:"), "{marker}");
+    assert!(marker.contains("1024-byte limit"));
+}
+
+#[tokio::test]
+async fn test_oversized_output_is_rejected() {
+    let router = router_with(dump_tool(100_000).with_output_limit(ToolOutputLimit::reject(1024)));
+
+    let response = router.route(call(), RequestContext::new()).await;
+    let error = response.error.expect("oversized output must be rejected");
+    assert!(error.message.contains("dump"), "{}", error.message);
+    assert!(error.message.contains("1024"), "{}", error.message);
+
+    // Small output passes through untouched
+    let router = router_with(dump_tool(30).with_output_limit(ToolOutputLimit::reject(1024)));
+    let response = router.route(call(), RequestContext::new()).await;
+    let result = response.result.expect("small output is within the limit");
+    assert_eq!(result["content"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_rejection_follows_tool_error_mode() {
+    let router = router_with(dump_tool(100_000).with_output_limit(ToolOutputLimit::reject(1024)));
+    router.set_tool_errors(ToolErrorConfig {
+        mode: ToolErrorMode::Result,
+        ..ToolErrorConfig::default()
+    });
+
+    let response = router.route(call(), RequestContext::new()).await;
+    let result = response.result.expect("errors are reported as results");
+    assert_eq!(result["isError"], true);
+    assert!(serde_json::to_vec(&result).unwrap().len() < 1024);
+}
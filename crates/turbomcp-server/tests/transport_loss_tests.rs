@@ -0,0 +1,31 @@
+//! Tests for shutting down when the client goes away uncleanly
+
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::time::timeout;
+use turbomcp_server::ServerBuilder;
+use turbomcp_transport::StdioTransport;
+use turbomcp_transport::core::Transport;
+
+#[tokio::test]
+async fn test_server_stops_when_client_stops_reading() {
+    let server = ServerBuilder::new().build();
+    let (mut client_out, server_in) = tokio::io::duplex(4096);
+    let (server_out, client_in) = tokio::io::duplex(4096);
+    let mut transport = StdioTransport::with_io(server_in, server_out);
+    transport.connect().await.unwrap();
+    let run = tokio::spawn(async move { server.run_with_transport(transport).await });
+
+    // The client closes its read end but keeps sending
+    drop(client_in);
+    client_out
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n")
+        .await
+        .unwrap();
+
+    timeout(Duration::from_secs(5), run)
+        .await
+        .expect("server should stop once its reply cannot be delivered")
+        .unwrap()
+        .unwrap();
+}
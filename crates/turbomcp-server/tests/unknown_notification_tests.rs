@@ -0,0 +1,67 @@
+//! Tests for the unknown notification policy
+
+mod common;
+
+use common::ScriptedTransport;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::timeout;
+use turbomcp_protocol::UnknownNotificationPolicy;
+use turbomcp_server::ServerBuilder;
+
+const NOTIFICATIONS: &[&str] = &[
+    r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+    r#"{"jsonrpc":"2.0","method":"x/future/notification","params":{"n":1}}"#,
+    r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":1}}"#,
+];
+
+#[tokio::test]
+async fn test_unknown_notification_reaches_catch_all_handler() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let server = ServerBuilder::new()
+        .on_unknown_notification({
+            let seen = Arc::clone(&seen);
+            move |notification| {
+                seen.lock()
+                    .unwrap()
+                    .push((notification.method.clone(), notification.params.clone()));
+            }
+        })
+        .build();
+
+    timeout(
+        Duration::from_secs(5),
+        server.run_with_transport(ScriptedTransport::new(NOTIFICATIONS)),
+    )
+    .await
+    .expect("server should stop when the transport disconnects")
+    .unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![(
+            "x/future/notification".to_string(),
+            Some(serde_json::json!({"n": 1}))
+        )]
+    );
+}
+
+#[tokio::test]
+async fn test_ignore_and_log_policies_keep_serving() {
+    for policy in [
+        UnknownNotificationPolicy::Ignore,
+        UnknownNotificationPolicy::Log,
+    ] {
+        let server = ServerBuilder::new()
+            .with_unknown_notification_policy(policy)
+            .build();
+
+        let result = timeout(
+            Duration::from_secs(5),
+            server.run_with_transport(ScriptedTransport::new(NOTIFICATIONS)),
+        )
+        .await
+        .expect("server should stop when the transport disconnects");
+        assert!(result.is_ok());
+    }
+}
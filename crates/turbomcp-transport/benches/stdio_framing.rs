@@ -0,0 +1,108 @@
+//! Memory and time to frame a large stdio message
+//!
+//! Compares the line-based path the stdio transport used to take (buffer the
+//! whole line, parse it into a `serde_json::Value`, copy it into the payload)
+//! with [`JsonFrameCodec`], which checks the message as chunks arrive and
+//! hands out the frame itself as the payload. Peak heap use for each path is
+//! printed before the timing runs.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::{Bytes, BytesMut};
+use criterion::{Criterion, criterion_group, criterion_main};
+use tokio_util::codec::{Decoder, LinesCodec};
+use turbomcp_transport::JsonFrameCodec;
+
+/// Allocator tracking current and peak heap use
+struct CountingAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let now = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(now, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// A 16 MB `resources/read` response, newline-terminated
+fn large_message() -> Vec<u8> {
+    let text = "x".repeat(16 * 1024 * 1024 - 1024);
+    let mut message = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "result": {"contents": [{"uri": "file:///big.txt", "text": text}]},
+    })
+    .to_string()
+    .into_bytes();
+    message.push(b'\n');
+    message
+}
+
+/// Feed `input` to `decode` in 64 KB reads, as the transport receives it
+fn feed<T>(input: &[u8], mut decode: impl FnMut(&mut BytesMut) -> Option<T>) -> T {
+    let mut buf = BytesMut::with_capacity(8 * 1024);
+    for chunk in input.chunks(64 * 1024) {
+        buf.extend_from_slice(chunk);
+        if let Some(item) = decode(&mut buf) {
+            return item;
+        }
+    }
+    panic!("message did not complete");
+}
+
+fn line_path(input: &[u8]) -> Bytes {
+    let mut codec = LinesCodec::new();
+    let line = feed(input, |buf| codec.decode(buf).unwrap());
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+    black_box(value.get("id"));
+    Bytes::from(line.trim().to_string())
+}
+
+fn frame_path(input: &[u8]) -> Bytes {
+    let mut codec = JsonFrameCodec::default();
+    let frame = feed(input, |buf| codec.decode(buf).unwrap()).unwrap();
+    serde_json::from_slice::<serde::de::IgnoredAny>(&frame).unwrap();
+    frame
+}
+
+/// Heap in use at the peak of `f`, beyond what was in use before it
+fn peak_bytes(f: impl FnOnce()) -> usize {
+    let before = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    f();
+    PEAK.load(Ordering::Relaxed) - before
+}
+
+fn bench_framing(c: &mut Criterion) {
+    let input = large_message();
+    let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    println!(
+        "peak heap for a {:.1} MiB message: lines {:.1} MiB, frames {:.1} MiB",
+        mib(input.len()),
+        mib(peak_bytes(|| drop(black_box(line_path(&input))))),
+        mib(peak_bytes(|| drop(black_box(frame_path(&input))))),
+    );
+
+    let mut group = c.benchmark_group("stdio_framing_16mb");
+    group.sample_size(10);
+    group.bench_function("lines", |b| b.iter(|| line_path(black_box(&input))));
+    group.bench_function("frames", |b| b.iter(|| frame_path(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_framing);
+criterion_main!(benches);
@@ -0,0 +1,206 @@
+//! Accept-rate limiting for network listeners.
+//!
+//! Request rate limits only apply once a connection is up, so a peer that
+//! opens connections in a tight loop can exhaust file descriptors and tasks
+//! without sending a single request. An [`AcceptRateLimiter`] keeps a token
+//! bucket per peer IP and is consulted as each connection is accepted:
+//!
+//! - The TCP transport closes a connection over the limit straight away,
+//!   before reading anything from it.
+//! - HTTP and WebSocket servers answer every request on such a connection
+//!   with `429 Too Many Requests` and `Connection: close`, so nothing reaches
+//!   the application or upgrades to a WebSocket. [`serve`](crate::axum_integration::serve)
+//!   does this for [`McpServerConfig::accept_rate_limit`](crate::axum_integration::McpServerConfig::accept_rate_limit),
+//!   and [`AcceptRateLimitedRouter`] wraps a router passed to `axum::serve`.
+//!
+//! Rejected connections are counted in [`AcceptRateLimiter::rejected`] and,
+//! for the TCP transport, in [`TransportMetrics::rejected_connections`](crate::core::TransportMetrics::rejected_connections).
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use parking_lot::Mutex;
+
+/// Number of tracked peers above which idle buckets are dropped
+const PRUNE_THRESHOLD: usize = 4096;
+
+/// Per-IP limits on new connections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcceptRateLimitConfig {
+    /// Sustained new connections per second allowed from one IP
+    pub connections_per_second: u32,
+    /// Connections one IP may open back to back before the sustained rate
+    /// applies
+    pub burst: u32,
+}
+
+impl Default for AcceptRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            connections_per_second: 10,
+            burst: 20,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token buckets limiting how fast each IP may open connections
+#[derive(Debug)]
+pub struct AcceptRateLimiter {
+    config: AcceptRateLimitConfig,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+    rejected: AtomicU64,
+}
+
+impl AcceptRateLimiter {
+    /// Create a limiter enforcing `config`
+    #[must_use]
+    pub fn new(config: AcceptRateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// The limits being enforced
+    #[must_use]
+    pub const fn config(&self) -> AcceptRateLimitConfig {
+        self.config
+    }
+
+    /// Record a new connection from `ip`, returning whether to accept it
+    pub fn allow(&self, ip: IpAddr) -> bool {
+        self.allow_at(ip, Instant::now())
+    }
+
+    /// Number of connections turned away so far
+    #[must_use]
+    pub fn rejected(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+
+    fn allow_at(&self, ip: IpAddr, now: Instant) -> bool {
+        let capacity = f64::from(self.config.burst.max(1));
+        let rate = f64::from(self.config.connections_per_second);
+        let refill = |bucket: &Bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+            (bucket.tokens + elapsed * rate).min(capacity)
+        };
+
+        let mut buckets = self.buckets.lock();
+        if buckets.len() >= PRUNE_THRESHOLD {
+            // A full bucket behaves exactly like a missing one
+            buckets.retain(|_, bucket| refill(bucket) < capacity);
+        }
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        bucket.tokens = refill(bucket);
+        bucket.updated = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            self.rejected.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+}
+
+/// Make-service for `axum::serve` that enforces an [`AcceptRateLimiter`]
+///
+/// ```rust,ignore
+/// let limiter = Arc::new(AcceptRateLimiter::new(AcceptRateLimitConfig::default()));
+/// axum::serve(listener, AcceptRateLimitedRouter::new(router, limiter)).await?;
+/// ```
+#[cfg(feature = "http")]
+#[derive(Debug, Clone)]
+pub struct AcceptRateLimitedRouter {
+    router: axum::Router,
+    limiter: std::sync::Arc<AcceptRateLimiter>,
+}
+
+#[cfg(feature = "http")]
+impl AcceptRateLimitedRouter {
+    /// Serve `router` to connections `limiter` accepts
+    #[must_use]
+    pub const fn new(router: axum::Router, limiter: std::sync::Arc<AcceptRateLimiter>) -> Self {
+        Self { router, limiter }
+    }
+
+    /// Router to serve a new connection from `peer` with
+    ///
+    /// This is the wrapped router if the limiter accepts the connection, and
+    /// otherwise one answering every request with `429 Too Many Requests`.
+    pub fn router_for(&self, peer: std::net::SocketAddr) -> axum::Router {
+        use axum::http::{StatusCode, header};
+
+        if self.limiter.allow(peer.ip()) {
+            return self.router.clone();
+        }
+        tracing::warn!(%peer, "Rejected connection: accept rate exceeded");
+        axum::Router::new().fallback(|| async {
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::CONNECTION, "close")],
+                "Too many connections",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "http")]
+impl<'a> tower::Service<axum::serve::IncomingStream<'a>> for AcceptRateLimitedRouter {
+    type Response = axum::Router;
+    type Error = std::convert::Infallible;
+    type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, stream: axum::serve::IncomingStream<'a>) -> Self::Future {
+        std::future::ready(Ok(self.router_for(stream.remote_addr())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_bucket_refills_per_ip() {
+        let limiter = AcceptRateLimiter::new(AcceptRateLimitConfig {
+            connections_per_second: 2,
+            burst: 3,
+        });
+        let a: IpAddr = "10.0.0.1".parse().unwrap();
+        let b: IpAddr = "10.0.0.2".parse().unwrap();
+        let start = Instant::now();
+
+        // The burst is available at once, then the IP is cut off
+        assert!((0..3).all(|_| limiter.allow_at(a, start)));
+        assert!(!limiter.allow_at(a, start));
+        // Other IPs have their own bucket
+        assert!(limiter.allow_at(b, start));
+
+        // Half a second at two per second buys one more connection
+        let later = start + Duration::from_millis(500);
+        assert!(limiter.allow_at(a, later));
+        assert!(!limiter.allow_at(a, later));
+        assert_eq!(limiter.rejected(), 2);
+    }
+}
@@ -85,6 +85,8 @@ use std::convert::Infallible;
 #[cfg(feature = "http")]
 use std::sync::Arc;
 #[cfg(feature = "http")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "http")]
 use std::time::Duration;
 
 #[cfg(feature = "http")]
@@ -101,10 +103,16 @@ use axum::{
 };
 
 #[cfg(feature = "http")]
-use axum::http::{HeaderName, HeaderValue};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, header};
 #[cfg(feature = "http")]
 use futures::{SinkExt, StreamExt, stream::Stream};
 #[cfg(feature = "http")]
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+#[cfg(feature = "http")]
+use hyper_util::server::conn::auto::Builder as ConnectionBuilder;
+#[cfg(feature = "http")]
+use hyper_util::service::TowerToHyperService;
+#[cfg(feature = "http")]
 use parking_lot::Mutex;
 #[cfg(feature = "http")]
 use serde::{Deserialize, Serialize};
@@ -122,6 +130,14 @@ use tower_http::{
 #[cfg(feature = "http")]
 use tracing::{debug, error, info, trace, warn};
 
+#[cfg(feature = "http")]
+use crate::accept_limit::{AcceptRateLimitConfig, AcceptRateLimitedRouter, AcceptRateLimiter};
+#[cfg(feature = "http")]
+use crate::core::TransportMessageMetadata;
+#[cfg(feature = "http")]
+use crate::core::{TransportError, TransportResult};
+#[cfg(feature = "http")]
+use crate::tls::SessionResumption;
 #[cfg(feature = "http")]
 use crate::tower::{SessionInfo, SessionManager};
 #[cfg(feature = "http")]
@@ -138,6 +154,31 @@ pub trait McpService: Send + Sync + 'static {
         session: &SessionInfo,
     ) -> McpResult<serde_json::Value>;
 
+    /// Process an MCP request along with the transport metadata it arrived with
+    ///
+    /// The HTTP and WebSocket handlers call this with the request's headers
+    /// (the upgrade request's, for WebSocket), so services can read
+    /// `Authorization` or trace headers. The default ignores the metadata.
+    async fn process_request_with_metadata(
+        &self,
+        request: serde_json::Value,
+        session: &SessionInfo,
+        _metadata: &TransportMessageMetadata,
+    ) -> McpResult<serde_json::Value> {
+        self.process_request(request, session).await
+    }
+
+    /// Whether the service has finished starting up
+    ///
+    /// Until it has, `/mcp/readyz` answers `503 Service Unavailable` and
+    /// requests posted to `/mcp` are refused with the same status, so load
+    /// balancers and clients wait instead of reaching a half-initialized
+    /// service. Services with async setup, such as a server's startup tasks,
+    /// report it here; the default is always ready.
+    fn is_ready(&self) -> bool {
+        true
+    }
+
     /// Get service capabilities
     fn get_capabilities(&self) -> serde_json::Value {
         serde_json::json!({
@@ -232,6 +273,9 @@ pub struct McpAppState {
 
     /// Configuration options
     pub config: McpServerConfig,
+
+    /// SSE streams and WebSocket connections closed for idleness
+    pub idle_closed: Arc<AtomicU64>,
 }
 
 #[cfg(feature = "http")]
@@ -242,6 +286,7 @@ impl std::fmt::Debug for McpAppState {
             .field("session_manager", &self.session_manager)
             .field("sse_sender", &"<broadcast::Sender>")
             .field("config", &self.config)
+            .field("idle_closed", &self.idle_closed.load(Ordering::Relaxed))
             .finish()
     }
 }
@@ -259,9 +304,28 @@ pub struct McpServerConfig {
     /// SSE keep-alive interval
     pub sse_keep_alive: Duration,
 
+    /// Close SSE streams and WebSocket connections after this long without
+    /// a message (None = never)
+    ///
+    /// SSE keep-alive comments do not count as activity.
+    pub idle_timeout: Option<Duration>,
+
+    /// How long clients may hold an idle HTTP/1.1 connection open between
+    /// requests, advertised in the `Keep-Alive` response header
+    ///
+    /// `None` asks clients not to reuse connections by sending
+    /// `Connection: close`. [`serve`] enforces it, closing HTTP/1.1
+    /// connections that stay idle for longer, or after each response when
+    /// `None`.
+    pub http_keep_alive: Option<Duration>,
+
     /// Maximum concurrent connections
     pub max_connections: usize,
 
+    /// Per-IP limit on new connections accepted by [`serve`]; unlimited when
+    /// `None`
+    pub accept_rate_limit: Option<AcceptRateLimitConfig>,
+
     /// CORS configuration
     pub cors: CorsConfig,
 
@@ -271,7 +335,7 @@ pub struct McpServerConfig {
     /// Rate limiting configuration
     pub rate_limiting: RateLimitConfig,
 
-    /// TLS configuration
+    /// TLS for connections accepted by [`serve`]; plain HTTP when `None`
     pub tls: Option<TlsConfig>,
 
     /// Authentication configuration
@@ -379,6 +443,54 @@ pub struct TlsConfig {
     pub min_version: TlsVersion,
     /// Enable HTTP/2
     pub enable_http2: bool,
+    /// Session resumption for reconnecting clients, on by default
+    pub session_resumption: SessionResumption,
+}
+
+#[cfg(all(feature = "http", feature = "tls"))]
+impl TlsConfig {
+    /// Build a `rustls` server configuration from these settings
+    ///
+    /// Loads the PEM certificate chain and private key, enforces the minimum
+    /// version, advertises HTTP/2 over ALPN when enabled, and applies
+    /// [`session_resumption`](Self::session_resumption).
+    pub fn rustls_server_config(&self) -> TransportResult<Arc<rustls::ServerConfig>> {
+        use rustls::pki_types::pem::PemObject;
+        use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+
+        let certs = CertificateDer::pem_file_iter(&self.cert_file)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| {
+                TransportError::ConfigurationError(format!(
+                    "Cannot read TLS certificate {}: {e}",
+                    self.cert_file
+                ))
+            })?;
+        let key = PrivateKeyDer::from_pem_file(&self.key_file).map_err(|e| {
+            TransportError::ConfigurationError(format!(
+                "Cannot read TLS private key {}: {e}",
+                self.key_file
+            ))
+        })?;
+
+        let versions: &[&rustls::SupportedProtocolVersion] = match self.min_version {
+            TlsVersion::TlsV1_2 => &[&rustls::version::TLS13, &rustls::version::TLS12],
+            TlsVersion::TlsV1_3 => &[&rustls::version::TLS13],
+        };
+        let mut config = rustls::ServerConfig::builder_with_protocol_versions(versions)
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map_err(|e| {
+                TransportError::ConfigurationError(format!("Invalid TLS certificate or key: {e}"))
+            })?;
+        config.alpn_protocols = if self.enable_http2 {
+            vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+        } else {
+            vec![b"http/1.1".to_vec()]
+        };
+        crate::tls::apply_server_resumption(&mut config, &self.session_resumption)?;
+        Ok(Arc::new(config))
+    }
 }
 
 #[cfg(feature = "http")]
@@ -432,7 +544,10 @@ impl McpServerConfig {
             max_request_size: 16 * 1024 * 1024, // 16MB
             request_timeout: Duration::from_secs(30),
             sse_keep_alive: Duration::from_secs(15),
+            idle_timeout: Some(Duration::from_secs(600)),
+            http_keep_alive: Some(Duration::from_secs(60)),
             max_connections: 1000,
+            accept_rate_limit: None,
             cors: CorsConfig::permissive(),
             security: SecurityConfig::development(),
             rate_limiting: RateLimitConfig::disabled(),
@@ -450,7 +565,10 @@ impl McpServerConfig {
             max_request_size: 8 * 1024 * 1024, // 8MB
             request_timeout: Duration::from_secs(30),
             sse_keep_alive: Duration::from_secs(15),
+            idle_timeout: Some(Duration::from_secs(300)),
+            http_keep_alive: Some(Duration::from_secs(30)),
             max_connections: 500,
+            accept_rate_limit: Some(AcceptRateLimitConfig::default()),
             cors: CorsConfig::restrictive(),
             security: SecurityConfig::staging(),
             rate_limiting: RateLimitConfig::moderate(),
@@ -468,7 +586,10 @@ impl McpServerConfig {
             max_request_size: 4 * 1024 * 1024, // 4MB
             request_timeout: Duration::from_secs(15),
             sse_keep_alive: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(120)),
+            http_keep_alive: Some(Duration::from_secs(15)),
             max_connections: 200,
+            accept_rate_limit: Some(AcceptRateLimitConfig::default()),
             cors: CorsConfig::strict(),
             security: SecurityConfig::production(),
             rate_limiting: RateLimitConfig::strict(),
@@ -487,6 +608,9 @@ impl McpServerConfig {
     /// - `TLS_KEY_FILE`: Path to TLS private key file
     /// - `TLS_MIN_VERSION`: Minimum TLS version (1.2 or 1.3, defaults to 1.3)
     /// - `TLS_ENABLE_HTTP2`: Enable HTTP/2 (true/false, defaults to true)
+    /// - `TLS_SESSION_RESUMPTION`: Resume sessions (true/false, defaults to true)
+    /// - `TLS_SESSION_TICKET_LIFETIME`: Session ticket lifetime in seconds
+    ///   (defaults to 3600)
     fn load_tls_from_env() -> Option<TlsConfig> {
         let cert_file = std::env::var("TLS_CERT_FILE").ok()?;
         let key_file = std::env::var("TLS_KEY_FILE").ok()?;
@@ -505,11 +629,26 @@ impl McpServerConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(true);
 
+        let mut session_resumption = SessionResumption::default();
+        if let Some(enabled) = std::env::var("TLS_SESSION_RESUMPTION")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            session_resumption.enabled = enabled;
+        }
+        if let Some(secs) = std::env::var("TLS_SESSION_TICKET_LIFETIME")
+            .ok()
+            .and_then(|v| v.parse().ok())
+        {
+            session_resumption.ticket_lifetime = Duration::from_secs(secs);
+        }
+
         Some(TlsConfig {
             cert_file,
             key_file,
             min_version,
             enable_http2,
+            session_resumption,
         })
     }
 
@@ -551,6 +690,24 @@ impl McpServerConfig {
         self
     }
 
+    /// Configure the idle timeout for SSE streams and WebSocket connections
+    pub fn with_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Configure HTTP keep-alive (None disables connection reuse)
+    pub fn with_http_keep_alive(mut self, keep_alive: Option<Duration>) -> Self {
+        self.http_keep_alive = keep_alive;
+        self
+    }
+
+    /// Limit how fast each IP may open connections to [`serve`]
+    pub fn with_accept_rate_limit(mut self, limit: AcceptRateLimitConfig) -> Self {
+        self.accept_rate_limit = Some(limit);
+        self
+    }
+
     /// Configure rate limiting
     pub fn with_rate_limit(mut self, requests_per_minute: u32, burst: u32) -> Self {
         self.rate_limiting.requests_per_minute = requests_per_minute;
@@ -566,10 +723,19 @@ impl McpServerConfig {
             key_file,
             min_version: TlsVersion::TlsV1_3,
             enable_http2: true,
+            session_resumption: SessionResumption::default(),
         });
         self
     }
 
+    /// Configure TLS session resumption; has no effect until TLS is configured
+    pub fn with_tls_session_resumption(mut self, resumption: SessionResumption) -> Self {
+        if let Some(tls) = &mut self.tls {
+            tls.session_resumption = resumption;
+        }
+        self
+    }
+
     /// Enable API key authentication
     pub fn with_api_key_auth(mut self, header_name: String) -> Self {
         self.auth = Some(AuthConfig {
@@ -852,6 +1018,7 @@ where
             session_manager,
             sse_sender,
             config: config.clone(),
+            idle_closed: Arc::new(AtomicU64::new(0)),
         };
 
         // Create new router with MCP routes and state
@@ -861,6 +1028,7 @@ where
             .route("/mcp/sse", get(sse_handler))
             .route("/mcp/ws", get(websocket_handler))
             .route("/mcp/health", get(health_handler))
+            .route("/mcp/readyz", get(readiness_handler))
             .route("/mcp/metrics", get(metrics_handler))
             .with_state(app_state);
 
@@ -872,6 +1040,112 @@ where
     }
 }
 
+#[cfg(feature = "http")]
+/// Serve `router` on `listener` with the connection settings in `config`
+///
+/// HTTP/1.1 and HTTP/2 requests are served on each accepted connection, and
+/// WebSocket upgrades are handed to the router. With [`tls`](McpServerConfig::tls)
+/// set, every connection is TLS, using [`TlsConfig::rustls_server_config`].
+/// Connections beyond [`accept_rate_limit`](McpServerConfig::accept_rate_limit)
+/// are answered with `429 Too Many Requests` and closed, and idle HTTP/1.1
+/// connections are closed after [`http_keep_alive`](McpServerConfig::http_keep_alive).
+/// Pass the same `config` the router was built with.
+///
+/// ```rust,ignore
+/// let config = McpServerConfig::production();
+/// let router = Router::<()>::turbo_mcp_server_with_config(service, config.clone());
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:8443").await?;
+/// serve(listener, router, &config).await?;
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the listener is not bound to an address, or if TLS
+/// is configured but its certificate or key cannot be loaded, or the `tls`
+/// feature is disabled. Otherwise this runs until the task is dropped.
+pub async fn serve(
+    listener: tokio::net::TcpListener,
+    router: Router,
+    config: &McpServerConfig,
+) -> TransportResult<()> {
+    let addr = listener.local_addr().map_err(|e| {
+        TransportError::ConnectionFailed(format!("HTTP listener has no address: {e}"))
+    })?;
+    #[cfg(feature = "tls")]
+    let tls = match &config.tls {
+        Some(tls) => Some(tokio_rustls::TlsAcceptor::from(tls.rustls_server_config()?)),
+        None => None,
+    };
+    #[cfg(not(feature = "tls"))]
+    if config.tls.is_some() {
+        return Err(TransportError::ConfigurationError(
+            "TLS is configured but the `tls` feature is disabled".to_string(),
+        ));
+    }
+    let limited = config.accept_rate_limit.map(|limit| {
+        AcceptRateLimitedRouter::new(router.clone(), Arc::new(AcceptRateLimiter::new(limit)))
+    });
+    let mut builder = ConnectionBuilder::new(TokioExecutor::new());
+    match config.http_keep_alive {
+        // The header read timeout also runs while waiting for the next request
+        Some(keep_alive) => {
+            builder
+                .http1()
+                .timer(TokioTimer::new())
+                .header_read_timeout(keep_alive);
+        }
+        None => {
+            builder.http1().keep_alive(false);
+        }
+    }
+    info!("Serving MCP over HTTP on {addr}");
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                // Usually out of file descriptors; back off rather than spin
+                error!("Failed to accept HTTP connection: {e}");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+        };
+        let app = limited
+            .as_ref()
+            .map_or_else(|| router.clone(), |limited| limited.router_for(peer));
+        let builder = builder.clone();
+        #[cfg(feature = "tls")]
+        let tls = tls.clone();
+        tokio::spawn(async move {
+            #[cfg(feature = "tls")]
+            if let Some(tls) = tls {
+                match tls.accept(stream).await {
+                    Ok(stream) => serve_connection(&builder, TokioIo::new(stream), app, peer).await,
+                    Err(e) => debug!(%peer, "TLS handshake failed: {e}"),
+                }
+                return;
+            }
+            serve_connection(&builder, TokioIo::new(stream), app, peer).await;
+        });
+    }
+}
+
+#[cfg(feature = "http")]
+/// Serve `app` on one accepted connection until it closes
+async fn serve_connection<I>(
+    builder: &ConnectionBuilder<TokioExecutor>,
+    io: I,
+    app: Router,
+    peer: std::net::SocketAddr,
+) where
+    I: hyper::rt::Read + hyper::rt::Write + Unpin + Send + 'static,
+{
+    let service = TowerToHyperService::new(app);
+    if let Err(e) = builder.serve_connection_with_upgrades(io, service).await {
+        debug!(%peer, "HTTP connection closed with an error: {e}");
+    }
+}
+
 #[cfg(feature = "http")]
 /// Apply comprehensive middleware stack based on configuration
 #[allow(unused_variables)] // Some middleware may be conditionally applied
@@ -884,6 +1158,12 @@ where
     // 1. Basic MCP middleware (always applied)
     router = router.layer(middleware::from_fn(mcp_middleware));
 
+    // 1a. HTTP keep-alive headers (always applied)
+    router = router.layer(middleware::from_fn_with_state(
+        config.http_keep_alive,
+        keep_alive_middleware,
+    ));
+
     // 2. Security headers (applied based on config and environment)
     if config.security.enabled {
         router = router.layer(middleware::from_fn_with_state(
@@ -1013,20 +1293,45 @@ async fn root_handler() -> impl IntoResponse {
             "sse": "/mcp/sse",
             "websocket": "/mcp/ws",
             "health": "/mcp/health",
+            "readiness": "/mcp/readyz",
             "metrics": "/mcp/metrics"
         }
     }))
 }
 
+#[cfg(feature = "http")]
+/// Transport metadata carrying an HTTP request's headers
+///
+/// Header names are lower-cased; values that are not visible ASCII are
+/// skipped, and repeated headers keep their last value.
+fn http_metadata(headers: &HeaderMap) -> TransportMessageMetadata {
+    let mut metadata = TransportMessageMetadata::default();
+    for (name, value) in headers {
+        if let Ok(value) = value.to_str() {
+            metadata
+                .headers
+                .insert(name.as_str().to_string(), value.to_string());
+        }
+    }
+    metadata.content_type = metadata.header("content-type").map(str::to_string);
+    metadata.correlation_id = metadata.header("x-request-id").map(str::to_string);
+    metadata
+}
+
 #[cfg(feature = "http")]
 /// JSON-RPC HTTP handler
 async fn json_rpc_handler(
     State(app_state): State<McpAppState>,
     Extension(session): Extension<SessionInfo>,
+    headers: HeaderMap,
     Json(request): Json<JsonRpcRequest>,
 ) -> Result<Json<JsonRpcResponse>, StatusCode> {
     trace!("Processing JSON-RPC request: {:?}", request);
 
+    if !app_state.service.is_ready() {
+        return Err(StatusCode::SERVICE_UNAVAILABLE);
+    }
+
     // Validate JSON-RPC format
     if request.jsonrpc != "2.0" {
         return Ok(Json(JsonRpcResponse {
@@ -1052,9 +1357,10 @@ async fn json_rpc_handler(
     });
 
     // Process request through MCP service
+    let metadata = http_metadata(&headers);
     match app_state
         .service
-        .process_request(service_request, &session)
+        .process_request_with_metadata(service_request, &session, &metadata)
         .await
     {
         Ok(result) => {
@@ -1107,6 +1413,8 @@ async fn sse_handler(
     info!("SSE connection established for session: {}", session.id);
 
     let mut receiver = app_state.sse_sender.subscribe();
+    let idle_timeout = app_state.config.idle_timeout;
+    let idle_closed = Arc::clone(&app_state.idle_closed);
 
     // Create event stream
     let stream = async_stream::stream! {
@@ -1118,9 +1426,25 @@ async fn sse_handler(
                 "timestamp": chrono::Utc::now().to_rfc3339()
             }).to_string()));
 
-        // Stream events from broadcast channel
+        // Stream events from broadcast channel until it closes or goes idle
         loop {
-            match receiver.recv().await {
+            let received = match idle_timeout {
+                Some(idle) => match tokio::time::timeout(idle, receiver.recv()).await {
+                    Ok(received) => received,
+                    Err(_) => {
+                        idle_closed.fetch_add(1, Ordering::Relaxed);
+                        info!("Closing SSE stream for idle session: {}", session.id);
+                        yield Ok(Event::default()
+                            .event("idle_timeout")
+                            .data(serde_json::json!({
+                                "idle_seconds": idle.as_secs_f64()
+                            }).to_string()));
+                        break;
+                    }
+                },
+                None => receiver.recv().await,
+            };
+            match received {
                 Ok(message) => {
                     yield Ok(Event::default()
                         .event("message")
@@ -1153,10 +1477,12 @@ async fn websocket_handler(
     State(app_state): State<McpAppState>,
     Query(_query): Query<WebSocketQuery>,
     Extension(session): Extension<SessionInfo>,
+    headers: HeaderMap,
 ) -> Response {
     info!("WebSocket upgrade requested for session: {}", session.id);
 
-    ws.on_upgrade(move |socket| handle_websocket(socket, app_state, session))
+    let metadata = http_metadata(&headers);
+    ws.on_upgrade(move |socket| handle_websocket(socket, app_state, session, metadata))
 }
 
 #[cfg(feature = "http")]
@@ -1165,6 +1491,7 @@ async fn handle_websocket(
     socket: axum::extract::ws::WebSocket,
     app_state: McpAppState,
     session: SessionInfo,
+    metadata: TransportMessageMetadata,
 ) {
     let (mut sender, mut receiver) = socket.split();
 
@@ -1188,8 +1515,23 @@ async fn handle_websocket(
         return;
     }
 
-    // Handle incoming messages
-    while let Some(msg) = receiver.next().await {
+    // Handle incoming messages until the client closes or goes idle
+    loop {
+        let msg = match app_state.config.idle_timeout {
+            Some(idle) => match tokio::time::timeout(idle, receiver.next()).await {
+                Ok(msg) => msg,
+                Err(_) => {
+                    app_state.idle_closed.fetch_add(1, Ordering::Relaxed);
+                    info!("Closing idle WebSocket for session: {}", session.id);
+                    let _ = sender.send(axum::extract::ws::Message::Close(None)).await;
+                    break;
+                }
+            },
+            None => receiver.next().await,
+        };
+        let Some(msg) = msg else {
+            break;
+        };
         match msg {
             Ok(axum::extract::ws::Message::Text(text)) => {
                 trace!("WebSocket received text: {}", text);
@@ -1207,7 +1549,7 @@ async fn handle_websocket(
                         // Process through MCP service
                         match app_state
                             .service
-                            .process_request(service_request, &session)
+                            .process_request_with_metadata(service_request, &session, &metadata)
                             .await
                         {
                             Ok(result) => {
@@ -1322,6 +1664,20 @@ async fn health_handler(State(app_state): State<McpAppState>) -> Json<serde_json
     }))
 }
 
+#[cfg(feature = "http")]
+/// Readiness handler, answering 503 until the service has started up
+async fn readiness_handler(State(app_state): State<McpAppState>) -> Response {
+    if app_state.service.is_ready() {
+        Json(serde_json::json!({"status": "ready"})).into_response()
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"status": "starting"})),
+        )
+            .into_response()
+    }
+}
+
 #[cfg(feature = "http")]
 /// Metrics handler
 async fn metrics_handler(State(app_state): State<McpAppState>) -> Json<serde_json::Value> {
@@ -1339,6 +1695,9 @@ async fn metrics_handler(State(app_state): State<McpAppState>) -> Json<serde_jso
             "max": app_state.config.max_connections,
             "average_duration_seconds": avg_duration
         },
+        "connections": {
+            "idle_closed": app_state.idle_closed.load(Ordering::Relaxed)
+        },
         "server": {
             "uptime_seconds": std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -1376,6 +1735,36 @@ async fn mcp_middleware(
     Ok(response)
 }
 
+#[cfg(feature = "http")]
+/// HTTP keep-alive middleware - advertises how long idle connections may be reused
+async fn keep_alive_middleware(
+    State(keep_alive): State<Option<Duration>>,
+    request: axum::http::Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+
+    // Protocol upgrades (WebSocket) manage their own Connection header
+    if response.status() == StatusCode::SWITCHING_PROTOCOLS
+        || response.headers().contains_key(header::CONNECTION)
+    {
+        return response;
+    }
+
+    let headers = response.headers_mut();
+    match keep_alive {
+        Some(timeout) => {
+            if let Ok(value) = HeaderValue::from_str(&format!("timeout={}", timeout.as_secs())) {
+                headers.insert(HeaderName::from_static("keep-alive"), value);
+            }
+        }
+        None => {
+            headers.insert(header::CONNECTION, HeaderValue::from_static("close"));
+        }
+    }
+    response
+}
+
 #[cfg(feature = "http")]
 /// Security headers middleware - applies comprehensive security headers
 async fn security_headers_middleware(
@@ -1886,6 +2275,283 @@ mod tests {
         // If this test compiles, our production-grade configuration system works
     }
 
+    /// Service that reports the headers it was given
+    #[derive(Clone)]
+    struct HeaderEchoService;
+
+    #[async_trait::async_trait]
+    impl McpService for HeaderEchoService {
+        async fn process_request(
+            &self,
+            _request: serde_json::Value,
+            _session: &SessionInfo,
+        ) -> McpResult<serde_json::Value> {
+            Ok(serde_json::Value::Null)
+        }
+
+        async fn process_request_with_metadata(
+            &self,
+            _request: serde_json::Value,
+            _session: &SessionInfo,
+            metadata: &TransportMessageMetadata,
+        ) -> McpResult<serde_json::Value> {
+            Ok(serde_json::json!({
+                "authorization": metadata.authorization(),
+                "traceparent": metadata.header("TraceParent"),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_headers_reach_service_metadata() {
+        use tower::Service;
+        let mut router = Router::<()>::turbo_mcp_server(HeaderEchoService);
+        let request = axum::http::Request::post("/mcp")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, "Bearer abc123")
+            .header(
+                "traceparent",
+                "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            )
+            .body(axum::body::Body::from(
+                r#"{"jsonrpc":"2.0","id":1,"method":"tools/list"}"#,
+            ))
+            .unwrap();
+
+        let response = router.call(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["result"]["authorization"], "Bearer abc123");
+        assert_eq!(
+            body["result"]["traceparent"],
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"
+        );
+    }
+
+    /// Send `GET uri` through `router`
+    async fn get(router: &mut Router, uri: &str) -> Response {
+        use tower::Service;
+        let request = axum::http::Request::get(uri)
+            .body(axum::body::Body::empty())
+            .unwrap();
+        router.call(request).await.unwrap()
+    }
+
+    /// Serve the test service with `config` on a local port
+    async fn spawn_server(config: McpServerConfig) -> std::net::SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = Router::<()>::turbo_mcp_server_with_config(TestMcpService, config.clone());
+        tokio::spawn(async move { serve(listener, router, &config).await });
+        addr
+    }
+
+    /// Send `GET uri` to `addr` over a new connection, returning the status line
+    async fn get_over_tcp(addr: std::net::SocketAddr, uri: &str) -> String {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        get_over(&mut stream, uri).await
+    }
+
+    /// Send `GET uri` over `stream`, returning the status line
+    async fn get_over<S>(stream: &mut S, uri: &str) -> String
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let request = format!("GET {uri} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_serve_rejects_connections_over_the_accept_rate() {
+        let config = McpServerConfig::development().with_accept_rate_limit(AcceptRateLimitConfig {
+            connections_per_second: 1,
+            burst: 2,
+        });
+        let addr = spawn_server(config).await;
+
+        assert_eq!(get_over_tcp(addr, "/mcp/health").await, "HTTP/1.1 200 OK");
+        assert_eq!(get_over_tcp(addr, "/mcp/health").await, "HTTP/1.1 200 OK");
+        assert_eq!(
+            get_over_tcp(addr, "/mcp/health").await,
+            "HTTP/1.1 429 Too Many Requests"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve_closes_idle_keep_alive_connections() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let config =
+            McpServerConfig::development().with_http_keep_alive(Some(Duration::from_secs(1)));
+        let addr = spawn_server(config).await;
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /mcp/health HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        // The connection is kept alive after the response, then closed once idle
+        let mut response = String::new();
+        tokio::time::timeout(Duration::from_secs(5), stream.read_to_string(&mut response))
+            .await
+            .expect("idle connection was not closed")
+            .unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+        assert!(response.contains("keep-alive: timeout=1"), "{response}");
+    }
+
+    #[cfg(feature = "tls")]
+    #[tokio::test]
+    async fn test_serve_speaks_tls_when_configured() {
+        use rustls::HandshakeKind;
+        use rustls::pki_types::ServerName;
+
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let dir = std::env::temp_dir().join(format!("turbomcp-serve-tls-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (cert_file, key_file) = (dir.join("cert.pem"), dir.join("key.pem"));
+        std::fs::write(&cert_file, certified.cert.pem()).unwrap();
+        std::fs::write(&key_file, certified.key_pair.serialize_pem()).unwrap();
+        let config = McpServerConfig::development().with_tls(
+            cert_file.display().to_string(),
+            key_file.display().to_string(),
+        );
+        let addr = spawn_server(config).await;
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(certified.cert.der().clone()).unwrap();
+        let client = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = tokio_rustls::TlsConnector::from(Arc::new(client));
+
+        let mut handshakes = Vec::new();
+        for _ in 0..2 {
+            let tcp = tokio::net::TcpStream::connect(addr).await.unwrap();
+            let name = ServerName::try_from("localhost").unwrap();
+            let mut stream = connector.connect(name, tcp).await.unwrap();
+            assert_eq!(
+                get_over(&mut stream, "/mcp/health").await,
+                "HTTP/1.1 200 OK"
+            );
+            handshakes.push(stream.get_ref().1.handshake_kind().unwrap());
+        }
+        // Session resumption from the TLS settings applies too
+        assert_eq!(handshakes, [HandshakeKind::Full, HandshakeKind::Resumed]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(not(feature = "tls"))]
+    #[tokio::test]
+    async fn test_serve_refuses_tls_without_the_tls_feature() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let config =
+            McpServerConfig::development().with_tls("cert.pem".to_string(), "key.pem".to_string());
+        let router = Router::<()>::turbo_mcp_server_with_config(TestMcpService, config.clone());
+        let result = serve(listener, router, &config).await;
+        assert!(matches!(result, Err(TransportError::ConfigurationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_idle_sse_stream_is_closed() {
+        let config =
+            McpServerConfig::development().with_idle_timeout(Some(Duration::from_millis(50)));
+        let mut router = Router::<()>::turbo_mcp_server_with_config(TestMcpService, config);
+
+        let response = get(&mut router, "/mcp/sse").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["keep-alive"], "timeout=60");
+
+        // The body only ends once the stream has gone idle
+        let body = tokio::time::timeout(
+            Duration::from_secs(5),
+            axum::body::to_bytes(response.into_body(), usize::MAX),
+        )
+        .await
+        .expect("idle SSE stream was not closed")
+        .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("event: connected"));
+        assert!(body.contains("event: idle_timeout"));
+
+        let metrics = get(&mut router, "/mcp/metrics").await;
+        let metrics = axum::body::to_bytes(metrics.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let metrics: serde_json::Value = serde_json::from_slice(&metrics).unwrap();
+        assert_eq!(metrics["connections"]["idle_closed"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_keep_alive_closes_connections() {
+        let config = McpServerConfig::development().with_http_keep_alive(None);
+        let mut router = Router::<()>::turbo_mcp_server_with_config(TestMcpService, config);
+
+        let response = get(&mut router, "/mcp/health").await;
+        assert_eq!(response.headers()[header::CONNECTION], "close");
+        assert!(!response.headers().contains_key("keep-alive"));
+    }
+
+    /// Service whose startup is finished by flipping a flag
+    #[derive(Clone, Default)]
+    struct StartingService {
+        ready: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl McpService for StartingService {
+        async fn process_request(
+            &self,
+            _request: serde_json::Value,
+            _session: &SessionInfo,
+        ) -> McpResult<serde_json::Value> {
+            Ok(serde_json::json!({}))
+        }
+
+        fn is_ready(&self) -> bool {
+            self.ready.load(Ordering::SeqCst)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_requests_wait_for_readiness() {
+        use tower::Service;
+        let service = StartingService::default();
+        let ready = Arc::clone(&service.ready);
+        let mut router = Router::<()>::turbo_mcp_server(service);
+        let ping = || {
+            axum::http::Request::post("/mcp")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(axum::body::Body::from(
+                    r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+                ))
+                .unwrap()
+        };
+
+        let probe = get(&mut router, "/mcp/readyz").await;
+        assert_eq!(probe.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let response = router.call(ping()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        // Liveness is unaffected while starting
+        assert_eq!(
+            get(&mut router, "/mcp/health").await.status(),
+            StatusCode::OK
+        );
+
+        ready.store(true, Ordering::SeqCst);
+        let probe = get(&mut router, "/mcp/readyz").await;
+        assert_eq!(probe.status(), StatusCode::OK);
+        let response = router.call(ping()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[test]
     fn test_configuration_loading_logic() {
         // Test TLS configuration parsing logic directly
@@ -1897,11 +2563,13 @@ mod tests {
             key_file: "/etc/ssl/private/server.key".to_string(),
             min_version: TlsVersion::TlsV1_3,
             enable_http2: true,
+            session_resumption: SessionResumption::default(),
         };
         assert_eq!(tls_config.cert_file, "/etc/ssl/certs/server.pem");
         assert_eq!(tls_config.key_file, "/etc/ssl/private/server.key");
         assert!(matches!(tls_config.min_version, TlsVersion::TlsV1_3));
         assert!(tls_config.enable_http2);
+        assert!(tls_config.session_resumption.enabled);
 
         // Test authentication configuration creation
         let auth_config = AuthConfig {
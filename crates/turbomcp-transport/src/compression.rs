@@ -1,12 +1,26 @@
 //! Message compression support
+//!
+//! The algorithm, level and minimum message size come from
+//! [`TransportConfig`]. Defaults favor speed: each codec runs at its fastest
+//! level and messages under [`DEFAULT_COMPRESSION_MIN_SIZE`] bytes are sent as
+//! plain JSON, since compressing them costs more CPU than it saves bandwidth.
+//! Local deployments can leave compression off entirely; bandwidth-constrained
+//! ones can raise the level.
+//!
+//! [`DEFAULT_COMPRESSION_MIN_SIZE`]: crate::core::DEFAULT_COMPRESSION_MIN_SIZE
 
 use std::io::{Read, Write};
 
-use crate::core::{TransportError, TransportResult};
+use bytes::Bytes;
+use turbomcp_core::MessageId;
+
+use crate::core::{
+    TransportConfig, TransportError, TransportMessage, TransportMessageMetadata, TransportResult,
+};
 use serde_json::Value;
 
 /// Compression algorithm
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompressionType {
     /// No compression
     None,
@@ -19,36 +33,201 @@ pub enum CompressionType {
     /// LZ4 compression
     #[cfg(feature = "lz4_flex")]
     Lz4,
+    /// Zstandard compression
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+impl CompressionType {
+    /// Parse an algorithm name as used in configuration and message encodings
+    pub fn from_name(name: &str) -> TransportResult<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "none" | "identity" => Ok(Self::None),
+            #[cfg(feature = "flate2")]
+            "gzip" => Ok(Self::Gzip),
+            #[cfg(feature = "brotli")]
+            "br" | "brotli" => Ok(Self::Brotli),
+            #[cfg(feature = "lz4_flex")]
+            "lz4" => Ok(Self::Lz4),
+            #[cfg(feature = "zstd")]
+            "zstd" => Ok(Self::Zstd),
+            other => Err(TransportError::ConfigurationError(format!(
+                "Unsupported compression algorithm '{other}'"
+            ))),
+        }
+    }
+
+    /// Encoding name recorded in message metadata
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::None => "identity",
+            #[cfg(feature = "flate2")]
+            Self::Gzip => "gzip",
+            #[cfg(feature = "brotli")]
+            Self::Brotli => "br",
+            #[cfg(feature = "lz4_flex")]
+            Self::Lz4 => "lz4",
+            #[cfg(feature = "zstd")]
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Algorithm used when compression is enabled without naming one
+    #[cfg(feature = "zstd")]
+    const fn preferred() -> Self {
+        Self::Zstd
+    }
+
+    #[cfg(all(not(feature = "zstd"), feature = "flate2"))]
+    const fn preferred() -> Self {
+        Self::Gzip
+    }
+
+    #[cfg(not(any(feature = "zstd", feature = "flate2")))]
+    const fn preferred() -> Self {
+        Self::None
+    }
+
+    /// Valid level range, or `None` if the algorithm has no levels
+    const fn level_range(self) -> Option<(u32, u32)> {
+        match self {
+            #[cfg(feature = "flate2")]
+            Self::Gzip => Some((0, 9)),
+            #[cfg(feature = "brotli")]
+            Self::Brotli => Some((0, 11)),
+            #[cfg(feature = "zstd")]
+            Self::Zstd => Some((1, 22)),
+            _ => None,
+        }
+    }
 }
 
 /// Message compressor/decompressor
 #[derive(Debug)]
 pub struct MessageCompressor {
     compression_type: CompressionType,
+    level: Option<u32>,
+    min_size: usize,
 }
 
 impl MessageCompressor {
     /// Create a new message compressor
     #[must_use]
     pub const fn new(compression_type: CompressionType) -> Self {
-        Self { compression_type }
+        Self {
+            compression_type,
+            level: None,
+            min_size: 0,
+        }
+    }
+
+    /// Create a compressor from the compression settings of a transport configuration
+    ///
+    /// Returns a pass-through compressor when compression is disabled.
+    pub fn from_config(config: &TransportConfig) -> TransportResult<Self> {
+        if !config.compression {
+            return Ok(Self::new(CompressionType::None));
+        }
+
+        let compression_type = match config.compression_algorithm.as_deref() {
+            Some(name) => CompressionType::from_name(name)?,
+            None => CompressionType::preferred(),
+        };
+        if let (Some(level), Some((min, max))) =
+            (config.compression_level, compression_type.level_range())
+            && !(min..=max).contains(&level)
+        {
+            return Err(TransportError::ConfigurationError(format!(
+                "Compression level {level} is out of range {min}..={max} for {}",
+                compression_type.name()
+            )));
+        }
+
+        Ok(Self {
+            compression_type,
+            level: config.compression_level,
+            min_size: config.compression_min_size,
+        })
+    }
+
+    /// Set the compression level
+    #[must_use]
+    pub const fn with_level(mut self, level: u32) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Set the message size below which compression is skipped
+    #[must_use]
+    pub const fn with_min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Configured compression algorithm
+    #[must_use]
+    pub const fn compression_type(&self) -> CompressionType {
+        self.compression_type
+    }
+
+    /// Serialize a message into a transport message, compressing it if it is large enough
+    ///
+    /// The algorithm used is recorded in the message's `encoding` metadata so
+    /// that [`decode`](Self::decode) can reverse it.
+    pub fn encode(&self, id: MessageId, message: &Value) -> TransportResult<TransportMessage> {
+        let json_bytes = serde_json::to_vec(message)
+            .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        let mut metadata = TransportMessageMetadata::with_content_type("application/json");
+
+        if self.compression_type == CompressionType::None || json_bytes.len() < self.min_size {
+            return Ok(TransportMessage::with_metadata(
+                id,
+                Bytes::from(json_bytes),
+                metadata,
+            ));
+        }
+
+        let compressed = self.compress_bytes(&json_bytes)?;
+        metadata.encoding = Some(self.compression_type.name().to_string());
+        Ok(TransportMessage::with_metadata(
+            id,
+            Bytes::from(compressed),
+            metadata,
+        ))
+    }
+
+    /// Decode a transport message produced by [`encode`](Self::encode)
+    ///
+    /// Uses the algorithm named in the message's `encoding` metadata, so
+    /// messages from peers configured with a different codec still decode.
+    pub fn decode(&self, message: &TransportMessage) -> TransportResult<Value> {
+        let compression_type = match message.metadata.encoding.as_deref() {
+            None => CompressionType::None,
+            Some(name) => CompressionType::from_name(name)?,
+        };
+        Self::new(compression_type).decompress(&message.payload)
     }
 
     /// Compress a JSON message
     pub fn compress(&self, message: &Value) -> TransportResult<Vec<u8>> {
         let json_bytes = serde_json::to_vec(message)
             .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        self.compress_bytes(&json_bytes)
+    }
 
+    fn compress_bytes(&self, json_bytes: &[u8]) -> TransportResult<Vec<u8>> {
         match self.compression_type {
-            CompressionType::None => Ok(json_bytes),
+            CompressionType::None => Ok(json_bytes.to_vec()),
 
             #[cfg(feature = "flate2")]
             CompressionType::Gzip => {
                 use flate2::{Compression, write::GzEncoder};
 
-                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                let level = self.level.map_or_else(Compression::fast, Compression::new);
+                let mut encoder = GzEncoder::new(Vec::new(), level);
                 encoder
-                    .write_all(&json_bytes)
+                    .write_all(json_bytes)
                     .map_err(|e| TransportError::Internal(e.to_string()))?;
                 encoder
                     .finish()
@@ -59,19 +238,27 @@ impl MessageCompressor {
             CompressionType::Brotli => {
                 use brotli::enc::BrotliEncoderParams;
 
-                let params = BrotliEncoderParams::default();
+                let params = BrotliEncoderParams {
+                    quality: self.level.unwrap_or(1) as i32,
+                    ..BrotliEncoderParams::default()
+                };
                 let mut compressed = Vec::new();
-                brotli::BrotliCompress(&mut json_bytes.as_slice(), &mut compressed, &params)
-                    .map_err(|e| {
-                        TransportError::Internal(format!("Brotli compression failed: {e}"))
-                    })?;
+                brotli::BrotliCompress(&mut &json_bytes[..], &mut compressed, &params).map_err(
+                    |e| TransportError::Internal(format!("Brotli compression failed: {e}")),
+                )?;
                 Ok(compressed)
             }
 
             #[cfg(feature = "lz4_flex")]
             CompressionType::Lz4 => {
                 use lz4_flex::compress_prepend_size;
-                Ok(compress_prepend_size(&json_bytes))
+                Ok(compress_prepend_size(json_bytes))
+            }
+
+            #[cfg(feature = "zstd")]
+            CompressionType::Zstd => {
+                zstd::bulk::compress(json_bytes, self.level.unwrap_or(1) as i32)
+                    .map_err(|e| TransportError::Internal(format!("Zstd compression failed: {e}")))
             }
         }
     }
@@ -108,6 +295,10 @@ impl MessageCompressor {
                 decompress_size_prepended(compressed)
                     .map_err(|e| TransportError::Internal(e.to_string()))?
             }
+
+            #[cfg(feature = "zstd")]
+            CompressionType::Zstd => zstd::stream::decode_all(compressed)
+                .map_err(|e| TransportError::Internal(format!("Zstd decompression failed: {e}")))?,
         };
 
         serde_json::from_slice(&json_bytes)
@@ -137,6 +328,70 @@ mod tests {
         assert_eq!(message, decompressed);
     }
 
+    fn config_with(algorithm: &str, level: Option<u32>) -> TransportConfig {
+        TransportConfig {
+            compression: true,
+            compression_algorithm: Some(algorithm.to_string()),
+            compression_level: level,
+            ..TransportConfig::default()
+        }
+    }
+
+    fn large_message() -> Value {
+        json!({"jsonrpc": "2.0", "id": 1, "result": {"text": "lorem ipsum ".repeat(500)}})
+    }
+
+    #[test]
+    fn test_compression_disabled_by_default() {
+        let compressor = MessageCompressor::from_config(&TransportConfig::default()).unwrap();
+        assert_eq!(compressor.compression_type(), CompressionType::None);
+
+        let encoded = compressor
+            .encode(MessageId::from("1"), &large_message())
+            .unwrap();
+        assert!(encoded.metadata.encoding.is_none());
+        assert_eq!(compressor.decode(&encoded).unwrap(), large_message());
+    }
+
+    #[test]
+    fn test_unknown_algorithm_rejected() {
+        let result = MessageCompressor::from_config(&config_with("snappy", None));
+        assert!(matches!(result, Err(TransportError::ConfigurationError(_))));
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn test_small_messages_skip_compression() {
+        let compressor = MessageCompressor::from_config(&config_with("gzip", Some(9))).unwrap();
+
+        let small = json!({"jsonrpc": "2.0", "id": 1, "method": "ping"});
+        let encoded = compressor.encode(MessageId::from("small"), &small).unwrap();
+        assert!(encoded.metadata.encoding.is_none());
+        assert_eq!(encoded.payload, serde_json::to_vec(&small).unwrap());
+
+        let large = large_message();
+        let encoded = compressor.encode(MessageId::from("large"), &large).unwrap();
+        assert_eq!(encoded.metadata.encoding.as_deref(), Some("gzip"));
+        assert!(encoded.payload.len() < serde_json::to_vec(&large).unwrap().len());
+        assert_eq!(compressor.decode(&encoded).unwrap(), large);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_level_is_validated_and_applied() {
+        assert!(MessageCompressor::from_config(&config_with("zstd", Some(23))).is_err());
+
+        let compressor = MessageCompressor::from_config(&config_with("zstd", Some(19))).unwrap();
+        let encoded = compressor
+            .encode(MessageId::from("1"), &large_message())
+            .unwrap();
+        assert_eq!(encoded.metadata.encoding.as_deref(), Some("zstd"));
+
+        // A peer configured for a different codec still decodes by encoding name
+        let other = MessageCompressor::new(CompressionType::None);
+        assert_eq!(other.decode(&encoded).unwrap(), large_message());
+    }
+
     #[cfg(feature = "lz4_flex")]
     #[test]
     fn test_lz4_compression() {
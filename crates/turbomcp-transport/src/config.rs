@@ -3,7 +3,9 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
-use crate::core::{TransportConfig, TransportError, TransportResult, TransportType};
+use crate::core::{
+    DEFAULT_COMPRESSION_MIN_SIZE, TransportConfig, TransportError, TransportResult, TransportType,
+};
 
 /// Builder for transport configurations
 #[derive(Debug, Clone)]
@@ -16,6 +18,8 @@ pub struct TransportConfigBuilder {
     max_connections: Option<usize>,
     compression: bool,
     compression_algorithm: Option<String>,
+    compression_level: Option<u32>,
+    compression_min_size: usize,
     custom: HashMap<String, serde_json::Value>,
 }
 
@@ -32,6 +36,8 @@ impl TransportConfigBuilder {
             max_connections: None,
             compression: false,
             compression_algorithm: None,
+            compression_level: None,
+            compression_min_size: DEFAULT_COMPRESSION_MIN_SIZE,
             custom: HashMap::new(),
         }
     }
@@ -84,6 +90,20 @@ impl TransportConfigBuilder {
         self
     }
 
+    /// Set compression level (higher is smaller but slower)
+    #[must_use]
+    pub const fn compression_level(mut self, level: u32) -> Self {
+        self.compression_level = Some(level);
+        self
+    }
+
+    /// Set the message size below which compression is skipped
+    #[must_use]
+    pub const fn compression_min_size(mut self, bytes: usize) -> Self {
+        self.compression_min_size = bytes;
+        self
+    }
+
     /// Add custom configuration
     pub fn custom(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
         self.custom.insert(key.into(), value.into());
@@ -116,6 +136,8 @@ impl TransportConfigBuilder {
             max_connections: self.max_connections,
             compression: self.compression,
             compression_algorithm: self.compression_algorithm,
+            compression_level: self.compression_level,
+            compression_min_size: self.compression_min_size,
             custom: self.custom,
         })
     }
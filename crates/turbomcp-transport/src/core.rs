@@ -162,13 +162,32 @@ pub struct TransportConfig {
     /// Enable compression
     pub compression: bool,
 
-    /// Compression algorithm preference
+    /// Compression algorithm preference (`gzip`, `zstd`, `br`, `lz4` or `none`)
     pub compression_algorithm: Option<String>,
 
+    /// Compression level; `None` uses the algorithm's fastest level
+    #[serde(default)]
+    pub compression_level: Option<u32>,
+
+    /// Messages smaller than this many bytes are sent uncompressed
+    #[serde(default = "default_compression_min_size")]
+    pub compression_min_size: usize,
+
     /// Custom configuration
     pub custom: HashMap<String, serde_json::Value>,
 }
 
+/// Default size below which messages are not worth compressing, in bytes
+pub const DEFAULT_COMPRESSION_MIN_SIZE: usize = 1024;
+
+const fn default_compression_min_size() -> usize {
+    DEFAULT_COMPRESSION_MIN_SIZE
+}
+
+/// How long a send may wait on a peer that has stopped reading when
+/// [`TransportConfig::write_timeout`] is not set
+pub const DEFAULT_WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Transport message wrapper
 #[derive(Debug, Clone)]
 pub struct TransportMessage {
@@ -182,7 +201,19 @@ pub struct TransportMessage {
     pub metadata: TransportMessageMetadata,
 }
 
+/// Extension key under which a resumable stream records a message's event id
+///
+/// The client skips notifications redelivered under an id it has already
+/// seen, and reports the last id it saw for resuming the stream.
+pub const EVENT_ID_EXTENSION: &str = "sse.event_id";
+
 /// Transport message metadata
+///
+/// Besides the common fields, transports attach what they know about a
+/// message's origin here so handlers can use it for authentication and
+/// tracing: HTTP transports copy request headers into `headers` (with
+/// lower-cased names), and anything else goes in `extensions` under a key
+/// prefixed with the transport name, such as `unix.peer_uid`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TransportMessageMetadata {
     /// Content encoding
@@ -205,6 +236,10 @@ pub struct TransportMessageMetadata {
 
     /// Heartbeat marker
     pub is_heartbeat: Option<bool>,
+
+    /// Transport-specific context, keyed by `<transport>.<name>`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, serde_json::Value>,
 }
 
 /// Transport metrics
@@ -228,6 +263,10 @@ pub struct TransportMetrics {
     /// Failed connections
     pub failed_connections: u64,
 
+    /// Incoming connections closed by an accept rate limit
+    #[serde(default)]
+    pub rejected_connections: u64,
+
     /// Average latency in milliseconds
     pub average_latency_ms: f64,
 
@@ -290,6 +329,9 @@ pub enum TransportEvent {
     },
 }
 
+/// Longest pause between polls in the default [`Transport::receive_next`]
+pub const MAX_RECEIVE_BACKOFF: Duration = Duration::from_millis(50);
+
 /// Core transport trait
 #[async_trait]
 pub trait Transport: Send + Sync + std::fmt::Debug {
@@ -314,6 +356,24 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
     /// Receive a message (non-blocking)
     async fn receive(&mut self) -> TransportResult<Option<TransportMessage>>;
 
+    /// Wait for the next message
+    ///
+    /// Where [`receive`](Self::receive) returns `None` when nothing is
+    /// waiting, this returns once a message arrives or the transport fails.
+    /// The default polls `receive`, backing off up to
+    /// [`MAX_RECEIVE_BACKOFF`] between attempts; transports that can wait on
+    /// their input override it. Dropping the future loses no message.
+    async fn receive_next(&mut self) -> TransportResult<TransportMessage> {
+        let mut backoff = Duration::from_millis(1);
+        loop {
+            if let Some(message) = self.receive().await? {
+                return Ok(message);
+            }
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECEIVE_BACKOFF);
+        }
+    }
+
     /// Get transport metrics
     async fn metrics(&self) -> TransportMetrics;
 
@@ -335,6 +395,59 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
     }
 }
 
+/// Boxed transports are transports, so transports chosen at runtime can be
+/// used wherever a concrete transport type is expected
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn transport_type(&self) -> TransportType {
+        (**self).transport_type()
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        (**self).capabilities()
+    }
+
+    async fn state(&self) -> TransportState {
+        (**self).state().await
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        (**self).connect().await
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        (**self).disconnect().await
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        (**self).send(message).await
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        (**self).receive().await
+    }
+
+    async fn receive_next(&mut self) -> TransportResult<TransportMessage> {
+        (**self).receive_next().await
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        (**self).metrics().await
+    }
+
+    async fn is_connected(&self) -> bool {
+        (**self).is_connected().await
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        (**self).endpoint()
+    }
+
+    async fn configure(&mut self, config: TransportConfig) -> TransportResult<()> {
+        (**self).configure(config).await
+    }
+}
+
 /// Bidirectional transport trait for full-duplex communication
 #[async_trait]
 pub trait BidirectionalTransport: Transport {
@@ -547,6 +660,8 @@ impl Default for TransportConfig {
             max_connections: None,
             compression: false,
             compression_algorithm: None,
+            compression_level: None,
+            compression_min_size: DEFAULT_COMPRESSION_MIN_SIZE,
             custom: HashMap::new(),
         }
     }
@@ -639,6 +754,46 @@ impl TransportMessageMetadata {
         self.is_heartbeat = Some(true);
         self
     }
+
+    /// Attach a transport-specific value
+    #[must_use]
+    pub fn with_extension(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.extensions.insert(key.into(), value.into());
+        self
+    }
+
+    /// Look up a header, ignoring the case of its name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The `Authorization` header, if the transport carried one
+    pub fn authorization(&self) -> Option<&str> {
+        self.header("authorization")
+    }
+
+    /// The stream event id the message arrived under, if any
+    ///
+    /// See [`EVENT_ID_EXTENSION`].
+    pub fn event_id(&self) -> Option<&str> {
+        self.extensions.get(EVENT_ID_EXTENSION)?.as_str()
+    }
+
+    /// Read a transport-specific value as `T`
+    ///
+    /// Returns `None` if the key is absent or its value is not a `T`.
+    pub fn extension<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.extensions
+            .get(key)
+            .and_then(|value| T::deserialize(value).ok())
+    }
 }
 
 impl fmt::Display for TransportType {
@@ -676,6 +831,26 @@ impl From<std::io::Error> for TransportError {
     }
 }
 
+impl TransportError {
+    /// Classify an error from writing to a peer
+    ///
+    /// A broken pipe or reset means the peer closed its read side or went
+    /// away entirely, so the connection is reported as lost rather than as
+    /// a one-off send failure.
+    #[must_use]
+    pub fn from_write_error(err: &std::io::Error) -> Self {
+        use std::io::ErrorKind;
+        match err.kind() {
+            ErrorKind::BrokenPipe
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::WriteZero => Self::ConnectionLost(format!("peer stopped reading: {err}")),
+            _ => Self::SendFailed(err.to_string()),
+        }
+    }
+}
+
 impl From<serde_json::Error> for TransportError {
     fn from(err: serde_json::Error) -> Self {
         Self::SerializationFailed(err.to_string())
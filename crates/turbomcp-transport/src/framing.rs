@@ -0,0 +1,380 @@
+//! Incremental framing for newline-delimited JSON
+//!
+//! A line codec only looks at a message once its final newline arrives, so a
+//! malformed or oversized message is buffered in full before anything can
+//! reject it. [`JsonFrameCodec`] instead checks each chunk as it is read: it
+//! tracks the JSON nesting and string state across reads, fails a frame as
+//! soon as its structure goes wrong or it grows past the size limit, and
+//! discards the rest of that line without buffering it. Complete frames are
+//! handed out as [`Bytes`] split off the read buffer, without copying.
+//!
+//! The scan is structural only: brackets, strings and escapes. Full syntax is
+//! left to the parser that consumes the frame.
+//!
+//! Lines may end in `\n` or `\r\n`. Some Windows tools open their output with
+//! a UTF-8 byte order mark, which is dropped from the front of a frame unless
+//! [`JsonFrameCodec::with_bom_stripping`] turns that off. A UTF-16 byte order
+//! mark fails the frame, since the protocol stream must be UTF-8.
+
+use bytes::{Buf, Bytes, BytesMut};
+use tokio_util::codec::Decoder;
+
+/// UTF-8 encoding of U+FEFF
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Why a frame was rejected
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FrameError {
+    /// The frame grew past the size limit
+    #[error("Message exceeds {max} bytes")]
+    TooLarge {
+        /// Size limit in bytes
+        max: usize,
+    },
+    /// The frame cannot be a JSON object or array
+    #[error("Malformed message at byte {offset}: {reason}")]
+    Malformed {
+        /// Offset into the frame where the problem was found
+        offset: usize,
+        /// What was wrong
+        reason: &'static str,
+    },
+}
+
+/// Scanner state for the frame at the front of the buffer
+#[derive(Debug, Default)]
+struct Scan {
+    /// Bytes of the frame already examined
+    scanned: usize,
+    /// Whether the opening bracket has been seen
+    started: bool,
+    /// Whether the outermost value has been closed
+    closed: bool,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl Scan {
+    /// Advance over one byte of the frame, other than its newline
+    fn feed(&mut self, byte: u8) -> Result<(), &'static str> {
+        if self.in_string {
+            match byte {
+                _ if self.escaped => self.escaped = false,
+                b'\\' => self.escaped = true,
+                b'"' => self.in_string = false,
+                _ => {}
+            }
+            return Ok(());
+        }
+        match byte {
+            b' ' | b'\t' | b'\r' => {}
+            _ if self.closed => return Err("trailing data after the message"),
+            b'{' | b'[' => {
+                self.started = true;
+                self.depth += 1;
+            }
+            _ if !self.started => return Err("expected an object or array"),
+            b'}' | b']' => {
+                self.depth -= 1;
+                self.closed = self.depth == 0;
+            }
+            b'"' => self.in_string = true,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Decoder splitting a byte stream into newline-delimited JSON frames
+///
+/// Frames are yielded as `Ok(Ok(frame))` with surrounding whitespace trimmed;
+/// blank lines are skipped. A rejected frame is yielded as `Ok(Err(_))` and
+/// the stream carries on with the next line, so one bad message does not end
+/// the connection.
+#[derive(Debug)]
+pub struct JsonFrameCodec {
+    max_frame_size: usize,
+    scan: Scan,
+    /// Dropping input up to the next newline after a rejected frame
+    discarding: bool,
+    strip_bom: bool,
+}
+
+impl JsonFrameCodec {
+    /// Create a codec rejecting frames larger than `max_frame_size` bytes
+    #[must_use]
+    pub fn new(max_frame_size: usize) -> Self {
+        Self {
+            max_frame_size,
+            scan: Scan::default(),
+            discarding: false,
+            strip_bom: true,
+        }
+    }
+
+    /// Set whether a UTF-8 byte order mark ahead of a frame is dropped
+    ///
+    /// On by default. When off, such a frame is rejected as malformed.
+    #[must_use]
+    pub const fn with_bom_stripping(mut self, strip: bool) -> Self {
+        self.strip_bom = strip;
+        self
+    }
+
+    /// Largest frame accepted, in bytes
+    #[must_use]
+    pub const fn max_frame_size(&self) -> usize {
+        self.max_frame_size
+    }
+
+    /// Drop the rejected frame, and anything after it up to the next newline
+    fn reject(&mut self, buf: &mut BytesMut, error: FrameError) -> Result<Bytes, FrameError> {
+        self.scan = Scan::default();
+        match buf.iter().position(|&b| b == b'\n') {
+            Some(newline) => buf.advance(newline + 1),
+            None => {
+                buf.clear();
+                self.discarding = true;
+            }
+        }
+        Err(error)
+    }
+
+    /// Handle a byte order mark at the start of a frame
+    ///
+    /// Returns `None` while a partial UTF-8 mark waits for its remaining bytes.
+    fn skip_bom(&mut self, buf: &mut BytesMut) -> Option<Result<(), FrameError>> {
+        if self.strip_bom {
+            if buf.starts_with(UTF8_BOM) {
+                buf.advance(UTF8_BOM.len());
+            } else if !buf.is_empty() && UTF8_BOM.starts_with(&buf[..]) {
+                return None;
+            }
+        }
+        if buf.starts_with(b"\xFF\xFE") || buf.starts_with(b"\xFE\xFF") {
+            let error = FrameError::Malformed {
+                offset: 0,
+                reason: "UTF-16 byte order mark; messages must be UTF-8",
+            };
+            return Some(self.reject(buf, error).map(drop));
+        }
+        Some(Ok(()))
+    }
+
+    /// Split off a complete frame of `len` bytes, followed by `terminator` bytes
+    fn take(
+        &mut self,
+        buf: &mut BytesMut,
+        len: usize,
+        terminator: usize,
+    ) -> Option<Result<Bytes, FrameError>> {
+        let scan = std::mem::take(&mut self.scan);
+        let frame = buf.split_to(len).freeze();
+        buf.advance(terminator);
+        if !scan.started {
+            return None;
+        }
+        if !scan.closed {
+            return Some(Err(FrameError::Malformed {
+                offset: len,
+                reason: "message ends before its closing bracket",
+            }));
+        }
+        let start = frame
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .unwrap_or(0);
+        let end = frame
+            .iter()
+            .rposition(|b| !b.is_ascii_whitespace())
+            .map_or(start, |last| last + 1);
+        Some(Ok(frame.slice(start..end)))
+    }
+}
+
+impl Default for JsonFrameCodec {
+    fn default() -> Self {
+        Self::new(turbomcp_core::MAX_MESSAGE_SIZE)
+    }
+}
+
+impl Decoder for JsonFrameCodec {
+    type Item = Result<Bytes, FrameError>;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            if self.discarding {
+                match buf.iter().position(|&b| b == b'\n') {
+                    Some(newline) => {
+                        buf.advance(newline + 1);
+                        self.discarding = false;
+                    }
+                    None => {
+                        buf.clear();
+                        return Ok(None);
+                    }
+                }
+            }
+
+            if self.scan.scanned == 0 {
+                match self.skip_bom(buf) {
+                    Some(Ok(())) => {}
+                    Some(Err(error)) => return Ok(Some(Err(error))),
+                    None => return Ok(None),
+                }
+            }
+
+            // Only bytes that arrived since the last call need looking at
+            let mut newline = None;
+            for offset in self.scan.scanned..buf.len() {
+                let byte = buf[offset];
+                if byte == b'\n' {
+                    newline = Some(offset);
+                    break;
+                }
+                if offset >= self.max_frame_size {
+                    let error = FrameError::TooLarge {
+                        max: self.max_frame_size,
+                    };
+                    return Ok(Some(self.reject(buf, error)));
+                }
+                if let Err(reason) = self.scan.feed(byte) {
+                    let error = FrameError::Malformed { offset, reason };
+                    return Ok(Some(self.reject(buf, error)));
+                }
+            }
+
+            let Some(newline) = newline else {
+                self.scan.scanned = buf.len();
+                return Ok(None);
+            };
+            if let Some(frame) = self.take(buf, newline, 1) {
+                return Ok(Some(frame));
+            }
+            // Blank line; look for the next frame
+        }
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some(frame) = self.decode(buf)? {
+            return Ok(Some(frame));
+        }
+        // The last message may be missing its newline
+        if self.discarding || buf.is_empty() {
+            buf.clear();
+            return Ok(None);
+        }
+        let len = buf.len();
+        Ok(self.take(buf, len, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_all(codec: &mut JsonFrameCodec, input: &[u8]) -> Vec<Result<Bytes, FrameError>> {
+        let mut buf = BytesMut::from(input);
+        let mut frames = Vec::new();
+        while let Some(frame) = codec.decode(&mut buf).unwrap() {
+            frames.push(frame);
+        }
+        while let Some(frame) = codec.decode_eof(&mut buf).unwrap() {
+            frames.push(frame);
+        }
+        frames
+    }
+
+    #[test]
+    fn test_frames_split_on_newlines_outside_brackets() {
+        let mut codec = JsonFrameCodec::default();
+        let frames = decode_all(
+            &mut codec,
+            b"{\"a\":\"}\\\"{\"}\n\n  [1,{\"b\":[]}] \r\n{\"c\":1}",
+        );
+        let frames: Vec<_> = frames.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            frames,
+            vec![
+                Bytes::from_static(b"{\"a\":\"}\\\"{\"}"),
+                Bytes::from_static(b"[1,{\"b\":[]}]"),
+                Bytes::from_static(b"{\"c\":1}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bad_frames_are_rejected_and_skipped() {
+        let mut codec = JsonFrameCodec::new(16);
+        let frames = decode_all(
+            &mut codec,
+            b"hello\n{\"a\":1}}\n{\"padding\":\"xxxxxxxxxx\"}\n{\"a\":\n{\"ok\":true}\n",
+        );
+        assert!(matches!(
+            frames[0],
+            Err(FrameError::Malformed { offset: 0, .. })
+        ));
+        assert!(matches!(
+            frames[1],
+            Err(FrameError::Malformed { offset: 7, .. })
+        ));
+        assert_eq!(frames[2], Err(FrameError::TooLarge { max: 16 }));
+        assert!(matches!(frames[3], Err(FrameError::Malformed { .. })));
+        assert_eq!(frames[4], Ok(Bytes::from_static(b"{\"ok\":true}")));
+        assert_eq!(frames.len(), 5);
+    }
+
+    #[test]
+    fn test_byte_order_marks() {
+        let input =
+            b"\xEF\xBB\xBF{\"a\":1}\r\n\xEF\xBB\xBF[2]\r\n\xFF\xFE{\x00}\x00\n{\"b\":3}\r\n";
+        let frames = decode_all(&mut JsonFrameCodec::default(), input);
+        assert_eq!(frames[0], Ok(Bytes::from_static(b"{\"a\":1}")));
+        assert_eq!(frames[1], Ok(Bytes::from_static(b"[2]")));
+        assert!(matches!(
+            frames[2],
+            Err(FrameError::Malformed { offset: 0, .. })
+        ));
+        assert_eq!(frames[3], Ok(Bytes::from_static(b"{\"b\":3}")));
+        assert_eq!(frames.len(), 4);
+
+        let frames = decode_all(
+            &mut JsonFrameCodec::default().with_bom_stripping(false),
+            input,
+        );
+        assert!(frames[0].is_err());
+        assert!(frames[1].is_err());
+    }
+
+    #[test]
+    fn test_byte_order_mark_split_across_reads() {
+        let mut codec = JsonFrameCodec::default();
+        let mut buf = BytesMut::from(&b"\xEF\xBB"[..]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        buf.extend_from_slice(b"\xBF{\"a\":1}\n");
+        let frame = codec.decode(&mut buf).unwrap();
+        assert_eq!(frame, Some(Ok(Bytes::from_static(b"{\"a\":1}"))));
+    }
+
+    #[test]
+    fn test_oversized_frame_is_rejected_before_it_ends() {
+        let mut codec = JsonFrameCodec::new(1024);
+        let mut buf = BytesMut::from(&b"{\"data\":\""[..]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+
+        // The rest of the line is dropped as it arrives instead of piling up
+        buf.extend_from_slice(&[b'x'; 2048]);
+        let rejected = codec.decode(&mut buf).unwrap();
+        assert_eq!(rejected, Some(Err(FrameError::TooLarge { max: 1024 })));
+        assert!(buf.is_empty());
+        buf.extend_from_slice(&[b'x'; 2048]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        assert!(buf.is_empty());
+
+        buf.extend_from_slice(b"\"}\n{\"next\":1}\n");
+        let next = codec.decode(&mut buf).unwrap();
+        assert_eq!(next, Some(Ok(Bytes::from_static(b"{\"next\":1}"))));
+    }
+}
@@ -20,6 +20,22 @@
 //! - **Message Deduplication**: Prevention of duplicate message processing
 //! - **Graceful Degradation**: Maintained service availability during failures
 //!
+//! ## Message Ordering
+//!
+//! Within one connection every transport delivers messages in the order they
+//! were sent, since stdio, TCP, Unix sockets and WebSocket are single ordered
+//! streams. Nothing orders messages across connections, so a session spread
+//! over a connection pool, concurrent HTTP requests, or a reconnect can see
+//! them reordered. Servers built with notification sequencing stamp each
+//! notification using `turbomcp_protocol::NotificationSequencer`; the client
+//! restores send order with `NotificationReorderBuffer`, giving up on a gap
+//! that stays open too long.
+//!
+//! A resumed stream may also replay messages the client already received.
+//! Transports that know each message's event id record it under
+//! [`core::EVENT_ID_EXTENSION`], and the client drops notifications it has
+//! already seen under that id.
+//!
 //! ## Module Organization
 //!
 //! ```text
@@ -33,7 +49,10 @@
 //! ├── unix/           # Unix domain socket implementation
 //! ├── compression/    # Message compression support
 //! ├── pool/           # Connection pooling utilities
-//! └── metrics/        # Transport performance metrics
+//! ├── replay/         # Session recording and deterministic replay
+//! ├── tls/            # TLS session resumption and certificate pinning
+//! ├── metrics/        # Transport performance metrics
+//! └── wire_trace/     # Wire-level message tracing for debugging
 //! ```
 
 #![warn(
@@ -56,6 +75,7 @@
     clippy::default_trait_access  // Default::default() is sometimes clearer
 )]
 
+pub mod accept_limit;
 pub mod core;
 
 #[cfg(feature = "stdio")]
@@ -82,9 +102,13 @@ pub mod child_process;
 pub mod compression;
 
 pub mod config;
+pub mod framing;
 pub mod metrics;
 pub mod pool;
+pub mod replay;
 pub mod robustness;
+pub mod tls;
+pub mod wire_trace;
 
 // Re-export core transport traits and types
 pub use core::{
@@ -116,12 +140,16 @@ pub use unix::UnixTransport;
 pub use child_process::{ChildProcessConfig, ChildProcessTransport};
 
 // Re-export utilities
+pub use accept_limit::{AcceptRateLimitConfig, AcceptRateLimiter};
 pub use config::TransportConfigBuilder;
+pub use framing::{FrameError, JsonFrameCodec};
 pub use pool::ConnectionPool;
+pub use replay::{RecordedMessage, RecordingTransport, ReplayTransport};
 pub use robustness::{
     CircuitBreakerConfig, CircuitBreakerStats, CircuitState, HealthCheckConfig, HealthInfo,
-    HealthStatus, RetryConfig, RobustTransport,
+    HealthStatus, JitterStrategy, RetryConfig, RobustTransport,
 };
+pub use wire_trace::{WireDirection, WireTracer};
 
 /// Transport feature detection
 #[derive(Debug)]
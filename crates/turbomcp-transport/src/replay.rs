@@ -0,0 +1,374 @@
+//! Session recording and deterministic replay
+//!
+//! To reproduce a protocol bug, record the session where it happened with a
+//! [`RecordingTransport`] and drive the same client or server through it
+//! again in a test with a [`ReplayTransport`]. Either side can be recorded:
+//! wrap the client's transport to replay server behaviour to a client, or the
+//! server's transport to replay a client's requests to a server.
+//!
+//! # File format
+//!
+//! A recording is a JSON Lines file with one [`RecordedMessage`] per line, in
+//! the order the messages crossed the recorded transport:
+//!
+//! ```text
+//! {"direction":"outbound","elapsedMs":0,"id":"1","payload":{"jsonrpc":"2.0","id":"1","method":"tools/list"},"metadata":{...}}
+//! {"direction":"inbound","elapsedMs":3,"id":"1","payload":{"jsonrpc":"2.0","id":"1","result":{"tools":[]}},"metadata":{...}}
+//! ```
+//!
+//! - `direction` is relative to the recorded side: `outbound` messages were
+//!   sent by it and `inbound` messages were received from its peer.
+//! - `elapsedMs` is the time since the recording started. Replay ignores it.
+//! - `payload` holds the message as JSON when it parses as JSON, and
+//!   `payloadText` holds it as a string otherwise.
+//! - `metadata` is the message's [`TransportMessageMetadata`].
+//!
+//! Recordings contain full payloads, including tool arguments and results, so
+//! treat them like wire traces and scrub secrets before sharing them.
+
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use turbomcp_core::MessageId;
+
+use crate::core::{
+    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMessageMetadata,
+    TransportMetrics, TransportResult, TransportState, TransportType,
+};
+use crate::wire_trace::WireDirection;
+
+/// One message in a recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedMessage {
+    /// Whether the recorded side sent or received the message
+    pub direction: WireDirection,
+    /// Milliseconds since the recording started
+    pub elapsed_ms: u64,
+    /// Transport message id
+    pub id: MessageId,
+    /// Payload, when it is valid JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Value>,
+    /// Payload as text, when it is not valid JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_text: Option<String>,
+    /// Transport message metadata
+    #[serde(default)]
+    pub metadata: TransportMessageMetadata,
+}
+
+impl RecordedMessage {
+    /// Record `message` as crossing the transport in `direction`
+    #[must_use]
+    pub fn new(direction: WireDirection, elapsed_ms: u64, message: &TransportMessage) -> Self {
+        let (payload, payload_text) = match serde_json::from_slice(&message.payload) {
+            Ok(value) => (Some(value), None),
+            Err(_) => (
+                None,
+                Some(String::from_utf8_lossy(&message.payload).into_owned()),
+            ),
+        };
+        Self {
+            direction,
+            elapsed_ms,
+            id: message.id.clone(),
+            payload,
+            payload_text,
+            metadata: message.metadata.clone(),
+        }
+    }
+
+    /// Rebuild the transport message
+    ///
+    /// JSON payloads are re-serialized, so insignificant whitespace in the
+    /// original bytes is not preserved.
+    #[must_use]
+    pub fn to_message(&self) -> TransportMessage {
+        let payload = match (&self.payload, &self.payload_text) {
+            (Some(value), _) => Bytes::from(serde_json::to_vec(value).unwrap_or_default()),
+            (None, Some(text)) => Bytes::from(text.clone()),
+            (None, None) => Bytes::new(),
+        };
+        TransportMessage::with_metadata(self.id.clone(), payload, self.metadata.clone())
+    }
+
+    /// Whether `message` carries the same payload as this record
+    fn matches(&self, message: &TransportMessage) -> bool {
+        let other = Self::new(self.direction, 0, message);
+        self.payload == other.payload && self.payload_text == other.payload_text
+    }
+}
+
+/// Read a recording written by [`RecordingTransport`]
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a
+/// [`RecordedMessage`].
+pub fn read_recording(path: impl AsRef<Path>) -> TransportResult<Vec<RecordedMessage>> {
+    let file = File::open(path.as_ref()).map_err(|e| TransportError::Io(e.to_string()))?;
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| TransportError::Io(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line).map_err(|e| {
+            TransportError::SerializationFailed(format!("Recording line {}: {e}", index + 1))
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Wraps a transport and records every message it sends or receives
+pub struct RecordingTransport<T> {
+    inner: T,
+    sink: Box<dyn Write + Send + Sync>,
+    started: Instant,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RecordingTransport<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingTransport")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Record `inner`'s messages as JSON lines written to `sink`
+    pub fn new(inner: T, sink: impl Write + Send + Sync + 'static) -> Self {
+        Self {
+            inner,
+            sink: Box::new(sink),
+            started: Instant::now(),
+        }
+    }
+
+    /// Record `inner`'s messages to a new file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created.
+    pub fn to_file(inner: T, path: impl AsRef<Path>) -> TransportResult<Self> {
+        let file = File::create(path.as_ref()).map_err(|e| TransportError::Io(e.to_string()))?;
+        Ok(Self::new(inner, BufWriter::new(file)))
+    }
+
+    /// The wrapped transport
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Stop recording and return the wrapped transport
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn record(
+        &mut self,
+        direction: WireDirection,
+        message: &TransportMessage,
+    ) -> TransportResult<()> {
+        let elapsed_ms = u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let record = RecordedMessage::new(direction, elapsed_ms, message);
+        let line = serde_json::to_string(&record)
+            .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+        // Flush each line so a crash still leaves a usable recording
+        writeln!(self.sink, "{line}")
+            .and_then(|()| self.sink.flush())
+            .map_err(|e| TransportError::Io(format!("Failed to write recording: {e}")))
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn transport_type(&self) -> TransportType {
+        self.inner.transport_type()
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn state(&self) -> TransportState {
+        self.inner.state().await
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        self.inner.connect().await
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        self.inner.disconnect().await
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        self.record(WireDirection::Outbound, &message)?;
+        self.inner.send(message).await
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        let message = self.inner.receive().await?;
+        if let Some(message) = &message {
+            self.record(WireDirection::Inbound, message)?;
+        }
+        Ok(message)
+    }
+
+    async fn receive_next(&mut self) -> TransportResult<TransportMessage> {
+        let message = self.inner.receive_next().await?;
+        self.record(WireDirection::Inbound, &message)?;
+        Ok(message)
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        self.inner.metrics().await
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        self.inner.endpoint()
+    }
+}
+
+/// Plays back a recording in place of the recorded side's peer
+///
+/// Inbound records are returned from [`receive`](Transport::receive) and
+/// outbound records are the messages [`send`](Transport::send) expects, in
+/// recorded order. `receive` returns `None` while the next record is one the
+/// side under test has yet to send, and once the recording is exhausted.
+/// A sent message that differs from the recorded payload, or that is sent
+/// when the recording expects a message to be received first, fails with a
+/// protocol error naming the record.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    records: VecDeque<RecordedMessage>,
+    position: usize,
+    state: TransportState,
+    capabilities: TransportCapabilities,
+    metrics: TransportMetrics,
+}
+
+impl ReplayTransport {
+    /// Replay `records` in order
+    #[must_use]
+    pub fn new(records: Vec<RecordedMessage>) -> Self {
+        Self {
+            records: records.into(),
+            position: 0,
+            state: TransportState::Disconnected,
+            capabilities: TransportCapabilities::default(),
+            metrics: TransportMetrics::default(),
+        }
+    }
+
+    /// Replay the recording at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recording cannot be read.
+    pub fn from_file(path: impl AsRef<Path>) -> TransportResult<Self> {
+        read_recording(path).map(Self::new)
+    }
+
+    /// Whether every recorded message has been replayed
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Number of recorded messages not yet replayed
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.records.len()
+    }
+
+    fn diverged(&self, reason: String) -> TransportError {
+        TransportError::ProtocolError(format!(
+            "Replay diverged at record {}: {reason}",
+            self.position + 1
+        ))
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        self.state.clone()
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        self.state = TransportState::Connected;
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        self.state = TransportState::Disconnected;
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        let expected = match self.records.front() {
+            None => return Err(self.diverged("recording ended, but a message was sent".into())),
+            Some(record) if record.direction == WireDirection::Inbound => {
+                return Err(self.diverged(
+                    "a message was sent before the recorded response was received".into(),
+                ));
+            }
+            Some(record) => record,
+        };
+        if !expected.matches(&message) {
+            let sent = String::from_utf8_lossy(&message.payload).into_owned();
+            let recorded = expected.to_message();
+            let recorded = String::from_utf8_lossy(&recorded.payload).into_owned();
+            return Err(self.diverged(format!("expected {recorded}, sent {sent}")));
+        }
+        self.records.pop_front();
+        self.position += 1;
+        self.metrics.messages_sent += 1;
+        self.metrics.bytes_sent += message.size() as u64;
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        if self
+            .records
+            .front()
+            .is_none_or(|record| record.direction != WireDirection::Inbound)
+        {
+            return Ok(None);
+        }
+        let message = self.records.pop_front().map(|record| record.to_message());
+        self.position += 1;
+        if let Some(message) = &message {
+            self.metrics.messages_received += 1;
+            self.metrics.bytes_received += message.size() as u64;
+        }
+        Ok(message)
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        self.metrics.clone()
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        Some("replay://".to_string())
+    }
+}
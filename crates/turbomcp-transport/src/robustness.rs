@@ -33,8 +33,18 @@ pub struct RetryConfig {
     pub max_delay: Duration,
     /// Exponential backoff multiplier
     pub backoff_multiplier: f64,
-    /// Jitter factor (0.0 - 1.0) to avoid thundering herd
+    /// Jitter factor (0.0 - 1.0) for [`JitterStrategy::Proportional`]
     pub jitter_factor: f64,
+    /// How delays are randomized so clients that failed together do not
+    /// retry together
+    #[serde(default)]
+    pub jitter: JitterStrategy,
+    /// Upper bound of a random delay before the first connection attempt
+    ///
+    /// Zero (the default) connects at once. A second or so spreads out a
+    /// fleet of clients restarted at the same moment.
+    #[serde(default)]
+    pub initial_jitter: Duration,
     /// Whether to retry on connection errors
     pub retry_on_connection_error: bool,
     /// Whether to retry on timeout errors
@@ -43,6 +53,49 @@ pub struct RetryConfig {
     pub custom_retry_conditions: Vec<RetryCondition>,
 }
 
+/// How retry delays are randomized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JitterStrategy {
+    /// Wait exactly the backoff delay
+    None,
+    /// Wait the backoff delay plus up to `jitter_factor` of it
+    Proportional,
+    /// Wait a random time between zero and the backoff delay
+    #[default]
+    Full,
+}
+
+impl RetryConfig {
+    /// Delay before retry number `attempt` (counting from 1)
+    ///
+    /// The exponential backoff is capped at `max_delay` before jitter is
+    /// applied, so even [`JitterStrategy::Proportional`] never exceeds it.
+    #[must_use]
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let backoff_ms =
+            self.base_delay.as_millis() as f64 * self.backoff_multiplier.powi(exponent);
+        let max_ms = self.max_delay.as_millis() as f64;
+        let capped_ms = backoff_ms.min(max_ms);
+
+        let delay_ms = match self.jitter {
+            JitterStrategy::None => capped_ms,
+            JitterStrategy::Proportional => {
+                (capped_ms * (1.0 + fastrand::f64() * self.jitter_factor)).min(max_ms)
+            }
+            JitterStrategy::Full => capped_ms * fastrand::f64(),
+        };
+        Duration::from_millis(delay_ms as u64)
+    }
+
+    /// Random delay before the first connection attempt
+    #[must_use]
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_jitter.mul_f64(fastrand::f64())
+    }
+}
+
 /// Custom retry condition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RetryCondition {
@@ -231,6 +284,8 @@ impl Default for RetryConfig {
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
             jitter_factor: 0.1,
+            jitter: JitterStrategy::Full,
+            initial_jitter: Duration::ZERO,
             retry_on_connection_error: true,
             retry_on_timeout: true,
             custom_retry_conditions: Vec::new(),
@@ -660,18 +715,7 @@ impl RobustTransport {
 
     /// Calculate retry delay with exponential backoff and jitter
     fn calculate_retry_delay(&self, attempt: u32) -> Duration {
-        let base_delay_ms = self.retry_config.base_delay.as_millis() as f64;
-        let multiplier = self.retry_config.backoff_multiplier.powi(attempt as i32);
-        let delay_ms = base_delay_ms * multiplier;
-
-        // Apply jitter
-        let jitter = fastrand::f64() * self.retry_config.jitter_factor;
-        let jittered_delay_ms = delay_ms * (1.0 + jitter);
-
-        // Cap at max delay
-        let final_delay_ms = jittered_delay_ms.min(self.retry_config.max_delay.as_millis() as f64);
-
-        Duration::from_millis(final_delay_ms as u64)
+        self.retry_config.backoff_delay(attempt)
     }
 
     /// Start background health checking
@@ -776,6 +820,10 @@ impl Transport for RobustTransport {
     }
 
     async fn connect(&mut self) -> TransportResult<()> {
+        let initial_delay = self.retry_config.initial_delay();
+        if !initial_delay.is_zero() {
+            sleep(initial_delay).await;
+        }
         let inner = self.inner.clone();
         self.execute_with_retry(move || {
             let inner = inner.clone();
@@ -862,6 +910,7 @@ mod tests {
     struct MockTransport {
         should_fail: Arc<AtomicUsize>,
         fail_count: Arc<AtomicUsize>,
+        connect_times: Arc<parking_lot::Mutex<Vec<tokio::time::Instant>>>,
     }
 
     impl MockTransport {
@@ -869,6 +918,7 @@ mod tests {
             Self {
                 should_fail: Arc::new(AtomicUsize::new(0)),
                 fail_count: Arc::new(AtomicUsize::new(0)),
+                connect_times: Arc::default(),
             }
         }
 
@@ -895,6 +945,7 @@ mod tests {
         }
 
         async fn connect(&mut self) -> TransportResult<()> {
+            self.connect_times.lock().push(tokio::time::Instant::now());
             let current_fail = self.fail_count.fetch_add(1, Ordering::Relaxed);
             let should_fail = self.should_fail.load(Ordering::Relaxed);
 
@@ -990,6 +1041,56 @@ mod tests {
         assert_eq!(metrics.successful_retries.load(Ordering::Relaxed), 1);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_delays_are_jittered_and_capped() {
+        let mock = MockTransport::new();
+        mock.set_failure_mode(7);
+        let connect_times = Arc::clone(&mock.connect_times);
+        let retry_config = RetryConfig {
+            max_attempts: 8,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            initial_jitter: Duration::from_millis(500),
+            ..Default::default()
+        };
+        let mut robust = RobustTransport::new(
+            Box::new(mock),
+            retry_config.clone(),
+            CircuitBreakerConfig {
+                failure_threshold: 100,
+                ..Default::default()
+            },
+            HealthCheckConfig::default(),
+        );
+
+        // Sleeps complete instantly on the paused clock, which still records
+        // how long each one was
+        let started = tokio::time::Instant::now();
+        robust.connect().await.unwrap();
+        let times = connect_times.lock().clone();
+        assert_eq!(times.len(), 8);
+        assert!(times[0] - started <= retry_config.initial_jitter);
+
+        let gaps: Vec<Duration> = times.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let backoffs: Vec<Duration> = (1..8)
+            .map(|attempt| {
+                let exponential = Duration::from_millis(100 * 2u64.pow(attempt));
+                exponential.min(retry_config.max_delay)
+            })
+            .collect();
+        for (gap, backoff) in gaps.iter().zip(&backoffs) {
+            assert!(gap <= backoff, "waited {gap:?}, more than {backoff:?}");
+        }
+        // With full jitter the waits fall anywhere below the backoff; all
+        // seven landing in its top tenth would mean they are not spread out
+        assert!(
+            gaps.iter()
+                .zip(&backoffs)
+                .any(|(gap, backoff)| *gap < backoff.mul_f64(0.9)),
+            "{gaps:?}"
+        );
+    }
+
     #[tokio::test]
     async fn test_deduplication_cache() {
         let mut cache = DeduplicationCache::new(3, Duration::from_millis(100));
@@ -1037,6 +1138,7 @@ mod tests {
             backoff_multiplier: 2.0,
             max_delay: Duration::from_secs(10),
             jitter_factor: 0.0, // No jitter for predictable testing
+            jitter: JitterStrategy::Proportional,
             ..Default::default()
         };
 
@@ -3,27 +3,54 @@
 //! This transport uses stdin/stdout for communication, which is the
 //! standard way MCP servers communicate with clients. It supports
 //! JSON-RPC over newline-delimited JSON.
+//!
+//! Incoming messages are framed by a [`JsonFrameCodec`], which rejects
+//! malformed or oversized messages while they are still arriving. Small
+//! messages are then parsed as a whole; large ones, such as big resource
+//! contents, only have their `id` read, so the frame is the sole copy of the
+//! message held in memory.
 
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use futures::StreamExt;
+use futures::{SinkExt, StreamExt};
 use parking_lot::Mutex;
+use serde::Deserialize;
 use serde_json;
-use tokio::io::{BufReader, Stdin, Stdout};
+use tokio::io::{AsyncRead, AsyncWrite};
 use tokio::sync::mpsc;
-use tokio_util::codec::{FramedRead, FramedWrite, LinesCodec};
+use tokio_util::codec::{FramedRead, FramedWrite, LinesCodec, LinesCodecError};
 use tracing::{debug, error, trace, warn};
 use turbomcp_core::MessageId;
 use uuid::Uuid;
 
 use crate::core::{
-    Transport, TransportCapabilities, TransportConfig, TransportError, TransportEventEmitter,
-    TransportFactory, TransportMessage, TransportMessageMetadata, TransportMetrics,
-    TransportResult, TransportState, TransportType,
+    DEFAULT_WRITE_TIMEOUT, Transport, TransportCapabilities, TransportConfig, TransportError,
+    TransportEventEmitter, TransportFactory, TransportMessage, TransportMessageMetadata,
+    TransportMetrics, TransportResult, TransportState, TransportType,
 };
+use crate::framing::JsonFrameCodec;
+
+/// Messages up to this size are parsed in full; larger ones only for their id
+const BUFFERED_PARSE_LIMIT: usize = 64 * 1024;
+
+/// The part of a large message the transport needs; the rest is skipped
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+}
+
+/// Byte stream messages are read from
+trait Input: AsyncRead + Send + Unpin + fmt::Debug {}
+impl<T: AsyncRead + Send + Unpin + fmt::Debug> Input for T {}
+
+/// Byte stream messages are written to
+trait Output: AsyncWrite + Send + Unpin + fmt::Debug {}
+impl<T: AsyncWrite + Send + Unpin + fmt::Debug> Output for T {}
 
 /// Standard I/O transport implementation
 #[derive(Debug)]
@@ -44,10 +71,10 @@ pub struct StdioTransport {
     event_emitter: TransportEventEmitter,
 
     /// Stdin reader
-    stdin_reader: Option<FramedRead<BufReader<Stdin>, LinesCodec>>,
+    stdin_reader: Option<FramedRead<Box<dyn Input>, JsonFrameCodec>>,
 
     /// Stdout writer
-    stdout_writer: Option<FramedWrite<Stdout, LinesCodec>>,
+    stdout_writer: Option<FramedWrite<Box<dyn Output>, LinesCodec>>,
 
     /// Message receive channel
     receive_channel: Option<mpsc::UnboundedReceiver<TransportMessage>>,
@@ -102,6 +129,20 @@ impl StdioTransport {
         transport
     }
 
+    /// Create a transport over the given streams instead of the process's
+    /// stdin and stdout, such as the pipes of a child process
+    #[must_use]
+    pub fn with_io<R, W>(reader: R, writer: W) -> Self
+    where
+        R: AsyncRead + Send + Unpin + fmt::Debug + 'static,
+        W: AsyncWrite + Send + Unpin + fmt::Debug + 'static,
+    {
+        let mut transport = Self::new();
+        transport.stdin_reader = Some(FramedRead::new(Box::new(reader), JsonFrameCodec::default()));
+        transport.stdout_writer = Some(FramedWrite::new(Box::new(writer), LinesCodec::new()));
+        transport
+    }
+
     fn update_metrics<F>(&self, updater: F)
     where
         F: FnOnce(&mut TransportMetrics),
@@ -148,14 +189,17 @@ impl StdioTransport {
     }
 
     async fn setup_stdio_streams(&mut self) -> TransportResult<()> {
-        // Setup stdin reader
-        let stdin = tokio::io::stdin();
-        let reader = BufReader::new(stdin);
-        self.stdin_reader = Some(FramedRead::new(reader, LinesCodec::new()));
+        // Setup stdin reader, unless streams were given to `with_io`
+        if self.stdin_reader.is_none() {
+            let stdin: Box<dyn Input> = Box::new(tokio::io::stdin());
+            self.stdin_reader = Some(FramedRead::new(stdin, JsonFrameCodec::default()));
+        }
 
         // Setup stdout writer
-        let stdout = tokio::io::stdout();
-        self.stdout_writer = Some(FramedWrite::new(stdout, LinesCodec::new()));
+        if self.stdout_writer.is_none() {
+            let stdout: Box<dyn Output> = Box::new(tokio::io::stdout());
+            self.stdout_writer = Some(FramedWrite::new(stdout, LinesCodec::new()));
+        }
 
         // Setup message receive channel
         let (tx, rx) = mpsc::unbounded_channel();
@@ -170,10 +214,10 @@ impl StdioTransport {
             let task_handle = tokio::spawn(async move {
                 while let Some(result) = reader.next().await {
                     match result {
-                        Ok(line) => {
-                            trace!("Received line: {}", line);
+                        Ok(Ok(frame)) => {
+                            trace!("Received frame: {} bytes", frame.len());
 
-                            match Self::parse_message(&line) {
+                            match Self::parse_frame(frame) {
                                 Ok(message) => {
                                     let size = message.size();
 
@@ -199,6 +243,13 @@ impl StdioTransport {
                                 }
                             }
                         }
+                        Ok(Err(e)) => {
+                            error!("Rejected incoming message: {}", e);
+                            event_emitter.emit_error(
+                                TransportError::ProtocolError(e.to_string()),
+                                Some("message framing".to_string()),
+                            );
+                        }
                         Err(e) => {
                             error!("Failed to read from stdin: {}", e);
                             event_emitter.emit_error(
@@ -219,6 +270,46 @@ impl StdioTransport {
         Ok(())
     }
 
+    /// Turn a frame from the codec into a message
+    fn parse_frame(frame: Bytes) -> TransportResult<TransportMessage> {
+        let text = std::str::from_utf8(&frame).map_err(|e| {
+            TransportError::ProtocolError(format!(
+                "Message is not valid UTF-8: invalid byte at offset {}",
+                e.valid_up_to()
+            ))
+        })?;
+        if frame.len() <= BUFFERED_PARSE_LIMIT {
+            return Self::parse_message(text);
+        }
+
+        // Batches are arrays and have no id of their own
+        let id = if frame.starts_with(b"{") {
+            serde_json::from_slice::<Envelope>(&frame)
+                .map_err(|e| TransportError::SerializationFailed(e.to_string()))?
+                .id
+        } else {
+            serde_json::from_slice::<serde::de::IgnoredAny>(&frame)
+                .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
+            None
+        };
+        let metadata = TransportMessageMetadata::with_content_type("application/json");
+        Ok(TransportMessage::with_metadata(
+            Self::message_id(id.as_ref()),
+            frame,
+            metadata,
+        ))
+    }
+
+    /// The message's JSON-RPC id, or a fresh one for notifications and batches
+    fn message_id(id: Option<&serde_json::Value>) -> MessageId {
+        id.and_then(|id| match id {
+            serde_json::Value::String(s) => Some(MessageId::from(s.clone())),
+            serde_json::Value::Number(n) => n.as_i64().map(MessageId::from),
+            _ => None,
+        })
+        .unwrap_or_else(|| MessageId::from(Uuid::new_v4()))
+    }
+
     fn parse_message(line: &str) -> TransportResult<TransportMessage> {
         let line = line.trim();
         if line.is_empty() {
@@ -230,14 +321,7 @@ impl StdioTransport {
             .map_err(|e| TransportError::SerializationFailed(e.to_string()))?;
 
         // Extract message ID
-        let message_id = json_value
-            .get("id")
-            .and_then(|id| match id {
-                serde_json::Value::String(s) => Some(MessageId::from(s.clone())),
-                serde_json::Value::Number(n) => n.as_i64().map(MessageId::from),
-                _ => None,
-            })
-            .unwrap_or_else(|| MessageId::from(Uuid::new_v4()));
+        let message_id = Self::message_id(json_value.get("id"));
 
         // Create transport message
         let payload = Bytes::from(line.to_string());
@@ -248,6 +332,19 @@ impl StdioTransport {
         ))
     }
 
+    /// Fail fast unless connected, reporting a lost peer as such
+    fn ensure_connected(&self) -> TransportResult<()> {
+        match &*self.state.lock() {
+            TransportState::Connected => Ok(()),
+            TransportState::Failed { reason } => {
+                Err(TransportError::ConnectionLost(reason.clone()))
+            }
+            state => Err(TransportError::ConnectionFailed(format!(
+                "Transport not connected: {state}"
+            ))),
+        }
+    }
+
     fn serialize_message(message: &TransportMessage) -> TransportResult<String> {
         // Convert bytes back to string for stdio transport
         let json_str = std::str::from_utf8(&message.payload)
@@ -323,30 +420,32 @@ impl Transport for StdioTransport {
     }
 
     async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
-        let state = self.state().await;
-        if !matches!(state, TransportState::Connected) {
-            return Err(TransportError::ConnectionFailed(format!(
-                "Transport not connected: {state}"
-            )));
-        }
+        self.ensure_connected()?;
 
         let json_line = Self::serialize_message(&message)?;
         let size = json_line.len();
+        let write_timeout = self.config.write_timeout.unwrap_or(DEFAULT_WRITE_TIMEOUT);
 
         if let Some(writer) = &mut self.stdout_writer {
-            if let Err(e) = writer.send(json_line).await {
+            // `send` flushes, so a peer that stopped reading fails or stalls
+            // here rather than on some later message
+            let written = match tokio::time::timeout(write_timeout, writer.send(json_line)).await {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(LinesCodecError::Io(e))) => Err(TransportError::from_write_error(&e)),
+                Ok(Err(e)) => Err(TransportError::SendFailed(e.to_string())),
+                Err(_) => Err(TransportError::ConnectionLost(format!(
+                    "write stalled for {write_timeout:?}; peer is not reading"
+                ))),
+            };
+            if let Err(e) = written {
                 error!("Failed to send message: {}", e);
+                if matches!(e, TransportError::ConnectionLost(_)) {
+                    self.stdout_writer = None;
+                }
                 self.set_state(TransportState::Failed {
                     reason: e.to_string(),
                 });
-                return Err(TransportError::SendFailed(e.to_string()));
-            }
-
-            // Flush to ensure message is sent immediately
-            use futures::SinkExt;
-            if let Err(e) = SinkExt::<String>::flush(writer).await {
-                error!("Failed to flush stdout: {}", e);
-                return Err(TransportError::SendFailed(e.to_string()));
+                return Err(e);
             }
 
             // Update metrics
@@ -368,12 +467,7 @@ impl Transport for StdioTransport {
     }
 
     async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
-        let state = self.state().await;
-        if !matches!(state, TransportState::Connected) {
-            return Err(TransportError::ConnectionFailed(format!(
-                "Transport not connected: {state}"
-            )));
-        }
+        self.ensure_connected()?;
 
         if let Some(receiver) = &mut self.receive_channel {
             match receiver.try_recv() {
@@ -399,6 +493,27 @@ impl Transport for StdioTransport {
         }
     }
 
+    async fn receive_next(&mut self) -> TransportResult<TransportMessage> {
+        self.ensure_connected()?;
+
+        let Some(receiver) = &mut self.receive_channel else {
+            return Err(TransportError::ReceiveFailed(
+                "Receive channel not available".to_string(),
+            ));
+        };
+        if let Some(message) = receiver.recv().await {
+            trace!("Received message: {} bytes", message.size());
+            return Ok(message);
+        }
+        warn!("Receive channel disconnected");
+        self.set_state(TransportState::Failed {
+            reason: "Receive channel disconnected".to_string(),
+        });
+        Err(TransportError::ReceiveFailed(
+            "Channel disconnected".to_string(),
+        ))
+    }
+
     async fn metrics(&self) -> TransportMetrics {
         self.metrics.lock().clone()
     }
@@ -648,4 +763,164 @@ mod tests {
         let result = transport.configure(invalid_timeout_config).await;
         assert!(matches!(result, Err(TransportError::ConfigurationError(_))));
     }
+
+    fn ping() -> TransportMessage {
+        TransportMessage::new(
+            MessageId::from(1),
+            Bytes::from(r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#),
+        )
+    }
+
+    /// Wait for the reader task to deliver the next message
+    async fn next_received(transport: &mut StdioTransport) -> TransportMessage {
+        tokio::time::timeout(Duration::from_secs(5), transport.receive_next())
+            .await
+            .expect("message should arrive")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_receive_next_waits_for_input() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut peer_out, input) = tokio::io::duplex(1024);
+        let (output, _peer_in) = tokio::io::duplex(1024);
+        let mut transport = StdioTransport::with_io(input, output);
+        transport.connect().await.unwrap();
+
+        let waiting = tokio::spawn(async move { transport.receive_next().await.map(|m| m.id) });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+
+        peer_out
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\n")
+            .await
+            .unwrap();
+        let id = tokio::time::timeout(Duration::from_secs(5), waiting)
+            .await
+            .expect("message should arrive")
+            .unwrap()
+            .unwrap();
+        assert_eq!(id, MessageId::from(1));
+    }
+
+    #[tokio::test]
+    async fn test_large_message_parses_as_it_arrives() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut peer_out, input) = tokio::io::duplex(64 * 1024);
+        let (output, _peer_in) = tokio::io::duplex(1024);
+        let mut transport = StdioTransport::with_io(input, output);
+        transport.connect().await.unwrap();
+
+        let contents = "line of a large file \"quoted\" {braces}\n".repeat(100_000);
+        let message = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 42,
+            "result": {"contents": [{"uri": "file:///big.txt", "text": contents}]},
+        })
+        .to_string();
+        assert!(message.len() > 4 * BUFFERED_PARSE_LIMIT);
+
+        // A broken message ahead of it is dropped without ending the stream
+        peer_out.write_all(b"{\"id\": oops\n").await.unwrap();
+        for chunk in message.as_bytes().chunks(4096) {
+            peer_out.write_all(chunk).await.unwrap();
+            assert!(transport.receive().await.unwrap().is_none());
+        }
+        peer_out.write_all(b"\n").await.unwrap();
+
+        let received = next_received(&mut transport).await;
+        assert_eq!(received.id, MessageId::from(42));
+        assert_eq!(received.payload, message.as_bytes());
+        let parsed: serde_json::Value = serde_json::from_slice(&received.payload).unwrap();
+        assert_eq!(parsed["result"]["contents"][0]["text"], contents);
+    }
+
+    #[tokio::test]
+    async fn test_bom_and_crlf_input() {
+        use tokio::io::AsyncWriteExt;
+
+        let (mut peer_out, input) = tokio::io::duplex(1024);
+        let (output, _peer_in) = tokio::io::duplex(1024);
+        let mut transport = StdioTransport::with_io(input, output);
+        transport.connect().await.unwrap();
+
+        peer_out
+            .write_all(b"\xEF\xBB\xBF{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"ping\"}\r\n")
+            .await
+            .unwrap();
+        peer_out
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"ping\"}\r\n")
+            .await
+            .unwrap();
+
+        let first = next_received(&mut transport).await;
+        assert_eq!(first.id, MessageId::from(1));
+        assert_eq!(
+            first.payload,
+            &br#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#[..]
+        );
+        let second = next_received(&mut transport).await;
+        assert_eq!(second.id, MessageId::from(2));
+    }
+
+    #[test]
+    fn test_non_utf8_frame_is_a_protocol_error() {
+        let frame = Bytes::from_static(b"{\"method\":\"caf\xE9\"}");
+        match StdioTransport::parse_frame(frame) {
+            Err(TransportError::ProtocolError(message)) => {
+                assert!(message.contains("not valid UTF-8"), "{message}");
+                assert!(message.contains("offset 14"), "{message}");
+            }
+            other => panic!("expected a protocol error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_detects_peer_that_closed_its_read_end() {
+        let (_peer_out, input) = tokio::io::duplex(1024);
+        let (output, peer_in) = tokio::io::duplex(1024);
+        let mut transport = StdioTransport::with_io(input, output);
+        transport.connect().await.unwrap();
+        transport.send(ping()).await.unwrap();
+
+        // The peer stops reading but keeps its write side open
+        drop(peer_in);
+
+        let result = transport.send(ping()).await;
+        assert!(matches!(result, Err(TransportError::ConnectionLost(_))));
+        assert!(matches!(
+            transport.state().await,
+            TransportState::Failed { .. }
+        ));
+        assert!(matches!(
+            transport.receive().await,
+            Err(TransportError::ConnectionLost(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_gives_up_on_peer_that_is_not_reading() {
+        let (_peer_out, input) = tokio::io::duplex(1024);
+        let (output, _peer_in) = tokio::io::duplex(16);
+        let mut transport = StdioTransport::with_io(input, output);
+        transport
+            .configure(TransportConfig {
+                write_timeout: Some(Duration::from_millis(50)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        transport.connect().await.unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), transport.send(ping()))
+            .await
+            .expect("send should not block indefinitely");
+        assert!(matches!(result, Err(TransportError::ConnectionLost(_))));
+        assert!(matches!(
+            transport.state().await,
+            TransportState::Failed { .. }
+        ));
+    }
 }
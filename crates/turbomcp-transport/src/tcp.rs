@@ -3,14 +3,18 @@
 use async_trait::async_trait;
 use bytes::BytesMut;
 use std::net::SocketAddr;
-use tokio::io::{AsyncReadExt, BufReader};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::mpsc;
+use tokio::sync::{Mutex, mpsc};
 use tracing::{debug, error, info, warn};
 
+use crate::accept_limit::{AcceptRateLimitConfig, AcceptRateLimiter};
 use crate::core::{
-    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
-    TransportResult, TransportState, TransportType,
+    DEFAULT_WRITE_TIMEOUT, Transport, TransportCapabilities, TransportError, TransportMessage,
+    TransportMetrics, TransportResult, TransportState, TransportType,
 };
 use turbomcp_core::MessageId;
 
@@ -21,10 +25,15 @@ pub struct TcpTransport {
     bind_addr: SocketAddr,
     /// Remote address to connect to (for client mode)
     remote_addr: Option<SocketAddr>,
-    /// Message sender
-    sender: Option<mpsc::UnboundedSender<TransportMessage>>,
+    /// Write side of the connected peer; in server mode, the most recently
+    /// accepted connection
+    writer: Option<Arc<Mutex<Option<OwnedWriteHalf>>>>,
     /// Message receiver
     receiver: Option<mpsc::UnboundedReceiver<TransportMessage>>,
+    /// How long a send may wait on a peer that is not reading
+    write_timeout: Duration,
+    /// Per-IP limit on accepted connections in server mode
+    accept_limiter: Option<Arc<AcceptRateLimiter>>,
     /// Transport capabilities
     capabilities: TransportCapabilities,
     /// Current state
@@ -40,8 +49,10 @@ impl TcpTransport {
         Self {
             bind_addr,
             remote_addr: None,
-            sender: None,
+            writer: None,
             receiver: None,
+            write_timeout: DEFAULT_WRITE_TIMEOUT,
+            accept_limiter: None,
             capabilities: TransportCapabilities {
                 supports_bidirectional: true,
                 supports_streaming: true,
@@ -59,8 +70,10 @@ impl TcpTransport {
         Self {
             bind_addr,
             remote_addr: Some(remote_addr),
-            sender: None,
+            writer: None,
             receiver: None,
+            write_timeout: DEFAULT_WRITE_TIMEOUT,
+            accept_limiter: None,
             capabilities: TransportCapabilities {
                 supports_bidirectional: true,
                 supports_streaming: true,
@@ -85,7 +98,9 @@ impl TcpTransport {
         })?;
 
         let (tx, rx) = mpsc::unbounded_channel();
-        self.sender = Some(tx.clone());
+        let limiter = self.accept_limiter.clone();
+        let writer = Arc::new(Mutex::new(None));
+        self.writer = Some(Arc::clone(&writer));
         self.receiver = Some(rx);
         self.state = TransportState::Connected;
 
@@ -94,11 +109,20 @@ impl TcpTransport {
             loop {
                 match listener.accept().await {
                     Ok((stream, addr)) => {
+                        if let Some(limiter) = &limiter
+                            && !limiter.allow(addr.ip())
+                        {
+                            // Dropping the stream closes it before anything is read
+                            warn!("Rejected TCP connection from {addr}: accept rate exceeded");
+                            continue;
+                        }
                         info!("Accepted TCP connection from {}", addr);
+                        let (read_half, write_half) = stream.into_split();
+                        *writer.lock().await = Some(write_half);
                         let sender = tx.clone();
                         // Handle connection in separate task
                         tokio::spawn(async move {
-                            if let Err(e) = handle_tcp_connection(stream, addr, sender).await {
+                            if let Err(e) = handle_tcp_connection(read_half, addr, sender).await {
                                 error!("TCP connection handler failed for {}: {}", addr, e);
                             }
                         });
@@ -131,30 +155,52 @@ impl TcpTransport {
         })?;
 
         let (tx, rx) = mpsc::unbounded_channel();
-        self.sender = Some(tx.clone());
+        let (read_half, write_half) = stream.into_split();
+        self.writer = Some(Arc::new(Mutex::new(Some(write_half))));
         self.receiver = Some(rx);
         self.state = TransportState::Connected;
 
         // Handle connection
         tokio::spawn(async move {
-            if let Err(e) = handle_tcp_connection(stream, remote_addr, tx).await {
+            if let Err(e) = handle_tcp_connection(read_half, remote_addr, tx).await {
                 error!("TCP client connection handler failed: {}", e);
             }
         });
 
         Ok(())
     }
+
+    /// Write one length-prefixed frame, giving up after `write_timeout`
+    async fn write_frame(
+        writer: &mut OwnedWriteHalf,
+        payload: &[u8],
+        write_timeout: Duration,
+    ) -> TransportResult<()> {
+        let length = u32::try_from(payload.len())
+            .map_err(|_| TransportError::ProtocolError("Message too large".into()))?;
+        let write = async {
+            writer.write_all(&length.to_be_bytes()).await?;
+            writer.write_all(payload).await?;
+            writer.flush().await
+        };
+        match tokio::time::timeout(write_timeout, write).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(TransportError::from_write_error(&e)),
+            Err(_) => Err(TransportError::ConnectionLost(format!(
+                "write stalled for {write_timeout:?}; peer is not reading"
+            ))),
+        }
+    }
 }
 
 /// Handle a TCP connection with proper message framing
 async fn handle_tcp_connection(
-    stream: TcpStream,
+    read_half: OwnedReadHalf,
     addr: SocketAddr,
     message_sender: mpsc::UnboundedSender<TransportMessage>,
 ) -> TransportResult<()> {
     debug!("Handling TCP connection from {}", addr);
 
-    let (read_half, _write_half) = stream.into_split();
     let mut reader = BufReader::new(read_half);
 
     let mut buffer = BytesMut::with_capacity(8192);
@@ -261,25 +307,51 @@ impl Transport for TcpTransport {
     async fn disconnect(&mut self) -> TransportResult<()> {
         info!("Stopping TCP transport");
         self.state = TransportState::Disconnecting;
-        self.sender = None;
+        if let Some(writer) = self.writer.take()
+            && let Some(mut write_half) = writer.lock().await.take()
+        {
+            let _ = write_half.shutdown().await;
+        }
         self.receiver = None;
         self.state = TransportState::Disconnected;
         Ok(())
     }
 
     async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
-        if let Some(ref sender) = self.sender {
-            self.metrics.messages_sent += 1;
-            self.metrics.bytes_sent += message.size() as u64;
-
-            sender.send(message).map_err(|e| {
-                TransportError::SendFailed(format!("Failed to send message via TCP: {e}"))
-            })?;
-            Ok(())
-        } else {
-            Err(TransportError::ConnectionFailed(
+        if let TransportState::Failed { reason } = &self.state {
+            return Err(TransportError::ConnectionLost(reason.clone()));
+        }
+        let Some(writer) = self.writer.clone() else {
+            return Err(TransportError::ConnectionFailed(
                 "TCP transport not connected".into(),
-            ))
+            ));
+        };
+
+        let mut slot = writer.lock().await;
+        let Some(write_half) = slot.as_mut() else {
+            return Err(TransportError::ConnectionLost(
+                "no TCP peer is connected".into(),
+            ));
+        };
+        match Self::write_frame(write_half, &message.payload, self.write_timeout).await {
+            Ok(()) => {
+                self.metrics.messages_sent += 1;
+                self.metrics.bytes_sent += message.size() as u64;
+                Ok(())
+            }
+            Err(e) => {
+                warn!("TCP send failed: {}", e);
+                if matches!(e, TransportError::ConnectionLost(_)) {
+                    // Drop the dead peer; a server keeps listening for the next one
+                    *slot = None;
+                    if self.remote_addr.is_some() {
+                        self.state = TransportState::Failed {
+                            reason: e.to_string(),
+                        };
+                    }
+                }
+                Err(e)
+            }
         }
     }
 
@@ -308,8 +380,31 @@ impl Transport for TcpTransport {
         }
     }
 
+    async fn receive_next(&mut self) -> TransportResult<TransportMessage> {
+        let Some(receiver) = &mut self.receiver else {
+            return Err(TransportError::ConnectionFailed(
+                "TCP transport not connected".into(),
+            ));
+        };
+        if let Some(message) = receiver.recv().await {
+            self.metrics.messages_received += 1;
+            self.metrics.bytes_received += message.size() as u64;
+            return Ok(message);
+        }
+        self.state = TransportState::Failed {
+            reason: "Channel disconnected".into(),
+        };
+        Err(TransportError::ReceiveFailed(
+            "TCP transport channel closed".into(),
+        ))
+    }
+
     async fn metrics(&self) -> TransportMetrics {
-        self.metrics.clone()
+        let mut metrics = self.metrics.clone();
+        if let Some(limiter) = &self.accept_limiter {
+            metrics.rejected_connections = limiter.rejected();
+        }
+        metrics
     }
 
     fn endpoint(&self) -> Option<String> {
@@ -334,6 +429,10 @@ pub struct TcpConfig {
     pub keep_alive: bool,
     /// Buffer sizes
     pub buffer_size: usize,
+    /// How long a send may wait on a peer that is not reading, in milliseconds
+    pub write_timeout_ms: u64,
+    /// Per-IP limit on new connections in server mode; unlimited when `None`
+    pub accept_rate_limit: Option<AcceptRateLimitConfig>,
 }
 
 impl Default for TcpConfig {
@@ -346,6 +445,8 @@ impl Default for TcpConfig {
             connect_timeout_ms: 5000,
             keep_alive: true,
             buffer_size: 8192,
+            write_timeout_ms: 30_000,
+            accept_rate_limit: None,
         }
     }
 }
@@ -400,14 +501,34 @@ impl TcpTransportBuilder {
         self
     }
 
+    /// Set how long a send may wait on a peer that is not reading
+    #[must_use]
+    pub const fn write_timeout_ms(mut self, timeout: u64) -> Self {
+        self.config.write_timeout_ms = timeout;
+        self
+    }
+
+    /// Limit how fast each IP may open connections to the server
+    #[must_use]
+    pub const fn accept_rate_limit(mut self, limit: AcceptRateLimitConfig) -> Self {
+        self.config.accept_rate_limit = Some(limit);
+        self
+    }
+
     /// Build the TCP transport
     #[must_use]
     pub fn build(self) -> TcpTransport {
-        if let Some(remote_addr) = self.config.remote_addr {
+        let mut transport = if let Some(remote_addr) = self.config.remote_addr {
             TcpTransport::new_client(self.config.bind_addr, remote_addr)
         } else {
             TcpTransport::new_server(self.config.bind_addr)
-        }
+        };
+        transport.write_timeout = Duration::from_millis(self.config.write_timeout_ms);
+        transport.accept_limiter = self
+            .config
+            .accept_rate_limit
+            .map(|limit| Arc::new(AcceptRateLimiter::new(limit)));
+        transport
     }
 }
 
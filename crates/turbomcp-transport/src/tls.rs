@@ -0,0 +1,407 @@
+//! TLS session resumption and certificate pinning
+//!
+//! A full TLS handshake costs a round trip and an asymmetric key exchange,
+//! which dominates setup time for workloads that open many short
+//! connections. With resumption, a client that has connected before presents
+//! a session ticket (or, for TLS 1.2, a session ID) and the server skips the
+//! certificate exchange.
+//!
+//! [`SessionResumption`] is on by default. The `tls` feature adds helpers
+//! that apply it to `rustls` server and client configurations, so any
+//! transport that terminates TLS with `rustls` resumes sessions the same way.
+//!
+//! ## Security considerations
+//!
+//! Session tickets are encrypted with a key held by the server. Anyone who
+//! obtains that key can decrypt every ticket issued under it and recover the
+//! resumption secrets, so a ticket's lifetime is also how long a leaked key
+//! stays useful. Keep [`SessionResumption::ticket_lifetime`] as short as the
+//! workload allows: long enough to cover a burst of reconnects, not days.
+//! Ticket keys are generated in memory at startup and rotated by `rustls`,
+//! so they are never written to disk and are not shared between processes;
+//! a restarted or load-balanced server simply performs a full handshake.
+//! Early data (0-RTT) is not enabled, so resumed connections do not expose
+//! replayable requests.
+//!
+//! ## Certificate pinning
+//!
+//! A client that talks to a known set of servers can go further than chain
+//! validation and require the server to present a specific certificate or
+//! key. [`CertificatePins`] holds SHA-256 fingerprints of either the whole
+//! certificate or its public key (the `SubjectPublicKeyInfo`), and
+//! [`apply_client_pinning`] installs a verifier that validates the chain as
+//! usual and then fails the handshake unless a certificate the server sent
+//! matches one of the pins. Pinning the public key survives certificate
+//! renewal with the same key; to rotate keys, pin the old and the new one
+//! until every server has switched.
+
+use std::time::Duration;
+
+/// Session resumption settings for a TLS endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionResumption {
+    /// Resume sessions on reconnect
+    pub enabled: bool,
+    /// How long a client may present an issued ticket
+    pub ticket_lifetime: Duration,
+    /// Sessions remembered in memory, per server or per client
+    pub cache_size: usize,
+}
+
+impl Default for SessionResumption {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ticket_lifetime: Duration::from_secs(60 * 60),
+            cache_size: 256,
+        }
+    }
+}
+
+impl SessionResumption {
+    /// Resumption turned off: every connection performs a full handshake
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Ticket lifetime in whole seconds, as sent on the wire
+    fn lifetime_secs(&self) -> u32 {
+        u32::try_from(self.ticket_lifetime.as_secs()).unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(feature = "tls")]
+mod rustls_support {
+    use std::sync::Arc;
+
+    use base64::Engine as _;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+    use rustls::client::{Resumption, WebPkiServerVerifier};
+    use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+    use rustls::server::{NoServerSessionStorage, ProducesTickets, ServerSessionMemoryCache};
+    use rustls::{
+        CertificateError, ClientConfig, DigitallySignedStruct, OtherError, RootCertStore,
+        ServerConfig, SignatureScheme,
+    };
+    use sha2::{Digest, Sha256};
+
+    use super::SessionResumption;
+    use crate::core::{TransportError, TransportResult};
+
+    /// Ticket producer that advertises a shorter lifetime than its inner one
+    #[derive(Debug)]
+    struct BoundedTickets {
+        inner: Arc<dyn ProducesTickets>,
+        lifetime: u32,
+    }
+
+    impl ProducesTickets for BoundedTickets {
+        fn enabled(&self) -> bool {
+            self.inner.enabled()
+        }
+
+        fn lifetime(&self) -> u32 {
+            self.lifetime.min(self.inner.lifetime())
+        }
+
+        fn encrypt(&self, plain: &[u8]) -> Option<Vec<u8>> {
+            self.inner.encrypt(plain)
+        }
+
+        fn decrypt(&self, cipher: &[u8]) -> Option<Vec<u8>> {
+            self.inner.decrypt(cipher)
+        }
+    }
+
+    /// Ticket producer for servers with resumption turned off
+    #[derive(Debug)]
+    struct NoTickets;
+
+    impl ProducesTickets for NoTickets {
+        fn enabled(&self) -> bool {
+            false
+        }
+
+        fn lifetime(&self) -> u32 {
+            0
+        }
+
+        fn encrypt(&self, _plain: &[u8]) -> Option<Vec<u8>> {
+            None
+        }
+
+        fn decrypt(&self, _cipher: &[u8]) -> Option<Vec<u8>> {
+            None
+        }
+    }
+
+    /// Configure how a `rustls` server resumes sessions
+    ///
+    /// When enabled, the server issues stateless tickets under an in-memory,
+    /// rotating key and also keeps up to `cache_size` sessions for TLS 1.2
+    /// session IDs. When disabled, it does neither.
+    pub fn apply_server_resumption(
+        config: &mut ServerConfig,
+        resumption: &SessionResumption,
+    ) -> TransportResult<()> {
+        if resumption.enabled {
+            let ticketer = rustls::crypto::aws_lc_rs::Ticketer::new().map_err(|e| {
+                TransportError::ConfigurationError(format!("Cannot create TLS ticketer: {e}"))
+            })?;
+            config.ticketer = Arc::new(BoundedTickets {
+                inner: ticketer,
+                lifetime: resumption.lifetime_secs(),
+            });
+            config.session_storage = ServerSessionMemoryCache::new(resumption.cache_size);
+        } else {
+            config.ticketer = Arc::new(NoTickets);
+            config.session_storage = Arc::new(NoServerSessionStorage {});
+        }
+        Ok(())
+    }
+
+    /// Configure whether a `rustls` client offers to resume sessions
+    pub fn apply_client_resumption(config: &mut ClientConfig, resumption: &SessionResumption) {
+        config.resumption = if resumption.enabled {
+            Resumption::in_memory_sessions(resumption.cache_size)
+        } else {
+            Resumption::disabled()
+        };
+    }
+
+    type Fingerprint = [u8; 32];
+
+    /// SHA-256 fingerprints a server's certificate must match
+    ///
+    /// Fingerprints are given either as hex, with or without `:` separators
+    /// (as printed by `openssl x509 -fingerprint -sha256`), or as base64 (the
+    /// `pin-sha256` form used for public key pins).
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct CertificatePins {
+        certificates: Vec<Fingerprint>,
+        public_keys: Vec<Fingerprint>,
+    }
+
+    impl CertificatePins {
+        /// An empty pin set
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Accept a certificate whose DER encoding has this SHA-256 fingerprint
+        pub fn certificate(mut self, fingerprint: &str) -> TransportResult<Self> {
+            self.certificates.push(parse_fingerprint(fingerprint)?);
+            Ok(self)
+        }
+
+        /// Accept a certificate whose `SubjectPublicKeyInfo` has this SHA-256
+        /// fingerprint
+        pub fn public_key(mut self, fingerprint: &str) -> TransportResult<Self> {
+            self.public_keys.push(parse_fingerprint(fingerprint)?);
+            Ok(self)
+        }
+
+        /// Accept exactly this DER-encoded certificate
+        #[must_use]
+        pub fn certificate_der(mut self, der: &[u8]) -> Self {
+            self.certificates.push(Sha256::digest(der).into());
+            self
+        }
+
+        /// Accept certificates for this DER-encoded `SubjectPublicKeyInfo`
+        #[must_use]
+        pub fn public_key_der(mut self, spki: &[u8]) -> Self {
+            self.public_keys.push(Sha256::digest(spki).into());
+            self
+        }
+
+        /// True when no pins have been added
+        #[must_use]
+        pub fn is_empty(&self) -> bool {
+            self.certificates.is_empty() && self.public_keys.is_empty()
+        }
+
+        /// Whether a DER-encoded certificate matches one of the pins
+        #[must_use]
+        pub fn matches(&self, der: &[u8]) -> bool {
+            let certificate: Fingerprint = Sha256::digest(der).into();
+            if self.certificates.contains(&certificate) {
+                return true;
+            }
+            !self.public_keys.is_empty()
+                && subject_public_key_info(der).is_some_and(|spki| {
+                    let key: Fingerprint = Sha256::digest(spki).into();
+                    self.public_keys.contains(&key)
+                })
+        }
+    }
+
+    fn parse_fingerprint(fingerprint: &str) -> TransportResult<Fingerprint> {
+        let fingerprint = fingerprint.trim();
+        let hex: String = fingerprint.chars().filter(|&c| c != ':').collect();
+        let bytes = if hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            (0..64)
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()
+        } else {
+            BASE64.decode(fingerprint).ok()
+        };
+        bytes
+            .and_then(|bytes| Fingerprint::try_from(bytes).ok())
+            .ok_or_else(|| {
+                TransportError::ConfigurationError(format!(
+                    "Invalid SHA-256 fingerprint '{fingerprint}': expected 32 bytes as hex or base64"
+                ))
+            })
+    }
+
+    /// Split one DER element off the front of `input` as (tag, contents, rest)
+    fn der_element(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+        let (&tag, rest) = input.split_first()?;
+        let (&first, rest) = rest.split_first()?;
+        let (len, rest) = if first < 0x80 {
+            (usize::from(first), rest)
+        } else {
+            let octets = usize::from(first & 0x7f);
+            if octets == 0 || octets > size_of::<usize>() || rest.len() < octets {
+                return None;
+            }
+            let (octets, rest) = rest.split_at(octets);
+            let len = octets
+                .iter()
+                .fold(0usize, |len, &b| (len << 8) | usize::from(b));
+            (len, rest)
+        };
+        (rest.len() >= len).then(|| (tag, &rest[..len], &rest[len..]))
+    }
+
+    /// Find the `SubjectPublicKeyInfo` inside a DER-encoded X.509 certificate
+    fn subject_public_key_info(certificate: &[u8]) -> Option<&[u8]> {
+        const SEQUENCE: u8 = 0x30;
+        const VERSION: u8 = 0xa0;
+
+        let (SEQUENCE, certificate, _) = der_element(certificate)? else {
+            return None;
+        };
+        let (SEQUENCE, mut fields, _) = der_element(certificate)? else {
+            return None;
+        };
+        if fields.first() == Some(&VERSION) {
+            fields = der_element(fields)?.2;
+        }
+        // serialNumber, signature, issuer, validity and subject come first
+        for _ in 0..5 {
+            fields = der_element(fields)?.2;
+        }
+        // The pin covers the whole element, header included
+        let (SEQUENCE, _, rest) = der_element(fields)? else {
+            return None;
+        };
+        Some(&fields[..fields.len() - rest.len()])
+    }
+
+    /// The error a pin mismatch fails the handshake with
+    #[derive(Debug, thiserror::Error)]
+    #[error("server certificate does not match any pinned SHA-256 fingerprint")]
+    struct PinMismatch;
+
+    /// Verifier that checks the chain, then requires a pinned certificate
+    #[derive(Debug)]
+    struct PinnedServerVerifier {
+        inner: Arc<WebPkiServerVerifier>,
+        pins: CertificatePins,
+    }
+
+    impl ServerCertVerifier for PinnedServerVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            let verified = self.inner.verify_server_cert(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            )?;
+            let pinned = std::iter::once(end_entity)
+                .chain(intermediates)
+                .any(|cert| self.pins.matches(cert));
+            if pinned {
+                Ok(verified)
+            } else {
+                tracing::warn!(server = ?server_name, "TLS certificate pin mismatch");
+                Err(rustls::Error::InvalidCertificate(CertificateError::Other(
+                    OtherError(Arc::new(PinMismatch)),
+                )))
+            }
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls12_signature(message, cert, dss)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls13_signature(message, cert, dss)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.inner.supported_verify_schemes()
+        }
+    }
+
+    /// Require a `rustls` client's servers to present a pinned certificate
+    ///
+    /// The server's chain is still validated against `roots`; pinning only
+    /// narrows which valid certificates are accepted. The end-entity
+    /// certificate or any intermediate the server sends may match a pin.
+    pub fn apply_client_pinning(
+        config: &mut ClientConfig,
+        roots: impl Into<Arc<RootCertStore>>,
+        pins: CertificatePins,
+    ) -> TransportResult<()> {
+        if pins.is_empty() {
+            return Err(TransportError::ConfigurationError(
+                "Certificate pinning needs at least one pin".to_string(),
+            ));
+        }
+        let inner = WebPkiServerVerifier::builder(roots.into())
+            .build()
+            .map_err(|e| {
+                TransportError::ConfigurationError(format!(
+                    "Cannot create TLS certificate verifier: {e}"
+                ))
+            })?;
+        config
+            .dangerous()
+            .set_certificate_verifier(Arc::new(PinnedServerVerifier { inner, pins }));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tls")]
+pub use rustls_support::{
+    CertificatePins, apply_client_pinning, apply_client_resumption, apply_server_resumption,
+};
@@ -9,8 +9,8 @@ use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
 use crate::core::{
-    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMetrics,
-    TransportResult, TransportState, TransportType,
+    Transport, TransportCapabilities, TransportError, TransportMessage, TransportMessageMetadata,
+    TransportMetrics, TransportResult, TransportState, TransportType,
 };
 use turbomcp_core::MessageId;
 
@@ -154,6 +154,26 @@ impl UnixTransport {
     }
 }
 
+/// Metadata identifying the process on the other end of `stream`
+///
+/// Records `unix.peer_uid`, `unix.peer_gid` and, where the platform reports
+/// it, `unix.peer_pid`, so handlers can authorize local callers.
+fn peer_metadata(stream: &UnixStream) -> TransportMessageMetadata {
+    let mut metadata = TransportMessageMetadata::default();
+    match stream.peer_cred() {
+        Ok(cred) => {
+            metadata = metadata
+                .with_extension("unix.peer_uid", cred.uid())
+                .with_extension("unix.peer_gid", cred.gid());
+            if let Some(pid) = cred.pid() {
+                metadata = metadata.with_extension("unix.peer_pid", pid);
+            }
+        }
+        Err(e) => debug!("Unix socket peer credentials unavailable: {}", e),
+    }
+    metadata
+}
+
 /// Handle a Unix socket connection with proper message framing
 async fn handle_unix_connection(
     stream: UnixStream,
@@ -162,6 +182,7 @@ async fn handle_unix_connection(
 ) -> TransportResult<()> {
     debug!("Handling Unix socket connection for {:?}", socket_path);
 
+    let metadata = peer_metadata(&stream);
     let (read_half, _write_half) = stream.into_split();
     let mut reader = BufReader::new(read_half);
 
@@ -227,7 +248,11 @@ async fn handle_unix_connection(
                     serde_json::Value::Number(n) => MessageId::from(n.as_i64().unwrap_or_default()),
                     _ => MessageId::from(uuid::Uuid::new_v4()),
                 };
-                let transport_msg = TransportMessage::new(message_id, buffer.clone().freeze());
+                let transport_msg = TransportMessage::with_metadata(
+                    message_id,
+                    buffer.clone().freeze(),
+                    metadata.clone(),
+                );
 
                 if message_sender.send(transport_msg).is_err() {
                     warn!(
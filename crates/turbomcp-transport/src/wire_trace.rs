@@ -0,0 +1,225 @@
+//! Wire-level message tracing for protocol debugging
+//!
+//! When a client and server disagree about the protocol, the quickest way to
+//! find out who is wrong is to look at the exact messages on the wire. A
+//! [`WireTracer`] logs every inbound and outbound message at `TRACE` level
+//! under the [`WIRE_TRACE_TARGET`] target, tagged with its direction and
+//! correlation id.
+//!
+//! Tracing is off by default. When disabled, the cost per message is a single
+//! boolean check. Enable it with a builder option on the client or server, or
+//! from the environment:
+//!
+//! ```text
+//! TURBOMCP_WIRE_TRACE=1 \
+//! TURBOMCP_WIRE_TRACE_REDACT=token,password,apiKey \
+//! RUST_LOG=turbomcp::wire=trace \
+//!     my-mcp-server
+//! ```
+//!
+//! Wire traces contain full request and response payloads, including tool
+//! arguments and results. Only enable them outside production, and list any
+//! sensitive field names for redaction: values under matching object keys are
+//! replaced with `"[REDACTED]"` at any nesting depth.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::TransportMessage;
+
+/// Environment variable that enables wire tracing (`1` or `true`)
+pub const WIRE_TRACE_ENV: &str = "TURBOMCP_WIRE_TRACE";
+
+/// Environment variable holding a comma-separated list of fields to redact
+pub const WIRE_TRACE_REDACT_ENV: &str = "TURBOMCP_WIRE_TRACE_REDACT";
+
+/// Tracing target used for wire messages
+pub const WIRE_TRACE_TARGET: &str = "turbomcp::wire";
+
+/// Replacement value for redacted fields
+const REDACTED: &str = "[REDACTED]";
+
+/// Direction of a traced message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WireDirection {
+    /// Message received from the peer
+    Inbound,
+    /// Message sent to the peer
+    Outbound,
+}
+
+impl WireDirection {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inbound => "inbound",
+            Self::Outbound => "outbound",
+        }
+    }
+}
+
+/// Logs full wire messages when enabled
+#[derive(Debug, Clone, Default)]
+pub struct WireTracer {
+    enabled: bool,
+    redacted_fields: Arc<[String]>,
+}
+
+impl WireTracer {
+    /// Create a disabled tracer
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Create an enabled tracer with no redaction
+    #[must_use]
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            redacted_fields: Arc::from(Vec::new()),
+        }
+    }
+
+    /// Create a tracer configured from [`WIRE_TRACE_ENV`] and [`WIRE_TRACE_REDACT_ENV`]
+    #[must_use]
+    pub fn from_env() -> Self {
+        let enabled = std::env::var(WIRE_TRACE_ENV)
+            .is_ok_and(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true"));
+        if !enabled {
+            return Self::disabled();
+        }
+        let fields = std::env::var(WIRE_TRACE_REDACT_ENV).unwrap_or_default();
+        Self::enabled().with_redacted_fields(
+            fields
+                .split(',')
+                .map(str::trim)
+                .filter(|f| !f.is_empty())
+                .map(str::to_string),
+        )
+    }
+
+    /// Redact the values of these object keys in traced messages
+    #[must_use]
+    pub fn with_redacted_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.redacted_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether this tracer logs messages
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Log a message if tracing is enabled
+    pub fn trace(&self, direction: WireDirection, message: &TransportMessage) {
+        if !self.enabled || !tracing::enabled!(target: WIRE_TRACE_TARGET, tracing::Level::TRACE) {
+            return;
+        }
+
+        let parsed = serde_json::from_slice::<Value>(&message.payload).ok();
+        let correlation_id = parsed
+            .as_ref()
+            .and_then(|v| v.get("id"))
+            .filter(|id| !id.is_null())
+            .map(|id| id.to_string().trim_matches('"').to_string())
+            .or_else(|| message.metadata.correlation_id.clone())
+            .unwrap_or_else(|| message.id.to_string());
+        let body = match parsed {
+            Some(value) => self.redact(value).to_string(),
+            None => String::from_utf8_lossy(&message.payload).into_owned(),
+        };
+
+        tracing::trace!(
+            target: WIRE_TRACE_TARGET,
+            direction = direction.as_str(),
+            correlation_id = %correlation_id,
+            bytes = message.payload.len(),
+            message = %body,
+            "wire message"
+        );
+    }
+
+    /// Apply field redaction to a JSON value
+    #[must_use]
+    pub fn redact(&self, value: Value) -> Value {
+        if self.redacted_fields.is_empty() {
+            return value;
+        }
+        self.redact_value(value)
+    }
+
+    fn redact_value(&self, value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        if self.redacted_fields.iter().any(|f| *f == key) {
+                            (key, Value::String(REDACTED.to_string()))
+                        } else {
+                            (key, self.redact_value(value))
+                        }
+                    })
+                    .collect(),
+            ),
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(|v| self.redact_value(v)).collect())
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(!WireTracer::default().is_enabled());
+        assert!(!WireTracer::disabled().is_enabled());
+        assert!(WireTracer::enabled().is_enabled());
+    }
+
+    #[test]
+    fn test_redacts_nested_fields() {
+        let tracer = WireTracer::enabled().with_redacted_fields(["token", "password"]);
+        let redacted = tracer.redact(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "params": {
+                "token": "secret",
+                "arguments": [{"password": "hunter2", "user": "alice"}]
+            }
+        }));
+
+        assert_eq!(redacted["params"]["token"], "[REDACTED]");
+        assert_eq!(redacted["params"]["arguments"][0]["password"], "[REDACTED]");
+        assert_eq!(redacted["params"]["arguments"][0]["user"], "alice");
+        assert_eq!(redacted["id"], 1);
+    }
+
+    #[test]
+    fn test_no_redaction_is_identity() {
+        let value = json!({"token": "visible"});
+        assert_eq!(WireTracer::enabled().redact(value.clone()), value);
+    }
+
+    #[test]
+    fn test_trace_does_not_panic_on_non_json() {
+        let tracer = WireTracer::enabled();
+        let message = TransportMessage::new(
+            turbomcp_core::MessageId::from("raw"),
+            bytes::Bytes::from_static(b"\xffnot json"),
+        );
+        tracer.trace(WireDirection::Inbound, &message);
+    }
+}
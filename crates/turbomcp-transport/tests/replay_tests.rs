@@ -0,0 +1,169 @@
+//! Tests for recording sessions and replaying them
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde_json::{Value, json};
+use turbomcp_core::MessageId;
+use turbomcp_transport::core::{
+    Transport, TransportCapabilities, TransportMessage, TransportMetrics, TransportResult,
+    TransportState, TransportType,
+};
+use turbomcp_transport::replay::read_recording;
+use turbomcp_transport::{RecordingTransport, ReplayTransport, WireDirection};
+
+/// Peer that answers each request with its params and a running count
+#[derive(Debug, Default)]
+struct CountingPeer {
+    capabilities: TransportCapabilities,
+    handled: u64,
+    replies: VecDeque<TransportMessage>,
+}
+
+#[async_trait]
+impl Transport for CountingPeer {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> &TransportCapabilities {
+        &self.capabilities
+    }
+
+    async fn state(&self) -> TransportState {
+        TransportState::Connected
+    }
+
+    async fn connect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> TransportResult<()> {
+        Ok(())
+    }
+
+    async fn send(&mut self, message: TransportMessage) -> TransportResult<()> {
+        let request: Value = serde_json::from_slice(&message.payload).unwrap();
+        if let Some(id) = request.get("id") {
+            self.handled += 1;
+            let reply = json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "result": {"echo": request["params"], "count": self.handled}
+            });
+            self.replies.push_back(TransportMessage::new(
+                message.id,
+                Bytes::from(serde_json::to_vec(&reply).unwrap()),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn receive(&mut self) -> TransportResult<Option<TransportMessage>> {
+        Ok(self.replies.pop_front())
+    }
+
+    async fn metrics(&self) -> TransportMetrics {
+        TransportMetrics::default()
+    }
+}
+
+fn recording_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "turbomcp-replay-{name}-{}.jsonl",
+        std::process::id()
+    ))
+}
+
+fn message(id: &str, body: &Value) -> TransportMessage {
+    TransportMessage::new(
+        MessageId::from(id),
+        Bytes::from(serde_json::to_vec(body).unwrap()),
+    )
+}
+
+/// Drive a short session: two requests with a notification in between
+async fn run_session(transport: &mut impl Transport) -> TransportResult<Vec<Value>> {
+    let mut replies = Vec::new();
+    transport
+        .send(message(
+            "1",
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "x/echo", "params": {"n": 1}}),
+        ))
+        .await?;
+    replies.push(transport.receive().await?.unwrap());
+    transport
+        .send(message(
+            "note",
+            &json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+        ))
+        .await?;
+    transport
+        .send(message(
+            "2",
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "x/echo", "params": {"n": 2}}),
+        ))
+        .await?;
+    replies.push(transport.receive().await?.unwrap());
+    Ok(replies
+        .iter()
+        .map(|reply| serde_json::from_slice(&reply.payload).unwrap())
+        .collect())
+}
+
+#[tokio::test]
+async fn test_replay_reproduces_recorded_session() {
+    let path = recording_path("session");
+    let mut recorder = RecordingTransport::to_file(CountingPeer::default(), &path).unwrap();
+    let recorded = run_session(&mut recorder).await.unwrap();
+    assert_eq!(recorder.inner().handled, 2);
+    drop(recorder);
+
+    let records = read_recording(&path).unwrap();
+    let directions: Vec<_> = records.iter().map(|r| r.direction).collect();
+    assert_eq!(
+        directions,
+        [
+            WireDirection::Outbound,
+            WireDirection::Inbound,
+            WireDirection::Outbound,
+            WireDirection::Outbound,
+            WireDirection::Inbound,
+        ]
+    );
+    assert_eq!(records[1].payload.as_ref().unwrap()["result"]["count"], 1);
+
+    let mut replay = ReplayTransport::from_file(&path).unwrap();
+    let replayed = run_session(&mut replay).await.unwrap();
+    assert_eq!(replayed, recorded);
+    assert!(replay.is_finished());
+    assert!(replay.receive().await.unwrap().is_none());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn test_replay_reports_divergence() {
+    let path = recording_path("divergence");
+    let mut recorder = RecordingTransport::to_file(CountingPeer::default(), &path).unwrap();
+    run_session(&mut recorder).await.unwrap();
+    drop(recorder);
+
+    let mut replay = ReplayTransport::from_file(&path).unwrap();
+    // Nothing is received before the recorded request is sent
+    assert!(replay.receive().await.unwrap().is_none());
+
+    let err = replay
+        .send(message(
+            "1",
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "x/echo", "params": {"n": 9}}),
+        ))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Replay diverged at record 1"));
+    assert_eq!(replay.remaining(), 5);
+
+    std::fs::remove_file(path).unwrap();
+}
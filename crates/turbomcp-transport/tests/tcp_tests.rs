@@ -15,6 +15,8 @@ mod tcp_tests {
         assert_eq!(config.connect_timeout_ms, 5000);
         assert!(config.keep_alive);
         assert_eq!(config.buffer_size, 8192);
+        assert_eq!(config.write_timeout_ms, 30_000);
+        assert_eq!(config.accept_rate_limit, None);
     }
 
     #[test]
@@ -46,6 +48,8 @@ mod tcp_tests {
             connect_timeout_ms: 10000,
             keep_alive: false,
             buffer_size: 16384,
+            write_timeout_ms: 1000,
+            accept_rate_limit: None,
         };
 
         assert_eq!(config.bind_addr, bind_addr);
@@ -53,6 +57,7 @@ mod tcp_tests {
         assert_eq!(config.connect_timeout_ms, 10000);
         assert!(!config.keep_alive);
         assert_eq!(config.buffer_size, 16384);
+        assert_eq!(config.write_timeout_ms, 1000);
     }
 
     #[test]
@@ -394,6 +399,90 @@ mod tcp_tests {
         };
         assert_eq!(config2.buffer_size, 1024 * 1024);
     }
+
+    #[tokio::test]
+    async fn test_tcp_send_detects_peer_that_went_away() {
+        use bytes::Bytes;
+        use std::time::Duration;
+        use tokio::io::AsyncReadExt;
+        use turbomcp_core::MessageId;
+        use turbomcp_transport::core::{TransportError, TransportMessage};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut transport = TcpTransportBuilder::new()
+            .bind_addr("127.0.0.1:0".parse().unwrap())
+            .remote_addr(listener.local_addr().unwrap())
+            .build();
+        transport.connect().await.unwrap();
+        let (mut peer, _) = listener.accept().await.unwrap();
+
+        let message = || {
+            TransportMessage::new(
+                MessageId::from(1),
+                Bytes::from(r#"{"jsonrpc":"2.0","id":1,"result":{}}"#),
+            )
+        };
+        transport.send(message()).await.unwrap();
+        let mut length = [0u8; 4];
+        peer.read_exact(&mut length).await.unwrap();
+        assert_eq!(u32::from_be_bytes(length) as usize, message().payload.len());
+
+        // The peer goes away without the transport being told; the kernel
+        // may accept one more write before the reset arrives
+        drop(peer);
+        let mut error = None;
+        for _ in 0..20 {
+            if let Err(e) = transport.send(message()).await {
+                error = Some(e);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(matches!(error, Some(TransportError::ConnectionLost(_))));
+        assert!(matches!(
+            transport.state().await,
+            TransportState::Failed { .. }
+        ));
+        assert!(matches!(
+            transport.send(message()).await,
+            Err(TransportError::ConnectionLost(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tcp_server_rejects_connections_over_accept_rate() {
+        use std::time::Duration;
+        use tokio::io::AsyncReadExt;
+        use turbomcp_transport::AcceptRateLimitConfig;
+
+        let bind_addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let mut transport = TcpTransportBuilder::new()
+            .bind_addr(bind_addr)
+            .accept_rate_limit(AcceptRateLimitConfig {
+                connections_per_second: 1,
+                burst: 2,
+            })
+            .build();
+        transport.connect().await.unwrap();
+
+        let mut connections = Vec::new();
+        for _ in 0..4 {
+            connections.push(tokio::net::TcpStream::connect(bind_addr).await.unwrap());
+        }
+
+        // The burst stays open; the rest are closed without a byte read
+        let mut closed = Vec::new();
+        for connection in &mut connections {
+            let mut buf = [0u8; 1];
+            let read = tokio::time::timeout(Duration::from_millis(200), connection.read(&mut buf));
+            closed.push(matches!(read.await, Ok(Ok(0) | Err(_))));
+        }
+        assert_eq!(closed, [false, false, true, true]);
+        assert_eq!(transport.metrics().await.rejected_connections, 2);
+    }
 }
 
 // Tests that work without the tcp feature
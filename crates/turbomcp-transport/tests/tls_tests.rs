@@ -0,0 +1,180 @@
+//! Tests for TLS session resumption and certificate pinning
+
+#[cfg(feature = "tls")]
+mod tls_tests {
+    use std::sync::Arc;
+
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName};
+    use rustls::{ClientConfig, HandshakeKind, RootCertStore, ServerConfig};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::{TlsAcceptor, TlsConnector};
+    use turbomcp_transport::tls::{
+        CertificatePins, SessionResumption, apply_client_pinning, apply_client_resumption,
+        apply_server_resumption,
+    };
+
+    /// Fingerprint of some other certificate, for pins that must not match
+    const OTHER_PIN: &str = "AA:BB:CC:DD:EE:FF:00:11:22:33:44:55:66:77:88:99:\
+                             AA:BB:CC:DD:EE:FF:00:11:22:33:44:55:66:77:88:99";
+
+    /// A server and a client that trusts it, both using `resumption`
+    fn endpoints(resumption: &SessionResumption) -> (TlsAcceptor, TlsConnector) {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert: CertificateDer<'static> = certified.cert.der().clone();
+        let key =
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()));
+
+        let mut server = ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(vec![cert.clone()], key)
+            .unwrap();
+        apply_server_resumption(&mut server, resumption).unwrap();
+
+        let mut roots = RootCertStore::empty();
+        roots.add(cert).unwrap();
+        let mut client = ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        apply_client_resumption(&mut client, resumption);
+
+        (
+            TlsAcceptor::from(Arc::new(server)),
+            TlsConnector::from(Arc::new(client)),
+        )
+    }
+
+    /// Open one connection over an in-memory pipe and report its handshake
+    async fn connect(acceptor: &TlsAcceptor, connector: &TlsConnector) -> HandshakeKind {
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        let acceptor = acceptor.clone();
+        let server = tokio::spawn(async move {
+            let mut stream = acceptor.accept(server_io).await.unwrap();
+            stream.write_all(b"ok").await.unwrap();
+            stream.shutdown().await.unwrap();
+        });
+
+        let name = ServerName::try_from("localhost").unwrap();
+        let mut stream = connector.connect(name, client_io).await.unwrap();
+        // Reading also takes in the tickets sent after the handshake
+        let mut reply = Vec::new();
+        stream.read_to_end(&mut reply).await.unwrap();
+        assert_eq!(reply, b"ok");
+        server.await.unwrap();
+
+        stream.get_ref().1.handshake_kind().unwrap()
+    }
+
+    /// A server and a client whose pins are built from the server's key
+    fn pinned_endpoints(
+        pins: impl FnOnce(&rcgen::CertifiedKey) -> CertificatePins,
+    ) -> (TlsAcceptor, TlsConnector) {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert: CertificateDer<'static> = certified.cert.der().clone();
+        let key =
+            PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()));
+        let server = ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(vec![cert.clone()], key)
+            .unwrap();
+
+        let mut roots = RootCertStore::empty();
+        roots.add(cert).unwrap();
+        let mut client = ClientConfig::builder()
+            .with_root_certificates(roots.clone())
+            .with_no_client_auth();
+        apply_client_pinning(&mut client, roots, pins(&certified)).unwrap();
+
+        (
+            TlsAcceptor::from(Arc::new(server)),
+            TlsConnector::from(Arc::new(client)),
+        )
+    }
+
+    /// Perform one handshake, returning the client's error if it fails
+    async fn handshake(acceptor: &TlsAcceptor, connector: &TlsConnector) -> std::io::Result<()> {
+        let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+        let acceptor = acceptor.clone();
+        let server = tokio::spawn(async move { acceptor.accept(server_io).await.map(drop) });
+
+        let name = ServerName::try_from("localhost").unwrap();
+        let client = connector.connect(name, client_io).await.map(drop);
+        let _ = server.await.unwrap();
+        client
+    }
+
+    #[tokio::test]
+    async fn test_second_connection_resumes_session() {
+        let resumption = SessionResumption::default();
+        assert!(resumption.enabled);
+        let (acceptor, connector) = endpoints(&resumption);
+
+        assert_eq!(connect(&acceptor, &connector).await, HandshakeKind::Full);
+        assert_eq!(connect(&acceptor, &connector).await, HandshakeKind::Resumed);
+    }
+
+    #[tokio::test]
+    async fn test_disabled_resumption_always_does_full_handshake() {
+        let (acceptor, connector) = endpoints(&SessionResumption::disabled());
+
+        assert_eq!(connect(&acceptor, &connector).await, HandshakeKind::Full);
+        assert_eq!(connect(&acceptor, &connector).await, HandshakeKind::Full);
+    }
+
+    #[tokio::test]
+    async fn test_matching_pin_connects() {
+        // A certificate pin alongside one for a certificate being rotated in
+        let (acceptor, connector) = pinned_endpoints(|certified| {
+            CertificatePins::new()
+                .certificate(OTHER_PIN)
+                .unwrap()
+                .certificate_der(certified.cert.der())
+        });
+        handshake(&acceptor, &connector).await.unwrap();
+
+        // A public key pin, in the base64 `pin-sha256` form
+        let (acceptor, connector) = pinned_endpoints(|certified| {
+            use base64::Engine as _;
+            use sha2::Digest as _;
+            let spki = sha2::Sha256::digest(certified.key_pair.public_key_der());
+            let pin = base64::engine::general_purpose::STANDARD.encode(spki);
+            CertificatePins::new().public_key(&pin).unwrap()
+        });
+        handshake(&acceptor, &connector).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mismatched_pin_fails_handshake() {
+        let (acceptor, connector) = pinned_endpoints(|_| {
+            CertificatePins::new()
+                .certificate(OTHER_PIN)
+                .unwrap()
+                .public_key(&OTHER_PIN.replace(':', ""))
+                .unwrap()
+        });
+
+        // The chain is valid, but the certificate is not the pinned one
+        let error = handshake(&acceptor, &connector).await.unwrap_err();
+        assert!(
+            error.to_string().contains("does not match any pinned"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_invalid_pins_are_rejected() {
+        assert!(
+            CertificatePins::new()
+                .certificate("not a fingerprint")
+                .is_err()
+        );
+        assert!(CertificatePins::new().public_key("AA:BB").is_err());
+
+        let mut client = ClientConfig::builder()
+            .with_root_certificates(RootCertStore::empty())
+            .with_no_client_auth();
+        assert!(
+            apply_client_pinning(&mut client, RootCertStore::empty(), CertificatePins::new())
+                .is_err()
+        );
+    }
+}
@@ -42,7 +42,7 @@ async fn hello(
             annotations: None,
             meta: None,
         })],
-        is_error: None,
+        ..Default::default()
     })
 }
 
@@ -61,7 +61,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         title: Some("Hello".to_string()),
         description: Some("Say hello to someone".to_string()),
         input_schema: ToolInputSchema {
-            schema_type: "object".to_string(),
             properties: Some({
                 let mut props = HashMap::new();
                 props.insert(
@@ -75,10 +74,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }),
             required: None, // name is optional, defaults to "World"
             additional_properties: Some(false),
+            ..Default::default()
         },
-        output_schema: None,
-        annotations: None,
-        meta: None,
+        ..Default::default()
     };
 
     // Create handler
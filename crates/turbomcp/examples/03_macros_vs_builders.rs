@@ -197,7 +197,6 @@ mod builder_implementation {
                 title: Some("Uppercase".to_string()),
                 description: Some("Convert text to UPPERCASE".to_string()),
                 input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
                     properties: Some({
                         let mut props = HashMap::new();
                         props.insert(
@@ -211,10 +210,9 @@ mod builder_implementation {
                     }),
                     required: Some(vec!["text".to_string()]),
                     additional_properties: Some(false),
+                    ..Default::default()
                 },
-                output_schema: None,
-                annotations: None,
-                meta: None,
+                ..Default::default()
             };
 
             let handler = FunctionToolHandler::new(tool, move |req: CallToolRequest, _ctx| {
@@ -246,7 +244,7 @@ mod builder_implementation {
                             annotations: None,
                             meta: None,
                         })],
-                        is_error: None,
+                        ..Default::default()
                     })
                 }
             });
@@ -262,7 +260,6 @@ mod builder_implementation {
                 title: Some("Lowercase".to_string()),
                 description: Some("Convert text to lowercase".to_string()),
                 input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
                     properties: Some({
                         let mut props = HashMap::new();
                         props.insert(
@@ -276,10 +273,9 @@ mod builder_implementation {
                     }),
                     required: Some(vec!["text".to_string()]),
                     additional_properties: Some(false),
+                    ..Default::default()
                 },
-                output_schema: None,
-                annotations: None,
-                meta: None,
+                ..Default::default()
             };
 
             let handler = FunctionToolHandler::new(tool, move |req: CallToolRequest, _ctx| {
@@ -308,7 +304,7 @@ mod builder_implementation {
                             annotations: None,
                             meta: None,
                         })],
-                        is_error: None,
+                        ..Default::default()
                     })
                 }
             });
@@ -324,7 +320,6 @@ mod builder_implementation {
                 title: Some("Reverse".to_string()),
                 description: Some("Reverse the order of characters".to_string()),
                 input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
                     properties: Some({
                         let mut props = HashMap::new();
                         props.insert(
@@ -338,10 +333,9 @@ mod builder_implementation {
                     }),
                     required: Some(vec!["text".to_string()]),
                     additional_properties: Some(false),
+                    ..Default::default()
                 },
-                output_schema: None,
-                annotations: None,
-                meta: None,
+                ..Default::default()
             };
 
             let handler = FunctionToolHandler::new(tool, move |req: CallToolRequest, _ctx| {
@@ -370,7 +364,7 @@ mod builder_implementation {
                             annotations: None,
                             meta: None,
                         })],
-                        is_error: None,
+                        ..Default::default()
                     })
                 }
             });
@@ -386,7 +380,6 @@ mod builder_implementation {
                 title: Some("Word Count".to_string()),
                 description: Some("Count words in text".to_string()),
                 input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
                     properties: Some({
                         let mut props = HashMap::new();
                         props.insert(
@@ -400,10 +393,9 @@ mod builder_implementation {
                     }),
                     required: Some(vec!["text".to_string()]),
                     additional_properties: Some(false),
+                    ..Default::default()
                 },
-                output_schema: None,
-                annotations: None,
-                meta: None,
+                ..Default::default()
             };
 
             let handler = FunctionToolHandler::new(tool, move |req: CallToolRequest, _ctx| {
@@ -432,7 +424,7 @@ mod builder_implementation {
                             annotations: None,
                             meta: None,
                         })],
-                        is_error: None,
+                        ..Default::default()
                     })
                 }
             });
@@ -448,7 +440,6 @@ mod builder_implementation {
                 title: Some("Character Frequency".to_string()),
                 description: Some("Get character frequency".to_string()),
                 input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
                     properties: Some({
                         let mut props = HashMap::new();
                         props.insert(
@@ -462,10 +453,9 @@ mod builder_implementation {
                     }),
                     required: Some(vec!["text".to_string()]),
                     additional_properties: Some(false),
+                    ..Default::default()
                 },
-                output_schema: None,
-                annotations: None,
-                meta: None,
+                ..Default::default()
             };
 
             let handler = FunctionToolHandler::new(tool, move |req: CallToolRequest, _ctx| {
@@ -498,7 +488,7 @@ mod builder_implementation {
                             annotations: None,
                             meta: None,
                         })],
-                        is_error: None,
+                        ..Default::default()
                     })
                 }
             });
@@ -551,6 +541,7 @@ mod builder_implementation {
                                 text: stats_text,
                                 meta: None,
                             })],
+                            range: None,
                         })
                     }
                 });
@@ -255,6 +255,7 @@ pub struct DevProductivityAssistant {
     config: Arc<ServerConfig>,
     metrics: Arc<RwLock<ServerMetrics>>,
     active_sessions: Arc<RwLock<HashMap<String, UserSession>>>,
+    started_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone)]
@@ -342,6 +343,7 @@ impl DevProductivityAssistant {
             config,
             metrics: Arc::new(RwLock::new(ServerMetrics::default())),
             active_sessions: Arc::new(RwLock::new(HashMap::new())),
+            started_at: Utc::now(),
         };
 
         info!("✅ Server initialization complete");
@@ -2187,8 +2189,8 @@ impl DevProductivityAssistant {
                 "name": "Developer Productivity Assistant",
                 "version": "1.0.0",
                 "framework": "TurboMCP",
-                "uptime_seconds": 0, // Would track actual uptime in production
-                "started_at": Utc::now()
+                "uptime_seconds": (Utc::now() - self.started_at).num_seconds(),
+                "started_at": self.started_at
             },
             "performance_metrics": {
                 "requests_total": metrics.requests_total,
@@ -0,0 +1,117 @@
+//! # Streaming Resource - Serving Large Files in Chunks
+//!
+//! **What this example demonstrates:**
+//! - Implementing `StreamingResourceHandler` to produce content lazily
+//! - Reading a file in fixed-size chunks instead of loading it whole
+//! - Consuming the chunks in-process with `open_resource_stream`
+//! - Serving the same resource to clients over `resources/read`
+//!
+//! **Run with:** `cargo run --example streaming_resource [path]`
+//!
+//! Without a path, the example streams a generated 4 MiB log file.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::{StreamExt, stream};
+use tokio::io::AsyncReadExt;
+use turbomcp_core::RequestContext;
+use turbomcp_protocol::types::{ReadResourceRequest, Resource};
+use turbomcp_server::handlers::{ResourceStream, StreamingResourceHandler};
+use turbomcp_server::{ServerBuilder, ServerError, ServerResult};
+
+/// Size of each chunk read from disk
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streams a file from disk, one chunk at a time
+struct FileResource {
+    path: std::path::PathBuf,
+}
+
+#[async_trait]
+impl StreamingResourceHandler for FileResource {
+    async fn open(
+        &self,
+        _request: ReadResourceRequest,
+        _ctx: RequestContext,
+    ) -> ServerResult<ResourceStream> {
+        let file = tokio::fs::File::open(&self.path)
+            .await
+            .map_err(|e| ServerError::not_found(format!("{}: {e}", self.path.display())))?;
+
+        // Each poll reads at most one chunk; nothing is read ahead
+        let chunks = stream::try_unfold(file, |mut file| async move {
+            let mut buffer = vec![0; CHUNK_SIZE];
+            let read = file
+                .read(&mut buffer)
+                .await
+                .map_err(|e| ServerError::handler(format!("Read failed: {e}")))?;
+            if read == 0 {
+                return Ok(None);
+            }
+            buffer.truncate(read);
+            Ok(Some((Bytes::from(buffer), file)))
+        });
+        Ok(Box::pin(chunks))
+    }
+
+    fn resource_definition(&self) -> Resource {
+        Resource {
+            name: "big_file".to_string(),
+            title: Some("Big file".to_string()),
+            uri: "file:///big".to_string(),
+            description: Some("A large file served in chunks".to_string()),
+            mime_type: Some("text/plain".to_string()),
+            annotations: None,
+            size: std::fs::metadata(&self.path).ok().map(|m| m.len()),
+            meta: None,
+        }
+    }
+}
+
+/// Write a 4 MiB log file to the temp directory
+async fn generate_log() -> std::io::Result<std::path::PathBuf> {
+    let path = std::env::temp_dir().join("turbomcp-streaming-example.log");
+    let line = "2025-01-01T00:00:00Z INFO request handled in 3ms\n";
+    let contents = line.repeat(4 * 1024 * 1024 / line.len());
+    tokio::fs::write(&path, contents).await?;
+    Ok(path)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt()
+        .with_env_filter("info")
+        .with_writer(std::io::stderr)
+        .init();
+
+    let path = match std::env::args().nth(1) {
+        Some(path) => path.into(),
+        None => generate_log().await?,
+    };
+
+    let server = ServerBuilder::new()
+        .name("StreamingResource")
+        .version("1.0.0")
+        .streaming_resource("big_file", FileResource { path })?
+        .build();
+
+    // In-process consumers see the content chunk by chunk
+    let mut stream = server
+        .router()
+        .open_resource_stream(
+            ReadResourceRequest::new("file:///big"),
+            RequestContext::new(),
+        )
+        .await?;
+    let (mut chunks, mut bytes) = (0, 0);
+    while let Some(chunk) = stream.next().await {
+        chunks += 1;
+        bytes += chunk?.len();
+    }
+    tracing::info!("Streamed {bytes} bytes in {chunks} chunks of up to {CHUNK_SIZE} bytes");
+
+    // Clients reading over stdio get the buffered `resources/read` response
+    tracing::info!("Serving file:///big over stdio");
+    server.run_stdio().await?;
+    Ok(())
+}
@@ -66,6 +66,8 @@ pub enum AuthProviderType {
     Jwt,
     /// Custom authentication provider
     Custom,
+    /// Chain of providers tried in order
+    Composite,
 }
 
 /// Session configuration
@@ -1291,6 +1293,134 @@ impl AuthProvider for ApiKeyProvider {
     }
 }
 
+/// Authentication provider that accepts any of several methods
+///
+/// Each request is offered to the wrapped providers in order and the first
+/// to succeed supplies the [`AuthContext`], so a server can accept, say, an
+/// API key or an OAuth bearer token. A failing provider never ends the
+/// chain; the request is only rejected once every provider has declined it.
+#[derive(Debug)]
+pub struct CompositeAuthProvider {
+    /// Provider name
+    name: String,
+    /// Providers in the order they are tried
+    providers: Vec<Arc<dyn AuthProvider>>,
+}
+
+impl CompositeAuthProvider {
+    /// Start building a composite provider
+    #[must_use]
+    pub fn builder(name: impl Into<String>) -> CompositeAuthProviderBuilder {
+        CompositeAuthProviderBuilder {
+            name: name.into(),
+            providers: Vec::new(),
+        }
+    }
+
+    /// Names of the wrapped providers, in the order they are tried
+    #[must_use]
+    pub fn provider_names(&self) -> Vec<&str> {
+        self.providers.iter().map(|p| p.name()).collect()
+    }
+
+    /// Run `attempt` against each provider until one succeeds
+    async fn first_success<T, F, Fut>(&self, attempt: F) -> McpResult<T>
+    where
+        F: Fn(Arc<dyn AuthProvider>) -> Fut,
+        Fut: std::future::Future<Output = McpResult<T>>,
+    {
+        let mut failures = Vec::with_capacity(self.providers.len());
+        for provider in &self.providers {
+            match attempt(Arc::clone(provider)).await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    tracing::debug!(provider = provider.name(), error = %e, "Auth provider declined");
+                    failures.push(format!("{}: {e}", provider.name()));
+                }
+            }
+        }
+        Err(McpError::Unauthorized(format!(
+            "No provider in '{}' accepted the credentials ({})",
+            self.name,
+            failures.join("; ")
+        )))
+    }
+}
+
+/// Builder for [`CompositeAuthProvider`]
+#[derive(Debug)]
+pub struct CompositeAuthProviderBuilder {
+    /// Provider name
+    name: String,
+    /// Providers in the order they are tried
+    providers: Vec<Arc<dyn AuthProvider>>,
+}
+
+impl CompositeAuthProviderBuilder {
+    /// Append a provider to try after those already added
+    #[must_use]
+    pub fn provider(mut self, provider: Arc<dyn AuthProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// Build the provider
+    ///
+    /// Fails if no providers were added, since the composite could never
+    /// authenticate anyone.
+    pub fn build(self) -> McpResult<CompositeAuthProvider> {
+        if self.providers.is_empty() {
+            return Err(McpError::Tool(format!(
+                "Composite provider '{}' has no providers",
+                self.name
+            )));
+        }
+        Ok(CompositeAuthProvider {
+            name: self.name,
+            providers: self.providers,
+        })
+    }
+}
+
+#[async_trait]
+impl AuthProvider for CompositeAuthProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn provider_type(&self) -> AuthProviderType {
+        AuthProviderType::Composite
+    }
+
+    async fn authenticate(&self, credentials: AuthCredentials) -> McpResult<AuthContext> {
+        self.first_success(|provider| {
+            let credentials = credentials.clone();
+            async move { provider.authenticate(credentials).await }
+        })
+        .await
+    }
+
+    async fn validate_token(&self, token: &str) -> McpResult<AuthContext> {
+        self.first_success(|provider| async move { provider.validate_token(token).await })
+            .await
+    }
+
+    async fn refresh_token(&self, refresh_token: &str) -> McpResult<TokenInfo> {
+        self.first_success(|provider| async move { provider.refresh_token(refresh_token).await })
+            .await
+    }
+
+    async fn revoke_token(&self, token: &str) -> McpResult<()> {
+        self.first_success(|provider| async move { provider.revoke_token(token).await })
+            .await
+    }
+
+    async fn get_user_info(&self, token: &str) -> McpResult<UserInfo> {
+        self.first_success(|provider| async move { provider.get_user_info(token).await })
+            .await
+    }
+}
+
 /// Authentication manager
 #[derive(Debug)]
 pub struct AuthManager {
@@ -1340,6 +1470,34 @@ impl AuthManager {
         self.providers.write().await.insert(name, provider);
     }
 
+    /// Register a composite provider chaining the configured providers
+    ///
+    /// The chain holds every enabled, non-composite entry of
+    /// [`AuthConfig::providers`] in priority order (lowest number first).
+    /// Each must already be registered with [`add_provider`](Self::add_provider).
+    pub async fn register_composite(&self, name: impl Into<String>) -> McpResult<()> {
+        let mut configured: Vec<_> = self
+            .config
+            .providers
+            .iter()
+            .filter(|p| p.enabled && p.provider_type != AuthProviderType::Composite)
+            .collect();
+        configured.sort_by_key(|p| p.priority);
+
+        let mut builder = CompositeAuthProvider::builder(name);
+        {
+            let providers = self.providers.read().await;
+            for config in configured {
+                let provider = providers.get(&config.name).ok_or_else(|| {
+                    McpError::Tool(format!("Provider '{}' not found", config.name))
+                })?;
+                builder = builder.provider(Arc::clone(provider));
+            }
+        }
+        self.add_provider(Arc::new(builder.build()?)).await;
+        Ok(())
+    }
+
     /// Remove an authentication provider
     pub async fn remove_provider(&self, name: &str) -> bool {
         self.providers.write().await.remove(name).is_some()
@@ -1618,4 +1776,136 @@ mod tests {
         let providers = manager.list_providers().await;
         assert!(providers.contains(&"api".to_string()));
     }
+
+    /// Accepts a single fixed bearer token
+    #[derive(Debug)]
+    struct BearerProvider;
+
+    #[async_trait]
+    impl AuthProvider for BearerProvider {
+        fn name(&self) -> &str {
+            "bearer"
+        }
+
+        fn provider_type(&self) -> AuthProviderType {
+            AuthProviderType::Jwt
+        }
+
+        async fn authenticate(&self, credentials: AuthCredentials) -> McpResult<AuthContext> {
+            match credentials {
+                AuthCredentials::JwtToken { token } => self.validate_token(&token).await,
+                _ => Err(McpError::Tool("Expected a bearer token".to_string())),
+            }
+        }
+
+        async fn validate_token(&self, token: &str) -> McpResult<AuthContext> {
+            if token != "valid-bearer" {
+                return Err(McpError::Tool("Invalid bearer token".to_string()));
+            }
+            let user = self.get_user_info(token).await?;
+            Ok(AuthContext {
+                user_id: user.id.clone(),
+                user,
+                roles: vec!["bearer_user".to_string()],
+                permissions: vec![],
+                session_id: "session".to_string(),
+                token: None,
+                provider: self.name().to_string(),
+                authenticated_at: SystemTime::now(),
+                expires_at: None,
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn refresh_token(&self, _refresh_token: &str) -> McpResult<TokenInfo> {
+            Err(McpError::Tool("Not supported".to_string()))
+        }
+
+        async fn revoke_token(&self, _token: &str) -> McpResult<()> {
+            Ok(())
+        }
+
+        async fn get_user_info(&self, _token: &str) -> McpResult<UserInfo> {
+            Ok(UserInfo {
+                id: "oauth-user".to_string(),
+                username: "oauth".to_string(),
+                email: None,
+                display_name: None,
+                avatar_url: None,
+                metadata: HashMap::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_composite_falls_through_to_bearer() {
+        let composite = CompositeAuthProvider::builder("any")
+            .provider(Arc::new(ApiKeyProvider::new("api".to_string())))
+            .provider(Arc::new(BearerProvider))
+            .build()
+            .unwrap();
+
+        // The API key provider rejects the token, the bearer provider accepts it
+        let context = composite.validate_token("valid-bearer").await.unwrap();
+        assert_eq!(context.provider, "bearer");
+        assert_eq!(context.user_id, "oauth-user");
+        assert_eq!(context.roles, vec!["bearer_user".to_string()]);
+
+        let error = composite.validate_token("unknown").await.unwrap_err();
+        assert!(matches!(error, McpError::Unauthorized(_)));
+        assert!(error.to_string().contains("api:"));
+        assert!(error.to_string().contains("bearer:"));
+
+        assert!(CompositeAuthProvider::builder("empty").build().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_register_composite_from_config() {
+        let provider_config = |name: &str, provider_type, priority| AuthProviderConfig {
+            name: name.to_string(),
+            provider_type,
+            settings: HashMap::new(),
+            enabled: true,
+            priority,
+        };
+        let config = AuthConfig {
+            enabled: true,
+            providers: vec![
+                provider_config("bearer", AuthProviderType::Jwt, 2),
+                provider_config("api", AuthProviderType::ApiKey, 1),
+            ],
+            session: SessionConfig {
+                timeout_seconds: 3600,
+                secure_cookies: true,
+                cookie_domain: None,
+                storage: SessionStorageType::Memory,
+                max_sessions_per_user: None,
+            },
+            authorization: AuthorizationConfig {
+                rbac_enabled: false,
+                default_roles: vec![],
+                inheritance_rules: HashMap::new(),
+                resource_permissions: HashMap::new(),
+            },
+        };
+        let manager = AuthManager::new(config);
+        assert!(manager.register_composite("any").await.is_err());
+
+        manager
+            .add_provider(Arc::new(ApiKeyProvider::new("api".to_string())))
+            .await;
+        manager.add_provider(Arc::new(BearerProvider)).await;
+        manager.register_composite("any").await.unwrap();
+
+        let context = manager
+            .authenticate(
+                "any",
+                AuthCredentials::JwtToken {
+                    token: "valid-bearer".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(context.provider, "bearer");
+    }
 }
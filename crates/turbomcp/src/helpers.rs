@@ -1,6 +1,12 @@
 //! Helper functions and utilities
 
 use crate::{CallToolResult, Content, GetPromptResult, TextContent};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use turbomcp_protocol::types::{
+    BlobResourceContents, EmbeddedResource, ImageContent, PromptMessage, ReadResourceResult,
+    ResourceContent, Role, TextResourceContents,
+};
 
 /// Create text content helper
 pub fn text<S: AsRef<str>>(content: S) -> Content {
@@ -11,6 +17,44 @@ pub fn text<S: AsRef<str>>(content: S) -> Content {
     })
 }
 
+/// Create image content from base64-encoded `data`
+pub fn image<D: Into<String>, M: Into<String>>(data: D, mime_type: M) -> Content {
+    Content::Image(ImageContent {
+        data: data.into(),
+        mime_type: mime_type.into(),
+        annotations: None,
+        meta: None,
+    })
+}
+
+/// Create content embedding a resource's contents
+#[must_use]
+pub const fn embedded_resource(resource: ResourceContent) -> Content {
+    Content::Resource(EmbeddedResource {
+        resource,
+        annotations: None,
+        meta: None,
+    })
+}
+
+/// Create a prompt message sent as the user
+#[must_use]
+pub const fn user_message(content: Content) -> PromptMessage {
+    PromptMessage {
+        role: Role::User,
+        content,
+    }
+}
+
+/// Create a prompt message sent as the assistant
+#[must_use]
+pub const fn assistant_message(content: Content) -> PromptMessage {
+    PromptMessage {
+        role: Role::Assistant,
+        content,
+    }
+}
+
 /// Create an error content helper  
 pub fn error_text<S: AsRef<str>>(message: S) -> Content {
     Content::Text(TextContent {
@@ -26,6 +70,7 @@ pub const fn tool_success(content: Vec<Content>) -> CallToolResult {
     CallToolResult {
         content,
         is_error: Some(false),
+        structured_content: None,
     }
 }
 
@@ -34,6 +79,7 @@ pub fn tool_error<S: AsRef<str>>(message: S) -> CallToolResult {
     CallToolResult {
         content: vec![error_text(message)],
         is_error: Some(true),
+        ..Default::default()
     }
 }
 
@@ -42,21 +88,84 @@ pub fn prompt_result<S: AsRef<str>>(
     content: S,
     description: S,
 ) -> crate::McpResult<GetPromptResult> {
-    use turbomcp_protocol::types::{PromptMessage, Role};
+    Ok(GetPromptResult {
+        messages: vec![user_message(text(content))],
+        description: Some(description.as_ref().to_string()),
+    })
+}
 
+/// Create a prompt result from messages of any content type
+///
+/// Unlike [`prompt_result`], the messages may carry images or embedded
+/// resources alongside text, and are sent as given.
+pub fn prompt_messages<S: AsRef<str>>(
+    messages: Vec<PromptMessage>,
+    description: S,
+) -> crate::McpResult<GetPromptResult> {
     Ok(GetPromptResult {
-        messages: vec![PromptMessage {
-            role: Role::User,
-            content: Content::Text(TextContent {
-                text: content.as_ref().to_string(),
-                annotations: None,
-                meta: None,
-            }),
-        }],
+        messages,
         description: Some(description.as_ref().to_string()),
     })
 }
 
+/// Values a `#[prompt]` method may return
+///
+/// Text becomes a single user message. Content and messages are kept as they
+/// are, so a prompt can return screenshots or embedded resources without
+/// them being flattened to text.
+pub trait IntoPromptResult {
+    /// Convert into the result sent for `prompts/get`
+    fn into_prompt_result(self) -> GetPromptResult;
+}
+
+impl IntoPromptResult for GetPromptResult {
+    fn into_prompt_result(self) -> GetPromptResult {
+        self
+    }
+}
+
+impl IntoPromptResult for Vec<PromptMessage> {
+    fn into_prompt_result(self) -> GetPromptResult {
+        GetPromptResult {
+            description: None,
+            messages: self,
+        }
+    }
+}
+
+impl IntoPromptResult for PromptMessage {
+    fn into_prompt_result(self) -> GetPromptResult {
+        vec![self].into_prompt_result()
+    }
+}
+
+impl IntoPromptResult for Vec<Content> {
+    fn into_prompt_result(self) -> GetPromptResult {
+        self.into_iter()
+            .map(user_message)
+            .collect::<Vec<_>>()
+            .into_prompt_result()
+    }
+}
+
+impl IntoPromptResult for Content {
+    fn into_prompt_result(self) -> GetPromptResult {
+        user_message(self).into_prompt_result()
+    }
+}
+
+impl IntoPromptResult for String {
+    fn into_prompt_result(self) -> GetPromptResult {
+        text(self).into_prompt_result()
+    }
+}
+
+impl IntoPromptResult for &str {
+    fn into_prompt_result(self) -> GetPromptResult {
+        text(self).into_prompt_result()
+    }
+}
+
 /// Create a resource read result
 pub fn resource_result<S: AsRef<str>>(
     content: S,
@@ -70,5 +179,123 @@ pub fn resource_result<S: AsRef<str>>(
             text: content.as_ref().to_string(),
             meta: None,
         })],
+        range: None,
     })
 }
+
+/// Content of a resource, either text or raw bytes
+///
+/// Bytes are base64-encoded into the `blob` field of the contents read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceBody {
+    /// Text, sent as is
+    Text(String),
+    /// Binary data, such as an image
+    Blob(Vec<u8>),
+}
+
+impl From<String> for ResourceBody {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ResourceBody {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<u8>> for ResourceBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Blob(bytes)
+    }
+}
+
+/// Content a `#[resource]` method serves, with a MIME type chosen as it is read
+///
+/// For resources whose type depends on what is read, such as a file server.
+/// The type given here takes precedence over the attribute's `mime_type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedResource {
+    /// Resource content
+    pub body: ResourceBody,
+    /// MIME type of the content
+    pub mime_type: String,
+}
+
+impl TypedResource {
+    /// Text or bytes of the given MIME type
+    pub fn new(body: impl Into<ResourceBody>, mime_type: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            mime_type: mime_type.into(),
+        }
+    }
+}
+
+/// Values a `#[resource]` method may return
+///
+/// Text or bytes are served as the content of the URI read, typed with the
+/// MIME type declared on the attribute, if any. Without one the server infers
+/// the type when the result is sent: `text/plain` for most text, and for bytes
+/// the format their leading bytes identify, else `application/octet-stream`.
+pub trait IntoResourceResult {
+    /// Convert into the result sent for `resources/read` of `uri`
+    fn into_resource_result(self, uri: &str, mime_type: Option<&str>) -> ReadResourceResult;
+}
+
+impl IntoResourceResult for ReadResourceResult {
+    fn into_resource_result(self, _uri: &str, _mime_type: Option<&str>) -> ReadResourceResult {
+        self
+    }
+}
+
+impl IntoResourceResult for TypedResource {
+    fn into_resource_result(self, uri: &str, _mime_type: Option<&str>) -> ReadResourceResult {
+        self.body.into_resource_result(uri, Some(&self.mime_type))
+    }
+}
+
+impl IntoResourceResult for ResourceBody {
+    fn into_resource_result(self, uri: &str, mime_type: Option<&str>) -> ReadResourceResult {
+        let uri = uri.to_string();
+        let mime_type = mime_type.map(str::to_string);
+        let content = match self {
+            Self::Text(text) => ResourceContent::Text(TextResourceContents {
+                uri,
+                mime_type,
+                text,
+                meta: None,
+            }),
+            Self::Blob(bytes) => ResourceContent::Blob(BlobResourceContents {
+                uri,
+                mime_type,
+                blob: BASE64.encode(bytes),
+                meta: None,
+            }),
+        };
+        ReadResourceResult {
+            contents: vec![content],
+            range: None,
+        }
+    }
+}
+
+impl IntoResourceResult for String {
+    fn into_resource_result(self, uri: &str, mime_type: Option<&str>) -> ReadResourceResult {
+        ResourceBody::Text(self).into_resource_result(uri, mime_type)
+    }
+}
+
+impl IntoResourceResult for Vec<u8> {
+    fn into_resource_result(self, uri: &str, mime_type: Option<&str>) -> ReadResourceResult {
+        ResourceBody::Blob(self).into_resource_result(uri, mime_type)
+    }
+}
+
+impl IntoResourceResult for &str {
+    fn into_resource_result(self, uri: &str, mime_type: Option<&str>) -> ReadResourceResult {
+        self.to_string().into_resource_result(uri, mime_type)
+    }
+}
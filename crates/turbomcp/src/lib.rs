@@ -203,6 +203,11 @@
 //! - `server-only` - TCP + Unix (no STDIO)
 //! - `all-transports` - Maximum flexibility
 //!
+//! Enable `arbitrary-precision` when tools exchange numbers that must not be
+//! rounded to `f64`, such as monetary amounts or 128-bit identifiers. Numbers
+//! then keep their exact digits from the wire to the handler and back, at some
+//! cost in parsing speed; SIMD JSON parsing is bypassed for messages.
+//!
 //! For more examples and advanced usage, see the [examples directory](https://github.com/Epistates/turbomcp/tree/main/crates/turbomcp/examples).
 //!
 //! ## Architecture
@@ -234,22 +239,25 @@ use std::sync::Arc;
 // async_trait re-exported below
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
+use turbomcp_protocol::methods;
+use turbomcp_protocol::types::LoggingNotification;
 
 // Re-export core types for convenience
-pub use turbomcp_core::{MessageId, RequestContext};
+pub use turbomcp_core::{MessageId, ProgressStatus, RequestContext};
 // Re-export key protocol types (avoiding * import to prevent ambiguous re-exports)
 pub use turbomcp_protocol::GetPromptResult;
 pub use turbomcp_protocol::jsonrpc::{
     JsonRpcError, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
 };
 pub use turbomcp_protocol::types::{
-    CallToolRequest, CallToolResult, ClientCapabilities, Content, ImageContent, Implementation,
-    InitializeRequest, InitializeResult, PromptMessage, Resource, ServerCapabilities, TextContent,
-    Tool, ToolInputSchema,
+    CallToolRequest, CallToolResult, ClientCapabilities, CompleteRequest, CompleteResult,
+    Completion, CompletionReference, Content, ImageContent, Implementation, InitializeRequest,
+    InitializeResult, LogLevel, PromptMessage, ReadResourceRequest, ReadResourceResult, Resource,
+    ResourceLink, ServerCapabilities, TextContent, Tool, ToolInputSchema, ToolOutputSchema,
 };
 pub use turbomcp_server::{
-    McpServer, McpServer as Server, ServerBuilder, ServerError, ServerResult, ShutdownHandle,
-    handlers,
+    BuildInfo, McpServer, McpServer as Server, ServerBuilder, ServerError, ServerResult,
+    ShutdownHandle, ToolOutputLimit, handlers, uri_template_params,
 };
 
 // Re-export async_trait for macros
@@ -288,8 +296,9 @@ pub mod schema;
 pub use crate::auth::SessionConfig as AuthSessionConfig;
 pub use crate::auth::{
     ApiKeyProvider, AuthConfig, AuthContext, AuthCredentials, AuthManager, AuthMiddleware,
-    AuthProvider, AuthProviderConfig, AuthProviderType, OAuth2Config, OAuth2FlowType,
-    OAuth2Provider, TokenInfo, UserInfo,
+    AuthProvider, AuthProviderConfig, AuthProviderType, CompositeAuthProvider,
+    CompositeAuthProviderBuilder, OAuth2Config, OAuth2FlowType, OAuth2Provider, TokenInfo,
+    UserInfo,
 };
 pub use crate::context::*;
 pub use crate::context_factory::{
@@ -315,21 +324,25 @@ pub use crate::validation::*;
 pub use inventory;
 
 // Re-export macros
-pub use turbomcp_macros::{mcp_error, mcp_text, prompt, resource, server, tool, tool_result};
+pub use turbomcp_macros::{
+    completion, mcp_error, mcp_text, prompt, resource, server, tool, tool_result,
+};
 
 /// Convenient prelude for `TurboMCP` applications
 pub mod prelude {
     // Re-export procedural macros for zero-boilerplate development
-    pub use super::{mcp_error, mcp_text, prompt, resource, server, tool, tool_result};
+    pub use super::{completion, mcp_error, mcp_text, prompt, resource, server, tool, tool_result};
 
     pub use super::{
         ApiKeyProvider, AuthConfig, AuthContext, AuthCredentials, AuthManager, AuthMiddleware,
         AuthProvider, AuthProviderConfig, AuthProviderType, CallToolRequest, CallToolResult,
-        Context, ElicitationManager, HandlerMetadata, HandlerRegistration, McpError, McpResult,
-        McpServer, OAuth2Config, OAuth2FlowType, OAuth2Provider, RequestContext, Server,
-        ServerBuilder, ServerError, TokenInfo, Transport, TransportConfig, TransportFactory,
-        TransportManager, TurboMcpServer, UserInfo, error_text, handlers, prompt_result,
-        resource_result, text, tool_error, tool_success,
+        Context, ElicitationManager, HandlerMetadata, HandlerRegistration, IntoPromptResult,
+        IntoResourceResult, McpError, McpResult, McpServer, OAuth2Config, OAuth2FlowType,
+        OAuth2Provider, ProgressStatus, RequestContext, ResourceBody, Server, ServerBuilder,
+        ServerError, TokenInfo, Transport, TransportConfig, TransportFactory, TransportManager,
+        TurboMcpServer, TypedResource, UserInfo, assistant_message, embedded_resource, error_text,
+        handlers, image, prompt_messages, prompt_result, resource_result, text, tool_error,
+        tool_success, user_message,
     };
 
     // Re-export essential types
@@ -444,6 +457,65 @@ impl McpError {
     pub fn invalid_input(msg: impl Into<String>) -> Self {
         Self::InvalidInput(msg.into())
     }
+
+    /// JSON-RPC error code this error is reported with
+    ///
+    /// Errors about a missing resource or prompt, failed authentication, bad
+    /// input or a malformed request get their MCP or JSON-RPC codes; a tool
+    /// failure is a tool execution error, and anything else is internal.
+    #[must_use]
+    pub const fn error_code(&self) -> i32 {
+        use turbomcp_protocol::error_codes;
+        match self {
+            Self::Server(e) => e.error_code(),
+            Self::Protocol(_) | Self::InvalidRequest(_) => error_codes::INVALID_REQUEST,
+            Self::Tool(_) => error_codes::TOOL_EXECUTION_ERROR,
+            Self::Resource(_) => error_codes::RESOURCE_NOT_FOUND,
+            Self::Prompt(_) => error_codes::PROMPT_NOT_FOUND,
+            Self::Unauthorized(_) => error_codes::AUTHENTICATION_REQUIRED,
+            Self::InvalidInput(_) => error_codes::INVALID_PARAMS,
+            Self::Context(_)
+            | Self::Network(_)
+            | Self::Schema(_)
+            | Self::Transport(_)
+            | Self::Serialization(_)
+            | Self::Internal(_) => error_codes::INTERNAL_ERROR,
+        }
+    }
+
+    /// Structured details for the `data` member of a JSON-RPC error
+    #[must_use]
+    pub fn error_data(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::Server(e) => e.error_data(),
+            _ => None,
+        }
+    }
+
+    /// The JSON-RPC error this error is reported as
+    #[must_use]
+    pub fn to_json_rpc_error(&self) -> JsonRpcError {
+        JsonRpcError {
+            code: self.error_code(),
+            message: self.to_string(),
+            data: self.error_data(),
+        }
+    }
+}
+
+/// Keeps the code [`McpError::error_code`] assigns when a handler's error
+/// is answered by the server
+impl From<McpError> for turbomcp_server::ServerError {
+    fn from(error: McpError) -> Self {
+        match error {
+            McpError::Server(e) => e,
+            error => Self::JsonRpc {
+                code: error.error_code(),
+                message: error.to_string(),
+                data: error.error_data(),
+            },
+        }
+    }
 }
 
 impl From<turbomcp_transport::core::TransportError> for McpError {
@@ -452,6 +524,12 @@ impl From<turbomcp_transport::core::TransportError> for McpError {
     }
 }
 
+impl From<turbomcp_core::BudgetExceeded> for McpError {
+    fn from(exceeded: turbomcp_core::BudgetExceeded) -> Self {
+        Self::Server(exceeded.into())
+    }
+}
+
 impl From<Box<turbomcp_core::Error>> for McpError {
     fn from(core_error: Box<turbomcp_core::Error>) -> Self {
         // Convert core error to server error first, then to McpError
@@ -603,6 +681,45 @@ impl Context {
         }
     }
 
+    /// Get a value the client sent in its initialize `_meta`
+    ///
+    /// Returns `None` if the client sent no metadata or not this key.
+    #[must_use]
+    pub fn client_meta(&self, key: &str) -> Option<&serde_json::Value> {
+        self.request
+            .get_metadata(turbomcp_server::CLIENT_META_KEY)?
+            .get(key)
+    }
+
+    /// Build a link to one of this server's own resources
+    ///
+    /// `uri` must be the URI of a registered resource or match a registered
+    /// resource template; the link takes its name, description and MIME type
+    /// from that resource. A URI the server does not serve is rejected, so a
+    /// tool cannot hand the client a link that fails to read.
+    pub fn resource_link(&self, uri: &str) -> McpResult<Content> {
+        let resource: Resource = self
+            .request
+            .resources
+            .as_ref()
+            .and_then(|resources| resources.resource_for(uri))
+            .and_then(|value| serde_json::from_value(value).ok())
+            .ok_or_else(|| {
+                tracing::warn!(uri, handler = %self.handler.name, "Link to unknown resource");
+                McpError::Resource(format!("No registered resource matches '{uri}'"))
+            })?;
+        Ok(Content::ResourceLink(ResourceLink {
+            name: resource.name,
+            title: resource.title,
+            uri: uri.to_string(),
+            description: resource.description,
+            mime_type: resource.mime_type,
+            annotations: None,
+            size: None,
+            meta: None,
+        }))
+    }
+
     /// Resolve a service from the dependency injection container
     pub async fn resolve<T: 'static + Clone>(&self, name: &str) -> McpResult<T> {
         self.container.resolve_with_dependencies(name).await
@@ -628,40 +745,73 @@ impl Context {
         self.container.register_singleton(name, factory).await;
     }
 
+    /// Log a message to the client at `level`
+    ///
+    /// Sends a `notifications/message` from this handler's logger if `level`
+    /// is at least as severe as the level the client set with
+    /// `logging/setLevel`. Clients that set none receive
+    /// [`DEFAULT_LOG_LEVEL`](turbomcp_server::DEFAULT_LOG_LEVEL) and above.
+    pub async fn log<S: AsRef<str>>(&self, level: LogLevel, message: S) -> McpResult<()> {
+        let wanted = self
+            .request
+            .get_metadata(turbomcp_server::LOG_LEVEL_KEY)
+            .and_then(|wanted| serde_json::from_value::<LogLevel>(wanted.clone()).ok())
+            .unwrap_or(turbomcp_server::DEFAULT_LOG_LEVEL);
+        if level >= wanted {
+            let notification = LoggingNotification {
+                level,
+                data: message.as_ref().into(),
+                logger: Some(self.handler.name.clone()),
+            };
+            self.request
+                .notify(methods::LOG_MESSAGE, serde_json::to_value(notification)?);
+        }
+        Ok(())
+    }
+
     /// Log an info message to the client
     pub async fn info<S: AsRef<str>>(&self, message: S) -> McpResult<()> {
         tracing::info!("{}", message.as_ref());
-        // Logging notification sent via tracing infrastructure
-        Ok(())
+        self.log(LogLevel::Info, message).await
     }
 
     /// Log a warning message to the client
     pub async fn warn<S: AsRef<str>>(&self, message: S) -> McpResult<()> {
         tracing::warn!("{}", message.as_ref());
-        // Logging notification sent via tracing infrastructure
-        Ok(())
+        self.log(LogLevel::Warning, message).await
     }
 
     /// Log an error message to the client
     pub async fn error<S: AsRef<str>>(&self, message: S) -> McpResult<()> {
         tracing::error!("{}", message.as_ref());
-        // Logging notification sent via tracing infrastructure
-        Ok(())
+        self.log(LogLevel::Error, message).await
     }
 
     /// Report progress for long-running operations
+    ///
+    /// If the client attached a `_meta.progressToken` to the request, this
+    /// sends it a `notifications/progress` carrying that token; otherwise
+    /// the client did not ask for progress and it is only logged.
     pub async fn report_progress(&self, progress: f64, total: Option<f64>) -> McpResult<()> {
         tracing::debug!("Progress: {} / {:?}", progress, total);
+        self.request.notify_progress(progress, total, None);
+        Ok(())
+    }
 
-        // Generate or use existing progress token
-        let token = crate::progress::ProgressToken::new();
-
-        // Update progress using the global progress manager
-        crate::progress::global_progress_manager().update_progress(&token, progress, total)?;
-
-        // Progress notification sent to MCP client via notification system
-        // Integrated with the MCP notification protocol
-
+    /// Tell the client the progress-tracked operation has finished
+    ///
+    /// Sends a `notifications/progress/complete` with `status` and an
+    /// optional summary of the outcome. The server sends one automatically
+    /// when the handler returns, so call this only to finish early or to
+    /// describe the result; either way the client receives exactly one, and
+    /// progress reported after it is dropped.
+    pub async fn complete_progress(
+        &self,
+        status: ProgressStatus,
+        summary: Option<&str>,
+    ) -> McpResult<()> {
+        tracing::debug!("Progress complete: {:?}", status);
+        self.request.notify_progress_complete(status, summary);
         Ok(())
     }
 
@@ -32,7 +32,7 @@ use crate::{McpError, McpResult};
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::{
     jsonrpc::{JsonRpcRequest, JsonRpcVersion},
-    types::{CallToolRequest, CallToolResult, Tool, ToolInputSchema},
+    types::{CallToolRequest, CallToolResult, Tool},
 };
 
 /// Ergonomic tool router that wraps mcp-server functionality
@@ -152,26 +152,14 @@ impl ServerToolHandler for TurboToolHandlerWrapper {
         let args = request.arguments.unwrap_or_default();
         let args_value = serde_json::to_value(args)
             .map_err(|e| turbomcp_server::ServerError::Internal(e.to_string()))?;
-        match (self.handler)(args_value).await {
-            Ok(result) => Ok(result),
-            Err(e) => Err(turbomcp_server::ServerError::Internal(e.to_string())),
-        }
+        (self.handler)(args_value).await.map_err(Into::into)
     }
 
     fn tool_definition(&self) -> Tool {
         Tool {
             name: self.tool_name.clone(),
-            title: None,
             description: Some("Tool handler".to_string()),
-            input_schema: ToolInputSchema {
-                schema_type: "object".to_string(),
-                properties: None,
-                required: None,
-                additional_properties: None,
-            },
-            output_schema: None,
-            annotations: None,
-            meta: None,
+            ..Default::default()
         }
     }
 }
@@ -285,7 +273,7 @@ mod tests {
                         meta: None,
                     },
                 )],
-                is_error: None,
+                ..Default::default()
             })
         });
 
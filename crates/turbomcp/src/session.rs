@@ -34,6 +34,29 @@ pub struct SessionInfo {
 /// Client session information (alias for SSE server compatibility)
 pub type ClientSession = SessionInfo;
 
+/// Serializable copy of a session manager's sessions and their data
+///
+/// Taken with [`SessionManager::snapshot`] and loaded with
+/// [`SessionManager::restore`], possibly on another instance. Connections are
+/// not part of a snapshot: a client resumes its session by presenting the
+/// session id when it reconnects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    /// When the snapshot was taken
+    pub taken_at: SystemTime,
+    /// Sessions, least recently used first
+    pub sessions: Vec<SnapshotSession>,
+}
+
+/// One session in a [`SessionSnapshot`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotSession {
+    /// Session information
+    pub info: SessionInfo,
+    /// Session-scoped data, by key
+    pub data: HashMap<String, serde_json::Value>,
+}
+
 /// Request to create a new session
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateSessionRequest {
@@ -663,6 +686,94 @@ impl SessionManager {
     pub const fn get_config(&self) -> &SessionConfig {
         &self.config
     }
+
+    /// Copy every session and its session-scoped data
+    pub async fn snapshot(&self) -> SessionSnapshot {
+        let metadata = self.session_metadata.read().await;
+        let access_order = self.access_order.read().await;
+        let keys = self.state_manager.list_keys();
+
+        let sessions = access_order
+            .iter()
+            .filter_map(|id| metadata.get(id))
+            .map(|info| {
+                let data_prefix = format!("session:{}:data:", info.session_id);
+                let data = keys
+                    .iter()
+                    .filter_map(|key| {
+                        let name = key.strip_prefix(&data_prefix)?;
+                        Some((name.to_string(), self.state_manager.get(key)?))
+                    })
+                    .collect();
+                SnapshotSession {
+                    info: info.clone(),
+                    data,
+                }
+            })
+            .collect();
+
+        SessionSnapshot {
+            taken_at: SystemTime::now(),
+            sessions,
+        }
+    }
+
+    /// Load the sessions in `snapshot`, returning how many were restored
+    ///
+    /// Sessions keep their ids, so clients can resume them when they
+    /// reconnect. A session that already exists here is replaced, and one
+    /// that has been idle longer than the session timeout is skipped. The
+    /// session limits apply as if the sessions were being created, evicting
+    /// the least recently used sessions to make room.
+    pub async fn restore(&self, snapshot: SessionSnapshot) -> McpResult<usize> {
+        let now = SystemTime::now();
+        let mut restored = 0;
+
+        for SnapshotSession { info, data } in snapshot.sessions {
+            if now
+                .duration_since(info.last_activity)
+                .is_ok_and(|idle| idle > self.config.timeout)
+            {
+                continue;
+            }
+            let session_id = info.session_id.clone();
+            self.terminate_session(&session_id).await?;
+            self.enforce_capacity_limits(&info.client_id).await?;
+
+            self.state_manager.set(
+                format!("session:{session_id}"),
+                serde_json::to_value(&info)
+                    .map_err(|e| McpError::Tool(format!("Failed to serialize session: {e}")))?,
+            );
+            let mut data_size = 0;
+            for (key, value) in data {
+                data_size += serde_json::to_string(&value)
+                    .map_err(|e| McpError::Tool(format!("Failed to serialize value: {e}")))?
+                    .len();
+                self.state_manager
+                    .set(format!("session:{session_id}:data:{key}"), value);
+            }
+
+            self.client_sessions
+                .write()
+                .await
+                .entry(info.client_id.clone())
+                .or_default()
+                .push(session_id.clone());
+            self.session_metadata
+                .write()
+                .await
+                .insert(session_id.clone(), info);
+            self.session_data_sizes
+                .write()
+                .await
+                .insert(session_id.clone(), data_size);
+            self.update_access_order(&session_id).await;
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
 }
 
 /// Enhanced session statistics with performance metrics
@@ -900,4 +1011,78 @@ mod tests {
         assert_eq!(stats.total_sessions, 2);
         assert_eq!(stats.active_sessions, 2);
     }
+
+    #[tokio::test]
+    async fn test_snapshot_and_restore() {
+        let manager = SessionManager::new(SessionConfig::default());
+        let first = manager
+            .create_session_simple("client1".to_string())
+            .await
+            .unwrap();
+        let second = manager
+            .create_session_simple("client2".to_string())
+            .await
+            .unwrap();
+        manager
+            .set_session_data(
+                &first.session_id,
+                "cart".to_string(),
+                serde_json::json!(["apple", "pear"]),
+            )
+            .await
+            .unwrap();
+        manager
+            .set_session_data(
+                &second.session_id,
+                "theme".to_string(),
+                serde_json::json!("dark"),
+            )
+            .await
+            .unwrap();
+
+        // Through JSON, as it would be stored across a restart
+        let snapshot = serde_json::to_string(&manager.snapshot().await).unwrap();
+        let snapshot: SessionSnapshot = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(snapshot.sessions.len(), 2);
+
+        let restored = SessionManager::new(SessionConfig::default());
+        assert_eq!(restored.restore(snapshot).await.unwrap(), 2);
+
+        let session = restored.get_session(&first.session_id).await.unwrap();
+        assert_eq!(session.client_id, "client1");
+        assert_eq!(
+            restored
+                .get_session_data(&first.session_id, "cart")
+                .await
+                .unwrap(),
+            serde_json::json!(["apple", "pear"])
+        );
+        assert_eq!(
+            restored
+                .get_session_data(&second.session_id, "theme")
+                .await
+                .unwrap(),
+            serde_json::json!("dark")
+        );
+        assert_eq!(restored.get_client_sessions("client2").await.len(), 1);
+        assert_eq!(
+            restored.get_statistics().await.total_session_data_size,
+            manager.get_statistics().await.total_session_data_size
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_skips_expired_sessions() {
+        let manager = SessionManager::new(SessionConfig::default());
+        let session = manager
+            .create_session_simple("client1".to_string())
+            .await
+            .unwrap();
+        let mut snapshot = manager.snapshot().await;
+        snapshot.sessions[0].info.last_activity = SystemTime::now() - Duration::from_secs(7200);
+
+        let restored = SessionManager::new(SessionConfig::default());
+        assert_eq!(restored.restore(snapshot).await.unwrap(), 0);
+        assert!(!restored.session_exists(&session.session_id).await);
+    }
 }
@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 use std::convert::Infallible;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use axum::{
     Json, Router,
@@ -124,6 +124,8 @@ pub struct SseServerState {
     pub broadcaster: broadcast::Sender<SseMessage>,
     /// Connection cleanup interval
     pub cleanup_interval: Duration,
+    /// When the state was created, for reporting uptime
+    pub started_at: Instant,
 }
 
 /// Query parameters for SSE endpoint
@@ -188,6 +190,7 @@ impl SseServerState {
             connections: Arc::new(RwLock::new(HashMap::new())),
             broadcaster,
             cleanup_interval: Duration::from_secs(300), // 5 minutes
+            started_at: Instant::now(),
         }
     }
 
@@ -464,7 +467,7 @@ pub async fn health_handler(State(state): State<Arc<SseServerState>>) -> Json<He
         transport: "sse".to_string(),
         active_connections,
         active_sessions,
-        uptime_seconds: 0, // Would need to track server start time
+        uptime_seconds: state.started_at.elapsed().as_secs(),
     })
 }
 
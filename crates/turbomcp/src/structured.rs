@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::marker::PhantomData;
+use turbomcp_protocol::types::ToolOutputSchema;
 
 /// Wrapper type for structured JSON output with automatic schema generation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +79,103 @@ pub fn generate_json_schema<T>() -> serde_json::Value {
     })
 }
 
+/// Output schema for a tool returning `T`, as `#[tool]` advertises it
+///
+/// Returns `None` unless `T` serializes to an object, the only shape MCP
+/// allows for structured content. Definitions the schema refers to are
+/// written out in place; recursive types cannot be, and get no schema.
+#[cfg(feature = "schema-generation")]
+#[must_use]
+pub fn output_schema_for<T: schemars::JsonSchema>() -> Option<ToolOutputSchema> {
+    let root = schemars::schema_for!(T);
+    let mut schema = serde_json::to_value(&root.schema).ok()?;
+    let definitions = serde_json::to_value(&root.definitions).ok()?;
+    let definitions = definitions.as_object()?;
+    if !inline_definitions(&mut schema, definitions, &mut Vec::new()) {
+        return None;
+    }
+    if schema.get("type").and_then(serde_json::Value::as_str) != Some("object") {
+        return None;
+    }
+    serde_json::from_value(schema).ok()
+}
+
+/// Replace each `#/definitions/...` reference within `schema` with its
+/// definition, returning `false` for references that are unknown or recur
+/// within their own definition
+#[cfg(feature = "schema-generation")]
+fn inline_definitions(
+    schema: &mut serde_json::Value,
+    definitions: &serde_json::Map<String, serde_json::Value>,
+    expanding: &mut Vec<String>,
+) -> bool {
+    let reference = schema
+        .get("$ref")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+        .map(str::to_string);
+    if let Some(name) = reference {
+        let Some(definition) = definitions.get(&name) else {
+            return false;
+        };
+        if expanding.contains(&name) {
+            return false;
+        }
+        *schema = definition.clone();
+        expanding.push(name);
+        let inlined = inline_definitions(schema, definitions, expanding);
+        expanding.pop();
+        return inlined;
+    }
+    match schema {
+        serde_json::Value::Object(map) => map
+            .values_mut()
+            .all(|value| inline_definitions(value, definitions, expanding)),
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .all(|value| inline_definitions(value, definitions, expanding)),
+        _ => true,
+    }
+}
+
+/// Type marker `#[tool]` uses to find a return type's output schema
+///
+/// The macro expands to `(&&OutputSchemaProbe::<T>(PhantomData)).output_schema()`
+/// with [`JsonSchemaOutput`] and [`NoOutputSchema`] in scope. Method
+/// resolution tries the `&OutputSchemaProbe<T>` impl first, which applies
+/// only when `T` implements `JsonSchema`, and otherwise settles on the
+/// fallback, so tools returning types that only implement `Serialize`
+/// still compile.
+#[doc(hidden)]
+pub struct OutputSchemaProbe<T>(pub PhantomData<T>);
+
+/// Output schema of a probed type that implements `JsonSchema`
+#[doc(hidden)]
+pub trait JsonSchemaOutput {
+    /// The schema from [`output_schema_for`]
+    fn output_schema(&self) -> Option<ToolOutputSchema>;
+}
+
+#[cfg(feature = "schema-generation")]
+impl<T: schemars::JsonSchema> JsonSchemaOutput for &OutputSchemaProbe<T> {
+    fn output_schema(&self) -> Option<ToolOutputSchema> {
+        output_schema_for::<T>()
+    }
+}
+
+/// Output schema of a probed type without a `JsonSchema` implementation
+#[doc(hidden)]
+pub trait NoOutputSchema {
+    /// Always `None`
+    fn output_schema(&self) -> Option<ToolOutputSchema>;
+}
+
+impl<T> NoOutputSchema for OutputSchemaProbe<T> {
+    fn output_schema(&self) -> Option<ToolOutputSchema> {
+        None
+    }
+}
+
 /// Trait for types that can be converted to structured output
 pub trait ToStructuredOutput {
     /// Convert to a structured output with optional schema
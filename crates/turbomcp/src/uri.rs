@@ -1,73 +1,49 @@
 //! URI template matching and parameter extraction
+//!
+//! Templates are read by [`turbomcp_protocol::template`], the same as the
+//! server's resource routing, so a template matches here exactly the URIs
+//! it is served at.
 
-#[cfg(feature = "uri-templates")]
-use once_cell::sync::Lazy;
-#[cfg(feature = "uri-templates")]
-use regex::Regex;
 use std::collections::HashMap;
+use turbomcp_protocol::template;
 
 use crate::{McpError, McpResult};
 
+/// Most parameters a URI template may declare
+///
+/// Far more than any real resource URI needs, while keeping matching cheap.
+pub const MAX_TEMPLATE_PARAMETERS: usize = 64;
+
 /// URI template matcher
 pub struct UriTemplate {
-    pattern: Regex,
-    parameter_names: Vec<String>,
+    template: String,
 }
 
 impl UriTemplate {
     /// Create a new URI template
     pub fn new(template: &str) -> McpResult<Self> {
-        // Convert template like "config://settings/{section}" to regex
-        let mut parameter_names = Vec::new();
-        let mut regex_pattern = template.to_string();
-
-        // Find parameter placeholders
-        static PARAM_REGEX: Lazy<Regex> =
-            Lazy::new(|| Regex::new(r"\{([^}]+)\}").expect("URI parameter regex should be valid"));
-
-        for cap in PARAM_REGEX.captures_iter(template) {
-            if let Some(param_name) = cap.get(1) {
-                parameter_names.push(param_name.as_str().to_string());
-                // Replace {param} with named capture group (require non-empty match)
-                regex_pattern = regex_pattern.replace(
-                    &format!("{{{}}}", param_name.as_str()),
-                    &format!("(?P<{}>.+?)", param_name.as_str()),
-                );
-            }
+        let parameters = template::placeholders(template)
+            .map_err(|e| McpError::Resource(format!("Invalid URI template: {e}")))?;
+        if parameters.len() > MAX_TEMPLATE_PARAMETERS {
+            return Err(McpError::Resource(format!(
+                "Invalid URI template: more than {MAX_TEMPLATE_PARAMETERS} parameters"
+            )));
         }
-
-        // Escape other regex special characters but preserve our named groups
-        let pattern = Regex::new(&format!("^{regex_pattern}$"))
-            .map_err(|e| McpError::Resource(format!("Invalid URI template regex: {e}")))?;
-
         Ok(Self {
-            pattern,
-            parameter_names,
+            template: template.to_string(),
         })
     }
 
     /// Match a URI against this template and extract parameters
     #[must_use]
     pub fn matches(&self, uri: &str) -> Option<HashMap<String, String>> {
-        if let Some(captures) = self.pattern.captures(uri) {
-            let mut params = HashMap::new();
-
-            for param_name in &self.parameter_names {
-                if let Some(value) = captures.name(param_name) {
-                    params.insert(param_name.clone(), value.as_str().to_string());
-                }
-            }
-
-            Some(params)
-        } else {
-            None
-        }
+        template::match_uri(&self.template, uri)
     }
 
     /// Check if URI matches template (without extracting parameters)
     #[must_use]
     pub fn is_match(&self, uri: &str) -> bool {
-        self.pattern.is_match(uri)
+        self.matches(uri).is_some()
     }
 }
 
@@ -93,4 +69,14 @@ mod tests {
         assert_eq!(params.get("version"), Some(&"1".to_string()));
         assert_eq!(params.get("id"), Some(&"123".to_string()));
     }
+
+    #[test]
+    fn test_uri_template_rejects_pathological_templates() {
+        let nested = UriTemplate::new("api://{a{b{c}}}").err().unwrap();
+        assert!(nested.to_string().contains("nested '{'"));
+
+        let many: String = (0..10_000).map(|i| format!("/{{p{i}}}")).collect();
+        let too_many = UriTemplate::new(&format!("api://{many}")).err().unwrap();
+        assert!(too_many.to_string().contains("more than 64 parameters"));
+    }
 }
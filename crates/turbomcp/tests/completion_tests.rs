@@ -0,0 +1,105 @@
+//! End-to-end tests for `#[completion]` handlers and `completion/complete`
+
+#[path = "../../turbomcp-server/tests/common/channel.rs"]
+mod channel;
+
+use channel::ChannelTransport;
+use std::sync::Arc;
+use turbomcp::{CompletionReference, McpResult};
+use turbomcp_client::Client;
+use turbomcp_macros::{completion, server, tool};
+
+const LANGUAGES: &[&str] = &["english", "french", "frisian", "german"];
+
+#[derive(Clone)]
+struct Library;
+
+#[server(name = "Library", version = "1.0.0")]
+impl Library {
+    #[tool("Translate a book")]
+    async fn translate(&self, isbn: String, language: String) -> McpResult<String> {
+        Ok(format!("{isbn} in {language}"))
+    }
+
+    #[completion(prompt = "translate", argument = "language")]
+    async fn complete_language(
+        &self,
+        _reference: CompletionReference,
+        value: String,
+    ) -> McpResult<Vec<String>> {
+        Ok(LANGUAGES
+            .iter()
+            .filter(|language| language.starts_with(&value))
+            .map(|language| (*language).to_string())
+            .collect())
+    }
+
+    #[completion(resource = "books://{isbn}", argument = "isbn")]
+    async fn complete_isbn(
+        &self,
+        _reference: CompletionReference,
+        value: String,
+    ) -> McpResult<Vec<String>> {
+        Ok((0..150).map(|n| format!("{value}{n:03}")).collect())
+    }
+}
+
+/// Serve a fresh `Library` and return an initialized client connected to it
+async fn connect() -> Client<ChannelTransport> {
+    let (server, _shutdown) = Library.into_server_with_shutdown().unwrap();
+    let server = Arc::new(server);
+    let (client_transport, server_transport) = ChannelTransport::pair();
+    tokio::spawn(async move { server.run_with_transport(server_transport).await });
+
+    let mut client = Client::new(client_transport);
+    let init = client.initialize().await.unwrap();
+    assert!(init.server_capabilities.completions.is_some());
+    client
+}
+
+#[tokio::test]
+async fn test_client_receives_prompt_completions() {
+    let mut client = connect().await;
+    let reference = CompletionReference::Prompt {
+        name: "translate".to_string(),
+    };
+
+    let completion = client
+        .complete(reference.clone(), "language", "fr")
+        .await
+        .unwrap();
+    assert_eq!(completion.values, ["french", "frisian"]);
+    assert_eq!(completion.total, Some(2));
+    assert_eq!(completion.has_more, Some(false));
+
+    let completion = client.complete(reference, "language", "x").await.unwrap();
+    assert!(completion.values.is_empty());
+    assert_eq!(completion.total, Some(0));
+}
+
+#[tokio::test]
+async fn test_long_candidate_lists_are_cut_with_has_more() {
+    let mut client = connect().await;
+    let reference = CompletionReference::Resource {
+        uri: "books://{isbn}".to_string(),
+    };
+
+    let completion = client.complete(reference, "isbn", "978").await.unwrap();
+    assert_eq!(completion.values.len(), 100);
+    assert_eq!(completion.values[0], "978000");
+    assert_eq!(completion.total, Some(150));
+    assert_eq!(completion.has_more, Some(true));
+}
+
+#[tokio::test]
+async fn test_arguments_without_a_handler_have_no_suggestions() {
+    let mut client = connect().await;
+
+    // Right argument name, but of another prompt
+    let reference = CompletionReference::Prompt {
+        name: "summarize".to_string(),
+    };
+    let completion = client.complete(reference, "language", "fr").await.unwrap();
+    assert!(completion.values.is_empty());
+    assert_eq!(completion.has_more, Some(false));
+}
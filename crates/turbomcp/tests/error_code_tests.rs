@@ -0,0 +1,94 @@
+//! Tests for the JSON-RPC codes handler errors are reported with
+
+use serde_json::json;
+use turbomcp::{JsonRpcRequest, McpError, McpResult, RequestContext, ServerError};
+use turbomcp_macros::{server, tool};
+
+#[test]
+fn test_each_variant_maps_to_its_code() {
+    let cases = [
+        (McpError::Protocol("bad frame".into()), -32600),
+        (McpError::InvalidRequest("no id".into()), -32600),
+        (McpError::Tool("crashed".into()), -32002),
+        (McpError::Resource("not found".into()), -32004),
+        (McpError::Prompt("not found".into()), -32003),
+        (McpError::Unauthorized("no token".into()), -32008),
+        (McpError::InvalidInput("negative".into()), -32602),
+        (McpError::Context("missing".into()), -32603),
+        (McpError::Network("unreachable".into()), -32603),
+        (McpError::Schema("bad type".into()), -32603),
+        (McpError::Transport("closed".into()), -32603),
+        (McpError::Internal("bug".into()), -32603),
+        (
+            McpError::Serialization(serde_json::from_str::<i32>("x").unwrap_err()),
+            -32603,
+        ),
+        (McpError::Server(ServerError::not_found("Tool 'x'")), -32004),
+        (
+            McpError::Server(ServerError::authorization("denied")),
+            -32005,
+        ),
+    ];
+    for (error, code) in cases {
+        assert_eq!(error.error_code(), code, "{error}");
+        let wire = error.to_json_rpc_error();
+        assert_eq!(wire.code, code);
+        assert_eq!(wire.message, error.to_string());
+    }
+}
+
+#[test]
+fn test_server_errors_keep_their_data() {
+    let error = McpError::Server(ServerError::invalid_argument(
+        "count",
+        "an integer",
+        "got a string",
+    ));
+    assert_eq!(error.error_code(), -32602);
+    assert_eq!(error.to_json_rpc_error().data.unwrap()["argument"], "count");
+}
+
+#[test]
+fn test_conversion_to_server_error_keeps_code() {
+    let server_error = ServerError::from(McpError::Resource("not found".into()));
+    assert_eq!(server_error.error_code(), -32004);
+    assert_eq!(server_error.to_string(), "Resource error: not found");
+}
+
+#[derive(Clone)]
+struct Vault;
+
+#[server(name = "Vault", version = "1.0.0")]
+impl Vault {
+    #[tool("Open a secret")]
+    async fn open(&self, name: String) -> McpResult<String> {
+        match name.as_str() {
+            "public" => Ok("hello".to_string()),
+            "locked" => Err(McpError::Unauthorized("a key is required".to_string())),
+            _ => Err(McpError::Resource(format!("no secret named '{name}'"))),
+        }
+    }
+}
+
+fn open_request(name: &str) -> JsonRpcRequest {
+    serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "open", "arguments": {"name": name}}
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_tool_errors_reach_the_wire_with_their_codes() {
+    let (server, _shutdown) = Vault.into_server_with_shutdown().unwrap();
+    for (name, code) in [("locked", -32008), ("missing", -32004)] {
+        let response = server
+            .router()
+            .route(open_request(name), RequestContext::new())
+            .await;
+        let error = response.error.expect("the tool should fail");
+        assert_eq!(error.code, code, "{}", error.message);
+    }
+}
@@ -88,20 +88,17 @@ async fn test_uri_templates_comprehensive() {
         .unwrap();
     assert!(no_params.is_empty());
 
-    // Test edge cases
+    // Test edge cases: a parameter spans one path segment, as in resource routing
     let edge_template = UriTemplate::new("file:///{path}").unwrap();
+    assert!(edge_template.matches("file:///file.txt").is_some());
     assert!(
         edge_template
             .matches("file:///home/user/file.txt")
-            .is_some()
+            .is_none()
     );
-    assert!(edge_template.matches("file:///var/log/app.log").is_some());
 
-    let edge_params = edge_template.matches("file:///home/user/file.txt").unwrap();
-    assert_eq!(
-        edge_params.get("path"),
-        Some(&"home/user/file.txt".to_string())
-    );
+    let edge_params = edge_template.matches("file:///file.txt").unwrap();
+    assert_eq!(edge_params.get("path"), Some(&"file.txt".to_string()));
 
     // Test parameter extraction function directly using UriTemplate
     let direct_template = UriTemplate::new("config://settings/{section}").unwrap();
@@ -136,9 +133,9 @@ async fn test_uri_templates_comprehensive() {
 #[cfg(feature = "uri-templates")]
 #[tokio::test]
 async fn test_uri_template_error_cases() {
-    // Test invalid regex patterns (these should fail with proper error)
-    let result = UriTemplate::new("invalid[regex");
-    // URI template creation should fail for invalid regex patterns
+    // Test malformed templates (these should fail with proper error)
+    let result = UriTemplate::new("invalid{template");
+    // URI template creation should fail for an unclosed parameter
     assert!(result.is_err());
 
     // Test empty template
@@ -445,7 +442,7 @@ async fn test_real_world_scenarios() {
         permissions: String,
     }
 
-    let file_template = UriTemplate::new("file://{path}").unwrap();
+    let file_template = UriTemplate::new("file:///home/user/{path}").unwrap();
 
     // Test actual macro-generated schema instead of schemars
     #[derive(Clone)]
@@ -473,6 +473,62 @@ async fn test_error_handling() {
     assert!(result.is_ok());
 }
 
+/// Arguments of the wrong type are reported as invalid params naming the argument
+#[tokio::test]
+async fn test_wrong_argument_type_is_invalid_params() {
+    let error = ErrorHandlingServer
+        .test_tool_call(
+            "validate_range",
+            json!({"value": "five", "min": 0, "max": 10}),
+        )
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.error_code(), -32602);
+    assert_eq!(
+        error.to_string(),
+        "Invalid params: argument 'value' must be i32: \
+         invalid type: string \"five\", expected i32"
+    );
+    assert_eq!(
+        error.error_data(),
+        Some(json!({
+            "argument": "value",
+            "expected": "i32",
+            "reason": "invalid type: string \"five\", expected i32",
+        }))
+    );
+}
+
+/// A `tools/call` routed by the server checks arguments against the input
+/// schema first, and reports the mismatch the same way
+#[tokio::test]
+async fn test_wrong_argument_type_through_the_router_is_invalid_params() {
+    let (server, _shutdown) = ErrorHandlingServer.into_server_with_shutdown().unwrap();
+    let request: turbomcp::JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {
+            "name": "validate_range",
+            "arguments": {"value": "five", "min": 0, "max": 10}
+        }
+    }))
+    .unwrap();
+    let response = server
+        .router()
+        .route(request, turbomcp::RequestContext::new())
+        .await;
+
+    let error = response.error.expect("wrong-typed argument is rejected");
+    assert_eq!(error.code, -32602);
+    let data = error.data.expect("error names the argument");
+    assert_eq!(data["argument"], "value");
+    assert_eq!(data["expected"], "integer");
+    let reason = data["reason"].as_str().unwrap();
+    assert!(reason.contains("\"five\" is not of type"), "{reason}");
+}
+
 // =============================================================================
 // CRITICAL TEST 5: Regression Test for Schema Bug
 // =============================================================================
@@ -0,0 +1,132 @@
+//! Tests for `Context` logging sent to the client as `notifications/message`
+
+use serde_json::{Value, json};
+use tokio::sync::mpsc;
+use turbomcp::{Context, JsonRpcRequest, LogLevel, McpResult, RequestContext};
+use turbomcp_macros::{server, tool};
+
+#[derive(Clone)]
+struct Importer;
+
+#[server(name = "Importer", version = "1.0.0")]
+impl Importer {
+    #[tool("Import a batch of records")]
+    async fn import(&self, ctx: Context) -> McpResult<String> {
+        ctx.info("importing 3 records").await?;
+        ctx.warn("record 2 has no id").await?;
+        ctx.error("record 3 is malformed").await?;
+        Ok("imported 1 record".to_string())
+    }
+}
+
+fn request(method: &str, params: Value) -> JsonRpcRequest {
+    serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params
+    }))
+    .unwrap()
+}
+
+/// Call `import` in `session` and collect the log messages it sends the client
+async fn import_logs(server: &turbomcp::Server, session: &str) -> Vec<Value> {
+    let (notifier, mut notifications) = mpsc::unbounded_channel();
+    let ctx = RequestContext::new()
+        .with_session_id(session)
+        .with_notifier(notifier);
+    let response = server
+        .router()
+        .route(
+            request("tools/call", json!({"name": "import", "arguments": {}})),
+            ctx,
+        )
+        .await;
+    assert!(response.error.is_none());
+
+    let mut logs = Vec::new();
+    while let Ok(notification) = notifications.try_recv() {
+        assert_eq!(notification["method"], "notifications/message");
+        logs.push(notification["params"].clone());
+    }
+    logs
+}
+
+async fn set_level(server: &turbomcp::Server, session: &str, level: &str) {
+    let response = server
+        .router()
+        .route(
+            request("logging/setLevel", json!({"level": level})),
+            RequestContext::new().with_session_id(session),
+        )
+        .await;
+    assert!(response.error.is_none(), "{:?}", response.error);
+}
+
+#[tokio::test]
+async fn test_messages_below_the_level_are_suppressed() {
+    let (server, _shutdown) = Importer.into_server_with_shutdown().unwrap();
+    set_level(&server, "a", "warning").await;
+    let session = RequestContext::new().with_session_id("a");
+    assert_eq!(server.router().log_level(&session), LogLevel::Warning);
+
+    let logs = import_logs(&server, "a").await;
+    assert_eq!(
+        logs,
+        [
+            json!({"level": "warning", "data": "record 2 has no id", "logger": "import"}),
+            json!({"level": "error", "data": "record 3 is malformed", "logger": "import"}),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_lowering_the_level_delivers_more() {
+    let (server, _shutdown) = Importer.into_server_with_shutdown().unwrap();
+    set_level(&server, "a", "error").await;
+    assert_eq!(import_logs(&server, "a").await.len(), 1);
+
+    set_level(&server, "a", "debug").await;
+    let logs = import_logs(&server, "a").await;
+    assert_eq!(logs.len(), 3);
+    assert_eq!(logs[0]["level"], "info");
+    assert_eq!(logs[0]["data"], "importing 3 records");
+}
+
+#[tokio::test]
+async fn test_info_and_above_are_sent_until_the_client_sets_a_level() {
+    let (server, _shutdown) = Importer.into_server_with_shutdown().unwrap();
+    let logs = import_logs(&server, "a").await;
+    assert_eq!(logs.len(), 3);
+    assert_eq!(logs[0]["level"], "info");
+}
+
+#[tokio::test]
+async fn test_each_session_keeps_its_own_level() {
+    let (server, _shutdown) = Importer.into_server_with_shutdown().unwrap();
+    set_level(&server, "quiet", "error").await;
+
+    assert_eq!(import_logs(&server, "quiet").await.len(), 1);
+    assert_eq!(import_logs(&server, "chatty").await.len(), 3);
+}
+
+#[tokio::test]
+async fn test_logging_capability_is_advertised() {
+    let (server, _shutdown) = Importer.into_server_with_shutdown().unwrap();
+    let response = server
+        .router()
+        .route(
+            request(
+                "initialize",
+                json!({
+                    "protocolVersion": "2025-06-18",
+                    "capabilities": {},
+                    "clientInfo": {"name": "test", "version": "1.0.0"}
+                }),
+            ),
+            RequestContext::new(),
+        )
+        .await;
+    let result = response.result.unwrap();
+    assert_eq!(result["capabilities"]["logging"], json!({}));
+}
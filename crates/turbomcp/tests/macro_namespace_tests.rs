@@ -0,0 +1,100 @@
+//! Tests for `#[server(namespace = ...)]` prefixing tool names
+
+use serde_json::{Value, json};
+use turbomcp::{JsonRpcRequest, RequestContext};
+use turbomcp_macros::{server, tool};
+
+#[derive(Clone)]
+struct FileTools;
+
+#[server(name = "Files", namespace = "fs")]
+impl FileTools {
+    #[tool("Read a file")]
+    async fn read(&self, path: String) -> turbomcp::McpResult<String> {
+        Ok(format!("contents of {path}"))
+    }
+
+    #[tool("Write a file")]
+    async fn write(&self, path: String, text: String) -> turbomcp::McpResult<String> {
+        Ok(format!("wrote {} bytes to {path}", text.len()))
+    }
+}
+
+#[derive(Clone)]
+struct FlatTools;
+
+#[server(name = "Flat")]
+impl FlatTools {
+    #[tool("Read a file")]
+    async fn read(&self, path: String) -> turbomcp::McpResult<String> {
+        Ok(path)
+    }
+}
+
+async fn route(server: &turbomcp::Server, method: &str, params: Value) -> Value {
+    let request: JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params
+    }))
+    .unwrap();
+    let response = server.router().route(request, RequestContext::new()).await;
+    serde_json::to_value(response).unwrap()
+}
+
+#[test]
+fn test_namespace_prefixes_discovered_tools() {
+    assert_eq!(FileTools::tool_namespace(), Some("fs"));
+    let mut names: Vec<String> = FileTools::get_tools_metadata()
+        .into_iter()
+        .map(|(name, _, _)| name)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["fs.read", "fs.write"]);
+
+    // Flat naming stays the default
+    assert_eq!(FlatTools::tool_namespace(), None);
+    assert_eq!(FlatTools::get_tools_metadata()[0].0, "read");
+}
+
+#[tokio::test]
+async fn test_namespaced_tools_route_by_prefixed_name() {
+    let (server, _shutdown) = FileTools.into_server_with_shutdown().unwrap();
+
+    let listed = route(&server, "tools/list", json!({})).await;
+    let mut names: Vec<&str> = listed["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, ["fs.read", "fs.write"]);
+
+    let called = route(
+        &server,
+        "tools/call",
+        json!({"name": "fs.read", "arguments": {"path": "/etc/motd"}}),
+    )
+    .await;
+    assert_eq!(
+        called["result"]["content"][0]["text"],
+        "contents of /etc/motd"
+    );
+
+    // The bare method name is not a tool
+    let bare = route(
+        &server,
+        "tools/call",
+        json!({"name": "read", "arguments": {"path": "/etc/motd"}}),
+    )
+    .await;
+    assert!(bare.get("error").is_some());
+
+    let direct = FileTools
+        .test_tool_call("fs.write", json!({"path": "a.txt", "text": "hi"}))
+        .await
+        .unwrap();
+    assert!(!direct.content.is_empty());
+}
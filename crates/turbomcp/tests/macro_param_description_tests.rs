@@ -0,0 +1,87 @@
+//! Tests for parameter descriptions in `#[tool]` input schemas
+
+use serde_json::json;
+use turbomcp_macros::{server, tool};
+
+#[derive(Clone)]
+struct Catalogue;
+
+#[server(name = "Catalogue", version = "1.0.0")]
+impl Catalogue {
+    #[tool("Search the catalogue")]
+    async fn search(
+        &self,
+        /// Words to look for in titles
+        /// and descriptions
+        ///
+        /// Matching ignores case.
+        query: String,
+        /// Most results to return
+        limit: Option<u32>,
+        #[param(description = "Only items in this section")] section: Option<String>,
+        /// Overridden by the attribute
+        #[param(description = "Sort order, `title` or `date`")]
+        order: String,
+        exact: bool,
+    ) -> turbomcp::McpResult<String> {
+        Ok(format!("{query} {limit:?} {section:?} {order} {exact}"))
+    }
+}
+
+#[test]
+fn test_doc_comments_describe_parameters() {
+    let (_, _, schema) = Catalogue::search_metadata();
+    let properties = &schema["properties"];
+
+    assert_eq!(
+        properties["query"]["description"],
+        "Words to look for in titles and descriptions\n\nMatching ignores case."
+    );
+    assert_eq!(properties["query"]["type"], "string");
+
+    // Optional parameters are described on their inner type's schema
+    assert_eq!(properties["limit"]["description"], "Most results to return");
+    assert_eq!(properties["limit"]["type"], "integer");
+}
+
+#[test]
+fn test_param_attribute_describes_parameters() {
+    let (_, _, schema) = Catalogue::search_metadata();
+    let properties = &schema["properties"];
+
+    assert_eq!(
+        properties["section"]["description"],
+        "Only items in this section"
+    );
+    assert_eq!(
+        properties["order"]["description"],
+        "Sort order, `title` or `date`"
+    );
+}
+
+#[test]
+fn test_undocumented_parameters_have_no_description() {
+    let (_, _, schema) = Catalogue::search_metadata();
+    assert_eq!(schema["properties"]["exact"], json!({"type": "boolean"}));
+
+    let mut required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|name| name.as_str().unwrap())
+        .collect();
+    required.sort_unstable();
+    assert_eq!(required, ["exact", "order", "query"]);
+}
+
+#[tokio::test]
+async fn test_documented_tool_still_runs() {
+    let result = Catalogue
+        .test_tool_call(
+            "search",
+            json!({"query": "rust", "order": "title", "exact": true}),
+        )
+        .await
+        .unwrap();
+    assert!(!result.content.is_empty());
+}
@@ -1,6 +1,7 @@
 //! Simple test to verify macro basics work
 
-use turbomcp_macros::{server, tool};
+use turbomcp::handlers::ToolHandler;
+use turbomcp_macros::{prompt, server, tool};
 
 #[derive(Clone)]
 struct SimpleServer;
@@ -26,3 +27,186 @@ async fn test_tool_method_exists() {
     let result = server.test_tool().await;
     assert_eq!(result.unwrap(), "test");
 }
+
+#[derive(Clone)]
+struct CachedServer;
+
+#[server(name = "Cached", version = "1.0.0")]
+impl CachedServer {
+    #[tool("Square a number, exactly", cacheable, ttl = 60)]
+    async fn square(&self, x: i64) -> turbomcp::McpResult<i64> {
+        Ok(x * x)
+    }
+}
+
+#[test]
+fn test_cacheable_tool_keeps_description() {
+    let (name, description, _schema) = CachedServer::square_metadata();
+    assert_eq!(name, "square");
+    assert_eq!(description, "Square a number, exactly");
+}
+
+#[derive(Clone)]
+struct LimitedServer;
+
+#[server(name = "Limited", version = "1.0.0")]
+impl LimitedServer {
+    #[tool("Echo short text", max_input = 4096)]
+    async fn echo(&self, text: String) -> turbomcp::McpResult<String> {
+        Ok(text)
+    }
+}
+
+#[test]
+fn test_max_input_tool_keeps_description() {
+    let (name, description, _schema) = LimitedServer::echo_metadata();
+    assert_eq!(name, "echo");
+    assert_eq!(description, "Echo short text");
+}
+
+#[derive(Clone)]
+struct BoundedServer;
+
+#[server(name = "Bounded", version = "1.0.0")]
+impl BoundedServer {
+    #[tool("Dump a log file", max_output = 8192, on_oversized_output = "reject")]
+    async fn dump(&self, path: String) -> turbomcp::McpResult<String> {
+        Ok(path)
+    }
+}
+
+#[test]
+fn test_max_output_tool_registers_limit() {
+    let (server, _shutdown) = BoundedServer.into_server_with_shutdown().unwrap();
+    let handler = server.registry().get_tool("dump").unwrap();
+    assert_eq!(
+        handler.output_limit(),
+        Some(turbomcp::ToolOutputLimit::reject(8192))
+    );
+}
+
+#[derive(Clone)]
+struct GuardedServer;
+
+#[server(name = "Guarded", version = "1.0.0")]
+impl GuardedServer {
+    #[tool("Query a flaky backend", circuit_breaker)]
+    async fn query(&self, key: String) -> turbomcp::McpResult<String> {
+        Ok(key)
+    }
+}
+
+#[test]
+fn test_circuit_breaker_tool_registers_breaker() {
+    let (name, description, _schema) = GuardedServer::query_metadata();
+    assert_eq!(name, "query");
+    assert_eq!(description, "Query a flaky backend");
+
+    let (server, _shutdown) = GuardedServer.into_server_with_shutdown().unwrap();
+    let handler = server.registry().get_tool("query").unwrap();
+    assert!(handler.circuit_breaker().is_some());
+}
+
+#[derive(Clone, Default)]
+struct WarmServer {
+    loaded: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[server(name = "Warm", version = "1.0.0")]
+impl WarmServer {
+    async fn load_model(&self) -> turbomcp::McpResult<()> {
+        self.loaded.store(true, std::sync::atomic::Ordering::SeqCst);
+        Ok(())
+    }
+
+    #[tool("Classify text", prewarm = "load_model")]
+    async fn classify(&self, text: String) -> turbomcp::McpResult<String> {
+        Ok(text)
+    }
+}
+
+#[tokio::test]
+async fn test_prewarm_tool_runs_hook_at_boot() {
+    let warm = WarmServer::default();
+    let loaded = std::sync::Arc::clone(&warm.loaded);
+
+    let (server, _shutdown) = warm.into_server_with_shutdown().unwrap();
+    assert!(!loaded.load(std::sync::atomic::Ordering::SeqCst));
+
+    let report = server.prewarm().await.unwrap();
+    assert_eq!(report.warmed, 1);
+    assert!(loaded.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[derive(Clone)]
+struct ScreenshotServer;
+
+#[server(name = "Screenshots", version = "1.0.0")]
+impl ScreenshotServer {
+    #[tool("Take a screenshot")]
+    async fn capture(&self) -> turbomcp::McpResult<String> {
+        Ok("aGVsbG8=".to_string())
+    }
+
+    #[prompt("Ask what a screenshot shows")]
+    async fn explain(&self, png: String) -> turbomcp::McpResult<Vec<turbomcp::Content>> {
+        Ok(vec![
+            turbomcp::image(png, "image/png"),
+            turbomcp::text("What does this screenshot show?"),
+        ])
+    }
+}
+
+#[tokio::test]
+async fn test_prompt_returns_image_content_intact() {
+    use turbomcp::IntoPromptResult;
+
+    let content = ScreenshotServer.explain("aGVsbG8=".to_string()).await;
+    let prompt = content.unwrap().into_prompt_result();
+    assert_eq!(prompt.messages.len(), 2);
+    let turbomcp::Content::Image(image) = &prompt.messages[0].content else {
+        panic!("image content was flattened: {:?}", prompt.messages[0]);
+    };
+    assert_eq!(image.data, "aGVsbG8=");
+    assert_eq!(image.mime_type, "image/png");
+    assert!(matches!(
+        prompt.messages[1].content,
+        turbomcp::Content::Text(_)
+    ));
+}
+
+#[derive(Clone)]
+struct ExampleServer;
+
+#[server(name = "Examples", version = "1.0.0")]
+impl ExampleServer {
+    #[tool(
+        "Convert an amount between currencies",
+        example = r#"{"amount": 12.5, "from": "EUR", "to": "USD"}"#,
+        example = "{\"amount\": 100, \"from\": \"JPY\", \"to\": \"GBP\"}"
+    )]
+    async fn convert(&self, amount: f64, from: String, to: String) -> turbomcp::McpResult<String> {
+        Ok(format!("{amount} {from} in {to}"))
+    }
+}
+
+#[test]
+fn test_tool_examples_appear_in_input_schema() {
+    let (_name, description, schema) = ExampleServer::convert_metadata();
+    assert_eq!(description, "Convert an amount between currencies");
+    let expected = vec![
+        serde_json::json!({"amount": 12.5, "from": "EUR", "to": "USD"}),
+        serde_json::json!({"amount": 100, "from": "JPY", "to": "GBP"}),
+    ];
+    assert_eq!(schema["examples"], serde_json::json!(expected));
+
+    let (server, _shutdown) = ExampleServer.into_server_with_shutdown().unwrap();
+    let tool = server
+        .registry()
+        .get_tool("convert")
+        .unwrap()
+        .tool_definition();
+    assert_eq!(tool.input_schema.examples, Some(expected));
+    let listed = serde_json::to_value(&tool).unwrap();
+    assert_eq!(listed["inputSchema"]["examples"][1]["from"], "JPY");
+}
@@ -0,0 +1,167 @@
+//! Tests for the output schemas `#[tool]` derives from return types
+#![cfg(feature = "schema-generation")]
+
+use serde::Serialize;
+use serde_json::{Value, json};
+use turbomcp::{JsonRpcRequest, McpResult, RequestContext, output_schema_for};
+use turbomcp_macros::{server, tool};
+
+#[derive(Serialize, schemars::JsonSchema)]
+struct Location {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Serialize, schemars::JsonSchema)]
+struct Forecast {
+    city: String,
+    celsius: f64,
+    location: Location,
+    note: Option<String>,
+}
+
+/// Serializable, but without a schema to advertise
+#[derive(Serialize)]
+struct Reading {
+    celsius: f64,
+}
+
+#[derive(Clone)]
+struct Weather;
+
+#[server(name = "Weather", version = "1.0.0")]
+impl Weather {
+    #[tool(
+        "Forecast the weather",
+        output_example = r#"{"city": "Oslo", "celsius": -3.0, "location": {"lat": 59.9, "lon": 10.7}}"#
+    )]
+    async fn forecast(&self, city: String) -> McpResult<Forecast> {
+        Ok(Forecast {
+            city,
+            celsius: 21.5,
+            location: Location { lat: 1.0, lon: 2.0 },
+            note: None,
+        })
+    }
+
+    #[tool("Read the thermometer")]
+    async fn read(&self) -> McpResult<Reading> {
+        Ok(Reading { celsius: 19.0 })
+    }
+
+    #[tool("Describe the weather")]
+    async fn describe(&self, city: String) -> McpResult<String> {
+        Ok(format!("Sunny in {city}"))
+    }
+}
+
+async fn route(server: &turbomcp::Server, method: &str, params: Value) -> Value {
+    let request: JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params
+    }))
+    .unwrap();
+    let response = server.router().route(request, RequestContext::new()).await;
+    serde_json::to_value(response).unwrap()
+}
+
+async fn listed_tool(server: &turbomcp::Server, name: &str) -> Value {
+    let listed = route(server, "tools/list", json!({})).await;
+    listed["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|tool| tool["name"] == name)
+        .cloned()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_struct_return_advertises_output_schema() {
+    let (server, _shutdown) = Weather.into_server_with_shutdown().unwrap();
+    let schema = &listed_tool(&server, "forecast").await["outputSchema"];
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["city"]["type"], "string");
+    assert_eq!(schema["properties"]["celsius"]["type"], "number");
+    let mut required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|name| name.as_str().unwrap())
+        .collect();
+    required.sort_unstable();
+    assert_eq!(required, ["celsius", "city", "location"]);
+
+    // Nested structs are written out rather than referenced
+    let location = &schema["properties"]["location"];
+    assert!(location.get("$ref").is_none(), "{location}");
+    assert_eq!(location["properties"]["lat"]["type"], "number");
+}
+
+#[tokio::test]
+async fn test_output_examples_appear_in_output_schema() {
+    let (server, _shutdown) = Weather.into_server_with_shutdown().unwrap();
+    let schema = &listed_tool(&server, "forecast").await["outputSchema"];
+    assert_eq!(
+        schema["examples"],
+        json!([{"city": "Oslo", "celsius": -3.0, "location": {"lat": 59.9, "lon": 10.7}}])
+    );
+}
+
+#[tokio::test]
+async fn test_plain_returns_have_no_output_schema() {
+    let (server, _shutdown) = Weather.into_server_with_shutdown().unwrap();
+    assert!(
+        listed_tool(&server, "describe")
+            .await
+            .get("outputSchema")
+            .is_none()
+    );
+    // A struct without a JsonSchema implementation still compiles, unadvertised
+    assert!(
+        listed_tool(&server, "read")
+            .await
+            .get("outputSchema")
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_struct_results_carry_structured_content() {
+    let (server, _shutdown) = Weather.into_server_with_shutdown().unwrap();
+    let called = route(
+        &server,
+        "tools/call",
+        json!({"name": "forecast", "arguments": {"city": "Lisbon"}}),
+    )
+    .await;
+    let result = &called["result"];
+    assert_eq!(result["structuredContent"]["city"], "Lisbon");
+    assert_eq!(result["structuredContent"]["location"]["lon"], 2.0);
+    let text: Value = serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(text, result["structuredContent"]);
+
+    let described = route(
+        &server,
+        "tools/call",
+        json!({"name": "describe", "arguments": {"city": "Lisbon"}}),
+    )
+    .await;
+    assert!(described["result"].get("structuredContent").is_none());
+}
+
+#[derive(Serialize, schemars::JsonSchema)]
+struct Node {
+    label: String,
+    children: Vec<Node>,
+}
+
+#[test]
+fn test_recursive_types_get_no_output_schema() {
+    assert!(output_schema_for::<Node>().is_none());
+    assert!(output_schema_for::<String>().is_none());
+    assert!(output_schema_for::<Location>().is_some());
+}
@@ -0,0 +1,139 @@
+//! Tests for progress reports against client-supplied progress tokens
+
+use serde_json::{Value, json};
+use tokio::sync::mpsc;
+use turbomcp::{Context, JsonRpcRequest, McpError, McpResult, ProgressStatus, RequestContext};
+use turbomcp_macros::{server, tool};
+
+#[derive(Clone)]
+struct Indexer;
+
+#[server(name = "Indexer", version = "1.0.0")]
+impl Indexer {
+    #[tool("Reindex every document")]
+    async fn reindex(&self, ctx: Context) -> McpResult<String> {
+        ctx.report_progress(1.0, Some(2.0)).await?;
+        ctx.report_progress(2.0, Some(2.0)).await?;
+        Ok("reindexed".to_string())
+    }
+
+    #[tool("Compact the index, summarizing the result")]
+    async fn compact(&self, ctx: Context) -> McpResult<String> {
+        ctx.report_progress(1.0, Some(1.0)).await?;
+        ctx.complete_progress(ProgressStatus::Success, Some("freed 3 segments"))
+            .await?;
+        // Too late: the client has been told the operation is over
+        ctx.report_progress(1.0, Some(1.0)).await?;
+        Ok("compacted".to_string())
+    }
+
+    #[tool("Verify the index")]
+    async fn verify(&self, ctx: Context) -> McpResult<String> {
+        ctx.report_progress(1.0, None).await?;
+        Err(McpError::Tool("segment 4 is corrupt".to_string()))
+    }
+}
+
+fn tool_request(name: &str, meta: Value) -> JsonRpcRequest {
+    serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": name, "arguments": {}, "_meta": meta}
+    }))
+    .unwrap()
+}
+
+fn reindex_request(meta: Value) -> JsonRpcRequest {
+    tool_request("reindex", meta)
+}
+
+#[tokio::test]
+async fn test_progress_notifications_carry_client_token() {
+    let (server, _shutdown) = Indexer.into_server_with_shutdown().unwrap();
+    let (notifier, mut notifications) = mpsc::unbounded_channel();
+    let ctx = RequestContext::new().with_notifier(notifier);
+
+    let response = server
+        .router()
+        .route(
+            reindex_request(json!({"progressToken": "reindex-7"})),
+            ctx.clone(),
+        )
+        .await;
+    assert!(response.error.is_none());
+    assert_eq!(
+        notifications.try_recv().unwrap(),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress",
+            "params": {"progressToken": "reindex-7", "progress": 1.0, "total": 2.0}
+        })
+    );
+    assert_eq!(notifications.try_recv().unwrap()["params"]["progress"], 2.0);
+    assert_eq!(
+        notifications.try_recv().unwrap(),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/progress/complete",
+            "params": {"progressToken": "reindex-7", "status": "success"}
+        })
+    );
+    assert!(notifications.try_recv().is_err());
+
+    // Integer tokens are echoed back as integers
+    server
+        .router()
+        .route(reindex_request(json!({"progressToken": 42})), ctx.clone())
+        .await;
+    for _ in 0..3 {
+        assert_eq!(
+            notifications.try_recv().unwrap()["params"]["progressToken"],
+            42
+        );
+    }
+
+    // A client that sent no token asked for no progress
+    server.router().route(reindex_request(json!({})), ctx).await;
+    assert!(notifications.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_completion_follows_final_progress_update_exactly_once() {
+    let (server, _shutdown) = Indexer.into_server_with_shutdown().unwrap();
+    let (notifier, mut notifications) = mpsc::unbounded_channel();
+    let ctx = RequestContext::new().with_notifier(notifier);
+
+    // A handler that completes its own progress is not completed twice
+    server
+        .router()
+        .route(
+            tool_request("compact", json!({"progressToken": "c"})),
+            ctx.clone(),
+        )
+        .await;
+    let methods: Vec<Value> = std::iter::from_fn(|| notifications.try_recv().ok()).collect();
+    assert_eq!(methods.len(), 2);
+    assert_eq!(methods[0]["method"], "notifications/progress");
+    assert_eq!(methods[1]["method"], "notifications/progress/complete");
+    assert_eq!(methods[1]["params"]["status"], "success");
+    assert_eq!(methods[1]["params"]["summary"], "freed 3 segments");
+
+    // A handler that fails completes with an error status
+    server
+        .router()
+        .route(
+            tool_request("verify", json!({"progressToken": "v"})),
+            ctx.clone(),
+        )
+        .await;
+    assert_eq!(
+        notifications.try_recv().unwrap()["method"],
+        "notifications/progress"
+    );
+    let complete = notifications.try_recv().unwrap();
+    assert_eq!(complete["method"], "notifications/progress/complete");
+    assert_eq!(complete["params"]["progressToken"], "v");
+    assert_eq!(complete["params"]["status"], "error");
+    assert!(notifications.try_recv().is_err());
+}
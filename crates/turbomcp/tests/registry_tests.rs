@@ -28,6 +28,7 @@ fn dummy_tool_handler(
                 meta: None,
             })],
             is_error: Some(false),
+            ..Default::default()
         })
     })
 }
@@ -45,6 +46,7 @@ fn dummy_resource_handler(
                 text: "dummy resource".to_string(),
                 meta: None,
             })],
+            range: None,
         })
     })
 }
@@ -0,0 +1,101 @@
+//! Tests for `#[resource]` methods serving binary content
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use serde_json::{Value, json};
+use turbomcp::{JsonRpcRequest, McpResult, RequestContext, ResourceBody, TypedResource};
+use turbomcp_macros::{resource, server, tool};
+
+/// A 1x1 transparent PNG
+const PIXEL: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0x00, 0x01, 0x00, 0x00,
+    0x05, 0x00, 0x01, 0x0d, 0x0a, 0x2d, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae,
+    0x42, 0x60, 0x82,
+];
+
+#[derive(Clone)]
+struct Gallery;
+
+#[server(name = "Gallery", version = "1.0.0")]
+impl Gallery {
+    #[tool("Count the images")]
+    async fn count(&self) -> McpResult<String> {
+        Ok("1".to_string())
+    }
+
+    #[resource("images://{name}", mime_type = "image/png")]
+    async fn image(&self, _name: String) -> McpResult<Vec<u8>> {
+        Ok(PIXEL.to_vec())
+    }
+
+    #[resource("thumbnails://{name}")]
+    async fn thumbnail(&self, _name: String) -> McpResult<Vec<u8>> {
+        Ok(PIXEL.to_vec())
+    }
+
+    #[resource("captions://{name}")]
+    async fn caption(&self, name: String) -> McpResult<ResourceBody> {
+        Ok(ResourceBody::from(format!("A pixel named {name}")))
+    }
+
+    #[resource("downloads://{name}")]
+    async fn download(&self, name: String) -> McpResult<TypedResource> {
+        Ok(TypedResource::new(
+            name.into_bytes(),
+            "application/x-custom",
+        ))
+    }
+}
+
+async fn read(uri: &str) -> Value {
+    let (server, _shutdown) = Gallery.into_server_with_shutdown().unwrap();
+    let request: JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "resources/read",
+        "params": { "uri": uri }
+    }))
+    .unwrap();
+    let response = server.router().route(request, RequestContext::new()).await;
+    let response = serde_json::to_value(response).unwrap();
+    response["result"]["contents"][0].clone()
+}
+
+fn decode(contents: &Value) -> Vec<u8> {
+    BASE64
+        .decode(contents["blob"].as_str().expect("content is a blob"))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_bytes_round_trip_through_base64() {
+    let contents = read("images://pixel").await;
+    assert_eq!(contents["uri"], "images://pixel");
+    assert_eq!(contents["mimeType"], "image/png");
+    assert!(contents.get("text").is_none());
+    assert_eq!(decode(&contents), PIXEL);
+}
+
+#[tokio::test]
+async fn test_undeclared_blob_type_is_sniffed() {
+    let contents = read("thumbnails://pixel").await;
+    assert_eq!(contents["mimeType"], "image/png");
+    assert_eq!(decode(&contents), PIXEL);
+}
+
+#[tokio::test]
+async fn test_text_bodies_stay_text() {
+    let contents = read("captions://pixel").await;
+    assert_eq!(contents["text"], "A pixel named pixel");
+    assert_eq!(contents["mimeType"], "text/plain");
+    assert!(contents.get("blob").is_none());
+}
+
+#[tokio::test]
+async fn test_typed_bytes_keep_their_type() {
+    let contents = read("downloads://archive").await;
+    assert_eq!(contents["mimeType"], "application/x-custom");
+    assert_eq!(decode(&contents), b"archive");
+}
@@ -0,0 +1,97 @@
+//! Tests for linking tool results to the server's own resources
+
+use serde_json::{Value, json};
+use turbomcp::handlers::FunctionResourceHandler;
+use turbomcp::{Content, Context, JsonRpcRequest, McpResult, RequestContext, Resource, Server};
+use turbomcp_macros::{server, tool};
+use turbomcp_protocol::types::{ReadResourceResult, ResourceContent, TextResourceContents};
+
+#[derive(Clone)]
+struct Library;
+
+#[server(name = "Library", version = "1.0.0")]
+impl Library {
+    #[tool("Link to a book")]
+    async fn cite(&self, ctx: Context, uri: String) -> McpResult<Content> {
+        ctx.resource_link(&uri)
+    }
+}
+
+fn library() -> Server {
+    let (server, _shutdown) = Library.into_server_with_shutdown().unwrap();
+    let book = Resource {
+        name: "book".to_string(),
+        title: Some("Book".to_string()),
+        uri: "books://{isbn}".to_string(),
+        description: Some("A book in the catalogue".to_string()),
+        mime_type: Some("text/plain".to_string()),
+        annotations: None,
+        size: None,
+        meta: None,
+    };
+    let handler = FunctionResourceHandler::new(book, |request, _ctx| async move {
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContent::Text(TextResourceContents {
+                uri: request.uri,
+                mime_type: Some("text/plain".to_string()),
+                text: "Once upon a time".to_string(),
+                meta: None,
+            })],
+            range: None,
+        })
+    });
+    server
+        .registry()
+        .register_resource("book", handler)
+        .unwrap();
+    server
+}
+
+fn cite_request(uri: &str) -> JsonRpcRequest {
+    serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "tools/call",
+        "params": {"name": "cite", "arguments": {"uri": uri}}
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_link_to_registered_resource() {
+    let server = library();
+    let response = server
+        .router()
+        .route(
+            cite_request("books://978-0262510875"),
+            RequestContext::new(),
+        )
+        .await;
+    assert!(response.error.is_none(), "{:?}", response.error);
+
+    let result = response.result.unwrap();
+    let text = result["content"][0]["text"].as_str().unwrap();
+    let link: Value = serde_json::from_str(text).unwrap();
+    assert_eq!(link["type"], "resource_link");
+    assert_eq!(link["uri"], "books://978-0262510875");
+    assert_eq!(link["name"], "book");
+    assert_eq!(link["title"], "Book");
+    assert_eq!(link["mimeType"], "text/plain");
+}
+
+#[tokio::test]
+async fn test_link_to_unknown_resource_is_rejected() {
+    let server = library();
+    let response = server
+        .router()
+        .route(cite_request("films://vertigo"), RequestContext::new())
+        .await;
+    let error = response
+        .error
+        .expect("an unknown resource cannot be linked");
+    assert!(
+        error.message.contains("films://vertigo"),
+        "{}",
+        error.message
+    );
+}
@@ -0,0 +1,108 @@
+//! Tests for the MIME types `#[resource]` methods serve their content with
+
+use serde_json::{Value, json};
+use turbomcp::{JsonRpcRequest, McpResult, RequestContext, TypedResource};
+use turbomcp_macros::{resource, server, tool};
+
+#[derive(Clone)]
+struct Settings;
+
+#[server(name = "Settings", version = "1.0.0")]
+impl Settings {
+    #[tool("Describe the settings")]
+    async fn describe(&self) -> McpResult<String> {
+        Ok("Settings by section".to_string())
+    }
+
+    #[resource("config://{section}", mime_type = "application/json")]
+    async fn config(&self, section: String) -> McpResult<String> {
+        Ok(json!({ "section": section }).to_string())
+    }
+
+    #[resource("notes://{page}")]
+    async fn notes(&self, page: u32) -> McpResult<String> {
+        Ok(format!("Notes, page {page}"))
+    }
+
+    #[resource(uri = "exports://{name}", mime_type = "text/plain")]
+    async fn export(&self, name: String) -> McpResult<TypedResource> {
+        Ok(match name.as_str() {
+            "settings" => TypedResource::new("[general]", "application/toml"),
+            _ => TypedResource::new(format!("no export {name}"), "text/plain"),
+        })
+    }
+}
+
+async fn route(server: &turbomcp::Server, method: &str, params: Value) -> Value {
+    let request: JsonRpcRequest = serde_json::from_value(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params
+    }))
+    .unwrap();
+    let response = server.router().route(request, RequestContext::new()).await;
+    serde_json::to_value(response).unwrap()
+}
+
+async fn read(uri: &str) -> Value {
+    let (server, _shutdown) = Settings.into_server_with_shutdown().unwrap();
+    route(&server, "resources/read", json!({ "uri": uri })).await
+}
+
+#[tokio::test]
+async fn test_declared_mime_type_is_served() {
+    let read = read("config://network").await;
+    let contents = &read["result"]["contents"][0];
+    assert_eq!(contents["uri"], "config://network");
+    assert_eq!(contents["mimeType"], "application/json");
+    assert_eq!(contents["text"], r#"{"section":"network"}"#);
+}
+
+#[tokio::test]
+async fn test_undeclared_mime_type_defaults_to_text_plain() {
+    let read = read("notes://3").await;
+    let contents = &read["result"]["contents"][0];
+    assert_eq!(contents["mimeType"], "text/plain");
+    assert_eq!(contents["text"], "Notes, page 3");
+}
+
+#[tokio::test]
+async fn test_mime_type_chosen_while_reading_wins() {
+    let read_settings = read("exports://settings").await;
+    assert_eq!(
+        read_settings["result"]["contents"][0]["mimeType"],
+        "application/toml"
+    );
+
+    let read_other = read("exports://other").await;
+    assert_eq!(
+        read_other["result"]["contents"][0]["mimeType"],
+        "text/plain"
+    );
+}
+
+#[tokio::test]
+async fn test_unparsable_template_values_are_invalid_params() {
+    let read = read("notes://first").await;
+    assert_eq!(read["error"]["code"], -32602, "{read}");
+}
+
+#[tokio::test]
+async fn test_listing_carries_declared_mime_types() {
+    let (server, _shutdown) = Settings.into_server_with_shutdown().unwrap();
+    let listed = route(&server, "resources/list", json!({})).await;
+    let resources = listed["result"]["resources"].as_array().unwrap();
+    let mime_type = |uri: &str| {
+        resources
+            .iter()
+            .find(|resource| resource["uri"] == uri)
+            .map(|resource| resource.get("mimeType").cloned())
+            .unwrap()
+    };
+    assert_eq!(
+        mime_type("config://{section}"),
+        Some(json!("application/json"))
+    );
+    assert_eq!(mime_type("notes://{page}"), None);
+}
@@ -3,7 +3,7 @@
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::types::{CallToolRequest, CallToolResult, Content, TextContent};
 use turbomcp_server::ServerError;
-use turbomcp_server::{handlers::utils, ServerBuilder};
+use turbomcp_server::{ServerBuilder, handlers::utils};
 
 #[tokio::main]
 async fn main() -> Result<(), ServerError> {
@@ -32,7 +32,7 @@ async fn main() -> Result<(), ServerError> {
                         annotations: None,
                         meta: None,
                     })],
-                    is_error: None,
+                    ..Default::default()
                 })
             },
         ),
@@ -51,7 +51,7 @@ async fn main() -> Result<(), ServerError> {
                         annotations: None,
                         meta: None,
                     })],
-                    is_error: None,
+                    ..Default::default()
                 })
             },
         ),
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use turbomcp_core::RequestContext;
 use turbomcp_protocol::types::{CallToolRequest, CallToolResult, Content, TextContent};
 use turbomcp_server::ServerError;
-use turbomcp_server::{handlers::utils, ServerBuilder};
+use turbomcp_server::{ServerBuilder, handlers::utils};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct AddParams {
@@ -41,7 +41,7 @@ async fn main() -> Result<(), ServerError> {
                         annotations: None,
                         meta: None,
                     })],
-                    is_error: None,
+                    ..Default::default()
                 })
             },
         ),
@@ -67,7 +67,7 @@ async fn main() -> Result<(), ServerError> {
                         annotations: None,
                         meta: None,
                     })],
-                    is_error: None,
+                    ..Default::default()
                 })
             },
         ),